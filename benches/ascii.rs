@@ -0,0 +1,41 @@
+//! Measures the ASCII fast path of the `Decoder`, by comparing it with the `decode` function,
+//! which decodes every character through the multi-byte decoding, as `Decoder::next` did before
+//! the fast path.
+//!
+//! Run with `cargo bench --bench ascii`.
+
+extern crate utf8_decode;
+
+use std::hint::black_box;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use utf8_decode::{Decoder, decode};
+
+/// Log-like corpus of about 1 MiB, mostly ASCII.
+fn corpus() -> Vec<u8> {
+    let text = "2024-03-01T12:00:00Z INFO server: request handled in 12ms (path=/api/v1/users, status=200)\n2024-03-01T12:00:01Z WARN client: user \"José\" sent an invalid token\n";
+    text.repeat((1 << 20) / text.len()).into_bytes()
+}
+
+fn ascii(c: &mut Criterion) {
+    let bytes = corpus();
+    let mut group = c.benchmark_group("ascii");
+    group.throughput(Throughput::Bytes(bytes.len() as u64));
+
+    group.bench_function("decode", |b| b.iter(|| {
+        let mut iter = black_box(&bytes[..]).iter().cloned();
+        while let Some(c) = decode(&mut iter) {
+            black_box(c.unwrap());
+        }
+    }));
+
+    group.bench_function("Decoder", |b| b.iter(|| {
+        for c in Decoder::new(black_box(&bytes[..]).iter().cloned()) {
+            black_box(c.unwrap());
+        }
+    }));
+
+    group.finish()
+}
+
+criterion_group!(benches, ascii);
+criterion_main!(benches);
@@ -0,0 +1,41 @@
+//! Measures the lead byte table driven decoding of `decode` on a multilingual corpus, mixing
+//! characters of every length.
+//!
+//! Run with `cargo bench --bench lead_bytes`, and compare with a run on a revision preceding the
+//! lead byte table.
+
+extern crate utf8_decode;
+
+use std::hint::black_box;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use utf8_decode::{Decoder, decode};
+
+/// Multilingual corpus of about 1 MiB.
+fn corpus() -> Vec<u8> {
+    let text = "English, Français, Ελληνικά, Русский, العربية, עברית, हिन्दी, 日本語, 한국어, 中文, 😀🚀🌍, Ünïcödé façade.\n";
+    text.repeat((1 << 20) / text.len()).into_bytes()
+}
+
+fn lead_bytes(c: &mut Criterion) {
+    let bytes = corpus();
+    let mut group = c.benchmark_group("lead_bytes");
+    group.throughput(Throughput::Bytes(bytes.len() as u64));
+
+    group.bench_function("decode", |b| b.iter(|| {
+        let mut iter = black_box(&bytes[..]).iter().cloned();
+        while let Some(c) = decode(&mut iter) {
+            black_box(c.unwrap());
+        }
+    }));
+
+    group.bench_function("Decoder", |b| b.iter(|| {
+        for c in Decoder::new(black_box(&bytes[..]).iter().cloned()) {
+            black_box(c.unwrap());
+        }
+    }));
+
+    group.finish()
+}
+
+criterion_group!(benches, lead_bytes);
+criterion_main!(benches);
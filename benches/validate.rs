@@ -0,0 +1,34 @@
+//! Compares `validate` with collecting the `Decoder` output into a `String`.
+//!
+//! Run with `cargo bench --bench validate`.
+
+extern crate utf8_decode;
+
+use std::hint::black_box;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use utf8_decode::{Decoder, validate};
+
+/// Multilingual corpus of about 1 MiB.
+fn corpus() -> Vec<u8> {
+    let text = "The quick brown fox jumps over the lazy dog. Ünïcödé façade, naïve café. Съешь же ещё этих мягких французских булок. 色は匂へど散りぬるを. 🦊🐶🌍\n";
+    text.repeat((1 << 20) / text.len()).into_bytes()
+}
+
+fn validate_corpus(c: &mut Criterion) {
+    let bytes = corpus();
+    let mut group = c.benchmark_group("validate");
+    group.throughput(Throughput::Bytes(bytes.len() as u64));
+
+    group.bench_function("collect::<Result<String>>", |b| b.iter(|| {
+        black_box(Decoder::new(black_box(&bytes[..]).iter().cloned()).collect::<std::io::Result<String>>().unwrap())
+    }));
+
+    group.bench_function("validate", |b| b.iter(|| {
+        validate(black_box(&bytes[..]).iter().cloned()).unwrap()
+    }));
+
+    group.finish()
+}
+
+criterion_group!(benches, validate_corpus);
+criterion_main!(benches);
@@ -4,6 +4,7 @@ use std::fs::File;
 use std::io::Read;
 use utf8_decode::UnsafeDecoder;
 
+#[allow(clippy::unbuffered_bytes)]
 fn main() -> std::io::Result<()> {
     let file = File::open("examples/file.txt")?;
 
@@ -0,0 +1,300 @@
+#!/usr/bin/env python3
+# Generates the Unicode property tables of `src/tables` from the Unicode Character Database
+# shipped with the Python `unicodedata` module.
+#
+# Usage: python3 scripts/unicode.py
+
+import os
+import subprocess
+import unicodedata
+
+TABLES = os.path.join(os.path.dirname(__file__), '..', 'src', 'tables')
+
+HEADER = '''// This file is generated by `scripts/unicode.py` from the Unicode Character Database,
+// version {version}. Do not edit it manually.
+
+'''
+
+
+def runs(prop, default):
+	"""Group the code points into `(first, last, value)` runs of equal property value,
+	skipping the runs of `default` value."""
+	result = []
+	start = 0
+	current = prop(0)
+	for cp in range(1, 0x110001):
+		value = prop(cp) if cp < 0x110000 else None
+		if value != current:
+			if current != default:
+				result.append((start, cp - 1, current))
+			start = cp
+			current = value
+	return result
+
+
+def ranges(prop):
+	"""Group the code points with the given boolean property into `(first, last)` ranges."""
+	return [(first, last) for (first, last, _) in runs(lambda cp: prop(cp) or None, None)]
+
+
+def range_set(name, doc, table):
+	body = '/// ' + doc + '\n'
+	body += 'pub(crate) const ' + name.upper() + ': &[(u32, u32)] = &[\n'
+	for (first, last) in table:
+		body += '\t(0x{:x}, 0x{:x}),\n'.format(first, last)
+	body += '];\n'
+	write(name, body)
+
+
+def perl_invmap(prop):
+	"""Property inversion map of the UCD shipped with Perl (same version as `unicodedata`),
+	for the properties not provided by Python. Returns a list of `(first, last, value)`."""
+	script = (
+		'use Unicode::UCD "prop_invmap";'
+		'my ($l, $m) = prop_invmap("' + prop + '");'
+		'print "$l->[$_] $m->[$_]\\n" for 0..$#$l;'
+	)
+	output = subprocess.run(['perl', '-e', script], check=True, capture_output=True, text=True).stdout
+	entries = [line.split(' ', 1) for line in output.splitlines()]
+	result = []
+	for i, (first, value) in enumerate(entries):
+		last = int(entries[i + 1][0]) - 1 if i + 1 < len(entries) else 0x10ffff
+		result.append((int(first), last, value))
+	return result
+
+
+def perl_invlist(prop):
+	"""Set of the code points with the given binary property, from the UCD shipped with Perl."""
+	script = (
+		'use Unicode::UCD "prop_invlist";'
+		'print "$_\\n" for prop_invlist("' + prop + '");'
+	)
+	output = subprocess.run(['perl', '-e', script], check=True, capture_output=True, text=True).stdout
+	bounds = [int(line) for line in output.splitlines()] + [0x110000]
+	result = set()
+	for i in range(0, len(bounds) - 1, 2):
+		result.update(range(bounds[i], bounds[i + 1]))
+	return result
+
+
+def write(name, body):
+	with open(os.path.join(TABLES, name + '.rs'), 'w') as f:
+		f.write(HEADER.format(version=unicodedata.unidata_version))
+		f.write(body)
+
+
+def east_asian_width():
+	names = {
+		'A': 'Ambiguous',
+		'F': 'Fullwidth',
+		'H': 'Halfwidth',
+		'Na': 'Narrow',
+		'W': 'Wide'
+	}
+	table = runs(lambda cp: unicodedata.east_asian_width(chr(cp)), 'N')
+	body = 'use crate::EastAsianWidth::{self, *};\n\n'
+	body += '/// East Asian Width of the code points not `Neutral`.\n'
+	body += 'pub(crate) const EAST_ASIAN_WIDTH: &[(u32, u32, EastAsianWidth)] = &[\n'
+	for (first, last, value) in table:
+		body += '\t(0x{:x}, 0x{:x}, {}),\n'.format(first, last, names[value])
+	body += '];\n'
+	write('east_asian_width', body)
+
+
+def age():
+	table = [(first, last, value) for (first, last, value) in perl_invmap('Age') if value != 'Unassigned']
+	body = 'use crate::UnicodeVersion;\n\n'
+	body += '/// Unicode version in which each assigned code point was assigned.\n'
+	body += 'pub(crate) const AGE: &[(u32, u32, UnicodeVersion)] = &[\n'
+	for (first, last, value) in table:
+		major, minor = value.split('.')
+		body += '\t(0x{:x}, 0x{:x}, UnicodeVersion::new({}, {})),\n'.format(first, last, major, minor)
+	body += '];\n'
+	write('age', body)
+
+
+def strong_rtl():
+	table = ranges(lambda cp: unicodedata.bidirectional(chr(cp)) in ('R', 'AL'))
+	range_set('strong_rtl', 'Code points with a strong right-to-left bidi class (`R` or `AL`).', table)
+
+
+def format():
+	table = ranges(lambda cp: unicodedata.category(chr(cp)) == 'Cf')
+	range_set('format', 'Format characters (general category `Cf`).', table)
+
+
+def combining_mark():
+	table = ranges(lambda cp: unicodedata.category(chr(cp)) in ('Mn', 'Mc', 'Me'))
+	range_set('combining_mark', 'Combining marks (general categories `Mn`, `Mc` and `Me`).', table)
+
+
+def complex_rendering():
+	def complex(cp):
+		c = chr(cp)
+		return (
+			unicodedata.category(c) in ('Mn', 'Mc', 'Me')
+			or unicodedata.bidirectional(c) in ('R', 'AL')
+			or unicodedata.east_asian_width(c) in ('W', 'F')
+			or cp == 0x200d
+		)
+	table = ranges(complex)
+	range_set(
+		'complex_rendering',
+		'Combining marks, strong right-to-left, wide and fullwidth characters, and ZERO WIDTH JOINER.',
+		table
+	)
+
+
+def ident():
+	start = perl_invlist('XID_Start')
+	cont = perl_invlist('XID_Continue')
+	role = lambda cp: 'Start' if cp in start else ('Continue' if cp in cont else None)
+	table = runs(role, None)
+	body = 'use crate::IdentRole::{self, *};\n\n'
+	body += '/// Identifier role of the code points with the `XID_Start` or `XID_Continue` property.\n'
+	body += 'pub(crate) const IDENT: &[(u32, u32, IdentRole)] = &[\n'
+	for (first, last, value) in table:
+		body += '\t(0x{:x}, 0x{:x}, {}),\n'.format(first, last, value)
+	body += '];\n'
+	write('ident', body)
+
+
+def emoji():
+	emoji = perl_invlist('Emoji')
+	table = ranges(lambda cp: cp in emoji)
+	range_set('emoji', 'Code points with the `Emoji` property.', table)
+
+
+def grapheme_break():
+	names = {
+		'CR': 'CR',
+		'LF': 'LF',
+		'Control': 'Control',
+		'Extend': 'Extend',
+		'ZWJ': 'ZWJ',
+		'Regional_Indicator': 'RegionalIndicator',
+		'Prepend': 'Prepend',
+		'SpacingMark': 'SpacingMark',
+		'L': 'L',
+		'V': 'V',
+		'T': 'T',
+		'LV': 'LV',
+		'LVT': 'LVT',
+		'ExtPict_XX': 'ExtendedPictographic'
+	}
+	table = [(first, last, value) for (first, last, value) in perl_invmap('Grapheme_Cluster_Break') if value != 'Other']
+	body = 'use crate::grapheme::GraphemeBreak::{self, *};\n\n'
+	body += '/// Grapheme cluster break property of the code points not `Other`.\n'
+	body += '/// Extended pictographic code points are included with their own value.\n'
+	body += 'pub(crate) const GRAPHEME_BREAK: &[(u32, u32, GraphemeBreak)] = &[\n'
+	for (first, last, value) in table:
+		body += '\t(0x{:x}, 0x{:x}, {}),\n'.format(first, last, names[value])
+	body += '];\n'
+	write('grapheme_break', body)
+
+
+def numeric():
+	def value(cp):
+		n = unicodedata.numeric(chr(cp), None)
+		return int(n) if n is not None and n.is_integer() else None
+	table = runs(value, None)
+	body = '/// Numeric value of the code points with an integer numeric value.\n'
+	body += 'pub(crate) const NUMERIC: &[(u32, u32, i64)] = &[\n'
+	for (first, last, value) in table:
+		body += '\t(0x{:x}, 0x{:x}, {}),\n'.format(first, last, value)
+	body += '];\n'
+	write('numeric', body)
+
+
+def canonical_decomposition():
+	table = []
+	for cp in range(0x110000):
+		if 0xac00 <= cp <= 0xd7a3 or 0xd800 <= cp < 0xe000:
+			continue
+		c = chr(cp)
+		decomposed = unicodedata.normalize('NFD', c)
+		if decomposed != c:
+			table.append((cp, decomposed))
+	body = '/// Full canonical decomposition of the decomposable characters, except Hangul syllables.\n'
+	body += 'pub(crate) const CANONICAL_DECOMPOSITION: &[(u32, &str)] = &[\n'
+	for (cp, decomposed) in table:
+		escaped = ''.join('\\u{{{:x}}}'.format(ord(c)) for c in decomposed)
+		body += '\t(0x{:x}, "{}"),\n'.format(cp, escaped)
+	body += '];\n'
+	write('canonical_decomposition', body)
+
+	table = runs(lambda cp: unicodedata.combining(chr(cp)), 0)
+	body = '/// Canonical combining class of the code points with a non-zero class.\n'
+	body += 'pub(crate) const COMBINING_CLASS: &[(u32, u32, u8)] = &[\n'
+	for (first, last, value) in table:
+		body += '\t(0x{:x}, 0x{:x}, {}),\n'.format(first, last, value)
+	body += '];\n'
+	write('combining_class', body)
+
+
+def case_folding():
+	table = []
+	for cp in range(0x110000):
+		c = chr(cp)
+		if not 0xd800 <= cp < 0xe000 and c.casefold() != c.lower():
+			table.append((cp, c.casefold()))
+	body = '/// Full case folding of the characters whose folding differs from their lowercase mapping.\n'
+	body += 'pub(crate) const CASE_FOLDING: &[(u32, &str)] = &[\n'
+	for (cp, folded) in table:
+		escaped = ''.join('\\u{{{:x}}}'.format(ord(c)) for c in folded)
+		body += '\t(0x{:x}, "{}"),\n'.format(cp, escaped)
+	body += '];\n'
+	write('case_folding', body)
+
+
+# Blocks covered by the character name table.
+NAME_BLOCKS = [
+	(0x0000, 0x07ff), # Basic Latin to NKo.
+	(0x2000, 0x2bff), # General Punctuation to Miscellaneous Symbols and Arrows.
+	(0x3000, 0x30ff), # CJK Symbols and Punctuation, Hiragana and Katakana.
+	(0x1f300, 0x1faff) # Miscellaneous Symbols and Pictographs to Symbols and Pictographs Extended-A.
+]
+
+
+def names():
+	algorithmic = []
+	table = []
+	for cp in range(0x110000):
+		name = unicodedata.name(chr(cp), '')
+		suffix = '-{:04X}'.format(cp)
+		if name.endswith(suffix):
+			prefix = name[:-len(suffix) + 1]
+			if algorithmic and algorithmic[-1][1] == cp - 1 and algorithmic[-1][2] == prefix:
+				algorithmic[-1] = (algorithmic[-1][0], cp, prefix)
+			else:
+				algorithmic.append((cp, cp, prefix))
+		elif name and not name.startswith('HANGUL SYLLABLE ') and any(first <= cp <= last for (first, last) in NAME_BLOCKS):
+			table.append((cp, name))
+
+	body = '/// Code point ranges whose names are derived by appending the code point\n'
+	body += '/// hexadecimal value to a prefix.\n'
+	body += 'pub(crate) const ALGORITHMIC_NAMES: &[(u32, u32, &str)] = &[\n'
+	for (first, last, prefix) in algorithmic:
+		body += '\t(0x{:x}, 0x{:x}, "{}"),\n'.format(first, last, prefix)
+	body += '];\n\n'
+	body += '/// Names of the characters of the covered blocks.\n'
+	body += 'pub(crate) const NAMES: &[(u32, &str)] = &[\n'
+	for (cp, name) in table:
+		body += '\t(0x{:x}, "{}"),\n'.format(cp, name)
+	body += '];\n'
+	write('names', body)
+
+
+east_asian_width()
+age()
+strong_rtl()
+format()
+names()
+complex_rendering()
+ident()
+case_folding()
+emoji()
+numeric()
+combining_mark()
+canonical_decomposition()
+grapheme_break()
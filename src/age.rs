@@ -0,0 +1,83 @@
+use std::io::Result;
+use crate::tables;
+
+/// Version of the Unicode Standard.
+///
+/// Versions are ordered chronologically.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct UnicodeVersion {
+	/// Major version number.
+	pub major: u8,
+
+	/// Minor version number.
+	pub minor: u8
+}
+
+impl UnicodeVersion {
+	/// Version of the Unicode Character Database the assignment tables are generated from.
+	///
+	/// Characters assigned in later versions are considered unassigned whatever the version.
+	pub const LATEST: UnicodeVersion = UnicodeVersion::new(14, 0);
+
+	/// Creates a new Unicode version.
+	pub const fn new(major: u8, minor: u8) -> UnicodeVersion {
+		UnicodeVersion {
+			major,
+			minor
+		}
+	}
+
+	/// Returns the version in which the given character was assigned, or `None` if it is
+	/// unassigned as of [`LATEST`](UnicodeVersion::LATEST).
+	pub fn of(c: char) -> Option<UnicodeVersion> {
+		tables::lookup(tables::AGE, c)
+	}
+
+	/// Checks if the given character is assigned as of this version.
+	pub fn is_assigned(&self, c: char) -> bool {
+		match UnicodeVersion::of(c) {
+			Some(age) => age <= *self,
+			None => false
+		}
+	}
+}
+
+/// UTF-8 decoder iterator yielding each character with whether it is assigned as of a given
+/// Unicode version.
+///
+/// Created by the [`Decoder::with_assignment`](crate::Decoder::with_assignment) method.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::{Decoder, UnicodeVersion};
+/// // U+1FAE0 MELTING FACE was added in Unicode 14.0.
+/// let mut decoder = Decoder::new("a🫠".bytes()).with_assignment(UnicodeVersion::new(13, 0));
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('a', true));
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('🫠', false));
+///
+/// let mut decoder = Decoder::new("a🫠".bytes()).with_assignment(UnicodeVersion::new(14, 0));
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('a', true));
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('🫠', true));
+/// ```
+pub struct WithAssignment<I: Iterator<Item=Result<char>>> {
+	chars: I,
+	version: UnicodeVersion
+}
+
+impl<I: Iterator<Item=Result<char>>> WithAssignment<I> {
+	pub(crate) fn new(chars: I, version: UnicodeVersion) -> WithAssignment<I> {
+		WithAssignment {
+			chars,
+			version
+		}
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for WithAssignment<I> {
+	type Item = Result<(char, bool)>;
+
+	fn next(&mut self) -> Option<Result<(char, bool)>> {
+		let version = self.version;
+		self.chars.next().map(|c| c.map(|c| (c, version.is_assigned(c))))
+	}
+}
@@ -0,0 +1,265 @@
+use std::io::Result;
+use std::collections::HashMap;
+use crate::Decoder;
+
+/// Returns the length of the longest run of consecutive ASCII bytes (`< 0x80`) in the given
+/// bytes.
+///
+/// The bytes are only scanned, not decoded, which makes it a cheap diagnostic to decide if an
+/// ASCII fast path is worthwhile.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::longest_ascii_run;
+/// assert_eq!(longest_ascii_run(b"Hello World!"), 12);
+/// assert_eq!(longest_ascii_run("añb€cdé".as_bytes()), 2);
+/// assert_eq!(longest_ascii_run("é€".as_bytes()), 0);
+/// ```
+pub fn longest_ascii_run(bytes: &[u8]) -> usize {
+	bytes.split(|b| !b.is_ascii()).map(|run| run.len()).max().unwrap_or(0)
+}
+
+/// Narrowest class of characters fitting a text.
+///
+/// Classes are ordered from the narrowest to the widest.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum TextClass {
+	/// Only ASCII characters (up to U+007F).
+	Ascii,
+
+	/// Only Latin-1 characters (up to U+00FF).
+	Latin1,
+
+	/// Only characters of the Basic Multilingual Plane (up to U+FFFF).
+	Bmp,
+
+	/// Any character.
+	Full
+}
+
+impl TextClass {
+	/// Returns the narrowest class of the given character.
+	pub fn of(c: char) -> TextClass {
+		match c as u32 {
+			0x00..=0x7F => TextClass::Ascii,
+			0x80..=0xFF => TextClass::Latin1,
+			0x100..=0xFFFF => TextClass::Bmp,
+			_ => TextClass::Full
+		}
+	}
+}
+
+/// Decodes the given bytes and returns the narrowest class fitting all the characters.
+///
+/// This can be used to pick a storage representation: bytes for [`Ascii`](TextClass::Ascii)
+/// and [`Latin1`](TextClass::Latin1), UTF-16 code units without surrogates for
+/// [`Bmp`](TextClass::Bmp), or UTF-8 for [`Full`](TextClass::Full).
+/// An empty input is [`Ascii`](TextClass::Ascii).
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::{classify, TextClass};
+/// assert_eq!(classify("Hello".as_bytes()).unwrap(), TextClass::Ascii);
+/// assert_eq!(classify("Héllo".as_bytes()).unwrap(), TextClass::Latin1);
+/// assert_eq!(classify("Hello €".as_bytes()).unwrap(), TextClass::Bmp);
+/// assert_eq!(classify("Hello 🌍".as_bytes()).unwrap(), TextClass::Full);
+/// ```
+///
+/// ## Errors
+/// Returns the first decoding error encountered.
+pub fn classify(bytes: &[u8]) -> Result<TextClass> {
+	let mut class = TextClass::Ascii;
+	for c in Decoder::new(bytes.iter().cloned()) {
+		class = class.max(TextClass::of(c?));
+	}
+
+	Ok(class)
+}
+
+/// Initial capacity of the [`char_frequencies`] map, for large inputs.
+const FREQUENCIES_CAPACITY: usize = 256;
+
+/// Decodes the given bytes and counts the occurrences of each character.
+///
+/// The map is preallocated for up to 256 distinct characters, or the length of the input if
+/// shorter, since it cannot contain more distinct characters than bytes.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::char_frequencies;
+/// let frequencies = char_frequencies("abracadabra €€".as_bytes()).unwrap();
+/// assert_eq!(frequencies.len(), 7);
+/// assert_eq!(frequencies[&'a'], 5);
+/// assert_eq!(frequencies[&'b'], 2);
+/// assert_eq!(frequencies[&'r'], 2);
+/// assert_eq!(frequencies[&'c'], 1);
+/// assert_eq!(frequencies[&'d'], 1);
+/// assert_eq!(frequencies[&' '], 1);
+/// assert_eq!(frequencies[&'€'], 2);
+///
+/// assert!(char_frequencies(b"abc\xff").is_err());
+/// ```
+///
+/// ## Errors
+/// Returns the first decoding error encountered.
+pub fn char_frequencies(bytes: &[u8]) -> Result<HashMap<char, usize>> {
+	let mut frequencies = HashMap::with_capacity(bytes.len().min(FREQUENCIES_CAPACITY));
+	for c in Decoder::new(bytes.iter().cloned()) {
+		*frequencies.entry(c?).or_insert(0) += 1
+	}
+
+	Ok(frequencies)
+}
+
+/// Decodes the given bytes and returns the Shannon entropy of the distribution of characters,
+/// in bits per character.
+///
+/// This is `0` for an empty input or a single repeated character, and `log2(n)` for a text
+/// where `n` distinct characters are equally frequent. Natural language text has a much lower
+/// entropy than random or encrypted data decoded as text.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::char_entropy;
+/// assert_eq!(char_entropy(b"").unwrap(), 0.0);
+/// assert_eq!(char_entropy("ééééé".as_bytes()).unwrap(), 0.0);
+/// assert!((char_entropy(b"abcdabcd").unwrap() - 2.0).abs() < 1e-12);
+/// assert!((char_entropy("aé€😀".as_bytes()).unwrap() - 2.0).abs() < 1e-12);
+///
+/// let english = char_entropy(b"the quick brown fox jumps over the lazy dog").unwrap();
+/// assert!(english > 3.0 && english < 5.0);
+///
+/// assert!(char_entropy(b"abc\xff").is_err());
+/// ```
+///
+/// ## Errors
+/// Returns the first decoding error encountered.
+pub fn char_entropy(bytes: &[u8]) -> Result<f64> {
+	let frequencies = char_frequencies(bytes)?;
+	let total: usize = frequencies.values().sum();
+	let total = total as f64;
+	Ok(frequencies.values().map(|&n| {
+		let p = n as f64 / total;
+		-p * p.log2()
+	}).sum::<f64>().max(0.0))
+}
+
+/// Kind of byte order mark.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum BomKind {
+	/// UTF-8 byte order mark (`EF BB BF`).
+	Utf8,
+
+	/// UTF-16 little endian byte order mark (`FF FE`).
+	Utf16Le,
+
+	/// UTF-16 big endian byte order mark (`FE FF`).
+	Utf16Be,
+
+	/// UTF-32 little endian byte order mark (`FF FE 00 00`).
+	Utf32Le,
+
+	/// UTF-32 big endian byte order mark (`00 00 FE FF`).
+	Utf32Be
+}
+
+impl BomKind {
+	/// Returns the kind of the byte order mark starting the given bytes, if any.
+	///
+	/// Since the UTF-32LE mark starts with the UTF-16LE mark, bytes starting with
+	/// `FF FE 00 00` are considered UTF-32LE.
+	pub fn detect(bytes: &[u8]) -> Option<BomKind> {
+		const BOMS: [(&[u8], BomKind); 5] = [
+			(&[0xEF, 0xBB, 0xBF], BomKind::Utf8),
+			(&[0xFF, 0xFE, 0x00, 0x00], BomKind::Utf32Le),
+			(&[0x00, 0x00, 0xFE, 0xFF], BomKind::Utf32Be),
+			(&[0xFF, 0xFE], BomKind::Utf16Le),
+			(&[0xFE, 0xFF], BomKind::Utf16Be)
+		];
+
+		BOMS.iter().find(|(bom, _)| bytes.starts_with(bom)).map(|&(_, kind)| kind)
+	}
+}
+
+/// Summary of the encoding properties of some bytes.
+///
+/// Created by the [`analyze`] function.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct EncodingAnalysis {
+	/// Whether the bytes are valid UTF-8.
+	pub valid_utf8: bool,
+
+	/// Byte order mark starting the bytes, if any.
+	pub bom: Option<BomKind>,
+
+	/// Ratio of ASCII bytes, `1.0` for empty input.
+	pub ascii_ratio: f32,
+
+	/// Whether the bytes are not valid UTF-8 but look like Latin-1 (ISO-8859-1) text.
+	pub likely_latin1: bool
+}
+
+/// Analyzes the given bytes to help deciding how to decode them.
+///
+/// The bytes are valid UTF-8 if they can be decoded by a [`Decoder`](crate::Decoder), including
+/// a UTF-8 byte order mark. The bytes look like Latin-1 if they are not valid UTF-8, do not start
+/// with a byte order mark, and only contain printable characters, tabulations and new lines in
+/// Latin-1: C0 control characters other than whitespace, DEL and C1 control characters are rare
+/// in Latin-1 text.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::{analyze, BomKind};
+/// let analysis = analyze("Hello World! 🌍".as_bytes());
+/// assert!(analysis.valid_utf8);
+/// assert_eq!(analysis.bom, None);
+/// assert_eq!(analysis.ascii_ratio, 13.0 / 17.0);
+/// assert!(!analysis.likely_latin1);
+///
+/// // "Café" in Latin-1.
+/// let analysis = analyze(b"Caf\xe9");
+/// assert!(!analysis.valid_utf8);
+/// assert_eq!(analysis.ascii_ratio, 0.75);
+/// assert!(analysis.likely_latin1);
+///
+/// let analysis = analyze(b"\xef\xbb\xbfHi");
+/// assert!(analysis.valid_utf8);
+/// assert_eq!(analysis.bom, Some(BomKind::Utf8));
+///
+/// // "Hi" in UTF-16LE.
+/// let analysis = analyze(b"\xff\xfeH\x00i\x00");
+/// assert!(!analysis.valid_utf8);
+/// assert_eq!(analysis.bom, Some(BomKind::Utf16Le));
+/// assert!(!analysis.likely_latin1);
+/// ```
+pub fn analyze(bytes: &[u8]) -> EncodingAnalysis {
+	let valid_utf8 = Decoder::new(bytes.iter().cloned()).all(|c| c.is_ok());
+	let bom = BomKind::detect(bytes);
+
+	let mut ascii = 0;
+	let mut latin1 = true;
+	for &b in bytes {
+		if b.is_ascii() {
+			ascii += 1
+		}
+
+		latin1 &= match b {
+			b'\t' | b'\n' | b'\r' => true,
+			0x00..=0x1F | 0x7F..=0x9F => false,
+			_ => true
+		}
+	}
+
+	let ascii_ratio = if bytes.is_empty() {
+		1.0
+	} else {
+		ascii as f32 / bytes.len() as f32
+	};
+
+	EncodingAnalysis {
+		valid_utf8,
+		bom,
+		ascii_ratio,
+		likely_latin1: !valid_utf8 && bom.is_none() && latin1
+	}
+}
@@ -0,0 +1,131 @@
+use std::io::Result;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use futures_core::Stream;
+use crate::StreamingDecoder;
+
+/// UTF-8 decoder of an asynchronous byte stream.
+///
+/// Bytes are decoded with a [`StreamingDecoder`](crate::StreamingDecoder) as they are pulled
+/// out of the source [`Stream`](futures_core::Stream), such as a socket of an asynchronous
+/// runtime, so that a character may span several polls: `Poll::Pending` is returned whenever
+/// the source stream has no byte available, even in the middle of a character.
+/// The decoder is itself a [`Stream`](futures_core::Stream) of characters.
+///
+/// ## Example
+/// ```rust
+/// # use std::{io, pin::Pin, task::{Context, Poll, Waker, RawWaker, RawWakerVTable}};
+/// # use futures_core::Stream;
+/// # use utf8_decode::AsyncDecoder;
+/// # fn noop_waker() -> Waker {
+/// #     fn clone(_: *const ()) -> RawWaker {
+/// #         RawWaker::new(std::ptr::null(), &VTABLE)
+/// #     }
+/// #     fn noop(_: *const ()) {}
+/// #     static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+/// #     unsafe { Waker::from_raw(clone(std::ptr::null())) }
+/// # }
+/// // Stream yielding one byte every two polls.
+/// struct Bytes<'a> {
+///     bytes: &'a [u8],
+///     ready: bool
+/// }
+///
+/// impl<'a> Stream for Bytes<'a> {
+///     type Item = io::Result<u8>;
+///
+///     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<io::Result<u8>>> {
+///         self.ready = !self.ready;
+///         if self.ready {
+///             let (first, rest) = match self.bytes.split_first() {
+///                 Some((&first, rest)) => (first, rest),
+///                 None => return Poll::Ready(None)
+///             };
+///             self.bytes = rest;
+///             Poll::Ready(Some(Ok(first)))
+///         } else {
+///             cx.waker().wake_by_ref();
+///             Poll::Pending
+///         }
+///     }
+/// }
+///
+/// let mut decoder = AsyncDecoder::new(Bytes { bytes: "a€😀".as_bytes(), ready: false });
+/// let waker = noop_waker();
+/// let mut cx = Context::from_waker(&waker);
+/// let mut string = String::new();
+/// let mut pending = 0;
+/// loop {
+///     match Pin::new(&mut decoder).poll_next(&mut cx) {
+///         Poll::Ready(Some(c)) => string.push(c.unwrap()),
+///         Poll::Ready(None) => break,
+///         Poll::Pending => pending += 1
+///     }
+/// }
+///
+/// assert_eq!(string, "a€😀");
+/// // One pending poll per byte.
+/// assert_eq!(pending, 8);
+/// ```
+///
+/// ## Errors
+/// Errors are the same as the [`StreamingDecoder`](crate::StreamingDecoder) errors. Errors of
+/// the source stream are forwarded.
+pub struct AsyncDecoder<S: Stream<Item=Result<u8>> + Unpin> {
+	bytes: S,
+	decoder: StreamingDecoder,
+	done: bool
+}
+
+impl<S: Stream<Item=Result<u8>> + Unpin> AsyncDecoder<S> {
+	/// Creates a new `AsyncDecoder` stream from the given `io::Result<u8>` source stream.
+	pub fn new(source: S) -> AsyncDecoder<S> {
+		AsyncDecoder {
+			bytes: source,
+			decoder: StreamingDecoder::new(),
+			done: false
+		}
+	}
+
+	/// Returns a reference to the source stream.
+	pub fn get_ref(&self) -> &S {
+		&self.bytes
+	}
+
+	/// Returns a mutable reference to the source stream.
+	pub fn get_mut(&mut self) -> &mut S {
+		&mut self.bytes
+	}
+
+	/// Unwraps this `AsyncDecoder`, returning the source stream.
+	///
+	/// The bytes of a partially received character are lost.
+	pub fn into_inner(self) -> S {
+		self.bytes
+	}
+}
+
+impl<S: Stream<Item=Result<u8>> + Unpin> Stream for AsyncDecoder<S> {
+	type Item = Result<char>;
+
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Result<char>>> {
+		let this = &mut *self;
+		if this.done {
+			return Poll::Ready(None)
+		}
+
+		loop {
+			match Pin::new(&mut this.bytes).poll_next(cx) {
+				Poll::Ready(Some(Ok(b))) => if let Some(c) = this.decoder.push(b) {
+					return Poll::Ready(Some(c))
+				},
+				Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+				Poll::Ready(None) => {
+					this.done = true;
+					return Poll::Ready(this.decoder.finish())
+				},
+				Poll::Pending => return Poll::Pending
+			}
+		}
+	}
+}
@@ -0,0 +1,121 @@
+use std::io::{Result, Error};
+
+/// Number of characters in each batch of a [`Batched`] decoder.
+pub const BATCH_SIZE: usize = 16;
+
+/// UTF-8 decoder iterator yielding characters in batches.
+///
+/// Created by the [`Decoder::batched`](crate::Decoder::batched) method.
+/// Each batch holds [`BATCH_SIZE`](crate::BATCH_SIZE) characters, except for the last one which
+/// holds the remaining characters. This reduces the per-item overhead for bulk consumers.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let s = "é".repeat(40);
+/// let sizes: Vec<usize> = Decoder::new(s.bytes()).batched().map(|b| b.unwrap().len()).collect();
+/// assert_eq!(sizes, [16, 16, 8]);
+/// ```
+///
+/// ## Errors
+/// If a decoding error occurs, the batch being filled is replaced by this error.
+/// The following characters are collected in a new batch.
+pub struct Batched<I: Iterator<Item=Result<char>>> {
+	chars: I
+}
+
+impl<I: Iterator<Item=Result<char>>> Batched<I> {
+	pub(crate) fn new(chars: I) -> Batched<I> {
+		Batched {
+			chars
+		}
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for Batched<I> {
+	type Item = Result<Vec<char>>;
+
+	fn next(&mut self) -> Option<Result<Vec<char>>> {
+		let mut batch = Vec::with_capacity(BATCH_SIZE);
+		while batch.len() < BATCH_SIZE {
+			match self.chars.next() {
+				Some(Ok(c)) => batch.push(c),
+				Some(Err(e)) => return Some(Err(e)),
+				None => break
+			}
+		}
+
+		if batch.is_empty() {
+			None
+		} else {
+			Some(Ok(batch))
+		}
+	}
+}
+
+/// UTF-8 decoder iterator yielding runs of identical characters.
+///
+/// Created by the [`Decoder::run_length`](crate::Decoder::run_length) method.
+/// Consecutive identical characters are collapsed into a single `(c, count)` pair.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let runs: Vec<(char, usize)> = Decoder::new("aaabbbc".bytes()).run_length().map(Result::unwrap).collect();
+/// assert_eq!(runs, [('a', 3), ('b', 3), ('c', 1)]);
+///
+/// let mut decoder = Decoder::new(b"aa\xffaa".iter().cloned()).run_length();
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('a', 2));
+/// assert!(decoder.next().unwrap().is_err());
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('a', 2));
+/// assert!(decoder.next().is_none());
+/// ```
+///
+/// ## Errors
+/// Decoding errors are forwarded, ending the current run.
+pub struct RunLength<I: Iterator<Item=Result<char>>> {
+	chars: I,
+	run: Option<(char, usize)>,
+	error: Option<Error>
+}
+
+impl<I: Iterator<Item=Result<char>>> RunLength<I> {
+	pub(crate) fn new(chars: I) -> RunLength<I> {
+		RunLength {
+			chars,
+			run: None,
+			error: None
+		}
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for RunLength<I> {
+	type Item = Result<(char, usize)>;
+
+	fn next(&mut self) -> Option<Result<(char, usize)>> {
+		if let Some(e) = self.error.take() {
+			return Some(Err(e))
+		}
+
+		loop {
+			match self.chars.next() {
+				Some(Ok(c)) => match &mut self.run {
+					Some((run_c, count)) if *run_c == c => *count += 1,
+					run => {
+						if let Some(previous) = run.replace((c, 1)) {
+							return Some(Ok(previous))
+						}
+					}
+				},
+				Some(Err(e)) => match self.run.take() {
+					Some(run) => {
+						self.error = Some(e);
+						return Some(Ok(run))
+					},
+					None => return Some(Err(e))
+				},
+				None => return self.run.take().map(Ok)
+			}
+		}
+	}
+}
@@ -0,0 +1,33 @@
+use std::io::Result;
+use crate::{Decoder, tables};
+
+/// Checks if the given character has a strong right-to-left bidi class
+/// (`R` or `AL`, see [Unicode Standard Annex #9](https://www.unicode.org/reports/tr9/)).
+pub fn is_rtl(c: char) -> bool {
+	tables::contains(tables::STRONG_RTL, c)
+}
+
+/// Decodes the given bytes and checks if they contain any character with a strong right-to-left
+/// bidi class.
+///
+/// Decoding stops at the first right-to-left character. This is a cheap test to decide whether
+/// bidirectional rendering is required, not a full direction analysis.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::contains_rtl;
+/// assert_eq!(contains_rtl("Hello World!".as_bytes()).unwrap(), false);
+/// assert_eq!(contains_rtl("Hello مرحبا!".as_bytes()).unwrap(), true);
+/// ```
+///
+/// ## Errors
+/// Returns the first decoding error encountered before any right-to-left character.
+pub fn contains_rtl(bytes: &[u8]) -> Result<bool> {
+	for c in Decoder::new(bytes.iter().cloned()) {
+		if is_rtl(c?) {
+			return Ok(true)
+		}
+	}
+
+	Ok(false)
+}
@@ -0,0 +1,84 @@
+use std::io::Result;
+use std::iter::FusedIterator;
+use crate::Decoder;
+
+/// Byte order mark, as decoded.
+const BOM: char = '\u{feff}';
+
+/// UTF-8 decoder iterator removing the byte order mark starting the input, if any.
+///
+/// Created by the [`Decoder::skip_bom`](crate::Decoder::skip_bom) and
+/// [`Decoder::new_skip_bom`](crate::Decoder::new_skip_bom) methods.
+/// Only the very first character is removed if it is U+FEFF: a U+FEFF appearing later in the
+/// input is a ZERO WIDTH NO-BREAK SPACE, and is kept. The [`had_bom`](SkipBom::had_bom) method
+/// tells, once decoding started, if a byte order mark was removed.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// fn skip_bom(bytes: &[u8]) -> String {
+///     Decoder::new_skip_bom(bytes.iter().cloned()).collect::<std::io::Result<String>>().unwrap()
+/// }
+///
+/// assert_eq!(skip_bom(b"\xef\xbb\xbfabc"), "abc");
+/// assert_eq!(skip_bom(b"abc"), "abc");
+/// assert_eq!(skip_bom(b"\xef\xbb\xbf"), "");
+/// assert_eq!(skip_bom(b"\xef\xbb\xbf\xef\xbb\xbfa"), "\u{feff}a");
+/// assert_eq!(skip_bom(b"a\xef\xbb\xbfb"), "a\u{feff}b");
+///
+/// let mut decoder = Decoder::new(b"\xef\xbb\xbfa".iter().cloned()).skip_bom();
+/// assert!(!decoder.had_bom());
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'a');
+/// assert!(decoder.had_bom());
+///
+/// let mut decoder = Decoder::new(b"\xef\xbb\xbf".iter().cloned()).skip_bom();
+/// assert!(decoder.next().is_none());
+/// assert!(decoder.had_bom());
+/// ```
+///
+/// ## Errors
+/// Decoding errors are the same as the [`Decoder`](crate::Decoder) errors.
+pub struct SkipBom<R: Iterator<Item=u8>> {
+	decoder: Decoder<R>,
+	started: bool,
+	had_bom: bool
+}
+
+impl<R: Iterator<Item=u8>> SkipBom<R> {
+	pub(crate) fn new(decoder: Decoder<R>) -> SkipBom<R> {
+		SkipBom {
+			decoder,
+			started: false,
+			had_bom: false
+		}
+	}
+
+	/// Tells if the input started with a byte order mark.
+	///
+	/// This is always `false` before the first call to `next`.
+	pub fn had_bom(&self) -> bool {
+		self.had_bom
+	}
+}
+
+impl<R: Iterator<Item=u8>> Iterator for SkipBom<R> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		if !self.started {
+			self.started = true;
+			match self.decoder.next()? {
+				Ok(BOM) => self.had_bom = true,
+				c => return Some(c)
+			}
+		}
+
+		self.decoder.next()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.decoder.size_hint()
+	}
+}
+
+impl<R: FusedIterator<Item=u8>> FusedIterator for SkipBom<R> {}
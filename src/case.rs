@@ -0,0 +1,84 @@
+use std::io::Result;
+use crate::tables;
+
+/// Maximum number of characters a single character folds to.
+const MAX_FOLDING_LEN: usize = 3;
+
+/// UTF-8 decoder iterator yielding the case-folded characters of the input.
+///
+/// Created by the [`Decoder::casefold`](crate::Decoder::casefold) method.
+/// Characters are mapped using the full case folding of the Unicode Character Database, which
+/// matches [`char::to_lowercase`] except for a few hundred characters (such as `ß` folding to
+/// `ss`). A character may fold into several characters: they are buffered and yielded one by
+/// one. Two strings are equal under case-insensitive comparison if their foldings are equal.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// fn casefold(s: &str) -> String {
+///     Decoder::new(s.bytes()).casefold().collect::<std::io::Result<String>>().unwrap()
+/// }
+///
+/// assert_eq!(casefold("ß"), "ss");
+/// assert_eq!(casefold("İ"), "i\u{307}");
+/// assert_eq!(casefold("ſ"), "s");
+/// assert_eq!(casefold("Straße"), casefold("STRASSE"));
+/// assert_ne!(casefold("Straße"), casefold("Strasbourg"));
+/// ```
+///
+/// ## Errors
+/// Decoding errors are forwarded.
+pub struct CaseFold<I: Iterator<Item=Result<char>>> {
+	chars: I,
+	pending: [char; MAX_FOLDING_LEN],
+	start: usize,
+	end: usize
+}
+
+impl<I: Iterator<Item=Result<char>>> CaseFold<I> {
+	pub(crate) fn new(chars: I) -> CaseFold<I> {
+		CaseFold {
+			chars,
+			pending: ['\0'; MAX_FOLDING_LEN],
+			start: 0,
+			end: 0
+		}
+	}
+
+	/// Buffer the folding of the given character.
+	fn fold(&mut self, c: char) {
+		self.start = 0;
+		self.end = 0;
+		match tables::CASE_FOLDING.binary_search_by_key(&(c as u32), |&(code, _)| code) {
+			Ok(i) => {
+				for folded in tables::CASE_FOLDING[i].1.chars() {
+					self.pending[self.end] = folded;
+					self.end += 1
+				}
+			},
+			Err(_) => {
+				for folded in c.to_lowercase() {
+					self.pending[self.end] = folded;
+					self.end += 1
+				}
+			}
+		}
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for CaseFold<I> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		if self.start == self.end {
+			match self.chars.next()? {
+				Ok(c) => self.fold(c),
+				Err(e) => return Some(Err(e))
+			}
+		}
+
+		let c = self.pending[self.start];
+		self.start += 1;
+		Some(Ok(c))
+	}
+}
@@ -0,0 +1,48 @@
+use std::io::Result;
+use crate::tables;
+
+/// Checks if the given character has the `Emoji` property
+/// (see [Unicode Technical Standard #51](https://www.unicode.org/reports/tr51/)).
+///
+/// Note that this property also covers some characters usually rendered as text, such as the
+/// ASCII digits, `#` and `*`, which are only rendered as emoji when followed by a variation
+/// selector or a keycap.
+pub fn is_emoji(c: char) -> bool {
+	tables::contains(tables::EMOJI, c)
+}
+
+/// UTF-8 decoder iterator yielding each character with whether it has the `Emoji` property.
+///
+/// Created by the [`Decoder::with_emoji_flag`](crate::Decoder::with_emoji_flag) method.
+/// See [`is_emoji`](crate::is_emoji) for the definition of an emoji character.
+/// Characters are flagged individually: this does not recognize emoji sequences, such as
+/// ZWJ sequences or flags, whose components are flagged one by one and the joiners are not.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let mut decoder = Decoder::new("a😀\u{200d}".bytes()).with_emoji_flag();
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('a', false));
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('😀', true));
+/// // ZERO WIDTH JOINER, used in emoji sequences but not an emoji itself.
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('\u{200d}', false));
+/// ```
+pub struct WithEmojiFlag<I: Iterator<Item=Result<char>>> {
+	chars: I
+}
+
+impl<I: Iterator<Item=Result<char>>> WithEmojiFlag<I> {
+	pub(crate) fn new(chars: I) -> WithEmojiFlag<I> {
+		WithEmojiFlag {
+			chars
+		}
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for WithEmojiFlag<I> {
+	type Item = Result<(char, bool)>;
+
+	fn next(&mut self) -> Option<Result<(char, bool)>> {
+		self.chars.next().map(|c| c.map(|c| (c, is_emoji(c))))
+	}
+}
@@ -0,0 +1,249 @@
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io::{Error, ErrorKind};
+
+/// UTF-8 decoding error.
+///
+/// Returned by the [`TypedDecoder`](crate::TypedDecoder) iterator, and reported to the
+/// [`ErrorSink`](crate::ErrorSink) of lossy decoders. Each variant gives the byte offset of the
+/// first byte of the faulty sequence.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::{TypedDecoder, DecodeError};
+/// let mut decoder = TypedDecoder::new(b"a\xe0\x80\xaf".iter().cloned());
+/// decoder.next();
+/// let error = decoder.next().unwrap().unwrap_err();
+/// assert_eq!(error, DecodeError::Overlong { offset: 1, code_point: 0x2f });
+/// assert_eq!(error.offset(), 1);
+/// assert_eq!(error.to_string(), "overlong UTF-8 sequence for U+002F at byte 1.");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum DecodeError {
+	/// The byte cannot start a UTF-8 sequence.
+	///
+	/// This is either a continuation byte (`0x80` to `0xBF`) or a byte that never appears in
+	/// UTF-8 (`0xC0`, `0xC1` and `0xF5` to `0xFF`).
+	InvalidLeadByte {
+		/// Offset of the byte.
+		offset: usize,
+
+		/// The invalid byte.
+		byte: u8
+	},
+
+	/// The byte cannot continue the UTF-8 sequence.
+	InvalidContinuation {
+		/// Offset of the first byte of the sequence.
+		offset: usize,
+
+		/// The invalid byte.
+		byte: u8
+	},
+
+	/// The sequence encodes a code point that has a shorter encoding.
+	///
+	/// The lossy decoders report this error after the first continuation byte, with the code
+	/// point partially decoded: its remaining bits are zero.
+	Overlong {
+		/// Offset of the first byte of the sequence.
+		offset: usize,
+
+		/// The (partial) encoded code point.
+		code_point: u32
+	},
+
+	/// The sequence encodes a surrogate code point (U+D800 to U+DFFF).
+	///
+	/// The lossy decoders report this error after the first continuation byte, with the code
+	/// point partially decoded: its remaining bits are zero.
+	Surrogate {
+		/// Offset of the first byte of the sequence.
+		offset: usize,
+
+		/// The (partial) encoded code point.
+		code_point: u32
+	},
+
+	/// The sequence encodes a code point greater than U+10FFFF.
+	///
+	/// The lossy decoders report this error after the first continuation byte, with the code
+	/// point partially decoded: its remaining bits are zero.
+	OutOfRange {
+		/// Offset of the first byte of the sequence.
+		offset: usize,
+
+		/// The (partial) encoded code point.
+		code_point: u32
+	},
+
+	/// The input ends before the end of a UTF-8 sequence.
+	UnexpectedEof {
+		/// Offset of the first byte of the sequence.
+		offset: usize
+	}
+}
+
+impl DecodeError {
+	/// Byte offset of the first byte of the faulty sequence.
+	pub fn offset(&self) -> usize {
+		match *self {
+			DecodeError::InvalidLeadByte { offset, .. } => offset,
+			DecodeError::InvalidContinuation { offset, .. } => offset,
+			DecodeError::Overlong { offset, .. } => offset,
+			DecodeError::Surrogate { offset, .. } => offset,
+			DecodeError::OutOfRange { offset, .. } => offset,
+			DecodeError::UnexpectedEof { offset } => offset
+		}
+	}
+}
+
+impl DecodeError {
+	/// Writes the description of the error, without its offset.
+	fn fmt_message(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			DecodeError::InvalidLeadByte { byte, .. } => write!(f, "invalid lead byte 0x{:02X} in UTF-8 sequence", byte),
+			DecodeError::InvalidContinuation { byte, .. } => write!(f, "invalid continuation byte 0x{:02X} in UTF-8 sequence", byte),
+			DecodeError::Overlong { code_point, .. } => write!(f, "overlong UTF-8 sequence for U+{:04X}", code_point),
+			DecodeError::Surrogate { code_point, .. } => write!(f, "surrogate code point U+{:04X} in UTF-8 sequence", code_point),
+			DecodeError::OutOfRange { code_point, .. } => write!(f, "code point U+{:X} out of range", code_point),
+			DecodeError::UnexpectedEof { .. } => write!(f, "unexpected end of UTF-8 sequence")
+		}
+	}
+
+	/// Message of the `io::Error` reporting this error, without its offset. The
+	/// [`OffsetError`] wrapping it appends the offset, giving the same message as this error.
+	#[cfg(feature = "std")]
+	pub(crate) fn message(&self) -> String {
+		format!("{}.", Message(self))
+	}
+}
+
+impl fmt::Display for DecodeError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.fmt_message(f)?;
+		write!(f, " at byte {}.", self.offset())
+	}
+}
+
+/// Description of a [`DecodeError`], without its offset.
+#[cfg(feature = "std")]
+struct Message<'a>(&'a DecodeError);
+
+#[cfg(feature = "std")]
+impl<'a> fmt::Display for Message<'a> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.0.fmt_message(f)
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+/// Existing `io::Result` code keeps working: the error converts into an
+/// [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof) or
+/// [`InvalidData`](std::io::ErrorKind::InvalidData) `io::Error` wrapping it.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::{Decoder, TypedDecoder, DecodeError};
+/// let error: std::io::Error = DecodeError::Overlong { offset: 1, code_point: 0x2f }.into();
+/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+/// assert_eq!(error.to_string(), "overlong UTF-8 sequence for U+002F at byte 1.");
+///
+/// // The errors of the `Decoder` use the same wording when they give the same details.
+/// for bytes in &[&b"a\xff"[..], b"a\xed\xa0\x80", b"a\xe2\x82"] {
+///     let typed = TypedDecoder::new(bytes.iter().cloned()).nth(1).unwrap().unwrap_err();
+///     let error = Decoder::new(bytes.iter().cloned()).nth(1).unwrap().unwrap_err();
+///     assert_eq!(typed.to_string(), error.to_string());
+/// }
+/// ```
+#[cfg(feature = "std")]
+impl From<DecodeError> for Error {
+	fn from(e: DecodeError) -> Error {
+		let kind = match e {
+			DecodeError::UnexpectedEof { .. } => ErrorKind::UnexpectedEof,
+			_ => ErrorKind::InvalidData
+		};
+
+		Error::new(kind, e)
+	}
+}
+
+/// Decoding error located in the input.
+///
+/// The errors returned by the [`Decoder`](crate::Decoder) and
+/// [`UnsafeDecoder`](crate::UnsafeDecoder) iterators wrap this type, which gives the offset of
+/// the first byte of the faulty sequence. It can be retrieved with
+/// [`io::Error::get_ref`](std::io::Error::get_ref).
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::{Decoder, UnsafeDecoder, OffsetError};
+/// let mut decoder = Decoder::new([0x41, 0xff].iter().cloned());
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'A');
+///
+/// let error = decoder.next().unwrap().unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+/// assert_eq!(error.to_string(), "invalid lead byte 0xFF in UTF-8 sequence at byte 1.");
+/// let offset_error = error.get_ref().unwrap().downcast_ref::<OffsetError>().unwrap();
+/// assert_eq!(offset_error.offset(), 1);
+///
+/// // The offset is the one of the first byte of the sequence.
+/// let mut decoder = Decoder::new([0x41, 0xe2, 0x82, 0x41].iter().cloned());
+/// decoder.next();
+/// assert_eq!(decoder.next().unwrap().unwrap_err().to_string(), "invalid UTF-8 sequence at byte 1.");
+///
+/// let mut decoder = UnsafeDecoder::new([0x41, 0xff].iter().cloned().map(Ok));
+/// decoder.next();
+/// let error = decoder.next().unwrap().unwrap_err();
+/// assert_eq!(error.get_ref().unwrap().downcast_ref::<OffsetError>().unwrap().offset(), 1);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct OffsetError {
+	offset: usize,
+	error: Error
+}
+
+#[cfg(feature = "std")]
+impl OffsetError {
+	pub(crate) fn new(offset: usize, error: Error) -> OffsetError {
+		OffsetError {
+			offset,
+			error
+		}
+	}
+
+	/// Byte offset of the first byte of the faulty sequence.
+	pub fn offset(&self) -> usize {
+		self.offset
+	}
+
+	/// Returns the underlying error, without offset.
+	pub fn into_inner(self) -> Error {
+		self.error
+	}
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for OffsetError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let message = self.error.to_string();
+		write!(f, "{} at byte {}.", message.trim_end_matches('.'), self.offset)
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OffsetError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		Some(&self.error)
+	}
+}
+
+#[cfg(feature = "std")]
+impl From<OffsetError> for Error {
+	fn from(e: OffsetError) -> Error {
+		Error::new(e.error.kind(), e)
+	}
+}
@@ -0,0 +1,21 @@
+use std::io::Result;
+use std::fmt::Write;
+
+/// Collect the given characters, escaping the non-ASCII ones as `\uXXXX` sequences.
+/// Characters outside the Basic Multilingual Plane are escaped as a UTF-16 surrogate pair.
+pub(crate) fn escape_unicode_json<I: Iterator<Item=Result<char>>>(chars: I) -> Result<String> {
+	let mut string = String::new();
+	let mut units = [0; 2];
+	for c in chars {
+		let c = c?;
+		if c.is_ascii() {
+			string.push(c)
+		} else {
+			for unit in c.encode_utf16(&mut units) {
+				write!(string, "\\u{:04x}", unit).unwrap()
+			}
+		}
+	}
+
+	Ok(string)
+}
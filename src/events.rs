@@ -0,0 +1,76 @@
+use std::iter::FusedIterator;
+use crate::{DecodeError, TypedDecoder};
+
+/// Event of a decoded stream.
+///
+/// Yielded by the [`Events`](crate::Events) iterator.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum DecodeEvent {
+	/// Decoded character, other than `'\n'`.
+	Char(char),
+
+	/// Decoded `'\n'` character.
+	LineBreak,
+
+	/// Invalid UTF-8 sequence. Decoding resumes after it.
+	Error(DecodeError),
+
+	/// End of the input.
+	Eof
+}
+
+/// UTF-8 decoder iterator yielding the decoded characters along with the structural events of
+/// the stream.
+///
+/// Created by the [`Decoder::events`](crate::Decoder::events) method.
+/// Line breaks (`'\n'`) are yielded as [`DecodeEvent::LineBreak`] events, and errors as
+/// [`DecodeEvent::Error`] events, after which decoding resumes. The last event is always
+/// [`DecodeEvent::Eof`].
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::{Decoder, DecodeEvent, DecodeError};
+/// let events: Vec<_> = Decoder::new(b"a\n\xff\xc3\xa9".iter().cloned()).events().collect();
+/// assert_eq!(events, [
+///     DecodeEvent::Char('a'),
+///     DecodeEvent::LineBreak,
+///     DecodeEvent::Error(DecodeError::InvalidLeadByte { offset: 2, byte: 0xff }),
+///     DecodeEvent::Char('é'),
+///     DecodeEvent::Eof
+/// ]);
+/// ```
+pub struct Events<R: Iterator<Item=u8>> {
+	decoder: TypedDecoder<R>,
+	done: bool
+}
+
+impl<R: Iterator<Item=u8>> Events<R> {
+	pub(crate) fn new(decoder: TypedDecoder<R>) -> Events<R> {
+		Events {
+			decoder,
+			done: false
+		}
+	}
+}
+
+impl<R: Iterator<Item=u8>> Iterator for Events<R> {
+	type Item = DecodeEvent;
+
+	fn next(&mut self) -> Option<DecodeEvent> {
+		if self.done {
+			return None
+		}
+
+		match self.decoder.next() {
+			Some(Ok('\n')) => Some(DecodeEvent::LineBreak),
+			Some(Ok(c)) => Some(DecodeEvent::Char(c)),
+			Some(Err(e)) => Some(DecodeEvent::Error(e)),
+			None => {
+				self.done = true;
+				Some(DecodeEvent::Eof)
+			}
+		}
+	}
+}
+
+impl<R: Iterator<Item=u8>> FusedIterator for Events<R> {}
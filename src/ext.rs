@@ -0,0 +1,47 @@
+use std::io::Result;
+use crate::{Decoder, UnsafeDecoder};
+
+/// Extension trait decoding byte iterators.
+///
+/// It is implemented for every iterator of `u8`, decoded with a [`Decoder`](crate::Decoder),
+/// and for every iterator of `io::Result<u8>`, decoded with an
+/// [`UnsafeDecoder`](crate::UnsafeDecoder). The type parameter `T` is the item type of the
+/// iterator, and is always inferred.
+///
+/// ## Example
+/// ```rust
+/// # use std::io::Read;
+/// # use utf8_decode::Utf8DecodeExt;
+/// let bytes = "aé€😀".as_bytes();
+/// let string: std::io::Result<String> = bytes.iter().cloned().decode_utf8().collect();
+/// assert_eq!(string.unwrap(), "aé€😀");
+///
+/// let string: std::io::Result<String> = bytes.bytes().decode_utf8().collect();
+/// assert_eq!(string.unwrap(), "aé€😀");
+///
+/// let non_ascii = "héllo wörld".bytes().decode_utf8().lossy().filter_map(Result::ok).filter(|c| !c.is_ascii()).count();
+/// assert_eq!(non_ascii, 2);
+/// ```
+pub trait Utf8DecodeExt<T>: Sized {
+	/// Decoder iterator.
+	type Decoder: Iterator<Item=Result<char>>;
+
+	/// Decodes this byte iterator.
+	fn decode_utf8(self) -> Self::Decoder;
+}
+
+impl<I: Iterator<Item=u8>> Utf8DecodeExt<u8> for I {
+	type Decoder = Decoder<I>;
+
+	fn decode_utf8(self) -> Decoder<I> {
+		Decoder::new(self)
+	}
+}
+
+impl<I: Iterator<Item=Result<u8>>> Utf8DecodeExt<Result<u8>> for I {
+	type Decoder = UnsafeDecoder<I>;
+
+	fn decode_utf8(self) -> UnsafeDecoder<I> {
+		UnsafeDecoder::new(self)
+	}
+}
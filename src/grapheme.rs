@@ -0,0 +1,89 @@
+use crate::tables;
+
+/// Grapheme cluster break property of a character (UAX #29).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[allow(clippy::upper_case_acronyms)]
+pub(crate) enum GraphemeBreak {
+	CR,
+	LF,
+	Control,
+	Extend,
+	ZWJ,
+	RegionalIndicator,
+	Prepend,
+	SpacingMark,
+	L,
+	V,
+	T,
+	LV,
+	LVT,
+
+	/// `Other` character with the `Extended_Pictographic` property.
+	ExtendedPictographic,
+
+	Other
+}
+
+impl GraphemeBreak {
+	fn of(c: char) -> GraphemeBreak {
+		tables::lookup(tables::GRAPHEME_BREAK, c).unwrap_or(GraphemeBreak::Other)
+	}
+}
+
+/// Extended grapheme cluster boundary detector, fed one character at a time.
+///
+/// Implements the rules GB3 to GB13 of UAX #29.
+pub(crate) struct Boundaries {
+	/// Break property of the previous character, if any.
+	previous: Option<GraphemeBreak>,
+
+	/// Whether the previous characters are an extended pictographic character followed by
+	/// `Extend` characters (GB11).
+	pictographic: bool,
+
+	/// Whether the previous character is preceded by a sequence of regional indicators of odd
+	/// length, itself included (GB12 and GB13).
+	odd_regional_indicators: bool
+}
+
+impl Boundaries {
+	pub(crate) fn new() -> Boundaries {
+		Boundaries {
+			previous: None,
+			pictographic: false,
+			odd_regional_indicators: false
+		}
+	}
+
+	/// Returns `true` if a grapheme cluster boundary precedes the given character, which is the
+	/// character following the previously given one.
+	///
+	/// There is always a boundary before the first character.
+	pub(crate) fn is_boundary_before(&mut self, c: char) -> bool {
+		use GraphemeBreak::*;
+		let next = GraphemeBreak::of(c);
+		let boundary = match (self.previous, next) {
+			(None, _) => true,
+			(Some(CR), LF) => false,
+			(Some(CR), _) | (Some(LF), _) | (Some(Control), _) => true,
+			(_, CR) | (_, LF) | (_, Control) => true,
+			(Some(L), L) | (Some(L), V) | (Some(L), LV) | (Some(L), LVT) => false,
+			(Some(LV), V) | (Some(LV), T) | (Some(V), V) | (Some(V), T) => false,
+			(Some(LVT), T) | (Some(T), T) => false,
+			(_, Extend) | (_, ZWJ) | (_, SpacingMark) | (Some(Prepend), _) => false,
+			(Some(ZWJ), ExtendedPictographic) => !self.pictographic,
+			(Some(RegionalIndicator), RegionalIndicator) => !self.odd_regional_indicators,
+			_ => true
+		};
+
+		self.pictographic = match next {
+			ExtendedPictographic => true,
+			// Nothing can follow the ZWJ ending the sequence but the pictographic character.
+			Extend | ZWJ => self.pictographic && self.previous != Some(ZWJ),
+			_ => false
+		};
+		self.odd_regional_indicators = next == RegionalIndicator && !(self.previous == Some(RegionalIndicator) && self.odd_regional_indicators);
+		self.previous = Some(next);
+		boundary
+	}
+}
@@ -0,0 +1,141 @@
+use std::io::Result;
+use crate::Decoder;
+
+/// UTF-8 decoder iterator yielding each character with a rolling polynomial hash.
+///
+/// Created by the [`Decoder::with_rolling_hash`](crate::Decoder::with_rolling_hash) method.
+/// The hash yielded with a character is the polynomial hash of all the characters decoded so
+/// far, using `char as u32` as symbol: `h = h * base + c` (with wrapping arithmetic), starting
+/// from `0`. This is the hash used by the Rabin-Karp substring search algorithm.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let hashes: Vec<u64> = Decoder::new("aé😀".bytes()).with_rolling_hash(31).map(|r| r.unwrap().1).collect();
+///
+/// let mut h = 0u64;
+/// let expected: Vec<u64> = "aé😀".chars().map(|c| {
+///     h = h.wrapping_mul(31).wrapping_add(c as u64);
+///     h
+/// }).collect();
+///
+/// assert_eq!(hashes, expected);
+/// ```
+///
+/// ## Errors
+/// Decoding errors are forwarded, and leave the hash unchanged.
+pub struct WithRollingHash<I: Iterator<Item=Result<char>>> {
+	chars: I,
+	base: u64,
+	hash: u64
+}
+
+impl<I: Iterator<Item=Result<char>>> WithRollingHash<I> {
+	pub(crate) fn new(chars: I, base: u64) -> WithRollingHash<I> {
+		WithRollingHash {
+			chars,
+			base,
+			hash: 0
+		}
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for WithRollingHash<I> {
+	type Item = Result<(char, u64)>;
+
+	fn next(&mut self) -> Option<Result<(char, u64)>> {
+		match self.chars.next()? {
+			Ok(c) => {
+				self.hash = self.hash.wrapping_mul(self.base).wrapping_add(c as u64);
+				Some(Ok((c, self.hash)))
+			},
+			Err(e) => Some(Err(e))
+		}
+	}
+}
+
+/// Byte iterator computing the Fletcher-16 checksum of the bytes it outputs.
+struct Fletcher16<R: Iterator<Item=u8>> {
+	bytes: R,
+	sum1: u16,
+	sum2: u16
+}
+
+impl<R: Iterator<Item=u8>> Fletcher16<R> {
+	/// Adds the given byte to the checksum.
+	fn update(&mut self, b: u8) {
+		self.sum1 = (self.sum1 + b as u16) % 255;
+		self.sum2 = (self.sum2 + self.sum1) % 255;
+	}
+}
+
+impl<R: Iterator<Item=u8>> Iterator for Fletcher16<R> {
+	type Item = u8;
+
+	fn next(&mut self) -> Option<u8> {
+		let b = self.bytes.next()?;
+		self.update(b);
+		Some(b)
+	}
+}
+
+/// UTF-8 decoder iterator computing a checksum of the consumed input bytes.
+///
+/// Created by the [`Decoder::with_byte_checksum`](crate::Decoder::with_byte_checksum) method.
+/// The checksum is the Fletcher-16 checksum of all the bytes consumed so far, valid or not,
+/// and can be retrieved with [`checksum`](WithByteChecksum::checksum). This can be used to
+/// verify the integrity of a framed payload without a second pass over the bytes.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let bytes = "Hello Wörld! 🌍".as_bytes();
+///
+/// let mut decoder = Decoder::new(bytes.iter().cloned()).with_byte_checksum();
+/// let string: std::io::Result<String> = (&mut decoder).collect();
+/// assert_eq!(string.unwrap(), "Hello Wörld! 🌍");
+///
+/// let (mut sum1, mut sum2) = (0u16, 0u16);
+/// for &b in bytes {
+///     sum1 = (sum1 + b as u16) % 255;
+///     sum2 = (sum2 + sum1) % 255;
+/// }
+/// assert_eq!(decoder.checksum(), sum2 << 8 | sum1);
+/// ```
+pub struct WithByteChecksum<R: Iterator<Item=u8>> {
+	decoder: Decoder<Fletcher16<R>>
+}
+
+impl<R: Iterator<Item=u8>> WithByteChecksum<R> {
+	pub(crate) fn new(decoder: Decoder<R>) -> WithByteChecksum<R> {
+		let pending = decoder.pending();
+		let mut decoder = decoder.map_source(|bytes| Fletcher16 {
+			bytes,
+			sum1: 0,
+			sum2: 0
+		});
+
+		// The byte kept by the decoder is consumed first.
+		if let Some(b) = pending {
+			decoder.get_mut().update(b)
+		}
+
+		WithByteChecksum {
+			decoder
+		}
+	}
+
+	/// Returns the Fletcher-16 checksum of the bytes consumed so far.
+	pub fn checksum(&self) -> u16 {
+		let bytes = self.decoder.get_ref();
+		bytes.sum2 << 8 | bytes.sum1
+	}
+}
+
+impl<R: Iterator<Item=u8>> Iterator for WithByteChecksum<R> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		self.decoder.next()
+	}
+}
@@ -0,0 +1,59 @@
+use std::io::Result;
+use crate::tables;
+
+/// Role of a character in an identifier.
+///
+/// See [Unicode Standard Annex #31](https://www.unicode.org/reports/tr31/).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum IdentRole {
+	/// The character can start an identifier (`XID_Start` property).
+	/// Such characters can also continue an identifier.
+	Start,
+
+	/// The character can continue an identifier but not start it (`XID_Continue` property
+	/// without `XID_Start`), such as digits.
+	Continue,
+
+	/// The character cannot appear in an identifier.
+	Invalid
+}
+
+impl IdentRole {
+	/// Returns the identifier role of the given character.
+	pub fn of(c: char) -> IdentRole {
+		tables::lookup(tables::IDENT, c).unwrap_or(IdentRole::Invalid)
+	}
+}
+
+/// UTF-8 decoder iterator yielding each character with its identifier role.
+///
+/// Created by the [`Decoder::with_ident_flags`](crate::Decoder::with_ident_flags) method.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::{Decoder, IdentRole};
+/// let mut decoder = Decoder::new("a1 é".bytes()).with_ident_flags();
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('a', IdentRole::Start));
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('1', IdentRole::Continue));
+/// assert_eq!(decoder.next().unwrap().unwrap(), (' ', IdentRole::Invalid));
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('é', IdentRole::Start));
+/// ```
+pub struct WithIdentFlags<I: Iterator<Item=Result<char>>> {
+	chars: I
+}
+
+impl<I: Iterator<Item=Result<char>>> WithIdentFlags<I> {
+	pub(crate) fn new(chars: I) -> WithIdentFlags<I> {
+		WithIdentFlags {
+			chars
+		}
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for WithIdentFlags<I> {
+	type Item = Result<(char, IdentRole)>;
+
+	fn next(&mut self) -> Option<Result<(char, IdentRole)>> {
+		self.chars.next().map(|c| c.map(|c| (c, IdentRole::of(c))))
+	}
+}
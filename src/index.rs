@@ -0,0 +1,214 @@
+use std::io::{Result, Error, ErrorKind};
+use std::convert::TryFrom;
+use crate::Decoder;
+
+/// Non-ASCII character of a line, whose UTF-8 and UTF-16 lengths differ.
+struct Wide {
+	/// Byte offset of the character from the start of the line.
+	column: usize,
+
+	/// Length of the character in UTF-8 bytes.
+	utf8_len: usize,
+
+	/// Length of the character in UTF-16 code units.
+	utf16_len: usize
+}
+
+/// Line of a [`LineIndex`].
+struct Line {
+	/// Byte offset of the first character of the line.
+	start: usize,
+
+	/// Byte offset of the line terminator, or of the end of the input for the last line.
+	end: usize,
+
+	/// Non-ASCII characters of the line, in order.
+	wide: Vec<Wide>
+}
+
+/// Index mapping positions expressed as `(line, UTF-16 column)` to byte offsets and back.
+///
+/// Created by the [`Decoder::lsp_index`](crate::Decoder::lsp_index) method.
+/// This is the position encoding of the Language Server Protocol: lines and columns are
+/// 0-based and columns count UTF-16 code units, so that characters outside the Basic
+/// Multilingual Plane count as 2 columns. Lines are terminated by `'\n'` or `"\r\n"`, and the
+/// terminator is not part of the line.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let index = Decoder::new("a😀b\r\né\n".bytes()).lsp_index().unwrap();
+/// assert_eq!(index.line_count(), 3);
+///
+/// assert_eq!(index.byte_of_position(0, 0), Some(0));
+/// assert_eq!(index.byte_of_position(0, 1), Some(1));
+/// // "😀" spans 2 UTF-16 code units and 4 bytes.
+/// assert_eq!(index.byte_of_position(0, 2), None);
+/// assert_eq!(index.byte_of_position(0, 3), Some(5));
+/// assert_eq!(index.byte_of_position(0, 4), Some(6));
+/// assert_eq!(index.byte_of_position(0, 5), None);
+/// assert_eq!(index.byte_of_position(1, 1), Some(10));
+/// assert_eq!(index.byte_of_position(2, 0), Some(11));
+/// assert_eq!(index.byte_of_position(3, 0), None);
+///
+/// assert_eq!(index.position_of_byte(5), Some((0, 3)));
+/// assert_eq!(index.position_of_byte(3), None);
+/// assert_eq!(index.position_of_byte(8), Some((1, 0)));
+/// assert_eq!(index.position_of_byte(10), Some((1, 1)));
+/// assert_eq!(index.position_of_byte(11), Some((2, 0)));
+/// assert_eq!(index.position_of_byte(12), None);
+/// ```
+pub struct LineIndex {
+	lines: Vec<Line>
+}
+
+impl LineIndex {
+	/// Decodes the whole input of the given decoder and indexes its lines.
+	pub(crate) fn new<R: Iterator<Item=u8>>(mut decoder: Decoder<R>) -> Result<LineIndex> {
+		let mut lines = Vec::new();
+		let mut line = Line {
+			start: 0,
+			end: 0,
+			wide: Vec::new()
+		};
+		let mut after_cr = false;
+
+		loop {
+			let offset = decoder.offset();
+			match decoder.next() {
+				Some(c) => {
+					let c = c?;
+					if c == '\n' {
+						line.end = if after_cr { offset - 1 } else { offset };
+						lines.push(line);
+						line = Line {
+							start: decoder.offset(),
+							end: 0,
+							wide: Vec::new()
+						}
+					} else if !c.is_ascii() {
+						line.wide.push(Wide {
+							column: offset - line.start,
+							utf8_len: c.len_utf8(),
+							utf16_len: c.len_utf16()
+						})
+					}
+
+					after_cr = c == '\r'
+				},
+				None => {
+					line.end = offset;
+					lines.push(line);
+					break
+				}
+			}
+		}
+
+		Ok(LineIndex {
+			lines
+		})
+	}
+
+	/// Number of lines of the input.
+	///
+	/// This is the number of line terminators plus one: an input ending with a line terminator
+	/// has a final empty line.
+	pub fn line_count(&self) -> usize {
+		self.lines.len()
+	}
+
+	/// Returns the byte offset of the given 0-based `(line, UTF-16 column)` position.
+	///
+	/// The column may be the length of the line, designating its end.
+	/// Returns `None` if the line does not exist, if the column is past the end of the line or
+	/// if it points into the middle of a surrogate pair.
+	pub fn byte_of_position(&self, line: usize, utf16_column: usize) -> Option<usize> {
+		let line = self.lines.get(line)?;
+		let mut delta = 0;
+		for wide in &line.wide {
+			let column = wide.column - delta;
+			if utf16_column <= column {
+				break
+			}
+
+			if utf16_column < column + wide.utf16_len {
+				return None
+			}
+
+			delta += wide.utf8_len - wide.utf16_len
+		}
+
+		let offset = line.start + utf16_column + delta;
+		if offset <= line.end {
+			Some(offset)
+		} else {
+			None
+		}
+	}
+
+	/// Returns the 0-based `(line, UTF-16 column)` position of the given byte offset.
+	///
+	/// Returns `None` if the offset is past the end of the input, if it points into the middle
+	/// of a character, or into a line terminator (except its first byte, designating the end of
+	/// the line).
+	pub fn position_of_byte(&self, offset: usize) -> Option<(usize, usize)> {
+		let index = self.lines.partition_point(|line| line.start <= offset) - 1;
+		let line = &self.lines[index];
+		if offset > line.end {
+			return None
+		}
+
+		let mut delta = 0;
+		for wide in &line.wide {
+			let start = line.start + wide.column;
+			if offset <= start {
+				break
+			}
+
+			if offset < start + wide.utf8_len {
+				return None
+			}
+
+			delta += wide.utf8_len - wide.utf16_len
+		}
+
+		Some((index, offset - line.start - delta))
+	}
+}
+
+/// Decodes the given bytes and returns the byte offset of each character, as a `u32`.
+///
+/// This is a compact offset map for inputs smaller than 4 GiB, using half the memory of `usize`
+/// offsets on 64-bit platforms.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::char_byte_offsets_u32;
+/// assert_eq!(char_byte_offsets_u32("aé€😀b".as_bytes()).unwrap(), [0, 1, 3, 6, 10]);
+/// assert!(char_byte_offsets_u32(b"").unwrap().is_empty());
+/// assert!(char_byte_offsets_u32(b"a\xff").is_err());
+/// ```
+///
+/// ## Errors
+/// Returns an [`InvalidData`](std::io::ErrorKind::InvalidData) error if a character starts at an
+/// offset greater than [`u32::MAX`], which can only happen for inputs larger than 4 GiB, or the
+/// first decoding error encountered.
+pub fn char_byte_offsets_u32(bytes: &[u8]) -> Result<Vec<u32>> {
+	let mut offsets = Vec::new();
+	let mut decoder = Decoder::new(bytes.iter().cloned());
+	loop {
+		let offset = decoder.offset();
+		match decoder.next() {
+			Some(c) => {
+				c?;
+				match u32::try_from(offset) {
+					Ok(offset) => offsets.push(offset),
+					Err(_) => return Err(Error::new(ErrorKind::InvalidData, format!("byte offset {} exceeds u32::MAX.", offset)))
+				}
+			},
+			None => break
+		}
+	}
+
+	Ok(offsets)
+}
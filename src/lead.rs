@@ -0,0 +1,57 @@
+/// Marker of the continuation bytes in [`LEAD_BYTES`].
+pub(crate) const CONTINUATION: u8 = 0xFE;
+
+/// Marker of the bytes that never appear in UTF-8 in [`LEAD_BYTES`].
+pub(crate) const INVALID_LEAD: u8 = 0xFF;
+
+/// Number of continuation bytes following each lead byte, or [`CONTINUATION`] for continuation
+/// bytes, or [`INVALID_LEAD`] for `0xC0`, `0xC1` and `0xF5` to `0xFF`.
+/// The payload bits of a lead byte followed by `n` continuation bytes are its lowest
+/// `6 - n` bits.
+pub(crate) static LEAD_BYTES: [u8; 256] = lead_bytes();
+
+/// Smallest code point encoded with each number of continuation bytes.
+/// Smaller code points encoded with as many bytes are overlong.
+pub(crate) const MIN_CODE_POINTS: [u32; 4] = [0, 0x80, 0x800, 0x10000];
+
+/// Returns `true` if the given byte may start a UTF-8 sequence. After an invalid sequence, the
+/// decoders skip the following bytes up to such a byte.
+#[cfg(feature = "std")]
+pub(crate) fn is_lead(b: u8) -> bool {
+    LEAD_BYTES[b as usize] < CONTINUATION
+}
+
+/// Skips the bytes following an invalid sequence, starting with `pending` if any, up to the next
+/// byte that may start a sequence, which is returned. The skipped bytes are counted in `offset`.
+#[cfg(feature = "std")]
+pub(crate) fn skip_invalid<I: Iterator<Item=u8>>(bytes: &mut I, pending: Option<u8>, offset: &mut usize) -> Option<u8> {
+    let mut b = match pending {
+        Some(b) => b,
+        None => bytes.next()?
+    };
+    while !is_lead(b) {
+        *offset += 1;
+        b = bytes.next()?
+    }
+
+    Some(b)
+}
+
+/// Builds the [`LEAD_BYTES`] table.
+const fn lead_bytes() -> [u8; 256] {
+    let mut table = [0; 256];
+    let mut b = 0;
+    while b < 256 {
+        table[b] = match b {
+            0x00..=0x7F => 0,
+            0x80..=0xBF => CONTINUATION,
+            0xC2..=0xDF => 1,
+            0xE0..=0xEF => 2,
+            0xF0..=0xF4 => 3,
+            _ => INVALID_LEAD
+        };
+        b += 1
+    }
+
+    table
+}
@@ -6,65 +6,242 @@
 //! The [`Decoder`](safe::Decoder) struct wraps [`u8`] iterators.
 //! You can use it, for instance, to decode `u8` slices.
 //!
-//! ```rust
-//! extern crate utf8_decode;
-//!
-//! use utf8_decode::Decoder;
-//!
-//! fn main() -> std::io::Result<()> {
-//!     let bytes = [72, 101, 108, 108, 111, 32, 87, 111, 114, 108, 100, 33, 32, 240, 159, 140, 141];
-//!
-//!     let decoder = Decoder::new(bytes.iter().cloned());
-//!
-//!     let mut string = String::new();
-//!     for c in decoder {
-//!         string.push(c?);
-//!     }
-//!
-//!     println!("{}", string);
-//!
-//!     Ok(())
-//! }
-//! ```
+#![cfg_attr(feature = "std", doc = r#"
+```rust
+extern crate utf8_decode;
+
+use utf8_decode::Decoder;
+
+fn main() -> std::io::Result<()> {
+    let bytes = [72, 101, 108, 108, 111, 32, 87, 111, 114, 108, 100, 33, 32, 240, 159, 140, 141];
+
+    let decoder = Decoder::new(bytes.iter().cloned());
+
+    let mut string = String::new();
+    for c in decoder {
+        string.push(c?);
+    }
+
+    println!("{}", string);
+
+    Ok(())
+}
+```
+"#)]
 //!
 //! ## UnsafeDecoder
 //!
 //! The [`UnsafeDecoder`] wraps [`std::io::Result<u8>`](std::io::Result) iterators.
 //! You can use it, for instance, to decode UTF-8 encoded files.
 //!
-//! ```rust
-//! extern crate utf8_decode;
-//!
-//! use std::fs::File;
-//! use std::io::Read;
-//! use utf8_decode::UnsafeDecoder;
-//!
-//! fn main() -> std::io::Result<()> {
-//!     let file = File::open("examples/file.txt")?;
-//!
-//!     let decoder = UnsafeDecoder::new(file.bytes());
+#![cfg_attr(feature = "std", doc = r#"
+```rust
+extern crate utf8_decode;
+
+use std::fs::File;
+use std::io::Read;
+use utf8_decode::UnsafeDecoder;
+
+fn main() -> std::io::Result<()> {
+    let file = File::open("examples/file.txt")?;
+
+    let decoder = UnsafeDecoder::new(file.bytes());
+
+    let mut string = String::new();
+    for c in decoder {
+        string.push(c?);
+    }
+
+    println!("{}", string);
+
+    Ok(())
+}
+```
+"#)]
 //!
-//!     let mut string = String::new();
-//!     for c in decoder {
-//!         string.push(c?);
-//!     }
+//! ## `no_std` support
 //!
-//!     println!("{}", string);
+//! The `std` feature is enabled by default. Without it, the crate is `no_std` and only provides
+//! the [`Decoder`] iterator and the [`decode`] function, reporting errors with the
+//! [`DecodeError`] type instead of [`std::io::Error`]. They are then aliases of the
+//! [`TypedDecoder`] iterator and the [`decode_typed`] function, also available with the `std`
+//! feature.
 //!
-//!     Ok(())
-//! }
+//! ```toml
+//! [dependencies]
+//! utf8-decode = { version = "1.0", default-features = false }
 //! ```
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
 use std::io::{Result, Error, ErrorKind};
+#[cfg(feature = "std")]
 use std::convert::TryFrom;
+#[cfg(feature = "std")]
+use std::iter::FusedIterator;
+#[cfg(feature = "std")]
+use std::fmt;
+#[cfg(feature = "std")]
+use lead::{LEAD_BYTES, CONTINUATION, INVALID_LEAD, MIN_CODE_POINTS, is_lead};
 
+#[cfg(feature = "std")]
 mod safe;
-pub use safe::{Decoder, decode};
+mod lead;
+mod typed;
+mod error;
+#[cfg(feature = "std")]
+mod lines;
+#[cfg(feature = "std")]
+mod read;
+#[cfg(feature = "std")]
+mod slice;
+#[cfg(feature = "std")]
+mod lossy;
+#[cfg(feature = "std")]
+mod sink;
+#[cfg(feature = "std")]
+mod escape;
+#[cfg(feature = "std")]
+mod policy;
+#[cfg(feature = "std")]
+mod batch;
+#[cfg(feature = "std")]
+mod hash;
+#[cfg(feature = "std")]
+mod scan;
+#[cfg(feature = "std")]
+mod stats;
+#[cfg(feature = "std")]
+mod segment;
+#[cfg(feature = "std")]
+mod grapheme;
+#[cfg(feature = "std")]
+mod analysis;
+#[cfg(feature = "std")]
+mod utf16;
+#[cfg(feature = "std")]
+mod string;
+#[cfg(feature = "std")]
+mod printable;
+#[cfg(feature = "std")]
+mod case;
+#[cfg(feature = "std")]
+mod index;
+#[cfg(feature = "std")]
+mod stream;
+#[cfg(feature = "std")]
+mod emoji;
+#[cfg(feature = "std")]
+mod events;
+#[cfg(feature = "std")]
+mod ext;
+#[cfg(feature = "std")]
+mod overlong;
+#[cfg(feature = "std")]
+mod peek;
+#[cfg(feature = "std")]
+mod source;
+#[cfg(feature = "std")]
+mod bom;
+#[cfg(feature = "std")]
+mod tables;
+#[cfg(feature = "width")]
+mod width;
+#[cfg(feature = "age")]
+mod age;
+#[cfg(feature = "bidi")]
+mod bidi;
+#[cfg(feature = "names")]
+mod names;
+#[cfg(feature = "ident")]
+mod ident;
+#[cfg(feature = "numeric")]
+mod numeric;
+#[cfg(feature = "normalization")]
+mod normalization;
+#[cfg(feature = "async")]
+mod asynchronous;
+#[cfg(feature = "std")]
+pub use safe::{Decoder, decode, validate, RawDecoder};
+#[cfg(not(feature = "std"))]
+pub use typed::{TypedDecoder as Decoder, decode_typed as decode};
+pub use typed::{TypedDecoder, decode_typed};
+pub use error::DecodeError;
+#[cfg(feature = "std")]
+pub use error::OffsetError;
+#[cfg(feature = "std")]
+pub use lines::{MaxLineBytes, NormalizeIndent, NormalizeAllLineBreaks, OnLineEnd};
+#[cfg(feature = "std")]
+pub use lossy::{Lossy, LossySpanned, LossyCoalesced, RecoveryStrategy, LossyPreserveWidth, UnsafeLossy, Wtf8Lossy, FailOnRepeatedError, LossyRecoverOverlong};
+#[cfg(feature = "std")]
+pub use sink::{ErrorSink, WithErrorSink};
+#[cfg(feature = "std")]
+pub use policy::{RejectC1Controls, RejectNoncharacters, TextFieldSafe, MaxDistinctChars, MaxClusterSize, SoftCharLimit};
+#[cfg(feature = "std")]
+pub use batch::{Batched, BATCH_SIZE, RunLength};
+#[cfg(feature = "std")]
+pub use hash::{WithRollingHash, WithByteChecksum};
+#[cfg(feature = "std")]
+pub use scan::{BracketDepth, DEFAULT_BRACKETS, WithWordStartFlag};
+#[cfg(feature = "std")]
+pub use stats::WithStats;
+#[cfg(feature = "std")]
+pub use segment::Sentences;
+#[cfg(feature = "std")]
+pub use printable::{is_printable, WithPrintableFlag};
+#[cfg(feature = "std")]
+pub use case::CaseFold;
+#[cfg(feature = "std")]
+pub use index::{LineIndex, char_byte_offsets_u32};
+#[cfg(feature = "std")]
+pub use stream::{StreamDecoder, StringBuilder, BomPolicy, StreamingDecoder};
+#[cfg(feature = "std")]
+pub use emoji::{is_emoji, WithEmojiFlag};
+#[cfg(feature = "std")]
+pub use events::{DecodeEvent, Events};
+#[cfg(feature = "std")]
+pub use ext::Utf8DecodeExt;
+#[cfg(feature = "std")]
+pub use overlong::{AcceptOverlong, is_canonical};
+#[cfg(feature = "std")]
+pub use peek::PeekableDecoder;
+#[cfg(feature = "std")]
+pub use source::{SourceBytes, WithSourceBytes, WithLengths, FrameAware};
+#[cfg(feature = "std")]
+pub use bom::SkipBom;
+#[cfg(feature = "std")]
+pub use analysis::{longest_ascii_run, TextClass, classify, char_frequencies, char_entropy, BomKind, EncodingAnalysis, analyze};
+#[cfg(feature = "std")]
+pub use utf16::{utf16_len, decode_to_utf16};
+#[cfg(feature = "std")]
+pub use string::{decode_str, decode_slice, decode_reader, common_char_prefix, nth_char_occurrence, decode_str_capped, decode_nonempty, decode_normalized_trailing, decode_lossy_counted, decode_first_line, decode_interned, clear_interned, expect_prefix};
+#[cfg(feature = "width")]
+pub use width::{EastAsianWidth, WithEaw};
+#[cfg(feature = "age")]
+pub use age::{UnicodeVersion, WithAssignment};
+#[cfg(feature = "bidi")]
+pub use bidi::{is_rtl, contains_rtl};
+#[cfg(feature = "names")]
+pub use names::{name, WithName};
+#[cfg(feature = "ident")]
+pub use ident::{IdentRole, WithIdentFlags};
+#[cfg(feature = "numeric")]
+pub use numeric::{numeric_value, WithNumericValue};
+#[cfg(feature = "normalization")]
+pub use normalization::Nfd;
+#[cfg(feature = "async")]
+pub use asynchronous::AsyncDecoder;
+#[cfg(feature = "std")]
+pub use read::{ReadDecoder, ReadLines, Positioned};
+#[cfg(feature = "std")]
+pub use slice::SliceDecoder;
 
 /// Read the next byte of the UTF-8 character out of the given byte iterator.
 /// The byte is returned as a `u32` for later shifting.
 /// Returns an `InvalidData` error if the byte is not part of a valid UTF-8 sequence.
 /// Returns an `UnexpectedEof` error if the input iterator returns `None`.
+#[cfg(feature = "std")]
 fn next_byte<I: Iterator<Item=Result<u8>>>(iter: &mut I) -> Result<u32> {
     match iter.next() {
         Some(Ok(c)) => {
@@ -75,7 +252,30 @@ fn next_byte<I: Iterator<Item=Result<u8>>>(iter: &mut I) -> Result<u32> {
             }
         },
         Some(Err(e)) => Err(e),
-        None => Err(Error::new(ErrorKind::UnexpectedEof, "unexpected end of UTF-8 sequence."))
+        None => Err(Error::new(ErrorKind::UnexpectedEof, DecodeError::UnexpectedEof { offset: 0 }.message()))
+    }
+}
+
+/// Checks that the given decoded codepoint is at least `min`, the smallest codepoint encoded
+/// with the same number of bytes.
+/// Returns an `InvalidData` error if it is not, since the sequence is then an overlong encoding.
+#[cfg(feature = "std")]
+fn check_overlong(c: u32, min: u32) -> Result<u32> {
+    if c >= min {
+        Ok(c)
+    } else {
+        Err(Error::new(ErrorKind::InvalidData, "overlong UTF-8 sequence."))
+    }
+}
+
+/// Checks that the given decoded codepoint is not a UTF-16 surrogate.
+/// Returns an `InvalidData` error naming the surrogate otherwise, as found in CESU-8 data.
+#[cfg(feature = "std")]
+fn check_surrogate(c: u32) -> Result<u32> {
+    if (0xD800..=0xDFFF).contains(&c) {
+        Err(Error::new(ErrorKind::InvalidData, DecodeError::Surrogate { offset: 0, code_point: c }.message()))
+    } else {
+        Ok(c)
     }
 }
 
@@ -84,23 +284,24 @@ fn next_byte<I: Iterator<Item=Result<u8>>>(iter: &mut I) -> Result<u32> {
 /// Returns an `InvalidData` error the input iterator does not output a valid UTF-8 sequence.
 /// Returns an `UnexpectedEof` error if the input iterator returns `None` before the end of the
 /// UTF-8 character.
+#[cfg(feature = "std")]
 fn raw_decode_from<I: Iterator<Item=Result<u8>>>(a: u32, iter: &mut I) -> Result<u32> {
-    if a & 0x80 == 0x00 {
-        Ok(a)
-    } else if a & 0xE0 == 0xC0 {
-        let b = next_byte(iter)?;
-        Ok((a & 0x1F) << 6 | b)
-    } else if a & 0xF0 == 0xE0 {
-        let b = next_byte(iter)?;
-        let c = next_byte(iter)?;
-        Ok((a & 0x0F) << 12 | b << 6 | c)
-    } else if a & 0xF8 == 0xF0 {
-        let b = next_byte(iter)?;
-        let c = next_byte(iter)?;
-        let d = next_byte(iter)?;
-        Ok((a & 0x07) << 18 | b << 12 | c << 6 | d)
-    } else {
-        Err(Error::new(ErrorKind::InvalidData, "invalid UTF-8 sequence."))
+    match LEAD_BYTES[a as usize] {
+        0 => Ok(a),
+        CONTINUATION => Err(Error::new(ErrorKind::InvalidData, "invalid UTF-8 sequence.")),
+        INVALID_LEAD => {
+            // Lead bytes of overlong two-byte sequences, and of sequences beyond U+10FFFF (or of
+            // no sequence at all). Rejected before reading any continuation byte.
+            Err(Error::new(ErrorKind::InvalidData, DecodeError::InvalidLeadByte { offset: 0, byte: a as u8 }.message()))
+        },
+        len => {
+            let mut c = a & (0x7F >> (len + 1));
+            for _ in 0..len {
+                c = c << 6 | next_byte(iter)?;
+            }
+
+            check_overlong(c, MIN_CODE_POINTS[len as usize])
+        }
     }
 }
 
@@ -108,8 +309,9 @@ fn raw_decode_from<I: Iterator<Item=Result<u8>>>(a: u32, iter: &mut I) -> Result
 /// Returns an `InvalidData` error the input iterator does not output a valid UTF-8 sequence.
 /// Returns an `UnexpectedEof` error if the input iterator returns `None` before the end of the
 /// UTF-8 character.
+#[cfg(feature = "std")]
 fn decode_from<I: Iterator<Item=Result<u8>>>(a: u32, iter: &mut I) -> Result<char> {
-    match char::try_from(raw_decode_from(a, iter)?) {
+    match char::try_from(check_surrogate(raw_decode_from(a, iter)?)?) {
         Ok(c) => Ok(c),
         Err(_) => Err(Error::new(ErrorKind::InvalidData, "invalid UTF-8 sequence."))
     }
@@ -122,6 +324,53 @@ fn decode_from<I: Iterator<Item=Result<u8>>>(a: u32, iter: &mut I) -> Result<cha
 /// output a valid UTF-8 sequence.
 /// Returns an [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof) error if the input iterator
 /// returns `None` before the end of an UTF-8 character.
+/// Overlong encodings and surrogate code points are invalid.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::decode_unsafe;
+/// // Overlong encodings of U+0000, U+002F, U+07FF and U+FFFF.
+/// let overlong: [&[u8]; 5] = [
+///     &[0xe0, 0x80, 0x80],
+///     &[0xe0, 0x80, 0xaf],
+///     &[0xe0, 0x9f, 0xbf],
+///     &[0xf0, 0x80, 0x80, 0xaf],
+///     &[0xf0, 0x8f, 0xbf, 0xbf]
+/// ];
+///
+/// for bytes in &overlong {
+///     let error = decode_unsafe(&mut bytes.iter().cloned().map(Ok)).unwrap().unwrap_err();
+///     assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+///     assert_eq!(error.to_string(), "overlong UTF-8 sequence.");
+/// }
+///
+/// // Lead bytes that never appear in UTF-8 are rejected without reading the next bytes.
+/// for &a in &[0xc0, 0xc1, 0xf5, 0xf6, 0xf7, 0xf8, 0xfb, 0xfc, 0xfe, 0xff] {
+///     let source = [a, 0x80, 0x41];
+///     let mut bytes = source.iter().cloned().map(Ok);
+///     let error = decode_unsafe(&mut bytes).unwrap().unwrap_err();
+///     assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+///     assert_eq!(error.to_string(), format!("invalid lead byte 0x{:02X} in UTF-8 sequence.", a));
+///     assert_eq!(bytes.len(), 2);
+/// }
+///
+/// // Smallest two-byte and largest four-byte lead bytes of valid sequences.
+/// assert!(decode_unsafe(&mut [0xc2, 0x80].iter().cloned().map(Ok)).unwrap().is_ok());
+/// assert!(decode_unsafe(&mut [0xf4, 0x8f, 0xbf, 0xbf].iter().cloned().map(Ok)).unwrap().is_ok());
+///
+/// // Shortest encodings of U+0080, U+0800 and U+10000.
+/// assert_eq!(decode_unsafe(&mut [0xc2, 0x80].iter().cloned().map(Ok)).unwrap().unwrap(), '\u{80}');
+/// assert_eq!(decode_unsafe(&mut [0xe0, 0xa0, 0x80].iter().cloned().map(Ok)).unwrap().unwrap(), '\u{800}');
+/// assert_eq!(decode_unsafe(&mut [0xf0, 0x90, 0x80, 0x80].iter().cloned().map(Ok)).unwrap().unwrap(), '\u{10000}');
+///
+/// // High and low surrogates.
+/// let error = decode_unsafe(&mut [0xed, 0xa0, 0x80].iter().cloned().map(Ok)).unwrap().unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+/// assert_eq!(error.to_string(), "surrogate code point U+D800 in UTF-8 sequence.");
+/// let error = decode_unsafe(&mut [0xed, 0xbf, 0xbf].iter().cloned().map(Ok)).unwrap().unwrap_err();
+/// assert_eq!(error.to_string(), "surrogate code point U+DFFF in UTF-8 sequence.");
+/// ```
+#[cfg(feature = "std")]
 pub fn decode_unsafe<I: Iterator<Item=Result<u8>>>(iter: &mut I) -> Option<Result<char>> {
 	match iter.next() {
 		Some(Ok(a)) => Some(decode_from(a as u32, iter)),
@@ -130,6 +379,65 @@ pub fn decode_unsafe<I: Iterator<Item=Result<u8>>>(iter: &mut I) -> Option<Resul
 	}
 }
 
+/// Checks the next Unicode character given its first byte, without building it.
+/// Returns the same errors as `decode_from`.
+#[cfg(feature = "std")]
+fn validate_from<I: Iterator<Item=Result<u8>>>(a: u32, iter: &mut I) -> Result<()> {
+    if check_surrogate(raw_decode_from(a, iter)?)? <= 0x10FFFF {
+        Ok(())
+    } else {
+        Err(Error::new(ErrorKind::InvalidData, "invalid UTF-8 sequence."))
+    }
+}
+
+/// Checks that the given [`Result<u8>`](std::io::Result) iterator outputs a valid UTF-8
+/// sequence.
+///
+/// This is the [`validate`] function for fallible sources, running the decoder over the whole
+/// input without building the decoded characters. Like the [`UnsafeDecoder`], it skips the
+/// [`Interrupted`](std::io::ErrorKind::Interrupted) errors of the source iterator.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::validate_unsafe;
+/// assert!(validate_unsafe("aé€😀".bytes().map(Ok)).is_ok());
+///
+/// let error = validate_unsafe(b"a\xc3\x28".iter().cloned().map(Ok)).unwrap_err();
+/// assert_eq!(error.to_string(), "invalid UTF-8 sequence at byte 1.");
+///
+/// let source = vec![Ok(b'a'), Err(std::io::Error::new(std::io::ErrorKind::Other, "disconnected"))];
+/// let error = validate_unsafe(source.into_iter()).unwrap_err();
+/// assert_eq!(error.to_string(), "disconnected");
+/// ```
+///
+/// ## Errors
+/// Returns the error the [`UnsafeDecoder`] would return on the first invalid sequence, or the
+/// first error of the source iterator.
+#[cfg(feature = "std")]
+pub fn validate_unsafe<I: Iterator<Item=Result<u8>>>(mut iter: I) -> Result<()> {
+	let mut offset = 0;
+	loop {
+		let start = offset;
+		let mut source_error = false;
+		let mut bytes = CountedUnsafe {
+			bytes: &mut iter,
+			count: &mut offset,
+			error: &mut source_error
+		};
+
+		let result = match bytes.next() {
+			Some(Ok(a)) => validate_from(a as u32, &mut bytes),
+			Some(Err(e)) => Err(e),
+			None => return Ok(())
+		};
+
+		match result {
+			Err(e) if !source_error => return Err(OffsetError::new(start, e).into()),
+			result => result?
+		}
+	}
+}
+
 /// UTF-8 decoder iterator for unsafe input.
 ///
 /// Transform the given [`io::Result<u8>`](std::io::Result) iterator into a [`io::Result<char>`](std::io::Result) iterator.
@@ -139,7 +447,11 @@ pub fn decode_unsafe<I: Iterator<Item=Result<u8>>>(iter: &mut I) -> Option<Resul
 /// ## Example
 /// The `UnsafeDecoder` iterator can be used, for instance, to decode UTF-8 encoded files.
 /// ```rust
-/// let file = File::open("file.txt")?;
+/// # use std::fs::File;
+/// # use std::io::Read;
+/// # use utf8_decode::UnsafeDecoder;
+/// # fn main() -> std::io::Result<()> {
+/// let file = File::open("examples/file.txt")?;
 ///
 /// let decoder = UnsafeDecoder::new(file.bytes());
 ///
@@ -147,31 +459,435 @@ pub fn decode_unsafe<I: Iterator<Item=Result<u8>>>(iter: &mut I) -> Option<Resul
 /// for c in decoder {
 ///     string.push(c?);
 /// }
+/// # Ok(())
+/// # }
 /// ```
 ///
 /// ## Errors
 /// A call to [`next`](Iterator::next) returns an [`InvalidData`](std::io::ErrorKind::InvalidData)
 /// error if the input iterator does not output a valid UTF-8 sequence, or an
 /// [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof) if the stream ends before the end of a
-/// valid character.
+/// valid character. The error wraps an [`OffsetError`](crate::OffsetError) giving the offset of
+/// the faulty sequence. Errors returned by the input iterator are forwarded as is, except
+/// [`Interrupted`](std::io::ErrorKind::Interrupted) errors, which are skipped: following the
+/// `io` convention, the next byte is pulled out of the input iterator instead.
+///
+/// ```rust
+/// # use std::io::{Error, ErrorKind};
+/// # use utf8_decode::UnsafeDecoder;
+/// let interrupted = || Err(Error::new(ErrorKind::Interrupted, "interrupted"));
+/// let bytes = vec![Ok(0x61), interrupted(), Ok(0xc3), interrupted(), Ok(0xa9)];
+/// let string: std::io::Result<String> = UnsafeDecoder::new(bytes.into_iter()).collect();
+/// assert_eq!(string.unwrap(), "aé");
+/// ```
+///
+/// Like the [`Decoder`](crate::Decoder), decoding resumes after an error at the next byte that
+/// may start a sequence, which may be the byte that ended the invalid sequence. The bytes up to
+/// it are skipped by the next call to [`next`](Iterator::next) rather than right after the
+/// error, so that an error returned by the input iterator meanwhile is returned by this call.
+///
+/// ```rust
+/// # use utf8_decode::UnsafeDecoder;
+/// let mut decoder = UnsafeDecoder::new(b"\xe2\x82AB\x80\x80C".iter().cloned().map(Ok));
+/// assert!(decoder.next().unwrap().is_err());
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'A');
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'B');
+/// assert!(decoder.next().unwrap().is_err());
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'C');
+/// assert!(decoder.next().is_none());
+/// ```
+#[cfg(feature = "std")]
 pub struct UnsafeDecoder<R: Iterator<Item=Result<u8>>> {
-	bytes: R
+	bytes: R,
+	pending: Option<u8>,
+	resync: bool,
+	offset: usize,
+	chars: usize
 }
 
+#[cfg(feature = "std")]
 impl<R: Iterator<Item=Result<u8>>> UnsafeDecoder<R> {
     /// Creates a new `Decoder` iterator from the given [`Result<u8>`](std::io::Result) source
     /// iterator.
 	pub fn new(source: R) -> UnsafeDecoder<R> {
 		UnsafeDecoder {
-			bytes: source
+			bytes: source,
+			pending: None,
+			resync: false,
+			offset: 0,
+			chars: 0
 		}
 	}
+
+	/// Returns a reference to the source iterator.
+	pub fn get_ref(&self) -> &R {
+		&self.bytes
+	}
+
+	/// Returns a mutable reference to the source iterator.
+	///
+	/// Pulling bytes out of the source iterator directly skips them in the decoded output.
+	pub fn get_mut(&mut self) -> &mut R {
+		&mut self.bytes
+	}
+
+	/// Unwraps this `UnsafeDecoder`, returning the source iterator.
+	///
+	/// The source iterator is positioned after the last byte read by the decoder. Right after an
+	/// error, the byte that ended the invalid sequence may be kept by the decoder to be decoded
+	/// next: it is then lost.
+	///
+	/// ## Example
+	/// ```rust
+	/// # use utf8_decode::UnsafeDecoder;
+	/// let bytes = b"ab\x00\x01\x02";
+	/// let mut decoder = UnsafeDecoder::new(bytes.iter().cloned().map(Ok));
+	/// assert_eq!(decoder.next().unwrap().unwrap(), 'a');
+	/// assert_eq!(decoder.next().unwrap().unwrap(), 'b');
+	/// assert_eq!(decoder.next().unwrap().unwrap(), '\0');
+	/// let rest: std::io::Result<Vec<u8>> = decoder.into_inner().collect();
+	/// assert_eq!(rest.unwrap(), [0x01, 0x02]);
+	/// ```
+	pub fn into_inner(self) -> R {
+		self.bytes
+	}
+
+	/// Returns the number of characters decoded so far.
+	///
+	/// Errors are not counted.
+	///
+	/// ## Example
+	/// ```rust
+	/// # use utf8_decode::UnsafeDecoder;
+	/// let mut decoder = UnsafeDecoder::new(b"a\xc3\xa9\xff\xe2\x82\xac".iter().cloned().map(Ok));
+	/// decoder.next();
+	/// decoder.next();
+	/// assert_eq!((decoder.char_count(), decoder.byte_count()), (2, 3));
+	/// assert!(decoder.next().unwrap().is_err());
+	/// assert_eq!((decoder.char_count(), decoder.byte_count()), (2, 4));
+	/// decoder.next();
+	/// assert_eq!((decoder.char_count(), decoder.byte_count()), (3, 7));
+	/// ```
+	pub fn char_count(&self) -> usize {
+		self.chars
+	}
+
+	/// Returns the number of bytes decoded so far.
+	///
+	/// This includes the bytes of invalid sequences and the bytes skipped after them, but not the
+	/// byte that ended one when it is kept to be decoded next, nor the errors of the source
+	/// iterator. See
+	/// [`char_count`](UnsafeDecoder::char_count) for an example.
+	pub fn byte_count(&self) -> usize {
+		self.offset
+	}
+
+	/// Creates a new lossy decoder iterator from the given [`Result<u8>`](std::io::Result)
+	/// source iterator, replacing each maximal invalid subsequence with one replacement
+	/// character.
+	///
+	/// See [`UnsafeLossy`](crate::UnsafeLossy) for more details.
+	pub fn new_lossy(source: R) -> UnsafeLossy<R> {
+		UnsafeLossy::new(source)
+	}
 }
 
+#[cfg(feature = "std")]
 impl<R: Iterator<Item=Result<u8>>> Iterator for UnsafeDecoder<R> {
 	type Item = Result<char>;
 
 	fn next(&mut self) -> Option<Result<char>> {
-		decode_unsafe(&mut self.bytes)
+		if self.resync {
+			if let Err(e) = skip_invalid(&mut self.bytes, &mut self.pending, &mut self.offset)? {
+				return Some(Err(e))
+			}
+			self.resync = false
+		}
+
+		let offset = self.offset;
+		let mut source_error = false;
+		let mut last = 0;
+		let c = decode_unsafe(&mut CountedUnsafe {
+			bytes: &mut self.pending.take().map(Ok).into_iter().chain(&mut self.bytes),
+			count: &mut self.offset,
+			error: &mut source_error
+		}.inspect(|b| if let Ok(b) = b { last = *b }))?;
+
+		match c {
+			Ok(c) => {
+				self.chars += 1;
+				Some(Ok(c))
+			},
+			Err(e) if !source_error => {
+				if self.offset - offset > 1 && last & 0xC0 != 0x80 {
+					// The byte ending the invalid sequence may start a new one.
+					self.pending = Some(last);
+					self.offset -= 1
+				}
+
+				self.resync = true;
+				Some(Err(OffsetError::new(offset, e).into()))
+			},
+			c => Some(c)
+		}
+	}
+
+	/// Each character is encoded by 1 to 4 bytes, and each error consumes at least one byte.
+	///
+	/// ## Example
+	/// ```rust
+	/// # use utf8_decode::UnsafeDecoder;
+	/// let bytes = "aé€😀".as_bytes();
+	/// let decoder = UnsafeDecoder::new(bytes.iter().cloned().map(Ok));
+	/// assert_eq!(decoder.size_hint(), (2, Some(10)));
+	/// assert_eq!(decoder.count(), 4);
+	/// ```
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let pending = self.pending.is_some() as usize;
+		let (lower, upper) = self.bytes.size_hint();
+		((lower + pending) / 4, upper.and_then(|upper| upper.checked_add(pending)))
+	}
+}
+
+/// An `UnsafeDecoder` returns `None` only when the source iterator returns `None` in place of the
+/// first byte of a character. If the source iterator is fused, so is the decoder, even after an
+/// error.
+///
+/// ## Example
+/// ```rust
+/// # use std::iter::FusedIterator;
+/// # use utf8_decode::UnsafeDecoder;
+/// fn assert_fused<I: FusedIterator>(_: &I) {}
+///
+/// let mut decoder = UnsafeDecoder::new(b"a\xe2\x82".iter().cloned().map(Ok));
+/// assert_fused(&decoder);
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'a');
+/// assert!(decoder.next().unwrap().is_err());
+/// assert!(decoder.next().is_none());
+/// assert!(decoder.next().is_none());
+/// ```
+#[cfg(feature = "std")]
+impl<R: FusedIterator<Item=Result<u8>>> FusedIterator for UnsafeDecoder<R> {}
+
+/// An `UnsafeDecoder` can be cloned if its source iterator can, to decode ahead and backtrack.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::UnsafeDecoder;
+/// let mut decoder = UnsafeDecoder::new("aé€".bytes().map(Ok));
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'a');
+///
+/// let mut lookahead = decoder.clone();
+/// assert_eq!(lookahead.next().unwrap().unwrap(), 'é');
+/// assert_eq!(lookahead.next().unwrap().unwrap(), '€');
+/// assert!(lookahead.next().is_none());
+///
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'é');
+/// assert_eq!(decoder.next().unwrap().unwrap(), '€');
+/// ```
+#[cfg(feature = "std")]
+impl<R: Clone + Iterator<Item=Result<u8>>> Clone for UnsafeDecoder<R> {
+	fn clone(&self) -> UnsafeDecoder<R> {
+		UnsafeDecoder {
+			bytes: self.bytes.clone(),
+			pending: self.pending,
+			resync: self.resync,
+			offset: self.offset,
+			chars: self.chars
+		}
+	}
+}
+
+/// Formats the source iterator, the byte kept to be decoded next, if any, whether the rest of an
+/// invalid sequence is to be skipped, and the numbers of bytes and characters decoded so far.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::UnsafeDecoder;
+/// let mut decoder = UnsafeDecoder::new(vec![Ok(0xc3), Ok(0xa9), Ok(0xe2), Ok(0x41)].into_iter());
+/// decoder.next();
+/// decoder.next();
+/// assert_eq!(
+///     format!("{:?}", decoder),
+///     "UnsafeDecoder { bytes: IntoIter([]), pending: Some(65), resync: true, offset: 3, chars: 1 }"
+/// );
+/// ```
+#[cfg(feature = "std")]
+impl<R: fmt::Debug + Iterator<Item=Result<u8>>> fmt::Debug for UnsafeDecoder<R> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("UnsafeDecoder")
+			.field("bytes", &self.bytes)
+			.field("pending", &self.pending)
+			.field("resync", &self.resync)
+			.field("offset", &self.offset)
+			.field("chars", &self.chars)
+			.finish()
+	}
+}
+
+/// UTF-8 decoder iterator yielding raw code points from a [`Result<u8>`](std::io::Result)
+/// source iterator.
+///
+/// This is the [`RawDecoder`] for fallible sources, as the [`UnsafeDecoder`] is the
+/// [`Decoder`] for fallible sources. Surrogate code points, and code points from U+110000 to
+/// U+13FFFF, are yielded as is.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::UnsafeRawDecoder;
+/// let bytes = [0x61, 0xed, 0xa0, 0xbd, 0xed, 0xb8, 0x80];
+/// let decoder = UnsafeRawDecoder::new(bytes.iter().cloned().map(Ok));
+/// let code_points: std::io::Result<Vec<u32>> = decoder.collect();
+/// assert_eq!(code_points.unwrap(), [0x61, 0xd83d, 0xde00]);
+/// ```
+///
+/// ## Errors
+/// Errors are the same as the [`UnsafeDecoder`] errors, except for the errors on surrogate code
+/// points. Decoding resumes after an error like with the [`UnsafeDecoder`], at the next byte that
+/// may start a sequence.
+#[cfg(feature = "std")]
+pub struct UnsafeRawDecoder<R: Iterator<Item=Result<u8>>> {
+	bytes: R,
+	pending: Option<u8>,
+	resync: bool,
+	offset: usize
+}
+
+#[cfg(feature = "std")]
+impl<R: Iterator<Item=Result<u8>>> UnsafeRawDecoder<R> {
+	/// Creates a new `UnsafeRawDecoder` iterator from the given
+	/// [`Result<u8>`](std::io::Result) source iterator.
+	pub fn new(source: R) -> UnsafeRawDecoder<R> {
+		UnsafeRawDecoder {
+			bytes: source,
+			pending: None,
+			resync: false,
+			offset: 0
+		}
+	}
+
+	/// Returns a reference to the source iterator.
+	pub fn get_ref(&self) -> &R {
+		&self.bytes
+	}
+
+	/// Returns a mutable reference to the source iterator.
+	pub fn get_mut(&mut self) -> &mut R {
+		&mut self.bytes
+	}
+
+	/// Unwraps this `UnsafeRawDecoder`, returning the source iterator.
+	///
+	/// Right after an error, the byte that ended the invalid sequence may be kept by the decoder
+	/// to be decoded next: it is then lost.
+	pub fn into_inner(self) -> R {
+		self.bytes
+	}
+}
+
+#[cfg(feature = "std")]
+impl<R: Iterator<Item=Result<u8>>> Iterator for UnsafeRawDecoder<R> {
+	type Item = Result<u32>;
+
+	fn next(&mut self) -> Option<Result<u32>> {
+		if self.resync {
+			if let Err(e) = skip_invalid(&mut self.bytes, &mut self.pending, &mut self.offset)? {
+				return Some(Err(e))
+			}
+			self.resync = false
+		}
+
+		let offset = self.offset;
+		let mut source_error = false;
+		let mut last = 0;
+		let mut source = self.pending.take().map(Ok).into_iter().chain(&mut self.bytes);
+		let mut bytes = CountedUnsafe {
+			bytes: &mut source,
+			count: &mut self.offset,
+			error: &mut source_error
+		}.inspect(|b| if let Ok(b) = b { last = *b });
+
+		let c = match bytes.next()? {
+			Ok(a) => raw_decode_from(a as u32, &mut bytes),
+			Err(e) => Err(e)
+		};
+
+		match c {
+			Err(e) if !source_error => {
+				if self.offset - offset > 1 && last & 0xC0 != 0x80 {
+					// The byte ending the invalid sequence may start a new one.
+					self.pending = Some(last);
+					self.offset -= 1
+				}
+
+				self.resync = true;
+				Some(Err(OffsetError::new(offset, e).into()))
+			},
+			c => Some(c)
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let pending = self.pending.is_some() as usize;
+		let (lower, upper) = self.bytes.size_hint();
+		((lower + pending) / 4, upper.and_then(|upper| upper.checked_add(pending)))
+	}
+}
+
+#[cfg(feature = "std")]
+impl<R: FusedIterator<Item=Result<u8>>> FusedIterator for UnsafeRawDecoder<R> {}
+
+/// Byte iterator counting the bytes pulled out of the underlying iterator, and recording if the
+/// underlying iterator returned an error.
+#[cfg(feature = "std")]
+struct CountedUnsafe<'a, R> {
+	bytes: &'a mut R,
+	count: &'a mut usize,
+	error: &'a mut bool
+}
+
+#[cfg(feature = "std")]
+impl<'a, R: Iterator<Item=Result<u8>>> Iterator for CountedUnsafe<'a, R> {
+	type Item = Result<u8>;
+
+	fn next(&mut self) -> Option<Result<u8>> {
+		loop {
+			match self.bytes.next()? {
+				Ok(b) => {
+					*self.count += 1;
+					return Some(Ok(b))
+				},
+				Err(e) if e.kind() == ErrorKind::Interrupted => (),
+				Err(e) => {
+					*self.error = true;
+					return Some(Err(e))
+				}
+			}
+		}
+	}
+}
+
+/// Skips the bytes of the given iterator up to the next byte that may start a sequence, which is
+/// then kept in `pending`, starting with the byte already in `pending`, if any.
+/// The skipped bytes are counted in `offset`, and
+/// [`Interrupted`](std::io::ErrorKind::Interrupted) errors are ignored.
+#[cfg(feature = "std")]
+pub(crate) fn skip_invalid<I: Iterator<Item=Result<u8>>>(bytes: &mut I, pending: &mut Option<u8>, offset: &mut usize) -> Option<Result<()>> {
+	loop {
+		let b = match pending.take() {
+			Some(b) => b,
+			None => match bytes.next()? {
+				Ok(b) => b,
+				Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+				Err(e) => return Some(Err(e))
+			}
+		};
+
+		if is_lead(b) {
+			*pending = Some(b);
+			return Some(Ok(()))
+		}
+
+		*offset += 1
 	}
 }
@@ -0,0 +1,259 @@
+use std::io::{Result, Error, ErrorKind};
+use crate::Decoder;
+
+/// UTF-8 decoder iterator limiting the length of each line.
+///
+/// Created by the [`Decoder::max_line_bytes`](crate::Decoder::max_line_bytes) method.
+/// The length of a line is counted in source bytes (not characters), from the start of the
+/// input or the last `'\n'`, excluding the `'\n'` itself.
+/// This can be used to enforce protocol line-length limits, such as SMTP's.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// // "é" is two bytes long, so each line is exactly 4 bytes long.
+/// let bytes = "abé\néab".bytes();
+/// let string: std::io::Result<String> = Decoder::new(bytes).max_line_bytes(4).collect();
+/// assert_eq!(string.unwrap(), "abé\néab");
+///
+/// // The second "é" pushes the line to 5 bytes.
+/// let bytes = "abéé".bytes();
+/// let mut decoder = Decoder::new(bytes).max_line_bytes(4);
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'a');
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'b');
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'é');
+/// assert_eq!(decoder.next().unwrap().unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+/// ```
+///
+/// ## Errors
+/// Every character ending beyond the limit of its line is replaced by an
+/// [`InvalidData`](std::io::ErrorKind::InvalidData) error.
+/// Decoding errors are forwarded.
+pub struct MaxLineBytes<R: Iterator<Item=u8>> {
+	decoder: Decoder<R>,
+	max: usize,
+	line_start: usize
+}
+
+impl<R: Iterator<Item=u8>> MaxLineBytes<R> {
+	pub(crate) fn new(decoder: Decoder<R>, max: usize) -> MaxLineBytes<R> {
+		let line_start = decoder.offset();
+		MaxLineBytes {
+			decoder,
+			max,
+			line_start
+		}
+	}
+}
+
+impl<R: Iterator<Item=u8>> Iterator for MaxLineBytes<R> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		match self.decoder.next()? {
+			Ok('\n') => {
+				self.line_start = self.decoder.offset();
+				Some(Ok('\n'))
+			},
+			Ok(c) => {
+				let len = self.decoder.offset() - self.line_start;
+				if len > self.max {
+					Some(Err(Error::new(ErrorKind::InvalidData, format!("line exceeds {} bytes.", self.max))))
+				} else {
+					Some(Ok(c))
+				}
+			},
+			Err(e) => Some(Err(e))
+		}
+	}
+}
+
+/// UTF-8 decoder iterator converting the leading tabs of each line into spaces.
+///
+/// Created by the [`Decoder::normalize_indent`](crate::Decoder::normalize_indent) method.
+/// Only the indentation, the leading sequence of tabs and spaces of each line, is affected: each
+/// tab is replaced by `tab_width` spaces. Tabs found after the first other character of a line
+/// are left untouched.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let bytes = "a:\n\t b:\tc\n \td\n".bytes();
+/// let string: std::io::Result<String> = Decoder::new(bytes).normalize_indent(2).collect();
+/// assert_eq!(string.unwrap(), "a:\n   b:\tc\n   d\n");
+/// ```
+pub struct NormalizeIndent<I: Iterator<Item=Result<char>>> {
+	chars: I,
+	tab_width: usize,
+	indent: bool,
+	spaces: usize
+}
+
+impl<I: Iterator<Item=Result<char>>> NormalizeIndent<I> {
+	pub(crate) fn new(chars: I, tab_width: usize) -> NormalizeIndent<I> {
+		NormalizeIndent {
+			chars,
+			tab_width,
+			indent: true,
+			spaces: 0
+		}
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for NormalizeIndent<I> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		loop {
+			if self.spaces > 0 {
+				self.spaces -= 1;
+				return Some(Ok(' '))
+			}
+
+			match self.chars.next()? {
+				Ok('\t') if self.indent => self.spaces = self.tab_width,
+				Ok(c) => {
+					self.indent = c == '\n' || (self.indent && c == ' ');
+					return Some(Ok(c))
+				},
+				Err(e) => return Some(Err(e))
+			}
+		}
+	}
+}
+
+/// UTF-8 decoder iterator converting all line breaks into `'\n'`.
+///
+/// Created by the [`Decoder::normalize_all_line_breaks`](crate::Decoder::normalize_all_line_breaks)
+/// method. The recognized line breaks are the ones of the Unicode Standard (section 5.8):
+/// CR, LF, CRLF, NEL (U+0085), LINE SEPARATOR (U+2028) and PARAGRAPH SEPARATOR (U+2029).
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let bytes = "a\rb\nc\r\nd\u{85}e\u{2028}f\u{2029}g\r\r\n".bytes();
+/// let string: std::io::Result<String> = Decoder::new(bytes).normalize_all_line_breaks().collect();
+/// assert_eq!(string.unwrap(), "a\nb\nc\nd\ne\nf\ng\n\n");
+/// ```
+pub struct NormalizeAllLineBreaks<I: Iterator<Item=Result<char>>> {
+	chars: I,
+	after_cr: bool
+}
+
+impl<I: Iterator<Item=Result<char>>> NormalizeAllLineBreaks<I> {
+	pub(crate) fn new(chars: I) -> NormalizeAllLineBreaks<I> {
+		NormalizeAllLineBreaks {
+			chars,
+			after_cr: false
+		}
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for NormalizeAllLineBreaks<I> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		loop {
+			let after_cr = self.after_cr;
+			self.after_cr = false;
+			match self.chars.next()? {
+				Ok('\n') if after_cr => (),
+				Ok('\r') => {
+					self.after_cr = true;
+					return Some(Ok('\n'))
+				},
+				Ok('\n') | Ok('\u{85}') | Ok('\u{2028}') | Ok('\u{2029}') => return Some(Ok('\n')),
+				c => return Some(c)
+			}
+		}
+	}
+}
+
+/// UTF-8 decoder iterator calling a function at the end of each line.
+///
+/// Created by the [`Decoder::on_line_end`](crate::Decoder::on_line_end) method.
+/// Characters are yielded unchanged. Each time a `'\n'` is yielded, the function is called with
+/// its index in the decoded characters. Errors are not counted as characters.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let mut ends = Vec::new();
+/// let string: std::io::Result<String> = Decoder::new("héllo\n€\n\n😀".bytes())
+///     .on_line_end(|i| ends.push(i))
+///     .collect();
+/// assert_eq!(string.unwrap(), "héllo\n€\n\n😀");
+/// assert_eq!(ends, [5, 7, 8]);
+/// ```
+pub struct OnLineEnd<I: Iterator<Item=Result<char>>, F: FnMut(usize)> {
+	chars: I,
+	f: F,
+	index: usize
+}
+
+impl<I: Iterator<Item=Result<char>>, F: FnMut(usize)> OnLineEnd<I, F> {
+	pub(crate) fn new(chars: I, f: F) -> OnLineEnd<I, F> {
+		OnLineEnd {
+			chars,
+			f,
+			index: 0
+		}
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>, F: FnMut(usize)> Iterator for OnLineEnd<I, F> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		let c = self.chars.next()?;
+		if let Ok(c) = c {
+			if c == '\n' {
+				(self.f)(self.index)
+			}
+
+			self.index += 1
+		}
+
+		Some(c)
+	}
+}
+
+/// Decodes the given characters and returns the 1-based numbers of the lines ending with
+/// whitespace.
+///
+/// A `'\r'` directly preceding a `'\n'` is part of the line terminator, not trailing whitespace.
+pub(crate) fn trailing_whitespace_lines<I: Iterator<Item=Result<char>>>(chars: I) -> Result<Vec<usize>> {
+	let mut lines = Vec::new();
+	let mut line = 1;
+	let mut trailing = false;
+	let mut cr = false;
+	let mut trailing_before_cr = false;
+	for c in chars {
+		match c? {
+			'\n' => {
+				if (cr && trailing_before_cr) || (!cr && trailing) {
+					lines.push(line)
+				}
+
+				line += 1;
+				trailing = false;
+				cr = false
+			},
+			'\r' => {
+				trailing_before_cr = trailing;
+				trailing = true;
+				cr = true
+			},
+			c => {
+				trailing = c.is_whitespace();
+				cr = false
+			}
+		}
+	}
+
+	if trailing {
+		lines.push(line)
+	}
+
+	Ok(lines)
+}
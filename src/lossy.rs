@@ -0,0 +1,947 @@
+use std::io::{Result, Error, ErrorKind};
+use std::ops::Range;
+use std::collections::HashMap;
+use crate::DecodeError;
+
+/// Lossy UTF-8 decoder iterator preserving the width of the input.
+///
+/// Created by the [`Decoder::lossy_preserve_width`](crate::Decoder::lossy_preserve_width) method.
+/// Each source byte that is not part of a valid UTF-8 sequence is replaced by exactly
+/// one replacement character ([`char::REPLACEMENT_CHARACTER`] by default), so the number of
+/// characters of a fixed-width record is never altered by invalid data.
+/// Decoding then resumes at the next byte.
+///
+/// If the replacement character is encoded on a single byte, such as `'?'`, re-encoding the
+/// output preserves the byte alignment of the input.
+/// Otherwise the difference between the output and input byte lengths is reported by
+/// [`byte_delta`](LossyPreserveWidth::byte_delta).
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// // A Latin-1 "é" and a truncated "€" sequence.
+/// let bytes = [0x61, 0xe9, 0x62, 0xe2, 0x82, 0x63];
+///
+/// let mut decoder = Decoder::new(bytes.iter().cloned()).lossy_preserve_width().with_replacement('?');
+/// let string: String = (&mut decoder).collect();
+/// assert_eq!(string, "a?b??c");
+/// assert_eq!(string.len(), bytes.len());
+/// assert_eq!(decoder.byte_delta(), 0);
+///
+/// let mut decoder = Decoder::new(bytes.iter().cloned()).lossy_preserve_width();
+/// let string: String = (&mut decoder).collect();
+/// assert_eq!(string, "a\u{fffd}b\u{fffd}\u{fffd}c");
+/// assert_eq!(decoder.byte_delta(), 6);
+///
+/// fn preserve_width(bytes: &[u8]) -> String {
+///     Decoder::new(bytes.iter().cloned()).lossy_preserve_width().with_replacement('?').collect()
+/// }
+///
+/// // Overlong encodings of "/" and U+0000, a surrogate, and a code point above U+10FFFF.
+/// assert_eq!(preserve_width(b"\xc0\xaf"), "??");
+/// assert_eq!(preserve_width(b"\xe0\x80\x80"), "???");
+/// assert_eq!(preserve_width(b"a\xf0\x80\x80\xafb"), "a????b");
+/// assert_eq!(preserve_width(b"\xed\xa0\x80"), "???");
+/// assert_eq!(preserve_width(b"\xf4\x90\x80\x80"), "????");
+/// assert_eq!(preserve_width("é€😀".as_bytes()), "é€😀");
+/// ```
+pub struct LossyPreserveWidth<R: Iterator<Item=u8>> {
+	bytes: R,
+	pending: Option<u8>,
+	replacements: usize,
+	replacement: char,
+	delta: isize
+}
+
+impl<R: Iterator<Item=u8>> LossyPreserveWidth<R> {
+	pub(crate) fn new(bytes: R, pending: Option<u8>) -> LossyPreserveWidth<R> {
+		LossyPreserveWidth {
+			bytes,
+			pending,
+			replacements: 0,
+			replacement: char::REPLACEMENT_CHARACTER,
+			delta: 0
+		}
+	}
+
+	/// Sets the character substituted to each invalid byte.
+	pub fn with_replacement(mut self, replacement: char) -> LossyPreserveWidth<R> {
+		self.replacement = replacement;
+		self
+	}
+
+	/// Difference between the number of bytes of the UTF-8 encoded output so far, and the number
+	/// of input bytes consumed so far.
+	///
+	/// This is always `0` if the replacement character is encoded on a single byte.
+	pub fn byte_delta(&self) -> isize {
+		self.delta
+	}
+
+	/// Substitute the replacement character to the `n` first bytes of the current sequence.
+	fn invalid(&mut self, n: usize) -> char {
+		self.replacements = n - 1;
+		self.replace()
+	}
+
+	fn replace(&mut self) -> char {
+		self.delta += self.replacement.len_utf8() as isize - 1;
+		self.replacement
+	}
+}
+
+impl<R: Iterator<Item=u8>> Iterator for LossyPreserveWidth<R> {
+	type Item = char;
+
+	fn next(&mut self) -> Option<char> {
+		if self.replacements > 0 {
+			self.replacements -= 1;
+			return Some(self.replace())
+		}
+
+		let a = match self.pending.take() {
+			Some(a) => a,
+			None => self.bytes.next()?
+		};
+
+		// Bounds of the first continuation byte, as given by the Table 3-7 of the Unicode
+		// Standard, rejecting overlong encodings, surrogates and code points above U+10FFFF.
+		let mut lower = 0x80;
+		let mut upper = 0xBF;
+		let (len, mut codepoint) = match a {
+			0x00..=0x7F => return Some(a as char),
+			0xC2..=0xDF => (2, (a & 0x1F) as u32),
+			0xE0..=0xEF => {
+				if a == 0xE0 {
+					lower = 0xA0
+				} else if a == 0xED {
+					upper = 0x9F
+				}
+				(3, (a & 0x0F) as u32)
+			},
+			0xF0..=0xF4 => {
+				if a == 0xF0 {
+					lower = 0x90
+				} else if a == 0xF4 {
+					upper = 0x8F
+				}
+				(4, (a & 0x07) as u32)
+			},
+			_ => return Some(self.invalid(1))
+		};
+
+		for i in 1..len {
+			match self.bytes.next() {
+				Some(b) if (lower..=upper).contains(&b) => {
+					lower = 0x80;
+					upper = 0xBF;
+					codepoint = codepoint << 6 | (b & 0x3F) as u32
+				},
+				Some(b) => {
+					// The byte is decoded again, as an invalid byte if it is a continuation byte.
+					self.pending = Some(b);
+					return Some(self.invalid(i))
+				},
+				None => return Some(self.invalid(i))
+			}
+		}
+
+		// Table 3-7 only allows Unicode scalar values.
+		Some(char::from_u32(codepoint).unwrap())
+	}
+}
+
+/// Recovery strategy after an ill-formed sequence.
+///
+/// An ill-formed sequence starts with a lead byte possibly followed by some continuation bytes,
+/// up to the first byte that cannot continue the sequence (or the end of the input).
+/// The bytes before this first offending byte form the *maximal subpart* of the ill-formed
+/// sequence. The offending byte itself is never skipped: it is always decoded as the start of
+/// the next sequence.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum RecoveryStrategy {
+	/// Skip only the lead byte of the ill-formed sequence, and resume decoding at the next byte.
+	///
+	/// One replacement character is emitted for each byte of the maximal subpart, since its
+	/// continuation bytes are then decoded as invalid lead bytes.
+	/// This is the most conservative strategy.
+	SkipOneByte,
+
+	/// Skip the whole maximal subpart of the ill-formed sequence, emitting a single replacement
+	/// character for it. This is the behavior of web browsers (WHATWG Encoding Standard) and
+	/// [`String::from_utf8_lossy`](std::string::String::from_utf8_lossy).
+	SkipMaximalSubpart
+}
+
+/// Lossy UTF-8 decoder iterator.
+///
+/// Created by the [`Decoder::lossy`](crate::Decoder::lossy) and
+/// [`Decoder::lossy_with_recovery`](crate::Decoder::lossy_with_recovery) methods.
+/// By default, with the [`SkipMaximalSubpart`](RecoveryStrategy::SkipMaximalSubpart) recovery
+/// strategy, each maximal subpart of an ill-formed sequence is replaced by a single
+/// [`char::REPLACEMENT_CHARACTER`], following the *substitution of maximal subparts* practice of
+/// the Unicode Standard (section 3.9), also specified by the WHATWG Encoding Standard.
+/// The output is hence identical to the one of web browsers and
+/// [`String::from_utf8_lossy`](std::string::String::from_utf8_lossy).
+/// Another replacement character can be chosen with [`with_replacement`](Lossy::with_replacement).
+///
+/// Only well-formed sequences as defined by the Table 3-7 of the Unicode Standard are accepted:
+/// overlong encodings, surrogate code points and code points above U+10FFFF are replaced.
+///
+/// Decoding is done in a single pass with no allocation: the state machine only spans the
+/// at-most-4-byte window of the current sequence, and the byte ending an ill-formed subpart is
+/// the only byte ever kept for the next character.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// fn lossy(bytes: &[u8]) -> String {
+///     Decoder::new(bytes.iter().cloned()).lossy().map(Result::unwrap).collect()
+/// }
+///
+/// // Table 3-8 of the Unicode Standard.
+/// let bytes = [0x61, 0xf1, 0x80, 0x80, 0xe1, 0x80, 0xc2, 0x62, 0x80, 0x63, 0x80, 0xbf, 0x64];
+/// assert_eq!(lossy(&bytes), "a\u{fffd}\u{fffd}\u{fffd}b\u{fffd}c\u{fffd}\u{fffd}d");
+/// assert_eq!(lossy(&bytes), String::from_utf8_lossy(&bytes));
+/// ```
+///
+/// The two recovery strategies only differ when the maximal subpart includes continuation
+/// bytes. In particular, they consume the same bytes on `F0 28 8C 28`: `0x28` cannot follow
+/// `0xF0`, so the maximal subpart is `0xF0` alone, and `0x8C` is an invalid lead byte on its own.
+/// Skipping the four bytes announced by `0xF0` would drop the valid `(` characters.
+/// ```rust
+/// # use utf8_decode::{Decoder, RecoveryStrategy};
+/// fn spans(bytes: &[u8], strategy: RecoveryStrategy) -> Vec<(char, std::ops::Range<usize>)> {
+///     Decoder::new(bytes.iter().cloned())
+///         .lossy_with_recovery(strategy)
+///         .spanned()
+///         .map(|r| { let (c, input, _) = r.unwrap(); (c, input) })
+///         .collect()
+/// }
+///
+/// let bytes = [0xf0, 0x28, 0x8c, 0x28];
+/// let expected = [('\u{fffd}', 0..1), ('(', 1..2), ('\u{fffd}', 2..3), ('(', 3..4)];
+/// assert_eq!(spans(&bytes, RecoveryStrategy::SkipMaximalSubpart), expected);
+/// assert_eq!(spans(&bytes, RecoveryStrategy::SkipOneByte), expected);
+///
+/// // The maximal subpart is `F0 90 8C`, three bytes skipped at once, or one by one.
+/// let bytes = [0xf0, 0x90, 0x8c, 0x28];
+/// assert_eq!(spans(&bytes, RecoveryStrategy::SkipMaximalSubpart), [('\u{fffd}', 0..3), ('(', 3..4)]);
+/// assert_eq!(spans(&bytes, RecoveryStrategy::SkipOneByte), [
+///     ('\u{fffd}', 0..1), ('\u{fffd}', 1..2), ('\u{fffd}', 2..3), ('(', 3..4)
+/// ]);
+/// ```
+pub struct Lossy<R: Iterator<Item=u8>> {
+	bytes: R,
+	strategy: RecoveryStrategy,
+	replacement: char,
+	pending: Option<u8>,
+	replacements: usize,
+	skipped: u32,
+	offset: usize,
+	start: usize,
+	surrogates: bool,
+	overlong: bool
+}
+
+impl<R: Iterator<Item=u8>> Lossy<R> {
+	pub(crate) fn new(bytes: R, strategy: RecoveryStrategy) -> Lossy<R> {
+		Lossy {
+			bytes,
+			strategy,
+			replacement: char::REPLACEMENT_CHARACTER,
+			pending: None,
+			replacements: 0,
+			skipped: 0,
+			offset: 0,
+			start: 0,
+			surrogates: false,
+			overlong: false
+		}
+	}
+
+	/// Creates a new lossy decoder resuming the decoding of an input after `offset` bytes,
+	/// already decoded from `pending` and `bytes`: `pending` is the byte, if any, kept to be
+	/// decoded before the rest of `bytes`.
+	pub(crate) fn resume(bytes: R, strategy: RecoveryStrategy, pending: Option<u8>, offset: usize) -> Lossy<R> {
+		let mut decoder = Lossy::new(bytes, strategy);
+		decoder.pending = pending;
+		decoder.offset = offset;
+		decoder.start = offset;
+		decoder
+	}
+
+	/// Sets the character substituted to invalid sequences.
+	///
+	/// This is [`char::REPLACEMENT_CHARACTER`] by default.
+	pub fn with_replacement(mut self, replacement: char) -> Lossy<R> {
+		self.replacement = replacement;
+		self
+	}
+
+	/// Yields each character along with its byte span in the input and in the UTF-8 encoded
+	/// output.
+	///
+	/// See [`LossySpanned`] for more details.
+	pub fn spanned(self) -> LossySpanned<R> {
+		LossySpanned {
+			decoder: self,
+			output: 0
+		}
+	}
+
+	/// Collapses each run of consecutive replacement characters into one.
+	///
+	/// See [`LossyCoalesced`] for more details.
+	pub fn coalesced(self) -> LossyCoalesced<R> {
+		LossyCoalesced {
+			decoder: self,
+			pending: None
+		}
+	}
+
+	/// Ends the ill-formed sequence after `seen` continuation bytes, whose bits are the lowest
+	/// of `codepoint`, according to the recovery strategy.
+	fn recover(&mut self, seen: usize, codepoint: u32, e: DecodeError) -> std::result::Result<u32, DecodeError> {
+		if self.strategy == RecoveryStrategy::SkipOneByte {
+			// Each continuation byte would be decoded as an invalid lead byte.
+			self.replacements = seen;
+			self.skipped = codepoint;
+			self.offset = self.start + 1;
+		}
+
+		Err(e)
+	}
+
+	/// Offset of the first byte of the last decoded sequence.
+	pub(crate) fn start(&self) -> usize {
+		self.start
+	}
+
+	fn next_byte(&mut self) -> Option<u8> {
+		let b = self.pending.take().or_else(|| self.bytes.next())?;
+		self.offset += 1;
+		Some(b)
+	}
+
+	/// Decode the next character, or the next maximal subpart of an ill-formed sequence as an
+	/// error.
+	pub(crate) fn next_char(&mut self) -> Option<std::result::Result<char, DecodeError>> {
+		// Without surrogates, Table 3-7 only allows Unicode scalar values.
+		self.next_code_point().map(|c| c.map(|c| char::from_u32(c).unwrap()))
+	}
+
+	/// Decode the next code point, or the next maximal subpart of an ill-formed sequence as an
+	/// error.
+	/// If `surrogates` is set, the encoding of surrogate code points is accepted, as in WTF-8.
+	/// If `overlong` is set, overlong encodings are accepted, except for surrogate code points
+	/// when `surrogates` is not set.
+	fn next_code_point(&mut self) -> Option<std::result::Result<u32, DecodeError>> {
+		let mut codepoint = 0;
+		let mut needed = 0;
+		let mut seen = 0;
+		let mut lower = 0x80;
+		let mut upper = 0xBF;
+
+		self.start = self.offset;
+		if self.replacements > 0 {
+			self.replacements -= 1;
+			self.offset += 1;
+			let byte = 0x80 | (self.skipped >> (6 * self.replacements) & 0x3F) as u8;
+			return Some(Err(DecodeError::InvalidLeadByte { offset: self.start, byte }))
+		}
+
+		loop {
+			let b = match self.next_byte() {
+				Some(b) => b,
+				None if needed == 0 => return None,
+				None => {
+					let offset = self.start;
+					return Some(self.recover(seen, codepoint, DecodeError::UnexpectedEof { offset }))
+				}
+			};
+
+			if needed == 0 {
+				match b {
+					0x00..=0x7F => return Some(Ok(b as u32)),
+					0xC0..=0xC1 if self.overlong => {
+						needed = 1;
+						codepoint = (b & 0x1F) as u32
+					},
+					0xC2..=0xDF => {
+						needed = 1;
+						codepoint = (b & 0x1F) as u32
+					},
+					0xE0..=0xEF => {
+						if b == 0xE0 && !self.overlong {
+							lower = 0xA0
+						} else if b == 0xED && !self.surrogates {
+							upper = 0x9F
+						}
+						needed = 2;
+						codepoint = (b & 0x0F) as u32
+					},
+					0xF0..=0xF4 => {
+						if b == 0xF0 && !self.overlong {
+							lower = 0x90
+						} else if b == 0xF4 {
+							upper = 0x8F
+						}
+						needed = 3;
+						codepoint = (b & 0x07) as u32
+					},
+					_ => return Some(Err(DecodeError::InvalidLeadByte { offset: self.start, byte: b }))
+				}
+			} else if b < lower || b > upper {
+				// End of the maximal subpart. The byte is decoded again as a new sequence.
+				self.pending = Some(b);
+				self.offset -= 1;
+				let offset = self.start;
+				let e = if b & 0xC0 != 0x80 {
+					DecodeError::InvalidContinuation { offset, byte: b }
+				} else {
+					// Only the first continuation byte has special bounds.
+					let code_point = (codepoint << 6 | (b & 0x3F) as u32) << (6 * (needed - 1));
+					if b < lower {
+						DecodeError::Overlong { offset, code_point }
+					} else if needed == 2 {
+						DecodeError::Surrogate { offset, code_point }
+					} else {
+						DecodeError::OutOfRange { offset, code_point }
+					}
+				};
+				return Some(self.recover(seen, codepoint, e))
+			} else {
+				lower = 0x80;
+				upper = 0xBF;
+				codepoint = codepoint << 6 | (b & 0x3F) as u32;
+				seen += 1;
+
+				if seen == needed {
+					if self.overlong && !self.surrogates && (0xD800..=0xDFFF).contains(&codepoint) {
+						// Overlong encoding of a surrogate code point.
+						let offset = self.start;
+						return Some(self.recover(seen, codepoint, DecodeError::Surrogate { offset, code_point: codepoint }))
+					}
+
+					return Some(Ok(codepoint))
+				}
+			}
+		}
+	}
+}
+
+/// Decodes the rest of the input of the given decoder, replacing each invalid sequence with
+/// `replacement`.
+pub(crate) fn decode_with_str<R: Iterator<Item=u8>>(mut decoder: Lossy<R>, replacement: &str) -> String {
+	let mut string = String::with_capacity(decoder.bytes.size_hint().0);
+	while let Some(c) = decoder.next_char() {
+		match c {
+			Ok(c) => string.push(c),
+			Err(_) => string.push_str(replacement)
+		}
+	}
+
+	string
+}
+
+impl<R: Iterator<Item=u8>> Iterator for Lossy<R> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		let replacement = self.replacement;
+		self.next_char().map(|c| Ok(c.unwrap_or(replacement)))
+	}
+}
+
+/// Lossy UTF-8 decoder iterator replacing each run of consecutive invalid sequences with a
+/// single replacement character.
+///
+/// Created by the [`Lossy::coalesced`] and [`Decoder::lossy_coalesced`](crate::Decoder::lossy_coalesced)
+/// methods. Only the replacement characters inserted by the decoder are collapsed: replacement
+/// characters (U+FFFD) actually present in the input are kept as is.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// fn lossy_coalesced(bytes: &[u8]) -> String {
+///     Decoder::new(bytes.iter().cloned()).lossy_coalesced().map(Result::unwrap).collect()
+/// }
+///
+/// let bytes = b"a\xff\xfe\x80\xe2\x82\xc0\xafb\xffc";
+/// assert_eq!(lossy_coalesced(bytes), "a\u{fffd}b\u{fffd}c");
+/// assert_eq!(String::from_utf8_lossy(bytes).matches('\u{fffd}').count(), 7);
+///
+/// // The encoding of U+FFFD in the input is kept.
+/// assert_eq!(lossy_coalesced(b"\xef\xbf\xbd\xff\xff\xef\xbf\xbd"), "\u{fffd}\u{fffd}\u{fffd}");
+/// ```
+pub struct LossyCoalesced<R: Iterator<Item=u8>> {
+	decoder: Lossy<R>,
+	pending: Option<char>
+}
+
+impl<R: Iterator<Item=u8>> Iterator for LossyCoalesced<R> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		if let Some(c) = self.pending.take() {
+			return Some(Ok(c))
+		}
+
+		match self.decoder.next_char()? {
+			Ok(c) => Some(Ok(c)),
+			Err(_) => {
+				loop {
+					match self.decoder.next_char() {
+						Some(Ok(c)) => {
+							self.pending = Some(c);
+							break
+						},
+						Some(Err(_)) => (),
+						None => break
+					}
+				}
+
+				Some(Ok(self.decoder.replacement))
+			}
+		}
+	}
+}
+
+/// Lossy UTF-8 decoder iterator decoding overlong encodings to their intended character.
+///
+/// Created by the [`Decoder::lossy_recover_overlong`](crate::Decoder::lossy_recover_overlong)
+/// method. An overlong encoding, produced by some buggy encoders, still has an unambiguous
+/// value: this decoder yields it along with a flag set to `true`, instead of replacement
+/// characters. Other invalid sequences, including overlong encodings of surrogate code points,
+/// are replaced like with [`Decoder::lossy`](crate::Decoder::lossy), with the flag set to
+/// `false`. Since every byte is decoded to some character, this iterator never fails.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// fn recover(bytes: &[u8]) -> Vec<(char, bool)> {
+///     Decoder::new(bytes.iter().cloned()).lossy_recover_overlong().collect()
+/// }
+///
+/// // Overlong "A", then an invalid byte.
+/// assert_eq!(recover(b"\xc1\x81\xffb"), [('A', true), ('\u{fffd}', false), ('b', false)]);
+/// assert_eq!(Decoder::new(b"\xc1\x81\xffb".iter().cloned()).lossy().map(Result::unwrap).collect::<String>(), "\u{fffd}\u{fffd}\u{fffd}b");
+///
+/// // Overlong U+0000 (as in Java's modified UTF-8), "é", overlong "€".
+/// assert_eq!(recover(b"\xc0\x80\xc3\xa9\xf0\x82\x82\xac"), [('\0', true), ('é', false), ('€', true)]);
+///
+/// // Truncated overlong sequence, and overlong encoding of the surrogate U+D800.
+/// assert_eq!(recover(b"\xe0\x81a"), [('\u{fffd}', false), ('a', false)]);
+/// assert_eq!(recover(b"\xf0\x8d\xa0\x80"), [('\u{fffd}', false)]);
+/// ```
+pub struct LossyRecoverOverlong<R: Iterator<Item=u8>> {
+	decoder: Lossy<R>
+}
+
+impl<R: Iterator<Item=u8>> LossyRecoverOverlong<R> {
+	pub(crate) fn new(mut decoder: Lossy<R>) -> LossyRecoverOverlong<R> {
+		decoder.overlong = true;
+		LossyRecoverOverlong {
+			decoder
+		}
+	}
+
+	/// Sets the character substituted to invalid sequences.
+	///
+	/// This is [`char::REPLACEMENT_CHARACTER`] by default.
+	pub fn with_replacement(mut self, replacement: char) -> LossyRecoverOverlong<R> {
+		self.decoder.replacement = replacement;
+		self
+	}
+}
+
+impl<R: Iterator<Item=u8>> Iterator for LossyRecoverOverlong<R> {
+	type Item = (char, bool);
+
+	fn next(&mut self) -> Option<(char, bool)> {
+		match self.decoder.next_char()? {
+			Ok(c) => Some((c, c.len_utf8() < self.decoder.offset - self.decoder.start)),
+			Err(_) => Some((self.decoder.replacement, false))
+		}
+	}
+}
+
+/// Lossy UTF-8 decoder iterator yielding each character with its byte spans.
+///
+/// Created by the [`Lossy::spanned`] method.
+/// Each character is yielded with its byte range in the input, and its byte range in the
+/// output once re-encoded in UTF-8. The two ranges have the same length for valid characters.
+/// For replacement characters, the input range covers the replaced bytes while the output range
+/// covers the replacement character encoding, whatever its width.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// // An invalid byte replaced with "€", three bytes wide.
+/// let bytes = [0x61, 0xff, 0xc3, 0xa9];
+/// let spans: Vec<_> = Decoder::new(bytes.iter().cloned()).lossy_with('€').spanned().map(Result::unwrap).collect();
+/// assert_eq!(spans, [
+///     ('a', 0..1, 0..1),
+///     ('€', 1..2, 1..4),
+///     ('é', 2..4, 4..6)
+/// ]);
+///
+/// let output: String = spans.iter().map(|(c, _, _)| c).collect();
+/// for (c, _, span) in spans {
+///     assert_eq!(output[span].chars().next(), Some(c));
+/// }
+/// ```
+pub struct LossySpanned<R: Iterator<Item=u8>> {
+	decoder: Lossy<R>,
+	output: usize
+}
+
+impl<R: Iterator<Item=u8>> Iterator for LossySpanned<R> {
+	type Item = Result<(char, Range<usize>, Range<usize>)>;
+
+	fn next(&mut self) -> Option<Result<(char, Range<usize>, Range<usize>)>> {
+		let c = self.decoder.next_char()?.unwrap_or(self.decoder.replacement);
+		let start = self.output;
+		self.output += c.len_utf8();
+		Some(Ok((c, self.decoder.start..self.decoder.offset, start..self.output)))
+	}
+}
+
+/// Lossy UTF-8 decoder iterator for unsafe input.
+///
+/// Created by the [`UnsafeDecoder::new_lossy`](crate::UnsafeDecoder::new_lossy) constructor.
+/// This is the [`Lossy`] decoder for a [`io::Result<u8>`](std::io::Result) source iterator:
+/// invalid sequences are replaced following the same rules, and errors returned by the source
+/// iterator are forwarded. Such an error ends the sequence being decoded, which is then replaced
+/// if incomplete.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::UnsafeDecoder;
+/// let bytes = [0x61, 0xe2, 0x82, 0x62, 0xf0, 0x9f];
+/// let decoder = UnsafeDecoder::new_lossy(bytes.iter().cloned().map(Ok));
+/// let string: std::io::Result<String> = decoder.collect();
+/// assert_eq!(string.unwrap(), "a\u{fffd}b\u{fffd}");
+///
+/// let source = vec![Ok(0x61), Err(std::io::ErrorKind::Other.into()), Ok(0x62)];
+/// let mut decoder = UnsafeDecoder::new_lossy(source.into_iter());
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'a');
+/// assert_eq!(decoder.next().unwrap().unwrap_err().kind(), std::io::ErrorKind::Other);
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'b');
+///
+/// // Interrupted errors are skipped.
+/// let source = vec![Ok(0xc3), Err(std::io::ErrorKind::Interrupted.into()), Ok(0xa9)];
+/// let string: std::io::Result<String> = UnsafeDecoder::new_lossy(source.into_iter()).collect();
+/// assert_eq!(string.unwrap(), "é");
+/// ```
+///
+/// ## Errors
+/// Errors returned by the source iterator are forwarded, except
+/// [`Interrupted`](std::io::ErrorKind::Interrupted) errors, which are skipped.
+pub struct UnsafeLossy<R: Iterator<Item=Result<u8>>> {
+	decoder: Lossy<Fallible<R>>,
+	error: Option<Error>
+}
+
+impl<R: Iterator<Item=Result<u8>>> UnsafeLossy<R> {
+	pub(crate) fn new(bytes: R) -> UnsafeLossy<R> {
+		let fallible = Fallible {
+			bytes,
+			error: None
+		};
+
+		UnsafeLossy {
+			decoder: Lossy::new(fallible, RecoveryStrategy::SkipMaximalSubpart),
+			error: None
+		}
+	}
+
+	/// Sets the character substituted to invalid sequences.
+	///
+	/// This is [`char::REPLACEMENT_CHARACTER`] by default.
+	pub fn with_replacement(mut self, replacement: char) -> UnsafeLossy<R> {
+		self.decoder.replacement = replacement;
+		self
+	}
+}
+
+impl<R: Iterator<Item=Result<u8>>> Iterator for UnsafeLossy<R> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		if let Some(e) = self.error.take() {
+			return Some(Err(e))
+		}
+
+		let c = self.decoder.next();
+		match self.decoder.bytes.error.take() {
+			Some(e) => match c {
+				Some(c) => {
+					self.error = Some(e);
+					Some(c)
+				},
+				None => Some(Err(e))
+			},
+			None => c
+		}
+	}
+}
+
+/// Byte iterator over a [`io::Result<u8>`](std::io::Result) iterator, ending at the first error
+/// and keeping it. [`Interrupted`](std::io::ErrorKind::Interrupted) errors are skipped.
+struct Fallible<R: Iterator<Item=Result<u8>>> {
+	bytes: R,
+	error: Option<Error>
+}
+
+impl<R: Iterator<Item=Result<u8>>> Iterator for Fallible<R> {
+	type Item = u8;
+
+	fn next(&mut self) -> Option<u8> {
+		loop {
+			match self.bytes.next()? {
+				Ok(b) => return Some(b),
+				Err(e) if e.kind() == ErrorKind::Interrupted => (),
+				Err(e) => {
+					self.error = Some(e);
+					return None
+				}
+			}
+		}
+	}
+}
+
+/// Lossy WTF-8 decoder iterator.
+///
+/// Created by the [`Decoder::wtf8_lossy`](crate::Decoder::wtf8_lossy) method.
+/// [WTF-8](https://simonsapin.github.io/wtf-8/) extends UTF-8 with the encoding of surrogate
+/// code points, to represent potentially ill-formed UTF-16 such as Windows file names.
+/// Surrogate code points are accepted while parsing, but since they are not valid characters,
+/// each lone surrogate is replaced by a replacement character ([`char::REPLACEMENT_CHARACTER`]
+/// by default). A high surrogate directly followed by a low surrogate is decoded as the
+/// supplementary character they encode. Other ill-formed sequences are replaced as by the
+/// [`Lossy`] decoder.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// fn wtf8_lossy(bytes: &[u8]) -> String {
+///     Decoder::new(bytes.iter().cloned()).wtf8_lossy().collect()
+/// }
+///
+/// // Lone high surrogate U+D83D, then lone low surrogate U+DE00.
+/// assert_eq!(wtf8_lossy(b"a\xed\xa0\xbdb\xed\xb8\x80c"), "a\u{fffd}b\u{fffd}c");
+/// // Low surrogate before a high surrogate.
+/// assert_eq!(wtf8_lossy(b"\xed\xb8\x80\xed\xa0\xbd"), "\u{fffd}\u{fffd}");
+/// // Surrogate pair encoding U+1F600.
+/// assert_eq!(wtf8_lossy(b"\xed\xa0\xbd\xed\xb8\x80"), "😀");
+/// // Other ill-formed sequences.
+/// assert_eq!(wtf8_lossy(b"\xffa\xe2\x82"), "\u{fffd}a\u{fffd}");
+/// ```
+pub struct Wtf8Lossy<R: Iterator<Item=u8>> {
+	decoder: Lossy<R>,
+	pending: Option<std::result::Result<u32, DecodeError>>
+}
+
+impl<R: Iterator<Item=u8>> Wtf8Lossy<R> {
+	pub(crate) fn new(mut decoder: Lossy<R>) -> Wtf8Lossy<R> {
+		decoder.surrogates = true;
+		Wtf8Lossy {
+			decoder,
+			pending: None
+		}
+	}
+
+	/// Sets the character substituted to lone surrogates and invalid sequences.
+	///
+	/// This is [`char::REPLACEMENT_CHARACTER`] by default.
+	pub fn with_replacement(mut self, replacement: char) -> Wtf8Lossy<R> {
+		self.decoder.replacement = replacement;
+		self
+	}
+}
+
+impl<R: Iterator<Item=u8>> Iterator for Wtf8Lossy<R> {
+	type Item = char;
+
+	fn next(&mut self) -> Option<char> {
+		let c = match self.pending.take() {
+			Some(c) => c,
+			None => self.decoder.next_code_point()?
+		};
+
+		let c = match c {
+			Ok(high @ 0xD800..=0xDBFF) => match self.decoder.next_code_point() {
+				Some(Ok(low @ 0xDC00..=0xDFFF)) => char::from_u32(0x10000 + ((high - 0xD800) << 10 | (low - 0xDC00))),
+				next => {
+					self.pending = next;
+					None
+				}
+			},
+			Ok(c) => char::from_u32(c),
+			Err(_) => None
+		};
+
+		Some(c.unwrap_or(self.decoder.replacement))
+	}
+}
+
+/// Lossy UTF-8 decoder iterator failing when the same invalid sequence repeats.
+///
+/// Created by the [`Decoder::fail_on_repeated_error`](crate::Decoder::fail_on_repeated_error)
+/// method. Like the [`Lossy`] decoder, each maximal invalid subsequence is replaced with one
+/// replacement character, but the occurrences of each distinct invalid byte sequence are
+/// counted. A sequence repeating many times usually means that the whole input is not UTF-8, or
+/// has been mis-encoded, rather than corrupted in a few places.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// fn check(bytes: &[u8], threshold: usize) -> std::io::Result<String> {
+///     Decoder::new(bytes.iter().cloned()).fail_on_repeated_error(threshold).collect()
+/// }
+///
+/// // Isolated corruption.
+/// assert_eq!(check(b"caf\xe9 cr\xe8me \xff", 2).unwrap(), "caf\u{fffd} cr\u{fffd}me \u{fffd}");
+///
+/// // Latin-1 input, where "é" is always encoded as `0xE9`.
+/// let error = check(b"caf\xe9, th\xe9, d\xe9j\xe0", 3).unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+/// assert_eq!(error.to_string(), "invalid UTF-8 sequence [E9] repeated 3 times, at byte 12.");
+///
+/// // `0xE9 0x2C` and `0xE9 0x20`: the maximal subpart is `0xE9` in both cases.
+/// assert!(check(b"\xe9,\xe9 ", 2).is_err());
+/// assert!(check(b"\xe2\x82 \xe2\x80 ", 2).is_ok());
+/// ```
+///
+/// ## Errors
+/// From the `threshold`th occurrence of an invalid byte sequence on, each occurrence is replaced
+/// by an [`InvalidData`](std::io::ErrorKind::InvalidData) error instead of a replacement
+/// character.
+pub struct FailOnRepeatedError<R: Iterator<Item=u8>> {
+	decoder: Lossy<Recorded<R>>,
+	threshold: usize,
+	base: usize,
+	counts: HashMap<Vec<u8>, usize>
+}
+
+impl<R: Iterator<Item=u8>> FailOnRepeatedError<R> {
+	pub(crate) fn new(bytes: R, pending: Option<u8>, offset: usize, threshold: usize) -> FailOnRepeatedError<R> {
+		// The kept byte is pulled out of the recorder, to be recorded as well.
+		let recorded = Recorded {
+			bytes,
+			pending,
+			buffer: Vec::new()
+		};
+
+		FailOnRepeatedError {
+			decoder: Lossy::resume(recorded, RecoveryStrategy::SkipMaximalSubpart, None, offset),
+			threshold,
+			base: offset,
+			counts: HashMap::new()
+		}
+	}
+
+	/// Sets the character substituted to invalid sequences.
+	///
+	/// This is [`char::REPLACEMENT_CHARACTER`] by default.
+	pub fn with_replacement(mut self, replacement: char) -> FailOnRepeatedError<R> {
+		self.decoder.replacement = replacement;
+		self
+	}
+}
+
+impl<R: Iterator<Item=u8>> Iterator for FailOnRepeatedError<R> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		let c = self.decoder.next_char()?;
+		let (start, end) = (self.decoder.start, self.decoder.offset);
+		let c = match c {
+			Ok(c) => Ok(c),
+			Err(_) => {
+				let sequence = &self.decoder.bytes.buffer[(start - self.base)..(end - self.base)];
+				let count = self.counts.entry(sequence.to_vec()).or_insert(0);
+				*count += 1;
+				if *count >= self.threshold {
+					Err(Error::new(ErrorKind::InvalidData, format!("invalid UTF-8 sequence {:02X?} repeated {} times, at byte {}.", sequence, count, start)))
+				} else {
+					Ok(self.decoder.replacement)
+				}
+			}
+		};
+
+		// The byte ending an invalid sequence may be kept to be decoded again.
+		self.decoder.bytes.buffer.drain(..(end - self.base));
+		self.base = end;
+		Some(c)
+	}
+}
+
+/// Byte iterator recording the bytes pulled out of the underlying iterator.
+struct Recorded<R: Iterator<Item=u8>> {
+	bytes: R,
+	pending: Option<u8>,
+	buffer: Vec<u8>
+}
+
+impl<R: Iterator<Item=u8>> Iterator for Recorded<R> {
+	type Item = u8;
+
+	fn next(&mut self) -> Option<u8> {
+		let b = self.pending.take().or_else(|| self.bytes.next())?;
+		self.buffer.push(b);
+		Some(b)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::Decoder;
+
+	fn lossy(bytes: &[u8]) -> String {
+		Decoder::new(bytes.iter().cloned()).lossy().map(Result::unwrap).collect()
+	}
+
+	#[test]
+	fn table_3_7() {
+		let table: &[(&[u8], &str)] = &[
+			// Second byte out of the bounds of Table 3-7.
+			(&[0xe0, 0x80, 0x80], "\u{fffd}\u{fffd}\u{fffd}"),
+			(&[0xe0, 0x9f, 0xbf], "\u{fffd}\u{fffd}\u{fffd}"),
+			(&[0xed, 0xa0, 0x80], "\u{fffd}\u{fffd}\u{fffd}"),
+			(&[0xed, 0xbf, 0xbf], "\u{fffd}\u{fffd}\u{fffd}"),
+			(&[0xf0, 0x80, 0x80, 0x80], "\u{fffd}\u{fffd}\u{fffd}\u{fffd}"),
+			(&[0xf0, 0x8f, 0xbf, 0xbf], "\u{fffd}\u{fffd}\u{fffd}\u{fffd}"),
+			(&[0xf4, 0x90, 0x80, 0x80], "\u{fffd}\u{fffd}\u{fffd}\u{fffd}"),
+			// Truncated sequences.
+			(&[0xf0, 0x80], "\u{fffd}\u{fffd}"),
+			(&[0xf4, 0x90], "\u{fffd}\u{fffd}"),
+			(&[0xe0, 0xa0], "\u{fffd}"),
+			(&[0xed, 0xa0], "\u{fffd}\u{fffd}"),
+			(&[0xf0, 0x90, 0x80], "\u{fffd}"),
+			(&[0xf0, 0x90, 0x80, 0x41], "\u{fffd}A"),
+			// Bounds of Table 3-7.
+			(&[0xc2, 0x80], "\u{80}"),
+			(&[0xdf, 0xbf], "\u{7ff}"),
+			(&[0xe0, 0xa0, 0x80], "\u{800}"),
+			(&[0xed, 0x9f, 0xbf], "\u{d7ff}"),
+			(&[0xee, 0x80, 0x80], "\u{e000}"),
+			(&[0xf0, 0x90, 0x80, 0x80], "\u{10000}"),
+			(&[0xf4, 0x8f, 0xbf, 0xbf], "\u{10ffff}"),
+			// Invalid lead bytes.
+			(&[0x80], "\u{fffd}"),
+			(&[0xbf], "\u{fffd}"),
+			(&[0xc0, 0x80], "\u{fffd}\u{fffd}"),
+			(&[0xc1, 0xbf], "\u{fffd}\u{fffd}"),
+			(&[0xf5, 0x80, 0x80, 0x80], "\u{fffd}\u{fffd}\u{fffd}\u{fffd}"),
+			(&[0xff], "\u{fffd}"),
+		];
+
+		for (bytes, expected) in table {
+			assert_eq!(lossy(bytes), *expected);
+			assert_eq!(lossy(bytes), String::from_utf8_lossy(bytes))
+		}
+	}
+}
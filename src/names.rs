@@ -0,0 +1,74 @@
+use std::io::Result;
+use crate::tables;
+
+const HANGUL_FIRST: u32 = 0xAC00;
+const HANGUL_LAST: u32 = 0xD7A3;
+
+const JAMO_L: [&str; 19] = ["G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T", "P", "H"];
+const JAMO_V: [&str; 21] = ["A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO", "WE", "WI", "YU", "EU", "YI", "I"];
+const JAMO_T: [&str; 28] = ["", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH", "M", "B", "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H"];
+
+/// Returns the Unicode name of the given character.
+///
+/// Names are derived algorithmically for Hangul syllables and CJK ideographs (see section 4.8 of
+/// the Unicode Standard). Other names are looked up in a compact table covering the following
+/// blocks:
+///  - Basic Latin to NKo (U+0000 to U+07FF),
+///  - General Punctuation to Miscellaneous Symbols and Arrows (U+2000 to U+2BFF),
+///  - CJK Symbols and Punctuation, Hiragana and Katakana (U+3000 to U+30FF),
+///  - Miscellaneous Symbols and Pictographs to Symbols and Pictographs Extended-A
+///    (U+1F300 to U+1FAFF).
+///
+/// Returns `None` for unnamed code points (such as control characters or unassigned code points)
+/// and for characters outside of these blocks.
+pub fn name(c: char) -> Option<String> {
+	let cp = c as u32;
+	if (HANGUL_FIRST..=HANGUL_LAST).contains(&cp) {
+		let s = (cp - HANGUL_FIRST) as usize;
+		let l = JAMO_L[s / (21 * 28)];
+		let v = JAMO_V[(s % (21 * 28)) / 28];
+		let t = JAMO_T[s % 28];
+		return Some(format!("HANGUL SYLLABLE {}{}{}", l, v, t))
+	}
+
+	if let Some(prefix) = tables::lookup(tables::ALGORITHMIC_NAMES, c) {
+		return Some(format!("{}{:04X}", prefix, cp))
+	}
+
+	tables::NAMES.binary_search_by_key(&cp, |&(cp, _)| cp).ok().map(|i| tables::NAMES[i].1.to_string())
+}
+
+/// UTF-8 decoder iterator yielding each character with its Unicode name.
+///
+/// Created by the [`Decoder::with_name`](crate::Decoder::with_name) method.
+/// See [`name`](crate::name) for the coverage of the name table.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let mut decoder = Decoder::new("a漢한\u{378}".bytes()).with_name();
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('a', Some("LATIN SMALL LETTER A".to_string())));
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('漢', Some("CJK UNIFIED IDEOGRAPH-6F22".to_string())));
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('한', Some("HANGUL SYLLABLE HAN".to_string())));
+/// // Unassigned code point.
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('\u{378}', None));
+/// ```
+pub struct WithName<I: Iterator<Item=Result<char>>> {
+	chars: I
+}
+
+impl<I: Iterator<Item=Result<char>>> WithName<I> {
+	pub(crate) fn new(chars: I) -> WithName<I> {
+		WithName {
+			chars
+		}
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for WithName<I> {
+	type Item = Result<(char, Option<String>)>;
+
+	fn next(&mut self) -> Option<Result<(char, Option<String>)>> {
+		self.chars.next().map(|c| c.map(|c| (c, name(c))))
+	}
+}
@@ -0,0 +1,172 @@
+use std::io::{Result, Error};
+use crate::tables;
+
+/// First Hangul syllable.
+const HANGUL_BASE: u32 = 0xAC00;
+
+/// Number of Hangul syllables.
+const HANGUL_COUNT: u32 = 11172;
+
+/// First leading consonant jamo.
+const JAMO_L_BASE: u32 = 0x1100;
+
+/// First vowel jamo.
+const JAMO_V_BASE: u32 = 0x1161;
+
+/// Code point preceding the first trailing consonant jamo.
+const JAMO_T_BASE: u32 = 0x11A7;
+
+/// Number of vowel jamos.
+const JAMO_V_COUNT: u32 = 21;
+
+/// Number of trailing consonant jamos, plus one for the absence of trailing consonant.
+const JAMO_T_COUNT: u32 = 28;
+
+/// Returns the canonical combining class of the given character.
+fn combining_class(c: char) -> u8 {
+	tables::lookup(tables::COMBINING_CLASS, c).unwrap_or(0)
+}
+
+/// Appends the full canonical decomposition of the given character to `buffer`.
+fn decompose(c: char, buffer: &mut Vec<char>) {
+	let cp = c as u32;
+	if (HANGUL_BASE..HANGUL_BASE + HANGUL_COUNT).contains(&cp) {
+		// See section 3.12 of the Unicode Standard.
+		let index = cp - HANGUL_BASE;
+		let l = JAMO_L_BASE + index / (JAMO_V_COUNT * JAMO_T_COUNT);
+		let v = JAMO_V_BASE + (index % (JAMO_V_COUNT * JAMO_T_COUNT)) / JAMO_T_COUNT;
+		let t = index % JAMO_T_COUNT;
+		buffer.extend(std::char::from_u32(l));
+		buffer.extend(std::char::from_u32(v));
+		if t != 0 {
+			buffer.extend(std::char::from_u32(JAMO_T_BASE + t))
+		}
+	} else {
+		match tables::CANONICAL_DECOMPOSITION.binary_search_by_key(&cp, |&(code, _)| code) {
+			Ok(i) => buffer.extend(tables::CANONICAL_DECOMPOSITION[i].1.chars()),
+			Err(_) => buffer.push(c)
+		}
+	}
+}
+
+/// Sorts each run of non-starters (characters with a non-zero combining class) of the given
+/// characters by combining class, preserving the order of characters of equal class.
+fn reorder(chars: &mut [char]) {
+	let mut i = 0;
+	while i < chars.len() {
+		if combining_class(chars[i]) == 0 {
+			i += 1
+		} else {
+			let len = chars[i..].iter().take_while(|&&c| combining_class(c) != 0).count();
+			chars[i..i + len].sort_by_key(|&c| combining_class(c));
+			i += len
+		}
+	}
+}
+
+/// UTF-8 decoder iterator yielding the input in Normalization Form D (NFD).
+///
+/// Created by the [`Decoder::nfd`](crate::Decoder::nfd) method.
+/// Each character is replaced by its full canonical decomposition, and each sequence of
+/// combining marks is put in canonical order, sorting the marks by canonical combining class.
+/// Since a sequence of combining marks can only be ordered once it is complete, the characters
+/// are buffered until the next starter (character of combining class `0`) or the end of the
+/// input.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// fn nfd(s: &str) -> String {
+///     Decoder::new(s.bytes()).nfd().collect::<std::io::Result<String>>().unwrap()
+/// }
+///
+/// // "é" is decomposed into "e" followed by U+0301 COMBINING ACUTE ACCENT.
+/// assert_eq!(nfd("café"), "cafe\u{301}");
+///
+/// // "ệ" is decomposed into "e", U+0323 COMBINING DOT BELOW (class 220), and U+0302
+/// // COMBINING CIRCUMFLEX ACCENT (class 230), whatever the order of the input marks.
+/// assert_eq!(nfd("ệ"), "e\u{323}\u{302}");
+/// assert_eq!(nfd("ê\u{323}"), "e\u{323}\u{302}");
+/// assert_eq!(nfd("e\u{302}\u{323}"), "e\u{323}\u{302}");
+///
+/// // Hangul syllables are decomposed into jamos.
+/// assert_eq!(nfd("한"), "\u{1112}\u{1161}\u{11ab}");
+/// ```
+///
+/// ## Errors
+/// Decoding errors are forwarded, after the characters decoded before them.
+pub struct Nfd<I: Iterator<Item=Result<char>>> {
+	chars: I,
+	buffer: Vec<char>,
+	ready: usize,
+	index: usize,
+	error: Option<Error>,
+	done: bool
+}
+
+impl<I: Iterator<Item=Result<char>>> Nfd<I> {
+	pub(crate) fn new(chars: I) -> Nfd<I> {
+		Nfd {
+			chars,
+			buffer: Vec::new(),
+			ready: 0,
+			index: 0,
+			error: None,
+			done: false
+		}
+	}
+
+	/// Reorders the whole buffer and marks it as ready.
+	fn flush(&mut self) {
+		reorder(&mut self.buffer);
+		self.ready = self.buffer.len()
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for Nfd<I> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		loop {
+			if self.index < self.ready {
+				let c = self.buffer[self.index];
+				self.index += 1;
+				return Some(Ok(c))
+			}
+
+			self.buffer.drain(..self.ready);
+			self.ready = 0;
+			self.index = 0;
+
+			if let Some(e) = self.error.take() {
+				return Some(Err(e))
+			}
+
+			if self.done {
+				if self.buffer.is_empty() {
+					return None
+				}
+
+				self.flush();
+				continue
+			}
+
+			match self.chars.next() {
+				Some(Ok(c)) => {
+					let len = self.buffer.len();
+					decompose(c, &mut self.buffer);
+					// Everything before the last starter is complete.
+					if let Some(last) = self.buffer[len..].iter().rposition(|&c| combining_class(c) == 0) {
+						reorder(&mut self.buffer[..len + last]);
+						self.ready = len + last
+					}
+				},
+				Some(Err(e)) => {
+					self.flush();
+					self.error = Some(e)
+				},
+				None => self.done = true
+			}
+		}
+	}
+}
@@ -0,0 +1,47 @@
+use std::io::Result;
+use crate::tables;
+
+/// Returns the numeric value of the given character, if it is an integer.
+///
+/// This includes the decimal digits of every script, but also characters such as Roman
+/// numerals or CJK ideographs used as numbers. Characters with a fractional numeric value,
+/// such as `½`, have no integer value and return `None`.
+pub fn numeric_value(c: char) -> Option<i64> {
+	tables::lookup(tables::NUMERIC, c)
+}
+
+/// UTF-8 decoder iterator yielding each character with its numeric value.
+///
+/// Created by the [`Decoder::with_numeric_value`](crate::Decoder::with_numeric_value) method.
+/// See [`numeric_value`](crate::numeric_value) for the characters having a numeric value.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let mut decoder = Decoder::new("7٣ⅻx".bytes()).with_numeric_value();
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('7', Some(7)));
+/// // ARABIC-INDIC DIGIT THREE.
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('٣', Some(3)));
+/// // SMALL ROMAN NUMERAL TWELVE.
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('ⅻ', Some(12)));
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('x', None));
+/// ```
+pub struct WithNumericValue<I: Iterator<Item=Result<char>>> {
+	chars: I
+}
+
+impl<I: Iterator<Item=Result<char>>> WithNumericValue<I> {
+	pub(crate) fn new(chars: I) -> WithNumericValue<I> {
+		WithNumericValue {
+			chars
+		}
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for WithNumericValue<I> {
+	type Item = Result<(char, Option<i64>)>;
+
+	fn next(&mut self) -> Option<Result<(char, Option<i64>)>> {
+		self.chars.next().map(|c| c.map(|c| (c, numeric_value(c))))
+	}
+}
@@ -0,0 +1,89 @@
+use std::io::{Result, Error};
+use crate::{DecodeError, TypedDecoder};
+
+/// UTF-8 decoder iterator accepting overlong encodings, and flagging them.
+///
+/// Created by the [`Decoder::accept_overlong`](crate::Decoder::accept_overlong) method.
+/// Each character is yielded along with a flag set to `true` if it was encoded with more bytes
+/// than necessary, meaning that re-encoding it would change the input bytes.
+/// Overlong encodings of surrogate code points are still rejected.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// // "a", overlong "/", "é", overlong U+0000 (as in Java's modified UTF-8), overlong "€".
+/// let bytes = [0x61, 0xc0, 0xaf, 0xc3, 0xa9, 0xc0, 0x80, 0xf0, 0x82, 0x82, 0xac];
+/// let chars: std::io::Result<Vec<_>> = Decoder::new(bytes.iter().cloned()).accept_overlong().collect();
+/// assert_eq!(chars.unwrap(), [('a', false), ('/', true), ('é', false), ('\0', true), ('€', true)]);
+///
+/// // Overlong encoding of the surrogate U+D800.
+/// let bytes = [0xf0, 0x8d, 0xa0, 0x80];
+/// let error = Decoder::new(bytes.iter().cloned()).accept_overlong().next().unwrap().unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+/// assert_eq!(error.to_string(), "surrogate code point U+D800 in UTF-8 sequence at byte 0.");
+/// ```
+///
+/// ## Errors
+/// Other decoding errors are the [`DecodeError`](crate::DecodeError) errors of a
+/// [`TypedDecoder`](crate::TypedDecoder), converted into [`std::io::Error`].
+pub struct AcceptOverlong<R: Iterator<Item=u8>> {
+	decoder: TypedDecoder<R>
+}
+
+impl<R: Iterator<Item=u8>> AcceptOverlong<R> {
+	pub(crate) fn new(decoder: TypedDecoder<R>) -> AcceptOverlong<R> {
+		AcceptOverlong {
+			decoder: decoder.accept_overlong()
+		}
+	}
+}
+
+impl<R: Iterator<Item=u8>> Iterator for AcceptOverlong<R> {
+	type Item = Result<(char, bool)>;
+
+	fn next(&mut self) -> Option<Result<(char, bool)>> {
+		match self.decoder.next()? {
+			Ok(c) => Some(Ok((c, false))),
+			Err(DecodeError::Overlong { offset, code_point }) => match std::char::from_u32(code_point) {
+				Some(c) => Some(Ok((c, true))),
+				None => Some(Err(Error::from(DecodeError::Surrogate { offset, code_point })))
+			},
+			Err(e) => Some(Err(e.into()))
+		}
+	}
+}
+
+/// Tells if the given bytes are in canonical UTF-8, meaning that decoding them and re-encoding
+/// the decoded characters gives back the same bytes.
+///
+/// The input is decoded leniently, accepting overlong encodings like an
+/// [`AcceptOverlong`](crate::AcceptOverlong) decoder. Returns `Ok(true)` if no character is
+/// encoded with more bytes than necessary, and `Ok(false)` otherwise.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::is_canonical;
+/// assert!(is_canonical("aé€😀".as_bytes()).unwrap());
+/// assert!(is_canonical(b"").unwrap());
+///
+/// // Overlong "/" and overlong "€".
+/// assert!(!is_canonical(b"a\xc0\xafb").unwrap());
+/// assert!(!is_canonical(b"\xf0\x82\x82\xac").unwrap());
+///
+/// assert!(is_canonical(b"a\xff").is_err());
+/// assert!(is_canonical(b"\xc0\xaf\xe2\x82").is_err());
+/// assert!(is_canonical(b"\xed\xa0\x80").is_err());
+/// ```
+///
+/// ## Errors
+/// Returns the first error of the [`AcceptOverlong`](crate::AcceptOverlong) decoder, if the
+/// input cannot be decoded even when accepting overlong encodings.
+pub fn is_canonical(bytes: &[u8]) -> Result<bool> {
+	let mut canonical = true;
+	for c in AcceptOverlong::new(TypedDecoder::new(bytes.iter().cloned())) {
+		let (_, overlong) = c?;
+		canonical &= !overlong
+	}
+
+	Ok(canonical)
+}
@@ -0,0 +1,88 @@
+use std::io::Result;
+use std::iter::FusedIterator;
+use crate::Decoder;
+
+/// UTF-8 decoder iterator with a one character lookahead.
+///
+/// Created by the [`Decoder::into_peekable`](crate::Decoder::into_peekable) method.
+/// The [`peek`](PeekableDecoder::peek) method decodes the next character and caches it,
+/// including when it is an error, so that the next call to [`next`](Iterator::next) returns it
+/// without reading the source iterator again. Unlike [`std::iter::Peekable`], the byte offset of
+/// the next character is available with [`offset`](PeekableDecoder::offset), even after a peek.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let mut decoder = Decoder::new(b"ab\xff".iter().cloned()).into_peekable();
+/// assert_eq!(decoder.peek().unwrap().as_ref().unwrap(), &'a');
+/// assert_eq!(decoder.peek().unwrap().as_ref().unwrap(), &'a');
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'a');
+///
+/// // Consume 'b' only if it is the next character.
+/// assert!(decoder.next_if_eq('c').is_none());
+/// assert_eq!(decoder.next_if_eq('b').unwrap().unwrap(), 'b');
+///
+/// // The error is cached too.
+/// assert_eq!(decoder.offset(), 2);
+/// assert!(decoder.peek().unwrap().is_err());
+/// assert_eq!(decoder.offset(), 2);
+/// let error = decoder.next().unwrap().unwrap_err();
+/// assert_eq!(error.to_string(), "invalid lead byte 0xFF in UTF-8 sequence at byte 2.");
+/// assert!(decoder.peek().is_none());
+/// assert!(decoder.next().is_none());
+/// ```
+pub struct PeekableDecoder<R: Iterator<Item=u8>> {
+	decoder: Decoder<R>,
+	offset: usize,
+	peeked: Option<Option<Result<char>>>
+}
+
+impl<R: Iterator<Item=u8>> PeekableDecoder<R> {
+	pub(crate) fn new(decoder: Decoder<R>) -> PeekableDecoder<R> {
+		PeekableDecoder {
+			offset: decoder.offset(),
+			decoder,
+			peeked: None
+		}
+	}
+
+	/// Returns a reference to the next character (or error) without consuming it.
+	///
+	/// Returns `None` at the end of the input.
+	pub fn peek(&mut self) -> Option<&Result<char>> {
+		let decoder = &mut self.decoder;
+		self.peeked.get_or_insert_with(|| decoder.next()).as_ref()
+	}
+
+	/// Consumes and returns the next character if it is equal to `expected`.
+	///
+	/// Returns `None`, without consuming anything, if the next character is different, is an
+	/// error, or if the end of the input is reached.
+	pub fn next_if_eq(&mut self, expected: char) -> Option<Result<char>> {
+		match self.peek() {
+			Some(Ok(c)) if *c == expected => self.next(),
+			_ => None
+		}
+	}
+
+	/// Byte offset of the next character, peeked or not.
+	pub fn offset(&self) -> usize {
+		self.offset
+	}
+}
+
+impl<R: Iterator<Item=u8>> Iterator for PeekableDecoder<R> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		let c = match self.peeked.take() {
+			Some(c) => c,
+			None => self.decoder.next()
+		};
+
+		self.offset = self.decoder.offset();
+		c
+	}
+}
+
+impl<R: FusedIterator<Item=u8>> FusedIterator for PeekableDecoder<R> {}
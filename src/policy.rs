@@ -0,0 +1,389 @@
+use std::io::{Result, Error, ErrorKind};
+use std::collections::HashSet;
+use crate::tables;
+use crate::grapheme::Boundaries;
+
+/// UTF-8 decoder iterator rejecting C1 control characters.
+///
+/// Created by the [`Decoder::reject_c1_controls`](crate::Decoder::reject_c1_controls) method.
+/// C1 control characters (U+0080 to U+009F) are rarely legitimate in text, and are a common way
+/// to bypass naive filters.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let string: std::io::Result<String> = Decoder::new("café".bytes()).reject_c1_controls().collect();
+/// assert_eq!(string.unwrap(), "café");
+///
+/// // U+0085 NEXT LINE.
+/// let mut decoder = Decoder::new("a\u{85}".bytes()).reject_c1_controls();
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'a');
+/// let error = decoder.next().unwrap().unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+/// assert_eq!(error.to_string(), "C1 control character U+0085.");
+///
+/// // Overlong encoding of U+0085, rejected by the decoder itself.
+/// let bytes = [0xe0, 0x82, 0x85];
+/// let mut decoder = Decoder::new(bytes.iter().cloned()).reject_c1_controls();
+/// assert!(decoder.next().unwrap().is_err());
+/// ```
+///
+/// ## Errors
+/// Every C1 control character is replaced by an [`InvalidData`](std::io::ErrorKind::InvalidData)
+/// error naming its code point. Decoding errors are forwarded.
+pub struct RejectC1Controls<I: Iterator<Item=Result<char>>> {
+	chars: I
+}
+
+impl<I: Iterator<Item=Result<char>>> RejectC1Controls<I> {
+	pub(crate) fn new(chars: I) -> RejectC1Controls<I> {
+		RejectC1Controls {
+			chars
+		}
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for RejectC1Controls<I> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		match self.chars.next()? {
+			Ok(c) if ('\u{80}'..='\u{9f}').contains(&c) => {
+				Some(Err(Error::new(ErrorKind::InvalidData, format!("C1 control character U+{:04X}.", c as u32))))
+			},
+			c => Some(c)
+		}
+	}
+}
+
+/// UTF-8 decoder iterator rejecting noncharacters.
+///
+/// Created by the [`Decoder::strict`](crate::Decoder::strict) method.
+/// Noncharacters (U+FDD0 to U+FDEF, and the last two code points of each plane, such as U+FFFE
+/// and U+FFFF) are permanently reserved for internal use, and should not be interchanged.
+/// RFC 3629 allows them in UTF-8, but they are unexpected in untrusted input: U+FFFE, for
+/// instance, is a byte-swapped byte order mark.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let string: std::io::Result<String> = Decoder::new("a\u{fffd}b".bytes()).strict().collect();
+/// assert_eq!(string.unwrap(), "a\u{fffd}b");
+///
+/// let mut decoder = Decoder::new("a\u{fdd0}".bytes()).strict();
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'a');
+/// let error = decoder.next().unwrap().unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+/// assert_eq!(error.to_string(), "noncharacter U+FDD0.");
+///
+/// for c in &['\u{fdef}', '\u{fffe}', '\u{ffff}', '\u{1fffe}', '\u{10ffff}'] {
+///     assert!(Decoder::new(c.to_string().bytes()).strict().next().unwrap().is_err());
+/// }
+/// ```
+///
+/// ## Errors
+/// Every noncharacter is replaced by an [`InvalidData`](std::io::ErrorKind::InvalidData) error
+/// naming its code point. Decoding errors are forwarded.
+pub struct RejectNoncharacters<I: Iterator<Item=Result<char>>> {
+	chars: I
+}
+
+impl<I: Iterator<Item=Result<char>>> RejectNoncharacters<I> {
+	pub(crate) fn new(chars: I) -> RejectNoncharacters<I> {
+		RejectNoncharacters {
+			chars
+		}
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for RejectNoncharacters<I> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		match self.chars.next()? {
+			Ok(c) if ('\u{fdd0}'..='\u{fdef}').contains(&c) || c as u32 & 0xFFFE == 0xFFFE => {
+				Some(Err(Error::new(ErrorKind::InvalidData, format!("noncharacter U+{:04X}.", c as u32))))
+			},
+			c => Some(c)
+		}
+	}
+}
+
+/// UTF-8 decoder iterator rejecting control characters other than tabulations and new lines.
+///
+/// Created by the [`Decoder::text_field_safe`](crate::Decoder::text_field_safe) method.
+/// Every control character (general category `Cc`: C0 controls, DEL and C1 controls) is
+/// rejected, except `'\t'`, `'\n'` and `'\r'`. This is a common sanitization policy for text
+/// fields such as CSV cells or form inputs.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let string: std::io::Result<String> = Decoder::new("a\tb\r\nc".bytes()).text_field_safe().collect();
+/// assert_eq!(string.unwrap(), "a\tb\r\nc");
+///
+/// let mut decoder = Decoder::new("a\0".bytes()).text_field_safe();
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'a');
+/// let error = decoder.next().unwrap().unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+/// assert_eq!(error.to_string(), "control character U+0000.");
+///
+/// // LINE TABULATION.
+/// let string: std::io::Result<String> = Decoder::new("a\u{b}b".bytes()).text_field_safe().collect();
+/// assert_eq!(string.unwrap_err().to_string(), "control character U+000B.");
+///
+/// let string: std::io::Result<String> = Decoder::new("\u{7f}".bytes()).text_field_safe().collect();
+/// assert!(string.is_err());
+/// ```
+///
+/// ## Errors
+/// Every rejected control character is replaced by an
+/// [`InvalidData`](std::io::ErrorKind::InvalidData) error naming its code point. Decoding errors
+/// are forwarded.
+pub struct TextFieldSafe<I: Iterator<Item=Result<char>>> {
+	chars: I
+}
+
+impl<I: Iterator<Item=Result<char>>> TextFieldSafe<I> {
+	pub(crate) fn new(chars: I) -> TextFieldSafe<I> {
+		TextFieldSafe {
+			chars
+		}
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for TextFieldSafe<I> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		match self.chars.next()? {
+			Ok(c) if c.is_control() && !matches!(c, '\t' | '\n' | '\r') => {
+				Some(Err(Error::new(ErrorKind::InvalidData, format!("control character U+{:04X}.", c as u32))))
+			},
+			c => Some(c)
+		}
+	}
+}
+
+/// UTF-8 decoder iterator limiting the number of distinct characters.
+///
+/// Created by the [`Decoder::max_distinct_chars`](crate::Decoder::max_distinct_chars) method.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let string: std::io::Result<String> = Decoder::new("abbaé".bytes()).max_distinct_chars(3).collect();
+/// assert_eq!(string.unwrap(), "abbaé");
+///
+/// let mut decoder = Decoder::new("abcab".bytes()).max_distinct_chars(2);
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'a');
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'b');
+/// assert_eq!(decoder.next().unwrap().unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'a');
+/// ```
+///
+/// ## Errors
+/// Every occurrence of a character that would exceed the limit of distinct characters is
+/// replaced by an [`InvalidData`](std::io::ErrorKind::InvalidData) error.
+/// Decoding errors are forwarded.
+pub struct MaxDistinctChars<I: Iterator<Item=Result<char>>> {
+	chars: I,
+	max: usize,
+	seen: HashSet<char>
+}
+
+impl<I: Iterator<Item=Result<char>>> MaxDistinctChars<I> {
+	pub(crate) fn new(chars: I, max: usize) -> MaxDistinctChars<I> {
+		MaxDistinctChars {
+			chars,
+			max,
+			seen: HashSet::new()
+		}
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for MaxDistinctChars<I> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		match self.chars.next()? {
+			Ok(c) if !self.seen.contains(&c) => {
+				if self.seen.len() < self.max {
+					self.seen.insert(c);
+					Some(Ok(c))
+				} else {
+					Some(Err(Error::new(ErrorKind::InvalidData, format!("more than {} distinct characters.", self.max))))
+				}
+			},
+			c => Some(c)
+		}
+	}
+}
+
+/// UTF-8 decoder iterator limiting the number of code points of each grapheme cluster.
+///
+/// Created by the [`Decoder::max_cluster_size`](crate::Decoder::max_cluster_size) method.
+/// Extended grapheme clusters are delimited following the rules GB3 to GB13 of
+/// [UAX #29](https://www.unicode.org/reports/tr29/). Bounding the size of clusters bounds the
+/// rendering cost of each of them, defeating "Zalgo" text stacking hundreds of combining marks
+/// on a single base character.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// fn check(s: &str, k: usize) -> std::io::Result<String> {
+///     Decoder::new(s.bytes()).max_cluster_size(k).collect()
+/// }
+///
+/// // "e" followed by two combining marks, a Hangul syllable made of three jamos, CRLF and a
+/// // family emoji made of three pictographs joined by two ZWJ characters.
+/// let text = "e\u{323}\u{302}\u{1112}\u{1161}\u{11ab}\r\n\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}";
+/// assert_eq!(check(text, 5).unwrap(), text);
+/// assert!(check(text, 4).is_err());
+///
+/// // Oversized cluster of combining marks.
+/// let error = check("ok e\u{301}\u{302}\u{303}\u{304}", 3).unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+/// assert_eq!(error.to_string(), "grapheme cluster exceeds 3 code points.");
+///
+/// // Each flag is a cluster of two regional indicators.
+/// assert!(check("\u{1f1eb}\u{1f1f7}\u{1f1e9}\u{1f1ea}", 2).is_ok());
+/// ```
+///
+/// ## Errors
+/// Every code point of a grapheme cluster after the first `k` is replaced by an
+/// [`InvalidData`](std::io::ErrorKind::InvalidData) error.
+/// Decoding errors are forwarded, and end the current cluster.
+pub struct MaxClusterSize<I: Iterator<Item=Result<char>>> {
+	chars: I,
+	max: usize,
+	boundaries: Boundaries,
+	size: usize
+}
+
+impl<I: Iterator<Item=Result<char>>> MaxClusterSize<I> {
+	pub(crate) fn new(chars: I, max: usize) -> MaxClusterSize<I> {
+		MaxClusterSize {
+			chars,
+			max,
+			boundaries: Boundaries::new(),
+			size: 0
+		}
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for MaxClusterSize<I> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		match self.chars.next()? {
+			Ok(c) => {
+				if self.boundaries.is_boundary_before(c) {
+					self.size = 0
+				}
+
+				self.size += 1;
+				if self.size > self.max {
+					Some(Err(Error::new(ErrorKind::InvalidData, format!("grapheme cluster exceeds {} code points.", self.max))))
+				} else {
+					Some(Ok(c))
+				}
+			},
+			Err(e) => {
+				self.boundaries = Boundaries::new();
+				Some(Err(e))
+			}
+		}
+	}
+}
+
+/// UTF-8 decoder iterator inserting a marker character where a soft limit on the number of
+/// characters is crossed.
+///
+/// Created by the [`Decoder::soft_char_limit`](crate::Decoder::soft_char_limit) method.
+/// Unlike a hard limit, nothing is rejected or truncated: the marker is inserted once, after the
+/// `n`-th character, and decoding continues. The marker is only inserted if the limit is
+/// crossed, that is if there are more than `n` characters. Errors are not counted as
+/// characters.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// fn soft_limit(s: &str, n: usize) -> String {
+///     Decoder::new(s.bytes()).soft_char_limit(n, '…').collect::<std::io::Result<String>>().unwrap()
+/// }
+///
+/// assert_eq!(soft_limit("héllo wörld", 5), "héllo… wörld");
+/// assert_eq!(soft_limit("héllo", 5), "héllo");
+/// assert_eq!(soft_limit("héllo", 0), "…héllo");
+/// assert_eq!(soft_limit("", 0), "");
+/// ```
+///
+/// ## Errors
+/// Decoding errors are forwarded.
+pub struct SoftCharLimit<I: Iterator<Item=Result<char>>> {
+	chars: I,
+	remaining: Option<usize>,
+	marker: char,
+	pending: Option<char>
+}
+
+impl<I: Iterator<Item=Result<char>>> SoftCharLimit<I> {
+	pub(crate) fn new(chars: I, n: usize, marker: char) -> SoftCharLimit<I> {
+		SoftCharLimit {
+			chars,
+			remaining: Some(n),
+			marker,
+			pending: None
+		}
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for SoftCharLimit<I> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		if let Some(c) = self.pending.take() {
+			return Some(Ok(c))
+		}
+
+		match self.chars.next()? {
+			Ok(c) => match self.remaining {
+				Some(0) => {
+					self.remaining = None;
+					self.pending = Some(c);
+					Some(Ok(self.marker))
+				},
+				Some(n) => {
+					self.remaining = Some(n - 1);
+					Some(Ok(c))
+				},
+				None => Some(Ok(c))
+			},
+			Err(e) => Some(Err(e))
+		}
+	}
+}
+
+/// Decodes the given characters, rejecting them if the fraction of combining marks exceeds
+/// `ratio`.
+pub(crate) fn max_combining_ratio<I: Iterator<Item=Result<char>>>(chars: I, ratio: f32) -> Result<String> {
+	let mut string = String::new();
+	let mut count = 0usize;
+	let mut combining = 0usize;
+	for c in chars {
+		let c = c?;
+		count += 1;
+		if tables::contains(tables::COMBINING_MARK, c) {
+			combining += 1
+		}
+
+		string.push(c)
+	}
+
+	if count > 0 && combining as f64 / count as f64 > ratio as f64 {
+		return Err(Error::new(ErrorKind::InvalidData, format!("combining marks ratio exceeds {}.", ratio)))
+	}
+
+	Ok(string)
+}
@@ -0,0 +1,54 @@
+use std::io::Result;
+use crate::tables;
+
+/// Checks if the given character is printable, that is neither a control character
+/// (general category `Cc`) nor a format character (general category `Cf`).
+pub fn is_printable(c: char) -> bool {
+	!c.is_control() && !tables::contains(tables::FORMAT, c)
+}
+
+/// Checks if any of the given characters requires Unicode-aware rendering.
+pub(crate) fn needs_complex_rendering<I: Iterator<Item=Result<char>>>(chars: I) -> Result<bool> {
+	for c in chars {
+		if tables::contains(tables::COMPLEX_RENDERING, c?) {
+			return Ok(true)
+		}
+	}
+
+	Ok(false)
+}
+
+/// UTF-8 decoder iterator yielding each character with whether it is printable.
+///
+/// Created by the [`Decoder::with_printable_flag`](crate::Decoder::with_printable_flag) method.
+/// See [`is_printable`](crate::is_printable) for the definition of a printable character.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let mut decoder = Decoder::new("a\u{7}\u{200b}".bytes()).with_printable_flag();
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('a', true));
+/// // BEL, a control character.
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('\u{7}', false));
+/// // ZERO WIDTH SPACE, a format character.
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('\u{200b}', false));
+/// ```
+pub struct WithPrintableFlag<I: Iterator<Item=Result<char>>> {
+	chars: I
+}
+
+impl<I: Iterator<Item=Result<char>>> WithPrintableFlag<I> {
+	pub(crate) fn new(chars: I) -> WithPrintableFlag<I> {
+		WithPrintableFlag {
+			chars
+		}
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for WithPrintableFlag<I> {
+	type Item = Result<(char, bool)>;
+
+	fn next(&mut self) -> Option<Result<(char, bool)>> {
+		self.chars.next().map(|c| c.map(|c| (c, is_printable(c))))
+	}
+}
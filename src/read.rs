@@ -0,0 +1,468 @@
+use std::io::{Read, Result, ErrorKind};
+use crate::{decode_unsafe, OffsetError};
+use crate::lead::is_lead;
+
+/// Default capacity of the [`ReadDecoder`] buffer.
+const DEFAULT_CAPACITY: usize = 8 * 1024;
+
+/// UTF-8 decoder iterator over an [`io::Read`](std::io::Read) source.
+///
+/// Transform the given reader into a [`io::Result<char>`](std::io::Result) iterator, reading the
+/// bytes through an internal buffer. Contrarily to the [`UnsafeDecoder`](crate::UnsafeDecoder)
+/// wrapped around [`Read::bytes`](std::io::Read::bytes), there is no per-byte `Result` to handle.
+///
+/// The buffer is refilled in whatever increments the reader provides: a call to
+/// [`read`](std::io::Read::read) is never expected to fill the buffer, and a character may span
+/// any number of refills. The buffer capacity can be chosen with
+/// [`with_capacity`](ReadDecoder::with_capacity), down to a single byte.
+///
+/// ## Example
+/// ```rust
+/// # use std::io::Read;
+/// # use utf8_decode::ReadDecoder;
+/// /// Reader providing at most 2 bytes per call.
+/// struct Trickle<'a>(&'a [u8]);
+///
+/// impl<'a> Read for Trickle<'a> {
+///     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+///         let n = self.0.len().min(buf.len()).min(2);
+///         buf[..n].copy_from_slice(&self.0[..n]);
+///         self.0 = &self.0[n..];
+///         Ok(n)
+///     }
+/// }
+///
+/// let decoder = ReadDecoder::with_capacity(4, Trickle("aé😀".as_bytes()));
+/// let string: std::io::Result<String> = decoder.collect();
+/// assert_eq!(string.unwrap(), "aé😀");
+///
+/// // The reader reaches the end of the stream in the middle of "😀".
+/// let mut decoder = ReadDecoder::with_capacity(4, Trickle(&"é😀".as_bytes()[..4]));
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'é');
+/// assert_eq!(decoder.next().unwrap().unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+/// ```
+///
+/// ## Errors
+/// A call to [`next`](Iterator::next) returns an [`InvalidData`](std::io::ErrorKind::InvalidData)
+/// error if the reader does not output a valid UTF-8 sequence, or an
+/// [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof) if the reader returns `0` before the end
+/// of a valid character. The error wraps an [`OffsetError`](crate::OffsetError) giving the offset
+/// of the faulty sequence in the stream.
+/// Errors returned by the reader are forwarded, except
+/// [`Interrupted`](std::io::ErrorKind::Interrupted) errors, after which the read is retried.
+///
+/// Like the [`UnsafeDecoder`](crate::UnsafeDecoder), decoding resumes after an error at the next
+/// byte that may start a sequence, which may be the byte that ended the invalid sequence.
+///
+/// ```rust
+/// # use utf8_decode::{ReadDecoder, OffsetError};
+/// let chars: Vec<_> = ReadDecoder::new(&b"\xe2\x82a\x80\x80b"[..]).map(Result::ok).collect();
+/// assert_eq!(chars, [None, Some('a'), None, Some('b')]);
+///
+/// let mut decoder = ReadDecoder::with_capacity(2, &b"ab\xc3\xa9\xff"[..]);
+/// let error = decoder.nth(3).unwrap().unwrap_err();
+/// assert_eq!(error.to_string(), "invalid lead byte 0xFF in UTF-8 sequence at byte 4.");
+/// assert_eq!(error.get_ref().unwrap().downcast_ref::<OffsetError>().unwrap().offset(), 4);
+/// ```
+pub struct ReadDecoder<R: Read> {
+	reader: R,
+	buffer: Box<[u8]>,
+	start: usize,
+	end: usize,
+	pending: Option<u8>,
+	resync: bool,
+	offset: usize
+}
+
+impl<R: Read> ReadDecoder<R> {
+	/// Creates a new `ReadDecoder` iterator from the given reader, with a buffer of 8 KiB.
+	pub fn new(reader: R) -> ReadDecoder<R> {
+		ReadDecoder::with_capacity(DEFAULT_CAPACITY, reader)
+	}
+
+	/// Creates a new `ReadDecoder` iterator from the given reader, with a buffer of `capacity`
+	/// bytes.
+	///
+	/// ## Panics
+	/// Panics if `capacity` is `0`.
+	pub fn with_capacity(capacity: usize, reader: R) -> ReadDecoder<R> {
+		assert!(capacity > 0, "buffer capacity must be non-zero.");
+		ReadDecoder {
+			reader,
+			buffer: vec![0; capacity].into_boxed_slice(),
+			start: 0,
+			end: 0,
+			pending: None,
+			resync: false,
+			offset: 0
+		}
+	}
+
+	/// Yields the lines of the input.
+	///
+	/// See [`ReadLines`] for more details.
+	pub fn lines(self) -> ReadLines<R> {
+		ReadLines {
+			decoder: self,
+			done: false
+		}
+	}
+
+	/// Yields each character along with the byte offset at which it starts in the stream.
+	///
+	/// See [`Positioned`] for more details.
+	pub fn positioned(self) -> Positioned<R> {
+		Positioned {
+			decoder: self
+		}
+	}
+
+	/// Read the next byte, kept after an invalid sequence or out of the buffer, refilling it if
+	/// necessary.
+	/// Returns `None` if the reader returns `0`.
+	fn next_byte(&mut self) -> Option<Result<u8>> {
+		if let Some(b) = self.pending.take() {
+			self.offset += 1;
+			return Some(Ok(b))
+		}
+
+		while self.start == self.end {
+			match self.reader.read(&mut self.buffer) {
+				Ok(0) => return None,
+				Ok(n) => {
+					self.start = 0;
+					self.end = n
+				},
+				Err(e) if e.kind() == ErrorKind::Interrupted => (),
+				Err(e) => return Some(Err(e))
+			}
+		}
+
+		let b = self.buffer[self.start];
+		self.start += 1;
+		self.offset += 1;
+		Some(Ok(b))
+	}
+
+	/// Skips the bytes following the last invalid sequence, if any, up to the next byte that may
+	/// start a sequence, kept to be decoded next.
+	/// Returns `None` if the reader returns `0` first.
+	fn skip_invalid(&mut self) -> Option<Result<()>> {
+		if self.resync {
+			loop {
+				match self.next_byte()? {
+					Ok(b) if is_lead(b) => {
+						self.pending = Some(b);
+						self.offset -= 1;
+						break
+					},
+					Ok(_) => (),
+					Err(e) => return Some(Err(e))
+				}
+			}
+
+			self.resync = false
+		}
+
+		Some(Ok(()))
+	}
+}
+
+impl<R: Read> Iterator for ReadDecoder<R> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		if let Err(e) = self.skip_invalid()? {
+			return Some(Err(e))
+		}
+
+		let offset = self.offset;
+		let mut bytes = Bytes {
+			decoder: self,
+			last: 0,
+			error: false
+		};
+
+		let c = decode_unsafe(&mut bytes)?;
+		let (last, source_error) = (bytes.last, bytes.error);
+		match c {
+			Err(e) if !source_error => {
+				if self.offset - offset > 1 && last & 0xC0 != 0x80 {
+					// The byte ending the invalid sequence may start a new one.
+					self.pending = Some(last);
+					self.offset -= 1
+				}
+
+				self.resync = true;
+				Some(Err(OffsetError::new(offset, e).into()))
+			},
+			c => Some(c)
+		}
+	}
+}
+
+/// Byte iterator over the buffer of a [`ReadDecoder`], recording the last byte and if the reader
+/// returned an error.
+struct Bytes<'a, R: Read> {
+	decoder: &'a mut ReadDecoder<R>,
+	last: u8,
+	error: bool
+}
+
+impl<'a, R: Read> Iterator for Bytes<'a, R> {
+	type Item = Result<u8>;
+
+	fn next(&mut self) -> Option<Result<u8>> {
+		match self.decoder.next_byte()? {
+			Ok(b) => {
+				self.last = b;
+				Some(Ok(b))
+			},
+			Err(e) => {
+				self.error = true;
+				Some(Err(e))
+			}
+		}
+	}
+}
+
+/// Iterator over the lines of a [`ReadDecoder`].
+///
+/// Created by the [`ReadDecoder::lines`] method.
+/// Lines are split on `'\n'`, which is not included in the yielded line, nor is the `'\r'`
+/// preceding it if any. A final empty line is not yielded, as with
+/// [`BufRead::lines`](std::io::BufRead::lines). Contrarily to `BufRead::lines`, characters are
+/// decoded with the crate's rules while the buffer is refilled, hence a character may span any
+/// number of refills.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::ReadDecoder;
+/// let mut lines = ReadDecoder::new(&b"h\xc3\xa9\xe2\x82\xac\r\n\xff!\n\xf0\x9f\x98\x80\n"[..]).lines();
+/// assert_eq!(lines.next().unwrap().unwrap(), "hé€");
+/// assert_eq!(lines.next().unwrap().unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+/// assert_eq!(lines.next().unwrap().unwrap(), "😀");
+/// assert!(lines.next().is_none());
+/// ```
+///
+/// ## Errors
+/// A line containing invalid UTF-8 data is replaced by the first decoding error it contains,
+/// and decoding resumes at the next line. Errors returned by the reader are forwarded and end
+/// the iteration.
+///
+/// ```rust
+/// # use utf8_decode::ReadDecoder;
+/// // The `'\n'` ending the invalid sequence `0xC3` still ends the line.
+/// let lines: Vec<_> = ReadDecoder::new(&b"a\xc3\nb\nc\n"[..]).lines().map(Result::ok).collect();
+/// assert_eq!(lines, [None, Some("b".to_string()), Some("c".to_string())]);
+/// ```
+pub struct ReadLines<R: Read> {
+	decoder: ReadDecoder<R>,
+	done: bool
+}
+
+impl<R: Read> Iterator for ReadLines<R> {
+	type Item = Result<String>;
+
+	fn next(&mut self) -> Option<Result<String>> {
+		if self.done {
+			return None
+		}
+
+		let mut line = String::new();
+		let mut error = None;
+		let mut empty = true;
+		loop {
+			match self.decoder.next() {
+				None => {
+					self.done = true;
+					if empty {
+						return None
+					}
+
+					break
+				},
+				Some(Ok('\n')) => break,
+				Some(Ok(c)) => line.push(c),
+				Some(Err(e)) => match e.kind() {
+					ErrorKind::InvalidData | ErrorKind::UnexpectedEof => {
+						if error.is_none() {
+							error = Some(e)
+						}
+					},
+					_ => {
+						self.done = true;
+						return Some(Err(e))
+					}
+				}
+			}
+
+			empty = false
+		}
+
+		match error {
+			Some(e) => Some(Err(e)),
+			None => {
+				if line.ends_with('\r') {
+					line.pop();
+				}
+
+				Some(Ok(line))
+			}
+		}
+	}
+}
+
+/// UTF-8 decoder iterator over an [`io::Read`](std::io::Read) source yielding each character
+/// with its byte offset.
+///
+/// Created by the [`ReadDecoder::positioned`] method.
+/// The offset is the position in the whole stream of the first byte of the character, counted
+/// across buffer refills.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::ReadDecoder;
+/// let decoder = ReadDecoder::new("aé€😀b".as_bytes()).positioned();
+/// let positions: Vec<(char, usize)> = decoder.map(|r| r.unwrap()).collect();
+/// assert_eq!(positions, [('a', 0), ('é', 1), ('€', 3), ('😀', 6), ('b', 10)]);
+/// ```
+///
+/// ## Errors
+/// Errors are the same as the [`ReadDecoder`] errors. The bytes skipped after an error are
+/// counted in the offsets.
+///
+/// ```rust
+/// # use utf8_decode::ReadDecoder;
+/// let positions: Vec<_> = ReadDecoder::new(&b"\xe2\x82a\x80\x80b"[..]).positioned().map(Result::ok).collect();
+/// assert_eq!(positions, [None, Some(('a', 2)), None, Some(('b', 5))]);
+/// ```
+pub struct Positioned<R: Read> {
+	decoder: ReadDecoder<R>
+}
+
+impl<R: Read> Iterator for Positioned<R> {
+	type Item = Result<(char, usize)>;
+
+	fn next(&mut self) -> Option<Result<(char, usize)>> {
+		if let Err(e) = self.decoder.skip_invalid()? {
+			return Some(Err(e))
+		}
+
+		let offset = self.decoder.offset;
+		self.decoder.next().map(|c| c.map(|c| (c, offset)))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::io::{Read, Result, Error, ErrorKind};
+	use super::ReadDecoder;
+
+	/// Reader providing at most `max` bytes per call, interrupted before each read if
+	/// `interrupt` is set.
+	struct Chunked<'a> {
+		bytes: &'a [u8],
+		max: usize,
+		interrupt: bool,
+		interrupted: bool
+	}
+
+	impl<'a> Read for Chunked<'a> {
+		fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+			if self.interrupt {
+				self.interrupted = !self.interrupted;
+				if self.interrupted {
+					return Err(Error::new(ErrorKind::Interrupted, "interrupted"))
+				}
+			}
+
+			let n = self.bytes.len().min(buf.len()).min(self.max);
+			buf[..n].copy_from_slice(&self.bytes[..n]);
+			self.bytes = &self.bytes[n..];
+			Ok(n)
+		}
+	}
+
+	/// Reader returning an error once its bytes are consumed.
+	struct Failing<'a>(&'a [u8]);
+
+	impl<'a> Read for Failing<'a> {
+		fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+			if self.0.is_empty() {
+				return Err(Error::new(ErrorKind::BrokenPipe, "broken pipe"))
+			}
+
+			self.0.read(buf)
+		}
+	}
+
+	/// Runs `f` on decoders over `bytes` for every small capacity and read size, with and
+	/// without interruptions.
+	fn for_each_decoder<F: FnMut(ReadDecoder<Chunked>)>(bytes: &[u8], mut f: F) {
+		for capacity in 1..=5 {
+			for max in 1..=5 {
+				for &interrupt in &[false, true] {
+					f(ReadDecoder::with_capacity(capacity, Chunked { bytes, max, interrupt, interrupted: false }))
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn decode_across_refills() {
+		for_each_decoder("aé€😀b".as_bytes(), |decoder| {
+			assert_eq!(decoder.collect::<Result<String>>().unwrap(), "aé€😀b")
+		})
+	}
+
+	#[test]
+	fn unexpected_eof_across_refills() {
+		for_each_decoder(&"é😀".as_bytes()[..4], |mut decoder| {
+			assert_eq!(decoder.next().unwrap().unwrap(), 'é');
+			assert_eq!(decoder.next().unwrap().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+			assert!(decoder.next().is_none())
+		})
+	}
+
+	#[test]
+	fn resync_across_refills() {
+		for_each_decoder(b"\xe2\x82a\x80\x80b\xc3\xc3\xa9", |decoder| {
+			let chars: Vec<_> = decoder.map(Result::ok).collect();
+			assert_eq!(chars, [None, Some('a'), None, Some('b'), None, Some('é')])
+		})
+	}
+
+	#[test]
+	fn lines_across_refills() {
+		for_each_decoder(b"h\xc3\xa9\xe2\x82\xac\r\n\xff!\n\xf0\x9f\x98\x80\na\xc3\nb", |decoder| {
+			let lines: Vec<_> = decoder.lines().map(Result::ok).collect();
+			assert_eq!(lines, [Some("hé€".to_string()), None, Some("😀".to_string()), None, Some("b".to_string())])
+		})
+	}
+
+	#[test]
+	fn positioned_across_refills() {
+		for_each_decoder("aé€😀b\u{80}".as_bytes(), |decoder| {
+			let positions: Vec<_> = decoder.positioned().map(|r| r.unwrap()).collect();
+			assert_eq!(positions, [('a', 0), ('é', 1), ('€', 3), ('😀', 6), ('b', 10), ('\u{80}', 11)])
+		});
+
+		for_each_decoder(b"\xe2\x82a\x80\x80b", |decoder| {
+			let positions: Vec<_> = decoder.positioned().map(Result::ok).collect();
+			assert_eq!(positions, [None, Some(('a', 2)), None, Some(('b', 5))])
+		})
+	}
+
+	#[test]
+	fn reader_error() {
+		let mut decoder = ReadDecoder::new(Failing(b"a\xc3"));
+		assert_eq!(decoder.next().unwrap().unwrap(), 'a');
+		assert_eq!(decoder.next().unwrap().unwrap_err().kind(), ErrorKind::BrokenPipe);
+
+		let mut lines = ReadDecoder::new(Failing(b"a\nb")).lines();
+		assert_eq!(lines.next().unwrap().unwrap(), "a");
+		assert_eq!(lines.next().unwrap().unwrap_err().kind(), ErrorKind::BrokenPipe);
+		assert!(lines.next().is_none())
+	}
+}
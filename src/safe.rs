@@ -1,5 +1,69 @@
 use std::io::{Result, Error, ErrorKind};
 use std::convert::TryFrom;
+use std::iter::FusedIterator;
+use std::ops::ControlFlow;
+use std::fmt;
+use crate::lead::{LEAD_BYTES, CONTINUATION, INVALID_LEAD, MIN_CODE_POINTS, skip_invalid};
+use crate::{
+	escape,
+	lines,
+	lossy,
+	policy,
+	printable,
+	MaxLineBytes,
+	NormalizeIndent,
+	NormalizeAllLineBreaks,
+	OnLineEnd,
+	Events,
+	AcceptOverlong,
+	PeekableDecoder,
+	WithSourceBytes,
+	WithLengths,
+	FrameAware,
+	SkipBom,
+	Lossy,
+	LossyCoalesced,
+	RecoveryStrategy,
+	LossyPreserveWidth,
+	Wtf8Lossy,
+	LossyRecoverOverlong,
+	ErrorSink,
+	WithErrorSink,
+	FailOnRepeatedError,
+	RejectC1Controls,
+	RejectNoncharacters,
+	TextFieldSafe,
+	MaxDistinctChars,
+	MaxClusterSize,
+	SoftCharLimit,
+	Batched,
+	RunLength,
+	WithRollingHash,
+	WithByteChecksum,
+	BracketDepth,
+	WithWordStartFlag,
+	WithStats,
+	Sentences,
+	WithPrintableFlag,
+	CaseFold,
+	WithEmojiFlag,
+	LineIndex,
+	OffsetError,
+	DecodeError,
+	TypedDecoder
+};
+#[cfg(feature = "width")]
+use crate::WithEaw;
+#[cfg(feature = "age")]
+use crate::{UnicodeVersion, WithAssignment};
+#[cfg(feature = "names")]
+use crate::WithName;
+#[cfg(feature = "ident")]
+use crate::WithIdentFlags;
+#[cfg(feature = "numeric")]
+use crate::WithNumericValue;
+#[cfg(feature = "normalization")]
+use crate::Nfd;
 
 /// Read the next byte of the UTF-8 character out of the given byte iterator.
 /// The byte is returned as a `u32` for later shifting.
@@ -14,7 +78,28 @@ fn next_byte<I: Iterator<Item=u8>>(iter: &mut I) -> Result<u32> {
                 Err(Error::new(ErrorKind::InvalidData, "invalid UTF-8 sequence."))
             }
         },
-        None => Err(Error::new(ErrorKind::UnexpectedEof, "unexpected end of UTF-8 sequence."))
+        None => Err(Error::new(ErrorKind::UnexpectedEof, DecodeError::UnexpectedEof { offset: 0 }.message()))
+    }
+}
+
+/// Checks that the given decoded codepoint is at least `min`, the smallest codepoint encoded
+/// with the same number of bytes.
+/// Returns an `InvalidData` error if it is not, since the sequence is then an overlong encoding.
+fn check_overlong(c: u32, min: u32) -> Result<u32> {
+    if c >= min {
+        Ok(c)
+    } else {
+        Err(Error::new(ErrorKind::InvalidData, "overlong UTF-8 sequence."))
+    }
+}
+
+/// Checks that the given decoded codepoint is not a UTF-16 surrogate.
+/// Returns an `InvalidData` error naming the surrogate otherwise, as found in CESU-8 data.
+fn check_surrogate(c: u32) -> Result<u32> {
+    if (0xD800..=0xDFFF).contains(&c) {
+        Err(Error::new(ErrorKind::InvalidData, DecodeError::Surrogate { offset: 0, code_point: c }.message()))
+    } else {
+        Ok(c)
     }
 }
 
@@ -24,22 +109,22 @@ fn next_byte<I: Iterator<Item=u8>>(iter: &mut I) -> Result<u32> {
 /// Returns an `UnexpectedEof` error if the input iterator returns `None` before the end of the
 /// UTF-8 character.
 fn raw_decode_from<I: Iterator<Item=u8>>(a: u32, iter: &mut I) -> Result<u32> {
-    if a & 0x80 == 0x00 {
-        Ok(a)
-    } else if a & 0xE0 == 0xC0 {
-        let b = next_byte(iter)?;
-        Ok((a & 0x1F) << 6 | b)
-    } else if a & 0xF0 == 0xE0 {
-        let b = next_byte(iter)?;
-        let c = next_byte(iter)?;
-        Ok((a & 0x0F) << 12 | b << 6 | c)
-    } else if a & 0xF8 == 0xF0 {
-        let b = next_byte(iter)?;
-        let c = next_byte(iter)?;
-        let d = next_byte(iter)?;
-        Ok((a & 0x07) << 18 | b << 12 | c << 6 | d)
-    } else {
-        Err(Error::new(ErrorKind::InvalidData, "invalid UTF-8 sequence."))
+    match LEAD_BYTES[a as usize] {
+        0 => Ok(a),
+        CONTINUATION => Err(Error::new(ErrorKind::InvalidData, "invalid UTF-8 sequence.")),
+        INVALID_LEAD => {
+            // Lead bytes of overlong two-byte sequences, and of sequences beyond U+10FFFF (or of
+            // no sequence at all). Rejected before reading any continuation byte.
+            Err(Error::new(ErrorKind::InvalidData, DecodeError::InvalidLeadByte { offset: 0, byte: a as u8 }.message()))
+        },
+        len => {
+            let mut c = a & (0x7F >> (len + 1));
+            for _ in 0..len {
+                c = c << 6 | next_byte(iter)?;
+            }
+
+            check_overlong(c, MIN_CODE_POINTS[len as usize])
+        }
     }
 }
 
@@ -48,7 +133,7 @@ fn raw_decode_from<I: Iterator<Item=u8>>(a: u32, iter: &mut I) -> Result<u32> {
 /// Returns an `UnexpectedEof` error if the input iterator returns `None` before the end of the
 /// UTF-8 character.
 fn decode_from<I: Iterator<Item=u8>>(a: u32, iter: &mut I) -> Result<char> {
-    match char::try_from(raw_decode_from(a, iter)?) {
+    match char::try_from(check_surrogate(raw_decode_from(a, iter)?)?) {
         Ok(c) => Ok(c),
         Err(_) => Err(Error::new(ErrorKind::InvalidData, "invalid UTF-8 sequence."))
     }
@@ -61,10 +146,106 @@ fn decode_from<I: Iterator<Item=u8>>(a: u32, iter: &mut I) -> Result<char> {
 /// output a valid UTF-8 sequence.
 /// Returns an [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof) error if the input iterator
 /// returns `None` before the end of an UTF-8 character.
+/// Overlong encodings and surrogate code points are invalid.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::decode;
+/// // Overlong encodings of U+0000, U+002F, U+07FF and U+FFFF.
+/// let overlong: [&[u8]; 5] = [
+///     &[0xe0, 0x80, 0x80],
+///     &[0xe0, 0x80, 0xaf],
+///     &[0xe0, 0x9f, 0xbf],
+///     &[0xf0, 0x80, 0x80, 0xaf],
+///     &[0xf0, 0x8f, 0xbf, 0xbf]
+/// ];
+///
+/// for bytes in &overlong {
+///     let error = decode(&mut bytes.iter().cloned()).unwrap().unwrap_err();
+///     assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+///     assert_eq!(error.to_string(), "overlong UTF-8 sequence.");
+/// }
+///
+/// // Lead bytes that never appear in UTF-8 are rejected without reading the next bytes.
+/// for &a in &[0xc0, 0xc1, 0xf5, 0xf6, 0xf7, 0xf8, 0xfb, 0xfc, 0xfe, 0xff] {
+///     let source = [a, 0x80, 0x41];
+///     let mut bytes = source.iter().cloned();
+///     let error = decode(&mut bytes).unwrap().unwrap_err();
+///     assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+///     assert_eq!(error.to_string(), format!("invalid lead byte 0x{:02X} in UTF-8 sequence.", a));
+///     assert_eq!(bytes.len(), 2);
+/// }
+///
+/// // Smallest two-byte and largest four-byte lead bytes of valid sequences.
+/// assert!(decode(&mut [0xc2, 0x80].iter().cloned()).unwrap().is_ok());
+/// assert!(decode(&mut [0xf4, 0x8f, 0xbf, 0xbf].iter().cloned()).unwrap().is_ok());
+///
+/// // Shortest encodings of U+0080, U+0800 and U+10000.
+/// assert_eq!(decode(&mut [0xc2, 0x80].iter().cloned()).unwrap().unwrap(), '\u{80}');
+/// assert_eq!(decode(&mut [0xe0, 0xa0, 0x80].iter().cloned()).unwrap().unwrap(), '\u{800}');
+/// assert_eq!(decode(&mut [0xf0, 0x90, 0x80, 0x80].iter().cloned()).unwrap().unwrap(), '\u{10000}');
+///
+/// // High and low surrogates.
+/// let error = decode(&mut [0xed, 0xa0, 0x80].iter().cloned()).unwrap().unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+/// assert_eq!(error.to_string(), "surrogate code point U+D800 in UTF-8 sequence.");
+/// let error = decode(&mut [0xed, 0xbf, 0xbf].iter().cloned()).unwrap().unwrap_err();
+/// assert_eq!(error.to_string(), "surrogate code point U+DFFF in UTF-8 sequence.");
+/// ```
 pub fn decode<I: Iterator<Item=u8>>(iter: &mut I) -> Option<Result<char>> {
-	match iter.next() {
-		Some(a) => Some(decode_from(a as u32, iter)),
-		None => None
+	iter.next().map(|a| decode_from(a as u32, iter))
+}
+
+/// Checks the next Unicode character given its first byte, without building it.
+/// Returns the same errors as `decode_from`.
+fn validate_from<I: Iterator<Item=u8>>(a: u32, iter: &mut I) -> Result<()> {
+    if check_surrogate(raw_decode_from(a, iter)?)? <= 0x10FFFF {
+        Ok(())
+    } else {
+        Err(Error::new(ErrorKind::InvalidData, "invalid UTF-8 sequence."))
+    }
+}
+
+/// Checks that the given [`u8`](u8) iterator outputs a valid UTF-8 sequence.
+///
+/// This runs the decoder over the whole input, stopping at the first error, but without
+/// building the decoded characters. It is faster than collecting a [`Decoder`] output when only
+/// the validity of the input matters. Use [`validate_unsafe`](crate::validate_unsafe) for a
+/// [`Result<u8>`](std::io::Result) iterator.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::validate;
+/// assert!(validate("aé€😀".bytes()).is_ok());
+/// assert!(validate(std::iter::empty()).is_ok());
+///
+/// let error = validate(b"ab\xc3\xa9\xffc".iter().cloned()).unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+/// assert_eq!(error.to_string(), "invalid lead byte 0xFF in UTF-8 sequence at byte 4.");
+///
+/// let error = validate(b"a\xed\xa0\x80".iter().cloned()).unwrap_err();
+/// assert_eq!(error.to_string(), "surrogate code point U+D800 in UTF-8 sequence at byte 1.");
+/// assert!(validate(b"\xf4\x90\x80\x80".iter().cloned()).is_err());
+///
+/// let error = validate(b"a\xe2\x82".iter().cloned()).unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+/// ```
+///
+/// ## Errors
+/// Returns the error the [`Decoder`] would return on the first invalid sequence.
+pub fn validate<I: Iterator<Item=u8>>(mut iter: I) -> Result<()> {
+	let mut offset = 0;
+	loop {
+		let start = offset;
+		let mut bytes = Counted {
+			bytes: &mut iter,
+			count: &mut offset
+		};
+
+		match bytes.next() {
+			Some(a) => validate_from(a as u32, &mut bytes).map_err(|e| OffsetError::new(start, e))?,
+			None => return Ok(())
+		}
 	}
 }
 
@@ -78,6 +259,8 @@ pub fn decode<I: Iterator<Item=u8>>(iter: &mut I) -> Option<Result<char>> {
 /// ## Example
 /// The `Decoder` iterator can be used, for instance, to decode `u8` slices.
 /// ```rust
+/// # use utf8_decode::Decoder;
+/// # fn main() -> std::io::Result<()> {
 /// let bytes = [72, 101, 108, 108, 111, 32, 87, 111, 114, 108, 100, 33];
 ///
 /// let decoder = Decoder::new(bytes.iter().cloned());
@@ -88,30 +271,1139 @@ pub fn decode<I: Iterator<Item=u8>>(iter: &mut I) -> Option<Result<char>> {
 /// }
 ///
 /// println!("{}", string);
+/// # Ok(())
+/// # }
 /// ```
 ///
 /// ## Errors
 /// A call to [`next`](Iterator::next) returns an [`InvalidData`](std::io::ErrorKind::InvalidData)
 /// error if the input iterator does not output a valid UTF-8 sequence, or an
 /// [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof) if the stream ends before the end of a
-/// valid character.
+/// valid character. The error wraps an [`OffsetError`](crate::OffsetError) giving the offset of
+/// the faulty sequence.
+///
+/// Decoding can go on after an error. The invalid sequence is consumed up to the byte that made
+/// it invalid, then the decoder skips forward to the next byte that may start a sequence (an
+/// ASCII byte, or a valid lead byte), which is kept and decoded by the next call to
+/// [`next`](Iterator::next) as the start of a new sequence. This may be the byte that made the
+/// sequence invalid.
+///
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let mut decoder = Decoder::new([0xff, 0x41].iter().cloned());
+/// assert!(decoder.next().unwrap().is_err());
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'A');
+/// assert!(decoder.next().is_none());
+///
+/// // The stray continuation and invalid lead bytes following an error are skipped.
+/// let mut decoder = Decoder::new([0x80, 0xbf, 0xff, 0xc3, 0xa9].iter().cloned());
+/// assert!(decoder.next().unwrap().is_err());
+/// assert_eq!(decoder.byte_count(), 3);
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'é');
+///
+/// // `0x41` ends the invalid sequence `0xE2 0x82`, and is decoded next.
+/// let mut decoder = Decoder::new([0xe2, 0x82, 0x41, 0x42].iter().cloned());
+/// assert!(decoder.next().unwrap().is_err());
+/// assert_eq!(decoder.byte_count(), 2);
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'A');
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'B');
+///
+/// // `0xC3` ends the invalid sequence `0xE2`, and starts the valid sequence of "é".
+/// let mut decoder = Decoder::new([0xe2, 0xc3, 0xa9].iter().cloned());
+/// assert!(decoder.next().unwrap().is_err());
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'é');
+/// assert!(decoder.next().is_none());
+/// ```
 pub struct Decoder<R: Iterator<Item=u8>> {
-	bytes: R
+	bytes: R,
+	pending: Option<u8>,
+	offset: usize,
+	chars: usize
 }
 
 impl<R: Iterator<Item=u8>> Decoder<R> {
     /// Creates a new `Decoder` iterator from the given `u8` source iterator.
 	pub fn new(source: R) -> Decoder<R> {
 		Decoder {
-			bytes: source
+			bytes: source,
+			pending: None,
+			offset: 0,
+			chars: 0
 		}
 	}
+
+	/// Creates a new lossy decoder iterator from the given `u8` source iterator, replacing each
+	/// maximal invalid subsequence with one replacement character.
+	///
+	/// This is the same as `Decoder::new(source).lossy()`. See [`Lossy`](crate::Lossy) for more
+	/// details.
+	///
+	/// ## Example
+	/// ```rust
+	/// # use utf8_decode::Decoder;
+	/// let bytes = [0x61, 0xff, 0xe2, 0x82, 0x62, 0xf0, 0x9f];
+	/// let string: std::io::Result<String> = Decoder::new_lossy(bytes.iter().cloned()).collect();
+	/// assert_eq!(string.unwrap(), "a\u{fffd}\u{fffd}b\u{fffd}");
+	/// ```
+	pub fn new_lossy(source: R) -> Lossy<R> {
+		Lossy::new(source, RecoveryStrategy::SkipMaximalSubpart)
+	}
+
+	/// Creates a new decoder iterator from the given `u8` source iterator, reporting errors with
+	/// the [`DecodeError`](crate::DecodeError) type instead of [`std::io::Error`].
+	///
+	/// See [`TypedDecoder`](crate::TypedDecoder) for more details.
+	///
+	/// ## Example
+	/// ```rust
+	/// # use utf8_decode::{Decoder, DecodeError};
+	/// let mut decoder = Decoder::new_typed([0x61, 0xed, 0xa0, 0x80].iter().cloned());
+	/// assert_eq!(decoder.next(), Some(Ok('a')));
+	/// assert_eq!(decoder.next(), Some(Err(DecodeError::Surrogate { offset: 1, code_point: 0xd800 })));
+	/// assert_eq!(decoder.next(), None);
+	/// ```
+	pub fn new_typed(source: R) -> TypedDecoder<R> {
+		TypedDecoder::new(source)
+	}
+
+	/// Creates a new decoder iterator from the given `u8` source iterator, removing the byte
+	/// order mark starting the input, if any.
+	///
+	/// This is the same as `Decoder::new(source).skip_bom()`. See [`SkipBom`](crate::SkipBom)
+	/// for more details.
+	pub fn new_skip_bom(source: R) -> SkipBom<R> {
+		Decoder::new(source).skip_bom()
+	}
+
+	/// Rejects noncharacters, on top of the RFC 3629 conformance of the decoder.
+	///
+	/// The default `Decoder` is already strict as defined by RFC 3629: it rejects overlong
+	/// encodings, surrogate code points, code points above U+10FFFF, and the lead bytes `0xC0`,
+	/// `0xC1` and `0xF5` to `0xFF`, that can never start a valid sequence. This method
+	/// additionally rejects noncharacters, such as U+FFFE and U+FFFF, that RFC 3629 allows.
+	/// This is the recommended way to decode untrusted input.
+	///
+	/// See [`RejectNoncharacters`](crate::RejectNoncharacters) for more details.
+	///
+	/// ## Example
+	/// The following cases are taken from Markus Kuhn's UTF-8 decoder capability and stress test.
+	/// ```rust
+	/// # use utf8_decode::Decoder;
+	/// fn strict(bytes: &[u8]) -> Vec<Option<char>> {
+	///     Decoder::new(bytes.iter().cloned()).strict().map(Result::ok).collect()
+	/// }
+	///
+	/// fn valid(bytes: &[u8]) -> bool {
+	///     strict(bytes).iter().all(Option::is_some)
+	/// }
+	///
+	/// // 1. Some correct UTF-8 text.
+	/// assert_eq!(strict("κόσμε".as_bytes()), "κόσμε".chars().map(Some).collect::<Vec<_>>());
+	///
+	/// // 2.1. First possible sequence of a certain length.
+	/// assert_eq!(strict(b"\x00"), [Some('\u{0}')]);
+	/// assert_eq!(strict(b"\xc2\x80"), [Some('\u{80}')]);
+	/// assert_eq!(strict(b"\xe0\xa0\x80"), [Some('\u{800}')]);
+	/// assert_eq!(strict(b"\xf0\x90\x80\x80"), [Some('\u{10000}')]);
+	/// assert!(!valid(b"\xf8\x88\x80\x80\x80"));
+	/// assert!(!valid(b"\xfc\x84\x80\x80\x80\x80"));
+	///
+	/// // 2.2. Last possible sequence of a certain length.
+	/// assert_eq!(strict(b"\x7f"), [Some('\u{7f}')]);
+	/// assert_eq!(strict(b"\xdf\xbf"), [Some('\u{7ff}')]);
+	/// // U+FFFF is a noncharacter.
+	/// assert_eq!(strict(b"\xef\xbf\xbf"), [None]);
+	/// assert!(!valid(b"\xf7\xbf\xbf\xbf"));
+	/// assert!(!valid(b"\xfb\xbf\xbf\xbf\xbf"));
+	/// assert!(!valid(b"\xfd\xbf\xbf\xbf\xbf\xbf"));
+	///
+	/// // 2.3. Other boundary conditions.
+	/// assert_eq!(strict(b"\xed\x9f\xbf"), [Some('\u{d7ff}')]);
+	/// assert_eq!(strict(b"\xee\x80\x80"), [Some('\u{e000}')]);
+	/// assert_eq!(strict(b"\xef\xbf\xbd"), [Some('\u{fffd}')]);
+	/// // U+10FFFF is a noncharacter.
+	/// assert_eq!(strict(b"\xf4\x8f\xbf\xbf"), [None]);
+	/// assert!(!valid(b"\xf4\x90\x80\x80"));
+	///
+	/// // 3.1. Unexpected continuation bytes, skipped up to the next lead byte after an error.
+	/// assert_eq!(strict(b"\x80"), [None]);
+	/// assert_eq!(strict(b"\xbf"), [None]);
+	/// assert_eq!(strict(b"\x80\xbf\x80\xbfa"), [None, Some('a')]);
+	/// let continuations: Vec<u8> = (0x80..=0xbf).collect();
+	/// assert_eq!(strict(&continuations), [None]);
+	///
+	/// // 3.2. Lonely start bytes, each followed by a space.
+	/// for a in 0xc0..=0xfd {
+	///     assert_eq!(strict(&[a, b' ']), [None, Some(' ')]);
+	/// }
+	///
+	/// // 3.3. Sequences with the last continuation byte missing.
+	/// let truncated: [&[u8]; 10] = [
+	///     b"\xc0", b"\xe0\x80", b"\xf0\x80\x80", b"\xf8\x80\x80\x80", b"\xfc\x80\x80\x80\x80",
+	///     b"\xdf", b"\xef\xbf", b"\xf7\xbf\xbf", b"\xfb\xbf\xbf\xbf", b"\xfd\xbf\xbf\xbf\xbf"
+	/// ];
+	/// for bytes in &truncated {
+	///     assert!(!valid(bytes));
+	/// }
+	///
+	/// // 3.4. Concatenation of the incomplete sequences above.
+	/// assert!(!valid(&truncated.concat()));
+	///
+	/// // 3.5. Impossible bytes.
+	/// assert_eq!(strict(b"\xfe"), [None]);
+	/// assert_eq!(strict(b"\xff"), [None]);
+	/// assert_eq!(strict(b"\xfe\xfe\xff\xff"), [None]);
+	///
+	/// // 4.1. Examples of an overlong ASCII character.
+	/// assert!(!valid(b"\xc0\xaf"));
+	/// assert!(!valid(b"\xe0\x80\xaf"));
+	/// assert!(!valid(b"\xf0\x80\x80\xaf"));
+	/// assert!(!valid(b"\xf8\x80\x80\x80\xaf"));
+	/// assert!(!valid(b"\xfc\x80\x80\x80\x80\xaf"));
+	///
+	/// // 4.2. Maximum overlong sequences.
+	/// assert!(!valid(b"\xc1\xbf"));
+	/// assert!(!valid(b"\xe0\x9f\xbf"));
+	/// assert!(!valid(b"\xf0\x8f\xbf\xbf"));
+	/// assert!(!valid(b"\xf8\x87\xbf\xbf\xbf"));
+	/// assert!(!valid(b"\xfc\x83\xbf\xbf\xbf\xbf"));
+	///
+	/// // 4.3. Overlong representation of the NUL character.
+	/// assert!(!valid(b"\xc0\x80"));
+	/// assert!(!valid(b"\xe0\x80\x80"));
+	/// assert!(!valid(b"\xf0\x80\x80\x80"));
+	/// assert!(!valid(b"\xf8\x80\x80\x80\x80"));
+	/// assert!(!valid(b"\xfc\x80\x80\x80\x80\x80"));
+	///
+	/// // 5.1. Single UTF-16 surrogates.
+	/// for low in &[0xa0, 0xad, 0xae, 0xaf, 0xb0, 0xbe, 0xbf] {
+	///     assert!(!valid(&[0xed, *low, 0x80]));
+	/// }
+	/// assert!(!valid(b"\xed\xbf\xbf"));
+	///
+	/// // 5.2. Paired UTF-16 surrogates.
+	/// assert_eq!(strict(b"\xed\xa0\x80\xed\xb0\x80"), [None, None]);
+	/// assert_eq!(strict(b"\xed\xaf\xbf\xed\xbf\xbf"), [None, None]);
+	///
+	/// // 5.3. Noncharacter code points, valid in RFC 3629 but rejected here.
+	/// assert_eq!(strict(b"\xef\xbf\xbe"), [None]);
+	/// assert_eq!(strict(b"\xef\xbf\xbf"), [None]);
+	/// assert_eq!(strict(b"\xef\xb7\x90"), [None]);
+	/// assert_eq!(strict(b"\xf4\x8f\xbf\xbe"), [None]);
+	/// assert!(Decoder::new(b"\xef\xbf\xbe".iter().cloned()).next().unwrap().is_ok());
+	/// ```
+	pub fn strict(self) -> RejectNoncharacters<Decoder<R>> {
+		RejectNoncharacters::new(self)
+	}
+
+	/// Adds a one character lookahead to this decoder.
+	///
+	/// See [`PeekableDecoder`](crate::PeekableDecoder) for more details.
+	pub fn into_peekable(self) -> PeekableDecoder<R> {
+		PeekableDecoder::new(self)
+	}
+
+	/// Number of bytes decoded so far.
+	pub(crate) fn offset(&self) -> usize {
+		self.offset
+	}
+
+	/// Returns the byte kept to be decoded next, if any.
+	pub(crate) fn pending(&self) -> Option<u8> {
+		self.pending
+	}
+
+	/// Replaces the source iterator with `f(source)`, keeping the state of the decoding.
+	pub(crate) fn map_source<S: Iterator<Item=u8>, F: FnOnce(R) -> S>(self, f: F) -> Decoder<S> {
+		Decoder {
+			bytes: f(self.bytes),
+			pending: self.pending,
+			offset: self.offset,
+			chars: self.chars
+		}
+	}
+
+	/// Returns the number of characters decoded so far.
+	///
+	/// Errors are not counted.
+	///
+	/// ## Example
+	/// ```rust
+	/// # use utf8_decode::Decoder;
+	/// let mut decoder = Decoder::new(b"a\xc3\xa9\xe2\x82\xac\xe2\x82b".iter().cloned());
+	/// assert_eq!((decoder.char_count(), decoder.byte_count()), (0, 0));
+	/// decoder.next();
+	/// assert_eq!((decoder.char_count(), decoder.byte_count()), (1, 1));
+	/// decoder.next();
+	/// decoder.next();
+	/// assert_eq!((decoder.char_count(), decoder.byte_count()), (3, 6));
+	///
+	/// // The invalid sequence `0xE2 0x82` is ended by `0x62`, decoded next.
+	/// assert!(decoder.next().unwrap().is_err());
+	/// assert_eq!((decoder.char_count(), decoder.byte_count()), (3, 8));
+	/// assert_eq!(decoder.next().unwrap().unwrap(), 'b');
+	/// assert_eq!((decoder.char_count(), decoder.byte_count()), (4, 9));
+	/// assert!(decoder.next().is_none());
+	/// ```
+	pub fn char_count(&self) -> usize {
+		self.chars
+	}
+
+	/// Returns the number of bytes decoded so far.
+	///
+	/// This includes the bytes of invalid sequences and the bytes skipped after them, but not the
+	/// byte that ended one when it is kept to be decoded next. See
+	/// [`char_count`](Decoder::char_count) for an example.
+	pub fn byte_count(&self) -> usize {
+		self.offset
+	}
+
+	/// Returns a reference to the source iterator.
+	pub fn get_ref(&self) -> &R {
+		&self.bytes
+	}
+
+	/// Returns a mutable reference to the source iterator.
+	///
+	/// Pulling bytes out of the source iterator directly skips them in the decoded output.
+	pub fn get_mut(&mut self) -> &mut R {
+		&mut self.bytes
+	}
+
+	/// Unwraps this `Decoder`, returning the source iterator.
+	///
+	/// The source iterator is positioned after the last byte read by the decoder. Right after an
+	/// error, the byte that ended the invalid sequence may be kept by the decoder to be decoded
+	/// next: it is then lost.
+	///
+	/// ## Example
+	/// ```rust
+	/// # use utf8_decode::Decoder;
+	/// let bytes = b"\xc3\xa9t\xc3\xa9\x00\x01\x02";
+	/// let mut decoder = Decoder::new(bytes.iter().cloned());
+	/// let mut string = String::new();
+	/// for c in &mut decoder {
+	///     match c.unwrap() {
+	///         '\0' => break,
+	///         c => string.push(c)
+	///     }
+	/// }
+	///
+	/// assert_eq!(string, "été");
+	/// let rest: Vec<u8> = decoder.into_inner().collect();
+	/// assert_eq!(rest, [0x01, 0x02]);
+	///
+	/// // The invalid sequence `0xC3` is ended by `0x41`, kept by the decoder.
+	/// let mut decoder = Decoder::new(b"\xc3\x41\x42".iter().cloned());
+	/// assert!(decoder.next().unwrap().is_err());
+	/// assert_eq!(decoder.into_inner().collect::<Vec<u8>>(), [0x42]);
+	/// ```
+	pub fn into_inner(self) -> R {
+		self.bytes
+	}
+
+	/// Limits the length of each line to `n` bytes.
+	///
+	/// See [`MaxLineBytes`](crate::MaxLineBytes) for more details.
+	pub fn max_line_bytes(self, n: usize) -> MaxLineBytes<R> {
+		MaxLineBytes::new(self, n)
+	}
+
+	/// Inserts `marker` once after the `n`-th character, if there are more.
+	///
+	/// See [`SoftCharLimit`](crate::SoftCharLimit) for more details.
+	pub fn soft_char_limit(self, n: usize, marker: char) -> SoftCharLimit<Decoder<R>> {
+		SoftCharLimit::new(self, n, marker)
+	}
+
+	/// Limits the number of code points of each grapheme cluster to `k`.
+	///
+	/// See [`MaxClusterSize`](crate::MaxClusterSize) for more details.
+	pub fn max_cluster_size(self, k: usize) -> MaxClusterSize<Decoder<R>> {
+		MaxClusterSize::new(self, k)
+	}
+
+	/// Limits the number of distinct characters to `n`.
+	///
+	/// See [`MaxDistinctChars`](crate::MaxDistinctChars) for more details.
+	pub fn max_distinct_chars(self, n: usize) -> MaxDistinctChars<Decoder<R>> {
+		MaxDistinctChars::new(self, n)
+	}
+
+	/// Converts the leading tabs of each line into `tab_width` spaces.
+	///
+	/// See [`NormalizeIndent`](crate::NormalizeIndent) for more details.
+	pub fn normalize_indent(self, tab_width: usize) -> NormalizeIndent<Decoder<R>> {
+		NormalizeIndent::new(self, tab_width)
+	}
+
+	/// Rejects the C1 control characters (U+0080 to U+009F).
+	///
+	/// See [`RejectC1Controls`](crate::RejectC1Controls) for more details.
+	pub fn reject_c1_controls(self) -> RejectC1Controls<Decoder<R>> {
+		RejectC1Controls::new(self)
+	}
+
+	/// Rejects the control characters, except `'\t'`, `'\n'` and `'\r'`.
+	///
+	/// See [`TextFieldSafe`](crate::TextFieldSafe) for more details.
+	pub fn text_field_safe(self) -> TextFieldSafe<Decoder<R>> {
+		TextFieldSafe::new(self)
+	}
+
+	/// Converts all the line breaks (CR, LF, CRLF, NEL, LS and PS) into `'\n'`.
+	///
+	/// See [`NormalizeAllLineBreaks`](crate::NormalizeAllLineBreaks) for more details.
+	pub fn normalize_all_line_breaks(self) -> NormalizeAllLineBreaks<Decoder<R>> {
+		NormalizeAllLineBreaks::new(self)
+	}
+
+	/// Decodes the whole input lossily, replacing each maximal invalid subsequence with the given
+	/// string.
+	///
+	/// Maximal invalid subsequences are the same as for the [`Lossy`](crate::Lossy) decoder. The
+	/// replacement string is inserted verbatim, and may be empty to remove invalid sequences.
+	///
+	/// ## Example
+	/// ```rust
+	/// # use utf8_decode::Decoder;
+	/// let bytes = [0x61, 0xff, 0x62, 0xe2, 0x82, 0x63, 0xf0, 0x9f];
+	/// assert_eq!(Decoder::new(bytes.iter().cloned()).lossy_with_str("[?]"), "a[?]b[?]c[?]");
+	/// assert_eq!(Decoder::new(bytes.iter().cloned()).lossy_with_str(""), "abc");
+	/// assert_eq!(Decoder::new("é".bytes()).lossy_with_str("[?]"), "é");
+	/// ```
+	pub fn lossy_with_str(self, replacement: &str) -> String {
+		lossy::decode_with_str(self.lossy(), replacement)
+	}
+
+	/// Yields each character along with the bytes encoding it in the input.
+	///
+	/// See [`WithSourceBytes`](crate::WithSourceBytes) for more details.
+	pub fn with_source_bytes(self) -> WithSourceBytes<R> {
+		WithSourceBytes::new(self.bytes, self.pending, self.offset)
+	}
+
+	/// Yields each character along with the length in bytes of its encoding.
+	///
+	/// See [`WithLengths`](crate::WithLengths) for more details.
+	pub fn with_lengths(self) -> WithLengths<R> {
+		WithLengths::new(self)
+	}
+
+	/// Ends without error when the input ends in the middle of a character, keeping its bytes.
+	///
+	/// See [`FrameAware`](crate::FrameAware) for more details.
+	pub fn frame_aware(self) -> FrameAware<R> {
+		FrameAware::new(self.bytes, self.pending, self.offset)
+	}
+
+	/// Removes the byte order mark starting the input, if any.
+	///
+	/// See [`SkipBom`](crate::SkipBom) for more details.
+	pub fn skip_bom(self) -> SkipBom<R> {
+		SkipBom::new(self)
+	}
+
+	/// Accepts overlong encodings, yielding each character along with a flag telling if it was
+	/// overlong.
+	///
+	/// See [`AcceptOverlong`](crate::AcceptOverlong) for more details.
+	pub fn accept_overlong(self) -> AcceptOverlong<R> {
+		AcceptOverlong::new(TypedDecoder::resume(self.bytes, self.pending, self.offset))
+	}
+
+	/// Yields the decoded characters along with the line breaks, errors and end of the input, as
+	/// [`DecodeEvent`](crate::DecodeEvent) events.
+	///
+	/// See [`Events`](crate::Events) for more details.
+	pub fn events(self) -> Events<R> {
+		Events::new(TypedDecoder::resume(self.bytes, self.pending, self.offset))
+	}
+
+	/// Calls `f` with the index of each `'\n'` character, as it is yielded.
+	///
+	/// See [`OnLineEnd`](crate::OnLineEnd) for more details.
+	pub fn on_line_end<F: FnMut(usize)>(self, f: F) -> OnLineEnd<Decoder<R>, F> {
+		OnLineEnd::new(self, f)
+	}
+
+	/// Decodes lossily, replacing each maximal invalid subsequence with one replacement character.
+	///
+	/// See [`Lossy`](crate::Lossy) for more details. Like the other adapters, the lossy decoder
+	/// resumes where this decoder stopped, including after an error.
+	///
+	/// ## Example
+	/// ```rust
+	/// # use utf8_decode::Decoder;
+	/// let mut decoder = Decoder::new(b"\xc3AB\xff".iter().cloned());
+	/// assert!(decoder.next().unwrap().is_err());
+	/// let string: std::io::Result<String> = decoder.lossy().collect();
+	/// assert_eq!(string.unwrap(), "AB\u{fffd}");
+	/// ```
+	pub fn lossy(self) -> Lossy<R> {
+		self.lossy_with_recovery(RecoveryStrategy::SkipMaximalSubpart)
+	}
+
+	/// Decodes lossily, replacing each run of consecutive invalid sequences with one replacement
+	/// character.
+	///
+	/// See [`LossyCoalesced`](crate::LossyCoalesced) for more details.
+	pub fn lossy_coalesced(self) -> LossyCoalesced<R> {
+		self.lossy().coalesced()
+	}
+
+	/// Decodes lossily, replacing each maximal invalid subsequence with the given character.
+	///
+	/// See [`Lossy`](crate::Lossy) for more details.
+	pub fn lossy_with(self, replacement: char) -> Lossy<R> {
+		self.lossy().with_replacement(replacement)
+	}
+
+	/// Decodes lossily, replacing invalid sequences with replacement characters according to the
+	/// given recovery strategy.
+	///
+	/// See [`Lossy`](crate::Lossy) for more details.
+	pub fn lossy_with_recovery(self, strategy: RecoveryStrategy) -> Lossy<R> {
+		Lossy::resume(self.bytes, strategy, self.pending, self.offset)
+	}
+
+	/// Decodes lossily, reporting each error to the given sink.
+	///
+	/// See [`WithErrorSink`](crate::WithErrorSink) for more details.
+	pub fn with_error_sink<S: ErrorSink>(self, sink: S) -> WithErrorSink<R, S> {
+		WithErrorSink::new(self.lossy(), sink)
+	}
+
+	/// Decodes lossily, replacing each invalid byte with exactly one replacement character.
+	///
+	/// See [`LossyPreserveWidth`](crate::LossyPreserveWidth) for more details.
+	pub fn lossy_preserve_width(self) -> LossyPreserveWidth<R> {
+		LossyPreserveWidth::new(self.bytes, self.pending)
+	}
+
+	/// Decodes WTF-8 lossily, replacing lone surrogates and invalid sequences with replacement
+	/// characters.
+	///
+	/// See [`Wtf8Lossy`](crate::Wtf8Lossy) for more details.
+	pub fn wtf8_lossy(self) -> Wtf8Lossy<R> {
+		Wtf8Lossy::new(self.lossy())
+	}
+
+	/// Decodes lossily, failing when the same invalid sequence occurs `threshold` times.
+	///
+	/// See [`FailOnRepeatedError`](crate::FailOnRepeatedError) for more details.
+	pub fn fail_on_repeated_error(self, threshold: usize) -> FailOnRepeatedError<R> {
+		FailOnRepeatedError::new(self.bytes, self.pending, self.offset, threshold)
+	}
+
+	/// Decodes lossily, decoding overlong encodings to their intended character instead of
+	/// replacing them.
+	///
+	/// See [`LossyRecoverOverlong`](crate::LossyRecoverOverlong) for more details.
+	pub fn lossy_recover_overlong(self) -> LossyRecoverOverlong<R> {
+		LossyRecoverOverlong::new(self.lossy())
+	}
+
+	/// Yields the characters in batches of [`BATCH_SIZE`](crate::BATCH_SIZE).
+	///
+	/// See [`Batched`](crate::Batched) for more details.
+	pub fn batched(self) -> Batched<Decoder<R>> {
+		Batched::new(self)
+	}
+
+	/// Yields runs of identical characters as `(c, count)` pairs.
+	///
+	/// See [`RunLength`](crate::RunLength) for more details.
+	pub fn run_length(self) -> RunLength<Decoder<R>> {
+		RunLength::new(self)
+	}
+
+	/// Yields the sentences of the input, using a basic heuristic.
+	///
+	/// See [`Sentences`](crate::Sentences) for more details.
+	pub fn sentences(self) -> Sentences<Decoder<R>> {
+		Sentences::new(self)
+	}
+
+	/// Yields the case-folded characters of the input, for case-insensitive comparison.
+	///
+	/// See [`CaseFold`](crate::CaseFold) for more details.
+	pub fn casefold(self) -> CaseFold<Decoder<R>> {
+		CaseFold::new(self)
+	}
+
+	/// Decodes and accumulates characters until `target` is decoded.
+	///
+	/// The returned string does not include `target`, and the decoder is left positioned after
+	/// it.
+	///
+	/// ## Example
+	/// ```rust
+	/// # use utf8_decode::Decoder;
+	/// let mut decoder = Decoder::new("\"héllo\" world".bytes());
+	/// assert_eq!(decoder.next().unwrap().unwrap(), '"');
+	/// assert_eq!(decoder.read_until_char('"').unwrap(), "héllo");
+	/// assert_eq!(decoder.next().unwrap().unwrap(), ' ');
+	///
+	/// let error = decoder.read_until_char('"').unwrap_err();
+	/// assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+	/// ```
+	///
+	/// ## Errors
+	/// Returns the first decoding error encountered, or an
+	/// [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof) error if the input ends before
+	/// `target`. Use [`read_until_char_or_end`](Decoder::read_until_char_or_end) to get the
+	/// accumulated text in this case.
+	pub fn read_until_char(&mut self, target: char) -> Result<String> {
+		match self.read_until_char_or_end(target)? {
+			(string, true) => Ok(string),
+			(_, false) => Err(Error::new(ErrorKind::UnexpectedEof, format!("{:?} not found.", target)))
+		}
+	}
+
+	/// Decodes and accumulates characters until `target` is decoded or the input ends.
+	///
+	/// The returned string does not include `target`, and the decoder is left positioned after
+	/// it. The returned boolean is `true` if `target` has been found, and `false` if the input
+	/// ended before.
+	///
+	/// ## Example
+	/// ```rust
+	/// # use utf8_decode::Decoder;
+	/// let mut decoder = Decoder::new("key: valué".bytes());
+	/// assert_eq!(decoder.read_until_char_or_end(':').unwrap(), ("key".to_string(), true));
+	/// assert_eq!(decoder.read_until_char_or_end(':').unwrap(), (" valué".to_string(), false));
+	/// ```
+	///
+	/// ## Errors
+	/// Returns the first decoding error encountered.
+	pub fn read_until_char_or_end(&mut self, target: char) -> Result<(String, bool)> {
+		let mut string = String::new();
+		for c in self {
+			let c = c?;
+			if c == target {
+				return Ok((string, true))
+			}
+
+			string.push(c)
+		}
+
+		Ok((string, false))
+	}
+
+	/// Calls `f` on each decoded character, until it returns
+	/// [`ControlFlow::Break`](std::ops::ControlFlow::Break) or the input ends.
+	///
+	/// Returns `true` if `f` stopped the decoding early, and `false` if the whole input has been
+	/// decoded. Characters after the one on which `f` stopped are not decoded.
+	///
+	/// ## Example
+	/// ```rust
+	/// # use std::ops::ControlFlow;
+	/// # use utf8_decode::Decoder;
+	/// let mut lowercase = String::new();
+	/// let stopped = Decoder::new("héllo Wörld".bytes()).try_for_each_char(|c| {
+	///     if c.is_uppercase() {
+	///         ControlFlow::Break(())
+	///     } else {
+	///         lowercase.push(c);
+	///         ControlFlow::Continue(())
+	///     }
+	/// });
+	/// assert!(stopped.unwrap());
+	/// assert_eq!(lowercase, "héllo ");
+	///
+	/// let stopped = Decoder::new("héllo".bytes()).try_for_each_char(|c| {
+	///     if c.is_uppercase() { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+	/// });
+	/// assert!(!stopped.unwrap());
+	///
+	/// // The error comes before the first uppercase character.
+	/// let stopped = Decoder::new(b"a\xffB".iter().cloned()).try_for_each_char(|c| {
+	///     if c.is_uppercase() { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+	/// });
+	/// assert!(stopped.is_err());
+	/// ```
+	///
+	/// ## Errors
+	/// Returns the first decoding error encountered before `f` stops the decoding.
+	pub fn try_for_each_char<F: FnMut(char) -> ControlFlow<()>>(self, mut f: F) -> Result<bool> {
+		for c in self {
+			if f(c?).is_break() {
+				return Ok(true)
+			}
+		}
+
+		Ok(false)
+	}
+
+	/// Decodes the whole input into an ASCII string, where every non-ASCII character is escaped
+	/// as a `\uXXXX` sequence, suitable for JSON or source code string literals.
+	///
+	/// Characters outside the Basic Multilingual Plane are escaped as a UTF-16 surrogate pair.
+	/// Note that only non-ASCII characters are escaped: quotes, backslashes and ASCII control
+	/// characters are left untouched.
+	///
+	/// ## Example
+	/// ```rust
+	/// # use utf8_decode::Decoder;
+	/// let escaped = Decoder::new("café 🌍".bytes()).escape_unicode_json().unwrap();
+	/// assert_eq!(escaped, "caf\\u00e9 \\ud83c\\udf0d");
+	/// ```
+	///
+	/// ## Errors
+	/// Returns the first decoding error encountered.
+	pub fn escape_unicode_json(self) -> Result<String> {
+		escape::escape_unicode_json(self)
+	}
+
+	/// Yields each character along with whether it starts a word.
+	///
+	/// See [`WithWordStartFlag`](crate::WithWordStartFlag) for more details.
+	pub fn with_word_start_flag(self) -> WithWordStartFlag<Decoder<R>> {
+		WithWordStartFlag::new(self)
+	}
+
+	/// Collects statistics about the decoded sequences.
+	///
+	/// See [`WithStats`](crate::WithStats) for more details.
+	pub fn with_stats(self) -> WithStats<R> {
+		WithStats::new(self)
+	}
+
+	/// Yields each character along with the current bracket nesting depth.
+	///
+	/// See [`BracketDepth`](crate::BracketDepth) for more details.
+	pub fn with_bracket_depth(self) -> BracketDepth<Decoder<R>> {
+		BracketDepth::new(self)
+	}
+
+	/// Yields each character along with the rolling polynomial hash of the characters decoded so
+	/// far.
+	///
+	/// See [`WithRollingHash`](crate::WithRollingHash) for more details.
+	pub fn with_rolling_hash(self, base: u64) -> WithRollingHash<Decoder<R>> {
+		WithRollingHash::new(self, base)
+	}
+
+	/// Computes a checksum of the consumed input bytes.
+	///
+	/// See [`WithByteChecksum`](crate::WithByteChecksum) for more details.
+	pub fn with_byte_checksum(self) -> WithByteChecksum<R> {
+		WithByteChecksum::new(self)
+	}
+
+	/// Yields each character along with whether it is printable.
+	///
+	/// See [`WithPrintableFlag`](crate::WithPrintableFlag) for more details.
+	pub fn with_printable_flag(self) -> WithPrintableFlag<Decoder<R>> {
+		WithPrintableFlag::new(self)
+	}
+
+	/// Yields each character along with whether it has the `Emoji` property.
+	///
+	/// See [`WithEmojiFlag`](crate::WithEmojiFlag) for more details.
+	pub fn with_emoji_flag(self) -> WithEmojiFlag<Decoder<R>> {
+		WithEmojiFlag::new(self)
+	}
+
+	/// Decodes the input and checks if any character requires Unicode-aware rendering.
+	///
+	/// Unicode-aware rendering involves shaping or layout that a simple ASCII-like renderer does
+	/// not support: combining marks, characters with a strong right-to-left bidi class,
+	/// wide and fullwidth characters, and ZERO WIDTH JOINER (used by emoji sequences).
+	/// Decoding stops at the first such character.
+	///
+	/// ## Example
+	/// ```rust
+	/// # use utf8_decode::Decoder;
+	/// assert_eq!(Decoder::new("Hello World!".bytes()).needs_complex_rendering().unwrap(), false);
+	/// // "e" followed by U+0301 COMBINING ACUTE ACCENT.
+	/// assert_eq!(Decoder::new("cafe\u{301}".bytes()).needs_complex_rendering().unwrap(), true);
+	/// ```
+	///
+	/// ## Errors
+	/// Returns the first decoding error encountered before any such character.
+	pub fn needs_complex_rendering(self) -> Result<bool> {
+		printable::needs_complex_rendering(self)
+	}
+
+	/// Decodes the input and returns the 1-based numbers of the lines ending with whitespace.
+	///
+	/// A line has trailing whitespace if the character preceding its terminating `'\n'`, or the
+	/// end of the input for the last line, is whitespace according to [`char::is_whitespace`].
+	/// A `'\r'` directly preceding a `'\n'` is considered part of the line terminator.
+	///
+	/// ## Example
+	/// ```rust
+	/// # use utf8_decode::Decoder;
+	/// let text = "clean\nspace \ntab\t\r\nclean\r\nno-break\u{a0}\nideographic\u{3000}";
+	/// assert_eq!(Decoder::new(text.bytes()).trailing_whitespace_lines().unwrap(), [2, 3, 5, 6]);
+	///
+	/// assert!(Decoder::new("a\nb\n".bytes()).trailing_whitespace_lines().unwrap().is_empty());
+	/// ```
+	///
+	/// ## Errors
+	/// Returns the first decoding error encountered.
+	pub fn trailing_whitespace_lines(self) -> Result<Vec<usize>> {
+		lines::trailing_whitespace_lines(self)
+	}
+
+	/// Decodes the input into a string, rejecting it if combining marks make up more than the
+	/// given fraction of its characters.
+	///
+	/// Combining marks are the characters of general category `Mn`, `Mc` or `Me`. A high ratio
+	/// of combining marks is typical of abusive "zalgo" text, stacking marks over few base
+	/// characters. Note that some scripts (such as Devanagari) legitimately use many combining
+	/// marks, so `ratio` should be chosen accordingly.
+	///
+	/// ## Example
+	/// ```rust
+	/// # use utf8_decode::Decoder;
+	/// assert_eq!(Decoder::new("cafe\u{301} crème".bytes()).max_combining_ratio(0.25).unwrap(), "cafe\u{301} crème");
+	///
+	/// let zalgo = "h\u{334}\u{31b}\u{35d}e\u{337}\u{320}\u{35a}";
+	/// let error = Decoder::new(zalgo.bytes()).max_combining_ratio(0.25).unwrap_err();
+	/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+	/// assert_eq!(error.to_string(), "combining marks ratio exceeds 0.25.");
+	/// ```
+	///
+	/// ## Errors
+	/// Returns an [`InvalidData`](std::io::ErrorKind::InvalidData) error if the ratio of
+	/// combining marks exceeds `ratio`, or the first decoding error encountered.
+	pub fn max_combining_ratio(self, ratio: f32) -> Result<String> {
+		policy::max_combining_ratio(self, ratio)
+	}
+
+	/// Decodes the input and indexes its lines to map Language Server Protocol positions to byte
+	/// offsets.
+	///
+	/// See [`LineIndex`](crate::LineIndex) for more details.
+	///
+	/// ## Errors
+	/// Returns the first decoding error encountered.
+	pub fn lsp_index(self) -> Result<LineIndex> {
+		LineIndex::new(self)
+	}
+
+	/// Yields each character along with its East Asian Width property.
+	///
+	/// See [`WithEaw`](crate::WithEaw) for more details.
+	#[cfg(feature = "width")]
+	pub fn with_eaw(self) -> WithEaw<Decoder<R>> {
+		WithEaw::new(self)
+	}
+
+	/// Yields each character along with whether it is assigned as of the given Unicode version.
+	///
+	/// See [`WithAssignment`](crate::WithAssignment) for more details.
+	#[cfg(feature = "age")]
+	pub fn with_assignment(self, version: UnicodeVersion) -> WithAssignment<Decoder<R>> {
+		WithAssignment::new(self, version)
+	}
+
+	/// Yields each character along with its Unicode name.
+	///
+	/// See [`WithName`](crate::WithName) for more details.
+	#[cfg(feature = "names")]
+	pub fn with_name(self) -> WithName<Decoder<R>> {
+		WithName::new(self)
+	}
+
+	/// Yields each character along with its role in identifiers.
+	///
+	/// See [`WithIdentFlags`](crate::WithIdentFlags) for more details.
+	#[cfg(feature = "ident")]
+	pub fn with_ident_flags(self) -> WithIdentFlags<Decoder<R>> {
+		WithIdentFlags::new(self)
+	}
+
+	/// Yields each character along with its numeric value, if any.
+	///
+	/// See [`WithNumericValue`](crate::WithNumericValue) for more details.
+	#[cfg(feature = "numeric")]
+	pub fn with_numeric_value(self) -> WithNumericValue<Decoder<R>> {
+		WithNumericValue::new(self)
+	}
+
+	/// Yields the characters of the input in Normalization Form D (canonical decomposition).
+	///
+	/// See [`Nfd`](crate::Nfd) for more details.
+	#[cfg(feature = "normalization")]
+	pub fn nfd(self) -> Nfd<Decoder<R>> {
+		Nfd::new(self)
+	}
 }
 
 impl<R: Iterator<Item=u8>> Iterator for Decoder<R> {
 	type Item = Result<char>;
 
+	/// ASCII characters are returned directly, without going through the multi-byte decoding.
+	///
+	/// ## Example
+	/// ```rust
+	/// # use utf8_decode::Decoder;
+	/// let bytes = b"log: caf\xc3\xa9 \xe2\x82\xac\x7f\x00 \xf0\x9f\x98\x80!\xff.";
+	/// let chars: Vec<_> = Decoder::new(bytes.iter().cloned()).map(Result::ok).collect();
+	/// let expected: Vec<_> = "log: café €\x7f\0 😀!".chars().map(Some).chain(vec![None, Some('.')]).collect();
+	/// assert_eq!(chars, expected);
+	/// ```
 	fn next(&mut self) -> Option<Result<char>> {
-		decode(&mut self.bytes)
+		let offset = self.offset;
+		let a = match self.pending.take() {
+			Some(a) => a,
+			None => self.bytes.next()?
+		};
+		self.offset += 1;
+		if a & 0x80 == 0x00 {
+			self.chars += 1;
+			return Some(Ok(a as char))
+		}
+
+		let mut last = a;
+		let c = decode_from(a as u32, &mut Counted {
+			bytes: &mut self.bytes.by_ref().inspect(|&b| last = b),
+			count: &mut self.offset
+		});
+
+		match c {
+			Ok(c) => {
+				self.chars += 1;
+				Some(Ok(c))
+			},
+			Err(e) => {
+				if self.offset - offset > 1 && last & 0xC0 != 0x80 {
+					// The byte ending the invalid sequence may start a new one.
+					self.pending = Some(last);
+					self.offset -= 1
+				}
+
+				// Skip the rest of the invalid sequence.
+				self.pending = skip_invalid(&mut self.bytes, self.pending.take(), &mut self.offset);
+				Some(Err(OffsetError::new(offset, e).into()))
+			}
+		}
+	}
+
+	/// Each character is encoded by 1 to 4 bytes, and each error consumes at least one byte.
+	///
+	/// ## Example
+	/// ```rust
+	/// # use utf8_decode::Decoder;
+	/// let bytes = "aé€😀".as_bytes();
+	/// let decoder = Decoder::new(bytes.iter().cloned());
+	/// assert_eq!(decoder.size_hint(), (2, Some(10)));
+	/// assert_eq!(decoder.count(), 4);
+	/// ```
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let pending = self.pending.is_some() as usize;
+		let (lower, upper) = self.bytes.size_hint();
+		((lower + pending) / 4, upper.and_then(|upper| upper.checked_add(pending)))
+	}
+}
+
+/// A `Decoder` returns `None` only when the source iterator returns `None` in place of the first
+/// byte of a character. If the source iterator is fused, so is the decoder, even after an error.
+///
+/// ## Example
+/// ```rust
+/// # use std::iter::FusedIterator;
+/// # use utf8_decode::Decoder;
+/// fn assert_fused<I: FusedIterator>(_: &I) {}
+///
+/// let mut decoder = Decoder::new(b"a\xe2\x82".iter().cloned());
+/// assert_fused(&decoder);
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'a');
+/// assert!(decoder.next().unwrap().is_err());
+/// assert!(decoder.next().is_none());
+/// assert!(decoder.next().is_none());
+/// ```
+impl<R: FusedIterator<Item=u8>> FusedIterator for Decoder<R> {}
+
+/// A `Decoder` can be cloned if its source iterator can, to decode ahead and backtrack. The
+/// clone continues from the same position, including the byte offset reported in errors,
+/// independently of the original decoder.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let mut decoder = Decoder::new(b"a\xc3\xa9\xe2\x82\xac\xff".iter().cloned());
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'a');
+///
+/// let mut lookahead = decoder.clone();
+/// assert_eq!(lookahead.next().unwrap().unwrap(), 'é');
+/// assert_eq!(lookahead.next().unwrap().unwrap(), '€');
+///
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'é');
+/// assert_eq!(lookahead.next().unwrap().unwrap_err().to_string(), "invalid lead byte 0xFF in UTF-8 sequence at byte 6.");
+/// assert_eq!(decoder.next().unwrap().unwrap(), '€');
+/// ```
+impl<R: Clone + Iterator<Item=u8>> Clone for Decoder<R> {
+	fn clone(&self) -> Decoder<R> {
+		Decoder {
+			bytes: self.bytes.clone(),
+			pending: self.pending,
+			offset: self.offset,
+			chars: self.chars
+		}
+	}
+}
+
+/// Formats the source iterator, the byte kept to be decoded next, if any, and the numbers of bytes
+/// and characters decoded so far.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// #[derive(Debug)]
+/// struct Bytes;
+///
+/// impl Iterator for Bytes {
+///     type Item = u8;
+///
+///     fn next(&mut self) -> Option<u8> {
+///         Some(b'a')
+///     }
+/// }
+///
+/// let mut decoder = Decoder::new(Bytes);
+/// decoder.next();
+/// assert_eq!(format!("{:?}", decoder), "Decoder { bytes: Bytes, pending: None, offset: 1, chars: 1 }");
+/// ```
+impl<R: fmt::Debug + Iterator<Item=u8>> fmt::Debug for Decoder<R> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("Decoder")
+			.field("bytes", &self.bytes)
+			.field("pending", &self.pending)
+			.field("offset", &self.offset)
+			.field("chars", &self.chars)
+			.finish()
+	}
+}
+
+/// UTF-8 decoder iterator yielding raw code points.
+///
+/// Transform the given [`u8`](u8) iterator into a [`io::Result<u32>`](std::io::Result) iterator.
+/// Only the structure of the UTF-8 sequences is checked: lead and continuation bytes, and
+/// overlong encodings. The decoded code points are not checked to be Unicode scalar values:
+/// surrogate code points (U+D800 to U+DFFF), as found in WTF-8 or CESU-8 data, and code points
+/// from U+110000 to U+13FFFF (encoded with the lead byte `0xF4`) are yielded as is, leaving
+/// their handling to the caller.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::RawDecoder;
+/// // "a", "é", the surrogate U+D800, and U+110000.
+/// let bytes = [0x61, 0xc3, 0xa9, 0xed, 0xa0, 0x80, 0xf4, 0x90, 0x80, 0x80];
+/// let code_points: std::io::Result<Vec<u32>> = RawDecoder::new(bytes.iter().cloned()).collect();
+/// assert_eq!(code_points.unwrap(), [0x61, 0xe9, 0xd800, 0x110000]);
+///
+/// // Structural errors are still reported.
+/// let mut decoder = RawDecoder::new([0x61, 0xc0, 0xaf].iter().cloned());
+/// assert_eq!(decoder.next().unwrap().unwrap(), 0x61);
+/// let error = decoder.next().unwrap().unwrap_err();
+/// assert_eq!(error.to_string(), "invalid lead byte 0xC0 in UTF-8 sequence at byte 1.");
+/// ```
+///
+/// ## Errors
+/// Errors are the same as the [`Decoder`](crate::Decoder) errors, except for the errors on
+/// surrogate code points. Decoding resumes after an error like with the
+/// [`Decoder`](crate::Decoder), at the next byte that may start a sequence.
+///
+/// ```rust
+/// # use utf8_decode::RawDecoder;
+/// let mut decoder = RawDecoder::new(b"\xe2\x82a\x80\x80b".iter().cloned());
+/// assert!(decoder.next().unwrap().is_err());
+/// assert_eq!(decoder.next().unwrap().unwrap(), 0x61);
+/// assert!(decoder.next().unwrap().is_err());
+/// assert_eq!(decoder.next().unwrap().unwrap(), 0x62);
+/// assert!(decoder.next().is_none());
+/// ```
+pub struct RawDecoder<R: Iterator<Item=u8>> {
+	bytes: R,
+	pending: Option<u8>,
+	offset: usize
+}
+
+impl<R: Iterator<Item=u8>> RawDecoder<R> {
+	/// Creates a new `RawDecoder` iterator from the given `u8` source iterator.
+	pub fn new(source: R) -> RawDecoder<R> {
+		RawDecoder {
+			bytes: source,
+			pending: None,
+			offset: 0
+		}
+	}
+
+	/// Returns a reference to the source iterator.
+	pub fn get_ref(&self) -> &R {
+		&self.bytes
+	}
+
+	/// Returns a mutable reference to the source iterator.
+	pub fn get_mut(&mut self) -> &mut R {
+		&mut self.bytes
+	}
+
+	/// Unwraps this `RawDecoder`, returning the source iterator.
+	///
+	/// Right after an error, the byte that ended the invalid sequence may be kept by the decoder
+	/// to be decoded next: it is then lost.
+	pub fn into_inner(self) -> R {
+		self.bytes
+	}
+}
+
+impl<R: Iterator<Item=u8>> Iterator for RawDecoder<R> {
+	type Item = Result<u32>;
+
+	fn next(&mut self) -> Option<Result<u32>> {
+		let offset = self.offset;
+		let a = match self.pending.take() {
+			Some(a) => a,
+			None => self.bytes.next()?
+		};
+		self.offset += 1;
+		let mut last = a;
+		let c = raw_decode_from(a as u32, &mut Counted {
+			bytes: &mut self.bytes.by_ref().inspect(|&b| last = b),
+			count: &mut self.offset
+		});
+
+		Some(c.map_err(|e| {
+			if self.offset - offset > 1 && last & 0xC0 != 0x80 {
+				// The byte ending the invalid sequence may start a new one.
+				self.pending = Some(last);
+				self.offset -= 1
+			}
+
+			// Skip the rest of the invalid sequence.
+			self.pending = skip_invalid(&mut self.bytes, self.pending.take(), &mut self.offset);
+			OffsetError::new(offset, e).into()
+		}))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let pending = self.pending.is_some() as usize;
+		let (lower, upper) = self.bytes.size_hint();
+		((lower + pending) / 4, upper.and_then(|upper| upper.checked_add(pending)))
+	}
+}
+
+impl<R: FusedIterator<Item=u8>> FusedIterator for RawDecoder<R> {}
+
+/// Byte iterator counting the bytes pulled out of the underlying iterator.
+struct Counted<'a, R> {
+	bytes: &'a mut R,
+	count: &'a mut usize
+}
+
+impl<'a, R: Iterator<Item=u8>> Iterator for Counted<'a, R> {
+	type Item = u8;
+
+	fn next(&mut self) -> Option<u8> {
+		let b = self.bytes.next()?;
+		*self.count += 1;
+		Some(b)
 	}
 }
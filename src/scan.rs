@@ -0,0 +1,133 @@
+use std::io::{Result, Error, ErrorKind};
+
+/// Default bracket pairs of the [`BracketDepth`] decoder.
+pub const DEFAULT_BRACKETS: &[(char, char)] = &[('(', ')'), ('[', ']'), ('{', '}')];
+
+/// UTF-8 decoder iterator yielding each character with the current bracket nesting depth.
+///
+/// Created by the [`Decoder::with_bracket_depth`](crate::Decoder::with_bracket_depth) method.
+/// The depth yielded with a character is the depth after this character: it is incremented by
+/// opening brackets and decremented by closing brackets.
+/// The bracket pairs are [`DEFAULT_BRACKETS`](crate::DEFAULT_BRACKETS) by default, and can be
+/// changed with [`with_pairs`](BracketDepth::with_pairs). Pairs are not matched against
+/// each other: any closing bracket closes the innermost bracket.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let depths: Vec<i32> = Decoder::new("a(b[c]d)e".bytes()).with_bracket_depth().map(|r| r.unwrap().1).collect();
+/// assert_eq!(depths, [0, 1, 1, 2, 2, 1, 1, 0, 0]);
+///
+/// let depths: Vec<i32> = Decoder::new("<a>)".bytes()).with_bracket_depth().with_pairs(&[('<', '>')]).map(|r| r.unwrap().1).collect();
+/// assert_eq!(depths, [1, 1, 0, 0]);
+///
+/// let mut decoder = Decoder::new("a)".bytes()).with_bracket_depth().deny_unbalanced();
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('a', 0));
+/// assert!(decoder.next().unwrap().is_err());
+/// ```
+///
+/// ## Errors
+/// Decoding errors are forwarded. If [`deny_unbalanced`](BracketDepth::deny_unbalanced) is set,
+/// a closing bracket that would make the depth negative is replaced by an
+/// [`InvalidData`](std::io::ErrorKind::InvalidData) error, leaving the depth unchanged.
+pub struct BracketDepth<I: Iterator<Item=Result<char>>> {
+	chars: I,
+	pairs: Vec<(char, char)>,
+	deny_unbalanced: bool,
+	depth: i32
+}
+
+impl<I: Iterator<Item=Result<char>>> BracketDepth<I> {
+	pub(crate) fn new(chars: I) -> BracketDepth<I> {
+		BracketDepth {
+			chars,
+			pairs: DEFAULT_BRACKETS.to_vec(),
+			deny_unbalanced: false,
+			depth: 0
+		}
+	}
+
+	/// Sets the `(open, close)` bracket pairs.
+	pub fn with_pairs(mut self, pairs: &[(char, char)]) -> BracketDepth<I> {
+		self.pairs = pairs.to_vec();
+		self
+	}
+
+	/// Rejects closing brackets making the depth negative.
+	pub fn deny_unbalanced(mut self) -> BracketDepth<I> {
+		self.deny_unbalanced = true;
+		self
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for BracketDepth<I> {
+	type Item = Result<(char, i32)>;
+
+	fn next(&mut self) -> Option<Result<(char, i32)>> {
+		match self.chars.next()? {
+			Ok(c) => {
+				if self.pairs.iter().any(|&(open, _)| open == c) {
+					self.depth += 1
+				} else if self.pairs.iter().any(|&(_, close)| close == c) {
+					if self.deny_unbalanced && self.depth == 0 {
+						return Some(Err(Error::new(ErrorKind::InvalidData, format!("unbalanced {:?}.", c))))
+					}
+
+					self.depth -= 1
+				}
+
+				Some(Ok((c, self.depth)))
+			},
+			Err(e) => Some(Err(e))
+		}
+	}
+}
+
+/// UTF-8 decoder iterator flagging the first character of each word.
+///
+/// Created by the [`Decoder::with_word_start_flag`](crate::Decoder::with_word_start_flag) method.
+/// A character starts a word if it is not whitespace, and is either the first character of the
+/// input or follows a whitespace character.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let starts: String = Decoder::new("hello world".bytes()).with_word_start_flag()
+///     .map(Result::unwrap)
+///     .filter(|(_, start)| *start)
+///     .map(|(c, _)| c)
+///     .collect();
+/// assert_eq!(starts, "hw");
+/// ```
+///
+/// ## Errors
+/// Decoding errors are forwarded, and are ignored to decide if the next character starts a word.
+pub struct WithWordStartFlag<I: Iterator<Item=Result<char>>> {
+	chars: I,
+	after_whitespace: bool
+}
+
+impl<I: Iterator<Item=Result<char>>> WithWordStartFlag<I> {
+	pub(crate) fn new(chars: I) -> WithWordStartFlag<I> {
+		WithWordStartFlag {
+			chars,
+			after_whitespace: true
+		}
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for WithWordStartFlag<I> {
+	type Item = Result<(char, bool)>;
+
+	fn next(&mut self) -> Option<Result<(char, bool)>> {
+		match self.chars.next()? {
+			Ok(c) => {
+				let whitespace = c.is_whitespace();
+				let start = self.after_whitespace && !whitespace;
+				self.after_whitespace = whitespace;
+				Some(Ok((c, start)))
+			},
+			Err(e) => Some(Err(e))
+		}
+	}
+}
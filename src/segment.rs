@@ -0,0 +1,95 @@
+use std::io::Result;
+
+/// Common abbreviations, in lower case, not ending a sentence.
+const ABBREVIATIONS: &[&str] = &[
+	"mr.", "mrs.", "ms.", "dr.", "prof.", "sr.", "jr.", "st.", "vs.", "etc.", "e.g.", "i.e.", "inc.", "ltd.", "no."
+];
+
+/// UTF-8 decoder iterator yielding sentences.
+///
+/// Created by the [`Decoder::sentences`](crate::Decoder::sentences) method.
+/// This uses a basic heuristic inspired by the sentence boundaries of
+/// [Unicode Standard Annex #29](https://www.unicode.org/reports/tr29/): a sentence ends after a
+/// `.`, `!` or `?` (possibly followed by closing quotes or brackets) followed by whitespace,
+/// unless the last word is a common abbreviation such as "Mr." or "e.g.".
+/// It is a simple starting point, not a full implementation of the annex.
+///
+/// Sentences include their terminal punctuation. The whitespace between sentences is not
+/// included.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let sentences: std::io::Result<Vec<String>> = Decoder::new("Hello world! How are you?".bytes()).sentences().collect();
+/// assert_eq!(sentences.unwrap(), ["Hello world!", "How are you?"]);
+///
+/// let sentences: std::io::Result<Vec<String>> = Decoder::new("I met Dr. Smith. He said \"Hi.\" Then left".bytes()).sentences().collect();
+/// assert_eq!(sentences.unwrap(), ["I met Dr. Smith.", "He said \"Hi.\"", "Then left"]);
+/// ```
+///
+/// ## Errors
+/// Decoding errors are forwarded as soon as they occur, the sentence being decoded continues
+/// after the error.
+pub struct Sentences<I: Iterator<Item=Result<char>>> {
+	chars: I,
+	sentence: String,
+	after_terminator: bool
+}
+
+impl<I: Iterator<Item=Result<char>>> Sentences<I> {
+	pub(crate) fn new(chars: I) -> Sentences<I> {
+		Sentences {
+			chars,
+			sentence: String::new(),
+			after_terminator: false
+		}
+	}
+
+	/// Checks if the sentence being decoded ends with an abbreviation.
+	fn ends_with_abbreviation(&self) -> bool {
+		let word = self.sentence.rsplit(char::is_whitespace).next().unwrap_or("");
+		let word = word.trim_start_matches(|c: char| !c.is_alphanumeric()).to_lowercase();
+		ABBREVIATIONS.contains(&word.as_str())
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for Sentences<I> {
+	type Item = Result<String>;
+
+	fn next(&mut self) -> Option<Result<String>> {
+		loop {
+			match self.chars.next() {
+				Some(Ok(c)) if c.is_whitespace() => {
+					if self.after_terminator && !self.ends_with_abbreviation() {
+						self.after_terminator = false;
+						return Some(Ok(std::mem::take(&mut self.sentence)))
+					}
+
+					self.after_terminator = false;
+					if !self.sentence.is_empty() {
+						self.sentence.push(c)
+					}
+				},
+				Some(Ok(c)) => {
+					match c {
+						'.' | '!' | '?' => self.after_terminator = true,
+						')' | ']' | '"' | '\'' | '’' | '”' | '»' => (),
+						_ => self.after_terminator = false
+					}
+
+					self.sentence.push(c)
+				},
+				Some(Err(e)) => return Some(Err(e)),
+				None => {
+					let sentence = std::mem::take(&mut self.sentence);
+					let trimmed = sentence.trim_end();
+					return if trimmed.is_empty() {
+						None
+					} else {
+						Some(Ok(trimmed.to_string()))
+					}
+				}
+			}
+		}
+	}
+}
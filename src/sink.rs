@@ -0,0 +1,79 @@
+use crate::{DecodeError, Lossy};
+
+/// Destination of the errors reported by a [`WithErrorSink`](crate::WithErrorSink) decoder.
+pub trait ErrorSink {
+	/// Report an error, where `offset` is the position in the input of the first byte of the
+	/// faulty sequence.
+	fn report(&mut self, offset: usize, err: DecodeError);
+}
+
+impl<S: ErrorSink + ?Sized> ErrorSink for &mut S {
+	fn report(&mut self, offset: usize, err: DecodeError) {
+		(**self).report(offset, err)
+	}
+}
+
+impl ErrorSink for Vec<(usize, DecodeError)> {
+	fn report(&mut self, offset: usize, err: DecodeError) {
+		self.push((offset, err))
+	}
+}
+
+/// UTF-8 decoder iterator routing errors to an [`ErrorSink`](crate::ErrorSink).
+///
+/// Created by the [`Decoder::with_error_sink`](crate::Decoder::with_error_sink) method.
+/// Each maximal subpart of an ill-formed sequence is replaced by a
+/// [`char::REPLACEMENT_CHARACTER`], exactly like the [`Lossy`](crate::Lossy) decoder with the
+/// [`SkipMaximalSubpart`](crate::RecoveryStrategy::SkipMaximalSubpart) recovery strategy, and
+/// reported to the sink.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::{Decoder, DecodeError};
+/// let bytes = [0x61, 0xff, 0x62, 0xe2, 0x82];
+///
+/// let mut errors = Vec::new();
+/// let string: String = Decoder::new(bytes.iter().cloned()).with_error_sink(&mut errors).collect();
+/// assert_eq!(string, "a\u{fffd}b\u{fffd}");
+/// assert_eq!(errors, [
+///     (1, DecodeError::InvalidLeadByte { offset: 1, byte: 0xff }),
+///     (3, DecodeError::UnexpectedEof { offset: 3 })
+/// ]);
+/// ```
+pub struct WithErrorSink<R: Iterator<Item=u8>, S: ErrorSink> {
+	decoder: Lossy<R>,
+	sink: S
+}
+
+impl<R: Iterator<Item=u8>, S: ErrorSink> WithErrorSink<R, S> {
+	pub(crate) fn new(decoder: Lossy<R>, sink: S) -> WithErrorSink<R, S> {
+		WithErrorSink {
+			decoder,
+			sink
+		}
+	}
+
+	/// Returns a reference to the error sink.
+	pub fn sink(&self) -> &S {
+		&self.sink
+	}
+
+	/// Consumes the decoder, returning the error sink.
+	pub fn into_sink(self) -> S {
+		self.sink
+	}
+}
+
+impl<R: Iterator<Item=u8>, S: ErrorSink> Iterator for WithErrorSink<R, S> {
+	type Item = char;
+
+	fn next(&mut self) -> Option<char> {
+		match self.decoder.next_char()? {
+			Ok(c) => Some(c),
+			Err(e) => {
+				self.sink.report(self.decoder.start(), e);
+				Some(char::REPLACEMENT_CHARACTER)
+			}
+		}
+	}
+}
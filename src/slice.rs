@@ -0,0 +1,159 @@
+use std::io::{Result, Error, ErrorKind};
+use crate::decode;
+
+/// UTF-8 decoder iterator over a byte slice.
+///
+/// Contrarily to the [`Decoder`](crate::Decoder), the `SliceDecoder` knows its position in the
+/// input, both as a byte offset and as a character index, and can be moved around.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::SliceDecoder;
+/// let mut decoder = SliceDecoder::new("aé€😀".as_bytes());
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'a');
+/// assert_eq!(decoder.offset(), 1);
+/// assert_eq!(decoder.char_index(), 1);
+/// ```
+///
+/// ## Errors
+/// A call to [`next`](Iterator::next) returns an [`InvalidData`](std::io::ErrorKind::InvalidData)
+/// error if the input is not a valid UTF-8 sequence, or an
+/// [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof) if it ends before the end of a
+/// valid character.
+pub struct SliceDecoder<'a> {
+	bytes: &'a [u8],
+	offset: usize,
+	char_index: usize
+}
+
+impl<'a> SliceDecoder<'a> {
+	/// Creates a new `SliceDecoder` iterator positioned at the start of the given bytes.
+	pub fn new(bytes: &'a [u8]) -> SliceDecoder<'a> {
+		SliceDecoder {
+			bytes,
+			offset: 0,
+			char_index: 0
+		}
+	}
+
+	/// Creates a new `SliceDecoder` iterator positioned at the given byte offset, such as an
+	/// offset previously saved from [`offset`](SliceDecoder::offset).
+	///
+	/// The offset must be on a character boundary, meaning that it is either the length of the
+	/// input or the offset of a byte that is not a continuation byte. The
+	/// [`char_index`](SliceDecoder::char_index) is computed by counting the characters before
+	/// `offset`, assuming they are valid UTF-8.
+	///
+	/// ## Example
+	/// ```rust
+	/// # use utf8_decode::SliceDecoder;
+	/// let bytes = "aé€😀".as_bytes();
+	/// let mut decoder = SliceDecoder::from_offset(bytes, 3).unwrap();
+	/// assert_eq!(decoder.char_index(), 2);
+	/// assert_eq!(decoder.next().unwrap().unwrap(), '€');
+	///
+	/// // Offset in the middle of "€".
+	/// let error = SliceDecoder::from_offset(bytes, 4).err().unwrap();
+	/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+	/// assert_eq!(error.to_string(), "offset 4 is not on a character boundary.");
+	///
+	/// assert!(SliceDecoder::from_offset(bytes, bytes.len()).unwrap().next().is_none());
+	/// assert!(SliceDecoder::from_offset(bytes, bytes.len() + 1).is_err());
+	/// ```
+	///
+	/// ## Errors
+	/// Returns an [`InvalidInput`](std::io::ErrorKind::InvalidInput) error if `offset` is out of
+	/// bounds or is not on a character boundary.
+	pub fn from_offset(bytes: &'a [u8], offset: usize) -> Result<SliceDecoder<'a>> {
+		if offset > bytes.len() {
+			return Err(Error::new(ErrorKind::InvalidInput, format!("offset {} out of bounds.", offset)))
+		}
+
+		if offset < bytes.len() && is_continuation(bytes[offset]) {
+			return Err(Error::new(ErrorKind::InvalidInput, format!("offset {} is not on a character boundary.", offset)))
+		}
+
+		Ok(SliceDecoder {
+			bytes,
+			offset,
+			char_index: bytes[..offset].iter().filter(|&&b| !is_continuation(b)).count()
+		})
+	}
+
+	/// Byte offset of the next character to decode.
+	pub fn offset(&self) -> usize {
+		self.offset
+	}
+
+	/// Index of the next character to decode.
+	pub fn char_index(&self) -> usize {
+		self.char_index
+	}
+
+	/// Moves the cursor to the character of the given index.
+	///
+	/// Characters are decoded forward from the current position if `index` is ahead of it,
+	/// or from the start of the input otherwise. Seeking to the number of characters of the
+	/// input positions the cursor at the end.
+	///
+	/// ## Example
+	/// ```rust
+	/// # use utf8_decode::SliceDecoder;
+	/// let mut decoder = SliceDecoder::new("aé€😀b".as_bytes());
+	/// decoder.seek_to_char(3).unwrap();
+	/// assert_eq!(decoder.offset(), 6);
+	/// assert_eq!(decoder.next().unwrap().unwrap(), '😀');
+	///
+	/// decoder.seek_to_char(1).unwrap();
+	/// assert_eq!(decoder.next().unwrap().unwrap(), 'é');
+	///
+	/// assert!(decoder.seek_to_char(6).is_err());
+	/// assert_eq!(decoder.next().unwrap().unwrap(), '€');
+	/// ```
+	///
+	/// ## Errors
+	/// Returns an [`InvalidInput`](std::io::ErrorKind::InvalidInput) error if `index` exceeds the
+	/// number of characters of the input, or the first decoding error encountered before
+	/// reaching it. The cursor is left unchanged in case of error.
+	pub fn seek_to_char(&mut self, index: usize) -> Result<()> {
+		let (offset, char_index) = (self.offset, self.char_index);
+		if index < self.char_index {
+			self.offset = 0;
+			self.char_index = 0;
+		}
+
+		while self.char_index < index {
+			let e = match self.next() {
+				Some(Ok(_)) => continue,
+				Some(Err(e)) => e,
+				None => Error::new(ErrorKind::InvalidInput, "character index out of bounds.")
+			};
+
+			self.offset = offset;
+			self.char_index = char_index;
+			return Err(e)
+		}
+
+		Ok(())
+	}
+}
+
+impl<'a> Iterator for SliceDecoder<'a> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		let mut bytes = self.bytes[self.offset..].iter().cloned();
+		let c = decode(&mut bytes)?;
+		self.offset = self.bytes.len() - bytes.len();
+		if c.is_ok() {
+			self.char_index += 1
+		}
+
+		Some(c)
+	}
+}
+
+/// Checks if the given byte is a continuation byte (`10xxxxxx`).
+fn is_continuation(b: u8) -> bool {
+	b & 0xC0 == 0x80
+}
@@ -0,0 +1,309 @@
+use std::io::{Result, ErrorKind};
+use std::ops::Deref;
+use crate::{decode, Decoder, OffsetError};
+use crate::lead::skip_invalid;
+
+/// Maximum length of a UTF-8 encoded character.
+const MAX_CHAR_LEN: usize = 4;
+
+/// Source bytes of a decoded character.
+///
+/// Yielded by the [`WithSourceBytes`](crate::WithSourceBytes) iterator, this holds the 1 to 4
+/// bytes of the encoding of a character, without allocation. It dereferences to a `[u8]` slice.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct SourceBytes {
+	bytes: [u8; MAX_CHAR_LEN],
+	len: usize
+}
+
+impl SourceBytes {
+	/// Returns the bytes as a slice.
+	pub fn as_slice(&self) -> &[u8] {
+		&self.bytes[..self.len]
+	}
+}
+
+impl Deref for SourceBytes {
+	type Target = [u8];
+
+	fn deref(&self) -> &[u8] {
+		self.as_slice()
+	}
+}
+
+impl AsRef<[u8]> for SourceBytes {
+	fn as_ref(&self) -> &[u8] {
+		self.as_slice()
+	}
+}
+
+/// UTF-8 decoder iterator yielding each character along with its source bytes.
+///
+/// Created by the [`Decoder::with_source_bytes`](crate::Decoder::with_source_bytes) method.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let mut decoder = Decoder::new("aé€😀".bytes()).with_source_bytes();
+/// let (c, bytes) = decoder.next().unwrap().unwrap();
+/// assert_eq!((c, &*bytes), ('a', &[0x61][..]));
+/// let (c, bytes) = decoder.next().unwrap().unwrap();
+/// assert_eq!((c, &*bytes), ('é', &[0xc3, 0xa9][..]));
+/// let (c, bytes) = decoder.next().unwrap().unwrap();
+/// assert_eq!((c, &*bytes), ('€', &[0xe2, 0x82, 0xac][..]));
+/// let (c, bytes) = decoder.next().unwrap().unwrap();
+/// assert_eq!((c, bytes.as_slice()), ('😀', &[0xf0, 0x9f, 0x98, 0x80][..]));
+/// assert!(decoder.next().is_none());
+///
+/// // Concatenating the source bytes gives back the input.
+/// let input = "round-trip: ∀x ∈ ℝ".as_bytes();
+/// let mut output = Vec::new();
+/// for c in Decoder::new(input.iter().cloned()).with_source_bytes() {
+///     output.extend_from_slice(&c.unwrap().1)
+/// }
+/// assert_eq!(output, input);
+/// ```
+///
+/// ## Errors
+/// Decoding errors are the same as the [`Decoder`](crate::Decoder) errors. The source bytes of
+/// an invalid sequence do not include the byte that ended it, decoded next, unless it is a
+/// continuation byte, nor the bytes skipped after it.
+///
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let mut decoder = Decoder::new(b"\xe2\x82a\xff\x80b".iter().cloned()).with_source_bytes();
+/// assert!(decoder.next().unwrap().is_err());
+/// let (c, bytes) = decoder.next().unwrap().unwrap();
+/// assert_eq!((c, &*bytes), ('a', &[0x61][..]));
+/// assert!(decoder.next().unwrap().is_err());
+/// let (c, bytes) = decoder.next().unwrap().unwrap();
+/// assert_eq!((c, &*bytes), ('b', &[0x62][..]));
+/// ```
+pub struct WithSourceBytes<R: Iterator<Item=u8>> {
+	bytes: R,
+	pending: Option<u8>,
+	offset: usize
+}
+
+impl<R: Iterator<Item=u8>> WithSourceBytes<R> {
+	pub(crate) fn new(bytes: R, pending: Option<u8>, offset: usize) -> WithSourceBytes<R> {
+		WithSourceBytes {
+			bytes,
+			pending,
+			offset
+		}
+	}
+}
+
+impl<R: Iterator<Item=u8>> Iterator for WithSourceBytes<R> {
+	type Item = Result<(char, SourceBytes)>;
+
+	fn next(&mut self) -> Option<Result<(char, SourceBytes)>> {
+		let mut source = SourceBytes {
+			bytes: [0; MAX_CHAR_LEN],
+			len: 0
+		};
+
+		let c = decode(&mut Recorded {
+			bytes: &mut self.pending.take().into_iter().chain(&mut self.bytes),
+			source: &mut source
+		})?;
+
+		let offset = self.offset;
+		Some(match c {
+			Ok(c) => {
+				self.offset += source.len;
+				Ok((c, source))
+			},
+			Err(e) => {
+				let pending = push_back(&mut source);
+				self.offset += source.len;
+				// Skip the rest of the invalid sequence.
+				self.pending = skip_invalid(&mut self.bytes, pending, &mut self.offset);
+				Err(OffsetError::new(offset, e).into())
+			}
+		})
+	}
+}
+
+/// UTF-8 decoder iterator over an input frame that may end in the middle of a character.
+///
+/// Created by the [`Decoder::frame_aware`](crate::Decoder::frame_aware) method.
+/// Instead of returning an [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof) error when the
+/// input ends in the middle of a character, the iterator ends, and the bytes of the incomplete
+/// character are kept and exposed by the [`pending`](FrameAware::pending) method. They can then
+/// be prepended to the next frame.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let frames: [&[u8]; 3] = [b"a\xc3\xa9\xe2\x82", b"\xac\xf0\x9f", b"\x98\x80b"];
+/// let mut pending = Vec::new();
+/// let mut string = String::new();
+/// for frame in &frames {
+///     let bytes: Vec<u8> = pending.iter().chain(frame.iter()).cloned().collect();
+///     let mut decoder = Decoder::new(bytes.into_iter()).frame_aware();
+///     for c in &mut decoder {
+///         string.push(c.unwrap())
+///     }
+///     pending = decoder.pending().to_vec();
+/// }
+/// assert_eq!(string, "aé€😀b");
+/// assert!(pending.is_empty());
+///
+/// let mut decoder = Decoder::new(frames[0].iter().cloned()).frame_aware();
+/// assert_eq!(decoder.by_ref().collect::<std::io::Result<String>>().unwrap(), "aé");
+/// assert_eq!(decoder.pending(), [0xe2, 0x82]);
+/// assert!(decoder.next().is_none());
+/// assert_eq!(decoder.pending(), [0xe2, 0x82]);
+/// ```
+///
+/// ## Errors
+/// Other decoding errors are the same as the [`Decoder`](crate::Decoder) errors. In particular,
+/// decoding resumes at the next byte that may start a sequence, which may be the byte ending the
+/// invalid sequence.
+///
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let mut decoder = Decoder::new(b"\xe2a\xc3".iter().cloned()).frame_aware();
+/// assert!(decoder.next().unwrap().is_err());
+/// assert_eq!(decoder.next().unwrap().unwrap(), 'a');
+/// assert!(decoder.next().is_none());
+/// assert_eq!(decoder.pending(), [0xc3]);
+/// ```
+pub struct FrameAware<R: Iterator<Item=u8>> {
+	bytes: R,
+	next: Option<u8>,
+	offset: usize,
+	pending: SourceBytes
+}
+
+impl<R: Iterator<Item=u8>> FrameAware<R> {
+	pub(crate) fn new(bytes: R, next: Option<u8>, offset: usize) -> FrameAware<R> {
+		FrameAware {
+			bytes,
+			next,
+			offset,
+			pending: SourceBytes {
+				bytes: [0; MAX_CHAR_LEN],
+				len: 0
+			}
+		}
+	}
+
+	/// Returns the bytes of the incomplete character ending the input.
+	///
+	/// This is empty until the iterator ends, or if the input ends on a character boundary.
+	pub fn pending(&self) -> &[u8] {
+		self.pending.as_slice()
+	}
+}
+
+impl<R: Iterator<Item=u8>> Iterator for FrameAware<R> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		let mut source = SourceBytes {
+			bytes: [0; MAX_CHAR_LEN],
+			len: 0
+		};
+
+		let c = decode(&mut Recorded {
+			bytes: &mut self.next.take().into_iter().chain(&mut self.bytes),
+			source: &mut source
+		})?;
+
+		let offset = self.offset;
+		match c {
+			Ok(c) => {
+				self.offset += source.len;
+				Some(Ok(c))
+			},
+			Err(e) if e.kind() == ErrorKind::UnexpectedEof => {
+				self.offset += source.len;
+				self.pending = source;
+				None
+			},
+			Err(e) => {
+				let next = push_back(&mut source);
+				self.offset += source.len;
+				// Skip the rest of the invalid sequence.
+				self.next = skip_invalid(&mut self.bytes, next, &mut self.offset);
+				Some(Err(OffsetError::new(offset, e).into()))
+			}
+		}
+	}
+}
+
+/// Removes the byte that ended the invalid sequence of the given source bytes, if it may start a
+/// new sequence, and returns it to be decoded next.
+fn push_back(source: &mut SourceBytes) -> Option<u8> {
+	let last = source.bytes[source.len - 1];
+	if source.len > 1 && last & 0xC0 != 0x80 {
+		source.len -= 1;
+		Some(last)
+	} else {
+		None
+	}
+}
+
+/// Byte iterator recording the bytes pulled out of the underlying iterator.
+struct Recorded<'a, R> {
+	bytes: &'a mut R,
+	source: &'a mut SourceBytes
+}
+
+impl<'a, R: Iterator<Item=u8>> Iterator for Recorded<'a, R> {
+	type Item = u8;
+
+	fn next(&mut self) -> Option<u8> {
+		let b = self.bytes.next()?;
+		self.source.bytes[self.source.len] = b;
+		self.source.len += 1;
+		Some(b)
+	}
+}
+
+/// UTF-8 decoder iterator yielding each character along with the length of its encoding.
+///
+/// Created by the [`Decoder::with_lengths`](crate::Decoder::with_lengths) method.
+/// The length is the number of bytes of the input encoding the character, between 1 and 4. It
+/// is the length the decoder just consumed, so computing it does not require re-encoding the
+/// character.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let bytes = "a😀é€b😀".as_bytes();
+/// let lengths: Vec<(char, usize)> = Decoder::new(bytes.iter().cloned()).with_lengths().map(Result::unwrap).collect();
+/// assert_eq!(lengths, [('a', 1), ('😀', 4), ('é', 2), ('€', 3), ('b', 1), ('😀', 4)]);
+/// assert_eq!(lengths.iter().map(|&(_, len)| len).sum::<usize>(), bytes.len());
+/// ```
+///
+/// ## Errors
+/// Decoding errors are the same as the [`Decoder`](crate::Decoder) errors.
+pub struct WithLengths<R: Iterator<Item=u8>> {
+	decoder: Decoder<R>
+}
+
+impl<R: Iterator<Item=u8>> WithLengths<R> {
+	pub(crate) fn new(decoder: Decoder<R>) -> WithLengths<R> {
+		WithLengths {
+			decoder
+		}
+	}
+}
+
+impl<R: Iterator<Item=u8>> Iterator for WithLengths<R> {
+	type Item = Result<(char, usize)>;
+
+	fn next(&mut self) -> Option<Result<(char, usize)>> {
+		let offset = self.decoder.offset();
+		let c = self.decoder.next()?;
+		Some(c.map(|c| (c, self.decoder.offset() - offset)))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.decoder.size_hint()
+	}
+}
@@ -0,0 +1,59 @@
+use std::io::Result;
+use crate::Decoder;
+
+/// UTF-8 decoder iterator collecting statistics about the decoded sequences.
+///
+/// Created by the [`Decoder::with_stats`](crate::Decoder::with_stats) method.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::Decoder;
+/// let mut decoder = Decoder::new("Hello".bytes()).with_stats();
+/// (&mut decoder).for_each(drop);
+/// assert_eq!(decoder.length_mask(), 0b0001);
+///
+/// let mut decoder = Decoder::new("aé€😀".bytes()).with_stats();
+/// (&mut decoder).for_each(drop);
+/// assert_eq!(decoder.length_mask(), 0b1111);
+///
+/// let mut decoder = Decoder::new("a€".bytes()).with_stats();
+/// (&mut decoder).for_each(drop);
+/// assert_eq!(decoder.length_mask(), 0b0101);
+/// ```
+pub struct WithStats<R: Iterator<Item=u8>> {
+	decoder: Decoder<R>,
+	length_mask: u8
+}
+
+impl<R: Iterator<Item=u8>> WithStats<R> {
+	pub(crate) fn new(decoder: Decoder<R>) -> WithStats<R> {
+		WithStats {
+			decoder,
+			length_mask: 0
+		}
+	}
+
+	/// Returns the set of the lengths of the sequences decoded so far, as a bitmask.
+	///
+	/// Bit `i - 1` is set if a character encoded on `i` bytes has been decoded.
+	/// For instance `0b0001` means that only ASCII characters have been decoded, and `0b1111`
+	/// that all the sequence lengths have been encountered.
+	pub fn length_mask(&self) -> u8 {
+		self.length_mask
+	}
+}
+
+impl<R: Iterator<Item=u8>> Iterator for WithStats<R> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		let start = self.decoder.offset();
+		let c = self.decoder.next()?;
+		if c.is_ok() {
+			let len = self.decoder.offset() - start;
+			self.length_mask |= 1 << (len - 1)
+		}
+
+		Some(c)
+	}
+}
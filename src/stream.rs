@@ -0,0 +1,449 @@
+use std::io::{Result, Error, ErrorKind};
+use crate::{decode, OffsetError};
+use crate::lead::is_lead;
+
+/// Maximum length of a UTF-8 encoded character.
+const MAX_CHAR_LEN: usize = 4;
+
+/// Behavior of a [`StreamDecoder`] when a byte order mark (U+FEFF) starts a chunk other than the
+/// first.
+///
+/// Such a mark is most likely the start of another stream, accidentally concatenated to the
+/// first one. A byte order mark at the very start of the stream is always kept.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum BomPolicy {
+	/// Decode the mark as any other character.
+	Keep,
+
+	/// Remove the mark from the output.
+	Strip,
+
+	/// Return an [`InvalidData`](std::io::ErrorKind::InvalidData) error.
+	Error
+}
+
+/// UTF-8 decoder of an input received in chunks.
+///
+/// Each chunk is decoded as it is received with [`decode_chunk`](StreamDecoder::decode_chunk).
+/// A character split across chunks is buffered until the chunk completing it is received.
+/// The [`finish`](StreamDecoder::finish) method must be called at the end of the stream to check
+/// that no partial character is left.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::{StreamDecoder, BomPolicy};
+/// let chunks: [&[u8]; 3] = [b"a\xc3", b"\xa9\xe2\x82", b"\xac"];
+/// let mut decoder = StreamDecoder::new();
+/// let mut string = String::new();
+/// for chunk in &chunks {
+///     string.push_str(&decoder.decode_chunk(chunk).unwrap());
+/// }
+/// decoder.finish().unwrap();
+/// assert_eq!(string, "aé€");
+///
+/// // The second chunk starts with a byte order mark.
+/// let chunks: [&[u8]; 2] = [b"\xef\xbb\xbfa", b"\xef\xbb\xbfb"];
+/// let mut decoder = StreamDecoder::new();
+/// assert_eq!(decoder.decode_chunk(chunks[0]).unwrap(), "\u{feff}a");
+/// assert_eq!(decoder.decode_chunk(chunks[1]).unwrap(), "\u{feff}b");
+///
+/// let mut decoder = StreamDecoder::new().with_bom_policy(BomPolicy::Strip);
+/// assert_eq!(decoder.decode_chunk(chunks[0]).unwrap(), "\u{feff}a");
+/// assert_eq!(decoder.decode_chunk(chunks[1]).unwrap(), "b");
+///
+/// let mut decoder = StreamDecoder::new().with_bom_policy(BomPolicy::Error);
+/// assert_eq!(decoder.decode_chunk(chunks[0]).unwrap(), "\u{feff}a");
+/// let error = decoder.decode_chunk(chunks[1]).unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+/// assert_eq!(error.to_string(), "byte order mark at the start of a chunk, at byte 4.");
+///
+/// // The byte order mark is split across the second and third chunks.
+/// let chunks: [&[u8]; 3] = [b"a", b"\xef\xbb", b"\xbfb"];
+/// let mut decoder = StreamDecoder::new().with_bom_policy(BomPolicy::Strip);
+/// let string: String = chunks.iter().map(|chunk| decoder.decode_chunk(chunk).unwrap()).collect();
+/// assert_eq!(string, "ab");
+///
+/// let mut decoder = StreamDecoder::new().with_bom_policy(BomPolicy::Error);
+/// assert_eq!(decoder.decode_chunk(chunks[0]).unwrap(), "a");
+/// assert_eq!(decoder.decode_chunk(chunks[1]).unwrap(), "");
+/// let error = decoder.decode_chunk(chunks[2]).unwrap_err();
+/// assert_eq!(error.to_string(), "byte order mark at the start of a chunk, at byte 1.");
+/// ```
+///
+/// ## Errors
+/// [`decode_chunk`](StreamDecoder::decode_chunk) returns an
+/// [`InvalidData`](std::io::ErrorKind::InvalidData) error if the input is not a valid UTF-8
+/// sequence, or if a chunk starts with a byte order mark under [`BomPolicy::Error`].
+/// Decoding errors wrap an [`OffsetError`](crate::OffsetError) giving the offset of the first
+/// byte of the faulty sequence in the stream.
+/// [`finish`](StreamDecoder::finish) returns an
+/// [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof) error if the stream ends before the end
+/// of a valid character.
+///
+/// After an error, the decoder is poisoned: the rest of the chunk is not decoded, and any
+/// further call to [`decode_chunk`](StreamDecoder::decode_chunk) or
+/// [`finish`](StreamDecoder::finish) returns an
+/// [`InvalidInput`](std::io::ErrorKind::InvalidInput) error.
+///
+/// ```rust
+/// # use utf8_decode::StreamDecoder;
+/// let mut decoder = StreamDecoder::new();
+/// assert_eq!(decoder.decode_chunk(b"ab").unwrap(), "ab");
+/// let error = decoder.decode_chunk(b"c\xe2\x82d").unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+/// assert_eq!(error.to_string(), "invalid UTF-8 sequence at byte 3.");
+///
+/// assert_eq!(decoder.decode_chunk(b"e").unwrap_err().kind(), std::io::ErrorKind::InvalidInput);
+/// assert_eq!(decoder.finish().unwrap_err().kind(), std::io::ErrorKind::InvalidInput);
+///
+/// // Split invalid sequence.
+/// let mut decoder = StreamDecoder::new();
+/// assert_eq!(decoder.decode_chunk(b"ab\xe2").unwrap(), "ab");
+/// let error = decoder.decode_chunk(b"\x82d").unwrap_err();
+/// assert_eq!(error.to_string(), "invalid UTF-8 sequence at byte 2.");
+/// ```
+pub struct StreamDecoder {
+	pending: Vec<u8>,
+	pending_starts_chunk: bool,
+	offset: usize,
+	bom_policy: BomPolicy,
+	poisoned: bool
+}
+
+impl StreamDecoder {
+	/// Creates a new `StreamDecoder`, keeping byte order marks.
+	pub fn new() -> StreamDecoder {
+		StreamDecoder {
+			pending: Vec::with_capacity(MAX_CHAR_LEN),
+			pending_starts_chunk: false,
+			offset: 0,
+			bom_policy: BomPolicy::Keep,
+			poisoned: false
+		}
+	}
+
+	/// Sets the behavior when a byte order mark starts a chunk other than the first.
+	pub fn with_bom_policy(mut self, policy: BomPolicy) -> StreamDecoder {
+		self.bom_policy = policy;
+		self
+	}
+
+	/// Decodes the given chunk, returning the characters completed by this chunk.
+	pub fn decode_chunk(&mut self, chunk: &[u8]) -> Result<String> {
+		let mut string = String::with_capacity(chunk.len());
+		self.decode_into(chunk, &mut string)?;
+		Ok(string)
+	}
+
+	/// Decodes the given chunk, appending the characters completed by this chunk to `string`.
+	///
+	/// Poisons the decoder on error.
+	pub(crate) fn decode_into(&mut self, chunk: &[u8], string: &mut String) -> Result<()> {
+		self.check_poisoned()?;
+		let result = self.try_decode_into(chunk, string);
+		if result.is_err() {
+			self.poisoned = true;
+			self.pending.clear()
+		}
+
+		result
+	}
+
+	fn try_decode_into(&mut self, chunk: &[u8], string: &mut String) -> Result<()> {
+		let mut start = 0;
+		if !self.pending.is_empty() {
+			let pending_len = self.pending.len();
+			let offset = self.offset - pending_len;
+			let take = chunk.len().min(MAX_CHAR_LEN - pending_len);
+			self.pending.extend_from_slice(&chunk[..take]);
+			let mut bytes = self.pending.iter().cloned();
+			match decode(&mut bytes) {
+				Some(Ok('\u{feff}')) if self.pending_starts_chunk => self.push_bom(offset, string)?,
+				Some(Ok(c)) => string.push(c),
+				Some(Err(e)) if e.kind() == ErrorKind::UnexpectedEof => {
+					self.offset += chunk.len();
+					return Ok(())
+				},
+				Some(Err(e)) => return Err(OffsetError::new(offset, e).into()),
+				None => unreachable!()
+			}
+
+			start = self.pending.len() - bytes.len() - pending_len;
+			self.pending.clear();
+			self.offset += start
+		}
+
+		while start < chunk.len() {
+			let mut bytes = chunk[start..].iter().cloned();
+			match decode(&mut bytes) {
+				Some(Ok('\u{feff}')) if start == 0 && self.offset > 0 => self.push_bom(self.offset, string)?,
+				Some(Ok(c)) => string.push(c),
+				Some(Err(e)) if e.kind() == ErrorKind::UnexpectedEof => {
+					self.pending.extend_from_slice(&chunk[start..]);
+					self.pending_starts_chunk = start == 0 && self.offset > 0;
+					self.offset += chunk.len() - start;
+					return Ok(())
+				},
+				Some(Err(e)) => return Err(OffsetError::new(self.offset, e).into()),
+				None => unreachable!()
+			}
+
+			let end = chunk.len() - bytes.len();
+			self.offset += end - start;
+			start = end
+		}
+
+		Ok(())
+	}
+
+	/// Applies the byte order mark policy to a mark starting a chunk at `offset`.
+	fn push_bom(&self, offset: usize, string: &mut String) -> Result<()> {
+		match self.bom_policy {
+			BomPolicy::Keep => string.push('\u{feff}'),
+			BomPolicy::Strip => (),
+			BomPolicy::Error => {
+				return Err(Error::new(ErrorKind::InvalidData, format!("byte order mark at the start of a chunk, at byte {}.", offset)))
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Returns an error if the decoder is poisoned.
+	fn check_poisoned(&self) -> Result<()> {
+		if self.poisoned {
+			Err(Error::new(ErrorKind::InvalidInput, "stream decoder used after an error."))
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Ends the stream, checking that no partial character is left.
+	pub fn finish(&mut self) -> Result<()> {
+		self.check_poisoned()?;
+		if self.pending.is_empty() {
+			Ok(())
+		} else {
+			self.pending.clear();
+			Err(Error::new(ErrorKind::UnexpectedEof, "unexpected end of UTF-8 sequence."))
+		}
+	}
+}
+
+impl Default for StreamDecoder {
+	fn default() -> StreamDecoder {
+		StreamDecoder::new()
+	}
+}
+
+/// String builder decoding an input received in chunks.
+///
+/// This is a [`StreamDecoder`] accumulating its output into a single string.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::{StringBuilder, BomPolicy};
+/// let mut builder = StringBuilder::new().with_bom_policy(BomPolicy::Strip);
+/// builder.push(b"a\xc3").unwrap();
+/// builder.push(b"\xa9").unwrap();
+/// // Second stream accidentally concatenated to the first.
+/// builder.push(b"\xef\xbb\xbfb").unwrap();
+/// assert_eq!(builder.finish().unwrap(), "aéb");
+///
+/// let mut builder = StringBuilder::new().with_bom_policy(BomPolicy::Error);
+/// builder.push(b"a").unwrap();
+/// assert!(builder.push(b"\xef\xbb\xbfb").is_err());
+///
+/// let mut builder = StringBuilder::new();
+/// builder.push(b"a\xc3").unwrap();
+/// assert_eq!(builder.finish().unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+/// ```
+///
+/// ## Errors
+/// Errors are the same as the [`StreamDecoder`] errors.
+pub struct StringBuilder {
+	decoder: StreamDecoder,
+	string: String
+}
+
+impl StringBuilder {
+	/// Creates a new empty `StringBuilder`, keeping byte order marks.
+	pub fn new() -> StringBuilder {
+		StringBuilder {
+			decoder: StreamDecoder::new(),
+			string: String::new()
+		}
+	}
+
+	/// Sets the behavior when a byte order mark starts a chunk other than the first.
+	pub fn with_bom_policy(mut self, policy: BomPolicy) -> StringBuilder {
+		self.decoder = self.decoder.with_bom_policy(policy);
+		self
+	}
+
+	/// Decodes the given chunk and appends its completed characters to the string.
+	pub fn push(&mut self, chunk: &[u8]) -> Result<()> {
+		self.decoder.decode_into(chunk, &mut self.string)
+	}
+
+	/// Returns the characters decoded so far.
+	pub fn as_str(&self) -> &str {
+		&self.string
+	}
+
+	/// Ends the stream and returns the decoded string.
+	pub fn finish(mut self) -> Result<String> {
+		self.decoder.finish()?;
+		Ok(self.string)
+	}
+}
+
+impl Default for StringBuilder {
+	fn default() -> StringBuilder {
+		StringBuilder::new()
+	}
+}
+
+/// Push-based UTF-8 decoder, receiving the input one byte at a time.
+///
+/// Each byte is given to the [`push`](StreamingDecoder::push) method, returning the decoded
+/// character once its last byte is received. The bytes of an incomplete character are buffered
+/// between calls. The [`finish`](StreamingDecoder::finish) method must be called at the end of
+/// the input to check that no partial character is left.
+///
+/// Bytes are decoded like the [`Decoder`](crate::Decoder) iterator would. In particular, the
+/// byte that made a sequence invalid may start the next sequence: since
+/// [`push`](StreamingDecoder::push) returns at most one item, it is then buffered, and the
+/// character it encodes is returned by the next call to [`push`](StreamingDecoder::push) or
+/// [`finish`](StreamingDecoder::finish), one byte late.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::StreamingDecoder;
+/// let mut decoder = StreamingDecoder::new();
+/// let mut string = String::new();
+/// for &b in "aé€😀".as_bytes() {
+///     if let Some(c) = decoder.push(b) {
+///         string.push(c.unwrap())
+///     }
+/// }
+/// assert!(decoder.finish().is_none());
+/// assert_eq!(string, "aé€😀");
+///
+/// // Partial character.
+/// assert!(decoder.push(0xe2).is_none());
+/// assert!(decoder.push(0x82).is_none());
+/// let error = decoder.finish().unwrap().unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+/// assert_eq!(error.to_string(), "unexpected end of UTF-8 sequence at byte 10.");
+///
+/// // Invalid sequence, ended by "A", returned by the next call.
+/// assert!(decoder.push(0xc3).is_none());
+/// let error = decoder.push(0x41).unwrap().unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+/// assert_eq!(error.to_string(), "invalid UTF-8 sequence at byte 12.");
+/// assert_eq!(decoder.push(0x42).unwrap().unwrap(), 'A');
+/// assert_eq!(decoder.finish().unwrap().unwrap(), 'B');
+/// assert!(decoder.finish().is_none());
+/// ```
+///
+/// ## Errors
+/// [`push`](StreamingDecoder::push) returns an [`InvalidData`](std::io::ErrorKind::InvalidData)
+/// error if the input is not a valid UTF-8 sequence.
+/// [`finish`](StreamingDecoder::finish) returns an
+/// [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof) error if the input ends before the end of
+/// a valid character.
+/// Errors wrap an [`OffsetError`](crate::OffsetError) giving the offset of the first byte of the
+/// faulty sequence.
+///
+/// After an error, the bytes up to the next one that may start a sequence are skipped.
+///
+/// ```rust
+/// # use utf8_decode::StreamingDecoder;
+/// let mut decoder = StreamingDecoder::new();
+/// let items: Vec<_> = b"\xe2\x82\xc3\xa9\xff\x80\x80a".iter().filter_map(|&b| decoder.push(b)).map(Result::ok).collect();
+/// assert_eq!(items, [None, Some('é'), None, Some('a')]);
+/// assert!(decoder.finish().is_none());
+/// ```
+pub struct StreamingDecoder {
+	pending: [u8; MAX_CHAR_LEN],
+	len: usize,
+	resync: bool,
+	offset: usize
+}
+
+impl StreamingDecoder {
+	/// Creates a new `StreamingDecoder`.
+	pub fn new() -> StreamingDecoder {
+		StreamingDecoder {
+			pending: [0; MAX_CHAR_LEN],
+			len: 0,
+			resync: false,
+			offset: 0
+		}
+	}
+
+	/// Decodes the given byte.
+	///
+	/// Returns the decoded character (or error) if the byte completes a sequence, or `None` if
+	/// more bytes are needed.
+	pub fn push(&mut self, byte: u8) -> Option<Result<char>> {
+		if self.resync {
+			if !is_lead(byte) {
+				self.offset += 1;
+				return None
+			}
+
+			self.resync = false
+		}
+
+		self.pending[self.len] = byte;
+		self.len += 1;
+		self.decode_pending(false)
+	}
+
+	/// Ends the input.
+	///
+	/// Returns an error if a partial character is left, the last character if its byte ended an
+	/// invalid sequence, or `None` otherwise.
+	pub fn finish(&mut self) -> Option<Result<char>> {
+		self.resync = false;
+		self.decode_pending(true)
+	}
+
+	/// Decodes the first sequence of the buffered bytes, if complete or if the input ended.
+	fn decode_pending(&mut self, end: bool) -> Option<Result<char>> {
+		let mut bytes = self.pending[..self.len].iter();
+		let c = decode(&mut bytes.by_ref().cloned())?;
+		let mut len = self.len - bytes.len();
+		match c {
+			Err(e) if e.kind() == ErrorKind::UnexpectedEof && !end => None,
+			c => {
+				let start = self.offset;
+				if c.is_err() {
+					if len > 1 && is_lead(self.pending[len - 1]) {
+						// The byte ending the invalid sequence starts a new one.
+						len -= 1
+					}
+
+					// Skip the rest of the invalid sequence.
+					while len < self.len && !is_lead(self.pending[len]) {
+						len += 1
+					}
+					self.resync = len == self.len
+				}
+
+				self.pending.copy_within(len..self.len, 0);
+				self.len -= len;
+				self.offset += len;
+				Some(c.map_err(|e| OffsetError::new(start, e).into()))
+			}
+		}
+	}
+}
+
+impl Default for StreamingDecoder {
+	fn default() -> StreamingDecoder {
+		StreamingDecoder::new()
+	}
+}
@@ -0,0 +1,446 @@
+use std::io::{Result, Error, ErrorKind, Read, BufReader};
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::Rc;
+use crate::{Decoder, UnsafeDecoder};
+
+/// Byte order marks of the UTF-16 and UTF-32 encodings.
+/// UTF-32 marks come first since the UTF-32LE mark starts with the UTF-16LE mark.
+const FOREIGN_BOMS: [(&[u8], &str); 4] = [
+	(&[0xFF, 0xFE, 0x00, 0x00], "UTF-32LE"),
+	(&[0x00, 0x00, 0xFE, 0xFF], "UTF-32BE"),
+	(&[0xFF, 0xFE], "UTF-16LE"),
+	(&[0xFE, 0xFF], "UTF-16BE")
+];
+
+/// Decodes the given bytes into a string.
+///
+/// This is [`decode_slice`], after checking that the input does not start with a foreign byte
+/// order mark.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::decode_str;
+/// assert_eq!(decode_str("Hello World! 🌍".as_bytes()).unwrap(), "Hello World! 🌍");
+///
+/// // UTF-16LE and UTF-32BE encodings of "hi", with a byte order mark.
+/// let error = decode_str(&[0xff, 0xfe, 0x68, 0x00, 0x69, 0x00]).unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+/// assert_eq!(error.to_string(), "input appears to be UTF-16LE, not UTF-8.");
+///
+/// let error = decode_str(&[0x00, 0x00, 0xfe, 0xff, 0x00, 0x00, 0x00, 0x68, 0x00, 0x00, 0x00, 0x69]).unwrap_err();
+/// assert_eq!(error.to_string(), "input appears to be UTF-32BE, not UTF-8.");
+/// ```
+///
+/// ## Errors
+/// Returns an [`InvalidData`](std::io::ErrorKind::InvalidData) error naming the encoding if the
+/// input starts with a UTF-16 or UTF-32 byte order mark, since this is most likely an encoding
+/// mismatch. Otherwise returns the errors of [`decode_slice`].
+pub fn decode_str(bytes: &[u8]) -> Result<String> {
+	check_foreign_bom(bytes)?;
+	decode_slice(bytes)
+}
+
+/// Decodes the whole given byte slice into a string.
+///
+/// Unlike [`decode_str`], this does not look for a foreign byte order mark: this is exactly
+/// collecting a [`Decoder`](crate::Decoder), into a string allocated once with the length of the
+/// input.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::decode_slice;
+/// assert_eq!(decode_slice("aé€😀".as_bytes()).unwrap(), "aé€😀");
+/// assert_eq!(decode_slice(b"").unwrap(), "");
+///
+/// let error = decode_slice(b"ab\xffcd").unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+/// assert_eq!(error.to_string(), "invalid lead byte 0xFF in UTF-8 sequence at byte 2.");
+///
+/// let error = decode_slice(b"ab\xe2\x82").unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+/// assert_eq!(error.to_string(), "unexpected end of UTF-8 sequence at byte 2.");
+/// ```
+///
+/// ## Errors
+/// Returns the first decoding error encountered.
+pub fn decode_slice(bytes: &[u8]) -> Result<String> {
+	let mut string = String::with_capacity(bytes.len());
+	for c in Decoder::new(bytes.iter().cloned()) {
+		string.push(c?)
+	}
+
+	Ok(string)
+}
+
+/// Decodes the whole content of the given reader into a string.
+///
+/// This is collecting an [`UnsafeDecoder`](crate::UnsafeDecoder) reading the bytes of `reader`
+/// through a [`BufReader`](std::io::BufReader).
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::decode_reader;
+/// assert_eq!(decode_reader("aé€😀".as_bytes()).unwrap(), "aé€😀");
+///
+/// let error = decode_reader(&b"ab\xffcd"[..]).unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+///
+/// let error = decode_reader(&b"ab\xe2\x82"[..]).unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+/// ```
+///
+/// ## Errors
+/// Returns the first decoding or reading error encountered.
+pub fn decode_reader<R: Read>(reader: R) -> Result<String> {
+	UnsafeDecoder::new(BufReader::new(reader).bytes()).collect()
+}
+
+/// Decodes the given bytes into a string of at most `max_bytes` bytes.
+///
+/// This is a guard against memory exhaustion when decoding untrusted input: the output is
+/// allocated once, with a capacity of at most `max_bytes`, and decoding stops as soon as the
+/// next character would not fit.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::decode_str_capped;
+/// assert_eq!(decode_str_capped("héllo".as_bytes(), 6).unwrap(), "héllo");
+///
+/// let error = decode_str_capped("héllo".as_bytes(), 5).unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+/// assert_eq!(error.to_string(), "output exceeds memory budget of 5 bytes.");
+/// ```
+///
+/// ## Errors
+/// Returns an [`InvalidData`](std::io::ErrorKind::InvalidData) error if the output exceeds
+/// `max_bytes`, or the errors of [`decode_str`] encountered before that.
+pub fn decode_str_capped(bytes: &[u8], max_bytes: usize) -> Result<String> {
+	check_foreign_bom(bytes)?;
+	let mut string = String::with_capacity(bytes.len().min(max_bytes));
+	for c in Decoder::new(bytes.iter().cloned()) {
+		let c = c?;
+		if string.len() + c.len_utf8() > max_bytes {
+			return Err(Error::new(ErrorKind::InvalidData, format!("output exceeds memory budget of {} bytes.", max_bytes)))
+		}
+
+		string.push(c)
+	}
+
+	Ok(string)
+}
+
+/// Checks that the given bytes do not start with a UTF-16 or UTF-32 byte order mark.
+fn check_foreign_bom(bytes: &[u8]) -> Result<()> {
+	for (bom, encoding) in &FOREIGN_BOMS {
+		if bytes.starts_with(bom) {
+			return Err(Error::new(ErrorKind::InvalidData, format!("input appears to be {}, not UTF-8.", encoding)))
+		}
+	}
+
+	Ok(())
+}
+
+/// Decodes the given bytes into a string, requiring the input to be non-empty.
+///
+/// This is useful when an empty input is a caller error, such as a required text field.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::decode_nonempty;
+/// assert_eq!(decode_nonempty("héllo".as_bytes()).unwrap(), "héllo");
+///
+/// let error = decode_nonempty(b"").unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+/// assert_eq!(error.to_string(), "empty input.");
+/// ```
+///
+/// ## Errors
+/// Returns an [`InvalidData`](std::io::ErrorKind::InvalidData) error if `bytes` is empty, or the
+/// errors of [`decode_str`].
+pub fn decode_nonempty(bytes: &[u8]) -> Result<String> {
+	if bytes.is_empty() {
+		return Err(Error::new(ErrorKind::InvalidData, "empty input."))
+	}
+
+	decode_str(bytes)
+}
+
+/// Decodes the given bytes into a string ending with exactly one `'\n'`.
+///
+/// Trailing new lines are collapsed into one, and a new line is added if the input does not
+/// end with one, following the POSIX convention for text files.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::decode_normalized_trailing;
+/// assert_eq!(decode_normalized_trailing(b"a\nb").unwrap(), "a\nb\n");
+/// assert_eq!(decode_normalized_trailing(b"a\nb\n").unwrap(), "a\nb\n");
+/// assert_eq!(decode_normalized_trailing(b"a\nb\n\n\n").unwrap(), "a\nb\n");
+/// assert_eq!(decode_normalized_trailing(b"").unwrap(), "\n");
+/// ```
+///
+/// ## Errors
+/// Returns the errors of [`decode_str`].
+pub fn decode_normalized_trailing(bytes: &[u8]) -> Result<String> {
+	let mut string = decode_str(bytes)?;
+	let len = string.trim_end_matches('\n').len();
+	string.truncate(len);
+	string.push('\n');
+	Ok(string)
+}
+
+/// Decodes the given bytes lossily, and returns the number of replacement characters inserted.
+///
+/// Each maximal invalid subsequence is replaced with one [`char::REPLACEMENT_CHARACTER`], as by
+/// the [`Lossy`](crate::Lossy) decoder. Replacement characters present in the input are not
+/// counted.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::decode_lossy_counted;
+/// assert_eq!(decode_lossy_counted("héllo".as_bytes()), ("héllo".to_string(), 0));
+/// assert_eq!(decode_lossy_counted(b"h\xffllo\xe2\x82"), ("h\u{fffd}llo\u{fffd}".to_string(), 2));
+///
+/// // Genuine replacement character.
+/// assert_eq!(decode_lossy_counted("\u{fffd}\u{fffd}".as_bytes()).1, 0);
+/// ```
+pub fn decode_lossy_counted(bytes: &[u8]) -> (String, usize) {
+	let mut string = String::with_capacity(bytes.len());
+	let mut count = 0;
+	let mut decoder = Decoder::new_lossy(bytes.iter().cloned());
+	while let Some(c) = decoder.next_char() {
+		match c {
+			Ok(c) => string.push(c),
+			Err(_) => {
+				string.push(char::REPLACEMENT_CHARACTER);
+				count += 1
+			}
+		}
+	}
+
+	(string, count)
+}
+
+/// Decodes the first line of the given bytes.
+///
+/// Characters are decoded up to the first `'\n'`, the rest of the input is left untouched.
+/// Returns the line without its `'\n'` (nor its trailing `'\r'` if any), along with the byte
+/// offset following the `'\n'`, or the length of the input if it contains no new line.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::decode_first_line;
+/// let bytes = b"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\n\r\n\xff";
+/// assert_eq!(decode_first_line(bytes).unwrap(), ("HTTP/1.1 200 OK".to_string(), 17));
+///
+/// assert_eq!(decode_first_line("#!/bin/sh".as_bytes()).unwrap(), ("#!/bin/sh".to_string(), 9));
+/// ```
+///
+/// ## Errors
+/// Returns the first decoding error encountered in the first line.
+pub fn decode_first_line(bytes: &[u8]) -> Result<(String, usize)> {
+	let mut decoder = Decoder::new(bytes.iter().cloned());
+	let (mut line, _) = decoder.read_until_char_or_end('\n')?;
+	if line.ends_with('\r') {
+		line.pop();
+	}
+
+	Ok((line, decoder.offset()))
+}
+
+thread_local! {
+	/// Strings interned by [`decode_interned`].
+	static INTERNED: RefCell<HashSet<Rc<str>>> = RefCell::new(HashSet::new());
+}
+
+/// Decodes the given bytes into a string interned in a thread-local pool.
+///
+/// If a string with the same content has already been interned by the current thread, it is
+/// shared instead of being stored again. The pool is never evicted: interned strings live as
+/// long as the thread, unless the pool is emptied with [`clear_interned`].
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::decode_interned;
+/// # use std::rc::Rc;
+/// let a = decode_interned("héllo".as_bytes()).unwrap();
+/// let b = decode_interned("héllo".as_bytes()).unwrap();
+/// assert_eq!(&*a, "héllo");
+/// assert!(Rc::ptr_eq(&a, &b));
+///
+/// let c = decode_interned("world".as_bytes()).unwrap();
+/// assert!(!Rc::ptr_eq(&a, &c));
+/// ```
+///
+/// ## Errors
+/// Returns the errors of [`decode_str`]. Nothing is interned in this case.
+pub fn decode_interned(bytes: &[u8]) -> Result<Rc<str>> {
+	let string = decode_str(bytes)?;
+	Ok(INTERNED.with(|interned| {
+		let mut interned = interned.borrow_mut();
+		match interned.get(string.as_str()) {
+			Some(rc) => rc.clone(),
+			None => {
+				let rc: Rc<str> = string.into();
+				interned.insert(rc.clone());
+				rc
+			}
+		}
+	}))
+}
+
+/// Empties the thread-local pool of [`decode_interned`].
+///
+/// Strings already returned remain valid, but they are no longer shared with the strings
+/// interned afterward. Returns the number of strings that were removed from the pool.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::{decode_interned, clear_interned};
+/// # use std::rc::Rc;
+/// let a = decode_interned("héllo".as_bytes()).unwrap();
+/// assert_eq!(clear_interned(), 1);
+/// assert_eq!(clear_interned(), 0);
+///
+/// let b = decode_interned("héllo".as_bytes()).unwrap();
+/// assert_eq!(a, b);
+/// assert!(!Rc::ptr_eq(&a, &b));
+/// ```
+pub fn clear_interned() -> usize {
+	INTERNED.with(|interned| {
+		let mut interned = interned.borrow_mut();
+		let len = interned.len();
+		interned.clear();
+		len
+	})
+}
+
+/// Decodes the start of the given bytes and checks that it matches `expected`.
+///
+/// Characters are decoded and compared one by one, so decoding stops at the first divergence.
+/// Returns the byte length of the matched prefix. This is useful to check file format magic
+/// strings.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::expect_prefix;
+/// assert_eq!(expect_prefix(b"%PDF-1.7\n\xff", "%PDF-").unwrap(), 5);
+///
+/// let error = expect_prefix("%PDX-1.7".as_bytes(), "%PDF-").unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+/// assert_eq!(error.to_string(), "mismatch at byte 3: expected 'F', found 'X'.");
+/// ```
+///
+/// ## Errors
+/// Returns an [`InvalidData`](std::io::ErrorKind::InvalidData) error naming the byte offset of
+/// the first divergence if the input does not start with `expected`, or the first decoding
+/// error encountered.
+pub fn expect_prefix(bytes: &[u8], expected: &str) -> Result<usize> {
+	let mut decoder = Decoder::new(bytes.iter().cloned());
+	for expected_c in expected.chars() {
+		let offset = decoder.offset();
+		let found = match decoder.next() {
+			Some(c) => {
+				let c = c?;
+				if c == expected_c {
+					continue
+				}
+
+				format!("{:?}", c)
+			},
+			None => "end of input".to_string()
+		};
+
+		return Err(Error::new(ErrorKind::InvalidData, format!("mismatch at byte {}: expected {:?}, found {}.", offset, expected_c, found)))
+	}
+
+	Ok(decoder.offset())
+}
+
+/// Decodes the two given byte buffers in lockstep and returns the byte length of their longest
+/// common prefix ending on a character boundary.
+///
+/// Decoding stops at the first differing character, or at the end of the shortest buffer.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::common_char_prefix;
+/// assert_eq!(common_char_prefix("héllo".as_bytes(), "héllo".as_bytes()).unwrap(), 6);
+/// assert_eq!(common_char_prefix("héllo".as_bytes(), "hé".as_bytes()).unwrap(), 3);
+/// assert_eq!(common_char_prefix("日本語".as_bytes(), "日本人".as_bytes()).unwrap(), 6);
+/// assert_eq!(common_char_prefix("€".as_bytes(), "é".as_bytes()).unwrap(), 0);
+///
+/// // "é" and "è" share their first byte, but no character.
+/// assert_eq!(common_char_prefix("aé".as_bytes(), "aè".as_bytes()).unwrap(), 1);
+///
+/// // Invalid bytes after the first difference are not decoded.
+/// assert_eq!(common_char_prefix(b"ab\xff", b"ac\xff").unwrap(), 1);
+/// assert!(common_char_prefix(b"a\xffb", b"a\xffc").is_err());
+/// ```
+///
+/// ## Errors
+/// Returns the first decoding error encountered in either buffer, before the first differing
+/// character.
+pub fn common_char_prefix(a: &[u8], b: &[u8]) -> Result<usize> {
+	let mut a = Decoder::new(a.iter().cloned());
+	let mut b = Decoder::new(b.iter().cloned());
+	loop {
+		let offset = a.offset();
+		match (a.next(), b.next()) {
+			(Some(ca), Some(cb)) => {
+				if ca? != cb? {
+					return Ok(offset)
+				}
+			},
+			_ => return Ok(offset)
+		}
+	}
+}
+
+/// Decodes the given bytes and returns the byte offset of the `n`th occurrence of `target`,
+/// counting from 1.
+///
+/// Decoding stops at the `n`th occurrence. Returns `None` if the input contains fewer than `n`
+/// occurrences of `target`.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::nth_char_occurrence;
+/// let bytes = "é,b,€,d".as_bytes();
+/// assert_eq!(nth_char_occurrence(bytes, ',', 1).unwrap(), Some(2));
+/// assert_eq!(nth_char_occurrence(bytes, ',', 3).unwrap(), Some(8));
+/// assert_eq!(nth_char_occurrence(bytes, ',', 4).unwrap(), None);
+/// assert_eq!(nth_char_occurrence(bytes, '€', 1).unwrap(), Some(5));
+///
+/// // Invalid bytes after the occurrence are not decoded.
+/// assert_eq!(nth_char_occurrence(b"a,\xff", ',', 1).unwrap(), Some(1));
+/// assert!(nth_char_occurrence(b"a\xff,", ',', 1).is_err());
+/// assert!(nth_char_occurrence(b"a,", ',', 0).is_err());
+/// ```
+///
+/// ## Errors
+/// Returns an [`InvalidInput`](std::io::ErrorKind::InvalidInput) error if `n` is `0`, or the
+/// first decoding error encountered before the `n`th occurrence.
+pub fn nth_char_occurrence(bytes: &[u8], target: char, n: usize) -> Result<Option<usize>> {
+	if n == 0 {
+		return Err(Error::new(ErrorKind::InvalidInput, "occurrences are counted from 1."))
+	}
+
+	let mut count = 0;
+	let mut decoder = Decoder::new(bytes.iter().cloned());
+	loop {
+		let offset = decoder.offset();
+		match decoder.next() {
+			Some(c) => {
+				if c? == target {
+					count += 1;
+					if count == n {
+						return Ok(Some(offset))
+					}
+				}
+			},
+			None => return Ok(None)
+		}
+	}
+}
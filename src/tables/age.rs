@@ -0,0 +1,1667 @@
+// This file is generated by `scripts/unicode.py` from the Unicode Character Database,
+// version 14.0.0. Do not edit it manually.
+
+use crate::UnicodeVersion;
+
+/// Unicode version in which each assigned code point was assigned.
+pub(crate) const AGE: &[(u32, u32, UnicodeVersion)] = &[
+	(0x0, 0x1f5, UnicodeVersion::new(1, 1)),
+	(0x1f6, 0x1f9, UnicodeVersion::new(3, 0)),
+	(0x1fa, 0x217, UnicodeVersion::new(1, 1)),
+	(0x218, 0x21f, UnicodeVersion::new(3, 0)),
+	(0x220, 0x220, UnicodeVersion::new(3, 2)),
+	(0x221, 0x221, UnicodeVersion::new(4, 0)),
+	(0x222, 0x233, UnicodeVersion::new(3, 0)),
+	(0x234, 0x236, UnicodeVersion::new(4, 0)),
+	(0x237, 0x241, UnicodeVersion::new(4, 1)),
+	(0x242, 0x24f, UnicodeVersion::new(5, 0)),
+	(0x250, 0x2a8, UnicodeVersion::new(1, 1)),
+	(0x2a9, 0x2ad, UnicodeVersion::new(3, 0)),
+	(0x2ae, 0x2af, UnicodeVersion::new(4, 0)),
+	(0x2b0, 0x2de, UnicodeVersion::new(1, 1)),
+	(0x2df, 0x2df, UnicodeVersion::new(3, 0)),
+	(0x2e0, 0x2e9, UnicodeVersion::new(1, 1)),
+	(0x2ea, 0x2ee, UnicodeVersion::new(3, 0)),
+	(0x2ef, 0x2ff, UnicodeVersion::new(4, 0)),
+	(0x300, 0x345, UnicodeVersion::new(1, 1)),
+	(0x346, 0x34e, UnicodeVersion::new(3, 0)),
+	(0x34f, 0x34f, UnicodeVersion::new(3, 2)),
+	(0x350, 0x357, UnicodeVersion::new(4, 0)),
+	(0x358, 0x35c, UnicodeVersion::new(4, 1)),
+	(0x35d, 0x35f, UnicodeVersion::new(4, 0)),
+	(0x360, 0x361, UnicodeVersion::new(1, 1)),
+	(0x362, 0x362, UnicodeVersion::new(3, 0)),
+	(0x363, 0x36f, UnicodeVersion::new(3, 2)),
+	(0x370, 0x373, UnicodeVersion::new(5, 1)),
+	(0x374, 0x375, UnicodeVersion::new(1, 1)),
+	(0x376, 0x377, UnicodeVersion::new(5, 1)),
+	(0x37a, 0x37a, UnicodeVersion::new(1, 1)),
+	(0x37b, 0x37d, UnicodeVersion::new(5, 0)),
+	(0x37e, 0x37e, UnicodeVersion::new(1, 1)),
+	(0x37f, 0x37f, UnicodeVersion::new(7, 0)),
+	(0x384, 0x38a, UnicodeVersion::new(1, 1)),
+	(0x38c, 0x38c, UnicodeVersion::new(1, 1)),
+	(0x38e, 0x3a1, UnicodeVersion::new(1, 1)),
+	(0x3a3, 0x3ce, UnicodeVersion::new(1, 1)),
+	(0x3cf, 0x3cf, UnicodeVersion::new(5, 1)),
+	(0x3d0, 0x3d6, UnicodeVersion::new(1, 1)),
+	(0x3d7, 0x3d7, UnicodeVersion::new(3, 0)),
+	(0x3d8, 0x3d9, UnicodeVersion::new(3, 2)),
+	(0x3da, 0x3da, UnicodeVersion::new(1, 1)),
+	(0x3db, 0x3db, UnicodeVersion::new(3, 0)),
+	(0x3dc, 0x3dc, UnicodeVersion::new(1, 1)),
+	(0x3dd, 0x3dd, UnicodeVersion::new(3, 0)),
+	(0x3de, 0x3de, UnicodeVersion::new(1, 1)),
+	(0x3df, 0x3df, UnicodeVersion::new(3, 0)),
+	(0x3e0, 0x3e0, UnicodeVersion::new(1, 1)),
+	(0x3e1, 0x3e1, UnicodeVersion::new(3, 0)),
+	(0x3e2, 0x3f3, UnicodeVersion::new(1, 1)),
+	(0x3f4, 0x3f5, UnicodeVersion::new(3, 1)),
+	(0x3f6, 0x3f6, UnicodeVersion::new(3, 2)),
+	(0x3f7, 0x3fb, UnicodeVersion::new(4, 0)),
+	(0x3fc, 0x3ff, UnicodeVersion::new(4, 1)),
+	(0x400, 0x400, UnicodeVersion::new(3, 0)),
+	(0x401, 0x40c, UnicodeVersion::new(1, 1)),
+	(0x40d, 0x40d, UnicodeVersion::new(3, 0)),
+	(0x40e, 0x44f, UnicodeVersion::new(1, 1)),
+	(0x450, 0x450, UnicodeVersion::new(3, 0)),
+	(0x451, 0x45c, UnicodeVersion::new(1, 1)),
+	(0x45d, 0x45d, UnicodeVersion::new(3, 0)),
+	(0x45e, 0x486, UnicodeVersion::new(1, 1)),
+	(0x487, 0x487, UnicodeVersion::new(5, 1)),
+	(0x488, 0x489, UnicodeVersion::new(3, 0)),
+	(0x48a, 0x48b, UnicodeVersion::new(3, 2)),
+	(0x48c, 0x48f, UnicodeVersion::new(3, 0)),
+	(0x490, 0x4c4, UnicodeVersion::new(1, 1)),
+	(0x4c5, 0x4c6, UnicodeVersion::new(3, 2)),
+	(0x4c7, 0x4c8, UnicodeVersion::new(1, 1)),
+	(0x4c9, 0x4ca, UnicodeVersion::new(3, 2)),
+	(0x4cb, 0x4cc, UnicodeVersion::new(1, 1)),
+	(0x4cd, 0x4ce, UnicodeVersion::new(3, 2)),
+	(0x4cf, 0x4cf, UnicodeVersion::new(5, 0)),
+	(0x4d0, 0x4eb, UnicodeVersion::new(1, 1)),
+	(0x4ec, 0x4ed, UnicodeVersion::new(3, 0)),
+	(0x4ee, 0x4f5, UnicodeVersion::new(1, 1)),
+	(0x4f6, 0x4f7, UnicodeVersion::new(4, 1)),
+	(0x4f8, 0x4f9, UnicodeVersion::new(1, 1)),
+	(0x4fa, 0x4ff, UnicodeVersion::new(5, 0)),
+	(0x500, 0x50f, UnicodeVersion::new(3, 2)),
+	(0x510, 0x513, UnicodeVersion::new(5, 0)),
+	(0x514, 0x523, UnicodeVersion::new(5, 1)),
+	(0x524, 0x525, UnicodeVersion::new(5, 2)),
+	(0x526, 0x527, UnicodeVersion::new(6, 0)),
+	(0x528, 0x52f, UnicodeVersion::new(7, 0)),
+	(0x531, 0x556, UnicodeVersion::new(1, 1)),
+	(0x559, 0x55f, UnicodeVersion::new(1, 1)),
+	(0x560, 0x560, UnicodeVersion::new(11, 0)),
+	(0x561, 0x587, UnicodeVersion::new(1, 1)),
+	(0x588, 0x588, UnicodeVersion::new(11, 0)),
+	(0x589, 0x589, UnicodeVersion::new(1, 1)),
+	(0x58a, 0x58a, UnicodeVersion::new(3, 0)),
+	(0x58d, 0x58e, UnicodeVersion::new(7, 0)),
+	(0x58f, 0x58f, UnicodeVersion::new(6, 1)),
+	(0x591, 0x5a1, UnicodeVersion::new(2, 0)),
+	(0x5a2, 0x5a2, UnicodeVersion::new(4, 1)),
+	(0x5a3, 0x5af, UnicodeVersion::new(2, 0)),
+	(0x5b0, 0x5b9, UnicodeVersion::new(1, 1)),
+	(0x5ba, 0x5ba, UnicodeVersion::new(5, 0)),
+	(0x5bb, 0x5c3, UnicodeVersion::new(1, 1)),
+	(0x5c4, 0x5c4, UnicodeVersion::new(2, 0)),
+	(0x5c5, 0x5c7, UnicodeVersion::new(4, 1)),
+	(0x5d0, 0x5ea, UnicodeVersion::new(1, 1)),
+	(0x5ef, 0x5ef, UnicodeVersion::new(11, 0)),
+	(0x5f0, 0x5f4, UnicodeVersion::new(1, 1)),
+	(0x600, 0x603, UnicodeVersion::new(4, 0)),
+	(0x604, 0x604, UnicodeVersion::new(6, 1)),
+	(0x605, 0x605, UnicodeVersion::new(7, 0)),
+	(0x606, 0x60a, UnicodeVersion::new(5, 1)),
+	(0x60b, 0x60b, UnicodeVersion::new(4, 1)),
+	(0x60c, 0x60c, UnicodeVersion::new(1, 1)),
+	(0x60d, 0x615, UnicodeVersion::new(4, 0)),
+	(0x616, 0x61a, UnicodeVersion::new(5, 1)),
+	(0x61b, 0x61b, UnicodeVersion::new(1, 1)),
+	(0x61c, 0x61c, UnicodeVersion::new(6, 3)),
+	(0x61d, 0x61d, UnicodeVersion::new(14, 0)),
+	(0x61e, 0x61e, UnicodeVersion::new(4, 1)),
+	(0x61f, 0x61f, UnicodeVersion::new(1, 1)),
+	(0x620, 0x620, UnicodeVersion::new(6, 0)),
+	(0x621, 0x63a, UnicodeVersion::new(1, 1)),
+	(0x63b, 0x63f, UnicodeVersion::new(5, 1)),
+	(0x640, 0x652, UnicodeVersion::new(1, 1)),
+	(0x653, 0x655, UnicodeVersion::new(3, 0)),
+	(0x656, 0x658, UnicodeVersion::new(4, 0)),
+	(0x659, 0x65e, UnicodeVersion::new(4, 1)),
+	(0x65f, 0x65f, UnicodeVersion::new(6, 0)),
+	(0x660, 0x66d, UnicodeVersion::new(1, 1)),
+	(0x66e, 0x66f, UnicodeVersion::new(3, 2)),
+	(0x670, 0x6b7, UnicodeVersion::new(1, 1)),
+	(0x6b8, 0x6b9, UnicodeVersion::new(3, 0)),
+	(0x6ba, 0x6be, UnicodeVersion::new(1, 1)),
+	(0x6bf, 0x6bf, UnicodeVersion::new(3, 0)),
+	(0x6c0, 0x6ce, UnicodeVersion::new(1, 1)),
+	(0x6cf, 0x6cf, UnicodeVersion::new(3, 0)),
+	(0x6d0, 0x6ed, UnicodeVersion::new(1, 1)),
+	(0x6ee, 0x6ef, UnicodeVersion::new(4, 0)),
+	(0x6f0, 0x6f9, UnicodeVersion::new(1, 1)),
+	(0x6fa, 0x6fe, UnicodeVersion::new(3, 0)),
+	(0x6ff, 0x6ff, UnicodeVersion::new(4, 0)),
+	(0x700, 0x70d, UnicodeVersion::new(3, 0)),
+	(0x70f, 0x72c, UnicodeVersion::new(3, 0)),
+	(0x72d, 0x72f, UnicodeVersion::new(4, 0)),
+	(0x730, 0x74a, UnicodeVersion::new(3, 0)),
+	(0x74d, 0x74f, UnicodeVersion::new(4, 0)),
+	(0x750, 0x76d, UnicodeVersion::new(4, 1)),
+	(0x76e, 0x77f, UnicodeVersion::new(5, 1)),
+	(0x780, 0x7b0, UnicodeVersion::new(3, 0)),
+	(0x7b1, 0x7b1, UnicodeVersion::new(3, 2)),
+	(0x7c0, 0x7fa, UnicodeVersion::new(5, 0)),
+	(0x7fd, 0x7ff, UnicodeVersion::new(11, 0)),
+	(0x800, 0x82d, UnicodeVersion::new(5, 2)),
+	(0x830, 0x83e, UnicodeVersion::new(5, 2)),
+	(0x840, 0x85b, UnicodeVersion::new(6, 0)),
+	(0x85e, 0x85e, UnicodeVersion::new(6, 0)),
+	(0x860, 0x86a, UnicodeVersion::new(10, 0)),
+	(0x870, 0x88e, UnicodeVersion::new(14, 0)),
+	(0x890, 0x891, UnicodeVersion::new(14, 0)),
+	(0x898, 0x89f, UnicodeVersion::new(14, 0)),
+	(0x8a0, 0x8a0, UnicodeVersion::new(6, 1)),
+	(0x8a1, 0x8a1, UnicodeVersion::new(7, 0)),
+	(0x8a2, 0x8ac, UnicodeVersion::new(6, 1)),
+	(0x8ad, 0x8b2, UnicodeVersion::new(7, 0)),
+	(0x8b3, 0x8b4, UnicodeVersion::new(8, 0)),
+	(0x8b5, 0x8b5, UnicodeVersion::new(14, 0)),
+	(0x8b6, 0x8bd, UnicodeVersion::new(9, 0)),
+	(0x8be, 0x8c7, UnicodeVersion::new(13, 0)),
+	(0x8c8, 0x8d2, UnicodeVersion::new(14, 0)),
+	(0x8d3, 0x8d3, UnicodeVersion::new(11, 0)),
+	(0x8d4, 0x8e2, UnicodeVersion::new(9, 0)),
+	(0x8e3, 0x8e3, UnicodeVersion::new(8, 0)),
+	(0x8e4, 0x8fe, UnicodeVersion::new(6, 1)),
+	(0x8ff, 0x8ff, UnicodeVersion::new(7, 0)),
+	(0x900, 0x900, UnicodeVersion::new(5, 2)),
+	(0x901, 0x903, UnicodeVersion::new(1, 1)),
+	(0x904, 0x904, UnicodeVersion::new(4, 0)),
+	(0x905, 0x939, UnicodeVersion::new(1, 1)),
+	(0x93a, 0x93b, UnicodeVersion::new(6, 0)),
+	(0x93c, 0x94d, UnicodeVersion::new(1, 1)),
+	(0x94e, 0x94e, UnicodeVersion::new(5, 2)),
+	(0x94f, 0x94f, UnicodeVersion::new(6, 0)),
+	(0x950, 0x954, UnicodeVersion::new(1, 1)),
+	(0x955, 0x955, UnicodeVersion::new(5, 2)),
+	(0x956, 0x957, UnicodeVersion::new(6, 0)),
+	(0x958, 0x970, UnicodeVersion::new(1, 1)),
+	(0x971, 0x972, UnicodeVersion::new(5, 1)),
+	(0x973, 0x977, UnicodeVersion::new(6, 0)),
+	(0x978, 0x978, UnicodeVersion::new(7, 0)),
+	(0x979, 0x97a, UnicodeVersion::new(5, 2)),
+	(0x97b, 0x97c, UnicodeVersion::new(5, 0)),
+	(0x97d, 0x97d, UnicodeVersion::new(4, 1)),
+	(0x97e, 0x97f, UnicodeVersion::new(5, 0)),
+	(0x980, 0x980, UnicodeVersion::new(7, 0)),
+	(0x981, 0x983, UnicodeVersion::new(1, 1)),
+	(0x985, 0x98c, UnicodeVersion::new(1, 1)),
+	(0x98f, 0x990, UnicodeVersion::new(1, 1)),
+	(0x993, 0x9a8, UnicodeVersion::new(1, 1)),
+	(0x9aa, 0x9b0, UnicodeVersion::new(1, 1)),
+	(0x9b2, 0x9b2, UnicodeVersion::new(1, 1)),
+	(0x9b6, 0x9b9, UnicodeVersion::new(1, 1)),
+	(0x9bc, 0x9bc, UnicodeVersion::new(1, 1)),
+	(0x9bd, 0x9bd, UnicodeVersion::new(4, 0)),
+	(0x9be, 0x9c4, UnicodeVersion::new(1, 1)),
+	(0x9c7, 0x9c8, UnicodeVersion::new(1, 1)),
+	(0x9cb, 0x9cd, UnicodeVersion::new(1, 1)),
+	(0x9ce, 0x9ce, UnicodeVersion::new(4, 1)),
+	(0x9d7, 0x9d7, UnicodeVersion::new(1, 1)),
+	(0x9dc, 0x9dd, UnicodeVersion::new(1, 1)),
+	(0x9df, 0x9e3, UnicodeVersion::new(1, 1)),
+	(0x9e6, 0x9fa, UnicodeVersion::new(1, 1)),
+	(0x9fb, 0x9fb, UnicodeVersion::new(5, 2)),
+	(0x9fc, 0x9fd, UnicodeVersion::new(10, 0)),
+	(0x9fe, 0x9fe, UnicodeVersion::new(11, 0)),
+	(0xa01, 0xa01, UnicodeVersion::new(4, 0)),
+	(0xa02, 0xa02, UnicodeVersion::new(1, 1)),
+	(0xa03, 0xa03, UnicodeVersion::new(4, 0)),
+	(0xa05, 0xa0a, UnicodeVersion::new(1, 1)),
+	(0xa0f, 0xa10, UnicodeVersion::new(1, 1)),
+	(0xa13, 0xa28, UnicodeVersion::new(1, 1)),
+	(0xa2a, 0xa30, UnicodeVersion::new(1, 1)),
+	(0xa32, 0xa33, UnicodeVersion::new(1, 1)),
+	(0xa35, 0xa36, UnicodeVersion::new(1, 1)),
+	(0xa38, 0xa39, UnicodeVersion::new(1, 1)),
+	(0xa3c, 0xa3c, UnicodeVersion::new(1, 1)),
+	(0xa3e, 0xa42, UnicodeVersion::new(1, 1)),
+	(0xa47, 0xa48, UnicodeVersion::new(1, 1)),
+	(0xa4b, 0xa4d, UnicodeVersion::new(1, 1)),
+	(0xa51, 0xa51, UnicodeVersion::new(5, 1)),
+	(0xa59, 0xa5c, UnicodeVersion::new(1, 1)),
+	(0xa5e, 0xa5e, UnicodeVersion::new(1, 1)),
+	(0xa66, 0xa74, UnicodeVersion::new(1, 1)),
+	(0xa75, 0xa75, UnicodeVersion::new(5, 1)),
+	(0xa76, 0xa76, UnicodeVersion::new(11, 0)),
+	(0xa81, 0xa83, UnicodeVersion::new(1, 1)),
+	(0xa85, 0xa8b, UnicodeVersion::new(1, 1)),
+	(0xa8c, 0xa8c, UnicodeVersion::new(4, 0)),
+	(0xa8d, 0xa8d, UnicodeVersion::new(1, 1)),
+	(0xa8f, 0xa91, UnicodeVersion::new(1, 1)),
+	(0xa93, 0xaa8, UnicodeVersion::new(1, 1)),
+	(0xaaa, 0xab0, UnicodeVersion::new(1, 1)),
+	(0xab2, 0xab3, UnicodeVersion::new(1, 1)),
+	(0xab5, 0xab9, UnicodeVersion::new(1, 1)),
+	(0xabc, 0xac5, UnicodeVersion::new(1, 1)),
+	(0xac7, 0xac9, UnicodeVersion::new(1, 1)),
+	(0xacb, 0xacd, UnicodeVersion::new(1, 1)),
+	(0xad0, 0xad0, UnicodeVersion::new(1, 1)),
+	(0xae0, 0xae0, UnicodeVersion::new(1, 1)),
+	(0xae1, 0xae3, UnicodeVersion::new(4, 0)),
+	(0xae6, 0xaef, UnicodeVersion::new(1, 1)),
+	(0xaf0, 0xaf0, UnicodeVersion::new(6, 1)),
+	(0xaf1, 0xaf1, UnicodeVersion::new(4, 0)),
+	(0xaf9, 0xaf9, UnicodeVersion::new(8, 0)),
+	(0xafa, 0xaff, UnicodeVersion::new(10, 0)),
+	(0xb01, 0xb03, UnicodeVersion::new(1, 1)),
+	(0xb05, 0xb0c, UnicodeVersion::new(1, 1)),
+	(0xb0f, 0xb10, UnicodeVersion::new(1, 1)),
+	(0xb13, 0xb28, UnicodeVersion::new(1, 1)),
+	(0xb2a, 0xb30, UnicodeVersion::new(1, 1)),
+	(0xb32, 0xb33, UnicodeVersion::new(1, 1)),
+	(0xb35, 0xb35, UnicodeVersion::new(4, 0)),
+	(0xb36, 0xb39, UnicodeVersion::new(1, 1)),
+	(0xb3c, 0xb43, UnicodeVersion::new(1, 1)),
+	(0xb44, 0xb44, UnicodeVersion::new(5, 1)),
+	(0xb47, 0xb48, UnicodeVersion::new(1, 1)),
+	(0xb4b, 0xb4d, UnicodeVersion::new(1, 1)),
+	(0xb55, 0xb55, UnicodeVersion::new(13, 0)),
+	(0xb56, 0xb57, UnicodeVersion::new(1, 1)),
+	(0xb5c, 0xb5d, UnicodeVersion::new(1, 1)),
+	(0xb5f, 0xb61, UnicodeVersion::new(1, 1)),
+	(0xb62, 0xb63, UnicodeVersion::new(5, 1)),
+	(0xb66, 0xb70, UnicodeVersion::new(1, 1)),
+	(0xb71, 0xb71, UnicodeVersion::new(4, 0)),
+	(0xb72, 0xb77, UnicodeVersion::new(6, 0)),
+	(0xb82, 0xb83, UnicodeVersion::new(1, 1)),
+	(0xb85, 0xb8a, UnicodeVersion::new(1, 1)),
+	(0xb8e, 0xb90, UnicodeVersion::new(1, 1)),
+	(0xb92, 0xb95, UnicodeVersion::new(1, 1)),
+	(0xb99, 0xb9a, UnicodeVersion::new(1, 1)),
+	(0xb9c, 0xb9c, UnicodeVersion::new(1, 1)),
+	(0xb9e, 0xb9f, UnicodeVersion::new(1, 1)),
+	(0xba3, 0xba4, UnicodeVersion::new(1, 1)),
+	(0xba8, 0xbaa, UnicodeVersion::new(1, 1)),
+	(0xbae, 0xbb5, UnicodeVersion::new(1, 1)),
+	(0xbb6, 0xbb6, UnicodeVersion::new(4, 1)),
+	(0xbb7, 0xbb9, UnicodeVersion::new(1, 1)),
+	(0xbbe, 0xbc2, UnicodeVersion::new(1, 1)),
+	(0xbc6, 0xbc8, UnicodeVersion::new(1, 1)),
+	(0xbca, 0xbcd, UnicodeVersion::new(1, 1)),
+	(0xbd0, 0xbd0, UnicodeVersion::new(5, 1)),
+	(0xbd7, 0xbd7, UnicodeVersion::new(1, 1)),
+	(0xbe6, 0xbe6, UnicodeVersion::new(4, 1)),
+	(0xbe7, 0xbf2, UnicodeVersion::new(1, 1)),
+	(0xbf3, 0xbfa, UnicodeVersion::new(4, 0)),
+	(0xc00, 0xc00, UnicodeVersion::new(7, 0)),
+	(0xc01, 0xc03, UnicodeVersion::new(1, 1)),
+	(0xc04, 0xc04, UnicodeVersion::new(11, 0)),
+	(0xc05, 0xc0c, UnicodeVersion::new(1, 1)),
+	(0xc0e, 0xc10, UnicodeVersion::new(1, 1)),
+	(0xc12, 0xc28, UnicodeVersion::new(1, 1)),
+	(0xc2a, 0xc33, UnicodeVersion::new(1, 1)),
+	(0xc34, 0xc34, UnicodeVersion::new(7, 0)),
+	(0xc35, 0xc39, UnicodeVersion::new(1, 1)),
+	(0xc3c, 0xc3c, UnicodeVersion::new(14, 0)),
+	(0xc3d, 0xc3d, UnicodeVersion::new(5, 1)),
+	(0xc3e, 0xc44, UnicodeVersion::new(1, 1)),
+	(0xc46, 0xc48, UnicodeVersion::new(1, 1)),
+	(0xc4a, 0xc4d, UnicodeVersion::new(1, 1)),
+	(0xc55, 0xc56, UnicodeVersion::new(1, 1)),
+	(0xc58, 0xc59, UnicodeVersion::new(5, 1)),
+	(0xc5a, 0xc5a, UnicodeVersion::new(8, 0)),
+	(0xc5d, 0xc5d, UnicodeVersion::new(14, 0)),
+	(0xc60, 0xc61, UnicodeVersion::new(1, 1)),
+	(0xc62, 0xc63, UnicodeVersion::new(5, 1)),
+	(0xc66, 0xc6f, UnicodeVersion::new(1, 1)),
+	(0xc77, 0xc77, UnicodeVersion::new(12, 0)),
+	(0xc78, 0xc7f, UnicodeVersion::new(5, 1)),
+	(0xc80, 0xc80, UnicodeVersion::new(9, 0)),
+	(0xc81, 0xc81, UnicodeVersion::new(7, 0)),
+	(0xc82, 0xc83, UnicodeVersion::new(1, 1)),
+	(0xc84, 0xc84, UnicodeVersion::new(11, 0)),
+	(0xc85, 0xc8c, UnicodeVersion::new(1, 1)),
+	(0xc8e, 0xc90, UnicodeVersion::new(1, 1)),
+	(0xc92, 0xca8, UnicodeVersion::new(1, 1)),
+	(0xcaa, 0xcb3, UnicodeVersion::new(1, 1)),
+	(0xcb5, 0xcb9, UnicodeVersion::new(1, 1)),
+	(0xcbc, 0xcbd, UnicodeVersion::new(4, 0)),
+	(0xcbe, 0xcc4, UnicodeVersion::new(1, 1)),
+	(0xcc6, 0xcc8, UnicodeVersion::new(1, 1)),
+	(0xcca, 0xccd, UnicodeVersion::new(1, 1)),
+	(0xcd5, 0xcd6, UnicodeVersion::new(1, 1)),
+	(0xcdd, 0xcdd, UnicodeVersion::new(14, 0)),
+	(0xcde, 0xcde, UnicodeVersion::new(1, 1)),
+	(0xce0, 0xce1, UnicodeVersion::new(1, 1)),
+	(0xce2, 0xce3, UnicodeVersion::new(5, 0)),
+	(0xce6, 0xcef, UnicodeVersion::new(1, 1)),
+	(0xcf1, 0xcf2, UnicodeVersion::new(5, 0)),
+	(0xd00, 0xd00, UnicodeVersion::new(10, 0)),
+	(0xd01, 0xd01, UnicodeVersion::new(7, 0)),
+	(0xd02, 0xd03, UnicodeVersion::new(1, 1)),
+	(0xd04, 0xd04, UnicodeVersion::new(13, 0)),
+	(0xd05, 0xd0c, UnicodeVersion::new(1, 1)),
+	(0xd0e, 0xd10, UnicodeVersion::new(1, 1)),
+	(0xd12, 0xd28, UnicodeVersion::new(1, 1)),
+	(0xd29, 0xd29, UnicodeVersion::new(6, 0)),
+	(0xd2a, 0xd39, UnicodeVersion::new(1, 1)),
+	(0xd3a, 0xd3a, UnicodeVersion::new(6, 0)),
+	(0xd3b, 0xd3c, UnicodeVersion::new(10, 0)),
+	(0xd3d, 0xd3d, UnicodeVersion::new(5, 1)),
+	(0xd3e, 0xd43, UnicodeVersion::new(1, 1)),
+	(0xd44, 0xd44, UnicodeVersion::new(5, 1)),
+	(0xd46, 0xd48, UnicodeVersion::new(1, 1)),
+	(0xd4a, 0xd4d, UnicodeVersion::new(1, 1)),
+	(0xd4e, 0xd4e, UnicodeVersion::new(6, 0)),
+	(0xd4f, 0xd4f, UnicodeVersion::new(9, 0)),
+	(0xd54, 0xd56, UnicodeVersion::new(9, 0)),
+	(0xd57, 0xd57, UnicodeVersion::new(1, 1)),
+	(0xd58, 0xd5e, UnicodeVersion::new(9, 0)),
+	(0xd5f, 0xd5f, UnicodeVersion::new(8, 0)),
+	(0xd60, 0xd61, UnicodeVersion::new(1, 1)),
+	(0xd62, 0xd63, UnicodeVersion::new(5, 1)),
+	(0xd66, 0xd6f, UnicodeVersion::new(1, 1)),
+	(0xd70, 0xd75, UnicodeVersion::new(5, 1)),
+	(0xd76, 0xd78, UnicodeVersion::new(9, 0)),
+	(0xd79, 0xd7f, UnicodeVersion::new(5, 1)),
+	(0xd81, 0xd81, UnicodeVersion::new(13, 0)),
+	(0xd82, 0xd83, UnicodeVersion::new(3, 0)),
+	(0xd85, 0xd96, UnicodeVersion::new(3, 0)),
+	(0xd9a, 0xdb1, UnicodeVersion::new(3, 0)),
+	(0xdb3, 0xdbb, UnicodeVersion::new(3, 0)),
+	(0xdbd, 0xdbd, UnicodeVersion::new(3, 0)),
+	(0xdc0, 0xdc6, UnicodeVersion::new(3, 0)),
+	(0xdca, 0xdca, UnicodeVersion::new(3, 0)),
+	(0xdcf, 0xdd4, UnicodeVersion::new(3, 0)),
+	(0xdd6, 0xdd6, UnicodeVersion::new(3, 0)),
+	(0xdd8, 0xddf, UnicodeVersion::new(3, 0)),
+	(0xde6, 0xdef, UnicodeVersion::new(7, 0)),
+	(0xdf2, 0xdf4, UnicodeVersion::new(3, 0)),
+	(0xe01, 0xe3a, UnicodeVersion::new(1, 1)),
+	(0xe3f, 0xe5b, UnicodeVersion::new(1, 1)),
+	(0xe81, 0xe82, UnicodeVersion::new(1, 1)),
+	(0xe84, 0xe84, UnicodeVersion::new(1, 1)),
+	(0xe86, 0xe86, UnicodeVersion::new(12, 0)),
+	(0xe87, 0xe88, UnicodeVersion::new(1, 1)),
+	(0xe89, 0xe89, UnicodeVersion::new(12, 0)),
+	(0xe8a, 0xe8a, UnicodeVersion::new(1, 1)),
+	(0xe8c, 0xe8c, UnicodeVersion::new(12, 0)),
+	(0xe8d, 0xe8d, UnicodeVersion::new(1, 1)),
+	(0xe8e, 0xe93, UnicodeVersion::new(12, 0)),
+	(0xe94, 0xe97, UnicodeVersion::new(1, 1)),
+	(0xe98, 0xe98, UnicodeVersion::new(12, 0)),
+	(0xe99, 0xe9f, UnicodeVersion::new(1, 1)),
+	(0xea0, 0xea0, UnicodeVersion::new(12, 0)),
+	(0xea1, 0xea3, UnicodeVersion::new(1, 1)),
+	(0xea5, 0xea5, UnicodeVersion::new(1, 1)),
+	(0xea7, 0xea7, UnicodeVersion::new(1, 1)),
+	(0xea8, 0xea9, UnicodeVersion::new(12, 0)),
+	(0xeaa, 0xeab, UnicodeVersion::new(1, 1)),
+	(0xeac, 0xeac, UnicodeVersion::new(12, 0)),
+	(0xead, 0xeb9, UnicodeVersion::new(1, 1)),
+	(0xeba, 0xeba, UnicodeVersion::new(12, 0)),
+	(0xebb, 0xebd, UnicodeVersion::new(1, 1)),
+	(0xec0, 0xec4, UnicodeVersion::new(1, 1)),
+	(0xec6, 0xec6, UnicodeVersion::new(1, 1)),
+	(0xec8, 0xecd, UnicodeVersion::new(1, 1)),
+	(0xed0, 0xed9, UnicodeVersion::new(1, 1)),
+	(0xedc, 0xedd, UnicodeVersion::new(1, 1)),
+	(0xede, 0xedf, UnicodeVersion::new(6, 1)),
+	(0xf00, 0xf47, UnicodeVersion::new(2, 0)),
+	(0xf49, 0xf69, UnicodeVersion::new(2, 0)),
+	(0xf6a, 0xf6a, UnicodeVersion::new(3, 0)),
+	(0xf6b, 0xf6c, UnicodeVersion::new(5, 1)),
+	(0xf71, 0xf8b, UnicodeVersion::new(2, 0)),
+	(0xf8c, 0xf8f, UnicodeVersion::new(6, 0)),
+	(0xf90, 0xf95, UnicodeVersion::new(2, 0)),
+	(0xf96, 0xf96, UnicodeVersion::new(3, 0)),
+	(0xf97, 0xf97, UnicodeVersion::new(2, 0)),
+	(0xf99, 0xfad, UnicodeVersion::new(2, 0)),
+	(0xfae, 0xfb0, UnicodeVersion::new(3, 0)),
+	(0xfb1, 0xfb7, UnicodeVersion::new(2, 0)),
+	(0xfb8, 0xfb8, UnicodeVersion::new(3, 0)),
+	(0xfb9, 0xfb9, UnicodeVersion::new(2, 0)),
+	(0xfba, 0xfbc, UnicodeVersion::new(3, 0)),
+	(0xfbe, 0xfcc, UnicodeVersion::new(3, 0)),
+	(0xfce, 0xfce, UnicodeVersion::new(5, 1)),
+	(0xfcf, 0xfcf, UnicodeVersion::new(3, 0)),
+	(0xfd0, 0xfd1, UnicodeVersion::new(4, 1)),
+	(0xfd2, 0xfd4, UnicodeVersion::new(5, 1)),
+	(0xfd5, 0xfd8, UnicodeVersion::new(5, 2)),
+	(0xfd9, 0xfda, UnicodeVersion::new(6, 0)),
+	(0x1000, 0x1021, UnicodeVersion::new(3, 0)),
+	(0x1022, 0x1022, UnicodeVersion::new(5, 1)),
+	(0x1023, 0x1027, UnicodeVersion::new(3, 0)),
+	(0x1028, 0x1028, UnicodeVersion::new(5, 1)),
+	(0x1029, 0x102a, UnicodeVersion::new(3, 0)),
+	(0x102b, 0x102b, UnicodeVersion::new(5, 1)),
+	(0x102c, 0x1032, UnicodeVersion::new(3, 0)),
+	(0x1033, 0x1035, UnicodeVersion::new(5, 1)),
+	(0x1036, 0x1039, UnicodeVersion::new(3, 0)),
+	(0x103a, 0x103f, UnicodeVersion::new(5, 1)),
+	(0x1040, 0x1059, UnicodeVersion::new(3, 0)),
+	(0x105a, 0x1099, UnicodeVersion::new(5, 1)),
+	(0x109a, 0x109d, UnicodeVersion::new(5, 2)),
+	(0x109e, 0x109f, UnicodeVersion::new(5, 1)),
+	(0x10a0, 0x10c5, UnicodeVersion::new(1, 1)),
+	(0x10c7, 0x10c7, UnicodeVersion::new(6, 1)),
+	(0x10cd, 0x10cd, UnicodeVersion::new(6, 1)),
+	(0x10d0, 0x10f6, UnicodeVersion::new(1, 1)),
+	(0x10f7, 0x10f8, UnicodeVersion::new(3, 2)),
+	(0x10f9, 0x10fa, UnicodeVersion::new(4, 1)),
+	(0x10fb, 0x10fb, UnicodeVersion::new(1, 1)),
+	(0x10fc, 0x10fc, UnicodeVersion::new(4, 1)),
+	(0x10fd, 0x10ff, UnicodeVersion::new(6, 1)),
+	(0x1100, 0x1159, UnicodeVersion::new(1, 1)),
+	(0x115a, 0x115e, UnicodeVersion::new(5, 2)),
+	(0x115f, 0x11a2, UnicodeVersion::new(1, 1)),
+	(0x11a3, 0x11a7, UnicodeVersion::new(5, 2)),
+	(0x11a8, 0x11f9, UnicodeVersion::new(1, 1)),
+	(0x11fa, 0x11ff, UnicodeVersion::new(5, 2)),
+	(0x1200, 0x1206, UnicodeVersion::new(3, 0)),
+	(0x1207, 0x1207, UnicodeVersion::new(4, 1)),
+	(0x1208, 0x1246, UnicodeVersion::new(3, 0)),
+	(0x1247, 0x1247, UnicodeVersion::new(4, 1)),
+	(0x1248, 0x1248, UnicodeVersion::new(3, 0)),
+	(0x124a, 0x124d, UnicodeVersion::new(3, 0)),
+	(0x1250, 0x1256, UnicodeVersion::new(3, 0)),
+	(0x1258, 0x1258, UnicodeVersion::new(3, 0)),
+	(0x125a, 0x125d, UnicodeVersion::new(3, 0)),
+	(0x1260, 0x1286, UnicodeVersion::new(3, 0)),
+	(0x1287, 0x1287, UnicodeVersion::new(4, 1)),
+	(0x1288, 0x1288, UnicodeVersion::new(3, 0)),
+	(0x128a, 0x128d, UnicodeVersion::new(3, 0)),
+	(0x1290, 0x12ae, UnicodeVersion::new(3, 0)),
+	(0x12af, 0x12af, UnicodeVersion::new(4, 1)),
+	(0x12b0, 0x12b0, UnicodeVersion::new(3, 0)),
+	(0x12b2, 0x12b5, UnicodeVersion::new(3, 0)),
+	(0x12b8, 0x12be, UnicodeVersion::new(3, 0)),
+	(0x12c0, 0x12c0, UnicodeVersion::new(3, 0)),
+	(0x12c2, 0x12c5, UnicodeVersion::new(3, 0)),
+	(0x12c8, 0x12ce, UnicodeVersion::new(3, 0)),
+	(0x12cf, 0x12cf, UnicodeVersion::new(4, 1)),
+	(0x12d0, 0x12d6, UnicodeVersion::new(3, 0)),
+	(0x12d8, 0x12ee, UnicodeVersion::new(3, 0)),
+	(0x12ef, 0x12ef, UnicodeVersion::new(4, 1)),
+	(0x12f0, 0x130e, UnicodeVersion::new(3, 0)),
+	(0x130f, 0x130f, UnicodeVersion::new(4, 1)),
+	(0x1310, 0x1310, UnicodeVersion::new(3, 0)),
+	(0x1312, 0x1315, UnicodeVersion::new(3, 0)),
+	(0x1318, 0x131e, UnicodeVersion::new(3, 0)),
+	(0x131f, 0x131f, UnicodeVersion::new(4, 1)),
+	(0x1320, 0x1346, UnicodeVersion::new(3, 0)),
+	(0x1347, 0x1347, UnicodeVersion::new(4, 1)),
+	(0x1348, 0x135a, UnicodeVersion::new(3, 0)),
+	(0x135d, 0x135e, UnicodeVersion::new(6, 0)),
+	(0x135f, 0x1360, UnicodeVersion::new(4, 1)),
+	(0x1361, 0x137c, UnicodeVersion::new(3, 0)),
+	(0x1380, 0x1399, UnicodeVersion::new(4, 1)),
+	(0x13a0, 0x13f4, UnicodeVersion::new(3, 0)),
+	(0x13f5, 0x13f5, UnicodeVersion::new(8, 0)),
+	(0x13f8, 0x13fd, UnicodeVersion::new(8, 0)),
+	(0x1400, 0x1400, UnicodeVersion::new(5, 2)),
+	(0x1401, 0x1676, UnicodeVersion::new(3, 0)),
+	(0x1677, 0x167f, UnicodeVersion::new(5, 2)),
+	(0x1680, 0x169c, UnicodeVersion::new(3, 0)),
+	(0x16a0, 0x16f0, UnicodeVersion::new(3, 0)),
+	(0x16f1, 0x16f8, UnicodeVersion::new(7, 0)),
+	(0x1700, 0x170c, UnicodeVersion::new(3, 2)),
+	(0x170d, 0x170d, UnicodeVersion::new(14, 0)),
+	(0x170e, 0x1714, UnicodeVersion::new(3, 2)),
+	(0x1715, 0x1715, UnicodeVersion::new(14, 0)),
+	(0x171f, 0x171f, UnicodeVersion::new(14, 0)),
+	(0x1720, 0x1736, UnicodeVersion::new(3, 2)),
+	(0x1740, 0x1753, UnicodeVersion::new(3, 2)),
+	(0x1760, 0x176c, UnicodeVersion::new(3, 2)),
+	(0x176e, 0x1770, UnicodeVersion::new(3, 2)),
+	(0x1772, 0x1773, UnicodeVersion::new(3, 2)),
+	(0x1780, 0x17dc, UnicodeVersion::new(3, 0)),
+	(0x17dd, 0x17dd, UnicodeVersion::new(4, 0)),
+	(0x17e0, 0x17e9, UnicodeVersion::new(3, 0)),
+	(0x17f0, 0x17f9, UnicodeVersion::new(4, 0)),
+	(0x1800, 0x180e, UnicodeVersion::new(3, 0)),
+	(0x180f, 0x180f, UnicodeVersion::new(14, 0)),
+	(0x1810, 0x1819, UnicodeVersion::new(3, 0)),
+	(0x1820, 0x1877, UnicodeVersion::new(3, 0)),
+	(0x1878, 0x1878, UnicodeVersion::new(11, 0)),
+	(0x1880, 0x18a9, UnicodeVersion::new(3, 0)),
+	(0x18aa, 0x18aa, UnicodeVersion::new(5, 1)),
+	(0x18b0, 0x18f5, UnicodeVersion::new(5, 2)),
+	(0x1900, 0x191c, UnicodeVersion::new(4, 0)),
+	(0x191d, 0x191e, UnicodeVersion::new(7, 0)),
+	(0x1920, 0x192b, UnicodeVersion::new(4, 0)),
+	(0x1930, 0x193b, UnicodeVersion::new(4, 0)),
+	(0x1940, 0x1940, UnicodeVersion::new(4, 0)),
+	(0x1944, 0x196d, UnicodeVersion::new(4, 0)),
+	(0x1970, 0x1974, UnicodeVersion::new(4, 0)),
+	(0x1980, 0x19a9, UnicodeVersion::new(4, 1)),
+	(0x19aa, 0x19ab, UnicodeVersion::new(5, 2)),
+	(0x19b0, 0x19c9, UnicodeVersion::new(4, 1)),
+	(0x19d0, 0x19d9, UnicodeVersion::new(4, 1)),
+	(0x19da, 0x19da, UnicodeVersion::new(5, 2)),
+	(0x19de, 0x19df, UnicodeVersion::new(4, 1)),
+	(0x19e0, 0x19ff, UnicodeVersion::new(4, 0)),
+	(0x1a00, 0x1a1b, UnicodeVersion::new(4, 1)),
+	(0x1a1e, 0x1a1f, UnicodeVersion::new(4, 1)),
+	(0x1a20, 0x1a5e, UnicodeVersion::new(5, 2)),
+	(0x1a60, 0x1a7c, UnicodeVersion::new(5, 2)),
+	(0x1a7f, 0x1a89, UnicodeVersion::new(5, 2)),
+	(0x1a90, 0x1a99, UnicodeVersion::new(5, 2)),
+	(0x1aa0, 0x1aad, UnicodeVersion::new(5, 2)),
+	(0x1ab0, 0x1abe, UnicodeVersion::new(7, 0)),
+	(0x1abf, 0x1ac0, UnicodeVersion::new(13, 0)),
+	(0x1ac1, 0x1ace, UnicodeVersion::new(14, 0)),
+	(0x1b00, 0x1b4b, UnicodeVersion::new(5, 0)),
+	(0x1b4c, 0x1b4c, UnicodeVersion::new(14, 0)),
+	(0x1b50, 0x1b7c, UnicodeVersion::new(5, 0)),
+	(0x1b7d, 0x1b7e, UnicodeVersion::new(14, 0)),
+	(0x1b80, 0x1baa, UnicodeVersion::new(5, 1)),
+	(0x1bab, 0x1bad, UnicodeVersion::new(6, 1)),
+	(0x1bae, 0x1bb9, UnicodeVersion::new(5, 1)),
+	(0x1bba, 0x1bbf, UnicodeVersion::new(6, 1)),
+	(0x1bc0, 0x1bf3, UnicodeVersion::new(6, 0)),
+	(0x1bfc, 0x1bff, UnicodeVersion::new(6, 0)),
+	(0x1c00, 0x1c37, UnicodeVersion::new(5, 1)),
+	(0x1c3b, 0x1c49, UnicodeVersion::new(5, 1)),
+	(0x1c4d, 0x1c7f, UnicodeVersion::new(5, 1)),
+	(0x1c80, 0x1c88, UnicodeVersion::new(9, 0)),
+	(0x1c90, 0x1cba, UnicodeVersion::new(11, 0)),
+	(0x1cbd, 0x1cbf, UnicodeVersion::new(11, 0)),
+	(0x1cc0, 0x1cc7, UnicodeVersion::new(6, 1)),
+	(0x1cd0, 0x1cf2, UnicodeVersion::new(5, 2)),
+	(0x1cf3, 0x1cf6, UnicodeVersion::new(6, 1)),
+	(0x1cf7, 0x1cf7, UnicodeVersion::new(10, 0)),
+	(0x1cf8, 0x1cf9, UnicodeVersion::new(7, 0)),
+	(0x1cfa, 0x1cfa, UnicodeVersion::new(12, 0)),
+	(0x1d00, 0x1d6b, UnicodeVersion::new(4, 0)),
+	(0x1d6c, 0x1dc3, UnicodeVersion::new(4, 1)),
+	(0x1dc4, 0x1dca, UnicodeVersion::new(5, 0)),
+	(0x1dcb, 0x1de6, UnicodeVersion::new(5, 1)),
+	(0x1de7, 0x1df5, UnicodeVersion::new(7, 0)),
+	(0x1df6, 0x1df9, UnicodeVersion::new(10, 0)),
+	(0x1dfa, 0x1dfa, UnicodeVersion::new(14, 0)),
+	(0x1dfb, 0x1dfb, UnicodeVersion::new(9, 0)),
+	(0x1dfc, 0x1dfc, UnicodeVersion::new(6, 0)),
+	(0x1dfd, 0x1dfd, UnicodeVersion::new(5, 2)),
+	(0x1dfe, 0x1dff, UnicodeVersion::new(5, 0)),
+	(0x1e00, 0x1e9a, UnicodeVersion::new(1, 1)),
+	(0x1e9b, 0x1e9b, UnicodeVersion::new(2, 0)),
+	(0x1e9c, 0x1e9f, UnicodeVersion::new(5, 1)),
+	(0x1ea0, 0x1ef9, UnicodeVersion::new(1, 1)),
+	(0x1efa, 0x1eff, UnicodeVersion::new(5, 1)),
+	(0x1f00, 0x1f15, UnicodeVersion::new(1, 1)),
+	(0x1f18, 0x1f1d, UnicodeVersion::new(1, 1)),
+	(0x1f20, 0x1f45, UnicodeVersion::new(1, 1)),
+	(0x1f48, 0x1f4d, UnicodeVersion::new(1, 1)),
+	(0x1f50, 0x1f57, UnicodeVersion::new(1, 1)),
+	(0x1f59, 0x1f59, UnicodeVersion::new(1, 1)),
+	(0x1f5b, 0x1f5b, UnicodeVersion::new(1, 1)),
+	(0x1f5d, 0x1f5d, UnicodeVersion::new(1, 1)),
+	(0x1f5f, 0x1f7d, UnicodeVersion::new(1, 1)),
+	(0x1f80, 0x1fb4, UnicodeVersion::new(1, 1)),
+	(0x1fb6, 0x1fc4, UnicodeVersion::new(1, 1)),
+	(0x1fc6, 0x1fd3, UnicodeVersion::new(1, 1)),
+	(0x1fd6, 0x1fdb, UnicodeVersion::new(1, 1)),
+	(0x1fdd, 0x1fef, UnicodeVersion::new(1, 1)),
+	(0x1ff2, 0x1ff4, UnicodeVersion::new(1, 1)),
+	(0x1ff6, 0x1ffe, UnicodeVersion::new(1, 1)),
+	(0x2000, 0x202e, UnicodeVersion::new(1, 1)),
+	(0x202f, 0x202f, UnicodeVersion::new(3, 0)),
+	(0x2030, 0x2046, UnicodeVersion::new(1, 1)),
+	(0x2047, 0x2047, UnicodeVersion::new(3, 2)),
+	(0x2048, 0x204d, UnicodeVersion::new(3, 0)),
+	(0x204e, 0x2052, UnicodeVersion::new(3, 2)),
+	(0x2053, 0x2054, UnicodeVersion::new(4, 0)),
+	(0x2055, 0x2056, UnicodeVersion::new(4, 1)),
+	(0x2057, 0x2057, UnicodeVersion::new(3, 2)),
+	(0x2058, 0x205e, UnicodeVersion::new(4, 1)),
+	(0x205f, 0x2063, UnicodeVersion::new(3, 2)),
+	(0x2064, 0x2064, UnicodeVersion::new(5, 1)),
+	(0x2066, 0x2069, UnicodeVersion::new(6, 3)),
+	(0x206a, 0x2070, UnicodeVersion::new(1, 1)),
+	(0x2071, 0x2071, UnicodeVersion::new(3, 2)),
+	(0x2074, 0x208e, UnicodeVersion::new(1, 1)),
+	(0x2090, 0x2094, UnicodeVersion::new(4, 1)),
+	(0x2095, 0x209c, UnicodeVersion::new(6, 0)),
+	(0x20a0, 0x20aa, UnicodeVersion::new(1, 1)),
+	(0x20ab, 0x20ab, UnicodeVersion::new(2, 0)),
+	(0x20ac, 0x20ac, UnicodeVersion::new(2, 1)),
+	(0x20ad, 0x20af, UnicodeVersion::new(3, 0)),
+	(0x20b0, 0x20b1, UnicodeVersion::new(3, 2)),
+	(0x20b2, 0x20b5, UnicodeVersion::new(4, 1)),
+	(0x20b6, 0x20b8, UnicodeVersion::new(5, 2)),
+	(0x20b9, 0x20b9, UnicodeVersion::new(6, 0)),
+	(0x20ba, 0x20ba, UnicodeVersion::new(6, 2)),
+	(0x20bb, 0x20bd, UnicodeVersion::new(7, 0)),
+	(0x20be, 0x20be, UnicodeVersion::new(8, 0)),
+	(0x20bf, 0x20bf, UnicodeVersion::new(10, 0)),
+	(0x20c0, 0x20c0, UnicodeVersion::new(14, 0)),
+	(0x20d0, 0x20e1, UnicodeVersion::new(1, 1)),
+	(0x20e2, 0x20e3, UnicodeVersion::new(3, 0)),
+	(0x20e4, 0x20ea, UnicodeVersion::new(3, 2)),
+	(0x20eb, 0x20eb, UnicodeVersion::new(4, 1)),
+	(0x20ec, 0x20ef, UnicodeVersion::new(5, 0)),
+	(0x20f0, 0x20f0, UnicodeVersion::new(5, 1)),
+	(0x2100, 0x2138, UnicodeVersion::new(1, 1)),
+	(0x2139, 0x213a, UnicodeVersion::new(3, 0)),
+	(0x213b, 0x213b, UnicodeVersion::new(4, 0)),
+	(0x213c, 0x213c, UnicodeVersion::new(4, 1)),
+	(0x213d, 0x214b, UnicodeVersion::new(3, 2)),
+	(0x214c, 0x214c, UnicodeVersion::new(4, 1)),
+	(0x214d, 0x214e, UnicodeVersion::new(5, 0)),
+	(0x214f, 0x214f, UnicodeVersion::new(5, 1)),
+	(0x2150, 0x2152, UnicodeVersion::new(5, 2)),
+	(0x2153, 0x2182, UnicodeVersion::new(1, 1)),
+	(0x2183, 0x2183, UnicodeVersion::new(3, 0)),
+	(0x2184, 0x2184, UnicodeVersion::new(5, 0)),
+	(0x2185, 0x2188, UnicodeVersion::new(5, 1)),
+	(0x2189, 0x2189, UnicodeVersion::new(5, 2)),
+	(0x218a, 0x218b, UnicodeVersion::new(8, 0)),
+	(0x2190, 0x21ea, UnicodeVersion::new(1, 1)),
+	(0x21eb, 0x21f3, UnicodeVersion::new(3, 0)),
+	(0x21f4, 0x21ff, UnicodeVersion::new(3, 2)),
+	(0x2200, 0x22f1, UnicodeVersion::new(1, 1)),
+	(0x22f2, 0x22ff, UnicodeVersion::new(3, 2)),
+	(0x2300, 0x2300, UnicodeVersion::new(1, 1)),
+	(0x2301, 0x2301, UnicodeVersion::new(3, 0)),
+	(0x2302, 0x237a, UnicodeVersion::new(1, 1)),
+	(0x237b, 0x237b, UnicodeVersion::new(3, 0)),
+	(0x237c, 0x237c, UnicodeVersion::new(3, 2)),
+	(0x237d, 0x239a, UnicodeVersion::new(3, 0)),
+	(0x239b, 0x23ce, UnicodeVersion::new(3, 2)),
+	(0x23cf, 0x23d0, UnicodeVersion::new(4, 0)),
+	(0x23d1, 0x23db, UnicodeVersion::new(4, 1)),
+	(0x23dc, 0x23e7, UnicodeVersion::new(5, 0)),
+	(0x23e8, 0x23e8, UnicodeVersion::new(5, 2)),
+	(0x23e9, 0x23f3, UnicodeVersion::new(6, 0)),
+	(0x23f4, 0x23fa, UnicodeVersion::new(7, 0)),
+	(0x23fb, 0x23fe, UnicodeVersion::new(9, 0)),
+	(0x23ff, 0x23ff, UnicodeVersion::new(10, 0)),
+	(0x2400, 0x2424, UnicodeVersion::new(1, 1)),
+	(0x2425, 0x2426, UnicodeVersion::new(3, 0)),
+	(0x2440, 0x244a, UnicodeVersion::new(1, 1)),
+	(0x2460, 0x24ea, UnicodeVersion::new(1, 1)),
+	(0x24eb, 0x24fe, UnicodeVersion::new(3, 2)),
+	(0x24ff, 0x24ff, UnicodeVersion::new(4, 0)),
+	(0x2500, 0x2595, UnicodeVersion::new(1, 1)),
+	(0x2596, 0x259f, UnicodeVersion::new(3, 2)),
+	(0x25a0, 0x25ef, UnicodeVersion::new(1, 1)),
+	(0x25f0, 0x25f7, UnicodeVersion::new(3, 0)),
+	(0x25f8, 0x25ff, UnicodeVersion::new(3, 2)),
+	(0x2600, 0x2613, UnicodeVersion::new(1, 1)),
+	(0x2614, 0x2615, UnicodeVersion::new(4, 0)),
+	(0x2616, 0x2617, UnicodeVersion::new(3, 2)),
+	(0x2618, 0x2618, UnicodeVersion::new(4, 1)),
+	(0x2619, 0x2619, UnicodeVersion::new(3, 0)),
+	(0x261a, 0x266f, UnicodeVersion::new(1, 1)),
+	(0x2670, 0x2671, UnicodeVersion::new(3, 0)),
+	(0x2672, 0x267d, UnicodeVersion::new(3, 2)),
+	(0x267e, 0x267f, UnicodeVersion::new(4, 1)),
+	(0x2680, 0x2689, UnicodeVersion::new(3, 2)),
+	(0x268a, 0x2691, UnicodeVersion::new(4, 0)),
+	(0x2692, 0x269c, UnicodeVersion::new(4, 1)),
+	(0x269d, 0x269d, UnicodeVersion::new(5, 1)),
+	(0x269e, 0x269f, UnicodeVersion::new(5, 2)),
+	(0x26a0, 0x26a1, UnicodeVersion::new(4, 0)),
+	(0x26a2, 0x26b1, UnicodeVersion::new(4, 1)),
+	(0x26b2, 0x26b2, UnicodeVersion::new(5, 0)),
+	(0x26b3, 0x26bc, UnicodeVersion::new(5, 1)),
+	(0x26bd, 0x26bf, UnicodeVersion::new(5, 2)),
+	(0x26c0, 0x26c3, UnicodeVersion::new(5, 1)),
+	(0x26c4, 0x26cd, UnicodeVersion::new(5, 2)),
+	(0x26ce, 0x26ce, UnicodeVersion::new(6, 0)),
+	(0x26cf, 0x26e1, UnicodeVersion::new(5, 2)),
+	(0x26e2, 0x26e2, UnicodeVersion::new(6, 0)),
+	(0x26e3, 0x26e3, UnicodeVersion::new(5, 2)),
+	(0x26e4, 0x26e7, UnicodeVersion::new(6, 0)),
+	(0x26e8, 0x26ff, UnicodeVersion::new(5, 2)),
+	(0x2700, 0x2700, UnicodeVersion::new(7, 0)),
+	(0x2701, 0x2704, UnicodeVersion::new(1, 1)),
+	(0x2705, 0x2705, UnicodeVersion::new(6, 0)),
+	(0x2706, 0x2709, UnicodeVersion::new(1, 1)),
+	(0x270a, 0x270b, UnicodeVersion::new(6, 0)),
+	(0x270c, 0x2727, UnicodeVersion::new(1, 1)),
+	(0x2728, 0x2728, UnicodeVersion::new(6, 0)),
+	(0x2729, 0x274b, UnicodeVersion::new(1, 1)),
+	(0x274c, 0x274c, UnicodeVersion::new(6, 0)),
+	(0x274d, 0x274d, UnicodeVersion::new(1, 1)),
+	(0x274e, 0x274e, UnicodeVersion::new(6, 0)),
+	(0x274f, 0x2752, UnicodeVersion::new(1, 1)),
+	(0x2753, 0x2755, UnicodeVersion::new(6, 0)),
+	(0x2756, 0x2756, UnicodeVersion::new(1, 1)),
+	(0x2757, 0x2757, UnicodeVersion::new(5, 2)),
+	(0x2758, 0x275e, UnicodeVersion::new(1, 1)),
+	(0x275f, 0x2760, UnicodeVersion::new(6, 0)),
+	(0x2761, 0x2767, UnicodeVersion::new(1, 1)),
+	(0x2768, 0x2775, UnicodeVersion::new(3, 2)),
+	(0x2776, 0x2794, UnicodeVersion::new(1, 1)),
+	(0x2795, 0x2797, UnicodeVersion::new(6, 0)),
+	(0x2798, 0x27af, UnicodeVersion::new(1, 1)),
+	(0x27b0, 0x27b0, UnicodeVersion::new(6, 0)),
+	(0x27b1, 0x27be, UnicodeVersion::new(1, 1)),
+	(0x27bf, 0x27bf, UnicodeVersion::new(6, 0)),
+	(0x27c0, 0x27c6, UnicodeVersion::new(4, 1)),
+	(0x27c7, 0x27ca, UnicodeVersion::new(5, 0)),
+	(0x27cb, 0x27cb, UnicodeVersion::new(6, 1)),
+	(0x27cc, 0x27cc, UnicodeVersion::new(5, 1)),
+	(0x27cd, 0x27cd, UnicodeVersion::new(6, 1)),
+	(0x27ce, 0x27cf, UnicodeVersion::new(6, 0)),
+	(0x27d0, 0x27eb, UnicodeVersion::new(3, 2)),
+	(0x27ec, 0x27ef, UnicodeVersion::new(5, 1)),
+	(0x27f0, 0x27ff, UnicodeVersion::new(3, 2)),
+	(0x2800, 0x28ff, UnicodeVersion::new(3, 0)),
+	(0x2900, 0x2aff, UnicodeVersion::new(3, 2)),
+	(0x2b00, 0x2b0d, UnicodeVersion::new(4, 0)),
+	(0x2b0e, 0x2b13, UnicodeVersion::new(4, 1)),
+	(0x2b14, 0x2b1a, UnicodeVersion::new(5, 0)),
+	(0x2b1b, 0x2b1f, UnicodeVersion::new(5, 1)),
+	(0x2b20, 0x2b23, UnicodeVersion::new(5, 0)),
+	(0x2b24, 0x2b4c, UnicodeVersion::new(5, 1)),
+	(0x2b4d, 0x2b4f, UnicodeVersion::new(7, 0)),
+	(0x2b50, 0x2b54, UnicodeVersion::new(5, 1)),
+	(0x2b55, 0x2b59, UnicodeVersion::new(5, 2)),
+	(0x2b5a, 0x2b73, UnicodeVersion::new(7, 0)),
+	(0x2b76, 0x2b95, UnicodeVersion::new(7, 0)),
+	(0x2b97, 0x2b97, UnicodeVersion::new(13, 0)),
+	(0x2b98, 0x2bb9, UnicodeVersion::new(7, 0)),
+	(0x2bba, 0x2bbc, UnicodeVersion::new(11, 0)),
+	(0x2bbd, 0x2bc8, UnicodeVersion::new(7, 0)),
+	(0x2bc9, 0x2bc9, UnicodeVersion::new(12, 0)),
+	(0x2bca, 0x2bd1, UnicodeVersion::new(7, 0)),
+	(0x2bd2, 0x2bd2, UnicodeVersion::new(10, 0)),
+	(0x2bd3, 0x2beb, UnicodeVersion::new(11, 0)),
+	(0x2bec, 0x2bef, UnicodeVersion::new(8, 0)),
+	(0x2bf0, 0x2bfe, UnicodeVersion::new(11, 0)),
+	(0x2bff, 0x2bff, UnicodeVersion::new(12, 0)),
+	(0x2c00, 0x2c2e, UnicodeVersion::new(4, 1)),
+	(0x2c2f, 0x2c2f, UnicodeVersion::new(14, 0)),
+	(0x2c30, 0x2c5e, UnicodeVersion::new(4, 1)),
+	(0x2c5f, 0x2c5f, UnicodeVersion::new(14, 0)),
+	(0x2c60, 0x2c6c, UnicodeVersion::new(5, 0)),
+	(0x2c6d, 0x2c6f, UnicodeVersion::new(5, 1)),
+	(0x2c70, 0x2c70, UnicodeVersion::new(5, 2)),
+	(0x2c71, 0x2c73, UnicodeVersion::new(5, 1)),
+	(0x2c74, 0x2c77, UnicodeVersion::new(5, 0)),
+	(0x2c78, 0x2c7d, UnicodeVersion::new(5, 1)),
+	(0x2c7e, 0x2c7f, UnicodeVersion::new(5, 2)),
+	(0x2c80, 0x2cea, UnicodeVersion::new(4, 1)),
+	(0x2ceb, 0x2cf1, UnicodeVersion::new(5, 2)),
+	(0x2cf2, 0x2cf3, UnicodeVersion::new(6, 1)),
+	(0x2cf9, 0x2d25, UnicodeVersion::new(4, 1)),
+	(0x2d27, 0x2d27, UnicodeVersion::new(6, 1)),
+	(0x2d2d, 0x2d2d, UnicodeVersion::new(6, 1)),
+	(0x2d30, 0x2d65, UnicodeVersion::new(4, 1)),
+	(0x2d66, 0x2d67, UnicodeVersion::new(6, 1)),
+	(0x2d6f, 0x2d6f, UnicodeVersion::new(4, 1)),
+	(0x2d70, 0x2d70, UnicodeVersion::new(6, 0)),
+	(0x2d7f, 0x2d7f, UnicodeVersion::new(6, 0)),
+	(0x2d80, 0x2d96, UnicodeVersion::new(4, 1)),
+	(0x2da0, 0x2da6, UnicodeVersion::new(4, 1)),
+	(0x2da8, 0x2dae, UnicodeVersion::new(4, 1)),
+	(0x2db0, 0x2db6, UnicodeVersion::new(4, 1)),
+	(0x2db8, 0x2dbe, UnicodeVersion::new(4, 1)),
+	(0x2dc0, 0x2dc6, UnicodeVersion::new(4, 1)),
+	(0x2dc8, 0x2dce, UnicodeVersion::new(4, 1)),
+	(0x2dd0, 0x2dd6, UnicodeVersion::new(4, 1)),
+	(0x2dd8, 0x2dde, UnicodeVersion::new(4, 1)),
+	(0x2de0, 0x2dff, UnicodeVersion::new(5, 1)),
+	(0x2e00, 0x2e17, UnicodeVersion::new(4, 1)),
+	(0x2e18, 0x2e1b, UnicodeVersion::new(5, 1)),
+	(0x2e1c, 0x2e1d, UnicodeVersion::new(4, 1)),
+	(0x2e1e, 0x2e30, UnicodeVersion::new(5, 1)),
+	(0x2e31, 0x2e31, UnicodeVersion::new(5, 2)),
+	(0x2e32, 0x2e3b, UnicodeVersion::new(6, 1)),
+	(0x2e3c, 0x2e42, UnicodeVersion::new(7, 0)),
+	(0x2e43, 0x2e44, UnicodeVersion::new(9, 0)),
+	(0x2e45, 0x2e49, UnicodeVersion::new(10, 0)),
+	(0x2e4a, 0x2e4e, UnicodeVersion::new(11, 0)),
+	(0x2e4f, 0x2e4f, UnicodeVersion::new(12, 0)),
+	(0x2e50, 0x2e52, UnicodeVersion::new(13, 0)),
+	(0x2e53, 0x2e5d, UnicodeVersion::new(14, 0)),
+	(0x2e80, 0x2e99, UnicodeVersion::new(3, 0)),
+	(0x2e9b, 0x2ef3, UnicodeVersion::new(3, 0)),
+	(0x2f00, 0x2fd5, UnicodeVersion::new(3, 0)),
+	(0x2ff0, 0x2ffb, UnicodeVersion::new(3, 0)),
+	(0x3000, 0x3037, UnicodeVersion::new(1, 1)),
+	(0x3038, 0x303a, UnicodeVersion::new(3, 0)),
+	(0x303b, 0x303d, UnicodeVersion::new(3, 2)),
+	(0x303e, 0x303e, UnicodeVersion::new(3, 0)),
+	(0x303f, 0x303f, UnicodeVersion::new(1, 1)),
+	(0x3041, 0x3094, UnicodeVersion::new(1, 1)),
+	(0x3095, 0x3096, UnicodeVersion::new(3, 2)),
+	(0x3099, 0x309e, UnicodeVersion::new(1, 1)),
+	(0x309f, 0x30a0, UnicodeVersion::new(3, 2)),
+	(0x30a1, 0x30fe, UnicodeVersion::new(1, 1)),
+	(0x30ff, 0x30ff, UnicodeVersion::new(3, 2)),
+	(0x3105, 0x312c, UnicodeVersion::new(1, 1)),
+	(0x312d, 0x312d, UnicodeVersion::new(5, 1)),
+	(0x312e, 0x312e, UnicodeVersion::new(10, 0)),
+	(0x312f, 0x312f, UnicodeVersion::new(11, 0)),
+	(0x3131, 0x318e, UnicodeVersion::new(1, 1)),
+	(0x3190, 0x319f, UnicodeVersion::new(1, 1)),
+	(0x31a0, 0x31b7, UnicodeVersion::new(3, 0)),
+	(0x31b8, 0x31ba, UnicodeVersion::new(6, 0)),
+	(0x31bb, 0x31bf, UnicodeVersion::new(13, 0)),
+	(0x31c0, 0x31cf, UnicodeVersion::new(4, 1)),
+	(0x31d0, 0x31e3, UnicodeVersion::new(5, 1)),
+	(0x31f0, 0x31ff, UnicodeVersion::new(3, 2)),
+	(0x3200, 0x321c, UnicodeVersion::new(1, 1)),
+	(0x321d, 0x321e, UnicodeVersion::new(4, 0)),
+	(0x3220, 0x3243, UnicodeVersion::new(1, 1)),
+	(0x3244, 0x324f, UnicodeVersion::new(5, 2)),
+	(0x3250, 0x3250, UnicodeVersion::new(4, 0)),
+	(0x3251, 0x325f, UnicodeVersion::new(3, 2)),
+	(0x3260, 0x327b, UnicodeVersion::new(1, 1)),
+	(0x327c, 0x327d, UnicodeVersion::new(4, 0)),
+	(0x327e, 0x327e, UnicodeVersion::new(4, 1)),
+	(0x327f, 0x32b0, UnicodeVersion::new(1, 1)),
+	(0x32b1, 0x32bf, UnicodeVersion::new(3, 2)),
+	(0x32c0, 0x32cb, UnicodeVersion::new(1, 1)),
+	(0x32cc, 0x32cf, UnicodeVersion::new(4, 0)),
+	(0x32d0, 0x32fe, UnicodeVersion::new(1, 1)),
+	(0x32ff, 0x32ff, UnicodeVersion::new(12, 1)),
+	(0x3300, 0x3376, UnicodeVersion::new(1, 1)),
+	(0x3377, 0x337a, UnicodeVersion::new(4, 0)),
+	(0x337b, 0x33dd, UnicodeVersion::new(1, 1)),
+	(0x33de, 0x33df, UnicodeVersion::new(4, 0)),
+	(0x33e0, 0x33fe, UnicodeVersion::new(1, 1)),
+	(0x33ff, 0x33ff, UnicodeVersion::new(4, 0)),
+	(0x3400, 0x4db5, UnicodeVersion::new(3, 0)),
+	(0x4db6, 0x4dbf, UnicodeVersion::new(13, 0)),
+	(0x4dc0, 0x4dff, UnicodeVersion::new(4, 0)),
+	(0x4e00, 0x9fa5, UnicodeVersion::new(1, 1)),
+	(0x9fa6, 0x9fbb, UnicodeVersion::new(4, 1)),
+	(0x9fbc, 0x9fc3, UnicodeVersion::new(5, 1)),
+	(0x9fc4, 0x9fcb, UnicodeVersion::new(5, 2)),
+	(0x9fcc, 0x9fcc, UnicodeVersion::new(6, 1)),
+	(0x9fcd, 0x9fd5, UnicodeVersion::new(8, 0)),
+	(0x9fd6, 0x9fea, UnicodeVersion::new(10, 0)),
+	(0x9feb, 0x9fef, UnicodeVersion::new(11, 0)),
+	(0x9ff0, 0x9ffc, UnicodeVersion::new(13, 0)),
+	(0x9ffd, 0x9fff, UnicodeVersion::new(14, 0)),
+	(0xa000, 0xa48c, UnicodeVersion::new(3, 0)),
+	(0xa490, 0xa4a1, UnicodeVersion::new(3, 0)),
+	(0xa4a2, 0xa4a3, UnicodeVersion::new(3, 2)),
+	(0xa4a4, 0xa4b3, UnicodeVersion::new(3, 0)),
+	(0xa4b4, 0xa4b4, UnicodeVersion::new(3, 2)),
+	(0xa4b5, 0xa4c0, UnicodeVersion::new(3, 0)),
+	(0xa4c1, 0xa4c1, UnicodeVersion::new(3, 2)),
+	(0xa4c2, 0xa4c4, UnicodeVersion::new(3, 0)),
+	(0xa4c5, 0xa4c5, UnicodeVersion::new(3, 2)),
+	(0xa4c6, 0xa4c6, UnicodeVersion::new(3, 0)),
+	(0xa4d0, 0xa4ff, UnicodeVersion::new(5, 2)),
+	(0xa500, 0xa62b, UnicodeVersion::new(5, 1)),
+	(0xa640, 0xa65f, UnicodeVersion::new(5, 1)),
+	(0xa660, 0xa661, UnicodeVersion::new(6, 0)),
+	(0xa662, 0xa673, UnicodeVersion::new(5, 1)),
+	(0xa674, 0xa67b, UnicodeVersion::new(6, 1)),
+	(0xa67c, 0xa697, UnicodeVersion::new(5, 1)),
+	(0xa698, 0xa69d, UnicodeVersion::new(7, 0)),
+	(0xa69e, 0xa69e, UnicodeVersion::new(8, 0)),
+	(0xa69f, 0xa69f, UnicodeVersion::new(6, 1)),
+	(0xa6a0, 0xa6f7, UnicodeVersion::new(5, 2)),
+	(0xa700, 0xa716, UnicodeVersion::new(4, 1)),
+	(0xa717, 0xa71a, UnicodeVersion::new(5, 0)),
+	(0xa71b, 0xa71f, UnicodeVersion::new(5, 1)),
+	(0xa720, 0xa721, UnicodeVersion::new(5, 0)),
+	(0xa722, 0xa78c, UnicodeVersion::new(5, 1)),
+	(0xa78d, 0xa78e, UnicodeVersion::new(6, 0)),
+	(0xa78f, 0xa78f, UnicodeVersion::new(8, 0)),
+	(0xa790, 0xa791, UnicodeVersion::new(6, 0)),
+	(0xa792, 0xa793, UnicodeVersion::new(6, 1)),
+	(0xa794, 0xa79f, UnicodeVersion::new(7, 0)),
+	(0xa7a0, 0xa7a9, UnicodeVersion::new(6, 0)),
+	(0xa7aa, 0xa7aa, UnicodeVersion::new(6, 1)),
+	(0xa7ab, 0xa7ad, UnicodeVersion::new(7, 0)),
+	(0xa7ae, 0xa7ae, UnicodeVersion::new(9, 0)),
+	(0xa7af, 0xa7af, UnicodeVersion::new(11, 0)),
+	(0xa7b0, 0xa7b1, UnicodeVersion::new(7, 0)),
+	(0xa7b2, 0xa7b7, UnicodeVersion::new(8, 0)),
+	(0xa7b8, 0xa7b9, UnicodeVersion::new(11, 0)),
+	(0xa7ba, 0xa7bf, UnicodeVersion::new(12, 0)),
+	(0xa7c0, 0xa7c1, UnicodeVersion::new(14, 0)),
+	(0xa7c2, 0xa7c6, UnicodeVersion::new(12, 0)),
+	(0xa7c7, 0xa7ca, UnicodeVersion::new(13, 0)),
+	(0xa7d0, 0xa7d1, UnicodeVersion::new(14, 0)),
+	(0xa7d3, 0xa7d3, UnicodeVersion::new(14, 0)),
+	(0xa7d5, 0xa7d9, UnicodeVersion::new(14, 0)),
+	(0xa7f2, 0xa7f4, UnicodeVersion::new(14, 0)),
+	(0xa7f5, 0xa7f6, UnicodeVersion::new(13, 0)),
+	(0xa7f7, 0xa7f7, UnicodeVersion::new(7, 0)),
+	(0xa7f8, 0xa7f9, UnicodeVersion::new(6, 1)),
+	(0xa7fa, 0xa7fa, UnicodeVersion::new(6, 0)),
+	(0xa7fb, 0xa7ff, UnicodeVersion::new(5, 1)),
+	(0xa800, 0xa82b, UnicodeVersion::new(4, 1)),
+	(0xa82c, 0xa82c, UnicodeVersion::new(13, 0)),
+	(0xa830, 0xa839, UnicodeVersion::new(5, 2)),
+	(0xa840, 0xa877, UnicodeVersion::new(5, 0)),
+	(0xa880, 0xa8c4, UnicodeVersion::new(5, 1)),
+	(0xa8c5, 0xa8c5, UnicodeVersion::new(9, 0)),
+	(0xa8ce, 0xa8d9, UnicodeVersion::new(5, 1)),
+	(0xa8e0, 0xa8fb, UnicodeVersion::new(5, 2)),
+	(0xa8fc, 0xa8fd, UnicodeVersion::new(8, 0)),
+	(0xa8fe, 0xa8ff, UnicodeVersion::new(11, 0)),
+	(0xa900, 0xa953, UnicodeVersion::new(5, 1)),
+	(0xa95f, 0xa95f, UnicodeVersion::new(5, 1)),
+	(0xa960, 0xa97c, UnicodeVersion::new(5, 2)),
+	(0xa980, 0xa9cd, UnicodeVersion::new(5, 2)),
+	(0xa9cf, 0xa9d9, UnicodeVersion::new(5, 2)),
+	(0xa9de, 0xa9df, UnicodeVersion::new(5, 2)),
+	(0xa9e0, 0xa9fe, UnicodeVersion::new(7, 0)),
+	(0xaa00, 0xaa36, UnicodeVersion::new(5, 1)),
+	(0xaa40, 0xaa4d, UnicodeVersion::new(5, 1)),
+	(0xaa50, 0xaa59, UnicodeVersion::new(5, 1)),
+	(0xaa5c, 0xaa5f, UnicodeVersion::new(5, 1)),
+	(0xaa60, 0xaa7b, UnicodeVersion::new(5, 2)),
+	(0xaa7c, 0xaa7f, UnicodeVersion::new(7, 0)),
+	(0xaa80, 0xaac2, UnicodeVersion::new(5, 2)),
+	(0xaadb, 0xaadf, UnicodeVersion::new(5, 2)),
+	(0xaae0, 0xaaf6, UnicodeVersion::new(6, 1)),
+	(0xab01, 0xab06, UnicodeVersion::new(6, 0)),
+	(0xab09, 0xab0e, UnicodeVersion::new(6, 0)),
+	(0xab11, 0xab16, UnicodeVersion::new(6, 0)),
+	(0xab20, 0xab26, UnicodeVersion::new(6, 0)),
+	(0xab28, 0xab2e, UnicodeVersion::new(6, 0)),
+	(0xab30, 0xab5f, UnicodeVersion::new(7, 0)),
+	(0xab60, 0xab63, UnicodeVersion::new(8, 0)),
+	(0xab64, 0xab65, UnicodeVersion::new(7, 0)),
+	(0xab66, 0xab67, UnicodeVersion::new(12, 0)),
+	(0xab68, 0xab6b, UnicodeVersion::new(13, 0)),
+	(0xab70, 0xabbf, UnicodeVersion::new(8, 0)),
+	(0xabc0, 0xabed, UnicodeVersion::new(5, 2)),
+	(0xabf0, 0xabf9, UnicodeVersion::new(5, 2)),
+	(0xac00, 0xd7a3, UnicodeVersion::new(2, 0)),
+	(0xd7b0, 0xd7c6, UnicodeVersion::new(5, 2)),
+	(0xd7cb, 0xd7fb, UnicodeVersion::new(5, 2)),
+	(0xd800, 0xdfff, UnicodeVersion::new(2, 0)),
+	(0xe000, 0xfa2d, UnicodeVersion::new(1, 1)),
+	(0xfa2e, 0xfa2f, UnicodeVersion::new(6, 1)),
+	(0xfa30, 0xfa6a, UnicodeVersion::new(3, 2)),
+	(0xfa6b, 0xfa6d, UnicodeVersion::new(5, 2)),
+	(0xfa70, 0xfad9, UnicodeVersion::new(4, 1)),
+	(0xfb00, 0xfb06, UnicodeVersion::new(1, 1)),
+	(0xfb13, 0xfb17, UnicodeVersion::new(1, 1)),
+	(0xfb1d, 0xfb1d, UnicodeVersion::new(3, 0)),
+	(0xfb1e, 0xfb36, UnicodeVersion::new(1, 1)),
+	(0xfb38, 0xfb3c, UnicodeVersion::new(1, 1)),
+	(0xfb3e, 0xfb3e, UnicodeVersion::new(1, 1)),
+	(0xfb40, 0xfb41, UnicodeVersion::new(1, 1)),
+	(0xfb43, 0xfb44, UnicodeVersion::new(1, 1)),
+	(0xfb46, 0xfbb1, UnicodeVersion::new(1, 1)),
+	(0xfbb2, 0xfbc1, UnicodeVersion::new(6, 0)),
+	(0xfbc2, 0xfbc2, UnicodeVersion::new(14, 0)),
+	(0xfbd3, 0xfd3f, UnicodeVersion::new(1, 1)),
+	(0xfd40, 0xfd4f, UnicodeVersion::new(14, 0)),
+	(0xfd50, 0xfd8f, UnicodeVersion::new(1, 1)),
+	(0xfd92, 0xfdc7, UnicodeVersion::new(1, 1)),
+	(0xfdcf, 0xfdcf, UnicodeVersion::new(14, 0)),
+	(0xfdd0, 0xfdef, UnicodeVersion::new(3, 1)),
+	(0xfdf0, 0xfdfb, UnicodeVersion::new(1, 1)),
+	(0xfdfc, 0xfdfc, UnicodeVersion::new(3, 2)),
+	(0xfdfd, 0xfdfd, UnicodeVersion::new(4, 0)),
+	(0xfdfe, 0xfdff, UnicodeVersion::new(14, 0)),
+	(0xfe00, 0xfe0f, UnicodeVersion::new(3, 2)),
+	(0xfe10, 0xfe19, UnicodeVersion::new(4, 1)),
+	(0xfe20, 0xfe23, UnicodeVersion::new(1, 1)),
+	(0xfe24, 0xfe26, UnicodeVersion::new(5, 1)),
+	(0xfe27, 0xfe2d, UnicodeVersion::new(7, 0)),
+	(0xfe2e, 0xfe2f, UnicodeVersion::new(8, 0)),
+	(0xfe30, 0xfe44, UnicodeVersion::new(1, 1)),
+	(0xfe45, 0xfe46, UnicodeVersion::new(3, 2)),
+	(0xfe47, 0xfe48, UnicodeVersion::new(4, 0)),
+	(0xfe49, 0xfe52, UnicodeVersion::new(1, 1)),
+	(0xfe54, 0xfe66, UnicodeVersion::new(1, 1)),
+	(0xfe68, 0xfe6b, UnicodeVersion::new(1, 1)),
+	(0xfe70, 0xfe72, UnicodeVersion::new(1, 1)),
+	(0xfe73, 0xfe73, UnicodeVersion::new(3, 2)),
+	(0xfe74, 0xfe74, UnicodeVersion::new(1, 1)),
+	(0xfe76, 0xfefc, UnicodeVersion::new(1, 1)),
+	(0xfeff, 0xfeff, UnicodeVersion::new(1, 1)),
+	(0xff01, 0xff5e, UnicodeVersion::new(1, 1)),
+	(0xff5f, 0xff60, UnicodeVersion::new(3, 2)),
+	(0xff61, 0xffbe, UnicodeVersion::new(1, 1)),
+	(0xffc2, 0xffc7, UnicodeVersion::new(1, 1)),
+	(0xffca, 0xffcf, UnicodeVersion::new(1, 1)),
+	(0xffd2, 0xffd7, UnicodeVersion::new(1, 1)),
+	(0xffda, 0xffdc, UnicodeVersion::new(1, 1)),
+	(0xffe0, 0xffe6, UnicodeVersion::new(1, 1)),
+	(0xffe8, 0xffee, UnicodeVersion::new(1, 1)),
+	(0xfff9, 0xfffb, UnicodeVersion::new(3, 0)),
+	(0xfffc, 0xfffc, UnicodeVersion::new(2, 1)),
+	(0xfffd, 0xffff, UnicodeVersion::new(1, 1)),
+	(0x10000, 0x1000b, UnicodeVersion::new(4, 0)),
+	(0x1000d, 0x10026, UnicodeVersion::new(4, 0)),
+	(0x10028, 0x1003a, UnicodeVersion::new(4, 0)),
+	(0x1003c, 0x1003d, UnicodeVersion::new(4, 0)),
+	(0x1003f, 0x1004d, UnicodeVersion::new(4, 0)),
+	(0x10050, 0x1005d, UnicodeVersion::new(4, 0)),
+	(0x10080, 0x100fa, UnicodeVersion::new(4, 0)),
+	(0x10100, 0x10102, UnicodeVersion::new(4, 0)),
+	(0x10107, 0x10133, UnicodeVersion::new(4, 0)),
+	(0x10137, 0x1013f, UnicodeVersion::new(4, 0)),
+	(0x10140, 0x1018a, UnicodeVersion::new(4, 1)),
+	(0x1018b, 0x1018c, UnicodeVersion::new(7, 0)),
+	(0x1018d, 0x1018e, UnicodeVersion::new(9, 0)),
+	(0x10190, 0x1019b, UnicodeVersion::new(5, 1)),
+	(0x1019c, 0x1019c, UnicodeVersion::new(13, 0)),
+	(0x101a0, 0x101a0, UnicodeVersion::new(7, 0)),
+	(0x101d0, 0x101fd, UnicodeVersion::new(5, 1)),
+	(0x10280, 0x1029c, UnicodeVersion::new(5, 1)),
+	(0x102a0, 0x102d0, UnicodeVersion::new(5, 1)),
+	(0x102e0, 0x102fb, UnicodeVersion::new(7, 0)),
+	(0x10300, 0x1031e, UnicodeVersion::new(3, 1)),
+	(0x1031f, 0x1031f, UnicodeVersion::new(7, 0)),
+	(0x10320, 0x10323, UnicodeVersion::new(3, 1)),
+	(0x1032d, 0x1032f, UnicodeVersion::new(10, 0)),
+	(0x10330, 0x1034a, UnicodeVersion::new(3, 1)),
+	(0x10350, 0x1037a, UnicodeVersion::new(7, 0)),
+	(0x10380, 0x1039d, UnicodeVersion::new(4, 0)),
+	(0x1039f, 0x1039f, UnicodeVersion::new(4, 0)),
+	(0x103a0, 0x103c3, UnicodeVersion::new(4, 1)),
+	(0x103c8, 0x103d5, UnicodeVersion::new(4, 1)),
+	(0x10400, 0x10425, UnicodeVersion::new(3, 1)),
+	(0x10426, 0x10427, UnicodeVersion::new(4, 0)),
+	(0x10428, 0x1044d, UnicodeVersion::new(3, 1)),
+	(0x1044e, 0x1049d, UnicodeVersion::new(4, 0)),
+	(0x104a0, 0x104a9, UnicodeVersion::new(4, 0)),
+	(0x104b0, 0x104d3, UnicodeVersion::new(9, 0)),
+	(0x104d8, 0x104fb, UnicodeVersion::new(9, 0)),
+	(0x10500, 0x10527, UnicodeVersion::new(7, 0)),
+	(0x10530, 0x10563, UnicodeVersion::new(7, 0)),
+	(0x1056f, 0x1056f, UnicodeVersion::new(7, 0)),
+	(0x10570, 0x1057a, UnicodeVersion::new(14, 0)),
+	(0x1057c, 0x1058a, UnicodeVersion::new(14, 0)),
+	(0x1058c, 0x10592, UnicodeVersion::new(14, 0)),
+	(0x10594, 0x10595, UnicodeVersion::new(14, 0)),
+	(0x10597, 0x105a1, UnicodeVersion::new(14, 0)),
+	(0x105a3, 0x105b1, UnicodeVersion::new(14, 0)),
+	(0x105b3, 0x105b9, UnicodeVersion::new(14, 0)),
+	(0x105bb, 0x105bc, UnicodeVersion::new(14, 0)),
+	(0x10600, 0x10736, UnicodeVersion::new(7, 0)),
+	(0x10740, 0x10755, UnicodeVersion::new(7, 0)),
+	(0x10760, 0x10767, UnicodeVersion::new(7, 0)),
+	(0x10780, 0x10785, UnicodeVersion::new(14, 0)),
+	(0x10787, 0x107b0, UnicodeVersion::new(14, 0)),
+	(0x107b2, 0x107ba, UnicodeVersion::new(14, 0)),
+	(0x10800, 0x10805, UnicodeVersion::new(4, 0)),
+	(0x10808, 0x10808, UnicodeVersion::new(4, 0)),
+	(0x1080a, 0x10835, UnicodeVersion::new(4, 0)),
+	(0x10837, 0x10838, UnicodeVersion::new(4, 0)),
+	(0x1083c, 0x1083c, UnicodeVersion::new(4, 0)),
+	(0x1083f, 0x1083f, UnicodeVersion::new(4, 0)),
+	(0x10840, 0x10855, UnicodeVersion::new(5, 2)),
+	(0x10857, 0x1085f, UnicodeVersion::new(5, 2)),
+	(0x10860, 0x1089e, UnicodeVersion::new(7, 0)),
+	(0x108a7, 0x108af, UnicodeVersion::new(7, 0)),
+	(0x108e0, 0x108f2, UnicodeVersion::new(8, 0)),
+	(0x108f4, 0x108f5, UnicodeVersion::new(8, 0)),
+	(0x108fb, 0x108ff, UnicodeVersion::new(8, 0)),
+	(0x10900, 0x10919, UnicodeVersion::new(5, 0)),
+	(0x1091a, 0x1091b, UnicodeVersion::new(5, 2)),
+	(0x1091f, 0x1091f, UnicodeVersion::new(5, 0)),
+	(0x10920, 0x10939, UnicodeVersion::new(5, 1)),
+	(0x1093f, 0x1093f, UnicodeVersion::new(5, 1)),
+	(0x10980, 0x109b7, UnicodeVersion::new(6, 1)),
+	(0x109bc, 0x109bd, UnicodeVersion::new(8, 0)),
+	(0x109be, 0x109bf, UnicodeVersion::new(6, 1)),
+	(0x109c0, 0x109cf, UnicodeVersion::new(8, 0)),
+	(0x109d2, 0x109ff, UnicodeVersion::new(8, 0)),
+	(0x10a00, 0x10a03, UnicodeVersion::new(4, 1)),
+	(0x10a05, 0x10a06, UnicodeVersion::new(4, 1)),
+	(0x10a0c, 0x10a13, UnicodeVersion::new(4, 1)),
+	(0x10a15, 0x10a17, UnicodeVersion::new(4, 1)),
+	(0x10a19, 0x10a33, UnicodeVersion::new(4, 1)),
+	(0x10a34, 0x10a35, UnicodeVersion::new(11, 0)),
+	(0x10a38, 0x10a3a, UnicodeVersion::new(4, 1)),
+	(0x10a3f, 0x10a47, UnicodeVersion::new(4, 1)),
+	(0x10a48, 0x10a48, UnicodeVersion::new(11, 0)),
+	(0x10a50, 0x10a58, UnicodeVersion::new(4, 1)),
+	(0x10a60, 0x10a7f, UnicodeVersion::new(5, 2)),
+	(0x10a80, 0x10a9f, UnicodeVersion::new(7, 0)),
+	(0x10ac0, 0x10ae6, UnicodeVersion::new(7, 0)),
+	(0x10aeb, 0x10af6, UnicodeVersion::new(7, 0)),
+	(0x10b00, 0x10b35, UnicodeVersion::new(5, 2)),
+	(0x10b39, 0x10b55, UnicodeVersion::new(5, 2)),
+	(0x10b58, 0x10b72, UnicodeVersion::new(5, 2)),
+	(0x10b78, 0x10b7f, UnicodeVersion::new(5, 2)),
+	(0x10b80, 0x10b91, UnicodeVersion::new(7, 0)),
+	(0x10b99, 0x10b9c, UnicodeVersion::new(7, 0)),
+	(0x10ba9, 0x10baf, UnicodeVersion::new(7, 0)),
+	(0x10c00, 0x10c48, UnicodeVersion::new(5, 2)),
+	(0x10c80, 0x10cb2, UnicodeVersion::new(8, 0)),
+	(0x10cc0, 0x10cf2, UnicodeVersion::new(8, 0)),
+	(0x10cfa, 0x10cff, UnicodeVersion::new(8, 0)),
+	(0x10d00, 0x10d27, UnicodeVersion::new(11, 0)),
+	(0x10d30, 0x10d39, UnicodeVersion::new(11, 0)),
+	(0x10e60, 0x10e7e, UnicodeVersion::new(5, 2)),
+	(0x10e80, 0x10ea9, UnicodeVersion::new(13, 0)),
+	(0x10eab, 0x10ead, UnicodeVersion::new(13, 0)),
+	(0x10eb0, 0x10eb1, UnicodeVersion::new(13, 0)),
+	(0x10f00, 0x10f27, UnicodeVersion::new(11, 0)),
+	(0x10f30, 0x10f59, UnicodeVersion::new(11, 0)),
+	(0x10f70, 0x10f89, UnicodeVersion::new(14, 0)),
+	(0x10fb0, 0x10fcb, UnicodeVersion::new(13, 0)),
+	(0x10fe0, 0x10ff6, UnicodeVersion::new(12, 0)),
+	(0x11000, 0x1104d, UnicodeVersion::new(6, 0)),
+	(0x11052, 0x1106f, UnicodeVersion::new(6, 0)),
+	(0x11070, 0x11075, UnicodeVersion::new(14, 0)),
+	(0x1107f, 0x1107f, UnicodeVersion::new(7, 0)),
+	(0x11080, 0x110c1, UnicodeVersion::new(5, 2)),
+	(0x110c2, 0x110c2, UnicodeVersion::new(14, 0)),
+	(0x110cd, 0x110cd, UnicodeVersion::new(11, 0)),
+	(0x110d0, 0x110e8, UnicodeVersion::new(6, 1)),
+	(0x110f0, 0x110f9, UnicodeVersion::new(6, 1)),
+	(0x11100, 0x11134, UnicodeVersion::new(6, 1)),
+	(0x11136, 0x11143, UnicodeVersion::new(6, 1)),
+	(0x11144, 0x11146, UnicodeVersion::new(11, 0)),
+	(0x11147, 0x11147, UnicodeVersion::new(13, 0)),
+	(0x11150, 0x11176, UnicodeVersion::new(7, 0)),
+	(0x11180, 0x111c8, UnicodeVersion::new(6, 1)),
+	(0x111c9, 0x111cc, UnicodeVersion::new(8, 0)),
+	(0x111cd, 0x111cd, UnicodeVersion::new(7, 0)),
+	(0x111ce, 0x111cf, UnicodeVersion::new(13, 0)),
+	(0x111d0, 0x111d9, UnicodeVersion::new(6, 1)),
+	(0x111da, 0x111da, UnicodeVersion::new(7, 0)),
+	(0x111db, 0x111df, UnicodeVersion::new(8, 0)),
+	(0x111e1, 0x111f4, UnicodeVersion::new(7, 0)),
+	(0x11200, 0x11211, UnicodeVersion::new(7, 0)),
+	(0x11213, 0x1123d, UnicodeVersion::new(7, 0)),
+	(0x1123e, 0x1123e, UnicodeVersion::new(9, 0)),
+	(0x11280, 0x11286, UnicodeVersion::new(8, 0)),
+	(0x11288, 0x11288, UnicodeVersion::new(8, 0)),
+	(0x1128a, 0x1128d, UnicodeVersion::new(8, 0)),
+	(0x1128f, 0x1129d, UnicodeVersion::new(8, 0)),
+	(0x1129f, 0x112a9, UnicodeVersion::new(8, 0)),
+	(0x112b0, 0x112ea, UnicodeVersion::new(7, 0)),
+	(0x112f0, 0x112f9, UnicodeVersion::new(7, 0)),
+	(0x11300, 0x11300, UnicodeVersion::new(8, 0)),
+	(0x11301, 0x11303, UnicodeVersion::new(7, 0)),
+	(0x11305, 0x1130c, UnicodeVersion::new(7, 0)),
+	(0x1130f, 0x11310, UnicodeVersion::new(7, 0)),
+	(0x11313, 0x11328, UnicodeVersion::new(7, 0)),
+	(0x1132a, 0x11330, UnicodeVersion::new(7, 0)),
+	(0x11332, 0x11333, UnicodeVersion::new(7, 0)),
+	(0x11335, 0x11339, UnicodeVersion::new(7, 0)),
+	(0x1133b, 0x1133b, UnicodeVersion::new(11, 0)),
+	(0x1133c, 0x11344, UnicodeVersion::new(7, 0)),
+	(0x11347, 0x11348, UnicodeVersion::new(7, 0)),
+	(0x1134b, 0x1134d, UnicodeVersion::new(7, 0)),
+	(0x11350, 0x11350, UnicodeVersion::new(8, 0)),
+	(0x11357, 0x11357, UnicodeVersion::new(7, 0)),
+	(0x1135d, 0x11363, UnicodeVersion::new(7, 0)),
+	(0x11366, 0x1136c, UnicodeVersion::new(7, 0)),
+	(0x11370, 0x11374, UnicodeVersion::new(7, 0)),
+	(0x11400, 0x11459, UnicodeVersion::new(9, 0)),
+	(0x1145a, 0x1145a, UnicodeVersion::new(13, 0)),
+	(0x1145b, 0x1145b, UnicodeVersion::new(9, 0)),
+	(0x1145d, 0x1145d, UnicodeVersion::new(9, 0)),
+	(0x1145e, 0x1145e, UnicodeVersion::new(11, 0)),
+	(0x1145f, 0x1145f, UnicodeVersion::new(12, 0)),
+	(0x11460, 0x11461, UnicodeVersion::new(13, 0)),
+	(0x11480, 0x114c7, UnicodeVersion::new(7, 0)),
+	(0x114d0, 0x114d9, UnicodeVersion::new(7, 0)),
+	(0x11580, 0x115b5, UnicodeVersion::new(7, 0)),
+	(0x115b8, 0x115c9, UnicodeVersion::new(7, 0)),
+	(0x115ca, 0x115dd, UnicodeVersion::new(8, 0)),
+	(0x11600, 0x11644, UnicodeVersion::new(7, 0)),
+	(0x11650, 0x11659, UnicodeVersion::new(7, 0)),
+	(0x11660, 0x1166c, UnicodeVersion::new(9, 0)),
+	(0x11680, 0x116b7, UnicodeVersion::new(6, 1)),
+	(0x116b8, 0x116b8, UnicodeVersion::new(12, 0)),
+	(0x116b9, 0x116b9, UnicodeVersion::new(14, 0)),
+	(0x116c0, 0x116c9, UnicodeVersion::new(6, 1)),
+	(0x11700, 0x11719, UnicodeVersion::new(8, 0)),
+	(0x1171a, 0x1171a, UnicodeVersion::new(11, 0)),
+	(0x1171d, 0x1172b, UnicodeVersion::new(8, 0)),
+	(0x11730, 0x1173f, UnicodeVersion::new(8, 0)),
+	(0x11740, 0x11746, UnicodeVersion::new(14, 0)),
+	(0x11800, 0x1183b, UnicodeVersion::new(11, 0)),
+	(0x118a0, 0x118f2, UnicodeVersion::new(7, 0)),
+	(0x118ff, 0x118ff, UnicodeVersion::new(7, 0)),
+	(0x11900, 0x11906, UnicodeVersion::new(13, 0)),
+	(0x11909, 0x11909, UnicodeVersion::new(13, 0)),
+	(0x1190c, 0x11913, UnicodeVersion::new(13, 0)),
+	(0x11915, 0x11916, UnicodeVersion::new(13, 0)),
+	(0x11918, 0x11935, UnicodeVersion::new(13, 0)),
+	(0x11937, 0x11938, UnicodeVersion::new(13, 0)),
+	(0x1193b, 0x11946, UnicodeVersion::new(13, 0)),
+	(0x11950, 0x11959, UnicodeVersion::new(13, 0)),
+	(0x119a0, 0x119a7, UnicodeVersion::new(12, 0)),
+	(0x119aa, 0x119d7, UnicodeVersion::new(12, 0)),
+	(0x119da, 0x119e4, UnicodeVersion::new(12, 0)),
+	(0x11a00, 0x11a47, UnicodeVersion::new(10, 0)),
+	(0x11a50, 0x11a83, UnicodeVersion::new(10, 0)),
+	(0x11a84, 0x11a85, UnicodeVersion::new(12, 0)),
+	(0x11a86, 0x11a9c, UnicodeVersion::new(10, 0)),
+	(0x11a9d, 0x11a9d, UnicodeVersion::new(11, 0)),
+	(0x11a9e, 0x11aa2, UnicodeVersion::new(10, 0)),
+	(0x11ab0, 0x11abf, UnicodeVersion::new(14, 0)),
+	(0x11ac0, 0x11af8, UnicodeVersion::new(7, 0)),
+	(0x11c00, 0x11c08, UnicodeVersion::new(9, 0)),
+	(0x11c0a, 0x11c36, UnicodeVersion::new(9, 0)),
+	(0x11c38, 0x11c45, UnicodeVersion::new(9, 0)),
+	(0x11c50, 0x11c6c, UnicodeVersion::new(9, 0)),
+	(0x11c70, 0x11c8f, UnicodeVersion::new(9, 0)),
+	(0x11c92, 0x11ca7, UnicodeVersion::new(9, 0)),
+	(0x11ca9, 0x11cb6, UnicodeVersion::new(9, 0)),
+	(0x11d00, 0x11d06, UnicodeVersion::new(10, 0)),
+	(0x11d08, 0x11d09, UnicodeVersion::new(10, 0)),
+	(0x11d0b, 0x11d36, UnicodeVersion::new(10, 0)),
+	(0x11d3a, 0x11d3a, UnicodeVersion::new(10, 0)),
+	(0x11d3c, 0x11d3d, UnicodeVersion::new(10, 0)),
+	(0x11d3f, 0x11d47, UnicodeVersion::new(10, 0)),
+	(0x11d50, 0x11d59, UnicodeVersion::new(10, 0)),
+	(0x11d60, 0x11d65, UnicodeVersion::new(11, 0)),
+	(0x11d67, 0x11d68, UnicodeVersion::new(11, 0)),
+	(0x11d6a, 0x11d8e, UnicodeVersion::new(11, 0)),
+	(0x11d90, 0x11d91, UnicodeVersion::new(11, 0)),
+	(0x11d93, 0x11d98, UnicodeVersion::new(11, 0)),
+	(0x11da0, 0x11da9, UnicodeVersion::new(11, 0)),
+	(0x11ee0, 0x11ef8, UnicodeVersion::new(11, 0)),
+	(0x11fb0, 0x11fb0, UnicodeVersion::new(13, 0)),
+	(0x11fc0, 0x11ff1, UnicodeVersion::new(12, 0)),
+	(0x11fff, 0x11fff, UnicodeVersion::new(12, 0)),
+	(0x12000, 0x1236e, UnicodeVersion::new(5, 0)),
+	(0x1236f, 0x12398, UnicodeVersion::new(7, 0)),
+	(0x12399, 0x12399, UnicodeVersion::new(8, 0)),
+	(0x12400, 0x12462, UnicodeVersion::new(5, 0)),
+	(0x12463, 0x1246e, UnicodeVersion::new(7, 0)),
+	(0x12470, 0x12473, UnicodeVersion::new(5, 0)),
+	(0x12474, 0x12474, UnicodeVersion::new(7, 0)),
+	(0x12480, 0x12543, UnicodeVersion::new(8, 0)),
+	(0x12f90, 0x12ff2, UnicodeVersion::new(14, 0)),
+	(0x13000, 0x1342e, UnicodeVersion::new(5, 2)),
+	(0x13430, 0x13438, UnicodeVersion::new(12, 0)),
+	(0x14400, 0x14646, UnicodeVersion::new(8, 0)),
+	(0x16800, 0x16a38, UnicodeVersion::new(6, 0)),
+	(0x16a40, 0x16a5e, UnicodeVersion::new(7, 0)),
+	(0x16a60, 0x16a69, UnicodeVersion::new(7, 0)),
+	(0x16a6e, 0x16a6f, UnicodeVersion::new(7, 0)),
+	(0x16a70, 0x16abe, UnicodeVersion::new(14, 0)),
+	(0x16ac0, 0x16ac9, UnicodeVersion::new(14, 0)),
+	(0x16ad0, 0x16aed, UnicodeVersion::new(7, 0)),
+	(0x16af0, 0x16af5, UnicodeVersion::new(7, 0)),
+	(0x16b00, 0x16b45, UnicodeVersion::new(7, 0)),
+	(0x16b50, 0x16b59, UnicodeVersion::new(7, 0)),
+	(0x16b5b, 0x16b61, UnicodeVersion::new(7, 0)),
+	(0x16b63, 0x16b77, UnicodeVersion::new(7, 0)),
+	(0x16b7d, 0x16b8f, UnicodeVersion::new(7, 0)),
+	(0x16e40, 0x16e9a, UnicodeVersion::new(11, 0)),
+	(0x16f00, 0x16f44, UnicodeVersion::new(6, 1)),
+	(0x16f45, 0x16f4a, UnicodeVersion::new(12, 0)),
+	(0x16f4f, 0x16f4f, UnicodeVersion::new(12, 0)),
+	(0x16f50, 0x16f7e, UnicodeVersion::new(6, 1)),
+	(0x16f7f, 0x16f87, UnicodeVersion::new(12, 0)),
+	(0x16f8f, 0x16f9f, UnicodeVersion::new(6, 1)),
+	(0x16fe0, 0x16fe0, UnicodeVersion::new(9, 0)),
+	(0x16fe1, 0x16fe1, UnicodeVersion::new(10, 0)),
+	(0x16fe2, 0x16fe3, UnicodeVersion::new(12, 0)),
+	(0x16fe4, 0x16fe4, UnicodeVersion::new(13, 0)),
+	(0x16ff0, 0x16ff1, UnicodeVersion::new(13, 0)),
+	(0x17000, 0x187ec, UnicodeVersion::new(9, 0)),
+	(0x187ed, 0x187f1, UnicodeVersion::new(11, 0)),
+	(0x187f2, 0x187f7, UnicodeVersion::new(12, 0)),
+	(0x18800, 0x18af2, UnicodeVersion::new(9, 0)),
+	(0x18af3, 0x18cd5, UnicodeVersion::new(13, 0)),
+	(0x18d00, 0x18d08, UnicodeVersion::new(13, 0)),
+	(0x1aff0, 0x1aff3, UnicodeVersion::new(14, 0)),
+	(0x1aff5, 0x1affb, UnicodeVersion::new(14, 0)),
+	(0x1affd, 0x1affe, UnicodeVersion::new(14, 0)),
+	(0x1b000, 0x1b001, UnicodeVersion::new(6, 0)),
+	(0x1b002, 0x1b11e, UnicodeVersion::new(10, 0)),
+	(0x1b11f, 0x1b122, UnicodeVersion::new(14, 0)),
+	(0x1b150, 0x1b152, UnicodeVersion::new(12, 0)),
+	(0x1b164, 0x1b167, UnicodeVersion::new(12, 0)),
+	(0x1b170, 0x1b2fb, UnicodeVersion::new(10, 0)),
+	(0x1bc00, 0x1bc6a, UnicodeVersion::new(7, 0)),
+	(0x1bc70, 0x1bc7c, UnicodeVersion::new(7, 0)),
+	(0x1bc80, 0x1bc88, UnicodeVersion::new(7, 0)),
+	(0x1bc90, 0x1bc99, UnicodeVersion::new(7, 0)),
+	(0x1bc9c, 0x1bca3, UnicodeVersion::new(7, 0)),
+	(0x1cf00, 0x1cf2d, UnicodeVersion::new(14, 0)),
+	(0x1cf30, 0x1cf46, UnicodeVersion::new(14, 0)),
+	(0x1cf50, 0x1cfc3, UnicodeVersion::new(14, 0)),
+	(0x1d000, 0x1d0f5, UnicodeVersion::new(3, 1)),
+	(0x1d100, 0x1d126, UnicodeVersion::new(3, 1)),
+	(0x1d129, 0x1d129, UnicodeVersion::new(5, 1)),
+	(0x1d12a, 0x1d1dd, UnicodeVersion::new(3, 1)),
+	(0x1d1de, 0x1d1e8, UnicodeVersion::new(8, 0)),
+	(0x1d1e9, 0x1d1ea, UnicodeVersion::new(14, 0)),
+	(0x1d200, 0x1d245, UnicodeVersion::new(4, 1)),
+	(0x1d2e0, 0x1d2f3, UnicodeVersion::new(11, 0)),
+	(0x1d300, 0x1d356, UnicodeVersion::new(4, 0)),
+	(0x1d360, 0x1d371, UnicodeVersion::new(5, 0)),
+	(0x1d372, 0x1d378, UnicodeVersion::new(11, 0)),
+	(0x1d400, 0x1d454, UnicodeVersion::new(3, 1)),
+	(0x1d456, 0x1d49c, UnicodeVersion::new(3, 1)),
+	(0x1d49e, 0x1d49f, UnicodeVersion::new(3, 1)),
+	(0x1d4a2, 0x1d4a2, UnicodeVersion::new(3, 1)),
+	(0x1d4a5, 0x1d4a6, UnicodeVersion::new(3, 1)),
+	(0x1d4a9, 0x1d4ac, UnicodeVersion::new(3, 1)),
+	(0x1d4ae, 0x1d4b9, UnicodeVersion::new(3, 1)),
+	(0x1d4bb, 0x1d4bb, UnicodeVersion::new(3, 1)),
+	(0x1d4bd, 0x1d4c0, UnicodeVersion::new(3, 1)),
+	(0x1d4c1, 0x1d4c1, UnicodeVersion::new(4, 0)),
+	(0x1d4c2, 0x1d4c3, UnicodeVersion::new(3, 1)),
+	(0x1d4c5, 0x1d505, UnicodeVersion::new(3, 1)),
+	(0x1d507, 0x1d50a, UnicodeVersion::new(3, 1)),
+	(0x1d50d, 0x1d514, UnicodeVersion::new(3, 1)),
+	(0x1d516, 0x1d51c, UnicodeVersion::new(3, 1)),
+	(0x1d51e, 0x1d539, UnicodeVersion::new(3, 1)),
+	(0x1d53b, 0x1d53e, UnicodeVersion::new(3, 1)),
+	(0x1d540, 0x1d544, UnicodeVersion::new(3, 1)),
+	(0x1d546, 0x1d546, UnicodeVersion::new(3, 1)),
+	(0x1d54a, 0x1d550, UnicodeVersion::new(3, 1)),
+	(0x1d552, 0x1d6a3, UnicodeVersion::new(3, 1)),
+	(0x1d6a4, 0x1d6a5, UnicodeVersion::new(4, 1)),
+	(0x1d6a8, 0x1d7c9, UnicodeVersion::new(3, 1)),
+	(0x1d7ca, 0x1d7cb, UnicodeVersion::new(5, 0)),
+	(0x1d7ce, 0x1d7ff, UnicodeVersion::new(3, 1)),
+	(0x1d800, 0x1da8b, UnicodeVersion::new(8, 0)),
+	(0x1da9b, 0x1da9f, UnicodeVersion::new(8, 0)),
+	(0x1daa1, 0x1daaf, UnicodeVersion::new(8, 0)),
+	(0x1df00, 0x1df1e, UnicodeVersion::new(14, 0)),
+	(0x1e000, 0x1e006, UnicodeVersion::new(9, 0)),
+	(0x1e008, 0x1e018, UnicodeVersion::new(9, 0)),
+	(0x1e01b, 0x1e021, UnicodeVersion::new(9, 0)),
+	(0x1e023, 0x1e024, UnicodeVersion::new(9, 0)),
+	(0x1e026, 0x1e02a, UnicodeVersion::new(9, 0)),
+	(0x1e100, 0x1e12c, UnicodeVersion::new(12, 0)),
+	(0x1e130, 0x1e13d, UnicodeVersion::new(12, 0)),
+	(0x1e140, 0x1e149, UnicodeVersion::new(12, 0)),
+	(0x1e14e, 0x1e14f, UnicodeVersion::new(12, 0)),
+	(0x1e290, 0x1e2ae, UnicodeVersion::new(14, 0)),
+	(0x1e2c0, 0x1e2f9, UnicodeVersion::new(12, 0)),
+	(0x1e2ff, 0x1e2ff, UnicodeVersion::new(12, 0)),
+	(0x1e7e0, 0x1e7e6, UnicodeVersion::new(14, 0)),
+	(0x1e7e8, 0x1e7eb, UnicodeVersion::new(14, 0)),
+	(0x1e7ed, 0x1e7ee, UnicodeVersion::new(14, 0)),
+	(0x1e7f0, 0x1e7fe, UnicodeVersion::new(14, 0)),
+	(0x1e800, 0x1e8c4, UnicodeVersion::new(7, 0)),
+	(0x1e8c7, 0x1e8d6, UnicodeVersion::new(7, 0)),
+	(0x1e900, 0x1e94a, UnicodeVersion::new(9, 0)),
+	(0x1e94b, 0x1e94b, UnicodeVersion::new(12, 0)),
+	(0x1e950, 0x1e959, UnicodeVersion::new(9, 0)),
+	(0x1e95e, 0x1e95f, UnicodeVersion::new(9, 0)),
+	(0x1ec71, 0x1ecb4, UnicodeVersion::new(11, 0)),
+	(0x1ed01, 0x1ed3d, UnicodeVersion::new(12, 0)),
+	(0x1ee00, 0x1ee03, UnicodeVersion::new(6, 1)),
+	(0x1ee05, 0x1ee1f, UnicodeVersion::new(6, 1)),
+	(0x1ee21, 0x1ee22, UnicodeVersion::new(6, 1)),
+	(0x1ee24, 0x1ee24, UnicodeVersion::new(6, 1)),
+	(0x1ee27, 0x1ee27, UnicodeVersion::new(6, 1)),
+	(0x1ee29, 0x1ee32, UnicodeVersion::new(6, 1)),
+	(0x1ee34, 0x1ee37, UnicodeVersion::new(6, 1)),
+	(0x1ee39, 0x1ee39, UnicodeVersion::new(6, 1)),
+	(0x1ee3b, 0x1ee3b, UnicodeVersion::new(6, 1)),
+	(0x1ee42, 0x1ee42, UnicodeVersion::new(6, 1)),
+	(0x1ee47, 0x1ee47, UnicodeVersion::new(6, 1)),
+	(0x1ee49, 0x1ee49, UnicodeVersion::new(6, 1)),
+	(0x1ee4b, 0x1ee4b, UnicodeVersion::new(6, 1)),
+	(0x1ee4d, 0x1ee4f, UnicodeVersion::new(6, 1)),
+	(0x1ee51, 0x1ee52, UnicodeVersion::new(6, 1)),
+	(0x1ee54, 0x1ee54, UnicodeVersion::new(6, 1)),
+	(0x1ee57, 0x1ee57, UnicodeVersion::new(6, 1)),
+	(0x1ee59, 0x1ee59, UnicodeVersion::new(6, 1)),
+	(0x1ee5b, 0x1ee5b, UnicodeVersion::new(6, 1)),
+	(0x1ee5d, 0x1ee5d, UnicodeVersion::new(6, 1)),
+	(0x1ee5f, 0x1ee5f, UnicodeVersion::new(6, 1)),
+	(0x1ee61, 0x1ee62, UnicodeVersion::new(6, 1)),
+	(0x1ee64, 0x1ee64, UnicodeVersion::new(6, 1)),
+	(0x1ee67, 0x1ee6a, UnicodeVersion::new(6, 1)),
+	(0x1ee6c, 0x1ee72, UnicodeVersion::new(6, 1)),
+	(0x1ee74, 0x1ee77, UnicodeVersion::new(6, 1)),
+	(0x1ee79, 0x1ee7c, UnicodeVersion::new(6, 1)),
+	(0x1ee7e, 0x1ee7e, UnicodeVersion::new(6, 1)),
+	(0x1ee80, 0x1ee89, UnicodeVersion::new(6, 1)),
+	(0x1ee8b, 0x1ee9b, UnicodeVersion::new(6, 1)),
+	(0x1eea1, 0x1eea3, UnicodeVersion::new(6, 1)),
+	(0x1eea5, 0x1eea9, UnicodeVersion::new(6, 1)),
+	(0x1eeab, 0x1eebb, UnicodeVersion::new(6, 1)),
+	(0x1eef0, 0x1eef1, UnicodeVersion::new(6, 1)),
+	(0x1f000, 0x1f02b, UnicodeVersion::new(5, 1)),
+	(0x1f030, 0x1f093, UnicodeVersion::new(5, 1)),
+	(0x1f0a0, 0x1f0ae, UnicodeVersion::new(6, 0)),
+	(0x1f0b1, 0x1f0be, UnicodeVersion::new(6, 0)),
+	(0x1f0bf, 0x1f0bf, UnicodeVersion::new(7, 0)),
+	(0x1f0c1, 0x1f0cf, UnicodeVersion::new(6, 0)),
+	(0x1f0d1, 0x1f0df, UnicodeVersion::new(6, 0)),
+	(0x1f0e0, 0x1f0f5, UnicodeVersion::new(7, 0)),
+	(0x1f100, 0x1f10a, UnicodeVersion::new(5, 2)),
+	(0x1f10b, 0x1f10c, UnicodeVersion::new(7, 0)),
+	(0x1f10d, 0x1f10f, UnicodeVersion::new(13, 0)),
+	(0x1f110, 0x1f12e, UnicodeVersion::new(5, 2)),
+	(0x1f12f, 0x1f12f, UnicodeVersion::new(11, 0)),
+	(0x1f130, 0x1f130, UnicodeVersion::new(6, 0)),
+	(0x1f131, 0x1f131, UnicodeVersion::new(5, 2)),
+	(0x1f132, 0x1f13c, UnicodeVersion::new(6, 0)),
+	(0x1f13d, 0x1f13d, UnicodeVersion::new(5, 2)),
+	(0x1f13e, 0x1f13e, UnicodeVersion::new(6, 0)),
+	(0x1f13f, 0x1f13f, UnicodeVersion::new(5, 2)),
+	(0x1f140, 0x1f141, UnicodeVersion::new(6, 0)),
+	(0x1f142, 0x1f142, UnicodeVersion::new(5, 2)),
+	(0x1f143, 0x1f145, UnicodeVersion::new(6, 0)),
+	(0x1f146, 0x1f146, UnicodeVersion::new(5, 2)),
+	(0x1f147, 0x1f149, UnicodeVersion::new(6, 0)),
+	(0x1f14a, 0x1f14e, UnicodeVersion::new(5, 2)),
+	(0x1f14f, 0x1f156, UnicodeVersion::new(6, 0)),
+	(0x1f157, 0x1f157, UnicodeVersion::new(5, 2)),
+	(0x1f158, 0x1f15e, UnicodeVersion::new(6, 0)),
+	(0x1f15f, 0x1f15f, UnicodeVersion::new(5, 2)),
+	(0x1f160, 0x1f169, UnicodeVersion::new(6, 0)),
+	(0x1f16a, 0x1f16b, UnicodeVersion::new(6, 1)),
+	(0x1f16c, 0x1f16c, UnicodeVersion::new(12, 0)),
+	(0x1f16d, 0x1f16f, UnicodeVersion::new(13, 0)),
+	(0x1f170, 0x1f178, UnicodeVersion::new(6, 0)),
+	(0x1f179, 0x1f179, UnicodeVersion::new(5, 2)),
+	(0x1f17a, 0x1f17a, UnicodeVersion::new(6, 0)),
+	(0x1f17b, 0x1f17c, UnicodeVersion::new(5, 2)),
+	(0x1f17d, 0x1f17e, UnicodeVersion::new(6, 0)),
+	(0x1f17f, 0x1f17f, UnicodeVersion::new(5, 2)),
+	(0x1f180, 0x1f189, UnicodeVersion::new(6, 0)),
+	(0x1f18a, 0x1f18d, UnicodeVersion::new(5, 2)),
+	(0x1f18e, 0x1f18f, UnicodeVersion::new(6, 0)),
+	(0x1f190, 0x1f190, UnicodeVersion::new(5, 2)),
+	(0x1f191, 0x1f19a, UnicodeVersion::new(6, 0)),
+	(0x1f19b, 0x1f1ac, UnicodeVersion::new(9, 0)),
+	(0x1f1ad, 0x1f1ad, UnicodeVersion::new(13, 0)),
+	(0x1f1e6, 0x1f1ff, UnicodeVersion::new(6, 0)),
+	(0x1f200, 0x1f200, UnicodeVersion::new(5, 2)),
+	(0x1f201, 0x1f202, UnicodeVersion::new(6, 0)),
+	(0x1f210, 0x1f231, UnicodeVersion::new(5, 2)),
+	(0x1f232, 0x1f23a, UnicodeVersion::new(6, 0)),
+	(0x1f23b, 0x1f23b, UnicodeVersion::new(9, 0)),
+	(0x1f240, 0x1f248, UnicodeVersion::new(5, 2)),
+	(0x1f250, 0x1f251, UnicodeVersion::new(6, 0)),
+	(0x1f260, 0x1f265, UnicodeVersion::new(10, 0)),
+	(0x1f300, 0x1f320, UnicodeVersion::new(6, 0)),
+	(0x1f321, 0x1f32c, UnicodeVersion::new(7, 0)),
+	(0x1f32d, 0x1f32f, UnicodeVersion::new(8, 0)),
+	(0x1f330, 0x1f335, UnicodeVersion::new(6, 0)),
+	(0x1f336, 0x1f336, UnicodeVersion::new(7, 0)),
+	(0x1f337, 0x1f37c, UnicodeVersion::new(6, 0)),
+	(0x1f37d, 0x1f37d, UnicodeVersion::new(7, 0)),
+	(0x1f37e, 0x1f37f, UnicodeVersion::new(8, 0)),
+	(0x1f380, 0x1f393, UnicodeVersion::new(6, 0)),
+	(0x1f394, 0x1f39f, UnicodeVersion::new(7, 0)),
+	(0x1f3a0, 0x1f3c4, UnicodeVersion::new(6, 0)),
+	(0x1f3c5, 0x1f3c5, UnicodeVersion::new(7, 0)),
+	(0x1f3c6, 0x1f3ca, UnicodeVersion::new(6, 0)),
+	(0x1f3cb, 0x1f3ce, UnicodeVersion::new(7, 0)),
+	(0x1f3cf, 0x1f3d3, UnicodeVersion::new(8, 0)),
+	(0x1f3d4, 0x1f3df, UnicodeVersion::new(7, 0)),
+	(0x1f3e0, 0x1f3f0, UnicodeVersion::new(6, 0)),
+	(0x1f3f1, 0x1f3f7, UnicodeVersion::new(7, 0)),
+	(0x1f3f8, 0x1f3ff, UnicodeVersion::new(8, 0)),
+	(0x1f400, 0x1f43e, UnicodeVersion::new(6, 0)),
+	(0x1f43f, 0x1f43f, UnicodeVersion::new(7, 0)),
+	(0x1f440, 0x1f440, UnicodeVersion::new(6, 0)),
+	(0x1f441, 0x1f441, UnicodeVersion::new(7, 0)),
+	(0x1f442, 0x1f4f7, UnicodeVersion::new(6, 0)),
+	(0x1f4f8, 0x1f4f8, UnicodeVersion::new(7, 0)),
+	(0x1f4f9, 0x1f4fc, UnicodeVersion::new(6, 0)),
+	(0x1f4fd, 0x1f4fe, UnicodeVersion::new(7, 0)),
+	(0x1f4ff, 0x1f4ff, UnicodeVersion::new(8, 0)),
+	(0x1f500, 0x1f53d, UnicodeVersion::new(6, 0)),
+	(0x1f53e, 0x1f53f, UnicodeVersion::new(7, 0)),
+	(0x1f540, 0x1f543, UnicodeVersion::new(6, 1)),
+	(0x1f544, 0x1f54a, UnicodeVersion::new(7, 0)),
+	(0x1f54b, 0x1f54f, UnicodeVersion::new(8, 0)),
+	(0x1f550, 0x1f567, UnicodeVersion::new(6, 0)),
+	(0x1f568, 0x1f579, UnicodeVersion::new(7, 0)),
+	(0x1f57a, 0x1f57a, UnicodeVersion::new(9, 0)),
+	(0x1f57b, 0x1f5a3, UnicodeVersion::new(7, 0)),
+	(0x1f5a4, 0x1f5a4, UnicodeVersion::new(9, 0)),
+	(0x1f5a5, 0x1f5fa, UnicodeVersion::new(7, 0)),
+	(0x1f5fb, 0x1f5ff, UnicodeVersion::new(6, 0)),
+	(0x1f600, 0x1f600, UnicodeVersion::new(6, 1)),
+	(0x1f601, 0x1f610, UnicodeVersion::new(6, 0)),
+	(0x1f611, 0x1f611, UnicodeVersion::new(6, 1)),
+	(0x1f612, 0x1f614, UnicodeVersion::new(6, 0)),
+	(0x1f615, 0x1f615, UnicodeVersion::new(6, 1)),
+	(0x1f616, 0x1f616, UnicodeVersion::new(6, 0)),
+	(0x1f617, 0x1f617, UnicodeVersion::new(6, 1)),
+	(0x1f618, 0x1f618, UnicodeVersion::new(6, 0)),
+	(0x1f619, 0x1f619, UnicodeVersion::new(6, 1)),
+	(0x1f61a, 0x1f61a, UnicodeVersion::new(6, 0)),
+	(0x1f61b, 0x1f61b, UnicodeVersion::new(6, 1)),
+	(0x1f61c, 0x1f61e, UnicodeVersion::new(6, 0)),
+	(0x1f61f, 0x1f61f, UnicodeVersion::new(6, 1)),
+	(0x1f620, 0x1f625, UnicodeVersion::new(6, 0)),
+	(0x1f626, 0x1f627, UnicodeVersion::new(6, 1)),
+	(0x1f628, 0x1f62b, UnicodeVersion::new(6, 0)),
+	(0x1f62c, 0x1f62c, UnicodeVersion::new(6, 1)),
+	(0x1f62d, 0x1f62d, UnicodeVersion::new(6, 0)),
+	(0x1f62e, 0x1f62f, UnicodeVersion::new(6, 1)),
+	(0x1f630, 0x1f633, UnicodeVersion::new(6, 0)),
+	(0x1f634, 0x1f634, UnicodeVersion::new(6, 1)),
+	(0x1f635, 0x1f640, UnicodeVersion::new(6, 0)),
+	(0x1f641, 0x1f642, UnicodeVersion::new(7, 0)),
+	(0x1f643, 0x1f644, UnicodeVersion::new(8, 0)),
+	(0x1f645, 0x1f64f, UnicodeVersion::new(6, 0)),
+	(0x1f650, 0x1f67f, UnicodeVersion::new(7, 0)),
+	(0x1f680, 0x1f6c5, UnicodeVersion::new(6, 0)),
+	(0x1f6c6, 0x1f6cf, UnicodeVersion::new(7, 0)),
+	(0x1f6d0, 0x1f6d0, UnicodeVersion::new(8, 0)),
+	(0x1f6d1, 0x1f6d2, UnicodeVersion::new(9, 0)),
+	(0x1f6d3, 0x1f6d4, UnicodeVersion::new(10, 0)),
+	(0x1f6d5, 0x1f6d5, UnicodeVersion::new(12, 0)),
+	(0x1f6d6, 0x1f6d7, UnicodeVersion::new(13, 0)),
+	(0x1f6dd, 0x1f6df, UnicodeVersion::new(14, 0)),
+	(0x1f6e0, 0x1f6ec, UnicodeVersion::new(7, 0)),
+	(0x1f6f0, 0x1f6f3, UnicodeVersion::new(7, 0)),
+	(0x1f6f4, 0x1f6f6, UnicodeVersion::new(9, 0)),
+	(0x1f6f7, 0x1f6f8, UnicodeVersion::new(10, 0)),
+	(0x1f6f9, 0x1f6f9, UnicodeVersion::new(11, 0)),
+	(0x1f6fa, 0x1f6fa, UnicodeVersion::new(12, 0)),
+	(0x1f6fb, 0x1f6fc, UnicodeVersion::new(13, 0)),
+	(0x1f700, 0x1f773, UnicodeVersion::new(6, 0)),
+	(0x1f780, 0x1f7d4, UnicodeVersion::new(7, 0)),
+	(0x1f7d5, 0x1f7d8, UnicodeVersion::new(11, 0)),
+	(0x1f7e0, 0x1f7eb, UnicodeVersion::new(12, 0)),
+	(0x1f7f0, 0x1f7f0, UnicodeVersion::new(14, 0)),
+	(0x1f800, 0x1f80b, UnicodeVersion::new(7, 0)),
+	(0x1f810, 0x1f847, UnicodeVersion::new(7, 0)),
+	(0x1f850, 0x1f859, UnicodeVersion::new(7, 0)),
+	(0x1f860, 0x1f887, UnicodeVersion::new(7, 0)),
+	(0x1f890, 0x1f8ad, UnicodeVersion::new(7, 0)),
+	(0x1f8b0, 0x1f8b1, UnicodeVersion::new(13, 0)),
+	(0x1f900, 0x1f90b, UnicodeVersion::new(10, 0)),
+	(0x1f90c, 0x1f90c, UnicodeVersion::new(13, 0)),
+	(0x1f90d, 0x1f90f, UnicodeVersion::new(12, 0)),
+	(0x1f910, 0x1f918, UnicodeVersion::new(8, 0)),
+	(0x1f919, 0x1f91e, UnicodeVersion::new(9, 0)),
+	(0x1f91f, 0x1f91f, UnicodeVersion::new(10, 0)),
+	(0x1f920, 0x1f927, UnicodeVersion::new(9, 0)),
+	(0x1f928, 0x1f92f, UnicodeVersion::new(10, 0)),
+	(0x1f930, 0x1f930, UnicodeVersion::new(9, 0)),
+	(0x1f931, 0x1f932, UnicodeVersion::new(10, 0)),
+	(0x1f933, 0x1f93e, UnicodeVersion::new(9, 0)),
+	(0x1f93f, 0x1f93f, UnicodeVersion::new(12, 0)),
+	(0x1f940, 0x1f94b, UnicodeVersion::new(9, 0)),
+	(0x1f94c, 0x1f94c, UnicodeVersion::new(10, 0)),
+	(0x1f94d, 0x1f94f, UnicodeVersion::new(11, 0)),
+	(0x1f950, 0x1f95e, UnicodeVersion::new(9, 0)),
+	(0x1f95f, 0x1f96b, UnicodeVersion::new(10, 0)),
+	(0x1f96c, 0x1f970, UnicodeVersion::new(11, 0)),
+	(0x1f971, 0x1f971, UnicodeVersion::new(12, 0)),
+	(0x1f972, 0x1f972, UnicodeVersion::new(13, 0)),
+	(0x1f973, 0x1f976, UnicodeVersion::new(11, 0)),
+	(0x1f977, 0x1f978, UnicodeVersion::new(13, 0)),
+	(0x1f979, 0x1f979, UnicodeVersion::new(14, 0)),
+	(0x1f97a, 0x1f97a, UnicodeVersion::new(11, 0)),
+	(0x1f97b, 0x1f97b, UnicodeVersion::new(12, 0)),
+	(0x1f97c, 0x1f97f, UnicodeVersion::new(11, 0)),
+	(0x1f980, 0x1f984, UnicodeVersion::new(8, 0)),
+	(0x1f985, 0x1f991, UnicodeVersion::new(9, 0)),
+	(0x1f992, 0x1f997, UnicodeVersion::new(10, 0)),
+	(0x1f998, 0x1f9a2, UnicodeVersion::new(11, 0)),
+	(0x1f9a3, 0x1f9a4, UnicodeVersion::new(13, 0)),
+	(0x1f9a5, 0x1f9aa, UnicodeVersion::new(12, 0)),
+	(0x1f9ab, 0x1f9ad, UnicodeVersion::new(13, 0)),
+	(0x1f9ae, 0x1f9af, UnicodeVersion::new(12, 0)),
+	(0x1f9b0, 0x1f9b9, UnicodeVersion::new(11, 0)),
+	(0x1f9ba, 0x1f9bf, UnicodeVersion::new(12, 0)),
+	(0x1f9c0, 0x1f9c0, UnicodeVersion::new(8, 0)),
+	(0x1f9c1, 0x1f9c2, UnicodeVersion::new(11, 0)),
+	(0x1f9c3, 0x1f9ca, UnicodeVersion::new(12, 0)),
+	(0x1f9cb, 0x1f9cb, UnicodeVersion::new(13, 0)),
+	(0x1f9cc, 0x1f9cc, UnicodeVersion::new(14, 0)),
+	(0x1f9cd, 0x1f9cf, UnicodeVersion::new(12, 0)),
+	(0x1f9d0, 0x1f9e6, UnicodeVersion::new(10, 0)),
+	(0x1f9e7, 0x1f9ff, UnicodeVersion::new(11, 0)),
+	(0x1fa00, 0x1fa53, UnicodeVersion::new(12, 0)),
+	(0x1fa60, 0x1fa6d, UnicodeVersion::new(11, 0)),
+	(0x1fa70, 0x1fa73, UnicodeVersion::new(12, 0)),
+	(0x1fa74, 0x1fa74, UnicodeVersion::new(13, 0)),
+	(0x1fa78, 0x1fa7a, UnicodeVersion::new(12, 0)),
+	(0x1fa7b, 0x1fa7c, UnicodeVersion::new(14, 0)),
+	(0x1fa80, 0x1fa82, UnicodeVersion::new(12, 0)),
+	(0x1fa83, 0x1fa86, UnicodeVersion::new(13, 0)),
+	(0x1fa90, 0x1fa95, UnicodeVersion::new(12, 0)),
+	(0x1fa96, 0x1faa8, UnicodeVersion::new(13, 0)),
+	(0x1faa9, 0x1faac, UnicodeVersion::new(14, 0)),
+	(0x1fab0, 0x1fab6, UnicodeVersion::new(13, 0)),
+	(0x1fab7, 0x1faba, UnicodeVersion::new(14, 0)),
+	(0x1fac0, 0x1fac2, UnicodeVersion::new(13, 0)),
+	(0x1fac3, 0x1fac5, UnicodeVersion::new(14, 0)),
+	(0x1fad0, 0x1fad6, UnicodeVersion::new(13, 0)),
+	(0x1fad7, 0x1fad9, UnicodeVersion::new(14, 0)),
+	(0x1fae0, 0x1fae7, UnicodeVersion::new(14, 0)),
+	(0x1faf0, 0x1faf6, UnicodeVersion::new(14, 0)),
+	(0x1fb00, 0x1fb92, UnicodeVersion::new(13, 0)),
+	(0x1fb94, 0x1fbca, UnicodeVersion::new(13, 0)),
+	(0x1fbf0, 0x1fbf9, UnicodeVersion::new(13, 0)),
+	(0x1fffe, 0x1ffff, UnicodeVersion::new(2, 0)),
+	(0x20000, 0x2a6d6, UnicodeVersion::new(3, 1)),
+	(0x2a6d7, 0x2a6dd, UnicodeVersion::new(13, 0)),
+	(0x2a6de, 0x2a6df, UnicodeVersion::new(14, 0)),
+	(0x2a700, 0x2b734, UnicodeVersion::new(5, 2)),
+	(0x2b735, 0x2b738, UnicodeVersion::new(14, 0)),
+	(0x2b740, 0x2b81d, UnicodeVersion::new(6, 0)),
+	(0x2b820, 0x2cea1, UnicodeVersion::new(8, 0)),
+	(0x2ceb0, 0x2ebe0, UnicodeVersion::new(10, 0)),
+	(0x2f800, 0x2fa1d, UnicodeVersion::new(3, 1)),
+	(0x2fffe, 0x2ffff, UnicodeVersion::new(2, 0)),
+	(0x30000, 0x3134a, UnicodeVersion::new(13, 0)),
+	(0x3fffe, 0x3ffff, UnicodeVersion::new(2, 0)),
+	(0x4fffe, 0x4ffff, UnicodeVersion::new(2, 0)),
+	(0x5fffe, 0x5ffff, UnicodeVersion::new(2, 0)),
+	(0x6fffe, 0x6ffff, UnicodeVersion::new(2, 0)),
+	(0x7fffe, 0x7ffff, UnicodeVersion::new(2, 0)),
+	(0x8fffe, 0x8ffff, UnicodeVersion::new(2, 0)),
+	(0x9fffe, 0x9ffff, UnicodeVersion::new(2, 0)),
+	(0xafffe, 0xaffff, UnicodeVersion::new(2, 0)),
+	(0xbfffe, 0xbffff, UnicodeVersion::new(2, 0)),
+	(0xcfffe, 0xcffff, UnicodeVersion::new(2, 0)),
+	(0xdfffe, 0xdffff, UnicodeVersion::new(2, 0)),
+	(0xe0001, 0xe0001, UnicodeVersion::new(3, 1)),
+	(0xe0020, 0xe007f, UnicodeVersion::new(3, 1)),
+	(0xe0100, 0xe01ef, UnicodeVersion::new(4, 0)),
+	(0xefffe, 0x10ffff, UnicodeVersion::new(2, 0)),
+];
@@ -0,0 +1,1023 @@
+// This file is generated by `scripts/unicode.py` from the Unicode Character Database,
+// version 14.0.0. Do not edit it manually.
+
+use crate::EastAsianWidth::{self, *};
+
+/// East Asian Width of the code points not `Neutral`.
+pub(crate) const EAST_ASIAN_WIDTH: &[(u32, u32, EastAsianWidth)] = &[
+	(0x20, 0x7e, Narrow),
+	(0xa1, 0xa1, Ambiguous),
+	(0xa2, 0xa3, Narrow),
+	(0xa4, 0xa4, Ambiguous),
+	(0xa5, 0xa6, Narrow),
+	(0xa7, 0xa8, Ambiguous),
+	(0xaa, 0xaa, Ambiguous),
+	(0xac, 0xac, Narrow),
+	(0xad, 0xae, Ambiguous),
+	(0xaf, 0xaf, Narrow),
+	(0xb0, 0xb4, Ambiguous),
+	(0xb6, 0xba, Ambiguous),
+	(0xbc, 0xbf, Ambiguous),
+	(0xc6, 0xc6, Ambiguous),
+	(0xd0, 0xd0, Ambiguous),
+	(0xd7, 0xd8, Ambiguous),
+	(0xde, 0xe1, Ambiguous),
+	(0xe6, 0xe6, Ambiguous),
+	(0xe8, 0xea, Ambiguous),
+	(0xec, 0xed, Ambiguous),
+	(0xf0, 0xf0, Ambiguous),
+	(0xf2, 0xf3, Ambiguous),
+	(0xf7, 0xfa, Ambiguous),
+	(0xfc, 0xfc, Ambiguous),
+	(0xfe, 0xfe, Ambiguous),
+	(0x101, 0x101, Ambiguous),
+	(0x111, 0x111, Ambiguous),
+	(0x113, 0x113, Ambiguous),
+	(0x11b, 0x11b, Ambiguous),
+	(0x126, 0x127, Ambiguous),
+	(0x12b, 0x12b, Ambiguous),
+	(0x131, 0x133, Ambiguous),
+	(0x138, 0x138, Ambiguous),
+	(0x13f, 0x142, Ambiguous),
+	(0x144, 0x144, Ambiguous),
+	(0x148, 0x14b, Ambiguous),
+	(0x14d, 0x14d, Ambiguous),
+	(0x152, 0x153, Ambiguous),
+	(0x166, 0x167, Ambiguous),
+	(0x16b, 0x16b, Ambiguous),
+	(0x1ce, 0x1ce, Ambiguous),
+	(0x1d0, 0x1d0, Ambiguous),
+	(0x1d2, 0x1d2, Ambiguous),
+	(0x1d4, 0x1d4, Ambiguous),
+	(0x1d6, 0x1d6, Ambiguous),
+	(0x1d8, 0x1d8, Ambiguous),
+	(0x1da, 0x1da, Ambiguous),
+	(0x1dc, 0x1dc, Ambiguous),
+	(0x251, 0x251, Ambiguous),
+	(0x261, 0x261, Ambiguous),
+	(0x2c4, 0x2c4, Ambiguous),
+	(0x2c7, 0x2c7, Ambiguous),
+	(0x2c9, 0x2cb, Ambiguous),
+	(0x2cd, 0x2cd, Ambiguous),
+	(0x2d0, 0x2d0, Ambiguous),
+	(0x2d8, 0x2db, Ambiguous),
+	(0x2dd, 0x2dd, Ambiguous),
+	(0x2df, 0x2df, Ambiguous),
+	(0x300, 0x36f, Ambiguous),
+	(0x378, 0x379, Fullwidth),
+	(0x380, 0x383, Fullwidth),
+	(0x38b, 0x38b, Fullwidth),
+	(0x38d, 0x38d, Fullwidth),
+	(0x391, 0x3a1, Ambiguous),
+	(0x3a2, 0x3a2, Fullwidth),
+	(0x3a3, 0x3a9, Ambiguous),
+	(0x3b1, 0x3c1, Ambiguous),
+	(0x3c3, 0x3c9, Ambiguous),
+	(0x401, 0x401, Ambiguous),
+	(0x410, 0x44f, Ambiguous),
+	(0x451, 0x451, Ambiguous),
+	(0x530, 0x530, Fullwidth),
+	(0x557, 0x558, Fullwidth),
+	(0x58b, 0x58c, Fullwidth),
+	(0x590, 0x590, Fullwidth),
+	(0x5c8, 0x5cf, Fullwidth),
+	(0x5eb, 0x5ee, Fullwidth),
+	(0x5f5, 0x5ff, Fullwidth),
+	(0x70e, 0x70e, Fullwidth),
+	(0x74b, 0x74c, Fullwidth),
+	(0x7b2, 0x7bf, Fullwidth),
+	(0x7fb, 0x7fc, Fullwidth),
+	(0x82e, 0x82f, Fullwidth),
+	(0x83f, 0x83f, Fullwidth),
+	(0x85c, 0x85d, Fullwidth),
+	(0x85f, 0x85f, Fullwidth),
+	(0x86b, 0x86f, Fullwidth),
+	(0x88f, 0x88f, Fullwidth),
+	(0x892, 0x897, Fullwidth),
+	(0x984, 0x984, Fullwidth),
+	(0x98d, 0x98e, Fullwidth),
+	(0x991, 0x992, Fullwidth),
+	(0x9a9, 0x9a9, Fullwidth),
+	(0x9b1, 0x9b1, Fullwidth),
+	(0x9b3, 0x9b5, Fullwidth),
+	(0x9ba, 0x9bb, Fullwidth),
+	(0x9c5, 0x9c6, Fullwidth),
+	(0x9c9, 0x9ca, Fullwidth),
+	(0x9cf, 0x9d6, Fullwidth),
+	(0x9d8, 0x9db, Fullwidth),
+	(0x9de, 0x9de, Fullwidth),
+	(0x9e4, 0x9e5, Fullwidth),
+	(0x9ff, 0xa00, Fullwidth),
+	(0xa04, 0xa04, Fullwidth),
+	(0xa0b, 0xa0e, Fullwidth),
+	(0xa11, 0xa12, Fullwidth),
+	(0xa29, 0xa29, Fullwidth),
+	(0xa31, 0xa31, Fullwidth),
+	(0xa34, 0xa34, Fullwidth),
+	(0xa37, 0xa37, Fullwidth),
+	(0xa3a, 0xa3b, Fullwidth),
+	(0xa3d, 0xa3d, Fullwidth),
+	(0xa43, 0xa46, Fullwidth),
+	(0xa49, 0xa4a, Fullwidth),
+	(0xa4e, 0xa50, Fullwidth),
+	(0xa52, 0xa58, Fullwidth),
+	(0xa5d, 0xa5d, Fullwidth),
+	(0xa5f, 0xa65, Fullwidth),
+	(0xa77, 0xa80, Fullwidth),
+	(0xa84, 0xa84, Fullwidth),
+	(0xa8e, 0xa8e, Fullwidth),
+	(0xa92, 0xa92, Fullwidth),
+	(0xaa9, 0xaa9, Fullwidth),
+	(0xab1, 0xab1, Fullwidth),
+	(0xab4, 0xab4, Fullwidth),
+	(0xaba, 0xabb, Fullwidth),
+	(0xac6, 0xac6, Fullwidth),
+	(0xaca, 0xaca, Fullwidth),
+	(0xace, 0xacf, Fullwidth),
+	(0xad1, 0xadf, Fullwidth),
+	(0xae4, 0xae5, Fullwidth),
+	(0xaf2, 0xaf8, Fullwidth),
+	(0xb00, 0xb00, Fullwidth),
+	(0xb04, 0xb04, Fullwidth),
+	(0xb0d, 0xb0e, Fullwidth),
+	(0xb11, 0xb12, Fullwidth),
+	(0xb29, 0xb29, Fullwidth),
+	(0xb31, 0xb31, Fullwidth),
+	(0xb34, 0xb34, Fullwidth),
+	(0xb3a, 0xb3b, Fullwidth),
+	(0xb45, 0xb46, Fullwidth),
+	(0xb49, 0xb4a, Fullwidth),
+	(0xb4e, 0xb54, Fullwidth),
+	(0xb58, 0xb5b, Fullwidth),
+	(0xb5e, 0xb5e, Fullwidth),
+	(0xb64, 0xb65, Fullwidth),
+	(0xb78, 0xb81, Fullwidth),
+	(0xb84, 0xb84, Fullwidth),
+	(0xb8b, 0xb8d, Fullwidth),
+	(0xb91, 0xb91, Fullwidth),
+	(0xb96, 0xb98, Fullwidth),
+	(0xb9b, 0xb9b, Fullwidth),
+	(0xb9d, 0xb9d, Fullwidth),
+	(0xba0, 0xba2, Fullwidth),
+	(0xba5, 0xba7, Fullwidth),
+	(0xbab, 0xbad, Fullwidth),
+	(0xbba, 0xbbd, Fullwidth),
+	(0xbc3, 0xbc5, Fullwidth),
+	(0xbc9, 0xbc9, Fullwidth),
+	(0xbce, 0xbcf, Fullwidth),
+	(0xbd1, 0xbd6, Fullwidth),
+	(0xbd8, 0xbe5, Fullwidth),
+	(0xbfb, 0xbff, Fullwidth),
+	(0xc0d, 0xc0d, Fullwidth),
+	(0xc11, 0xc11, Fullwidth),
+	(0xc29, 0xc29, Fullwidth),
+	(0xc3a, 0xc3b, Fullwidth),
+	(0xc45, 0xc45, Fullwidth),
+	(0xc49, 0xc49, Fullwidth),
+	(0xc4e, 0xc54, Fullwidth),
+	(0xc57, 0xc57, Fullwidth),
+	(0xc5b, 0xc5c, Fullwidth),
+	(0xc5e, 0xc5f, Fullwidth),
+	(0xc64, 0xc65, Fullwidth),
+	(0xc70, 0xc76, Fullwidth),
+	(0xc8d, 0xc8d, Fullwidth),
+	(0xc91, 0xc91, Fullwidth),
+	(0xca9, 0xca9, Fullwidth),
+	(0xcb4, 0xcb4, Fullwidth),
+	(0xcba, 0xcbb, Fullwidth),
+	(0xcc5, 0xcc5, Fullwidth),
+	(0xcc9, 0xcc9, Fullwidth),
+	(0xcce, 0xcd4, Fullwidth),
+	(0xcd7, 0xcdc, Fullwidth),
+	(0xcdf, 0xcdf, Fullwidth),
+	(0xce4, 0xce5, Fullwidth),
+	(0xcf0, 0xcf0, Fullwidth),
+	(0xcf3, 0xcff, Fullwidth),
+	(0xd0d, 0xd0d, Fullwidth),
+	(0xd11, 0xd11, Fullwidth),
+	(0xd45, 0xd45, Fullwidth),
+	(0xd49, 0xd49, Fullwidth),
+	(0xd50, 0xd53, Fullwidth),
+	(0xd64, 0xd65, Fullwidth),
+	(0xd80, 0xd80, Fullwidth),
+	(0xd84, 0xd84, Fullwidth),
+	(0xd97, 0xd99, Fullwidth),
+	(0xdb2, 0xdb2, Fullwidth),
+	(0xdbc, 0xdbc, Fullwidth),
+	(0xdbe, 0xdbf, Fullwidth),
+	(0xdc7, 0xdc9, Fullwidth),
+	(0xdcb, 0xdce, Fullwidth),
+	(0xdd5, 0xdd5, Fullwidth),
+	(0xdd7, 0xdd7, Fullwidth),
+	(0xde0, 0xde5, Fullwidth),
+	(0xdf0, 0xdf1, Fullwidth),
+	(0xdf5, 0xe00, Fullwidth),
+	(0xe3b, 0xe3e, Fullwidth),
+	(0xe5c, 0xe80, Fullwidth),
+	(0xe83, 0xe83, Fullwidth),
+	(0xe85, 0xe85, Fullwidth),
+	(0xe8b, 0xe8b, Fullwidth),
+	(0xea4, 0xea4, Fullwidth),
+	(0xea6, 0xea6, Fullwidth),
+	(0xebe, 0xebf, Fullwidth),
+	(0xec5, 0xec5, Fullwidth),
+	(0xec7, 0xec7, Fullwidth),
+	(0xece, 0xecf, Fullwidth),
+	(0xeda, 0xedb, Fullwidth),
+	(0xee0, 0xeff, Fullwidth),
+	(0xf48, 0xf48, Fullwidth),
+	(0xf6d, 0xf70, Fullwidth),
+	(0xf98, 0xf98, Fullwidth),
+	(0xfbd, 0xfbd, Fullwidth),
+	(0xfcd, 0xfcd, Fullwidth),
+	(0xfdb, 0xfff, Fullwidth),
+	(0x10c6, 0x10c6, Fullwidth),
+	(0x10c8, 0x10cc, Fullwidth),
+	(0x10ce, 0x10cf, Fullwidth),
+	(0x1100, 0x115f, Wide),
+	(0x1249, 0x1249, Fullwidth),
+	(0x124e, 0x124f, Fullwidth),
+	(0x1257, 0x1257, Fullwidth),
+	(0x1259, 0x1259, Fullwidth),
+	(0x125e, 0x125f, Fullwidth),
+	(0x1289, 0x1289, Fullwidth),
+	(0x128e, 0x128f, Fullwidth),
+	(0x12b1, 0x12b1, Fullwidth),
+	(0x12b6, 0x12b7, Fullwidth),
+	(0x12bf, 0x12bf, Fullwidth),
+	(0x12c1, 0x12c1, Fullwidth),
+	(0x12c6, 0x12c7, Fullwidth),
+	(0x12d7, 0x12d7, Fullwidth),
+	(0x1311, 0x1311, Fullwidth),
+	(0x1316, 0x1317, Fullwidth),
+	(0x135b, 0x135c, Fullwidth),
+	(0x137d, 0x137f, Fullwidth),
+	(0x139a, 0x139f, Fullwidth),
+	(0x13f6, 0x13f7, Fullwidth),
+	(0x13fe, 0x13ff, Fullwidth),
+	(0x169d, 0x169f, Fullwidth),
+	(0x16f9, 0x16ff, Fullwidth),
+	(0x1716, 0x171e, Fullwidth),
+	(0x1737, 0x173f, Fullwidth),
+	(0x1754, 0x175f, Fullwidth),
+	(0x176d, 0x176d, Fullwidth),
+	(0x1771, 0x1771, Fullwidth),
+	(0x1774, 0x177f, Fullwidth),
+	(0x17de, 0x17df, Fullwidth),
+	(0x17ea, 0x17ef, Fullwidth),
+	(0x17fa, 0x17ff, Fullwidth),
+	(0x181a, 0x181f, Fullwidth),
+	(0x1879, 0x187f, Fullwidth),
+	(0x18ab, 0x18af, Fullwidth),
+	(0x18f6, 0x18ff, Fullwidth),
+	(0x191f, 0x191f, Fullwidth),
+	(0x192c, 0x192f, Fullwidth),
+	(0x193c, 0x193f, Fullwidth),
+	(0x1941, 0x1943, Fullwidth),
+	(0x196e, 0x196f, Fullwidth),
+	(0x1975, 0x197f, Fullwidth),
+	(0x19ac, 0x19af, Fullwidth),
+	(0x19ca, 0x19cf, Fullwidth),
+	(0x19db, 0x19dd, Fullwidth),
+	(0x1a1c, 0x1a1d, Fullwidth),
+	(0x1a5f, 0x1a5f, Fullwidth),
+	(0x1a7d, 0x1a7e, Fullwidth),
+	(0x1a8a, 0x1a8f, Fullwidth),
+	(0x1a9a, 0x1a9f, Fullwidth),
+	(0x1aae, 0x1aaf, Fullwidth),
+	(0x1acf, 0x1aff, Fullwidth),
+	(0x1b4d, 0x1b4f, Fullwidth),
+	(0x1b7f, 0x1b7f, Fullwidth),
+	(0x1bf4, 0x1bfb, Fullwidth),
+	(0x1c38, 0x1c3a, Fullwidth),
+	(0x1c4a, 0x1c4c, Fullwidth),
+	(0x1c89, 0x1c8f, Fullwidth),
+	(0x1cbb, 0x1cbc, Fullwidth),
+	(0x1cc8, 0x1ccf, Fullwidth),
+	(0x1cfb, 0x1cff, Fullwidth),
+	(0x1f16, 0x1f17, Fullwidth),
+	(0x1f1e, 0x1f1f, Fullwidth),
+	(0x1f46, 0x1f47, Fullwidth),
+	(0x1f4e, 0x1f4f, Fullwidth),
+	(0x1f58, 0x1f58, Fullwidth),
+	(0x1f5a, 0x1f5a, Fullwidth),
+	(0x1f5c, 0x1f5c, Fullwidth),
+	(0x1f5e, 0x1f5e, Fullwidth),
+	(0x1f7e, 0x1f7f, Fullwidth),
+	(0x1fb5, 0x1fb5, Fullwidth),
+	(0x1fc5, 0x1fc5, Fullwidth),
+	(0x1fd4, 0x1fd5, Fullwidth),
+	(0x1fdc, 0x1fdc, Fullwidth),
+	(0x1ff0, 0x1ff1, Fullwidth),
+	(0x1ff5, 0x1ff5, Fullwidth),
+	(0x1fff, 0x1fff, Fullwidth),
+	(0x2010, 0x2010, Ambiguous),
+	(0x2013, 0x2016, Ambiguous),
+	(0x2018, 0x2019, Ambiguous),
+	(0x201c, 0x201d, Ambiguous),
+	(0x2020, 0x2022, Ambiguous),
+	(0x2024, 0x2027, Ambiguous),
+	(0x2030, 0x2030, Ambiguous),
+	(0x2032, 0x2033, Ambiguous),
+	(0x2035, 0x2035, Ambiguous),
+	(0x203b, 0x203b, Ambiguous),
+	(0x203e, 0x203e, Ambiguous),
+	(0x2065, 0x2065, Fullwidth),
+	(0x2072, 0x2073, Fullwidth),
+	(0x2074, 0x2074, Ambiguous),
+	(0x207f, 0x207f, Ambiguous),
+	(0x2081, 0x2084, Ambiguous),
+	(0x208f, 0x208f, Fullwidth),
+	(0x209d, 0x209f, Fullwidth),
+	(0x20a9, 0x20a9, Halfwidth),
+	(0x20ac, 0x20ac, Ambiguous),
+	(0x20c1, 0x20cf, Fullwidth),
+	(0x20f1, 0x20ff, Fullwidth),
+	(0x2103, 0x2103, Ambiguous),
+	(0x2105, 0x2105, Ambiguous),
+	(0x2109, 0x2109, Ambiguous),
+	(0x2113, 0x2113, Ambiguous),
+	(0x2116, 0x2116, Ambiguous),
+	(0x2121, 0x2122, Ambiguous),
+	(0x2126, 0x2126, Ambiguous),
+	(0x212b, 0x212b, Ambiguous),
+	(0x2153, 0x2154, Ambiguous),
+	(0x215b, 0x215e, Ambiguous),
+	(0x2160, 0x216b, Ambiguous),
+	(0x2170, 0x2179, Ambiguous),
+	(0x2189, 0x2189, Ambiguous),
+	(0x218c, 0x218f, Fullwidth),
+	(0x2190, 0x2199, Ambiguous),
+	(0x21b8, 0x21b9, Ambiguous),
+	(0x21d2, 0x21d2, Ambiguous),
+	(0x21d4, 0x21d4, Ambiguous),
+	(0x21e7, 0x21e7, Ambiguous),
+	(0x2200, 0x2200, Ambiguous),
+	(0x2202, 0x2203, Ambiguous),
+	(0x2207, 0x2208, Ambiguous),
+	(0x220b, 0x220b, Ambiguous),
+	(0x220f, 0x220f, Ambiguous),
+	(0x2211, 0x2211, Ambiguous),
+	(0x2215, 0x2215, Ambiguous),
+	(0x221a, 0x221a, Ambiguous),
+	(0x221d, 0x2220, Ambiguous),
+	(0x2223, 0x2223, Ambiguous),
+	(0x2225, 0x2225, Ambiguous),
+	(0x2227, 0x222c, Ambiguous),
+	(0x222e, 0x222e, Ambiguous),
+	(0x2234, 0x2237, Ambiguous),
+	(0x223c, 0x223d, Ambiguous),
+	(0x2248, 0x2248, Ambiguous),
+	(0x224c, 0x224c, Ambiguous),
+	(0x2252, 0x2252, Ambiguous),
+	(0x2260, 0x2261, Ambiguous),
+	(0x2264, 0x2267, Ambiguous),
+	(0x226a, 0x226b, Ambiguous),
+	(0x226e, 0x226f, Ambiguous),
+	(0x2282, 0x2283, Ambiguous),
+	(0x2286, 0x2287, Ambiguous),
+	(0x2295, 0x2295, Ambiguous),
+	(0x2299, 0x2299, Ambiguous),
+	(0x22a5, 0x22a5, Ambiguous),
+	(0x22bf, 0x22bf, Ambiguous),
+	(0x2312, 0x2312, Ambiguous),
+	(0x231a, 0x231b, Wide),
+	(0x2329, 0x232a, Wide),
+	(0x23e9, 0x23ec, Wide),
+	(0x23f0, 0x23f0, Wide),
+	(0x23f3, 0x23f3, Wide),
+	(0x2427, 0x243f, Fullwidth),
+	(0x244b, 0x245f, Fullwidth),
+	(0x2460, 0x24e9, Ambiguous),
+	(0x24eb, 0x254b, Ambiguous),
+	(0x2550, 0x2573, Ambiguous),
+	(0x2580, 0x258f, Ambiguous),
+	(0x2592, 0x2595, Ambiguous),
+	(0x25a0, 0x25a1, Ambiguous),
+	(0x25a3, 0x25a9, Ambiguous),
+	(0x25b2, 0x25b3, Ambiguous),
+	(0x25b6, 0x25b7, Ambiguous),
+	(0x25bc, 0x25bd, Ambiguous),
+	(0x25c0, 0x25c1, Ambiguous),
+	(0x25c6, 0x25c8, Ambiguous),
+	(0x25cb, 0x25cb, Ambiguous),
+	(0x25ce, 0x25d1, Ambiguous),
+	(0x25e2, 0x25e5, Ambiguous),
+	(0x25ef, 0x25ef, Ambiguous),
+	(0x25fd, 0x25fe, Wide),
+	(0x2605, 0x2606, Ambiguous),
+	(0x2609, 0x2609, Ambiguous),
+	(0x260e, 0x260f, Ambiguous),
+	(0x2614, 0x2615, Wide),
+	(0x261c, 0x261c, Ambiguous),
+	(0x261e, 0x261e, Ambiguous),
+	(0x2640, 0x2640, Ambiguous),
+	(0x2642, 0x2642, Ambiguous),
+	(0x2648, 0x2653, Wide),
+	(0x2660, 0x2661, Ambiguous),
+	(0x2663, 0x2665, Ambiguous),
+	(0x2667, 0x266a, Ambiguous),
+	(0x266c, 0x266d, Ambiguous),
+	(0x266f, 0x266f, Ambiguous),
+	(0x267f, 0x267f, Wide),
+	(0x2693, 0x2693, Wide),
+	(0x269e, 0x269f, Ambiguous),
+	(0x26a1, 0x26a1, Wide),
+	(0x26aa, 0x26ab, Wide),
+	(0x26bd, 0x26be, Wide),
+	(0x26bf, 0x26bf, Ambiguous),
+	(0x26c4, 0x26c5, Wide),
+	(0x26c6, 0x26cd, Ambiguous),
+	(0x26ce, 0x26ce, Wide),
+	(0x26cf, 0x26d3, Ambiguous),
+	(0x26d4, 0x26d4, Wide),
+	(0x26d5, 0x26e1, Ambiguous),
+	(0x26e3, 0x26e3, Ambiguous),
+	(0x26e8, 0x26e9, Ambiguous),
+	(0x26ea, 0x26ea, Wide),
+	(0x26eb, 0x26f1, Ambiguous),
+	(0x26f2, 0x26f3, Wide),
+	(0x26f4, 0x26f4, Ambiguous),
+	(0x26f5, 0x26f5, Wide),
+	(0x26f6, 0x26f9, Ambiguous),
+	(0x26fa, 0x26fa, Wide),
+	(0x26fb, 0x26fc, Ambiguous),
+	(0x26fd, 0x26fd, Wide),
+	(0x26fe, 0x26ff, Ambiguous),
+	(0x2705, 0x2705, Wide),
+	(0x270a, 0x270b, Wide),
+	(0x2728, 0x2728, Wide),
+	(0x273d, 0x273d, Ambiguous),
+	(0x274c, 0x274c, Wide),
+	(0x274e, 0x274e, Wide),
+	(0x2753, 0x2755, Wide),
+	(0x2757, 0x2757, Wide),
+	(0x2776, 0x277f, Ambiguous),
+	(0x2795, 0x2797, Wide),
+	(0x27b0, 0x27b0, Wide),
+	(0x27bf, 0x27bf, Wide),
+	(0x27e6, 0x27ed, Narrow),
+	(0x2985, 0x2986, Narrow),
+	(0x2b1b, 0x2b1c, Wide),
+	(0x2b50, 0x2b50, Wide),
+	(0x2b55, 0x2b55, Wide),
+	(0x2b56, 0x2b59, Ambiguous),
+	(0x2b74, 0x2b75, Fullwidth),
+	(0x2b96, 0x2b96, Fullwidth),
+	(0x2cf4, 0x2cf8, Fullwidth),
+	(0x2d26, 0x2d26, Fullwidth),
+	(0x2d28, 0x2d2c, Fullwidth),
+	(0x2d2e, 0x2d2f, Fullwidth),
+	(0x2d68, 0x2d6e, Fullwidth),
+	(0x2d71, 0x2d7e, Fullwidth),
+	(0x2d97, 0x2d9f, Fullwidth),
+	(0x2da7, 0x2da7, Fullwidth),
+	(0x2daf, 0x2daf, Fullwidth),
+	(0x2db7, 0x2db7, Fullwidth),
+	(0x2dbf, 0x2dbf, Fullwidth),
+	(0x2dc7, 0x2dc7, Fullwidth),
+	(0x2dcf, 0x2dcf, Fullwidth),
+	(0x2dd7, 0x2dd7, Fullwidth),
+	(0x2ddf, 0x2ddf, Fullwidth),
+	(0x2e5e, 0x2e7f, Fullwidth),
+	(0x2e80, 0x2e99, Wide),
+	(0x2e9a, 0x2e9a, Fullwidth),
+	(0x2e9b, 0x2ef3, Wide),
+	(0x2ef4, 0x2eff, Fullwidth),
+	(0x2f00, 0x2fd5, Wide),
+	(0x2fd6, 0x2fef, Fullwidth),
+	(0x2ff0, 0x2ffb, Wide),
+	(0x2ffc, 0x3000, Fullwidth),
+	(0x3001, 0x303e, Wide),
+	(0x3040, 0x3040, Fullwidth),
+	(0x3041, 0x3096, Wide),
+	(0x3097, 0x3098, Fullwidth),
+	(0x3099, 0x30ff, Wide),
+	(0x3100, 0x3104, Fullwidth),
+	(0x3105, 0x312f, Wide),
+	(0x3130, 0x3130, Fullwidth),
+	(0x3131, 0x318e, Wide),
+	(0x318f, 0x318f, Fullwidth),
+	(0x3190, 0x31e3, Wide),
+	(0x31e4, 0x31ef, Fullwidth),
+	(0x31f0, 0x321e, Wide),
+	(0x321f, 0x321f, Fullwidth),
+	(0x3220, 0x3247, Wide),
+	(0x3248, 0x324f, Ambiguous),
+	(0x3250, 0x4dbf, Wide),
+	(0x4e00, 0xa48c, Wide),
+	(0xa48d, 0xa48f, Fullwidth),
+	(0xa490, 0xa4c6, Wide),
+	(0xa4c7, 0xa4cf, Fullwidth),
+	(0xa62c, 0xa63f, Fullwidth),
+	(0xa6f8, 0xa6ff, Fullwidth),
+	(0xa7cb, 0xa7cf, Fullwidth),
+	(0xa7d2, 0xa7d2, Fullwidth),
+	(0xa7d4, 0xa7d4, Fullwidth),
+	(0xa7da, 0xa7f1, Fullwidth),
+	(0xa82d, 0xa82f, Fullwidth),
+	(0xa83a, 0xa83f, Fullwidth),
+	(0xa878, 0xa87f, Fullwidth),
+	(0xa8c6, 0xa8cd, Fullwidth),
+	(0xa8da, 0xa8df, Fullwidth),
+	(0xa954, 0xa95e, Fullwidth),
+	(0xa960, 0xa97c, Wide),
+	(0xa97d, 0xa97f, Fullwidth),
+	(0xa9ce, 0xa9ce, Fullwidth),
+	(0xa9da, 0xa9dd, Fullwidth),
+	(0xa9ff, 0xa9ff, Fullwidth),
+	(0xaa37, 0xaa3f, Fullwidth),
+	(0xaa4e, 0xaa4f, Fullwidth),
+	(0xaa5a, 0xaa5b, Fullwidth),
+	(0xaac3, 0xaada, Fullwidth),
+	(0xaaf7, 0xab00, Fullwidth),
+	(0xab07, 0xab08, Fullwidth),
+	(0xab0f, 0xab10, Fullwidth),
+	(0xab17, 0xab1f, Fullwidth),
+	(0xab27, 0xab27, Fullwidth),
+	(0xab2f, 0xab2f, Fullwidth),
+	(0xab6c, 0xab6f, Fullwidth),
+	(0xabee, 0xabef, Fullwidth),
+	(0xabfa, 0xabff, Fullwidth),
+	(0xac00, 0xd7a3, Wide),
+	(0xd7a4, 0xd7af, Fullwidth),
+	(0xd7c7, 0xd7ca, Fullwidth),
+	(0xd7fc, 0xd7ff, Fullwidth),
+	(0xe000, 0xf8ff, Ambiguous),
+	(0xf900, 0xfa6d, Wide),
+	(0xfa6e, 0xfa6f, Fullwidth),
+	(0xfa70, 0xfad9, Wide),
+	(0xfada, 0xfaff, Fullwidth),
+	(0xfb07, 0xfb12, Fullwidth),
+	(0xfb18, 0xfb1c, Fullwidth),
+	(0xfb37, 0xfb37, Fullwidth),
+	(0xfb3d, 0xfb3d, Fullwidth),
+	(0xfb3f, 0xfb3f, Fullwidth),
+	(0xfb42, 0xfb42, Fullwidth),
+	(0xfb45, 0xfb45, Fullwidth),
+	(0xfbc3, 0xfbd2, Fullwidth),
+	(0xfd90, 0xfd91, Fullwidth),
+	(0xfdc8, 0xfdce, Fullwidth),
+	(0xfdd0, 0xfdef, Fullwidth),
+	(0xfe00, 0xfe0f, Ambiguous),
+	(0xfe10, 0xfe19, Wide),
+	(0xfe1a, 0xfe1f, Fullwidth),
+	(0xfe30, 0xfe52, Wide),
+	(0xfe53, 0xfe53, Fullwidth),
+	(0xfe54, 0xfe66, Wide),
+	(0xfe67, 0xfe67, Fullwidth),
+	(0xfe68, 0xfe6b, Wide),
+	(0xfe6c, 0xfe6f, Fullwidth),
+	(0xfe75, 0xfe75, Fullwidth),
+	(0xfefd, 0xfefe, Fullwidth),
+	(0xff00, 0xff60, Fullwidth),
+	(0xff61, 0xffbe, Halfwidth),
+	(0xffbf, 0xffc1, Fullwidth),
+	(0xffc2, 0xffc7, Halfwidth),
+	(0xffc8, 0xffc9, Fullwidth),
+	(0xffca, 0xffcf, Halfwidth),
+	(0xffd0, 0xffd1, Fullwidth),
+	(0xffd2, 0xffd7, Halfwidth),
+	(0xffd8, 0xffd9, Fullwidth),
+	(0xffda, 0xffdc, Halfwidth),
+	(0xffdd, 0xffe7, Fullwidth),
+	(0xffe8, 0xffee, Halfwidth),
+	(0xffef, 0xfff8, Fullwidth),
+	(0xfffd, 0xfffd, Ambiguous),
+	(0xfffe, 0xffff, Fullwidth),
+	(0x1000c, 0x1000c, Fullwidth),
+	(0x10027, 0x10027, Fullwidth),
+	(0x1003b, 0x1003b, Fullwidth),
+	(0x1003e, 0x1003e, Fullwidth),
+	(0x1004e, 0x1004f, Fullwidth),
+	(0x1005e, 0x1007f, Fullwidth),
+	(0x100fb, 0x100ff, Fullwidth),
+	(0x10103, 0x10106, Fullwidth),
+	(0x10134, 0x10136, Fullwidth),
+	(0x1018f, 0x1018f, Fullwidth),
+	(0x1019d, 0x1019f, Fullwidth),
+	(0x101a1, 0x101cf, Fullwidth),
+	(0x101fe, 0x1027f, Fullwidth),
+	(0x1029d, 0x1029f, Fullwidth),
+	(0x102d1, 0x102df, Fullwidth),
+	(0x102fc, 0x102ff, Fullwidth),
+	(0x10324, 0x1032c, Fullwidth),
+	(0x1034b, 0x1034f, Fullwidth),
+	(0x1037b, 0x1037f, Fullwidth),
+	(0x1039e, 0x1039e, Fullwidth),
+	(0x103c4, 0x103c7, Fullwidth),
+	(0x103d6, 0x103ff, Fullwidth),
+	(0x1049e, 0x1049f, Fullwidth),
+	(0x104aa, 0x104af, Fullwidth),
+	(0x104d4, 0x104d7, Fullwidth),
+	(0x104fc, 0x104ff, Fullwidth),
+	(0x10528, 0x1052f, Fullwidth),
+	(0x10564, 0x1056e, Fullwidth),
+	(0x1057b, 0x1057b, Fullwidth),
+	(0x1058b, 0x1058b, Fullwidth),
+	(0x10593, 0x10593, Fullwidth),
+	(0x10596, 0x10596, Fullwidth),
+	(0x105a2, 0x105a2, Fullwidth),
+	(0x105b2, 0x105b2, Fullwidth),
+	(0x105ba, 0x105ba, Fullwidth),
+	(0x105bd, 0x105ff, Fullwidth),
+	(0x10737, 0x1073f, Fullwidth),
+	(0x10756, 0x1075f, Fullwidth),
+	(0x10768, 0x1077f, Fullwidth),
+	(0x10786, 0x10786, Fullwidth),
+	(0x107b1, 0x107b1, Fullwidth),
+	(0x107bb, 0x107ff, Fullwidth),
+	(0x10806, 0x10807, Fullwidth),
+	(0x10809, 0x10809, Fullwidth),
+	(0x10836, 0x10836, Fullwidth),
+	(0x10839, 0x1083b, Fullwidth),
+	(0x1083d, 0x1083e, Fullwidth),
+	(0x10856, 0x10856, Fullwidth),
+	(0x1089f, 0x108a6, Fullwidth),
+	(0x108b0, 0x108df, Fullwidth),
+	(0x108f3, 0x108f3, Fullwidth),
+	(0x108f6, 0x108fa, Fullwidth),
+	(0x1091c, 0x1091e, Fullwidth),
+	(0x1093a, 0x1093e, Fullwidth),
+	(0x10940, 0x1097f, Fullwidth),
+	(0x109b8, 0x109bb, Fullwidth),
+	(0x109d0, 0x109d1, Fullwidth),
+	(0x10a04, 0x10a04, Fullwidth),
+	(0x10a07, 0x10a0b, Fullwidth),
+	(0x10a14, 0x10a14, Fullwidth),
+	(0x10a18, 0x10a18, Fullwidth),
+	(0x10a36, 0x10a37, Fullwidth),
+	(0x10a3b, 0x10a3e, Fullwidth),
+	(0x10a49, 0x10a4f, Fullwidth),
+	(0x10a59, 0x10a5f, Fullwidth),
+	(0x10aa0, 0x10abf, Fullwidth),
+	(0x10ae7, 0x10aea, Fullwidth),
+	(0x10af7, 0x10aff, Fullwidth),
+	(0x10b36, 0x10b38, Fullwidth),
+	(0x10b56, 0x10b57, Fullwidth),
+	(0x10b73, 0x10b77, Fullwidth),
+	(0x10b92, 0x10b98, Fullwidth),
+	(0x10b9d, 0x10ba8, Fullwidth),
+	(0x10bb0, 0x10bff, Fullwidth),
+	(0x10c49, 0x10c7f, Fullwidth),
+	(0x10cb3, 0x10cbf, Fullwidth),
+	(0x10cf3, 0x10cf9, Fullwidth),
+	(0x10d28, 0x10d2f, Fullwidth),
+	(0x10d3a, 0x10e5f, Fullwidth),
+	(0x10e7f, 0x10e7f, Fullwidth),
+	(0x10eaa, 0x10eaa, Fullwidth),
+	(0x10eae, 0x10eaf, Fullwidth),
+	(0x10eb2, 0x10eff, Fullwidth),
+	(0x10f28, 0x10f2f, Fullwidth),
+	(0x10f5a, 0x10f6f, Fullwidth),
+	(0x10f8a, 0x10faf, Fullwidth),
+	(0x10fcc, 0x10fdf, Fullwidth),
+	(0x10ff7, 0x10fff, Fullwidth),
+	(0x1104e, 0x11051, Fullwidth),
+	(0x11076, 0x1107e, Fullwidth),
+	(0x110c3, 0x110cc, Fullwidth),
+	(0x110ce, 0x110cf, Fullwidth),
+	(0x110e9, 0x110ef, Fullwidth),
+	(0x110fa, 0x110ff, Fullwidth),
+	(0x11135, 0x11135, Fullwidth),
+	(0x11148, 0x1114f, Fullwidth),
+	(0x11177, 0x1117f, Fullwidth),
+	(0x111e0, 0x111e0, Fullwidth),
+	(0x111f5, 0x111ff, Fullwidth),
+	(0x11212, 0x11212, Fullwidth),
+	(0x1123f, 0x1127f, Fullwidth),
+	(0x11287, 0x11287, Fullwidth),
+	(0x11289, 0x11289, Fullwidth),
+	(0x1128e, 0x1128e, Fullwidth),
+	(0x1129e, 0x1129e, Fullwidth),
+	(0x112aa, 0x112af, Fullwidth),
+	(0x112eb, 0x112ef, Fullwidth),
+	(0x112fa, 0x112ff, Fullwidth),
+	(0x11304, 0x11304, Fullwidth),
+	(0x1130d, 0x1130e, Fullwidth),
+	(0x11311, 0x11312, Fullwidth),
+	(0x11329, 0x11329, Fullwidth),
+	(0x11331, 0x11331, Fullwidth),
+	(0x11334, 0x11334, Fullwidth),
+	(0x1133a, 0x1133a, Fullwidth),
+	(0x11345, 0x11346, Fullwidth),
+	(0x11349, 0x1134a, Fullwidth),
+	(0x1134e, 0x1134f, Fullwidth),
+	(0x11351, 0x11356, Fullwidth),
+	(0x11358, 0x1135c, Fullwidth),
+	(0x11364, 0x11365, Fullwidth),
+	(0x1136d, 0x1136f, Fullwidth),
+	(0x11375, 0x113ff, Fullwidth),
+	(0x1145c, 0x1145c, Fullwidth),
+	(0x11462, 0x1147f, Fullwidth),
+	(0x114c8, 0x114cf, Fullwidth),
+	(0x114da, 0x1157f, Fullwidth),
+	(0x115b6, 0x115b7, Fullwidth),
+	(0x115de, 0x115ff, Fullwidth),
+	(0x11645, 0x1164f, Fullwidth),
+	(0x1165a, 0x1165f, Fullwidth),
+	(0x1166d, 0x1167f, Fullwidth),
+	(0x116ba, 0x116bf, Fullwidth),
+	(0x116ca, 0x116ff, Fullwidth),
+	(0x1171b, 0x1171c, Fullwidth),
+	(0x1172c, 0x1172f, Fullwidth),
+	(0x11747, 0x117ff, Fullwidth),
+	(0x1183c, 0x1189f, Fullwidth),
+	(0x118f3, 0x118fe, Fullwidth),
+	(0x11907, 0x11908, Fullwidth),
+	(0x1190a, 0x1190b, Fullwidth),
+	(0x11914, 0x11914, Fullwidth),
+	(0x11917, 0x11917, Fullwidth),
+	(0x11936, 0x11936, Fullwidth),
+	(0x11939, 0x1193a, Fullwidth),
+	(0x11947, 0x1194f, Fullwidth),
+	(0x1195a, 0x1199f, Fullwidth),
+	(0x119a8, 0x119a9, Fullwidth),
+	(0x119d8, 0x119d9, Fullwidth),
+	(0x119e5, 0x119ff, Fullwidth),
+	(0x11a48, 0x11a4f, Fullwidth),
+	(0x11aa3, 0x11aaf, Fullwidth),
+	(0x11af9, 0x11bff, Fullwidth),
+	(0x11c09, 0x11c09, Fullwidth),
+	(0x11c37, 0x11c37, Fullwidth),
+	(0x11c46, 0x11c4f, Fullwidth),
+	(0x11c6d, 0x11c6f, Fullwidth),
+	(0x11c90, 0x11c91, Fullwidth),
+	(0x11ca8, 0x11ca8, Fullwidth),
+	(0x11cb7, 0x11cff, Fullwidth),
+	(0x11d07, 0x11d07, Fullwidth),
+	(0x11d0a, 0x11d0a, Fullwidth),
+	(0x11d37, 0x11d39, Fullwidth),
+	(0x11d3b, 0x11d3b, Fullwidth),
+	(0x11d3e, 0x11d3e, Fullwidth),
+	(0x11d48, 0x11d4f, Fullwidth),
+	(0x11d5a, 0x11d5f, Fullwidth),
+	(0x11d66, 0x11d66, Fullwidth),
+	(0x11d69, 0x11d69, Fullwidth),
+	(0x11d8f, 0x11d8f, Fullwidth),
+	(0x11d92, 0x11d92, Fullwidth),
+	(0x11d99, 0x11d9f, Fullwidth),
+	(0x11daa, 0x11edf, Fullwidth),
+	(0x11ef9, 0x11faf, Fullwidth),
+	(0x11fb1, 0x11fbf, Fullwidth),
+	(0x11ff2, 0x11ffe, Fullwidth),
+	(0x1239a, 0x123ff, Fullwidth),
+	(0x1246f, 0x1246f, Fullwidth),
+	(0x12475, 0x1247f, Fullwidth),
+	(0x12544, 0x12f8f, Fullwidth),
+	(0x12ff3, 0x12fff, Fullwidth),
+	(0x1342f, 0x1342f, Fullwidth),
+	(0x13439, 0x143ff, Fullwidth),
+	(0x14647, 0x167ff, Fullwidth),
+	(0x16a39, 0x16a3f, Fullwidth),
+	(0x16a5f, 0x16a5f, Fullwidth),
+	(0x16a6a, 0x16a6d, Fullwidth),
+	(0x16abf, 0x16abf, Fullwidth),
+	(0x16aca, 0x16acf, Fullwidth),
+	(0x16aee, 0x16aef, Fullwidth),
+	(0x16af6, 0x16aff, Fullwidth),
+	(0x16b46, 0x16b4f, Fullwidth),
+	(0x16b5a, 0x16b5a, Fullwidth),
+	(0x16b62, 0x16b62, Fullwidth),
+	(0x16b78, 0x16b7c, Fullwidth),
+	(0x16b90, 0x16e3f, Fullwidth),
+	(0x16e9b, 0x16eff, Fullwidth),
+	(0x16f4b, 0x16f4e, Fullwidth),
+	(0x16f88, 0x16f8e, Fullwidth),
+	(0x16fa0, 0x16fdf, Fullwidth),
+	(0x16fe0, 0x16fe4, Wide),
+	(0x16fe5, 0x16fef, Fullwidth),
+	(0x16ff0, 0x16ff1, Wide),
+	(0x16ff2, 0x16fff, Fullwidth),
+	(0x17000, 0x187f7, Wide),
+	(0x187f8, 0x187ff, Fullwidth),
+	(0x18800, 0x18cd5, Wide),
+	(0x18cd6, 0x18cff, Fullwidth),
+	(0x18d00, 0x18d08, Wide),
+	(0x18d09, 0x1afef, Fullwidth),
+	(0x1aff0, 0x1aff3, Wide),
+	(0x1aff4, 0x1aff4, Fullwidth),
+	(0x1aff5, 0x1affb, Wide),
+	(0x1affc, 0x1affc, Fullwidth),
+	(0x1affd, 0x1affe, Wide),
+	(0x1afff, 0x1afff, Fullwidth),
+	(0x1b000, 0x1b122, Wide),
+	(0x1b123, 0x1b14f, Fullwidth),
+	(0x1b150, 0x1b152, Wide),
+	(0x1b153, 0x1b163, Fullwidth),
+	(0x1b164, 0x1b167, Wide),
+	(0x1b168, 0x1b16f, Fullwidth),
+	(0x1b170, 0x1b2fb, Wide),
+	(0x1b2fc, 0x1bbff, Fullwidth),
+	(0x1bc6b, 0x1bc6f, Fullwidth),
+	(0x1bc7d, 0x1bc7f, Fullwidth),
+	(0x1bc89, 0x1bc8f, Fullwidth),
+	(0x1bc9a, 0x1bc9b, Fullwidth),
+	(0x1bca4, 0x1ceff, Fullwidth),
+	(0x1cf2e, 0x1cf2f, Fullwidth),
+	(0x1cf47, 0x1cf4f, Fullwidth),
+	(0x1cfc4, 0x1cfff, Fullwidth),
+	(0x1d0f6, 0x1d0ff, Fullwidth),
+	(0x1d127, 0x1d128, Fullwidth),
+	(0x1d1eb, 0x1d1ff, Fullwidth),
+	(0x1d246, 0x1d2df, Fullwidth),
+	(0x1d2f4, 0x1d2ff, Fullwidth),
+	(0x1d357, 0x1d35f, Fullwidth),
+	(0x1d379, 0x1d3ff, Fullwidth),
+	(0x1d455, 0x1d455, Fullwidth),
+	(0x1d49d, 0x1d49d, Fullwidth),
+	(0x1d4a0, 0x1d4a1, Fullwidth),
+	(0x1d4a3, 0x1d4a4, Fullwidth),
+	(0x1d4a7, 0x1d4a8, Fullwidth),
+	(0x1d4ad, 0x1d4ad, Fullwidth),
+	(0x1d4ba, 0x1d4ba, Fullwidth),
+	(0x1d4bc, 0x1d4bc, Fullwidth),
+	(0x1d4c4, 0x1d4c4, Fullwidth),
+	(0x1d506, 0x1d506, Fullwidth),
+	(0x1d50b, 0x1d50c, Fullwidth),
+	(0x1d515, 0x1d515, Fullwidth),
+	(0x1d51d, 0x1d51d, Fullwidth),
+	(0x1d53a, 0x1d53a, Fullwidth),
+	(0x1d53f, 0x1d53f, Fullwidth),
+	(0x1d545, 0x1d545, Fullwidth),
+	(0x1d547, 0x1d549, Fullwidth),
+	(0x1d551, 0x1d551, Fullwidth),
+	(0x1d6a6, 0x1d6a7, Fullwidth),
+	(0x1d7cc, 0x1d7cd, Fullwidth),
+	(0x1da8c, 0x1da9a, Fullwidth),
+	(0x1daa0, 0x1daa0, Fullwidth),
+	(0x1dab0, 0x1deff, Fullwidth),
+	(0x1df1f, 0x1dfff, Fullwidth),
+	(0x1e007, 0x1e007, Fullwidth),
+	(0x1e019, 0x1e01a, Fullwidth),
+	(0x1e022, 0x1e022, Fullwidth),
+	(0x1e025, 0x1e025, Fullwidth),
+	(0x1e02b, 0x1e0ff, Fullwidth),
+	(0x1e12d, 0x1e12f, Fullwidth),
+	(0x1e13e, 0x1e13f, Fullwidth),
+	(0x1e14a, 0x1e14d, Fullwidth),
+	(0x1e150, 0x1e28f, Fullwidth),
+	(0x1e2af, 0x1e2bf, Fullwidth),
+	(0x1e2fa, 0x1e2fe, Fullwidth),
+	(0x1e300, 0x1e7df, Fullwidth),
+	(0x1e7e7, 0x1e7e7, Fullwidth),
+	(0x1e7ec, 0x1e7ec, Fullwidth),
+	(0x1e7ef, 0x1e7ef, Fullwidth),
+	(0x1e7ff, 0x1e7ff, Fullwidth),
+	(0x1e8c5, 0x1e8c6, Fullwidth),
+	(0x1e8d7, 0x1e8ff, Fullwidth),
+	(0x1e94c, 0x1e94f, Fullwidth),
+	(0x1e95a, 0x1e95d, Fullwidth),
+	(0x1e960, 0x1ec70, Fullwidth),
+	(0x1ecb5, 0x1ed00, Fullwidth),
+	(0x1ed3e, 0x1edff, Fullwidth),
+	(0x1ee04, 0x1ee04, Fullwidth),
+	(0x1ee20, 0x1ee20, Fullwidth),
+	(0x1ee23, 0x1ee23, Fullwidth),
+	(0x1ee25, 0x1ee26, Fullwidth),
+	(0x1ee28, 0x1ee28, Fullwidth),
+	(0x1ee33, 0x1ee33, Fullwidth),
+	(0x1ee38, 0x1ee38, Fullwidth),
+	(0x1ee3a, 0x1ee3a, Fullwidth),
+	(0x1ee3c, 0x1ee41, Fullwidth),
+	(0x1ee43, 0x1ee46, Fullwidth),
+	(0x1ee48, 0x1ee48, Fullwidth),
+	(0x1ee4a, 0x1ee4a, Fullwidth),
+	(0x1ee4c, 0x1ee4c, Fullwidth),
+	(0x1ee50, 0x1ee50, Fullwidth),
+	(0x1ee53, 0x1ee53, Fullwidth),
+	(0x1ee55, 0x1ee56, Fullwidth),
+	(0x1ee58, 0x1ee58, Fullwidth),
+	(0x1ee5a, 0x1ee5a, Fullwidth),
+	(0x1ee5c, 0x1ee5c, Fullwidth),
+	(0x1ee5e, 0x1ee5e, Fullwidth),
+	(0x1ee60, 0x1ee60, Fullwidth),
+	(0x1ee63, 0x1ee63, Fullwidth),
+	(0x1ee65, 0x1ee66, Fullwidth),
+	(0x1ee6b, 0x1ee6b, Fullwidth),
+	(0x1ee73, 0x1ee73, Fullwidth),
+	(0x1ee78, 0x1ee78, Fullwidth),
+	(0x1ee7d, 0x1ee7d, Fullwidth),
+	(0x1ee7f, 0x1ee7f, Fullwidth),
+	(0x1ee8a, 0x1ee8a, Fullwidth),
+	(0x1ee9c, 0x1eea0, Fullwidth),
+	(0x1eea4, 0x1eea4, Fullwidth),
+	(0x1eeaa, 0x1eeaa, Fullwidth),
+	(0x1eebc, 0x1eeef, Fullwidth),
+	(0x1eef2, 0x1efff, Fullwidth),
+	(0x1f004, 0x1f004, Wide),
+	(0x1f02c, 0x1f02f, Fullwidth),
+	(0x1f094, 0x1f09f, Fullwidth),
+	(0x1f0af, 0x1f0b0, Fullwidth),
+	(0x1f0c0, 0x1f0c0, Fullwidth),
+	(0x1f0cf, 0x1f0cf, Wide),
+	(0x1f0d0, 0x1f0d0, Fullwidth),
+	(0x1f0f6, 0x1f0ff, Fullwidth),
+	(0x1f100, 0x1f10a, Ambiguous),
+	(0x1f110, 0x1f12d, Ambiguous),
+	(0x1f130, 0x1f169, Ambiguous),
+	(0x1f170, 0x1f18d, Ambiguous),
+	(0x1f18e, 0x1f18e, Wide),
+	(0x1f18f, 0x1f190, Ambiguous),
+	(0x1f191, 0x1f19a, Wide),
+	(0x1f19b, 0x1f1ac, Ambiguous),
+	(0x1f1ae, 0x1f1e5, Fullwidth),
+	(0x1f200, 0x1f202, Wide),
+	(0x1f203, 0x1f20f, Fullwidth),
+	(0x1f210, 0x1f23b, Wide),
+	(0x1f23c, 0x1f23f, Fullwidth),
+	(0x1f240, 0x1f248, Wide),
+	(0x1f249, 0x1f24f, Fullwidth),
+	(0x1f250, 0x1f251, Wide),
+	(0x1f252, 0x1f25f, Fullwidth),
+	(0x1f260, 0x1f265, Wide),
+	(0x1f266, 0x1f2ff, Fullwidth),
+	(0x1f300, 0x1f320, Wide),
+	(0x1f32d, 0x1f335, Wide),
+	(0x1f337, 0x1f37c, Wide),
+	(0x1f37e, 0x1f393, Wide),
+	(0x1f3a0, 0x1f3ca, Wide),
+	(0x1f3cf, 0x1f3d3, Wide),
+	(0x1f3e0, 0x1f3f0, Wide),
+	(0x1f3f4, 0x1f3f4, Wide),
+	(0x1f3f8, 0x1f43e, Wide),
+	(0x1f440, 0x1f440, Wide),
+	(0x1f442, 0x1f4fc, Wide),
+	(0x1f4ff, 0x1f53d, Wide),
+	(0x1f54b, 0x1f54e, Wide),
+	(0x1f550, 0x1f567, Wide),
+	(0x1f57a, 0x1f57a, Wide),
+	(0x1f595, 0x1f596, Wide),
+	(0x1f5a4, 0x1f5a4, Wide),
+	(0x1f5fb, 0x1f64f, Wide),
+	(0x1f680, 0x1f6c5, Wide),
+	(0x1f6cc, 0x1f6cc, Wide),
+	(0x1f6d0, 0x1f6d2, Wide),
+	(0x1f6d5, 0x1f6d7, Wide),
+	(0x1f6d8, 0x1f6dc, Fullwidth),
+	(0x1f6dd, 0x1f6df, Wide),
+	(0x1f6eb, 0x1f6ec, Wide),
+	(0x1f6ed, 0x1f6ef, Fullwidth),
+	(0x1f6f4, 0x1f6fc, Wide),
+	(0x1f6fd, 0x1f6ff, Fullwidth),
+	(0x1f774, 0x1f77f, Fullwidth),
+	(0x1f7d9, 0x1f7df, Fullwidth),
+	(0x1f7e0, 0x1f7eb, Wide),
+	(0x1f7ec, 0x1f7ef, Fullwidth),
+	(0x1f7f0, 0x1f7f0, Wide),
+	(0x1f7f1, 0x1f7ff, Fullwidth),
+	(0x1f80c, 0x1f80f, Fullwidth),
+	(0x1f848, 0x1f84f, Fullwidth),
+	(0x1f85a, 0x1f85f, Fullwidth),
+	(0x1f888, 0x1f88f, Fullwidth),
+	(0x1f8ae, 0x1f8af, Fullwidth),
+	(0x1f8b2, 0x1f8ff, Fullwidth),
+	(0x1f90c, 0x1f93a, Wide),
+	(0x1f93c, 0x1f945, Wide),
+	(0x1f947, 0x1f9ff, Wide),
+	(0x1fa54, 0x1fa5f, Fullwidth),
+	(0x1fa6e, 0x1fa6f, Fullwidth),
+	(0x1fa70, 0x1fa74, Wide),
+	(0x1fa75, 0x1fa77, Fullwidth),
+	(0x1fa78, 0x1fa7c, Wide),
+	(0x1fa7d, 0x1fa7f, Fullwidth),
+	(0x1fa80, 0x1fa86, Wide),
+	(0x1fa87, 0x1fa8f, Fullwidth),
+	(0x1fa90, 0x1faac, Wide),
+	(0x1faad, 0x1faaf, Fullwidth),
+	(0x1fab0, 0x1faba, Wide),
+	(0x1fabb, 0x1fabf, Fullwidth),
+	(0x1fac0, 0x1fac5, Wide),
+	(0x1fac6, 0x1facf, Fullwidth),
+	(0x1fad0, 0x1fad9, Wide),
+	(0x1fada, 0x1fadf, Fullwidth),
+	(0x1fae0, 0x1fae7, Wide),
+	(0x1fae8, 0x1faef, Fullwidth),
+	(0x1faf0, 0x1faf6, Wide),
+	(0x1faf7, 0x1faff, Fullwidth),
+	(0x1fb93, 0x1fb93, Fullwidth),
+	(0x1fbcb, 0x1fbef, Fullwidth),
+	(0x1fbfa, 0x1ffff, Fullwidth),
+	(0x20000, 0x2a6df, Wide),
+	(0x2a6e0, 0x2a6ff, Fullwidth),
+	(0x2a700, 0x2b738, Wide),
+	(0x2b739, 0x2b73f, Fullwidth),
+	(0x2b740, 0x2b81d, Wide),
+	(0x2b81e, 0x2b81f, Fullwidth),
+	(0x2b820, 0x2cea1, Wide),
+	(0x2cea2, 0x2ceaf, Fullwidth),
+	(0x2ceb0, 0x2ebe0, Wide),
+	(0x2ebe1, 0x2f7ff, Fullwidth),
+	(0x2f800, 0x2fa1d, Wide),
+	(0x2fa1e, 0x2ffff, Fullwidth),
+	(0x30000, 0x3134a, Wide),
+	(0x3134b, 0xe0000, Fullwidth),
+	(0xe0002, 0xe001f, Fullwidth),
+	(0xe0080, 0xe00ff, Fullwidth),
+	(0xe0100, 0xe01ef, Ambiguous),
+	(0xe01f0, 0xeffff, Fullwidth),
+	(0xf0000, 0xffffd, Ambiguous),
+	(0xffffe, 0xfffff, Fullwidth),
+	(0x100000, 0x10fffd, Ambiguous),
+	(0x10fffe, 0x10ffff, Fullwidth),
+];
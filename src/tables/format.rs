@@ -0,0 +1,27 @@
+// This file is generated by `scripts/unicode.py` from the Unicode Character Database,
+// version 14.0.0. Do not edit it manually.
+
+/// Format characters (general category `Cf`).
+pub(crate) const FORMAT: &[(u32, u32)] = &[
+	(0xad, 0xad),
+	(0x600, 0x605),
+	(0x61c, 0x61c),
+	(0x6dd, 0x6dd),
+	(0x70f, 0x70f),
+	(0x890, 0x891),
+	(0x8e2, 0x8e2),
+	(0x180e, 0x180e),
+	(0x200b, 0x200f),
+	(0x202a, 0x202e),
+	(0x2060, 0x2064),
+	(0x2066, 0x206f),
+	(0xfeff, 0xfeff),
+	(0xfff9, 0xfffb),
+	(0x110bd, 0x110bd),
+	(0x110cd, 0x110cd),
+	(0x13430, 0x13438),
+	(0x1bca0, 0x1bca3),
+	(0x1d173, 0x1d17a),
+	(0xe0001, 0xe0001),
+	(0xe0020, 0xe007f),
+];
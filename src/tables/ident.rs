@@ -0,0 +1,1026 @@
+// This file is generated by `scripts/unicode.py` from the Unicode Character Database,
+// version 14.0.0. Do not edit it manually.
+
+use crate::IdentRole::{self, *};
+
+/// Identifier role of the code points with the `XID_Start` or `XID_Continue` property.
+pub(crate) const IDENT: &[(u32, u32, IdentRole)] = &[
+	(0x30, 0x39, Continue),
+	(0x41, 0x5a, Start),
+	(0x5f, 0x5f, Continue),
+	(0x61, 0x7a, Start),
+	(0xaa, 0xaa, Start),
+	(0xb5, 0xb5, Start),
+	(0xb7, 0xb7, Continue),
+	(0xba, 0xba, Start),
+	(0xc0, 0xd6, Start),
+	(0xd8, 0xf6, Start),
+	(0xf8, 0x2c1, Start),
+	(0x2c6, 0x2d1, Start),
+	(0x2e0, 0x2e4, Start),
+	(0x2ec, 0x2ec, Start),
+	(0x2ee, 0x2ee, Start),
+	(0x300, 0x36f, Continue),
+	(0x370, 0x374, Start),
+	(0x376, 0x377, Start),
+	(0x37b, 0x37d, Start),
+	(0x37f, 0x37f, Start),
+	(0x386, 0x386, Start),
+	(0x387, 0x387, Continue),
+	(0x388, 0x38a, Start),
+	(0x38c, 0x38c, Start),
+	(0x38e, 0x3a1, Start),
+	(0x3a3, 0x3f5, Start),
+	(0x3f7, 0x481, Start),
+	(0x483, 0x487, Continue),
+	(0x48a, 0x52f, Start),
+	(0x531, 0x556, Start),
+	(0x559, 0x559, Start),
+	(0x560, 0x588, Start),
+	(0x591, 0x5bd, Continue),
+	(0x5bf, 0x5bf, Continue),
+	(0x5c1, 0x5c2, Continue),
+	(0x5c4, 0x5c5, Continue),
+	(0x5c7, 0x5c7, Continue),
+	(0x5d0, 0x5ea, Start),
+	(0x5ef, 0x5f2, Start),
+	(0x610, 0x61a, Continue),
+	(0x620, 0x64a, Start),
+	(0x64b, 0x669, Continue),
+	(0x66e, 0x66f, Start),
+	(0x670, 0x670, Continue),
+	(0x671, 0x6d3, Start),
+	(0x6d5, 0x6d5, Start),
+	(0x6d6, 0x6dc, Continue),
+	(0x6df, 0x6e4, Continue),
+	(0x6e5, 0x6e6, Start),
+	(0x6e7, 0x6e8, Continue),
+	(0x6ea, 0x6ed, Continue),
+	(0x6ee, 0x6ef, Start),
+	(0x6f0, 0x6f9, Continue),
+	(0x6fa, 0x6fc, Start),
+	(0x6ff, 0x6ff, Start),
+	(0x710, 0x710, Start),
+	(0x711, 0x711, Continue),
+	(0x712, 0x72f, Start),
+	(0x730, 0x74a, Continue),
+	(0x74d, 0x7a5, Start),
+	(0x7a6, 0x7b0, Continue),
+	(0x7b1, 0x7b1, Start),
+	(0x7c0, 0x7c9, Continue),
+	(0x7ca, 0x7ea, Start),
+	(0x7eb, 0x7f3, Continue),
+	(0x7f4, 0x7f5, Start),
+	(0x7fa, 0x7fa, Start),
+	(0x7fd, 0x7fd, Continue),
+	(0x800, 0x815, Start),
+	(0x816, 0x819, Continue),
+	(0x81a, 0x81a, Start),
+	(0x81b, 0x823, Continue),
+	(0x824, 0x824, Start),
+	(0x825, 0x827, Continue),
+	(0x828, 0x828, Start),
+	(0x829, 0x82d, Continue),
+	(0x840, 0x858, Start),
+	(0x859, 0x85b, Continue),
+	(0x860, 0x86a, Start),
+	(0x870, 0x887, Start),
+	(0x889, 0x88e, Start),
+	(0x898, 0x89f, Continue),
+	(0x8a0, 0x8c9, Start),
+	(0x8ca, 0x8e1, Continue),
+	(0x8e3, 0x903, Continue),
+	(0x904, 0x939, Start),
+	(0x93a, 0x93c, Continue),
+	(0x93d, 0x93d, Start),
+	(0x93e, 0x94f, Continue),
+	(0x950, 0x950, Start),
+	(0x951, 0x957, Continue),
+	(0x958, 0x961, Start),
+	(0x962, 0x963, Continue),
+	(0x966, 0x96f, Continue),
+	(0x971, 0x980, Start),
+	(0x981, 0x983, Continue),
+	(0x985, 0x98c, Start),
+	(0x98f, 0x990, Start),
+	(0x993, 0x9a8, Start),
+	(0x9aa, 0x9b0, Start),
+	(0x9b2, 0x9b2, Start),
+	(0x9b6, 0x9b9, Start),
+	(0x9bc, 0x9bc, Continue),
+	(0x9bd, 0x9bd, Start),
+	(0x9be, 0x9c4, Continue),
+	(0x9c7, 0x9c8, Continue),
+	(0x9cb, 0x9cd, Continue),
+	(0x9ce, 0x9ce, Start),
+	(0x9d7, 0x9d7, Continue),
+	(0x9dc, 0x9dd, Start),
+	(0x9df, 0x9e1, Start),
+	(0x9e2, 0x9e3, Continue),
+	(0x9e6, 0x9ef, Continue),
+	(0x9f0, 0x9f1, Start),
+	(0x9fc, 0x9fc, Start),
+	(0x9fe, 0x9fe, Continue),
+	(0xa01, 0xa03, Continue),
+	(0xa05, 0xa0a, Start),
+	(0xa0f, 0xa10, Start),
+	(0xa13, 0xa28, Start),
+	(0xa2a, 0xa30, Start),
+	(0xa32, 0xa33, Start),
+	(0xa35, 0xa36, Start),
+	(0xa38, 0xa39, Start),
+	(0xa3c, 0xa3c, Continue),
+	(0xa3e, 0xa42, Continue),
+	(0xa47, 0xa48, Continue),
+	(0xa4b, 0xa4d, Continue),
+	(0xa51, 0xa51, Continue),
+	(0xa59, 0xa5c, Start),
+	(0xa5e, 0xa5e, Start),
+	(0xa66, 0xa71, Continue),
+	(0xa72, 0xa74, Start),
+	(0xa75, 0xa75, Continue),
+	(0xa81, 0xa83, Continue),
+	(0xa85, 0xa8d, Start),
+	(0xa8f, 0xa91, Start),
+	(0xa93, 0xaa8, Start),
+	(0xaaa, 0xab0, Start),
+	(0xab2, 0xab3, Start),
+	(0xab5, 0xab9, Start),
+	(0xabc, 0xabc, Continue),
+	(0xabd, 0xabd, Start),
+	(0xabe, 0xac5, Continue),
+	(0xac7, 0xac9, Continue),
+	(0xacb, 0xacd, Continue),
+	(0xad0, 0xad0, Start),
+	(0xae0, 0xae1, Start),
+	(0xae2, 0xae3, Continue),
+	(0xae6, 0xaef, Continue),
+	(0xaf9, 0xaf9, Start),
+	(0xafa, 0xaff, Continue),
+	(0xb01, 0xb03, Continue),
+	(0xb05, 0xb0c, Start),
+	(0xb0f, 0xb10, Start),
+	(0xb13, 0xb28, Start),
+	(0xb2a, 0xb30, Start),
+	(0xb32, 0xb33, Start),
+	(0xb35, 0xb39, Start),
+	(0xb3c, 0xb3c, Continue),
+	(0xb3d, 0xb3d, Start),
+	(0xb3e, 0xb44, Continue),
+	(0xb47, 0xb48, Continue),
+	(0xb4b, 0xb4d, Continue),
+	(0xb55, 0xb57, Continue),
+	(0xb5c, 0xb5d, Start),
+	(0xb5f, 0xb61, Start),
+	(0xb62, 0xb63, Continue),
+	(0xb66, 0xb6f, Continue),
+	(0xb71, 0xb71, Start),
+	(0xb82, 0xb82, Continue),
+	(0xb83, 0xb83, Start),
+	(0xb85, 0xb8a, Start),
+	(0xb8e, 0xb90, Start),
+	(0xb92, 0xb95, Start),
+	(0xb99, 0xb9a, Start),
+	(0xb9c, 0xb9c, Start),
+	(0xb9e, 0xb9f, Start),
+	(0xba3, 0xba4, Start),
+	(0xba8, 0xbaa, Start),
+	(0xbae, 0xbb9, Start),
+	(0xbbe, 0xbc2, Continue),
+	(0xbc6, 0xbc8, Continue),
+	(0xbca, 0xbcd, Continue),
+	(0xbd0, 0xbd0, Start),
+	(0xbd7, 0xbd7, Continue),
+	(0xbe6, 0xbef, Continue),
+	(0xc00, 0xc04, Continue),
+	(0xc05, 0xc0c, Start),
+	(0xc0e, 0xc10, Start),
+	(0xc12, 0xc28, Start),
+	(0xc2a, 0xc39, Start),
+	(0xc3c, 0xc3c, Continue),
+	(0xc3d, 0xc3d, Start),
+	(0xc3e, 0xc44, Continue),
+	(0xc46, 0xc48, Continue),
+	(0xc4a, 0xc4d, Continue),
+	(0xc55, 0xc56, Continue),
+	(0xc58, 0xc5a, Start),
+	(0xc5d, 0xc5d, Start),
+	(0xc60, 0xc61, Start),
+	(0xc62, 0xc63, Continue),
+	(0xc66, 0xc6f, Continue),
+	(0xc80, 0xc80, Start),
+	(0xc81, 0xc83, Continue),
+	(0xc85, 0xc8c, Start),
+	(0xc8e, 0xc90, Start),
+	(0xc92, 0xca8, Start),
+	(0xcaa, 0xcb3, Start),
+	(0xcb5, 0xcb9, Start),
+	(0xcbc, 0xcbc, Continue),
+	(0xcbd, 0xcbd, Start),
+	(0xcbe, 0xcc4, Continue),
+	(0xcc6, 0xcc8, Continue),
+	(0xcca, 0xccd, Continue),
+	(0xcd5, 0xcd6, Continue),
+	(0xcdd, 0xcde, Start),
+	(0xce0, 0xce1, Start),
+	(0xce2, 0xce3, Continue),
+	(0xce6, 0xcef, Continue),
+	(0xcf1, 0xcf2, Start),
+	(0xd00, 0xd03, Continue),
+	(0xd04, 0xd0c, Start),
+	(0xd0e, 0xd10, Start),
+	(0xd12, 0xd3a, Start),
+	(0xd3b, 0xd3c, Continue),
+	(0xd3d, 0xd3d, Start),
+	(0xd3e, 0xd44, Continue),
+	(0xd46, 0xd48, Continue),
+	(0xd4a, 0xd4d, Continue),
+	(0xd4e, 0xd4e, Start),
+	(0xd54, 0xd56, Start),
+	(0xd57, 0xd57, Continue),
+	(0xd5f, 0xd61, Start),
+	(0xd62, 0xd63, Continue),
+	(0xd66, 0xd6f, Continue),
+	(0xd7a, 0xd7f, Start),
+	(0xd81, 0xd83, Continue),
+	(0xd85, 0xd96, Start),
+	(0xd9a, 0xdb1, Start),
+	(0xdb3, 0xdbb, Start),
+	(0xdbd, 0xdbd, Start),
+	(0xdc0, 0xdc6, Start),
+	(0xdca, 0xdca, Continue),
+	(0xdcf, 0xdd4, Continue),
+	(0xdd6, 0xdd6, Continue),
+	(0xdd8, 0xddf, Continue),
+	(0xde6, 0xdef, Continue),
+	(0xdf2, 0xdf3, Continue),
+	(0xe01, 0xe30, Start),
+	(0xe31, 0xe31, Continue),
+	(0xe32, 0xe32, Start),
+	(0xe33, 0xe3a, Continue),
+	(0xe40, 0xe46, Start),
+	(0xe47, 0xe4e, Continue),
+	(0xe50, 0xe59, Continue),
+	(0xe81, 0xe82, Start),
+	(0xe84, 0xe84, Start),
+	(0xe86, 0xe8a, Start),
+	(0xe8c, 0xea3, Start),
+	(0xea5, 0xea5, Start),
+	(0xea7, 0xeb0, Start),
+	(0xeb1, 0xeb1, Continue),
+	(0xeb2, 0xeb2, Start),
+	(0xeb3, 0xebc, Continue),
+	(0xebd, 0xebd, Start),
+	(0xec0, 0xec4, Start),
+	(0xec6, 0xec6, Start),
+	(0xec8, 0xecd, Continue),
+	(0xed0, 0xed9, Continue),
+	(0xedc, 0xedf, Start),
+	(0xf00, 0xf00, Start),
+	(0xf18, 0xf19, Continue),
+	(0xf20, 0xf29, Continue),
+	(0xf35, 0xf35, Continue),
+	(0xf37, 0xf37, Continue),
+	(0xf39, 0xf39, Continue),
+	(0xf3e, 0xf3f, Continue),
+	(0xf40, 0xf47, Start),
+	(0xf49, 0xf6c, Start),
+	(0xf71, 0xf84, Continue),
+	(0xf86, 0xf87, Continue),
+	(0xf88, 0xf8c, Start),
+	(0xf8d, 0xf97, Continue),
+	(0xf99, 0xfbc, Continue),
+	(0xfc6, 0xfc6, Continue),
+	(0x1000, 0x102a, Start),
+	(0x102b, 0x103e, Continue),
+	(0x103f, 0x103f, Start),
+	(0x1040, 0x1049, Continue),
+	(0x1050, 0x1055, Start),
+	(0x1056, 0x1059, Continue),
+	(0x105a, 0x105d, Start),
+	(0x105e, 0x1060, Continue),
+	(0x1061, 0x1061, Start),
+	(0x1062, 0x1064, Continue),
+	(0x1065, 0x1066, Start),
+	(0x1067, 0x106d, Continue),
+	(0x106e, 0x1070, Start),
+	(0x1071, 0x1074, Continue),
+	(0x1075, 0x1081, Start),
+	(0x1082, 0x108d, Continue),
+	(0x108e, 0x108e, Start),
+	(0x108f, 0x109d, Continue),
+	(0x10a0, 0x10c5, Start),
+	(0x10c7, 0x10c7, Start),
+	(0x10cd, 0x10cd, Start),
+	(0x10d0, 0x10fa, Start),
+	(0x10fc, 0x1248, Start),
+	(0x124a, 0x124d, Start),
+	(0x1250, 0x1256, Start),
+	(0x1258, 0x1258, Start),
+	(0x125a, 0x125d, Start),
+	(0x1260, 0x1288, Start),
+	(0x128a, 0x128d, Start),
+	(0x1290, 0x12b0, Start),
+	(0x12b2, 0x12b5, Start),
+	(0x12b8, 0x12be, Start),
+	(0x12c0, 0x12c0, Start),
+	(0x12c2, 0x12c5, Start),
+	(0x12c8, 0x12d6, Start),
+	(0x12d8, 0x1310, Start),
+	(0x1312, 0x1315, Start),
+	(0x1318, 0x135a, Start),
+	(0x135d, 0x135f, Continue),
+	(0x1369, 0x1371, Continue),
+	(0x1380, 0x138f, Start),
+	(0x13a0, 0x13f5, Start),
+	(0x13f8, 0x13fd, Start),
+	(0x1401, 0x166c, Start),
+	(0x166f, 0x167f, Start),
+	(0x1681, 0x169a, Start),
+	(0x16a0, 0x16ea, Start),
+	(0x16ee, 0x16f8, Start),
+	(0x1700, 0x1711, Start),
+	(0x1712, 0x1715, Continue),
+	(0x171f, 0x1731, Start),
+	(0x1732, 0x1734, Continue),
+	(0x1740, 0x1751, Start),
+	(0x1752, 0x1753, Continue),
+	(0x1760, 0x176c, Start),
+	(0x176e, 0x1770, Start),
+	(0x1772, 0x1773, Continue),
+	(0x1780, 0x17b3, Start),
+	(0x17b4, 0x17d3, Continue),
+	(0x17d7, 0x17d7, Start),
+	(0x17dc, 0x17dc, Start),
+	(0x17dd, 0x17dd, Continue),
+	(0x17e0, 0x17e9, Continue),
+	(0x180b, 0x180d, Continue),
+	(0x180f, 0x1819, Continue),
+	(0x1820, 0x1878, Start),
+	(0x1880, 0x18a8, Start),
+	(0x18a9, 0x18a9, Continue),
+	(0x18aa, 0x18aa, Start),
+	(0x18b0, 0x18f5, Start),
+	(0x1900, 0x191e, Start),
+	(0x1920, 0x192b, Continue),
+	(0x1930, 0x193b, Continue),
+	(0x1946, 0x194f, Continue),
+	(0x1950, 0x196d, Start),
+	(0x1970, 0x1974, Start),
+	(0x1980, 0x19ab, Start),
+	(0x19b0, 0x19c9, Start),
+	(0x19d0, 0x19da, Continue),
+	(0x1a00, 0x1a16, Start),
+	(0x1a17, 0x1a1b, Continue),
+	(0x1a20, 0x1a54, Start),
+	(0x1a55, 0x1a5e, Continue),
+	(0x1a60, 0x1a7c, Continue),
+	(0x1a7f, 0x1a89, Continue),
+	(0x1a90, 0x1a99, Continue),
+	(0x1aa7, 0x1aa7, Start),
+	(0x1ab0, 0x1abd, Continue),
+	(0x1abf, 0x1ace, Continue),
+	(0x1b00, 0x1b04, Continue),
+	(0x1b05, 0x1b33, Start),
+	(0x1b34, 0x1b44, Continue),
+	(0x1b45, 0x1b4c, Start),
+	(0x1b50, 0x1b59, Continue),
+	(0x1b6b, 0x1b73, Continue),
+	(0x1b80, 0x1b82, Continue),
+	(0x1b83, 0x1ba0, Start),
+	(0x1ba1, 0x1bad, Continue),
+	(0x1bae, 0x1baf, Start),
+	(0x1bb0, 0x1bb9, Continue),
+	(0x1bba, 0x1be5, Start),
+	(0x1be6, 0x1bf3, Continue),
+	(0x1c00, 0x1c23, Start),
+	(0x1c24, 0x1c37, Continue),
+	(0x1c40, 0x1c49, Continue),
+	(0x1c4d, 0x1c4f, Start),
+	(0x1c50, 0x1c59, Continue),
+	(0x1c5a, 0x1c7d, Start),
+	(0x1c80, 0x1c88, Start),
+	(0x1c90, 0x1cba, Start),
+	(0x1cbd, 0x1cbf, Start),
+	(0x1cd0, 0x1cd2, Continue),
+	(0x1cd4, 0x1ce8, Continue),
+	(0x1ce9, 0x1cec, Start),
+	(0x1ced, 0x1ced, Continue),
+	(0x1cee, 0x1cf3, Start),
+	(0x1cf4, 0x1cf4, Continue),
+	(0x1cf5, 0x1cf6, Start),
+	(0x1cf7, 0x1cf9, Continue),
+	(0x1cfa, 0x1cfa, Start),
+	(0x1d00, 0x1dbf, Start),
+	(0x1dc0, 0x1dff, Continue),
+	(0x1e00, 0x1f15, Start),
+	(0x1f18, 0x1f1d, Start),
+	(0x1f20, 0x1f45, Start),
+	(0x1f48, 0x1f4d, Start),
+	(0x1f50, 0x1f57, Start),
+	(0x1f59, 0x1f59, Start),
+	(0x1f5b, 0x1f5b, Start),
+	(0x1f5d, 0x1f5d, Start),
+	(0x1f5f, 0x1f7d, Start),
+	(0x1f80, 0x1fb4, Start),
+	(0x1fb6, 0x1fbc, Start),
+	(0x1fbe, 0x1fbe, Start),
+	(0x1fc2, 0x1fc4, Start),
+	(0x1fc6, 0x1fcc, Start),
+	(0x1fd0, 0x1fd3, Start),
+	(0x1fd6, 0x1fdb, Start),
+	(0x1fe0, 0x1fec, Start),
+	(0x1ff2, 0x1ff4, Start),
+	(0x1ff6, 0x1ffc, Start),
+	(0x203f, 0x2040, Continue),
+	(0x2054, 0x2054, Continue),
+	(0x2071, 0x2071, Start),
+	(0x207f, 0x207f, Start),
+	(0x2090, 0x209c, Start),
+	(0x20d0, 0x20dc, Continue),
+	(0x20e1, 0x20e1, Continue),
+	(0x20e5, 0x20f0, Continue),
+	(0x2102, 0x2102, Start),
+	(0x2107, 0x2107, Start),
+	(0x210a, 0x2113, Start),
+	(0x2115, 0x2115, Start),
+	(0x2118, 0x211d, Start),
+	(0x2124, 0x2124, Start),
+	(0x2126, 0x2126, Start),
+	(0x2128, 0x2128, Start),
+	(0x212a, 0x2139, Start),
+	(0x213c, 0x213f, Start),
+	(0x2145, 0x2149, Start),
+	(0x214e, 0x214e, Start),
+	(0x2160, 0x2188, Start),
+	(0x2c00, 0x2ce4, Start),
+	(0x2ceb, 0x2cee, Start),
+	(0x2cef, 0x2cf1, Continue),
+	(0x2cf2, 0x2cf3, Start),
+	(0x2d00, 0x2d25, Start),
+	(0x2d27, 0x2d27, Start),
+	(0x2d2d, 0x2d2d, Start),
+	(0x2d30, 0x2d67, Start),
+	(0x2d6f, 0x2d6f, Start),
+	(0x2d7f, 0x2d7f, Continue),
+	(0x2d80, 0x2d96, Start),
+	(0x2da0, 0x2da6, Start),
+	(0x2da8, 0x2dae, Start),
+	(0x2db0, 0x2db6, Start),
+	(0x2db8, 0x2dbe, Start),
+	(0x2dc0, 0x2dc6, Start),
+	(0x2dc8, 0x2dce, Start),
+	(0x2dd0, 0x2dd6, Start),
+	(0x2dd8, 0x2dde, Start),
+	(0x2de0, 0x2dff, Continue),
+	(0x3005, 0x3007, Start),
+	(0x3021, 0x3029, Start),
+	(0x302a, 0x302f, Continue),
+	(0x3031, 0x3035, Start),
+	(0x3038, 0x303c, Start),
+	(0x3041, 0x3096, Start),
+	(0x3099, 0x309a, Continue),
+	(0x309d, 0x309f, Start),
+	(0x30a1, 0x30fa, Start),
+	(0x30fc, 0x30ff, Start),
+	(0x3105, 0x312f, Start),
+	(0x3131, 0x318e, Start),
+	(0x31a0, 0x31bf, Start),
+	(0x31f0, 0x31ff, Start),
+	(0x3400, 0x4dbf, Start),
+	(0x4e00, 0xa48c, Start),
+	(0xa4d0, 0xa4fd, Start),
+	(0xa500, 0xa60c, Start),
+	(0xa610, 0xa61f, Start),
+	(0xa620, 0xa629, Continue),
+	(0xa62a, 0xa62b, Start),
+	(0xa640, 0xa66e, Start),
+	(0xa66f, 0xa66f, Continue),
+	(0xa674, 0xa67d, Continue),
+	(0xa67f, 0xa69d, Start),
+	(0xa69e, 0xa69f, Continue),
+	(0xa6a0, 0xa6ef, Start),
+	(0xa6f0, 0xa6f1, Continue),
+	(0xa717, 0xa71f, Start),
+	(0xa722, 0xa788, Start),
+	(0xa78b, 0xa7ca, Start),
+	(0xa7d0, 0xa7d1, Start),
+	(0xa7d3, 0xa7d3, Start),
+	(0xa7d5, 0xa7d9, Start),
+	(0xa7f2, 0xa801, Start),
+	(0xa802, 0xa802, Continue),
+	(0xa803, 0xa805, Start),
+	(0xa806, 0xa806, Continue),
+	(0xa807, 0xa80a, Start),
+	(0xa80b, 0xa80b, Continue),
+	(0xa80c, 0xa822, Start),
+	(0xa823, 0xa827, Continue),
+	(0xa82c, 0xa82c, Continue),
+	(0xa840, 0xa873, Start),
+	(0xa880, 0xa881, Continue),
+	(0xa882, 0xa8b3, Start),
+	(0xa8b4, 0xa8c5, Continue),
+	(0xa8d0, 0xa8d9, Continue),
+	(0xa8e0, 0xa8f1, Continue),
+	(0xa8f2, 0xa8f7, Start),
+	(0xa8fb, 0xa8fb, Start),
+	(0xa8fd, 0xa8fe, Start),
+	(0xa8ff, 0xa909, Continue),
+	(0xa90a, 0xa925, Start),
+	(0xa926, 0xa92d, Continue),
+	(0xa930, 0xa946, Start),
+	(0xa947, 0xa953, Continue),
+	(0xa960, 0xa97c, Start),
+	(0xa980, 0xa983, Continue),
+	(0xa984, 0xa9b2, Start),
+	(0xa9b3, 0xa9c0, Continue),
+	(0xa9cf, 0xa9cf, Start),
+	(0xa9d0, 0xa9d9, Continue),
+	(0xa9e0, 0xa9e4, Start),
+	(0xa9e5, 0xa9e5, Continue),
+	(0xa9e6, 0xa9ef, Start),
+	(0xa9f0, 0xa9f9, Continue),
+	(0xa9fa, 0xa9fe, Start),
+	(0xaa00, 0xaa28, Start),
+	(0xaa29, 0xaa36, Continue),
+	(0xaa40, 0xaa42, Start),
+	(0xaa43, 0xaa43, Continue),
+	(0xaa44, 0xaa4b, Start),
+	(0xaa4c, 0xaa4d, Continue),
+	(0xaa50, 0xaa59, Continue),
+	(0xaa60, 0xaa76, Start),
+	(0xaa7a, 0xaa7a, Start),
+	(0xaa7b, 0xaa7d, Continue),
+	(0xaa7e, 0xaaaf, Start),
+	(0xaab0, 0xaab0, Continue),
+	(0xaab1, 0xaab1, Start),
+	(0xaab2, 0xaab4, Continue),
+	(0xaab5, 0xaab6, Start),
+	(0xaab7, 0xaab8, Continue),
+	(0xaab9, 0xaabd, Start),
+	(0xaabe, 0xaabf, Continue),
+	(0xaac0, 0xaac0, Start),
+	(0xaac1, 0xaac1, Continue),
+	(0xaac2, 0xaac2, Start),
+	(0xaadb, 0xaadd, Start),
+	(0xaae0, 0xaaea, Start),
+	(0xaaeb, 0xaaef, Continue),
+	(0xaaf2, 0xaaf4, Start),
+	(0xaaf5, 0xaaf6, Continue),
+	(0xab01, 0xab06, Start),
+	(0xab09, 0xab0e, Start),
+	(0xab11, 0xab16, Start),
+	(0xab20, 0xab26, Start),
+	(0xab28, 0xab2e, Start),
+	(0xab30, 0xab5a, Start),
+	(0xab5c, 0xab69, Start),
+	(0xab70, 0xabe2, Start),
+	(0xabe3, 0xabea, Continue),
+	(0xabec, 0xabed, Continue),
+	(0xabf0, 0xabf9, Continue),
+	(0xac00, 0xd7a3, Start),
+	(0xd7b0, 0xd7c6, Start),
+	(0xd7cb, 0xd7fb, Start),
+	(0xf900, 0xfa6d, Start),
+	(0xfa70, 0xfad9, Start),
+	(0xfb00, 0xfb06, Start),
+	(0xfb13, 0xfb17, Start),
+	(0xfb1d, 0xfb1d, Start),
+	(0xfb1e, 0xfb1e, Continue),
+	(0xfb1f, 0xfb28, Start),
+	(0xfb2a, 0xfb36, Start),
+	(0xfb38, 0xfb3c, Start),
+	(0xfb3e, 0xfb3e, Start),
+	(0xfb40, 0xfb41, Start),
+	(0xfb43, 0xfb44, Start),
+	(0xfb46, 0xfbb1, Start),
+	(0xfbd3, 0xfc5d, Start),
+	(0xfc64, 0xfd3d, Start),
+	(0xfd50, 0xfd8f, Start),
+	(0xfd92, 0xfdc7, Start),
+	(0xfdf0, 0xfdf9, Start),
+	(0xfe00, 0xfe0f, Continue),
+	(0xfe20, 0xfe2f, Continue),
+	(0xfe33, 0xfe34, Continue),
+	(0xfe4d, 0xfe4f, Continue),
+	(0xfe71, 0xfe71, Start),
+	(0xfe73, 0xfe73, Start),
+	(0xfe77, 0xfe77, Start),
+	(0xfe79, 0xfe79, Start),
+	(0xfe7b, 0xfe7b, Start),
+	(0xfe7d, 0xfe7d, Start),
+	(0xfe7f, 0xfefc, Start),
+	(0xff10, 0xff19, Continue),
+	(0xff21, 0xff3a, Start),
+	(0xff3f, 0xff3f, Continue),
+	(0xff41, 0xff5a, Start),
+	(0xff66, 0xff9d, Start),
+	(0xff9e, 0xff9f, Continue),
+	(0xffa0, 0xffbe, Start),
+	(0xffc2, 0xffc7, Start),
+	(0xffca, 0xffcf, Start),
+	(0xffd2, 0xffd7, Start),
+	(0xffda, 0xffdc, Start),
+	(0x10000, 0x1000b, Start),
+	(0x1000d, 0x10026, Start),
+	(0x10028, 0x1003a, Start),
+	(0x1003c, 0x1003d, Start),
+	(0x1003f, 0x1004d, Start),
+	(0x10050, 0x1005d, Start),
+	(0x10080, 0x100fa, Start),
+	(0x10140, 0x10174, Start),
+	(0x101fd, 0x101fd, Continue),
+	(0x10280, 0x1029c, Start),
+	(0x102a0, 0x102d0, Start),
+	(0x102e0, 0x102e0, Continue),
+	(0x10300, 0x1031f, Start),
+	(0x1032d, 0x1034a, Start),
+	(0x10350, 0x10375, Start),
+	(0x10376, 0x1037a, Continue),
+	(0x10380, 0x1039d, Start),
+	(0x103a0, 0x103c3, Start),
+	(0x103c8, 0x103cf, Start),
+	(0x103d1, 0x103d5, Start),
+	(0x10400, 0x1049d, Start),
+	(0x104a0, 0x104a9, Continue),
+	(0x104b0, 0x104d3, Start),
+	(0x104d8, 0x104fb, Start),
+	(0x10500, 0x10527, Start),
+	(0x10530, 0x10563, Start),
+	(0x10570, 0x1057a, Start),
+	(0x1057c, 0x1058a, Start),
+	(0x1058c, 0x10592, Start),
+	(0x10594, 0x10595, Start),
+	(0x10597, 0x105a1, Start),
+	(0x105a3, 0x105b1, Start),
+	(0x105b3, 0x105b9, Start),
+	(0x105bb, 0x105bc, Start),
+	(0x10600, 0x10736, Start),
+	(0x10740, 0x10755, Start),
+	(0x10760, 0x10767, Start),
+	(0x10780, 0x10785, Start),
+	(0x10787, 0x107b0, Start),
+	(0x107b2, 0x107ba, Start),
+	(0x10800, 0x10805, Start),
+	(0x10808, 0x10808, Start),
+	(0x1080a, 0x10835, Start),
+	(0x10837, 0x10838, Start),
+	(0x1083c, 0x1083c, Start),
+	(0x1083f, 0x10855, Start),
+	(0x10860, 0x10876, Start),
+	(0x10880, 0x1089e, Start),
+	(0x108e0, 0x108f2, Start),
+	(0x108f4, 0x108f5, Start),
+	(0x10900, 0x10915, Start),
+	(0x10920, 0x10939, Start),
+	(0x10980, 0x109b7, Start),
+	(0x109be, 0x109bf, Start),
+	(0x10a00, 0x10a00, Start),
+	(0x10a01, 0x10a03, Continue),
+	(0x10a05, 0x10a06, Continue),
+	(0x10a0c, 0x10a0f, Continue),
+	(0x10a10, 0x10a13, Start),
+	(0x10a15, 0x10a17, Start),
+	(0x10a19, 0x10a35, Start),
+	(0x10a38, 0x10a3a, Continue),
+	(0x10a3f, 0x10a3f, Continue),
+	(0x10a60, 0x10a7c, Start),
+	(0x10a80, 0x10a9c, Start),
+	(0x10ac0, 0x10ac7, Start),
+	(0x10ac9, 0x10ae4, Start),
+	(0x10ae5, 0x10ae6, Continue),
+	(0x10b00, 0x10b35, Start),
+	(0x10b40, 0x10b55, Start),
+	(0x10b60, 0x10b72, Start),
+	(0x10b80, 0x10b91, Start),
+	(0x10c00, 0x10c48, Start),
+	(0x10c80, 0x10cb2, Start),
+	(0x10cc0, 0x10cf2, Start),
+	(0x10d00, 0x10d23, Start),
+	(0x10d24, 0x10d27, Continue),
+	(0x10d30, 0x10d39, Continue),
+	(0x10e80, 0x10ea9, Start),
+	(0x10eab, 0x10eac, Continue),
+	(0x10eb0, 0x10eb1, Start),
+	(0x10f00, 0x10f1c, Start),
+	(0x10f27, 0x10f27, Start),
+	(0x10f30, 0x10f45, Start),
+	(0x10f46, 0x10f50, Continue),
+	(0x10f70, 0x10f81, Start),
+	(0x10f82, 0x10f85, Continue),
+	(0x10fb0, 0x10fc4, Start),
+	(0x10fe0, 0x10ff6, Start),
+	(0x11000, 0x11002, Continue),
+	(0x11003, 0x11037, Start),
+	(0x11038, 0x11046, Continue),
+	(0x11066, 0x11070, Continue),
+	(0x11071, 0x11072, Start),
+	(0x11073, 0x11074, Continue),
+	(0x11075, 0x11075, Start),
+	(0x1107f, 0x11082, Continue),
+	(0x11083, 0x110af, Start),
+	(0x110b0, 0x110ba, Continue),
+	(0x110c2, 0x110c2, Continue),
+	(0x110d0, 0x110e8, Start),
+	(0x110f0, 0x110f9, Continue),
+	(0x11100, 0x11102, Continue),
+	(0x11103, 0x11126, Start),
+	(0x11127, 0x11134, Continue),
+	(0x11136, 0x1113f, Continue),
+	(0x11144, 0x11144, Start),
+	(0x11145, 0x11146, Continue),
+	(0x11147, 0x11147, Start),
+	(0x11150, 0x11172, Start),
+	(0x11173, 0x11173, Continue),
+	(0x11176, 0x11176, Start),
+	(0x11180, 0x11182, Continue),
+	(0x11183, 0x111b2, Start),
+	(0x111b3, 0x111c0, Continue),
+	(0x111c1, 0x111c4, Start),
+	(0x111c9, 0x111cc, Continue),
+	(0x111ce, 0x111d9, Continue),
+	(0x111da, 0x111da, Start),
+	(0x111dc, 0x111dc, Start),
+	(0x11200, 0x11211, Start),
+	(0x11213, 0x1122b, Start),
+	(0x1122c, 0x11237, Continue),
+	(0x1123e, 0x1123e, Continue),
+	(0x11280, 0x11286, Start),
+	(0x11288, 0x11288, Start),
+	(0x1128a, 0x1128d, Start),
+	(0x1128f, 0x1129d, Start),
+	(0x1129f, 0x112a8, Start),
+	(0x112b0, 0x112de, Start),
+	(0x112df, 0x112ea, Continue),
+	(0x112f0, 0x112f9, Continue),
+	(0x11300, 0x11303, Continue),
+	(0x11305, 0x1130c, Start),
+	(0x1130f, 0x11310, Start),
+	(0x11313, 0x11328, Start),
+	(0x1132a, 0x11330, Start),
+	(0x11332, 0x11333, Start),
+	(0x11335, 0x11339, Start),
+	(0x1133b, 0x1133c, Continue),
+	(0x1133d, 0x1133d, Start),
+	(0x1133e, 0x11344, Continue),
+	(0x11347, 0x11348, Continue),
+	(0x1134b, 0x1134d, Continue),
+	(0x11350, 0x11350, Start),
+	(0x11357, 0x11357, Continue),
+	(0x1135d, 0x11361, Start),
+	(0x11362, 0x11363, Continue),
+	(0x11366, 0x1136c, Continue),
+	(0x11370, 0x11374, Continue),
+	(0x11400, 0x11434, Start),
+	(0x11435, 0x11446, Continue),
+	(0x11447, 0x1144a, Start),
+	(0x11450, 0x11459, Continue),
+	(0x1145e, 0x1145e, Continue),
+	(0x1145f, 0x11461, Start),
+	(0x11480, 0x114af, Start),
+	(0x114b0, 0x114c3, Continue),
+	(0x114c4, 0x114c5, Start),
+	(0x114c7, 0x114c7, Start),
+	(0x114d0, 0x114d9, Continue),
+	(0x11580, 0x115ae, Start),
+	(0x115af, 0x115b5, Continue),
+	(0x115b8, 0x115c0, Continue),
+	(0x115d8, 0x115db, Start),
+	(0x115dc, 0x115dd, Continue),
+	(0x11600, 0x1162f, Start),
+	(0x11630, 0x11640, Continue),
+	(0x11644, 0x11644, Start),
+	(0x11650, 0x11659, Continue),
+	(0x11680, 0x116aa, Start),
+	(0x116ab, 0x116b7, Continue),
+	(0x116b8, 0x116b8, Start),
+	(0x116c0, 0x116c9, Continue),
+	(0x11700, 0x1171a, Start),
+	(0x1171d, 0x1172b, Continue),
+	(0x11730, 0x11739, Continue),
+	(0x11740, 0x11746, Start),
+	(0x11800, 0x1182b, Start),
+	(0x1182c, 0x1183a, Continue),
+	(0x118a0, 0x118df, Start),
+	(0x118e0, 0x118e9, Continue),
+	(0x118ff, 0x11906, Start),
+	(0x11909, 0x11909, Start),
+	(0x1190c, 0x11913, Start),
+	(0x11915, 0x11916, Start),
+	(0x11918, 0x1192f, Start),
+	(0x11930, 0x11935, Continue),
+	(0x11937, 0x11938, Continue),
+	(0x1193b, 0x1193e, Continue),
+	(0x1193f, 0x1193f, Start),
+	(0x11940, 0x11940, Continue),
+	(0x11941, 0x11941, Start),
+	(0x11942, 0x11943, Continue),
+	(0x11950, 0x11959, Continue),
+	(0x119a0, 0x119a7, Start),
+	(0x119aa, 0x119d0, Start),
+	(0x119d1, 0x119d7, Continue),
+	(0x119da, 0x119e0, Continue),
+	(0x119e1, 0x119e1, Start),
+	(0x119e3, 0x119e3, Start),
+	(0x119e4, 0x119e4, Continue),
+	(0x11a00, 0x11a00, Start),
+	(0x11a01, 0x11a0a, Continue),
+	(0x11a0b, 0x11a32, Start),
+	(0x11a33, 0x11a39, Continue),
+	(0x11a3a, 0x11a3a, Start),
+	(0x11a3b, 0x11a3e, Continue),
+	(0x11a47, 0x11a47, Continue),
+	(0x11a50, 0x11a50, Start),
+	(0x11a51, 0x11a5b, Continue),
+	(0x11a5c, 0x11a89, Start),
+	(0x11a8a, 0x11a99, Continue),
+	(0x11a9d, 0x11a9d, Start),
+	(0x11ab0, 0x11af8, Start),
+	(0x11c00, 0x11c08, Start),
+	(0x11c0a, 0x11c2e, Start),
+	(0x11c2f, 0x11c36, Continue),
+	(0x11c38, 0x11c3f, Continue),
+	(0x11c40, 0x11c40, Start),
+	(0x11c50, 0x11c59, Continue),
+	(0x11c72, 0x11c8f, Start),
+	(0x11c92, 0x11ca7, Continue),
+	(0x11ca9, 0x11cb6, Continue),
+	(0x11d00, 0x11d06, Start),
+	(0x11d08, 0x11d09, Start),
+	(0x11d0b, 0x11d30, Start),
+	(0x11d31, 0x11d36, Continue),
+	(0x11d3a, 0x11d3a, Continue),
+	(0x11d3c, 0x11d3d, Continue),
+	(0x11d3f, 0x11d45, Continue),
+	(0x11d46, 0x11d46, Start),
+	(0x11d47, 0x11d47, Continue),
+	(0x11d50, 0x11d59, Continue),
+	(0x11d60, 0x11d65, Start),
+	(0x11d67, 0x11d68, Start),
+	(0x11d6a, 0x11d89, Start),
+	(0x11d8a, 0x11d8e, Continue),
+	(0x11d90, 0x11d91, Continue),
+	(0x11d93, 0x11d97, Continue),
+	(0x11d98, 0x11d98, Start),
+	(0x11da0, 0x11da9, Continue),
+	(0x11ee0, 0x11ef2, Start),
+	(0x11ef3, 0x11ef6, Continue),
+	(0x11fb0, 0x11fb0, Start),
+	(0x12000, 0x12399, Start),
+	(0x12400, 0x1246e, Start),
+	(0x12480, 0x12543, Start),
+	(0x12f90, 0x12ff0, Start),
+	(0x13000, 0x1342e, Start),
+	(0x14400, 0x14646, Start),
+	(0x16800, 0x16a38, Start),
+	(0x16a40, 0x16a5e, Start),
+	(0x16a60, 0x16a69, Continue),
+	(0x16a70, 0x16abe, Start),
+	(0x16ac0, 0x16ac9, Continue),
+	(0x16ad0, 0x16aed, Start),
+	(0x16af0, 0x16af4, Continue),
+	(0x16b00, 0x16b2f, Start),
+	(0x16b30, 0x16b36, Continue),
+	(0x16b40, 0x16b43, Start),
+	(0x16b50, 0x16b59, Continue),
+	(0x16b63, 0x16b77, Start),
+	(0x16b7d, 0x16b8f, Start),
+	(0x16e40, 0x16e7f, Start),
+	(0x16f00, 0x16f4a, Start),
+	(0x16f4f, 0x16f4f, Continue),
+	(0x16f50, 0x16f50, Start),
+	(0x16f51, 0x16f87, Continue),
+	(0x16f8f, 0x16f92, Continue),
+	(0x16f93, 0x16f9f, Start),
+	(0x16fe0, 0x16fe1, Start),
+	(0x16fe3, 0x16fe3, Start),
+	(0x16fe4, 0x16fe4, Continue),
+	(0x16ff0, 0x16ff1, Continue),
+	(0x17000, 0x187f7, Start),
+	(0x18800, 0x18cd5, Start),
+	(0x18d00, 0x18d08, Start),
+	(0x1aff0, 0x1aff3, Start),
+	(0x1aff5, 0x1affb, Start),
+	(0x1affd, 0x1affe, Start),
+	(0x1b000, 0x1b122, Start),
+	(0x1b150, 0x1b152, Start),
+	(0x1b164, 0x1b167, Start),
+	(0x1b170, 0x1b2fb, Start),
+	(0x1bc00, 0x1bc6a, Start),
+	(0x1bc70, 0x1bc7c, Start),
+	(0x1bc80, 0x1bc88, Start),
+	(0x1bc90, 0x1bc99, Start),
+	(0x1bc9d, 0x1bc9e, Continue),
+	(0x1cf00, 0x1cf2d, Continue),
+	(0x1cf30, 0x1cf46, Continue),
+	(0x1d165, 0x1d169, Continue),
+	(0x1d16d, 0x1d172, Continue),
+	(0x1d17b, 0x1d182, Continue),
+	(0x1d185, 0x1d18b, Continue),
+	(0x1d1aa, 0x1d1ad, Continue),
+	(0x1d242, 0x1d244, Continue),
+	(0x1d400, 0x1d454, Start),
+	(0x1d456, 0x1d49c, Start),
+	(0x1d49e, 0x1d49f, Start),
+	(0x1d4a2, 0x1d4a2, Start),
+	(0x1d4a5, 0x1d4a6, Start),
+	(0x1d4a9, 0x1d4ac, Start),
+	(0x1d4ae, 0x1d4b9, Start),
+	(0x1d4bb, 0x1d4bb, Start),
+	(0x1d4bd, 0x1d4c3, Start),
+	(0x1d4c5, 0x1d505, Start),
+	(0x1d507, 0x1d50a, Start),
+	(0x1d50d, 0x1d514, Start),
+	(0x1d516, 0x1d51c, Start),
+	(0x1d51e, 0x1d539, Start),
+	(0x1d53b, 0x1d53e, Start),
+	(0x1d540, 0x1d544, Start),
+	(0x1d546, 0x1d546, Start),
+	(0x1d54a, 0x1d550, Start),
+	(0x1d552, 0x1d6a5, Start),
+	(0x1d6a8, 0x1d6c0, Start),
+	(0x1d6c2, 0x1d6da, Start),
+	(0x1d6dc, 0x1d6fa, Start),
+	(0x1d6fc, 0x1d714, Start),
+	(0x1d716, 0x1d734, Start),
+	(0x1d736, 0x1d74e, Start),
+	(0x1d750, 0x1d76e, Start),
+	(0x1d770, 0x1d788, Start),
+	(0x1d78a, 0x1d7a8, Start),
+	(0x1d7aa, 0x1d7c2, Start),
+	(0x1d7c4, 0x1d7cb, Start),
+	(0x1d7ce, 0x1d7ff, Continue),
+	(0x1da00, 0x1da36, Continue),
+	(0x1da3b, 0x1da6c, Continue),
+	(0x1da75, 0x1da75, Continue),
+	(0x1da84, 0x1da84, Continue),
+	(0x1da9b, 0x1da9f, Continue),
+	(0x1daa1, 0x1daaf, Continue),
+	(0x1df00, 0x1df1e, Start),
+	(0x1e000, 0x1e006, Continue),
+	(0x1e008, 0x1e018, Continue),
+	(0x1e01b, 0x1e021, Continue),
+	(0x1e023, 0x1e024, Continue),
+	(0x1e026, 0x1e02a, Continue),
+	(0x1e100, 0x1e12c, Start),
+	(0x1e130, 0x1e136, Continue),
+	(0x1e137, 0x1e13d, Start),
+	(0x1e140, 0x1e149, Continue),
+	(0x1e14e, 0x1e14e, Start),
+	(0x1e290, 0x1e2ad, Start),
+	(0x1e2ae, 0x1e2ae, Continue),
+	(0x1e2c0, 0x1e2eb, Start),
+	(0x1e2ec, 0x1e2f9, Continue),
+	(0x1e7e0, 0x1e7e6, Start),
+	(0x1e7e8, 0x1e7eb, Start),
+	(0x1e7ed, 0x1e7ee, Start),
+	(0x1e7f0, 0x1e7fe, Start),
+	(0x1e800, 0x1e8c4, Start),
+	(0x1e8d0, 0x1e8d6, Continue),
+	(0x1e900, 0x1e943, Start),
+	(0x1e944, 0x1e94a, Continue),
+	(0x1e94b, 0x1e94b, Start),
+	(0x1e950, 0x1e959, Continue),
+	(0x1ee00, 0x1ee03, Start),
+	(0x1ee05, 0x1ee1f, Start),
+	(0x1ee21, 0x1ee22, Start),
+	(0x1ee24, 0x1ee24, Start),
+	(0x1ee27, 0x1ee27, Start),
+	(0x1ee29, 0x1ee32, Start),
+	(0x1ee34, 0x1ee37, Start),
+	(0x1ee39, 0x1ee39, Start),
+	(0x1ee3b, 0x1ee3b, Start),
+	(0x1ee42, 0x1ee42, Start),
+	(0x1ee47, 0x1ee47, Start),
+	(0x1ee49, 0x1ee49, Start),
+	(0x1ee4b, 0x1ee4b, Start),
+	(0x1ee4d, 0x1ee4f, Start),
+	(0x1ee51, 0x1ee52, Start),
+	(0x1ee54, 0x1ee54, Start),
+	(0x1ee57, 0x1ee57, Start),
+	(0x1ee59, 0x1ee59, Start),
+	(0x1ee5b, 0x1ee5b, Start),
+	(0x1ee5d, 0x1ee5d, Start),
+	(0x1ee5f, 0x1ee5f, Start),
+	(0x1ee61, 0x1ee62, Start),
+	(0x1ee64, 0x1ee64, Start),
+	(0x1ee67, 0x1ee6a, Start),
+	(0x1ee6c, 0x1ee72, Start),
+	(0x1ee74, 0x1ee77, Start),
+	(0x1ee79, 0x1ee7c, Start),
+	(0x1ee7e, 0x1ee7e, Start),
+	(0x1ee80, 0x1ee89, Start),
+	(0x1ee8b, 0x1ee9b, Start),
+	(0x1eea1, 0x1eea3, Start),
+	(0x1eea5, 0x1eea9, Start),
+	(0x1eeab, 0x1eebb, Start),
+	(0x1fbf0, 0x1fbf9, Continue),
+	(0x20000, 0x2a6df, Start),
+	(0x2a700, 0x2b738, Start),
+	(0x2b740, 0x2b81d, Start),
+	(0x2b820, 0x2cea1, Start),
+	(0x2ceb0, 0x2ebe0, Start),
+	(0x2f800, 0x2fa1d, Start),
+	(0x30000, 0x3134a, Start),
+	(0xe0100, 0xe01ef, Continue),
+];
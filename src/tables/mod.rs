@@ -0,0 +1,98 @@
+//! Unicode property tables.
+//!
+//! The tables are generated by `scripts/unicode.py`.
+
+use std::cmp::Ordering;
+
+mod format;
+mod complex_rendering;
+mod case_folding;
+mod emoji;
+mod combining_mark;
+mod grapheme_break;
+
+pub(crate) use format::FORMAT;
+pub(crate) use complex_rendering::COMPLEX_RENDERING;
+pub(crate) use case_folding::CASE_FOLDING;
+pub(crate) use emoji::EMOJI;
+pub(crate) use combining_mark::COMBINING_MARK;
+pub(crate) use grapheme_break::GRAPHEME_BREAK;
+
+#[cfg(feature = "width")]
+mod east_asian_width;
+
+#[cfg(feature = "width")]
+pub(crate) use east_asian_width::EAST_ASIAN_WIDTH;
+
+#[cfg(feature = "age")]
+mod age;
+
+#[cfg(feature = "age")]
+pub(crate) use age::AGE;
+
+#[cfg(feature = "names")]
+mod names;
+
+#[cfg(feature = "names")]
+pub(crate) use names::{ALGORITHMIC_NAMES, NAMES};
+
+#[cfg(feature = "ident")]
+mod ident;
+
+#[cfg(feature = "ident")]
+pub(crate) use ident::IDENT;
+
+#[cfg(feature = "numeric")]
+mod numeric;
+
+#[cfg(feature = "numeric")]
+pub(crate) use numeric::NUMERIC;
+
+#[cfg(feature = "normalization")]
+mod canonical_decomposition;
+
+#[cfg(feature = "normalization")]
+mod combining_class;
+
+#[cfg(feature = "normalization")]
+pub(crate) use canonical_decomposition::CANONICAL_DECOMPOSITION;
+
+#[cfg(feature = "normalization")]
+pub(crate) use combining_class::COMBINING_CLASS;
+
+#[cfg(feature = "bidi")]
+mod strong_rtl;
+
+#[cfg(feature = "bidi")]
+pub(crate) use strong_rtl::STRONG_RTL;
+
+/// Find the value associated to the given character in a table of sorted, non-overlapping
+/// `(first, last, value)` code point ranges.
+#[allow(dead_code)]
+pub(crate) fn lookup<T: Copy>(table: &[(u32, u32, T)], c: char) -> Option<T> {
+	let c = c as u32;
+	table.binary_search_by(|&(first, last, _)| {
+		if last < c {
+			Ordering::Less
+		} else if first > c {
+			Ordering::Greater
+		} else {
+			Ordering::Equal
+		}
+	}).ok().map(|i| table[i].2)
+}
+
+/// Checks if the given character is in a table of sorted, non-overlapping `(first, last)` code
+/// point ranges.
+pub(crate) fn contains(table: &[(u32, u32)], c: char) -> bool {
+	let c = c as u32;
+	table.binary_search_by(|&(first, last)| {
+		if last < c {
+			Ordering::Less
+		} else if first > c {
+			Ordering::Greater
+		} else {
+			Ordering::Equal
+		}
+	}).is_ok()
+}
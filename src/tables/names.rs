@@ -0,0 +1,7008 @@
+// This file is generated by `scripts/unicode.py` from the Unicode Character Database,
+// version 14.0.0. Do not edit it manually.
+
+/// Code point ranges whose names are derived by appending the code point
+/// hexadecimal value to a prefix.
+pub(crate) const ALGORITHMIC_NAMES: &[(u32, u32, &str)] = &[
+	(0x3400, 0x4dbf, "CJK UNIFIED IDEOGRAPH-"),
+	(0x4e00, 0x9fff, "CJK UNIFIED IDEOGRAPH-"),
+	(0xf900, 0xfa6d, "CJK COMPATIBILITY IDEOGRAPH-"),
+	(0xfa70, 0xfad9, "CJK COMPATIBILITY IDEOGRAPH-"),
+	(0x18b00, 0x18cd5, "KHITAN SMALL SCRIPT CHARACTER-"),
+	(0x1b170, 0x1b2fb, "NUSHU CHARACTER-"),
+	(0x20000, 0x2a6df, "CJK UNIFIED IDEOGRAPH-"),
+	(0x2a700, 0x2b738, "CJK UNIFIED IDEOGRAPH-"),
+	(0x2b740, 0x2b81d, "CJK UNIFIED IDEOGRAPH-"),
+	(0x2b820, 0x2cea1, "CJK UNIFIED IDEOGRAPH-"),
+	(0x2ceb0, 0x2ebe0, "CJK UNIFIED IDEOGRAPH-"),
+	(0x2f800, 0x2fa1d, "CJK COMPATIBILITY IDEOGRAPH-"),
+	(0x30000, 0x3134a, "CJK UNIFIED IDEOGRAPH-"),
+];
+
+/// Names of the characters of the covered blocks.
+pub(crate) const NAMES: &[(u32, &str)] = &[
+	(0x20, "SPACE"),
+	(0x21, "EXCLAMATION MARK"),
+	(0x22, "QUOTATION MARK"),
+	(0x23, "NUMBER SIGN"),
+	(0x24, "DOLLAR SIGN"),
+	(0x25, "PERCENT SIGN"),
+	(0x26, "AMPERSAND"),
+	(0x27, "APOSTROPHE"),
+	(0x28, "LEFT PARENTHESIS"),
+	(0x29, "RIGHT PARENTHESIS"),
+	(0x2a, "ASTERISK"),
+	(0x2b, "PLUS SIGN"),
+	(0x2c, "COMMA"),
+	(0x2d, "HYPHEN-MINUS"),
+	(0x2e, "FULL STOP"),
+	(0x2f, "SOLIDUS"),
+	(0x30, "DIGIT ZERO"),
+	(0x31, "DIGIT ONE"),
+	(0x32, "DIGIT TWO"),
+	(0x33, "DIGIT THREE"),
+	(0x34, "DIGIT FOUR"),
+	(0x35, "DIGIT FIVE"),
+	(0x36, "DIGIT SIX"),
+	(0x37, "DIGIT SEVEN"),
+	(0x38, "DIGIT EIGHT"),
+	(0x39, "DIGIT NINE"),
+	(0x3a, "COLON"),
+	(0x3b, "SEMICOLON"),
+	(0x3c, "LESS-THAN SIGN"),
+	(0x3d, "EQUALS SIGN"),
+	(0x3e, "GREATER-THAN SIGN"),
+	(0x3f, "QUESTION MARK"),
+	(0x40, "COMMERCIAL AT"),
+	(0x41, "LATIN CAPITAL LETTER A"),
+	(0x42, "LATIN CAPITAL LETTER B"),
+	(0x43, "LATIN CAPITAL LETTER C"),
+	(0x44, "LATIN CAPITAL LETTER D"),
+	(0x45, "LATIN CAPITAL LETTER E"),
+	(0x46, "LATIN CAPITAL LETTER F"),
+	(0x47, "LATIN CAPITAL LETTER G"),
+	(0x48, "LATIN CAPITAL LETTER H"),
+	(0x49, "LATIN CAPITAL LETTER I"),
+	(0x4a, "LATIN CAPITAL LETTER J"),
+	(0x4b, "LATIN CAPITAL LETTER K"),
+	(0x4c, "LATIN CAPITAL LETTER L"),
+	(0x4d, "LATIN CAPITAL LETTER M"),
+	(0x4e, "LATIN CAPITAL LETTER N"),
+	(0x4f, "LATIN CAPITAL LETTER O"),
+	(0x50, "LATIN CAPITAL LETTER P"),
+	(0x51, "LATIN CAPITAL LETTER Q"),
+	(0x52, "LATIN CAPITAL LETTER R"),
+	(0x53, "LATIN CAPITAL LETTER S"),
+	(0x54, "LATIN CAPITAL LETTER T"),
+	(0x55, "LATIN CAPITAL LETTER U"),
+	(0x56, "LATIN CAPITAL LETTER V"),
+	(0x57, "LATIN CAPITAL LETTER W"),
+	(0x58, "LATIN CAPITAL LETTER X"),
+	(0x59, "LATIN CAPITAL LETTER Y"),
+	(0x5a, "LATIN CAPITAL LETTER Z"),
+	(0x5b, "LEFT SQUARE BRACKET"),
+	(0x5c, "REVERSE SOLIDUS"),
+	(0x5d, "RIGHT SQUARE BRACKET"),
+	(0x5e, "CIRCUMFLEX ACCENT"),
+	(0x5f, "LOW LINE"),
+	(0x60, "GRAVE ACCENT"),
+	(0x61, "LATIN SMALL LETTER A"),
+	(0x62, "LATIN SMALL LETTER B"),
+	(0x63, "LATIN SMALL LETTER C"),
+	(0x64, "LATIN SMALL LETTER D"),
+	(0x65, "LATIN SMALL LETTER E"),
+	(0x66, "LATIN SMALL LETTER F"),
+	(0x67, "LATIN SMALL LETTER G"),
+	(0x68, "LATIN SMALL LETTER H"),
+	(0x69, "LATIN SMALL LETTER I"),
+	(0x6a, "LATIN SMALL LETTER J"),
+	(0x6b, "LATIN SMALL LETTER K"),
+	(0x6c, "LATIN SMALL LETTER L"),
+	(0x6d, "LATIN SMALL LETTER M"),
+	(0x6e, "LATIN SMALL LETTER N"),
+	(0x6f, "LATIN SMALL LETTER O"),
+	(0x70, "LATIN SMALL LETTER P"),
+	(0x71, "LATIN SMALL LETTER Q"),
+	(0x72, "LATIN SMALL LETTER R"),
+	(0x73, "LATIN SMALL LETTER S"),
+	(0x74, "LATIN SMALL LETTER T"),
+	(0x75, "LATIN SMALL LETTER U"),
+	(0x76, "LATIN SMALL LETTER V"),
+	(0x77, "LATIN SMALL LETTER W"),
+	(0x78, "LATIN SMALL LETTER X"),
+	(0x79, "LATIN SMALL LETTER Y"),
+	(0x7a, "LATIN SMALL LETTER Z"),
+	(0x7b, "LEFT CURLY BRACKET"),
+	(0x7c, "VERTICAL LINE"),
+	(0x7d, "RIGHT CURLY BRACKET"),
+	(0x7e, "TILDE"),
+	(0xa0, "NO-BREAK SPACE"),
+	(0xa1, "INVERTED EXCLAMATION MARK"),
+	(0xa2, "CENT SIGN"),
+	(0xa3, "POUND SIGN"),
+	(0xa4, "CURRENCY SIGN"),
+	(0xa5, "YEN SIGN"),
+	(0xa6, "BROKEN BAR"),
+	(0xa7, "SECTION SIGN"),
+	(0xa8, "DIAERESIS"),
+	(0xa9, "COPYRIGHT SIGN"),
+	(0xaa, "FEMININE ORDINAL INDICATOR"),
+	(0xab, "LEFT-POINTING DOUBLE ANGLE QUOTATION MARK"),
+	(0xac, "NOT SIGN"),
+	(0xad, "SOFT HYPHEN"),
+	(0xae, "REGISTERED SIGN"),
+	(0xaf, "MACRON"),
+	(0xb0, "DEGREE SIGN"),
+	(0xb1, "PLUS-MINUS SIGN"),
+	(0xb2, "SUPERSCRIPT TWO"),
+	(0xb3, "SUPERSCRIPT THREE"),
+	(0xb4, "ACUTE ACCENT"),
+	(0xb5, "MICRO SIGN"),
+	(0xb6, "PILCROW SIGN"),
+	(0xb7, "MIDDLE DOT"),
+	(0xb8, "CEDILLA"),
+	(0xb9, "SUPERSCRIPT ONE"),
+	(0xba, "MASCULINE ORDINAL INDICATOR"),
+	(0xbb, "RIGHT-POINTING DOUBLE ANGLE QUOTATION MARK"),
+	(0xbc, "VULGAR FRACTION ONE QUARTER"),
+	(0xbd, "VULGAR FRACTION ONE HALF"),
+	(0xbe, "VULGAR FRACTION THREE QUARTERS"),
+	(0xbf, "INVERTED QUESTION MARK"),
+	(0xc0, "LATIN CAPITAL LETTER A WITH GRAVE"),
+	(0xc1, "LATIN CAPITAL LETTER A WITH ACUTE"),
+	(0xc2, "LATIN CAPITAL LETTER A WITH CIRCUMFLEX"),
+	(0xc3, "LATIN CAPITAL LETTER A WITH TILDE"),
+	(0xc4, "LATIN CAPITAL LETTER A WITH DIAERESIS"),
+	(0xc5, "LATIN CAPITAL LETTER A WITH RING ABOVE"),
+	(0xc6, "LATIN CAPITAL LETTER AE"),
+	(0xc7, "LATIN CAPITAL LETTER C WITH CEDILLA"),
+	(0xc8, "LATIN CAPITAL LETTER E WITH GRAVE"),
+	(0xc9, "LATIN CAPITAL LETTER E WITH ACUTE"),
+	(0xca, "LATIN CAPITAL LETTER E WITH CIRCUMFLEX"),
+	(0xcb, "LATIN CAPITAL LETTER E WITH DIAERESIS"),
+	(0xcc, "LATIN CAPITAL LETTER I WITH GRAVE"),
+	(0xcd, "LATIN CAPITAL LETTER I WITH ACUTE"),
+	(0xce, "LATIN CAPITAL LETTER I WITH CIRCUMFLEX"),
+	(0xcf, "LATIN CAPITAL LETTER I WITH DIAERESIS"),
+	(0xd0, "LATIN CAPITAL LETTER ETH"),
+	(0xd1, "LATIN CAPITAL LETTER N WITH TILDE"),
+	(0xd2, "LATIN CAPITAL LETTER O WITH GRAVE"),
+	(0xd3, "LATIN CAPITAL LETTER O WITH ACUTE"),
+	(0xd4, "LATIN CAPITAL LETTER O WITH CIRCUMFLEX"),
+	(0xd5, "LATIN CAPITAL LETTER O WITH TILDE"),
+	(0xd6, "LATIN CAPITAL LETTER O WITH DIAERESIS"),
+	(0xd7, "MULTIPLICATION SIGN"),
+	(0xd8, "LATIN CAPITAL LETTER O WITH STROKE"),
+	(0xd9, "LATIN CAPITAL LETTER U WITH GRAVE"),
+	(0xda, "LATIN CAPITAL LETTER U WITH ACUTE"),
+	(0xdb, "LATIN CAPITAL LETTER U WITH CIRCUMFLEX"),
+	(0xdc, "LATIN CAPITAL LETTER U WITH DIAERESIS"),
+	(0xdd, "LATIN CAPITAL LETTER Y WITH ACUTE"),
+	(0xde, "LATIN CAPITAL LETTER THORN"),
+	(0xdf, "LATIN SMALL LETTER SHARP S"),
+	(0xe0, "LATIN SMALL LETTER A WITH GRAVE"),
+	(0xe1, "LATIN SMALL LETTER A WITH ACUTE"),
+	(0xe2, "LATIN SMALL LETTER A WITH CIRCUMFLEX"),
+	(0xe3, "LATIN SMALL LETTER A WITH TILDE"),
+	(0xe4, "LATIN SMALL LETTER A WITH DIAERESIS"),
+	(0xe5, "LATIN SMALL LETTER A WITH RING ABOVE"),
+	(0xe6, "LATIN SMALL LETTER AE"),
+	(0xe7, "LATIN SMALL LETTER C WITH CEDILLA"),
+	(0xe8, "LATIN SMALL LETTER E WITH GRAVE"),
+	(0xe9, "LATIN SMALL LETTER E WITH ACUTE"),
+	(0xea, "LATIN SMALL LETTER E WITH CIRCUMFLEX"),
+	(0xeb, "LATIN SMALL LETTER E WITH DIAERESIS"),
+	(0xec, "LATIN SMALL LETTER I WITH GRAVE"),
+	(0xed, "LATIN SMALL LETTER I WITH ACUTE"),
+	(0xee, "LATIN SMALL LETTER I WITH CIRCUMFLEX"),
+	(0xef, "LATIN SMALL LETTER I WITH DIAERESIS"),
+	(0xf0, "LATIN SMALL LETTER ETH"),
+	(0xf1, "LATIN SMALL LETTER N WITH TILDE"),
+	(0xf2, "LATIN SMALL LETTER O WITH GRAVE"),
+	(0xf3, "LATIN SMALL LETTER O WITH ACUTE"),
+	(0xf4, "LATIN SMALL LETTER O WITH CIRCUMFLEX"),
+	(0xf5, "LATIN SMALL LETTER O WITH TILDE"),
+	(0xf6, "LATIN SMALL LETTER O WITH DIAERESIS"),
+	(0xf7, "DIVISION SIGN"),
+	(0xf8, "LATIN SMALL LETTER O WITH STROKE"),
+	(0xf9, "LATIN SMALL LETTER U WITH GRAVE"),
+	(0xfa, "LATIN SMALL LETTER U WITH ACUTE"),
+	(0xfb, "LATIN SMALL LETTER U WITH CIRCUMFLEX"),
+	(0xfc, "LATIN SMALL LETTER U WITH DIAERESIS"),
+	(0xfd, "LATIN SMALL LETTER Y WITH ACUTE"),
+	(0xfe, "LATIN SMALL LETTER THORN"),
+	(0xff, "LATIN SMALL LETTER Y WITH DIAERESIS"),
+	(0x100, "LATIN CAPITAL LETTER A WITH MACRON"),
+	(0x101, "LATIN SMALL LETTER A WITH MACRON"),
+	(0x102, "LATIN CAPITAL LETTER A WITH BREVE"),
+	(0x103, "LATIN SMALL LETTER A WITH BREVE"),
+	(0x104, "LATIN CAPITAL LETTER A WITH OGONEK"),
+	(0x105, "LATIN SMALL LETTER A WITH OGONEK"),
+	(0x106, "LATIN CAPITAL LETTER C WITH ACUTE"),
+	(0x107, "LATIN SMALL LETTER C WITH ACUTE"),
+	(0x108, "LATIN CAPITAL LETTER C WITH CIRCUMFLEX"),
+	(0x109, "LATIN SMALL LETTER C WITH CIRCUMFLEX"),
+	(0x10a, "LATIN CAPITAL LETTER C WITH DOT ABOVE"),
+	(0x10b, "LATIN SMALL LETTER C WITH DOT ABOVE"),
+	(0x10c, "LATIN CAPITAL LETTER C WITH CARON"),
+	(0x10d, "LATIN SMALL LETTER C WITH CARON"),
+	(0x10e, "LATIN CAPITAL LETTER D WITH CARON"),
+	(0x10f, "LATIN SMALL LETTER D WITH CARON"),
+	(0x110, "LATIN CAPITAL LETTER D WITH STROKE"),
+	(0x111, "LATIN SMALL LETTER D WITH STROKE"),
+	(0x112, "LATIN CAPITAL LETTER E WITH MACRON"),
+	(0x113, "LATIN SMALL LETTER E WITH MACRON"),
+	(0x114, "LATIN CAPITAL LETTER E WITH BREVE"),
+	(0x115, "LATIN SMALL LETTER E WITH BREVE"),
+	(0x116, "LATIN CAPITAL LETTER E WITH DOT ABOVE"),
+	(0x117, "LATIN SMALL LETTER E WITH DOT ABOVE"),
+	(0x118, "LATIN CAPITAL LETTER E WITH OGONEK"),
+	(0x119, "LATIN SMALL LETTER E WITH OGONEK"),
+	(0x11a, "LATIN CAPITAL LETTER E WITH CARON"),
+	(0x11b, "LATIN SMALL LETTER E WITH CARON"),
+	(0x11c, "LATIN CAPITAL LETTER G WITH CIRCUMFLEX"),
+	(0x11d, "LATIN SMALL LETTER G WITH CIRCUMFLEX"),
+	(0x11e, "LATIN CAPITAL LETTER G WITH BREVE"),
+	(0x11f, "LATIN SMALL LETTER G WITH BREVE"),
+	(0x120, "LATIN CAPITAL LETTER G WITH DOT ABOVE"),
+	(0x121, "LATIN SMALL LETTER G WITH DOT ABOVE"),
+	(0x122, "LATIN CAPITAL LETTER G WITH CEDILLA"),
+	(0x123, "LATIN SMALL LETTER G WITH CEDILLA"),
+	(0x124, "LATIN CAPITAL LETTER H WITH CIRCUMFLEX"),
+	(0x125, "LATIN SMALL LETTER H WITH CIRCUMFLEX"),
+	(0x126, "LATIN CAPITAL LETTER H WITH STROKE"),
+	(0x127, "LATIN SMALL LETTER H WITH STROKE"),
+	(0x128, "LATIN CAPITAL LETTER I WITH TILDE"),
+	(0x129, "LATIN SMALL LETTER I WITH TILDE"),
+	(0x12a, "LATIN CAPITAL LETTER I WITH MACRON"),
+	(0x12b, "LATIN SMALL LETTER I WITH MACRON"),
+	(0x12c, "LATIN CAPITAL LETTER I WITH BREVE"),
+	(0x12d, "LATIN SMALL LETTER I WITH BREVE"),
+	(0x12e, "LATIN CAPITAL LETTER I WITH OGONEK"),
+	(0x12f, "LATIN SMALL LETTER I WITH OGONEK"),
+	(0x130, "LATIN CAPITAL LETTER I WITH DOT ABOVE"),
+	(0x131, "LATIN SMALL LETTER DOTLESS I"),
+	(0x132, "LATIN CAPITAL LIGATURE IJ"),
+	(0x133, "LATIN SMALL LIGATURE IJ"),
+	(0x134, "LATIN CAPITAL LETTER J WITH CIRCUMFLEX"),
+	(0x135, "LATIN SMALL LETTER J WITH CIRCUMFLEX"),
+	(0x136, "LATIN CAPITAL LETTER K WITH CEDILLA"),
+	(0x137, "LATIN SMALL LETTER K WITH CEDILLA"),
+	(0x138, "LATIN SMALL LETTER KRA"),
+	(0x139, "LATIN CAPITAL LETTER L WITH ACUTE"),
+	(0x13a, "LATIN SMALL LETTER L WITH ACUTE"),
+	(0x13b, "LATIN CAPITAL LETTER L WITH CEDILLA"),
+	(0x13c, "LATIN SMALL LETTER L WITH CEDILLA"),
+	(0x13d, "LATIN CAPITAL LETTER L WITH CARON"),
+	(0x13e, "LATIN SMALL LETTER L WITH CARON"),
+	(0x13f, "LATIN CAPITAL LETTER L WITH MIDDLE DOT"),
+	(0x140, "LATIN SMALL LETTER L WITH MIDDLE DOT"),
+	(0x141, "LATIN CAPITAL LETTER L WITH STROKE"),
+	(0x142, "LATIN SMALL LETTER L WITH STROKE"),
+	(0x143, "LATIN CAPITAL LETTER N WITH ACUTE"),
+	(0x144, "LATIN SMALL LETTER N WITH ACUTE"),
+	(0x145, "LATIN CAPITAL LETTER N WITH CEDILLA"),
+	(0x146, "LATIN SMALL LETTER N WITH CEDILLA"),
+	(0x147, "LATIN CAPITAL LETTER N WITH CARON"),
+	(0x148, "LATIN SMALL LETTER N WITH CARON"),
+	(0x149, "LATIN SMALL LETTER N PRECEDED BY APOSTROPHE"),
+	(0x14a, "LATIN CAPITAL LETTER ENG"),
+	(0x14b, "LATIN SMALL LETTER ENG"),
+	(0x14c, "LATIN CAPITAL LETTER O WITH MACRON"),
+	(0x14d, "LATIN SMALL LETTER O WITH MACRON"),
+	(0x14e, "LATIN CAPITAL LETTER O WITH BREVE"),
+	(0x14f, "LATIN SMALL LETTER O WITH BREVE"),
+	(0x150, "LATIN CAPITAL LETTER O WITH DOUBLE ACUTE"),
+	(0x151, "LATIN SMALL LETTER O WITH DOUBLE ACUTE"),
+	(0x152, "LATIN CAPITAL LIGATURE OE"),
+	(0x153, "LATIN SMALL LIGATURE OE"),
+	(0x154, "LATIN CAPITAL LETTER R WITH ACUTE"),
+	(0x155, "LATIN SMALL LETTER R WITH ACUTE"),
+	(0x156, "LATIN CAPITAL LETTER R WITH CEDILLA"),
+	(0x157, "LATIN SMALL LETTER R WITH CEDILLA"),
+	(0x158, "LATIN CAPITAL LETTER R WITH CARON"),
+	(0x159, "LATIN SMALL LETTER R WITH CARON"),
+	(0x15a, "LATIN CAPITAL LETTER S WITH ACUTE"),
+	(0x15b, "LATIN SMALL LETTER S WITH ACUTE"),
+	(0x15c, "LATIN CAPITAL LETTER S WITH CIRCUMFLEX"),
+	(0x15d, "LATIN SMALL LETTER S WITH CIRCUMFLEX"),
+	(0x15e, "LATIN CAPITAL LETTER S WITH CEDILLA"),
+	(0x15f, "LATIN SMALL LETTER S WITH CEDILLA"),
+	(0x160, "LATIN CAPITAL LETTER S WITH CARON"),
+	(0x161, "LATIN SMALL LETTER S WITH CARON"),
+	(0x162, "LATIN CAPITAL LETTER T WITH CEDILLA"),
+	(0x163, "LATIN SMALL LETTER T WITH CEDILLA"),
+	(0x164, "LATIN CAPITAL LETTER T WITH CARON"),
+	(0x165, "LATIN SMALL LETTER T WITH CARON"),
+	(0x166, "LATIN CAPITAL LETTER T WITH STROKE"),
+	(0x167, "LATIN SMALL LETTER T WITH STROKE"),
+	(0x168, "LATIN CAPITAL LETTER U WITH TILDE"),
+	(0x169, "LATIN SMALL LETTER U WITH TILDE"),
+	(0x16a, "LATIN CAPITAL LETTER U WITH MACRON"),
+	(0x16b, "LATIN SMALL LETTER U WITH MACRON"),
+	(0x16c, "LATIN CAPITAL LETTER U WITH BREVE"),
+	(0x16d, "LATIN SMALL LETTER U WITH BREVE"),
+	(0x16e, "LATIN CAPITAL LETTER U WITH RING ABOVE"),
+	(0x16f, "LATIN SMALL LETTER U WITH RING ABOVE"),
+	(0x170, "LATIN CAPITAL LETTER U WITH DOUBLE ACUTE"),
+	(0x171, "LATIN SMALL LETTER U WITH DOUBLE ACUTE"),
+	(0x172, "LATIN CAPITAL LETTER U WITH OGONEK"),
+	(0x173, "LATIN SMALL LETTER U WITH OGONEK"),
+	(0x174, "LATIN CAPITAL LETTER W WITH CIRCUMFLEX"),
+	(0x175, "LATIN SMALL LETTER W WITH CIRCUMFLEX"),
+	(0x176, "LATIN CAPITAL LETTER Y WITH CIRCUMFLEX"),
+	(0x177, "LATIN SMALL LETTER Y WITH CIRCUMFLEX"),
+	(0x178, "LATIN CAPITAL LETTER Y WITH DIAERESIS"),
+	(0x179, "LATIN CAPITAL LETTER Z WITH ACUTE"),
+	(0x17a, "LATIN SMALL LETTER Z WITH ACUTE"),
+	(0x17b, "LATIN CAPITAL LETTER Z WITH DOT ABOVE"),
+	(0x17c, "LATIN SMALL LETTER Z WITH DOT ABOVE"),
+	(0x17d, "LATIN CAPITAL LETTER Z WITH CARON"),
+	(0x17e, "LATIN SMALL LETTER Z WITH CARON"),
+	(0x17f, "LATIN SMALL LETTER LONG S"),
+	(0x180, "LATIN SMALL LETTER B WITH STROKE"),
+	(0x181, "LATIN CAPITAL LETTER B WITH HOOK"),
+	(0x182, "LATIN CAPITAL LETTER B WITH TOPBAR"),
+	(0x183, "LATIN SMALL LETTER B WITH TOPBAR"),
+	(0x184, "LATIN CAPITAL LETTER TONE SIX"),
+	(0x185, "LATIN SMALL LETTER TONE SIX"),
+	(0x186, "LATIN CAPITAL LETTER OPEN O"),
+	(0x187, "LATIN CAPITAL LETTER C WITH HOOK"),
+	(0x188, "LATIN SMALL LETTER C WITH HOOK"),
+	(0x189, "LATIN CAPITAL LETTER AFRICAN D"),
+	(0x18a, "LATIN CAPITAL LETTER D WITH HOOK"),
+	(0x18b, "LATIN CAPITAL LETTER D WITH TOPBAR"),
+	(0x18c, "LATIN SMALL LETTER D WITH TOPBAR"),
+	(0x18d, "LATIN SMALL LETTER TURNED DELTA"),
+	(0x18e, "LATIN CAPITAL LETTER REVERSED E"),
+	(0x18f, "LATIN CAPITAL LETTER SCHWA"),
+	(0x190, "LATIN CAPITAL LETTER OPEN E"),
+	(0x191, "LATIN CAPITAL LETTER F WITH HOOK"),
+	(0x192, "LATIN SMALL LETTER F WITH HOOK"),
+	(0x193, "LATIN CAPITAL LETTER G WITH HOOK"),
+	(0x194, "LATIN CAPITAL LETTER GAMMA"),
+	(0x195, "LATIN SMALL LETTER HV"),
+	(0x196, "LATIN CAPITAL LETTER IOTA"),
+	(0x197, "LATIN CAPITAL LETTER I WITH STROKE"),
+	(0x198, "LATIN CAPITAL LETTER K WITH HOOK"),
+	(0x199, "LATIN SMALL LETTER K WITH HOOK"),
+	(0x19a, "LATIN SMALL LETTER L WITH BAR"),
+	(0x19b, "LATIN SMALL LETTER LAMBDA WITH STROKE"),
+	(0x19c, "LATIN CAPITAL LETTER TURNED M"),
+	(0x19d, "LATIN CAPITAL LETTER N WITH LEFT HOOK"),
+	(0x19e, "LATIN SMALL LETTER N WITH LONG RIGHT LEG"),
+	(0x19f, "LATIN CAPITAL LETTER O WITH MIDDLE TILDE"),
+	(0x1a0, "LATIN CAPITAL LETTER O WITH HORN"),
+	(0x1a1, "LATIN SMALL LETTER O WITH HORN"),
+	(0x1a2, "LATIN CAPITAL LETTER OI"),
+	(0x1a3, "LATIN SMALL LETTER OI"),
+	(0x1a4, "LATIN CAPITAL LETTER P WITH HOOK"),
+	(0x1a5, "LATIN SMALL LETTER P WITH HOOK"),
+	(0x1a6, "LATIN LETTER YR"),
+	(0x1a7, "LATIN CAPITAL LETTER TONE TWO"),
+	(0x1a8, "LATIN SMALL LETTER TONE TWO"),
+	(0x1a9, "LATIN CAPITAL LETTER ESH"),
+	(0x1aa, "LATIN LETTER REVERSED ESH LOOP"),
+	(0x1ab, "LATIN SMALL LETTER T WITH PALATAL HOOK"),
+	(0x1ac, "LATIN CAPITAL LETTER T WITH HOOK"),
+	(0x1ad, "LATIN SMALL LETTER T WITH HOOK"),
+	(0x1ae, "LATIN CAPITAL LETTER T WITH RETROFLEX HOOK"),
+	(0x1af, "LATIN CAPITAL LETTER U WITH HORN"),
+	(0x1b0, "LATIN SMALL LETTER U WITH HORN"),
+	(0x1b1, "LATIN CAPITAL LETTER UPSILON"),
+	(0x1b2, "LATIN CAPITAL LETTER V WITH HOOK"),
+	(0x1b3, "LATIN CAPITAL LETTER Y WITH HOOK"),
+	(0x1b4, "LATIN SMALL LETTER Y WITH HOOK"),
+	(0x1b5, "LATIN CAPITAL LETTER Z WITH STROKE"),
+	(0x1b6, "LATIN SMALL LETTER Z WITH STROKE"),
+	(0x1b7, "LATIN CAPITAL LETTER EZH"),
+	(0x1b8, "LATIN CAPITAL LETTER EZH REVERSED"),
+	(0x1b9, "LATIN SMALL LETTER EZH REVERSED"),
+	(0x1ba, "LATIN SMALL LETTER EZH WITH TAIL"),
+	(0x1bb, "LATIN LETTER TWO WITH STROKE"),
+	(0x1bc, "LATIN CAPITAL LETTER TONE FIVE"),
+	(0x1bd, "LATIN SMALL LETTER TONE FIVE"),
+	(0x1be, "LATIN LETTER INVERTED GLOTTAL STOP WITH STROKE"),
+	(0x1bf, "LATIN LETTER WYNN"),
+	(0x1c0, "LATIN LETTER DENTAL CLICK"),
+	(0x1c1, "LATIN LETTER LATERAL CLICK"),
+	(0x1c2, "LATIN LETTER ALVEOLAR CLICK"),
+	(0x1c3, "LATIN LETTER RETROFLEX CLICK"),
+	(0x1c4, "LATIN CAPITAL LETTER DZ WITH CARON"),
+	(0x1c5, "LATIN CAPITAL LETTER D WITH SMALL LETTER Z WITH CARON"),
+	(0x1c6, "LATIN SMALL LETTER DZ WITH CARON"),
+	(0x1c7, "LATIN CAPITAL LETTER LJ"),
+	(0x1c8, "LATIN CAPITAL LETTER L WITH SMALL LETTER J"),
+	(0x1c9, "LATIN SMALL LETTER LJ"),
+	(0x1ca, "LATIN CAPITAL LETTER NJ"),
+	(0x1cb, "LATIN CAPITAL LETTER N WITH SMALL LETTER J"),
+	(0x1cc, "LATIN SMALL LETTER NJ"),
+	(0x1cd, "LATIN CAPITAL LETTER A WITH CARON"),
+	(0x1ce, "LATIN SMALL LETTER A WITH CARON"),
+	(0x1cf, "LATIN CAPITAL LETTER I WITH CARON"),
+	(0x1d0, "LATIN SMALL LETTER I WITH CARON"),
+	(0x1d1, "LATIN CAPITAL LETTER O WITH CARON"),
+	(0x1d2, "LATIN SMALL LETTER O WITH CARON"),
+	(0x1d3, "LATIN CAPITAL LETTER U WITH CARON"),
+	(0x1d4, "LATIN SMALL LETTER U WITH CARON"),
+	(0x1d5, "LATIN CAPITAL LETTER U WITH DIAERESIS AND MACRON"),
+	(0x1d6, "LATIN SMALL LETTER U WITH DIAERESIS AND MACRON"),
+	(0x1d7, "LATIN CAPITAL LETTER U WITH DIAERESIS AND ACUTE"),
+	(0x1d8, "LATIN SMALL LETTER U WITH DIAERESIS AND ACUTE"),
+	(0x1d9, "LATIN CAPITAL LETTER U WITH DIAERESIS AND CARON"),
+	(0x1da, "LATIN SMALL LETTER U WITH DIAERESIS AND CARON"),
+	(0x1db, "LATIN CAPITAL LETTER U WITH DIAERESIS AND GRAVE"),
+	(0x1dc, "LATIN SMALL LETTER U WITH DIAERESIS AND GRAVE"),
+	(0x1dd, "LATIN SMALL LETTER TURNED E"),
+	(0x1de, "LATIN CAPITAL LETTER A WITH DIAERESIS AND MACRON"),
+	(0x1df, "LATIN SMALL LETTER A WITH DIAERESIS AND MACRON"),
+	(0x1e0, "LATIN CAPITAL LETTER A WITH DOT ABOVE AND MACRON"),
+	(0x1e1, "LATIN SMALL LETTER A WITH DOT ABOVE AND MACRON"),
+	(0x1e2, "LATIN CAPITAL LETTER AE WITH MACRON"),
+	(0x1e3, "LATIN SMALL LETTER AE WITH MACRON"),
+	(0x1e4, "LATIN CAPITAL LETTER G WITH STROKE"),
+	(0x1e5, "LATIN SMALL LETTER G WITH STROKE"),
+	(0x1e6, "LATIN CAPITAL LETTER G WITH CARON"),
+	(0x1e7, "LATIN SMALL LETTER G WITH CARON"),
+	(0x1e8, "LATIN CAPITAL LETTER K WITH CARON"),
+	(0x1e9, "LATIN SMALL LETTER K WITH CARON"),
+	(0x1ea, "LATIN CAPITAL LETTER O WITH OGONEK"),
+	(0x1eb, "LATIN SMALL LETTER O WITH OGONEK"),
+	(0x1ec, "LATIN CAPITAL LETTER O WITH OGONEK AND MACRON"),
+	(0x1ed, "LATIN SMALL LETTER O WITH OGONEK AND MACRON"),
+	(0x1ee, "LATIN CAPITAL LETTER EZH WITH CARON"),
+	(0x1ef, "LATIN SMALL LETTER EZH WITH CARON"),
+	(0x1f0, "LATIN SMALL LETTER J WITH CARON"),
+	(0x1f1, "LATIN CAPITAL LETTER DZ"),
+	(0x1f2, "LATIN CAPITAL LETTER D WITH SMALL LETTER Z"),
+	(0x1f3, "LATIN SMALL LETTER DZ"),
+	(0x1f4, "LATIN CAPITAL LETTER G WITH ACUTE"),
+	(0x1f5, "LATIN SMALL LETTER G WITH ACUTE"),
+	(0x1f6, "LATIN CAPITAL LETTER HWAIR"),
+	(0x1f7, "LATIN CAPITAL LETTER WYNN"),
+	(0x1f8, "LATIN CAPITAL LETTER N WITH GRAVE"),
+	(0x1f9, "LATIN SMALL LETTER N WITH GRAVE"),
+	(0x1fa, "LATIN CAPITAL LETTER A WITH RING ABOVE AND ACUTE"),
+	(0x1fb, "LATIN SMALL LETTER A WITH RING ABOVE AND ACUTE"),
+	(0x1fc, "LATIN CAPITAL LETTER AE WITH ACUTE"),
+	(0x1fd, "LATIN SMALL LETTER AE WITH ACUTE"),
+	(0x1fe, "LATIN CAPITAL LETTER O WITH STROKE AND ACUTE"),
+	(0x1ff, "LATIN SMALL LETTER O WITH STROKE AND ACUTE"),
+	(0x200, "LATIN CAPITAL LETTER A WITH DOUBLE GRAVE"),
+	(0x201, "LATIN SMALL LETTER A WITH DOUBLE GRAVE"),
+	(0x202, "LATIN CAPITAL LETTER A WITH INVERTED BREVE"),
+	(0x203, "LATIN SMALL LETTER A WITH INVERTED BREVE"),
+	(0x204, "LATIN CAPITAL LETTER E WITH DOUBLE GRAVE"),
+	(0x205, "LATIN SMALL LETTER E WITH DOUBLE GRAVE"),
+	(0x206, "LATIN CAPITAL LETTER E WITH INVERTED BREVE"),
+	(0x207, "LATIN SMALL LETTER E WITH INVERTED BREVE"),
+	(0x208, "LATIN CAPITAL LETTER I WITH DOUBLE GRAVE"),
+	(0x209, "LATIN SMALL LETTER I WITH DOUBLE GRAVE"),
+	(0x20a, "LATIN CAPITAL LETTER I WITH INVERTED BREVE"),
+	(0x20b, "LATIN SMALL LETTER I WITH INVERTED BREVE"),
+	(0x20c, "LATIN CAPITAL LETTER O WITH DOUBLE GRAVE"),
+	(0x20d, "LATIN SMALL LETTER O WITH DOUBLE GRAVE"),
+	(0x20e, "LATIN CAPITAL LETTER O WITH INVERTED BREVE"),
+	(0x20f, "LATIN SMALL LETTER O WITH INVERTED BREVE"),
+	(0x210, "LATIN CAPITAL LETTER R WITH DOUBLE GRAVE"),
+	(0x211, "LATIN SMALL LETTER R WITH DOUBLE GRAVE"),
+	(0x212, "LATIN CAPITAL LETTER R WITH INVERTED BREVE"),
+	(0x213, "LATIN SMALL LETTER R WITH INVERTED BREVE"),
+	(0x214, "LATIN CAPITAL LETTER U WITH DOUBLE GRAVE"),
+	(0x215, "LATIN SMALL LETTER U WITH DOUBLE GRAVE"),
+	(0x216, "LATIN CAPITAL LETTER U WITH INVERTED BREVE"),
+	(0x217, "LATIN SMALL LETTER U WITH INVERTED BREVE"),
+	(0x218, "LATIN CAPITAL LETTER S WITH COMMA BELOW"),
+	(0x219, "LATIN SMALL LETTER S WITH COMMA BELOW"),
+	(0x21a, "LATIN CAPITAL LETTER T WITH COMMA BELOW"),
+	(0x21b, "LATIN SMALL LETTER T WITH COMMA BELOW"),
+	(0x21c, "LATIN CAPITAL LETTER YOGH"),
+	(0x21d, "LATIN SMALL LETTER YOGH"),
+	(0x21e, "LATIN CAPITAL LETTER H WITH CARON"),
+	(0x21f, "LATIN SMALL LETTER H WITH CARON"),
+	(0x220, "LATIN CAPITAL LETTER N WITH LONG RIGHT LEG"),
+	(0x221, "LATIN SMALL LETTER D WITH CURL"),
+	(0x222, "LATIN CAPITAL LETTER OU"),
+	(0x223, "LATIN SMALL LETTER OU"),
+	(0x224, "LATIN CAPITAL LETTER Z WITH HOOK"),
+	(0x225, "LATIN SMALL LETTER Z WITH HOOK"),
+	(0x226, "LATIN CAPITAL LETTER A WITH DOT ABOVE"),
+	(0x227, "LATIN SMALL LETTER A WITH DOT ABOVE"),
+	(0x228, "LATIN CAPITAL LETTER E WITH CEDILLA"),
+	(0x229, "LATIN SMALL LETTER E WITH CEDILLA"),
+	(0x22a, "LATIN CAPITAL LETTER O WITH DIAERESIS AND MACRON"),
+	(0x22b, "LATIN SMALL LETTER O WITH DIAERESIS AND MACRON"),
+	(0x22c, "LATIN CAPITAL LETTER O WITH TILDE AND MACRON"),
+	(0x22d, "LATIN SMALL LETTER O WITH TILDE AND MACRON"),
+	(0x22e, "LATIN CAPITAL LETTER O WITH DOT ABOVE"),
+	(0x22f, "LATIN SMALL LETTER O WITH DOT ABOVE"),
+	(0x230, "LATIN CAPITAL LETTER O WITH DOT ABOVE AND MACRON"),
+	(0x231, "LATIN SMALL LETTER O WITH DOT ABOVE AND MACRON"),
+	(0x232, "LATIN CAPITAL LETTER Y WITH MACRON"),
+	(0x233, "LATIN SMALL LETTER Y WITH MACRON"),
+	(0x234, "LATIN SMALL LETTER L WITH CURL"),
+	(0x235, "LATIN SMALL LETTER N WITH CURL"),
+	(0x236, "LATIN SMALL LETTER T WITH CURL"),
+	(0x237, "LATIN SMALL LETTER DOTLESS J"),
+	(0x238, "LATIN SMALL LETTER DB DIGRAPH"),
+	(0x239, "LATIN SMALL LETTER QP DIGRAPH"),
+	(0x23a, "LATIN CAPITAL LETTER A WITH STROKE"),
+	(0x23b, "LATIN CAPITAL LETTER C WITH STROKE"),
+	(0x23c, "LATIN SMALL LETTER C WITH STROKE"),
+	(0x23d, "LATIN CAPITAL LETTER L WITH BAR"),
+	(0x23e, "LATIN CAPITAL LETTER T WITH DIAGONAL STROKE"),
+	(0x23f, "LATIN SMALL LETTER S WITH SWASH TAIL"),
+	(0x240, "LATIN SMALL LETTER Z WITH SWASH TAIL"),
+	(0x241, "LATIN CAPITAL LETTER GLOTTAL STOP"),
+	(0x242, "LATIN SMALL LETTER GLOTTAL STOP"),
+	(0x243, "LATIN CAPITAL LETTER B WITH STROKE"),
+	(0x244, "LATIN CAPITAL LETTER U BAR"),
+	(0x245, "LATIN CAPITAL LETTER TURNED V"),
+	(0x246, "LATIN CAPITAL LETTER E WITH STROKE"),
+	(0x247, "LATIN SMALL LETTER E WITH STROKE"),
+	(0x248, "LATIN CAPITAL LETTER J WITH STROKE"),
+	(0x249, "LATIN SMALL LETTER J WITH STROKE"),
+	(0x24a, "LATIN CAPITAL LETTER SMALL Q WITH HOOK TAIL"),
+	(0x24b, "LATIN SMALL LETTER Q WITH HOOK TAIL"),
+	(0x24c, "LATIN CAPITAL LETTER R WITH STROKE"),
+	(0x24d, "LATIN SMALL LETTER R WITH STROKE"),
+	(0x24e, "LATIN CAPITAL LETTER Y WITH STROKE"),
+	(0x24f, "LATIN SMALL LETTER Y WITH STROKE"),
+	(0x250, "LATIN SMALL LETTER TURNED A"),
+	(0x251, "LATIN SMALL LETTER ALPHA"),
+	(0x252, "LATIN SMALL LETTER TURNED ALPHA"),
+	(0x253, "LATIN SMALL LETTER B WITH HOOK"),
+	(0x254, "LATIN SMALL LETTER OPEN O"),
+	(0x255, "LATIN SMALL LETTER C WITH CURL"),
+	(0x256, "LATIN SMALL LETTER D WITH TAIL"),
+	(0x257, "LATIN SMALL LETTER D WITH HOOK"),
+	(0x258, "LATIN SMALL LETTER REVERSED E"),
+	(0x259, "LATIN SMALL LETTER SCHWA"),
+	(0x25a, "LATIN SMALL LETTER SCHWA WITH HOOK"),
+	(0x25b, "LATIN SMALL LETTER OPEN E"),
+	(0x25c, "LATIN SMALL LETTER REVERSED OPEN E"),
+	(0x25d, "LATIN SMALL LETTER REVERSED OPEN E WITH HOOK"),
+	(0x25e, "LATIN SMALL LETTER CLOSED REVERSED OPEN E"),
+	(0x25f, "LATIN SMALL LETTER DOTLESS J WITH STROKE"),
+	(0x260, "LATIN SMALL LETTER G WITH HOOK"),
+	(0x261, "LATIN SMALL LETTER SCRIPT G"),
+	(0x262, "LATIN LETTER SMALL CAPITAL G"),
+	(0x263, "LATIN SMALL LETTER GAMMA"),
+	(0x264, "LATIN SMALL LETTER RAMS HORN"),
+	(0x265, "LATIN SMALL LETTER TURNED H"),
+	(0x266, "LATIN SMALL LETTER H WITH HOOK"),
+	(0x267, "LATIN SMALL LETTER HENG WITH HOOK"),
+	(0x268, "LATIN SMALL LETTER I WITH STROKE"),
+	(0x269, "LATIN SMALL LETTER IOTA"),
+	(0x26a, "LATIN LETTER SMALL CAPITAL I"),
+	(0x26b, "LATIN SMALL LETTER L WITH MIDDLE TILDE"),
+	(0x26c, "LATIN SMALL LETTER L WITH BELT"),
+	(0x26d, "LATIN SMALL LETTER L WITH RETROFLEX HOOK"),
+	(0x26e, "LATIN SMALL LETTER LEZH"),
+	(0x26f, "LATIN SMALL LETTER TURNED M"),
+	(0x270, "LATIN SMALL LETTER TURNED M WITH LONG LEG"),
+	(0x271, "LATIN SMALL LETTER M WITH HOOK"),
+	(0x272, "LATIN SMALL LETTER N WITH LEFT HOOK"),
+	(0x273, "LATIN SMALL LETTER N WITH RETROFLEX HOOK"),
+	(0x274, "LATIN LETTER SMALL CAPITAL N"),
+	(0x275, "LATIN SMALL LETTER BARRED O"),
+	(0x276, "LATIN LETTER SMALL CAPITAL OE"),
+	(0x277, "LATIN SMALL LETTER CLOSED OMEGA"),
+	(0x278, "LATIN SMALL LETTER PHI"),
+	(0x279, "LATIN SMALL LETTER TURNED R"),
+	(0x27a, "LATIN SMALL LETTER TURNED R WITH LONG LEG"),
+	(0x27b, "LATIN SMALL LETTER TURNED R WITH HOOK"),
+	(0x27c, "LATIN SMALL LETTER R WITH LONG LEG"),
+	(0x27d, "LATIN SMALL LETTER R WITH TAIL"),
+	(0x27e, "LATIN SMALL LETTER R WITH FISHHOOK"),
+	(0x27f, "LATIN SMALL LETTER REVERSED R WITH FISHHOOK"),
+	(0x280, "LATIN LETTER SMALL CAPITAL R"),
+	(0x281, "LATIN LETTER SMALL CAPITAL INVERTED R"),
+	(0x282, "LATIN SMALL LETTER S WITH HOOK"),
+	(0x283, "LATIN SMALL LETTER ESH"),
+	(0x284, "LATIN SMALL LETTER DOTLESS J WITH STROKE AND HOOK"),
+	(0x285, "LATIN SMALL LETTER SQUAT REVERSED ESH"),
+	(0x286, "LATIN SMALL LETTER ESH WITH CURL"),
+	(0x287, "LATIN SMALL LETTER TURNED T"),
+	(0x288, "LATIN SMALL LETTER T WITH RETROFLEX HOOK"),
+	(0x289, "LATIN SMALL LETTER U BAR"),
+	(0x28a, "LATIN SMALL LETTER UPSILON"),
+	(0x28b, "LATIN SMALL LETTER V WITH HOOK"),
+	(0x28c, "LATIN SMALL LETTER TURNED V"),
+	(0x28d, "LATIN SMALL LETTER TURNED W"),
+	(0x28e, "LATIN SMALL LETTER TURNED Y"),
+	(0x28f, "LATIN LETTER SMALL CAPITAL Y"),
+	(0x290, "LATIN SMALL LETTER Z WITH RETROFLEX HOOK"),
+	(0x291, "LATIN SMALL LETTER Z WITH CURL"),
+	(0x292, "LATIN SMALL LETTER EZH"),
+	(0x293, "LATIN SMALL LETTER EZH WITH CURL"),
+	(0x294, "LATIN LETTER GLOTTAL STOP"),
+	(0x295, "LATIN LETTER PHARYNGEAL VOICED FRICATIVE"),
+	(0x296, "LATIN LETTER INVERTED GLOTTAL STOP"),
+	(0x297, "LATIN LETTER STRETCHED C"),
+	(0x298, "LATIN LETTER BILABIAL CLICK"),
+	(0x299, "LATIN LETTER SMALL CAPITAL B"),
+	(0x29a, "LATIN SMALL LETTER CLOSED OPEN E"),
+	(0x29b, "LATIN LETTER SMALL CAPITAL G WITH HOOK"),
+	(0x29c, "LATIN LETTER SMALL CAPITAL H"),
+	(0x29d, "LATIN SMALL LETTER J WITH CROSSED-TAIL"),
+	(0x29e, "LATIN SMALL LETTER TURNED K"),
+	(0x29f, "LATIN LETTER SMALL CAPITAL L"),
+	(0x2a0, "LATIN SMALL LETTER Q WITH HOOK"),
+	(0x2a1, "LATIN LETTER GLOTTAL STOP WITH STROKE"),
+	(0x2a2, "LATIN LETTER REVERSED GLOTTAL STOP WITH STROKE"),
+	(0x2a3, "LATIN SMALL LETTER DZ DIGRAPH"),
+	(0x2a4, "LATIN SMALL LETTER DEZH DIGRAPH"),
+	(0x2a5, "LATIN SMALL LETTER DZ DIGRAPH WITH CURL"),
+	(0x2a6, "LATIN SMALL LETTER TS DIGRAPH"),
+	(0x2a7, "LATIN SMALL LETTER TESH DIGRAPH"),
+	(0x2a8, "LATIN SMALL LETTER TC DIGRAPH WITH CURL"),
+	(0x2a9, "LATIN SMALL LETTER FENG DIGRAPH"),
+	(0x2aa, "LATIN SMALL LETTER LS DIGRAPH"),
+	(0x2ab, "LATIN SMALL LETTER LZ DIGRAPH"),
+	(0x2ac, "LATIN LETTER BILABIAL PERCUSSIVE"),
+	(0x2ad, "LATIN LETTER BIDENTAL PERCUSSIVE"),
+	(0x2ae, "LATIN SMALL LETTER TURNED H WITH FISHHOOK"),
+	(0x2af, "LATIN SMALL LETTER TURNED H WITH FISHHOOK AND TAIL"),
+	(0x2b0, "MODIFIER LETTER SMALL H"),
+	(0x2b1, "MODIFIER LETTER SMALL H WITH HOOK"),
+	(0x2b2, "MODIFIER LETTER SMALL J"),
+	(0x2b3, "MODIFIER LETTER SMALL R"),
+	(0x2b4, "MODIFIER LETTER SMALL TURNED R"),
+	(0x2b5, "MODIFIER LETTER SMALL TURNED R WITH HOOK"),
+	(0x2b6, "MODIFIER LETTER SMALL CAPITAL INVERTED R"),
+	(0x2b7, "MODIFIER LETTER SMALL W"),
+	(0x2b8, "MODIFIER LETTER SMALL Y"),
+	(0x2b9, "MODIFIER LETTER PRIME"),
+	(0x2ba, "MODIFIER LETTER DOUBLE PRIME"),
+	(0x2bb, "MODIFIER LETTER TURNED COMMA"),
+	(0x2bc, "MODIFIER LETTER APOSTROPHE"),
+	(0x2bd, "MODIFIER LETTER REVERSED COMMA"),
+	(0x2be, "MODIFIER LETTER RIGHT HALF RING"),
+	(0x2bf, "MODIFIER LETTER LEFT HALF RING"),
+	(0x2c0, "MODIFIER LETTER GLOTTAL STOP"),
+	(0x2c1, "MODIFIER LETTER REVERSED GLOTTAL STOP"),
+	(0x2c2, "MODIFIER LETTER LEFT ARROWHEAD"),
+	(0x2c3, "MODIFIER LETTER RIGHT ARROWHEAD"),
+	(0x2c4, "MODIFIER LETTER UP ARROWHEAD"),
+	(0x2c5, "MODIFIER LETTER DOWN ARROWHEAD"),
+	(0x2c6, "MODIFIER LETTER CIRCUMFLEX ACCENT"),
+	(0x2c7, "CARON"),
+	(0x2c8, "MODIFIER LETTER VERTICAL LINE"),
+	(0x2c9, "MODIFIER LETTER MACRON"),
+	(0x2ca, "MODIFIER LETTER ACUTE ACCENT"),
+	(0x2cb, "MODIFIER LETTER GRAVE ACCENT"),
+	(0x2cc, "MODIFIER LETTER LOW VERTICAL LINE"),
+	(0x2cd, "MODIFIER LETTER LOW MACRON"),
+	(0x2ce, "MODIFIER LETTER LOW GRAVE ACCENT"),
+	(0x2cf, "MODIFIER LETTER LOW ACUTE ACCENT"),
+	(0x2d0, "MODIFIER LETTER TRIANGULAR COLON"),
+	(0x2d1, "MODIFIER LETTER HALF TRIANGULAR COLON"),
+	(0x2d2, "MODIFIER LETTER CENTRED RIGHT HALF RING"),
+	(0x2d3, "MODIFIER LETTER CENTRED LEFT HALF RING"),
+	(0x2d4, "MODIFIER LETTER UP TACK"),
+	(0x2d5, "MODIFIER LETTER DOWN TACK"),
+	(0x2d6, "MODIFIER LETTER PLUS SIGN"),
+	(0x2d7, "MODIFIER LETTER MINUS SIGN"),
+	(0x2d8, "BREVE"),
+	(0x2d9, "DOT ABOVE"),
+	(0x2da, "RING ABOVE"),
+	(0x2db, "OGONEK"),
+	(0x2dc, "SMALL TILDE"),
+	(0x2dd, "DOUBLE ACUTE ACCENT"),
+	(0x2de, "MODIFIER LETTER RHOTIC HOOK"),
+	(0x2df, "MODIFIER LETTER CROSS ACCENT"),
+	(0x2e0, "MODIFIER LETTER SMALL GAMMA"),
+	(0x2e1, "MODIFIER LETTER SMALL L"),
+	(0x2e2, "MODIFIER LETTER SMALL S"),
+	(0x2e3, "MODIFIER LETTER SMALL X"),
+	(0x2e4, "MODIFIER LETTER SMALL REVERSED GLOTTAL STOP"),
+	(0x2e5, "MODIFIER LETTER EXTRA-HIGH TONE BAR"),
+	(0x2e6, "MODIFIER LETTER HIGH TONE BAR"),
+	(0x2e7, "MODIFIER LETTER MID TONE BAR"),
+	(0x2e8, "MODIFIER LETTER LOW TONE BAR"),
+	(0x2e9, "MODIFIER LETTER EXTRA-LOW TONE BAR"),
+	(0x2ea, "MODIFIER LETTER YIN DEPARTING TONE MARK"),
+	(0x2eb, "MODIFIER LETTER YANG DEPARTING TONE MARK"),
+	(0x2ec, "MODIFIER LETTER VOICING"),
+	(0x2ed, "MODIFIER LETTER UNASPIRATED"),
+	(0x2ee, "MODIFIER LETTER DOUBLE APOSTROPHE"),
+	(0x2ef, "MODIFIER LETTER LOW DOWN ARROWHEAD"),
+	(0x2f0, "MODIFIER LETTER LOW UP ARROWHEAD"),
+	(0x2f1, "MODIFIER LETTER LOW LEFT ARROWHEAD"),
+	(0x2f2, "MODIFIER LETTER LOW RIGHT ARROWHEAD"),
+	(0x2f3, "MODIFIER LETTER LOW RING"),
+	(0x2f4, "MODIFIER LETTER MIDDLE GRAVE ACCENT"),
+	(0x2f5, "MODIFIER LETTER MIDDLE DOUBLE GRAVE ACCENT"),
+	(0x2f6, "MODIFIER LETTER MIDDLE DOUBLE ACUTE ACCENT"),
+	(0x2f7, "MODIFIER LETTER LOW TILDE"),
+	(0x2f8, "MODIFIER LETTER RAISED COLON"),
+	(0x2f9, "MODIFIER LETTER BEGIN HIGH TONE"),
+	(0x2fa, "MODIFIER LETTER END HIGH TONE"),
+	(0x2fb, "MODIFIER LETTER BEGIN LOW TONE"),
+	(0x2fc, "MODIFIER LETTER END LOW TONE"),
+	(0x2fd, "MODIFIER LETTER SHELF"),
+	(0x2fe, "MODIFIER LETTER OPEN SHELF"),
+	(0x2ff, "MODIFIER LETTER LOW LEFT ARROW"),
+	(0x300, "COMBINING GRAVE ACCENT"),
+	(0x301, "COMBINING ACUTE ACCENT"),
+	(0x302, "COMBINING CIRCUMFLEX ACCENT"),
+	(0x303, "COMBINING TILDE"),
+	(0x304, "COMBINING MACRON"),
+	(0x305, "COMBINING OVERLINE"),
+	(0x306, "COMBINING BREVE"),
+	(0x307, "COMBINING DOT ABOVE"),
+	(0x308, "COMBINING DIAERESIS"),
+	(0x309, "COMBINING HOOK ABOVE"),
+	(0x30a, "COMBINING RING ABOVE"),
+	(0x30b, "COMBINING DOUBLE ACUTE ACCENT"),
+	(0x30c, "COMBINING CARON"),
+	(0x30d, "COMBINING VERTICAL LINE ABOVE"),
+	(0x30e, "COMBINING DOUBLE VERTICAL LINE ABOVE"),
+	(0x30f, "COMBINING DOUBLE GRAVE ACCENT"),
+	(0x310, "COMBINING CANDRABINDU"),
+	(0x311, "COMBINING INVERTED BREVE"),
+	(0x312, "COMBINING TURNED COMMA ABOVE"),
+	(0x313, "COMBINING COMMA ABOVE"),
+	(0x314, "COMBINING REVERSED COMMA ABOVE"),
+	(0x315, "COMBINING COMMA ABOVE RIGHT"),
+	(0x316, "COMBINING GRAVE ACCENT BELOW"),
+	(0x317, "COMBINING ACUTE ACCENT BELOW"),
+	(0x318, "COMBINING LEFT TACK BELOW"),
+	(0x319, "COMBINING RIGHT TACK BELOW"),
+	(0x31a, "COMBINING LEFT ANGLE ABOVE"),
+	(0x31b, "COMBINING HORN"),
+	(0x31c, "COMBINING LEFT HALF RING BELOW"),
+	(0x31d, "COMBINING UP TACK BELOW"),
+	(0x31e, "COMBINING DOWN TACK BELOW"),
+	(0x31f, "COMBINING PLUS SIGN BELOW"),
+	(0x320, "COMBINING MINUS SIGN BELOW"),
+	(0x321, "COMBINING PALATALIZED HOOK BELOW"),
+	(0x322, "COMBINING RETROFLEX HOOK BELOW"),
+	(0x323, "COMBINING DOT BELOW"),
+	(0x324, "COMBINING DIAERESIS BELOW"),
+	(0x325, "COMBINING RING BELOW"),
+	(0x326, "COMBINING COMMA BELOW"),
+	(0x327, "COMBINING CEDILLA"),
+	(0x328, "COMBINING OGONEK"),
+	(0x329, "COMBINING VERTICAL LINE BELOW"),
+	(0x32a, "COMBINING BRIDGE BELOW"),
+	(0x32b, "COMBINING INVERTED DOUBLE ARCH BELOW"),
+	(0x32c, "COMBINING CARON BELOW"),
+	(0x32d, "COMBINING CIRCUMFLEX ACCENT BELOW"),
+	(0x32e, "COMBINING BREVE BELOW"),
+	(0x32f, "COMBINING INVERTED BREVE BELOW"),
+	(0x330, "COMBINING TILDE BELOW"),
+	(0x331, "COMBINING MACRON BELOW"),
+	(0x332, "COMBINING LOW LINE"),
+	(0x333, "COMBINING DOUBLE LOW LINE"),
+	(0x334, "COMBINING TILDE OVERLAY"),
+	(0x335, "COMBINING SHORT STROKE OVERLAY"),
+	(0x336, "COMBINING LONG STROKE OVERLAY"),
+	(0x337, "COMBINING SHORT SOLIDUS OVERLAY"),
+	(0x338, "COMBINING LONG SOLIDUS OVERLAY"),
+	(0x339, "COMBINING RIGHT HALF RING BELOW"),
+	(0x33a, "COMBINING INVERTED BRIDGE BELOW"),
+	(0x33b, "COMBINING SQUARE BELOW"),
+	(0x33c, "COMBINING SEAGULL BELOW"),
+	(0x33d, "COMBINING X ABOVE"),
+	(0x33e, "COMBINING VERTICAL TILDE"),
+	(0x33f, "COMBINING DOUBLE OVERLINE"),
+	(0x340, "COMBINING GRAVE TONE MARK"),
+	(0x341, "COMBINING ACUTE TONE MARK"),
+	(0x342, "COMBINING GREEK PERISPOMENI"),
+	(0x343, "COMBINING GREEK KORONIS"),
+	(0x344, "COMBINING GREEK DIALYTIKA TONOS"),
+	(0x345, "COMBINING GREEK YPOGEGRAMMENI"),
+	(0x346, "COMBINING BRIDGE ABOVE"),
+	(0x347, "COMBINING EQUALS SIGN BELOW"),
+	(0x348, "COMBINING DOUBLE VERTICAL LINE BELOW"),
+	(0x349, "COMBINING LEFT ANGLE BELOW"),
+	(0x34a, "COMBINING NOT TILDE ABOVE"),
+	(0x34b, "COMBINING HOMOTHETIC ABOVE"),
+	(0x34c, "COMBINING ALMOST EQUAL TO ABOVE"),
+	(0x34d, "COMBINING LEFT RIGHT ARROW BELOW"),
+	(0x34e, "COMBINING UPWARDS ARROW BELOW"),
+	(0x34f, "COMBINING GRAPHEME JOINER"),
+	(0x350, "COMBINING RIGHT ARROWHEAD ABOVE"),
+	(0x351, "COMBINING LEFT HALF RING ABOVE"),
+	(0x352, "COMBINING FERMATA"),
+	(0x353, "COMBINING X BELOW"),
+	(0x354, "COMBINING LEFT ARROWHEAD BELOW"),
+	(0x355, "COMBINING RIGHT ARROWHEAD BELOW"),
+	(0x356, "COMBINING RIGHT ARROWHEAD AND UP ARROWHEAD BELOW"),
+	(0x357, "COMBINING RIGHT HALF RING ABOVE"),
+	(0x358, "COMBINING DOT ABOVE RIGHT"),
+	(0x359, "COMBINING ASTERISK BELOW"),
+	(0x35a, "COMBINING DOUBLE RING BELOW"),
+	(0x35b, "COMBINING ZIGZAG ABOVE"),
+	(0x35c, "COMBINING DOUBLE BREVE BELOW"),
+	(0x35d, "COMBINING DOUBLE BREVE"),
+	(0x35e, "COMBINING DOUBLE MACRON"),
+	(0x35f, "COMBINING DOUBLE MACRON BELOW"),
+	(0x360, "COMBINING DOUBLE TILDE"),
+	(0x361, "COMBINING DOUBLE INVERTED BREVE"),
+	(0x362, "COMBINING DOUBLE RIGHTWARDS ARROW BELOW"),
+	(0x363, "COMBINING LATIN SMALL LETTER A"),
+	(0x364, "COMBINING LATIN SMALL LETTER E"),
+	(0x365, "COMBINING LATIN SMALL LETTER I"),
+	(0x366, "COMBINING LATIN SMALL LETTER O"),
+	(0x367, "COMBINING LATIN SMALL LETTER U"),
+	(0x368, "COMBINING LATIN SMALL LETTER C"),
+	(0x369, "COMBINING LATIN SMALL LETTER D"),
+	(0x36a, "COMBINING LATIN SMALL LETTER H"),
+	(0x36b, "COMBINING LATIN SMALL LETTER M"),
+	(0x36c, "COMBINING LATIN SMALL LETTER R"),
+	(0x36d, "COMBINING LATIN SMALL LETTER T"),
+	(0x36e, "COMBINING LATIN SMALL LETTER V"),
+	(0x36f, "COMBINING LATIN SMALL LETTER X"),
+	(0x370, "GREEK CAPITAL LETTER HETA"),
+	(0x371, "GREEK SMALL LETTER HETA"),
+	(0x372, "GREEK CAPITAL LETTER ARCHAIC SAMPI"),
+	(0x373, "GREEK SMALL LETTER ARCHAIC SAMPI"),
+	(0x374, "GREEK NUMERAL SIGN"),
+	(0x375, "GREEK LOWER NUMERAL SIGN"),
+	(0x376, "GREEK CAPITAL LETTER PAMPHYLIAN DIGAMMA"),
+	(0x377, "GREEK SMALL LETTER PAMPHYLIAN DIGAMMA"),
+	(0x37a, "GREEK YPOGEGRAMMENI"),
+	(0x37b, "GREEK SMALL REVERSED LUNATE SIGMA SYMBOL"),
+	(0x37c, "GREEK SMALL DOTTED LUNATE SIGMA SYMBOL"),
+	(0x37d, "GREEK SMALL REVERSED DOTTED LUNATE SIGMA SYMBOL"),
+	(0x37e, "GREEK QUESTION MARK"),
+	(0x37f, "GREEK CAPITAL LETTER YOT"),
+	(0x384, "GREEK TONOS"),
+	(0x385, "GREEK DIALYTIKA TONOS"),
+	(0x386, "GREEK CAPITAL LETTER ALPHA WITH TONOS"),
+	(0x387, "GREEK ANO TELEIA"),
+	(0x388, "GREEK CAPITAL LETTER EPSILON WITH TONOS"),
+	(0x389, "GREEK CAPITAL LETTER ETA WITH TONOS"),
+	(0x38a, "GREEK CAPITAL LETTER IOTA WITH TONOS"),
+	(0x38c, "GREEK CAPITAL LETTER OMICRON WITH TONOS"),
+	(0x38e, "GREEK CAPITAL LETTER UPSILON WITH TONOS"),
+	(0x38f, "GREEK CAPITAL LETTER OMEGA WITH TONOS"),
+	(0x390, "GREEK SMALL LETTER IOTA WITH DIALYTIKA AND TONOS"),
+	(0x391, "GREEK CAPITAL LETTER ALPHA"),
+	(0x392, "GREEK CAPITAL LETTER BETA"),
+	(0x393, "GREEK CAPITAL LETTER GAMMA"),
+	(0x394, "GREEK CAPITAL LETTER DELTA"),
+	(0x395, "GREEK CAPITAL LETTER EPSILON"),
+	(0x396, "GREEK CAPITAL LETTER ZETA"),
+	(0x397, "GREEK CAPITAL LETTER ETA"),
+	(0x398, "GREEK CAPITAL LETTER THETA"),
+	(0x399, "GREEK CAPITAL LETTER IOTA"),
+	(0x39a, "GREEK CAPITAL LETTER KAPPA"),
+	(0x39b, "GREEK CAPITAL LETTER LAMDA"),
+	(0x39c, "GREEK CAPITAL LETTER MU"),
+	(0x39d, "GREEK CAPITAL LETTER NU"),
+	(0x39e, "GREEK CAPITAL LETTER XI"),
+	(0x39f, "GREEK CAPITAL LETTER OMICRON"),
+	(0x3a0, "GREEK CAPITAL LETTER PI"),
+	(0x3a1, "GREEK CAPITAL LETTER RHO"),
+	(0x3a3, "GREEK CAPITAL LETTER SIGMA"),
+	(0x3a4, "GREEK CAPITAL LETTER TAU"),
+	(0x3a5, "GREEK CAPITAL LETTER UPSILON"),
+	(0x3a6, "GREEK CAPITAL LETTER PHI"),
+	(0x3a7, "GREEK CAPITAL LETTER CHI"),
+	(0x3a8, "GREEK CAPITAL LETTER PSI"),
+	(0x3a9, "GREEK CAPITAL LETTER OMEGA"),
+	(0x3aa, "GREEK CAPITAL LETTER IOTA WITH DIALYTIKA"),
+	(0x3ab, "GREEK CAPITAL LETTER UPSILON WITH DIALYTIKA"),
+	(0x3ac, "GREEK SMALL LETTER ALPHA WITH TONOS"),
+	(0x3ad, "GREEK SMALL LETTER EPSILON WITH TONOS"),
+	(0x3ae, "GREEK SMALL LETTER ETA WITH TONOS"),
+	(0x3af, "GREEK SMALL LETTER IOTA WITH TONOS"),
+	(0x3b0, "GREEK SMALL LETTER UPSILON WITH DIALYTIKA AND TONOS"),
+	(0x3b1, "GREEK SMALL LETTER ALPHA"),
+	(0x3b2, "GREEK SMALL LETTER BETA"),
+	(0x3b3, "GREEK SMALL LETTER GAMMA"),
+	(0x3b4, "GREEK SMALL LETTER DELTA"),
+	(0x3b5, "GREEK SMALL LETTER EPSILON"),
+	(0x3b6, "GREEK SMALL LETTER ZETA"),
+	(0x3b7, "GREEK SMALL LETTER ETA"),
+	(0x3b8, "GREEK SMALL LETTER THETA"),
+	(0x3b9, "GREEK SMALL LETTER IOTA"),
+	(0x3ba, "GREEK SMALL LETTER KAPPA"),
+	(0x3bb, "GREEK SMALL LETTER LAMDA"),
+	(0x3bc, "GREEK SMALL LETTER MU"),
+	(0x3bd, "GREEK SMALL LETTER NU"),
+	(0x3be, "GREEK SMALL LETTER XI"),
+	(0x3bf, "GREEK SMALL LETTER OMICRON"),
+	(0x3c0, "GREEK SMALL LETTER PI"),
+	(0x3c1, "GREEK SMALL LETTER RHO"),
+	(0x3c2, "GREEK SMALL LETTER FINAL SIGMA"),
+	(0x3c3, "GREEK SMALL LETTER SIGMA"),
+	(0x3c4, "GREEK SMALL LETTER TAU"),
+	(0x3c5, "GREEK SMALL LETTER UPSILON"),
+	(0x3c6, "GREEK SMALL LETTER PHI"),
+	(0x3c7, "GREEK SMALL LETTER CHI"),
+	(0x3c8, "GREEK SMALL LETTER PSI"),
+	(0x3c9, "GREEK SMALL LETTER OMEGA"),
+	(0x3ca, "GREEK SMALL LETTER IOTA WITH DIALYTIKA"),
+	(0x3cb, "GREEK SMALL LETTER UPSILON WITH DIALYTIKA"),
+	(0x3cc, "GREEK SMALL LETTER OMICRON WITH TONOS"),
+	(0x3cd, "GREEK SMALL LETTER UPSILON WITH TONOS"),
+	(0x3ce, "GREEK SMALL LETTER OMEGA WITH TONOS"),
+	(0x3cf, "GREEK CAPITAL KAI SYMBOL"),
+	(0x3d0, "GREEK BETA SYMBOL"),
+	(0x3d1, "GREEK THETA SYMBOL"),
+	(0x3d2, "GREEK UPSILON WITH HOOK SYMBOL"),
+	(0x3d3, "GREEK UPSILON WITH ACUTE AND HOOK SYMBOL"),
+	(0x3d4, "GREEK UPSILON WITH DIAERESIS AND HOOK SYMBOL"),
+	(0x3d5, "GREEK PHI SYMBOL"),
+	(0x3d6, "GREEK PI SYMBOL"),
+	(0x3d7, "GREEK KAI SYMBOL"),
+	(0x3d8, "GREEK LETTER ARCHAIC KOPPA"),
+	(0x3d9, "GREEK SMALL LETTER ARCHAIC KOPPA"),
+	(0x3da, "GREEK LETTER STIGMA"),
+	(0x3db, "GREEK SMALL LETTER STIGMA"),
+	(0x3dc, "GREEK LETTER DIGAMMA"),
+	(0x3dd, "GREEK SMALL LETTER DIGAMMA"),
+	(0x3de, "GREEK LETTER KOPPA"),
+	(0x3df, "GREEK SMALL LETTER KOPPA"),
+	(0x3e0, "GREEK LETTER SAMPI"),
+	(0x3e1, "GREEK SMALL LETTER SAMPI"),
+	(0x3e2, "COPTIC CAPITAL LETTER SHEI"),
+	(0x3e3, "COPTIC SMALL LETTER SHEI"),
+	(0x3e4, "COPTIC CAPITAL LETTER FEI"),
+	(0x3e5, "COPTIC SMALL LETTER FEI"),
+	(0x3e6, "COPTIC CAPITAL LETTER KHEI"),
+	(0x3e7, "COPTIC SMALL LETTER KHEI"),
+	(0x3e8, "COPTIC CAPITAL LETTER HORI"),
+	(0x3e9, "COPTIC SMALL LETTER HORI"),
+	(0x3ea, "COPTIC CAPITAL LETTER GANGIA"),
+	(0x3eb, "COPTIC SMALL LETTER GANGIA"),
+	(0x3ec, "COPTIC CAPITAL LETTER SHIMA"),
+	(0x3ed, "COPTIC SMALL LETTER SHIMA"),
+	(0x3ee, "COPTIC CAPITAL LETTER DEI"),
+	(0x3ef, "COPTIC SMALL LETTER DEI"),
+	(0x3f0, "GREEK KAPPA SYMBOL"),
+	(0x3f1, "GREEK RHO SYMBOL"),
+	(0x3f2, "GREEK LUNATE SIGMA SYMBOL"),
+	(0x3f3, "GREEK LETTER YOT"),
+	(0x3f4, "GREEK CAPITAL THETA SYMBOL"),
+	(0x3f5, "GREEK LUNATE EPSILON SYMBOL"),
+	(0x3f6, "GREEK REVERSED LUNATE EPSILON SYMBOL"),
+	(0x3f7, "GREEK CAPITAL LETTER SHO"),
+	(0x3f8, "GREEK SMALL LETTER SHO"),
+	(0x3f9, "GREEK CAPITAL LUNATE SIGMA SYMBOL"),
+	(0x3fa, "GREEK CAPITAL LETTER SAN"),
+	(0x3fb, "GREEK SMALL LETTER SAN"),
+	(0x3fc, "GREEK RHO WITH STROKE SYMBOL"),
+	(0x3fd, "GREEK CAPITAL REVERSED LUNATE SIGMA SYMBOL"),
+	(0x3fe, "GREEK CAPITAL DOTTED LUNATE SIGMA SYMBOL"),
+	(0x3ff, "GREEK CAPITAL REVERSED DOTTED LUNATE SIGMA SYMBOL"),
+	(0x400, "CYRILLIC CAPITAL LETTER IE WITH GRAVE"),
+	(0x401, "CYRILLIC CAPITAL LETTER IO"),
+	(0x402, "CYRILLIC CAPITAL LETTER DJE"),
+	(0x403, "CYRILLIC CAPITAL LETTER GJE"),
+	(0x404, "CYRILLIC CAPITAL LETTER UKRAINIAN IE"),
+	(0x405, "CYRILLIC CAPITAL LETTER DZE"),
+	(0x406, "CYRILLIC CAPITAL LETTER BYELORUSSIAN-UKRAINIAN I"),
+	(0x407, "CYRILLIC CAPITAL LETTER YI"),
+	(0x408, "CYRILLIC CAPITAL LETTER JE"),
+	(0x409, "CYRILLIC CAPITAL LETTER LJE"),
+	(0x40a, "CYRILLIC CAPITAL LETTER NJE"),
+	(0x40b, "CYRILLIC CAPITAL LETTER TSHE"),
+	(0x40c, "CYRILLIC CAPITAL LETTER KJE"),
+	(0x40d, "CYRILLIC CAPITAL LETTER I WITH GRAVE"),
+	(0x40e, "CYRILLIC CAPITAL LETTER SHORT U"),
+	(0x40f, "CYRILLIC CAPITAL LETTER DZHE"),
+	(0x410, "CYRILLIC CAPITAL LETTER A"),
+	(0x411, "CYRILLIC CAPITAL LETTER BE"),
+	(0x412, "CYRILLIC CAPITAL LETTER VE"),
+	(0x413, "CYRILLIC CAPITAL LETTER GHE"),
+	(0x414, "CYRILLIC CAPITAL LETTER DE"),
+	(0x415, "CYRILLIC CAPITAL LETTER IE"),
+	(0x416, "CYRILLIC CAPITAL LETTER ZHE"),
+	(0x417, "CYRILLIC CAPITAL LETTER ZE"),
+	(0x418, "CYRILLIC CAPITAL LETTER I"),
+	(0x419, "CYRILLIC CAPITAL LETTER SHORT I"),
+	(0x41a, "CYRILLIC CAPITAL LETTER KA"),
+	(0x41b, "CYRILLIC CAPITAL LETTER EL"),
+	(0x41c, "CYRILLIC CAPITAL LETTER EM"),
+	(0x41d, "CYRILLIC CAPITAL LETTER EN"),
+	(0x41e, "CYRILLIC CAPITAL LETTER O"),
+	(0x41f, "CYRILLIC CAPITAL LETTER PE"),
+	(0x420, "CYRILLIC CAPITAL LETTER ER"),
+	(0x421, "CYRILLIC CAPITAL LETTER ES"),
+	(0x422, "CYRILLIC CAPITAL LETTER TE"),
+	(0x423, "CYRILLIC CAPITAL LETTER U"),
+	(0x424, "CYRILLIC CAPITAL LETTER EF"),
+	(0x425, "CYRILLIC CAPITAL LETTER HA"),
+	(0x426, "CYRILLIC CAPITAL LETTER TSE"),
+	(0x427, "CYRILLIC CAPITAL LETTER CHE"),
+	(0x428, "CYRILLIC CAPITAL LETTER SHA"),
+	(0x429, "CYRILLIC CAPITAL LETTER SHCHA"),
+	(0x42a, "CYRILLIC CAPITAL LETTER HARD SIGN"),
+	(0x42b, "CYRILLIC CAPITAL LETTER YERU"),
+	(0x42c, "CYRILLIC CAPITAL LETTER SOFT SIGN"),
+	(0x42d, "CYRILLIC CAPITAL LETTER E"),
+	(0x42e, "CYRILLIC CAPITAL LETTER YU"),
+	(0x42f, "CYRILLIC CAPITAL LETTER YA"),
+	(0x430, "CYRILLIC SMALL LETTER A"),
+	(0x431, "CYRILLIC SMALL LETTER BE"),
+	(0x432, "CYRILLIC SMALL LETTER VE"),
+	(0x433, "CYRILLIC SMALL LETTER GHE"),
+	(0x434, "CYRILLIC SMALL LETTER DE"),
+	(0x435, "CYRILLIC SMALL LETTER IE"),
+	(0x436, "CYRILLIC SMALL LETTER ZHE"),
+	(0x437, "CYRILLIC SMALL LETTER ZE"),
+	(0x438, "CYRILLIC SMALL LETTER I"),
+	(0x439, "CYRILLIC SMALL LETTER SHORT I"),
+	(0x43a, "CYRILLIC SMALL LETTER KA"),
+	(0x43b, "CYRILLIC SMALL LETTER EL"),
+	(0x43c, "CYRILLIC SMALL LETTER EM"),
+	(0x43d, "CYRILLIC SMALL LETTER EN"),
+	(0x43e, "CYRILLIC SMALL LETTER O"),
+	(0x43f, "CYRILLIC SMALL LETTER PE"),
+	(0x440, "CYRILLIC SMALL LETTER ER"),
+	(0x441, "CYRILLIC SMALL LETTER ES"),
+	(0x442, "CYRILLIC SMALL LETTER TE"),
+	(0x443, "CYRILLIC SMALL LETTER U"),
+	(0x444, "CYRILLIC SMALL LETTER EF"),
+	(0x445, "CYRILLIC SMALL LETTER HA"),
+	(0x446, "CYRILLIC SMALL LETTER TSE"),
+	(0x447, "CYRILLIC SMALL LETTER CHE"),
+	(0x448, "CYRILLIC SMALL LETTER SHA"),
+	(0x449, "CYRILLIC SMALL LETTER SHCHA"),
+	(0x44a, "CYRILLIC SMALL LETTER HARD SIGN"),
+	(0x44b, "CYRILLIC SMALL LETTER YERU"),
+	(0x44c, "CYRILLIC SMALL LETTER SOFT SIGN"),
+	(0x44d, "CYRILLIC SMALL LETTER E"),
+	(0x44e, "CYRILLIC SMALL LETTER YU"),
+	(0x44f, "CYRILLIC SMALL LETTER YA"),
+	(0x450, "CYRILLIC SMALL LETTER IE WITH GRAVE"),
+	(0x451, "CYRILLIC SMALL LETTER IO"),
+	(0x452, "CYRILLIC SMALL LETTER DJE"),
+	(0x453, "CYRILLIC SMALL LETTER GJE"),
+	(0x454, "CYRILLIC SMALL LETTER UKRAINIAN IE"),
+	(0x455, "CYRILLIC SMALL LETTER DZE"),
+	(0x456, "CYRILLIC SMALL LETTER BYELORUSSIAN-UKRAINIAN I"),
+	(0x457, "CYRILLIC SMALL LETTER YI"),
+	(0x458, "CYRILLIC SMALL LETTER JE"),
+	(0x459, "CYRILLIC SMALL LETTER LJE"),
+	(0x45a, "CYRILLIC SMALL LETTER NJE"),
+	(0x45b, "CYRILLIC SMALL LETTER TSHE"),
+	(0x45c, "CYRILLIC SMALL LETTER KJE"),
+	(0x45d, "CYRILLIC SMALL LETTER I WITH GRAVE"),
+	(0x45e, "CYRILLIC SMALL LETTER SHORT U"),
+	(0x45f, "CYRILLIC SMALL LETTER DZHE"),
+	(0x460, "CYRILLIC CAPITAL LETTER OMEGA"),
+	(0x461, "CYRILLIC SMALL LETTER OMEGA"),
+	(0x462, "CYRILLIC CAPITAL LETTER YAT"),
+	(0x463, "CYRILLIC SMALL LETTER YAT"),
+	(0x464, "CYRILLIC CAPITAL LETTER IOTIFIED E"),
+	(0x465, "CYRILLIC SMALL LETTER IOTIFIED E"),
+	(0x466, "CYRILLIC CAPITAL LETTER LITTLE YUS"),
+	(0x467, "CYRILLIC SMALL LETTER LITTLE YUS"),
+	(0x468, "CYRILLIC CAPITAL LETTER IOTIFIED LITTLE YUS"),
+	(0x469, "CYRILLIC SMALL LETTER IOTIFIED LITTLE YUS"),
+	(0x46a, "CYRILLIC CAPITAL LETTER BIG YUS"),
+	(0x46b, "CYRILLIC SMALL LETTER BIG YUS"),
+	(0x46c, "CYRILLIC CAPITAL LETTER IOTIFIED BIG YUS"),
+	(0x46d, "CYRILLIC SMALL LETTER IOTIFIED BIG YUS"),
+	(0x46e, "CYRILLIC CAPITAL LETTER KSI"),
+	(0x46f, "CYRILLIC SMALL LETTER KSI"),
+	(0x470, "CYRILLIC CAPITAL LETTER PSI"),
+	(0x471, "CYRILLIC SMALL LETTER PSI"),
+	(0x472, "CYRILLIC CAPITAL LETTER FITA"),
+	(0x473, "CYRILLIC SMALL LETTER FITA"),
+	(0x474, "CYRILLIC CAPITAL LETTER IZHITSA"),
+	(0x475, "CYRILLIC SMALL LETTER IZHITSA"),
+	(0x476, "CYRILLIC CAPITAL LETTER IZHITSA WITH DOUBLE GRAVE ACCENT"),
+	(0x477, "CYRILLIC SMALL LETTER IZHITSA WITH DOUBLE GRAVE ACCENT"),
+	(0x478, "CYRILLIC CAPITAL LETTER UK"),
+	(0x479, "CYRILLIC SMALL LETTER UK"),
+	(0x47a, "CYRILLIC CAPITAL LETTER ROUND OMEGA"),
+	(0x47b, "CYRILLIC SMALL LETTER ROUND OMEGA"),
+	(0x47c, "CYRILLIC CAPITAL LETTER OMEGA WITH TITLO"),
+	(0x47d, "CYRILLIC SMALL LETTER OMEGA WITH TITLO"),
+	(0x47e, "CYRILLIC CAPITAL LETTER OT"),
+	(0x47f, "CYRILLIC SMALL LETTER OT"),
+	(0x480, "CYRILLIC CAPITAL LETTER KOPPA"),
+	(0x481, "CYRILLIC SMALL LETTER KOPPA"),
+	(0x482, "CYRILLIC THOUSANDS SIGN"),
+	(0x483, "COMBINING CYRILLIC TITLO"),
+	(0x484, "COMBINING CYRILLIC PALATALIZATION"),
+	(0x485, "COMBINING CYRILLIC DASIA PNEUMATA"),
+	(0x486, "COMBINING CYRILLIC PSILI PNEUMATA"),
+	(0x487, "COMBINING CYRILLIC POKRYTIE"),
+	(0x488, "COMBINING CYRILLIC HUNDRED THOUSANDS SIGN"),
+	(0x489, "COMBINING CYRILLIC MILLIONS SIGN"),
+	(0x48a, "CYRILLIC CAPITAL LETTER SHORT I WITH TAIL"),
+	(0x48b, "CYRILLIC SMALL LETTER SHORT I WITH TAIL"),
+	(0x48c, "CYRILLIC CAPITAL LETTER SEMISOFT SIGN"),
+	(0x48d, "CYRILLIC SMALL LETTER SEMISOFT SIGN"),
+	(0x48e, "CYRILLIC CAPITAL LETTER ER WITH TICK"),
+	(0x48f, "CYRILLIC SMALL LETTER ER WITH TICK"),
+	(0x490, "CYRILLIC CAPITAL LETTER GHE WITH UPTURN"),
+	(0x491, "CYRILLIC SMALL LETTER GHE WITH UPTURN"),
+	(0x492, "CYRILLIC CAPITAL LETTER GHE WITH STROKE"),
+	(0x493, "CYRILLIC SMALL LETTER GHE WITH STROKE"),
+	(0x494, "CYRILLIC CAPITAL LETTER GHE WITH MIDDLE HOOK"),
+	(0x495, "CYRILLIC SMALL LETTER GHE WITH MIDDLE HOOK"),
+	(0x496, "CYRILLIC CAPITAL LETTER ZHE WITH DESCENDER"),
+	(0x497, "CYRILLIC SMALL LETTER ZHE WITH DESCENDER"),
+	(0x498, "CYRILLIC CAPITAL LETTER ZE WITH DESCENDER"),
+	(0x499, "CYRILLIC SMALL LETTER ZE WITH DESCENDER"),
+	(0x49a, "CYRILLIC CAPITAL LETTER KA WITH DESCENDER"),
+	(0x49b, "CYRILLIC SMALL LETTER KA WITH DESCENDER"),
+	(0x49c, "CYRILLIC CAPITAL LETTER KA WITH VERTICAL STROKE"),
+	(0x49d, "CYRILLIC SMALL LETTER KA WITH VERTICAL STROKE"),
+	(0x49e, "CYRILLIC CAPITAL LETTER KA WITH STROKE"),
+	(0x49f, "CYRILLIC SMALL LETTER KA WITH STROKE"),
+	(0x4a0, "CYRILLIC CAPITAL LETTER BASHKIR KA"),
+	(0x4a1, "CYRILLIC SMALL LETTER BASHKIR KA"),
+	(0x4a2, "CYRILLIC CAPITAL LETTER EN WITH DESCENDER"),
+	(0x4a3, "CYRILLIC SMALL LETTER EN WITH DESCENDER"),
+	(0x4a4, "CYRILLIC CAPITAL LIGATURE EN GHE"),
+	(0x4a5, "CYRILLIC SMALL LIGATURE EN GHE"),
+	(0x4a6, "CYRILLIC CAPITAL LETTER PE WITH MIDDLE HOOK"),
+	(0x4a7, "CYRILLIC SMALL LETTER PE WITH MIDDLE HOOK"),
+	(0x4a8, "CYRILLIC CAPITAL LETTER ABKHASIAN HA"),
+	(0x4a9, "CYRILLIC SMALL LETTER ABKHASIAN HA"),
+	(0x4aa, "CYRILLIC CAPITAL LETTER ES WITH DESCENDER"),
+	(0x4ab, "CYRILLIC SMALL LETTER ES WITH DESCENDER"),
+	(0x4ac, "CYRILLIC CAPITAL LETTER TE WITH DESCENDER"),
+	(0x4ad, "CYRILLIC SMALL LETTER TE WITH DESCENDER"),
+	(0x4ae, "CYRILLIC CAPITAL LETTER STRAIGHT U"),
+	(0x4af, "CYRILLIC SMALL LETTER STRAIGHT U"),
+	(0x4b0, "CYRILLIC CAPITAL LETTER STRAIGHT U WITH STROKE"),
+	(0x4b1, "CYRILLIC SMALL LETTER STRAIGHT U WITH STROKE"),
+	(0x4b2, "CYRILLIC CAPITAL LETTER HA WITH DESCENDER"),
+	(0x4b3, "CYRILLIC SMALL LETTER HA WITH DESCENDER"),
+	(0x4b4, "CYRILLIC CAPITAL LIGATURE TE TSE"),
+	(0x4b5, "CYRILLIC SMALL LIGATURE TE TSE"),
+	(0x4b6, "CYRILLIC CAPITAL LETTER CHE WITH DESCENDER"),
+	(0x4b7, "CYRILLIC SMALL LETTER CHE WITH DESCENDER"),
+	(0x4b8, "CYRILLIC CAPITAL LETTER CHE WITH VERTICAL STROKE"),
+	(0x4b9, "CYRILLIC SMALL LETTER CHE WITH VERTICAL STROKE"),
+	(0x4ba, "CYRILLIC CAPITAL LETTER SHHA"),
+	(0x4bb, "CYRILLIC SMALL LETTER SHHA"),
+	(0x4bc, "CYRILLIC CAPITAL LETTER ABKHASIAN CHE"),
+	(0x4bd, "CYRILLIC SMALL LETTER ABKHASIAN CHE"),
+	(0x4be, "CYRILLIC CAPITAL LETTER ABKHASIAN CHE WITH DESCENDER"),
+	(0x4bf, "CYRILLIC SMALL LETTER ABKHASIAN CHE WITH DESCENDER"),
+	(0x4c0, "CYRILLIC LETTER PALOCHKA"),
+	(0x4c1, "CYRILLIC CAPITAL LETTER ZHE WITH BREVE"),
+	(0x4c2, "CYRILLIC SMALL LETTER ZHE WITH BREVE"),
+	(0x4c3, "CYRILLIC CAPITAL LETTER KA WITH HOOK"),
+	(0x4c4, "CYRILLIC SMALL LETTER KA WITH HOOK"),
+	(0x4c5, "CYRILLIC CAPITAL LETTER EL WITH TAIL"),
+	(0x4c6, "CYRILLIC SMALL LETTER EL WITH TAIL"),
+	(0x4c7, "CYRILLIC CAPITAL LETTER EN WITH HOOK"),
+	(0x4c8, "CYRILLIC SMALL LETTER EN WITH HOOK"),
+	(0x4c9, "CYRILLIC CAPITAL LETTER EN WITH TAIL"),
+	(0x4ca, "CYRILLIC SMALL LETTER EN WITH TAIL"),
+	(0x4cb, "CYRILLIC CAPITAL LETTER KHAKASSIAN CHE"),
+	(0x4cc, "CYRILLIC SMALL LETTER KHAKASSIAN CHE"),
+	(0x4cd, "CYRILLIC CAPITAL LETTER EM WITH TAIL"),
+	(0x4ce, "CYRILLIC SMALL LETTER EM WITH TAIL"),
+	(0x4cf, "CYRILLIC SMALL LETTER PALOCHKA"),
+	(0x4d0, "CYRILLIC CAPITAL LETTER A WITH BREVE"),
+	(0x4d1, "CYRILLIC SMALL LETTER A WITH BREVE"),
+	(0x4d2, "CYRILLIC CAPITAL LETTER A WITH DIAERESIS"),
+	(0x4d3, "CYRILLIC SMALL LETTER A WITH DIAERESIS"),
+	(0x4d4, "CYRILLIC CAPITAL LIGATURE A IE"),
+	(0x4d5, "CYRILLIC SMALL LIGATURE A IE"),
+	(0x4d6, "CYRILLIC CAPITAL LETTER IE WITH BREVE"),
+	(0x4d7, "CYRILLIC SMALL LETTER IE WITH BREVE"),
+	(0x4d8, "CYRILLIC CAPITAL LETTER SCHWA"),
+	(0x4d9, "CYRILLIC SMALL LETTER SCHWA"),
+	(0x4da, "CYRILLIC CAPITAL LETTER SCHWA WITH DIAERESIS"),
+	(0x4db, "CYRILLIC SMALL LETTER SCHWA WITH DIAERESIS"),
+	(0x4dc, "CYRILLIC CAPITAL LETTER ZHE WITH DIAERESIS"),
+	(0x4dd, "CYRILLIC SMALL LETTER ZHE WITH DIAERESIS"),
+	(0x4de, "CYRILLIC CAPITAL LETTER ZE WITH DIAERESIS"),
+	(0x4df, "CYRILLIC SMALL LETTER ZE WITH DIAERESIS"),
+	(0x4e0, "CYRILLIC CAPITAL LETTER ABKHASIAN DZE"),
+	(0x4e1, "CYRILLIC SMALL LETTER ABKHASIAN DZE"),
+	(0x4e2, "CYRILLIC CAPITAL LETTER I WITH MACRON"),
+	(0x4e3, "CYRILLIC SMALL LETTER I WITH MACRON"),
+	(0x4e4, "CYRILLIC CAPITAL LETTER I WITH DIAERESIS"),
+	(0x4e5, "CYRILLIC SMALL LETTER I WITH DIAERESIS"),
+	(0x4e6, "CYRILLIC CAPITAL LETTER O WITH DIAERESIS"),
+	(0x4e7, "CYRILLIC SMALL LETTER O WITH DIAERESIS"),
+	(0x4e8, "CYRILLIC CAPITAL LETTER BARRED O"),
+	(0x4e9, "CYRILLIC SMALL LETTER BARRED O"),
+	(0x4ea, "CYRILLIC CAPITAL LETTER BARRED O WITH DIAERESIS"),
+	(0x4eb, "CYRILLIC SMALL LETTER BARRED O WITH DIAERESIS"),
+	(0x4ec, "CYRILLIC CAPITAL LETTER E WITH DIAERESIS"),
+	(0x4ed, "CYRILLIC SMALL LETTER E WITH DIAERESIS"),
+	(0x4ee, "CYRILLIC CAPITAL LETTER U WITH MACRON"),
+	(0x4ef, "CYRILLIC SMALL LETTER U WITH MACRON"),
+	(0x4f0, "CYRILLIC CAPITAL LETTER U WITH DIAERESIS"),
+	(0x4f1, "CYRILLIC SMALL LETTER U WITH DIAERESIS"),
+	(0x4f2, "CYRILLIC CAPITAL LETTER U WITH DOUBLE ACUTE"),
+	(0x4f3, "CYRILLIC SMALL LETTER U WITH DOUBLE ACUTE"),
+	(0x4f4, "CYRILLIC CAPITAL LETTER CHE WITH DIAERESIS"),
+	(0x4f5, "CYRILLIC SMALL LETTER CHE WITH DIAERESIS"),
+	(0x4f6, "CYRILLIC CAPITAL LETTER GHE WITH DESCENDER"),
+	(0x4f7, "CYRILLIC SMALL LETTER GHE WITH DESCENDER"),
+	(0x4f8, "CYRILLIC CAPITAL LETTER YERU WITH DIAERESIS"),
+	(0x4f9, "CYRILLIC SMALL LETTER YERU WITH DIAERESIS"),
+	(0x4fa, "CYRILLIC CAPITAL LETTER GHE WITH STROKE AND HOOK"),
+	(0x4fb, "CYRILLIC SMALL LETTER GHE WITH STROKE AND HOOK"),
+	(0x4fc, "CYRILLIC CAPITAL LETTER HA WITH HOOK"),
+	(0x4fd, "CYRILLIC SMALL LETTER HA WITH HOOK"),
+	(0x4fe, "CYRILLIC CAPITAL LETTER HA WITH STROKE"),
+	(0x4ff, "CYRILLIC SMALL LETTER HA WITH STROKE"),
+	(0x500, "CYRILLIC CAPITAL LETTER KOMI DE"),
+	(0x501, "CYRILLIC SMALL LETTER KOMI DE"),
+	(0x502, "CYRILLIC CAPITAL LETTER KOMI DJE"),
+	(0x503, "CYRILLIC SMALL LETTER KOMI DJE"),
+	(0x504, "CYRILLIC CAPITAL LETTER KOMI ZJE"),
+	(0x505, "CYRILLIC SMALL LETTER KOMI ZJE"),
+	(0x506, "CYRILLIC CAPITAL LETTER KOMI DZJE"),
+	(0x507, "CYRILLIC SMALL LETTER KOMI DZJE"),
+	(0x508, "CYRILLIC CAPITAL LETTER KOMI LJE"),
+	(0x509, "CYRILLIC SMALL LETTER KOMI LJE"),
+	(0x50a, "CYRILLIC CAPITAL LETTER KOMI NJE"),
+	(0x50b, "CYRILLIC SMALL LETTER KOMI NJE"),
+	(0x50c, "CYRILLIC CAPITAL LETTER KOMI SJE"),
+	(0x50d, "CYRILLIC SMALL LETTER KOMI SJE"),
+	(0x50e, "CYRILLIC CAPITAL LETTER KOMI TJE"),
+	(0x50f, "CYRILLIC SMALL LETTER KOMI TJE"),
+	(0x510, "CYRILLIC CAPITAL LETTER REVERSED ZE"),
+	(0x511, "CYRILLIC SMALL LETTER REVERSED ZE"),
+	(0x512, "CYRILLIC CAPITAL LETTER EL WITH HOOK"),
+	(0x513, "CYRILLIC SMALL LETTER EL WITH HOOK"),
+	(0x514, "CYRILLIC CAPITAL LETTER LHA"),
+	(0x515, "CYRILLIC SMALL LETTER LHA"),
+	(0x516, "CYRILLIC CAPITAL LETTER RHA"),
+	(0x517, "CYRILLIC SMALL LETTER RHA"),
+	(0x518, "CYRILLIC CAPITAL LETTER YAE"),
+	(0x519, "CYRILLIC SMALL LETTER YAE"),
+	(0x51a, "CYRILLIC CAPITAL LETTER QA"),
+	(0x51b, "CYRILLIC SMALL LETTER QA"),
+	(0x51c, "CYRILLIC CAPITAL LETTER WE"),
+	(0x51d, "CYRILLIC SMALL LETTER WE"),
+	(0x51e, "CYRILLIC CAPITAL LETTER ALEUT KA"),
+	(0x51f, "CYRILLIC SMALL LETTER ALEUT KA"),
+	(0x520, "CYRILLIC CAPITAL LETTER EL WITH MIDDLE HOOK"),
+	(0x521, "CYRILLIC SMALL LETTER EL WITH MIDDLE HOOK"),
+	(0x522, "CYRILLIC CAPITAL LETTER EN WITH MIDDLE HOOK"),
+	(0x523, "CYRILLIC SMALL LETTER EN WITH MIDDLE HOOK"),
+	(0x524, "CYRILLIC CAPITAL LETTER PE WITH DESCENDER"),
+	(0x525, "CYRILLIC SMALL LETTER PE WITH DESCENDER"),
+	(0x526, "CYRILLIC CAPITAL LETTER SHHA WITH DESCENDER"),
+	(0x527, "CYRILLIC SMALL LETTER SHHA WITH DESCENDER"),
+	(0x528, "CYRILLIC CAPITAL LETTER EN WITH LEFT HOOK"),
+	(0x529, "CYRILLIC SMALL LETTER EN WITH LEFT HOOK"),
+	(0x52a, "CYRILLIC CAPITAL LETTER DZZHE"),
+	(0x52b, "CYRILLIC SMALL LETTER DZZHE"),
+	(0x52c, "CYRILLIC CAPITAL LETTER DCHE"),
+	(0x52d, "CYRILLIC SMALL LETTER DCHE"),
+	(0x52e, "CYRILLIC CAPITAL LETTER EL WITH DESCENDER"),
+	(0x52f, "CYRILLIC SMALL LETTER EL WITH DESCENDER"),
+	(0x531, "ARMENIAN CAPITAL LETTER AYB"),
+	(0x532, "ARMENIAN CAPITAL LETTER BEN"),
+	(0x533, "ARMENIAN CAPITAL LETTER GIM"),
+	(0x534, "ARMENIAN CAPITAL LETTER DA"),
+	(0x535, "ARMENIAN CAPITAL LETTER ECH"),
+	(0x536, "ARMENIAN CAPITAL LETTER ZA"),
+	(0x537, "ARMENIAN CAPITAL LETTER EH"),
+	(0x538, "ARMENIAN CAPITAL LETTER ET"),
+	(0x539, "ARMENIAN CAPITAL LETTER TO"),
+	(0x53a, "ARMENIAN CAPITAL LETTER ZHE"),
+	(0x53b, "ARMENIAN CAPITAL LETTER INI"),
+	(0x53c, "ARMENIAN CAPITAL LETTER LIWN"),
+	(0x53d, "ARMENIAN CAPITAL LETTER XEH"),
+	(0x53e, "ARMENIAN CAPITAL LETTER CA"),
+	(0x53f, "ARMENIAN CAPITAL LETTER KEN"),
+	(0x540, "ARMENIAN CAPITAL LETTER HO"),
+	(0x541, "ARMENIAN CAPITAL LETTER JA"),
+	(0x542, "ARMENIAN CAPITAL LETTER GHAD"),
+	(0x543, "ARMENIAN CAPITAL LETTER CHEH"),
+	(0x544, "ARMENIAN CAPITAL LETTER MEN"),
+	(0x545, "ARMENIAN CAPITAL LETTER YI"),
+	(0x546, "ARMENIAN CAPITAL LETTER NOW"),
+	(0x547, "ARMENIAN CAPITAL LETTER SHA"),
+	(0x548, "ARMENIAN CAPITAL LETTER VO"),
+	(0x549, "ARMENIAN CAPITAL LETTER CHA"),
+	(0x54a, "ARMENIAN CAPITAL LETTER PEH"),
+	(0x54b, "ARMENIAN CAPITAL LETTER JHEH"),
+	(0x54c, "ARMENIAN CAPITAL LETTER RA"),
+	(0x54d, "ARMENIAN CAPITAL LETTER SEH"),
+	(0x54e, "ARMENIAN CAPITAL LETTER VEW"),
+	(0x54f, "ARMENIAN CAPITAL LETTER TIWN"),
+	(0x550, "ARMENIAN CAPITAL LETTER REH"),
+	(0x551, "ARMENIAN CAPITAL LETTER CO"),
+	(0x552, "ARMENIAN CAPITAL LETTER YIWN"),
+	(0x553, "ARMENIAN CAPITAL LETTER PIWR"),
+	(0x554, "ARMENIAN CAPITAL LETTER KEH"),
+	(0x555, "ARMENIAN CAPITAL LETTER OH"),
+	(0x556, "ARMENIAN CAPITAL LETTER FEH"),
+	(0x559, "ARMENIAN MODIFIER LETTER LEFT HALF RING"),
+	(0x55a, "ARMENIAN APOSTROPHE"),
+	(0x55b, "ARMENIAN EMPHASIS MARK"),
+	(0x55c, "ARMENIAN EXCLAMATION MARK"),
+	(0x55d, "ARMENIAN COMMA"),
+	(0x55e, "ARMENIAN QUESTION MARK"),
+	(0x55f, "ARMENIAN ABBREVIATION MARK"),
+	(0x560, "ARMENIAN SMALL LETTER TURNED AYB"),
+	(0x561, "ARMENIAN SMALL LETTER AYB"),
+	(0x562, "ARMENIAN SMALL LETTER BEN"),
+	(0x563, "ARMENIAN SMALL LETTER GIM"),
+	(0x564, "ARMENIAN SMALL LETTER DA"),
+	(0x565, "ARMENIAN SMALL LETTER ECH"),
+	(0x566, "ARMENIAN SMALL LETTER ZA"),
+	(0x567, "ARMENIAN SMALL LETTER EH"),
+	(0x568, "ARMENIAN SMALL LETTER ET"),
+	(0x569, "ARMENIAN SMALL LETTER TO"),
+	(0x56a, "ARMENIAN SMALL LETTER ZHE"),
+	(0x56b, "ARMENIAN SMALL LETTER INI"),
+	(0x56c, "ARMENIAN SMALL LETTER LIWN"),
+	(0x56d, "ARMENIAN SMALL LETTER XEH"),
+	(0x56e, "ARMENIAN SMALL LETTER CA"),
+	(0x56f, "ARMENIAN SMALL LETTER KEN"),
+	(0x570, "ARMENIAN SMALL LETTER HO"),
+	(0x571, "ARMENIAN SMALL LETTER JA"),
+	(0x572, "ARMENIAN SMALL LETTER GHAD"),
+	(0x573, "ARMENIAN SMALL LETTER CHEH"),
+	(0x574, "ARMENIAN SMALL LETTER MEN"),
+	(0x575, "ARMENIAN SMALL LETTER YI"),
+	(0x576, "ARMENIAN SMALL LETTER NOW"),
+	(0x577, "ARMENIAN SMALL LETTER SHA"),
+	(0x578, "ARMENIAN SMALL LETTER VO"),
+	(0x579, "ARMENIAN SMALL LETTER CHA"),
+	(0x57a, "ARMENIAN SMALL LETTER PEH"),
+	(0x57b, "ARMENIAN SMALL LETTER JHEH"),
+	(0x57c, "ARMENIAN SMALL LETTER RA"),
+	(0x57d, "ARMENIAN SMALL LETTER SEH"),
+	(0x57e, "ARMENIAN SMALL LETTER VEW"),
+	(0x57f, "ARMENIAN SMALL LETTER TIWN"),
+	(0x580, "ARMENIAN SMALL LETTER REH"),
+	(0x581, "ARMENIAN SMALL LETTER CO"),
+	(0x582, "ARMENIAN SMALL LETTER YIWN"),
+	(0x583, "ARMENIAN SMALL LETTER PIWR"),
+	(0x584, "ARMENIAN SMALL LETTER KEH"),
+	(0x585, "ARMENIAN SMALL LETTER OH"),
+	(0x586, "ARMENIAN SMALL LETTER FEH"),
+	(0x587, "ARMENIAN SMALL LIGATURE ECH YIWN"),
+	(0x588, "ARMENIAN SMALL LETTER YI WITH STROKE"),
+	(0x589, "ARMENIAN FULL STOP"),
+	(0x58a, "ARMENIAN HYPHEN"),
+	(0x58d, "RIGHT-FACING ARMENIAN ETERNITY SIGN"),
+	(0x58e, "LEFT-FACING ARMENIAN ETERNITY SIGN"),
+	(0x58f, "ARMENIAN DRAM SIGN"),
+	(0x591, "HEBREW ACCENT ETNAHTA"),
+	(0x592, "HEBREW ACCENT SEGOL"),
+	(0x593, "HEBREW ACCENT SHALSHELET"),
+	(0x594, "HEBREW ACCENT ZAQEF QATAN"),
+	(0x595, "HEBREW ACCENT ZAQEF GADOL"),
+	(0x596, "HEBREW ACCENT TIPEHA"),
+	(0x597, "HEBREW ACCENT REVIA"),
+	(0x598, "HEBREW ACCENT ZARQA"),
+	(0x599, "HEBREW ACCENT PASHTA"),
+	(0x59a, "HEBREW ACCENT YETIV"),
+	(0x59b, "HEBREW ACCENT TEVIR"),
+	(0x59c, "HEBREW ACCENT GERESH"),
+	(0x59d, "HEBREW ACCENT GERESH MUQDAM"),
+	(0x59e, "HEBREW ACCENT GERSHAYIM"),
+	(0x59f, "HEBREW ACCENT QARNEY PARA"),
+	(0x5a0, "HEBREW ACCENT TELISHA GEDOLA"),
+	(0x5a1, "HEBREW ACCENT PAZER"),
+	(0x5a2, "HEBREW ACCENT ATNAH HAFUKH"),
+	(0x5a3, "HEBREW ACCENT MUNAH"),
+	(0x5a4, "HEBREW ACCENT MAHAPAKH"),
+	(0x5a5, "HEBREW ACCENT MERKHA"),
+	(0x5a6, "HEBREW ACCENT MERKHA KEFULA"),
+	(0x5a7, "HEBREW ACCENT DARGA"),
+	(0x5a8, "HEBREW ACCENT QADMA"),
+	(0x5a9, "HEBREW ACCENT TELISHA QETANA"),
+	(0x5aa, "HEBREW ACCENT YERAH BEN YOMO"),
+	(0x5ab, "HEBREW ACCENT OLE"),
+	(0x5ac, "HEBREW ACCENT ILUY"),
+	(0x5ad, "HEBREW ACCENT DEHI"),
+	(0x5ae, "HEBREW ACCENT ZINOR"),
+	(0x5af, "HEBREW MARK MASORA CIRCLE"),
+	(0x5b0, "HEBREW POINT SHEVA"),
+	(0x5b1, "HEBREW POINT HATAF SEGOL"),
+	(0x5b2, "HEBREW POINT HATAF PATAH"),
+	(0x5b3, "HEBREW POINT HATAF QAMATS"),
+	(0x5b4, "HEBREW POINT HIRIQ"),
+	(0x5b5, "HEBREW POINT TSERE"),
+	(0x5b6, "HEBREW POINT SEGOL"),
+	(0x5b7, "HEBREW POINT PATAH"),
+	(0x5b8, "HEBREW POINT QAMATS"),
+	(0x5b9, "HEBREW POINT HOLAM"),
+	(0x5ba, "HEBREW POINT HOLAM HASER FOR VAV"),
+	(0x5bb, "HEBREW POINT QUBUTS"),
+	(0x5bc, "HEBREW POINT DAGESH OR MAPIQ"),
+	(0x5bd, "HEBREW POINT METEG"),
+	(0x5be, "HEBREW PUNCTUATION MAQAF"),
+	(0x5bf, "HEBREW POINT RAFE"),
+	(0x5c0, "HEBREW PUNCTUATION PASEQ"),
+	(0x5c1, "HEBREW POINT SHIN DOT"),
+	(0x5c2, "HEBREW POINT SIN DOT"),
+	(0x5c3, "HEBREW PUNCTUATION SOF PASUQ"),
+	(0x5c4, "HEBREW MARK UPPER DOT"),
+	(0x5c5, "HEBREW MARK LOWER DOT"),
+	(0x5c6, "HEBREW PUNCTUATION NUN HAFUKHA"),
+	(0x5c7, "HEBREW POINT QAMATS QATAN"),
+	(0x5d0, "HEBREW LETTER ALEF"),
+	(0x5d1, "HEBREW LETTER BET"),
+	(0x5d2, "HEBREW LETTER GIMEL"),
+	(0x5d3, "HEBREW LETTER DALET"),
+	(0x5d4, "HEBREW LETTER HE"),
+	(0x5d5, "HEBREW LETTER VAV"),
+	(0x5d6, "HEBREW LETTER ZAYIN"),
+	(0x5d7, "HEBREW LETTER HET"),
+	(0x5d8, "HEBREW LETTER TET"),
+	(0x5d9, "HEBREW LETTER YOD"),
+	(0x5da, "HEBREW LETTER FINAL KAF"),
+	(0x5db, "HEBREW LETTER KAF"),
+	(0x5dc, "HEBREW LETTER LAMED"),
+	(0x5dd, "HEBREW LETTER FINAL MEM"),
+	(0x5de, "HEBREW LETTER MEM"),
+	(0x5df, "HEBREW LETTER FINAL NUN"),
+	(0x5e0, "HEBREW LETTER NUN"),
+	(0x5e1, "HEBREW LETTER SAMEKH"),
+	(0x5e2, "HEBREW LETTER AYIN"),
+	(0x5e3, "HEBREW LETTER FINAL PE"),
+	(0x5e4, "HEBREW LETTER PE"),
+	(0x5e5, "HEBREW LETTER FINAL TSADI"),
+	(0x5e6, "HEBREW LETTER TSADI"),
+	(0x5e7, "HEBREW LETTER QOF"),
+	(0x5e8, "HEBREW LETTER RESH"),
+	(0x5e9, "HEBREW LETTER SHIN"),
+	(0x5ea, "HEBREW LETTER TAV"),
+	(0x5ef, "HEBREW YOD TRIANGLE"),
+	(0x5f0, "HEBREW LIGATURE YIDDISH DOUBLE VAV"),
+	(0x5f1, "HEBREW LIGATURE YIDDISH VAV YOD"),
+	(0x5f2, "HEBREW LIGATURE YIDDISH DOUBLE YOD"),
+	(0x5f3, "HEBREW PUNCTUATION GERESH"),
+	(0x5f4, "HEBREW PUNCTUATION GERSHAYIM"),
+	(0x600, "ARABIC NUMBER SIGN"),
+	(0x601, "ARABIC SIGN SANAH"),
+	(0x602, "ARABIC FOOTNOTE MARKER"),
+	(0x603, "ARABIC SIGN SAFHA"),
+	(0x604, "ARABIC SIGN SAMVAT"),
+	(0x605, "ARABIC NUMBER MARK ABOVE"),
+	(0x606, "ARABIC-INDIC CUBE ROOT"),
+	(0x607, "ARABIC-INDIC FOURTH ROOT"),
+	(0x608, "ARABIC RAY"),
+	(0x609, "ARABIC-INDIC PER MILLE SIGN"),
+	(0x60a, "ARABIC-INDIC PER TEN THOUSAND SIGN"),
+	(0x60b, "AFGHANI SIGN"),
+	(0x60c, "ARABIC COMMA"),
+	(0x60d, "ARABIC DATE SEPARATOR"),
+	(0x60e, "ARABIC POETIC VERSE SIGN"),
+	(0x60f, "ARABIC SIGN MISRA"),
+	(0x610, "ARABIC SIGN SALLALLAHOU ALAYHE WASSALLAM"),
+	(0x611, "ARABIC SIGN ALAYHE ASSALLAM"),
+	(0x612, "ARABIC SIGN RAHMATULLAH ALAYHE"),
+	(0x613, "ARABIC SIGN RADI ALLAHOU ANHU"),
+	(0x614, "ARABIC SIGN TAKHALLUS"),
+	(0x615, "ARABIC SMALL HIGH TAH"),
+	(0x616, "ARABIC SMALL HIGH LIGATURE ALEF WITH LAM WITH YEH"),
+	(0x617, "ARABIC SMALL HIGH ZAIN"),
+	(0x618, "ARABIC SMALL FATHA"),
+	(0x619, "ARABIC SMALL DAMMA"),
+	(0x61a, "ARABIC SMALL KASRA"),
+	(0x61b, "ARABIC SEMICOLON"),
+	(0x61c, "ARABIC LETTER MARK"),
+	(0x61d, "ARABIC END OF TEXT MARK"),
+	(0x61e, "ARABIC TRIPLE DOT PUNCTUATION MARK"),
+	(0x61f, "ARABIC QUESTION MARK"),
+	(0x620, "ARABIC LETTER KASHMIRI YEH"),
+	(0x621, "ARABIC LETTER HAMZA"),
+	(0x622, "ARABIC LETTER ALEF WITH MADDA ABOVE"),
+	(0x623, "ARABIC LETTER ALEF WITH HAMZA ABOVE"),
+	(0x624, "ARABIC LETTER WAW WITH HAMZA ABOVE"),
+	(0x625, "ARABIC LETTER ALEF WITH HAMZA BELOW"),
+	(0x626, "ARABIC LETTER YEH WITH HAMZA ABOVE"),
+	(0x627, "ARABIC LETTER ALEF"),
+	(0x628, "ARABIC LETTER BEH"),
+	(0x629, "ARABIC LETTER TEH MARBUTA"),
+	(0x62a, "ARABIC LETTER TEH"),
+	(0x62b, "ARABIC LETTER THEH"),
+	(0x62c, "ARABIC LETTER JEEM"),
+	(0x62d, "ARABIC LETTER HAH"),
+	(0x62e, "ARABIC LETTER KHAH"),
+	(0x62f, "ARABIC LETTER DAL"),
+	(0x630, "ARABIC LETTER THAL"),
+	(0x631, "ARABIC LETTER REH"),
+	(0x632, "ARABIC LETTER ZAIN"),
+	(0x633, "ARABIC LETTER SEEN"),
+	(0x634, "ARABIC LETTER SHEEN"),
+	(0x635, "ARABIC LETTER SAD"),
+	(0x636, "ARABIC LETTER DAD"),
+	(0x637, "ARABIC LETTER TAH"),
+	(0x638, "ARABIC LETTER ZAH"),
+	(0x639, "ARABIC LETTER AIN"),
+	(0x63a, "ARABIC LETTER GHAIN"),
+	(0x63b, "ARABIC LETTER KEHEH WITH TWO DOTS ABOVE"),
+	(0x63c, "ARABIC LETTER KEHEH WITH THREE DOTS BELOW"),
+	(0x63d, "ARABIC LETTER FARSI YEH WITH INVERTED V"),
+	(0x63e, "ARABIC LETTER FARSI YEH WITH TWO DOTS ABOVE"),
+	(0x63f, "ARABIC LETTER FARSI YEH WITH THREE DOTS ABOVE"),
+	(0x640, "ARABIC TATWEEL"),
+	(0x641, "ARABIC LETTER FEH"),
+	(0x642, "ARABIC LETTER QAF"),
+	(0x643, "ARABIC LETTER KAF"),
+	(0x644, "ARABIC LETTER LAM"),
+	(0x645, "ARABIC LETTER MEEM"),
+	(0x646, "ARABIC LETTER NOON"),
+	(0x647, "ARABIC LETTER HEH"),
+	(0x648, "ARABIC LETTER WAW"),
+	(0x649, "ARABIC LETTER ALEF MAKSURA"),
+	(0x64a, "ARABIC LETTER YEH"),
+	(0x64b, "ARABIC FATHATAN"),
+	(0x64c, "ARABIC DAMMATAN"),
+	(0x64d, "ARABIC KASRATAN"),
+	(0x64e, "ARABIC FATHA"),
+	(0x64f, "ARABIC DAMMA"),
+	(0x650, "ARABIC KASRA"),
+	(0x651, "ARABIC SHADDA"),
+	(0x652, "ARABIC SUKUN"),
+	(0x653, "ARABIC MADDAH ABOVE"),
+	(0x654, "ARABIC HAMZA ABOVE"),
+	(0x655, "ARABIC HAMZA BELOW"),
+	(0x656, "ARABIC SUBSCRIPT ALEF"),
+	(0x657, "ARABIC INVERTED DAMMA"),
+	(0x658, "ARABIC MARK NOON GHUNNA"),
+	(0x659, "ARABIC ZWARAKAY"),
+	(0x65a, "ARABIC VOWEL SIGN SMALL V ABOVE"),
+	(0x65b, "ARABIC VOWEL SIGN INVERTED SMALL V ABOVE"),
+	(0x65c, "ARABIC VOWEL SIGN DOT BELOW"),
+	(0x65d, "ARABIC REVERSED DAMMA"),
+	(0x65e, "ARABIC FATHA WITH TWO DOTS"),
+	(0x65f, "ARABIC WAVY HAMZA BELOW"),
+	(0x660, "ARABIC-INDIC DIGIT ZERO"),
+	(0x661, "ARABIC-INDIC DIGIT ONE"),
+	(0x662, "ARABIC-INDIC DIGIT TWO"),
+	(0x663, "ARABIC-INDIC DIGIT THREE"),
+	(0x664, "ARABIC-INDIC DIGIT FOUR"),
+	(0x665, "ARABIC-INDIC DIGIT FIVE"),
+	(0x666, "ARABIC-INDIC DIGIT SIX"),
+	(0x667, "ARABIC-INDIC DIGIT SEVEN"),
+	(0x668, "ARABIC-INDIC DIGIT EIGHT"),
+	(0x669, "ARABIC-INDIC DIGIT NINE"),
+	(0x66a, "ARABIC PERCENT SIGN"),
+	(0x66b, "ARABIC DECIMAL SEPARATOR"),
+	(0x66c, "ARABIC THOUSANDS SEPARATOR"),
+	(0x66d, "ARABIC FIVE POINTED STAR"),
+	(0x66e, "ARABIC LETTER DOTLESS BEH"),
+	(0x66f, "ARABIC LETTER DOTLESS QAF"),
+	(0x670, "ARABIC LETTER SUPERSCRIPT ALEF"),
+	(0x671, "ARABIC LETTER ALEF WASLA"),
+	(0x672, "ARABIC LETTER ALEF WITH WAVY HAMZA ABOVE"),
+	(0x673, "ARABIC LETTER ALEF WITH WAVY HAMZA BELOW"),
+	(0x674, "ARABIC LETTER HIGH HAMZA"),
+	(0x675, "ARABIC LETTER HIGH HAMZA ALEF"),
+	(0x676, "ARABIC LETTER HIGH HAMZA WAW"),
+	(0x677, "ARABIC LETTER U WITH HAMZA ABOVE"),
+	(0x678, "ARABIC LETTER HIGH HAMZA YEH"),
+	(0x679, "ARABIC LETTER TTEH"),
+	(0x67a, "ARABIC LETTER TTEHEH"),
+	(0x67b, "ARABIC LETTER BEEH"),
+	(0x67c, "ARABIC LETTER TEH WITH RING"),
+	(0x67d, "ARABIC LETTER TEH WITH THREE DOTS ABOVE DOWNWARDS"),
+	(0x67e, "ARABIC LETTER PEH"),
+	(0x67f, "ARABIC LETTER TEHEH"),
+	(0x680, "ARABIC LETTER BEHEH"),
+	(0x681, "ARABIC LETTER HAH WITH HAMZA ABOVE"),
+	(0x682, "ARABIC LETTER HAH WITH TWO DOTS VERTICAL ABOVE"),
+	(0x683, "ARABIC LETTER NYEH"),
+	(0x684, "ARABIC LETTER DYEH"),
+	(0x685, "ARABIC LETTER HAH WITH THREE DOTS ABOVE"),
+	(0x686, "ARABIC LETTER TCHEH"),
+	(0x687, "ARABIC LETTER TCHEHEH"),
+	(0x688, "ARABIC LETTER DDAL"),
+	(0x689, "ARABIC LETTER DAL WITH RING"),
+	(0x68a, "ARABIC LETTER DAL WITH DOT BELOW"),
+	(0x68b, "ARABIC LETTER DAL WITH DOT BELOW AND SMALL TAH"),
+	(0x68c, "ARABIC LETTER DAHAL"),
+	(0x68d, "ARABIC LETTER DDAHAL"),
+	(0x68e, "ARABIC LETTER DUL"),
+	(0x68f, "ARABIC LETTER DAL WITH THREE DOTS ABOVE DOWNWARDS"),
+	(0x690, "ARABIC LETTER DAL WITH FOUR DOTS ABOVE"),
+	(0x691, "ARABIC LETTER RREH"),
+	(0x692, "ARABIC LETTER REH WITH SMALL V"),
+	(0x693, "ARABIC LETTER REH WITH RING"),
+	(0x694, "ARABIC LETTER REH WITH DOT BELOW"),
+	(0x695, "ARABIC LETTER REH WITH SMALL V BELOW"),
+	(0x696, "ARABIC LETTER REH WITH DOT BELOW AND DOT ABOVE"),
+	(0x697, "ARABIC LETTER REH WITH TWO DOTS ABOVE"),
+	(0x698, "ARABIC LETTER JEH"),
+	(0x699, "ARABIC LETTER REH WITH FOUR DOTS ABOVE"),
+	(0x69a, "ARABIC LETTER SEEN WITH DOT BELOW AND DOT ABOVE"),
+	(0x69b, "ARABIC LETTER SEEN WITH THREE DOTS BELOW"),
+	(0x69c, "ARABIC LETTER SEEN WITH THREE DOTS BELOW AND THREE DOTS ABOVE"),
+	(0x69d, "ARABIC LETTER SAD WITH TWO DOTS BELOW"),
+	(0x69e, "ARABIC LETTER SAD WITH THREE DOTS ABOVE"),
+	(0x69f, "ARABIC LETTER TAH WITH THREE DOTS ABOVE"),
+	(0x6a0, "ARABIC LETTER AIN WITH THREE DOTS ABOVE"),
+	(0x6a1, "ARABIC LETTER DOTLESS FEH"),
+	(0x6a2, "ARABIC LETTER FEH WITH DOT MOVED BELOW"),
+	(0x6a3, "ARABIC LETTER FEH WITH DOT BELOW"),
+	(0x6a4, "ARABIC LETTER VEH"),
+	(0x6a5, "ARABIC LETTER FEH WITH THREE DOTS BELOW"),
+	(0x6a6, "ARABIC LETTER PEHEH"),
+	(0x6a7, "ARABIC LETTER QAF WITH DOT ABOVE"),
+	(0x6a8, "ARABIC LETTER QAF WITH THREE DOTS ABOVE"),
+	(0x6a9, "ARABIC LETTER KEHEH"),
+	(0x6aa, "ARABIC LETTER SWASH KAF"),
+	(0x6ab, "ARABIC LETTER KAF WITH RING"),
+	(0x6ac, "ARABIC LETTER KAF WITH DOT ABOVE"),
+	(0x6ad, "ARABIC LETTER NG"),
+	(0x6ae, "ARABIC LETTER KAF WITH THREE DOTS BELOW"),
+	(0x6af, "ARABIC LETTER GAF"),
+	(0x6b0, "ARABIC LETTER GAF WITH RING"),
+	(0x6b1, "ARABIC LETTER NGOEH"),
+	(0x6b2, "ARABIC LETTER GAF WITH TWO DOTS BELOW"),
+	(0x6b3, "ARABIC LETTER GUEH"),
+	(0x6b4, "ARABIC LETTER GAF WITH THREE DOTS ABOVE"),
+	(0x6b5, "ARABIC LETTER LAM WITH SMALL V"),
+	(0x6b6, "ARABIC LETTER LAM WITH DOT ABOVE"),
+	(0x6b7, "ARABIC LETTER LAM WITH THREE DOTS ABOVE"),
+	(0x6b8, "ARABIC LETTER LAM WITH THREE DOTS BELOW"),
+	(0x6b9, "ARABIC LETTER NOON WITH DOT BELOW"),
+	(0x6ba, "ARABIC LETTER NOON GHUNNA"),
+	(0x6bb, "ARABIC LETTER RNOON"),
+	(0x6bc, "ARABIC LETTER NOON WITH RING"),
+	(0x6bd, "ARABIC LETTER NOON WITH THREE DOTS ABOVE"),
+	(0x6be, "ARABIC LETTER HEH DOACHASHMEE"),
+	(0x6bf, "ARABIC LETTER TCHEH WITH DOT ABOVE"),
+	(0x6c0, "ARABIC LETTER HEH WITH YEH ABOVE"),
+	(0x6c1, "ARABIC LETTER HEH GOAL"),
+	(0x6c2, "ARABIC LETTER HEH GOAL WITH HAMZA ABOVE"),
+	(0x6c3, "ARABIC LETTER TEH MARBUTA GOAL"),
+	(0x6c4, "ARABIC LETTER WAW WITH RING"),
+	(0x6c5, "ARABIC LETTER KIRGHIZ OE"),
+	(0x6c6, "ARABIC LETTER OE"),
+	(0x6c7, "ARABIC LETTER U"),
+	(0x6c8, "ARABIC LETTER YU"),
+	(0x6c9, "ARABIC LETTER KIRGHIZ YU"),
+	(0x6ca, "ARABIC LETTER WAW WITH TWO DOTS ABOVE"),
+	(0x6cb, "ARABIC LETTER VE"),
+	(0x6cc, "ARABIC LETTER FARSI YEH"),
+	(0x6cd, "ARABIC LETTER YEH WITH TAIL"),
+	(0x6ce, "ARABIC LETTER YEH WITH SMALL V"),
+	(0x6cf, "ARABIC LETTER WAW WITH DOT ABOVE"),
+	(0x6d0, "ARABIC LETTER E"),
+	(0x6d1, "ARABIC LETTER YEH WITH THREE DOTS BELOW"),
+	(0x6d2, "ARABIC LETTER YEH BARREE"),
+	(0x6d3, "ARABIC LETTER YEH BARREE WITH HAMZA ABOVE"),
+	(0x6d4, "ARABIC FULL STOP"),
+	(0x6d5, "ARABIC LETTER AE"),
+	(0x6d6, "ARABIC SMALL HIGH LIGATURE SAD WITH LAM WITH ALEF MAKSURA"),
+	(0x6d7, "ARABIC SMALL HIGH LIGATURE QAF WITH LAM WITH ALEF MAKSURA"),
+	(0x6d8, "ARABIC SMALL HIGH MEEM INITIAL FORM"),
+	(0x6d9, "ARABIC SMALL HIGH LAM ALEF"),
+	(0x6da, "ARABIC SMALL HIGH JEEM"),
+	(0x6db, "ARABIC SMALL HIGH THREE DOTS"),
+	(0x6dc, "ARABIC SMALL HIGH SEEN"),
+	(0x6dd, "ARABIC END OF AYAH"),
+	(0x6de, "ARABIC START OF RUB EL HIZB"),
+	(0x6df, "ARABIC SMALL HIGH ROUNDED ZERO"),
+	(0x6e0, "ARABIC SMALL HIGH UPRIGHT RECTANGULAR ZERO"),
+	(0x6e1, "ARABIC SMALL HIGH DOTLESS HEAD OF KHAH"),
+	(0x6e2, "ARABIC SMALL HIGH MEEM ISOLATED FORM"),
+	(0x6e3, "ARABIC SMALL LOW SEEN"),
+	(0x6e4, "ARABIC SMALL HIGH MADDA"),
+	(0x6e5, "ARABIC SMALL WAW"),
+	(0x6e6, "ARABIC SMALL YEH"),
+	(0x6e7, "ARABIC SMALL HIGH YEH"),
+	(0x6e8, "ARABIC SMALL HIGH NOON"),
+	(0x6e9, "ARABIC PLACE OF SAJDAH"),
+	(0x6ea, "ARABIC EMPTY CENTRE LOW STOP"),
+	(0x6eb, "ARABIC EMPTY CENTRE HIGH STOP"),
+	(0x6ec, "ARABIC ROUNDED HIGH STOP WITH FILLED CENTRE"),
+	(0x6ed, "ARABIC SMALL LOW MEEM"),
+	(0x6ee, "ARABIC LETTER DAL WITH INVERTED V"),
+	(0x6ef, "ARABIC LETTER REH WITH INVERTED V"),
+	(0x6f0, "EXTENDED ARABIC-INDIC DIGIT ZERO"),
+	(0x6f1, "EXTENDED ARABIC-INDIC DIGIT ONE"),
+	(0x6f2, "EXTENDED ARABIC-INDIC DIGIT TWO"),
+	(0x6f3, "EXTENDED ARABIC-INDIC DIGIT THREE"),
+	(0x6f4, "EXTENDED ARABIC-INDIC DIGIT FOUR"),
+	(0x6f5, "EXTENDED ARABIC-INDIC DIGIT FIVE"),
+	(0x6f6, "EXTENDED ARABIC-INDIC DIGIT SIX"),
+	(0x6f7, "EXTENDED ARABIC-INDIC DIGIT SEVEN"),
+	(0x6f8, "EXTENDED ARABIC-INDIC DIGIT EIGHT"),
+	(0x6f9, "EXTENDED ARABIC-INDIC DIGIT NINE"),
+	(0x6fa, "ARABIC LETTER SHEEN WITH DOT BELOW"),
+	(0x6fb, "ARABIC LETTER DAD WITH DOT BELOW"),
+	(0x6fc, "ARABIC LETTER GHAIN WITH DOT BELOW"),
+	(0x6fd, "ARABIC SIGN SINDHI AMPERSAND"),
+	(0x6fe, "ARABIC SIGN SINDHI POSTPOSITION MEN"),
+	(0x6ff, "ARABIC LETTER HEH WITH INVERTED V"),
+	(0x700, "SYRIAC END OF PARAGRAPH"),
+	(0x701, "SYRIAC SUPRALINEAR FULL STOP"),
+	(0x702, "SYRIAC SUBLINEAR FULL STOP"),
+	(0x703, "SYRIAC SUPRALINEAR COLON"),
+	(0x704, "SYRIAC SUBLINEAR COLON"),
+	(0x705, "SYRIAC HORIZONTAL COLON"),
+	(0x706, "SYRIAC COLON SKEWED LEFT"),
+	(0x707, "SYRIAC COLON SKEWED RIGHT"),
+	(0x708, "SYRIAC SUPRALINEAR COLON SKEWED LEFT"),
+	(0x709, "SYRIAC SUBLINEAR COLON SKEWED RIGHT"),
+	(0x70a, "SYRIAC CONTRACTION"),
+	(0x70b, "SYRIAC HARKLEAN OBELUS"),
+	(0x70c, "SYRIAC HARKLEAN METOBELUS"),
+	(0x70d, "SYRIAC HARKLEAN ASTERISCUS"),
+	(0x70f, "SYRIAC ABBREVIATION MARK"),
+	(0x710, "SYRIAC LETTER ALAPH"),
+	(0x711, "SYRIAC LETTER SUPERSCRIPT ALAPH"),
+	(0x712, "SYRIAC LETTER BETH"),
+	(0x713, "SYRIAC LETTER GAMAL"),
+	(0x714, "SYRIAC LETTER GAMAL GARSHUNI"),
+	(0x715, "SYRIAC LETTER DALATH"),
+	(0x716, "SYRIAC LETTER DOTLESS DALATH RISH"),
+	(0x717, "SYRIAC LETTER HE"),
+	(0x718, "SYRIAC LETTER WAW"),
+	(0x719, "SYRIAC LETTER ZAIN"),
+	(0x71a, "SYRIAC LETTER HETH"),
+	(0x71b, "SYRIAC LETTER TETH"),
+	(0x71c, "SYRIAC LETTER TETH GARSHUNI"),
+	(0x71d, "SYRIAC LETTER YUDH"),
+	(0x71e, "SYRIAC LETTER YUDH HE"),
+	(0x71f, "SYRIAC LETTER KAPH"),
+	(0x720, "SYRIAC LETTER LAMADH"),
+	(0x721, "SYRIAC LETTER MIM"),
+	(0x722, "SYRIAC LETTER NUN"),
+	(0x723, "SYRIAC LETTER SEMKATH"),
+	(0x724, "SYRIAC LETTER FINAL SEMKATH"),
+	(0x725, "SYRIAC LETTER E"),
+	(0x726, "SYRIAC LETTER PE"),
+	(0x727, "SYRIAC LETTER REVERSED PE"),
+	(0x728, "SYRIAC LETTER SADHE"),
+	(0x729, "SYRIAC LETTER QAPH"),
+	(0x72a, "SYRIAC LETTER RISH"),
+	(0x72b, "SYRIAC LETTER SHIN"),
+	(0x72c, "SYRIAC LETTER TAW"),
+	(0x72d, "SYRIAC LETTER PERSIAN BHETH"),
+	(0x72e, "SYRIAC LETTER PERSIAN GHAMAL"),
+	(0x72f, "SYRIAC LETTER PERSIAN DHALATH"),
+	(0x730, "SYRIAC PTHAHA ABOVE"),
+	(0x731, "SYRIAC PTHAHA BELOW"),
+	(0x732, "SYRIAC PTHAHA DOTTED"),
+	(0x733, "SYRIAC ZQAPHA ABOVE"),
+	(0x734, "SYRIAC ZQAPHA BELOW"),
+	(0x735, "SYRIAC ZQAPHA DOTTED"),
+	(0x736, "SYRIAC RBASA ABOVE"),
+	(0x737, "SYRIAC RBASA BELOW"),
+	(0x738, "SYRIAC DOTTED ZLAMA HORIZONTAL"),
+	(0x739, "SYRIAC DOTTED ZLAMA ANGULAR"),
+	(0x73a, "SYRIAC HBASA ABOVE"),
+	(0x73b, "SYRIAC HBASA BELOW"),
+	(0x73c, "SYRIAC HBASA-ESASA DOTTED"),
+	(0x73d, "SYRIAC ESASA ABOVE"),
+	(0x73e, "SYRIAC ESASA BELOW"),
+	(0x73f, "SYRIAC RWAHA"),
+	(0x740, "SYRIAC FEMININE DOT"),
+	(0x741, "SYRIAC QUSHSHAYA"),
+	(0x742, "SYRIAC RUKKAKHA"),
+	(0x743, "SYRIAC TWO VERTICAL DOTS ABOVE"),
+	(0x744, "SYRIAC TWO VERTICAL DOTS BELOW"),
+	(0x745, "SYRIAC THREE DOTS ABOVE"),
+	(0x746, "SYRIAC THREE DOTS BELOW"),
+	(0x747, "SYRIAC OBLIQUE LINE ABOVE"),
+	(0x748, "SYRIAC OBLIQUE LINE BELOW"),
+	(0x749, "SYRIAC MUSIC"),
+	(0x74a, "SYRIAC BARREKH"),
+	(0x74d, "SYRIAC LETTER SOGDIAN ZHAIN"),
+	(0x74e, "SYRIAC LETTER SOGDIAN KHAPH"),
+	(0x74f, "SYRIAC LETTER SOGDIAN FE"),
+	(0x750, "ARABIC LETTER BEH WITH THREE DOTS HORIZONTALLY BELOW"),
+	(0x751, "ARABIC LETTER BEH WITH DOT BELOW AND THREE DOTS ABOVE"),
+	(0x752, "ARABIC LETTER BEH WITH THREE DOTS POINTING UPWARDS BELOW"),
+	(0x753, "ARABIC LETTER BEH WITH THREE DOTS POINTING UPWARDS BELOW AND TWO DOTS ABOVE"),
+	(0x754, "ARABIC LETTER BEH WITH TWO DOTS BELOW AND DOT ABOVE"),
+	(0x755, "ARABIC LETTER BEH WITH INVERTED SMALL V BELOW"),
+	(0x756, "ARABIC LETTER BEH WITH SMALL V"),
+	(0x757, "ARABIC LETTER HAH WITH TWO DOTS ABOVE"),
+	(0x758, "ARABIC LETTER HAH WITH THREE DOTS POINTING UPWARDS BELOW"),
+	(0x759, "ARABIC LETTER DAL WITH TWO DOTS VERTICALLY BELOW AND SMALL TAH"),
+	(0x75a, "ARABIC LETTER DAL WITH INVERTED SMALL V BELOW"),
+	(0x75b, "ARABIC LETTER REH WITH STROKE"),
+	(0x75c, "ARABIC LETTER SEEN WITH FOUR DOTS ABOVE"),
+	(0x75d, "ARABIC LETTER AIN WITH TWO DOTS ABOVE"),
+	(0x75e, "ARABIC LETTER AIN WITH THREE DOTS POINTING DOWNWARDS ABOVE"),
+	(0x75f, "ARABIC LETTER AIN WITH TWO DOTS VERTICALLY ABOVE"),
+	(0x760, "ARABIC LETTER FEH WITH TWO DOTS BELOW"),
+	(0x761, "ARABIC LETTER FEH WITH THREE DOTS POINTING UPWARDS BELOW"),
+	(0x762, "ARABIC LETTER KEHEH WITH DOT ABOVE"),
+	(0x763, "ARABIC LETTER KEHEH WITH THREE DOTS ABOVE"),
+	(0x764, "ARABIC LETTER KEHEH WITH THREE DOTS POINTING UPWARDS BELOW"),
+	(0x765, "ARABIC LETTER MEEM WITH DOT ABOVE"),
+	(0x766, "ARABIC LETTER MEEM WITH DOT BELOW"),
+	(0x767, "ARABIC LETTER NOON WITH TWO DOTS BELOW"),
+	(0x768, "ARABIC LETTER NOON WITH SMALL TAH"),
+	(0x769, "ARABIC LETTER NOON WITH SMALL V"),
+	(0x76a, "ARABIC LETTER LAM WITH BAR"),
+	(0x76b, "ARABIC LETTER REH WITH TWO DOTS VERTICALLY ABOVE"),
+	(0x76c, "ARABIC LETTER REH WITH HAMZA ABOVE"),
+	(0x76d, "ARABIC LETTER SEEN WITH TWO DOTS VERTICALLY ABOVE"),
+	(0x76e, "ARABIC LETTER HAH WITH SMALL ARABIC LETTER TAH BELOW"),
+	(0x76f, "ARABIC LETTER HAH WITH SMALL ARABIC LETTER TAH AND TWO DOTS"),
+	(0x770, "ARABIC LETTER SEEN WITH SMALL ARABIC LETTER TAH AND TWO DOTS"),
+	(0x771, "ARABIC LETTER REH WITH SMALL ARABIC LETTER TAH AND TWO DOTS"),
+	(0x772, "ARABIC LETTER HAH WITH SMALL ARABIC LETTER TAH ABOVE"),
+	(0x773, "ARABIC LETTER ALEF WITH EXTENDED ARABIC-INDIC DIGIT TWO ABOVE"),
+	(0x774, "ARABIC LETTER ALEF WITH EXTENDED ARABIC-INDIC DIGIT THREE ABOVE"),
+	(0x775, "ARABIC LETTER FARSI YEH WITH EXTENDED ARABIC-INDIC DIGIT TWO ABOVE"),
+	(0x776, "ARABIC LETTER FARSI YEH WITH EXTENDED ARABIC-INDIC DIGIT THREE ABOVE"),
+	(0x777, "ARABIC LETTER FARSI YEH WITH EXTENDED ARABIC-INDIC DIGIT FOUR BELOW"),
+	(0x778, "ARABIC LETTER WAW WITH EXTENDED ARABIC-INDIC DIGIT TWO ABOVE"),
+	(0x779, "ARABIC LETTER WAW WITH EXTENDED ARABIC-INDIC DIGIT THREE ABOVE"),
+	(0x77a, "ARABIC LETTER YEH BARREE WITH EXTENDED ARABIC-INDIC DIGIT TWO ABOVE"),
+	(0x77b, "ARABIC LETTER YEH BARREE WITH EXTENDED ARABIC-INDIC DIGIT THREE ABOVE"),
+	(0x77c, "ARABIC LETTER HAH WITH EXTENDED ARABIC-INDIC DIGIT FOUR BELOW"),
+	(0x77d, "ARABIC LETTER SEEN WITH EXTENDED ARABIC-INDIC DIGIT FOUR ABOVE"),
+	(0x77e, "ARABIC LETTER SEEN WITH INVERTED V"),
+	(0x77f, "ARABIC LETTER KAF WITH TWO DOTS ABOVE"),
+	(0x780, "THAANA LETTER HAA"),
+	(0x781, "THAANA LETTER SHAVIYANI"),
+	(0x782, "THAANA LETTER NOONU"),
+	(0x783, "THAANA LETTER RAA"),
+	(0x784, "THAANA LETTER BAA"),
+	(0x785, "THAANA LETTER LHAVIYANI"),
+	(0x786, "THAANA LETTER KAAFU"),
+	(0x787, "THAANA LETTER ALIFU"),
+	(0x788, "THAANA LETTER VAAVU"),
+	(0x789, "THAANA LETTER MEEMU"),
+	(0x78a, "THAANA LETTER FAAFU"),
+	(0x78b, "THAANA LETTER DHAALU"),
+	(0x78c, "THAANA LETTER THAA"),
+	(0x78d, "THAANA LETTER LAAMU"),
+	(0x78e, "THAANA LETTER GAAFU"),
+	(0x78f, "THAANA LETTER GNAVIYANI"),
+	(0x790, "THAANA LETTER SEENU"),
+	(0x791, "THAANA LETTER DAVIYANI"),
+	(0x792, "THAANA LETTER ZAVIYANI"),
+	(0x793, "THAANA LETTER TAVIYANI"),
+	(0x794, "THAANA LETTER YAA"),
+	(0x795, "THAANA LETTER PAVIYANI"),
+	(0x796, "THAANA LETTER JAVIYANI"),
+	(0x797, "THAANA LETTER CHAVIYANI"),
+	(0x798, "THAANA LETTER TTAA"),
+	(0x799, "THAANA LETTER HHAA"),
+	(0x79a, "THAANA LETTER KHAA"),
+	(0x79b, "THAANA LETTER THAALU"),
+	(0x79c, "THAANA LETTER ZAA"),
+	(0x79d, "THAANA LETTER SHEENU"),
+	(0x79e, "THAANA LETTER SAADHU"),
+	(0x79f, "THAANA LETTER DAADHU"),
+	(0x7a0, "THAANA LETTER TO"),
+	(0x7a1, "THAANA LETTER ZO"),
+	(0x7a2, "THAANA LETTER AINU"),
+	(0x7a3, "THAANA LETTER GHAINU"),
+	(0x7a4, "THAANA LETTER QAAFU"),
+	(0x7a5, "THAANA LETTER WAAVU"),
+	(0x7a6, "THAANA ABAFILI"),
+	(0x7a7, "THAANA AABAAFILI"),
+	(0x7a8, "THAANA IBIFILI"),
+	(0x7a9, "THAANA EEBEEFILI"),
+	(0x7aa, "THAANA UBUFILI"),
+	(0x7ab, "THAANA OOBOOFILI"),
+	(0x7ac, "THAANA EBEFILI"),
+	(0x7ad, "THAANA EYBEYFILI"),
+	(0x7ae, "THAANA OBOFILI"),
+	(0x7af, "THAANA OABOAFILI"),
+	(0x7b0, "THAANA SUKUN"),
+	(0x7b1, "THAANA LETTER NAA"),
+	(0x7c0, "NKO DIGIT ZERO"),
+	(0x7c1, "NKO DIGIT ONE"),
+	(0x7c2, "NKO DIGIT TWO"),
+	(0x7c3, "NKO DIGIT THREE"),
+	(0x7c4, "NKO DIGIT FOUR"),
+	(0x7c5, "NKO DIGIT FIVE"),
+	(0x7c6, "NKO DIGIT SIX"),
+	(0x7c7, "NKO DIGIT SEVEN"),
+	(0x7c8, "NKO DIGIT EIGHT"),
+	(0x7c9, "NKO DIGIT NINE"),
+	(0x7ca, "NKO LETTER A"),
+	(0x7cb, "NKO LETTER EE"),
+	(0x7cc, "NKO LETTER I"),
+	(0x7cd, "NKO LETTER E"),
+	(0x7ce, "NKO LETTER U"),
+	(0x7cf, "NKO LETTER OO"),
+	(0x7d0, "NKO LETTER O"),
+	(0x7d1, "NKO LETTER DAGBASINNA"),
+	(0x7d2, "NKO LETTER N"),
+	(0x7d3, "NKO LETTER BA"),
+	(0x7d4, "NKO LETTER PA"),
+	(0x7d5, "NKO LETTER TA"),
+	(0x7d6, "NKO LETTER JA"),
+	(0x7d7, "NKO LETTER CHA"),
+	(0x7d8, "NKO LETTER DA"),
+	(0x7d9, "NKO LETTER RA"),
+	(0x7da, "NKO LETTER RRA"),
+	(0x7db, "NKO LETTER SA"),
+	(0x7dc, "NKO LETTER GBA"),
+	(0x7dd, "NKO LETTER FA"),
+	(0x7de, "NKO LETTER KA"),
+	(0x7df, "NKO LETTER LA"),
+	(0x7e0, "NKO LETTER NA WOLOSO"),
+	(0x7e1, "NKO LETTER MA"),
+	(0x7e2, "NKO LETTER NYA"),
+	(0x7e3, "NKO LETTER NA"),
+	(0x7e4, "NKO LETTER HA"),
+	(0x7e5, "NKO LETTER WA"),
+	(0x7e6, "NKO LETTER YA"),
+	(0x7e7, "NKO LETTER NYA WOLOSO"),
+	(0x7e8, "NKO LETTER JONA JA"),
+	(0x7e9, "NKO LETTER JONA CHA"),
+	(0x7ea, "NKO LETTER JONA RA"),
+	(0x7eb, "NKO COMBINING SHORT HIGH TONE"),
+	(0x7ec, "NKO COMBINING SHORT LOW TONE"),
+	(0x7ed, "NKO COMBINING SHORT RISING TONE"),
+	(0x7ee, "NKO COMBINING LONG DESCENDING TONE"),
+	(0x7ef, "NKO COMBINING LONG HIGH TONE"),
+	(0x7f0, "NKO COMBINING LONG LOW TONE"),
+	(0x7f1, "NKO COMBINING LONG RISING TONE"),
+	(0x7f2, "NKO COMBINING NASALIZATION MARK"),
+	(0x7f3, "NKO COMBINING DOUBLE DOT ABOVE"),
+	(0x7f4, "NKO HIGH TONE APOSTROPHE"),
+	(0x7f5, "NKO LOW TONE APOSTROPHE"),
+	(0x7f6, "NKO SYMBOL OO DENNEN"),
+	(0x7f7, "NKO SYMBOL GBAKURUNEN"),
+	(0x7f8, "NKO COMMA"),
+	(0x7f9, "NKO EXCLAMATION MARK"),
+	(0x7fa, "NKO LAJANYALAN"),
+	(0x7fd, "NKO DANTAYALAN"),
+	(0x7fe, "NKO DOROME SIGN"),
+	(0x7ff, "NKO TAMAN SIGN"),
+	(0x2000, "EN QUAD"),
+	(0x2001, "EM QUAD"),
+	(0x2002, "EN SPACE"),
+	(0x2003, "EM SPACE"),
+	(0x2004, "THREE-PER-EM SPACE"),
+	(0x2005, "FOUR-PER-EM SPACE"),
+	(0x2006, "SIX-PER-EM SPACE"),
+	(0x2007, "FIGURE SPACE"),
+	(0x2008, "PUNCTUATION SPACE"),
+	(0x2009, "THIN SPACE"),
+	(0x200a, "HAIR SPACE"),
+	(0x200b, "ZERO WIDTH SPACE"),
+	(0x200c, "ZERO WIDTH NON-JOINER"),
+	(0x200d, "ZERO WIDTH JOINER"),
+	(0x200e, "LEFT-TO-RIGHT MARK"),
+	(0x200f, "RIGHT-TO-LEFT MARK"),
+	(0x2010, "HYPHEN"),
+	(0x2011, "NON-BREAKING HYPHEN"),
+	(0x2012, "FIGURE DASH"),
+	(0x2013, "EN DASH"),
+	(0x2014, "EM DASH"),
+	(0x2015, "HORIZONTAL BAR"),
+	(0x2016, "DOUBLE VERTICAL LINE"),
+	(0x2017, "DOUBLE LOW LINE"),
+	(0x2018, "LEFT SINGLE QUOTATION MARK"),
+	(0x2019, "RIGHT SINGLE QUOTATION MARK"),
+	(0x201a, "SINGLE LOW-9 QUOTATION MARK"),
+	(0x201b, "SINGLE HIGH-REVERSED-9 QUOTATION MARK"),
+	(0x201c, "LEFT DOUBLE QUOTATION MARK"),
+	(0x201d, "RIGHT DOUBLE QUOTATION MARK"),
+	(0x201e, "DOUBLE LOW-9 QUOTATION MARK"),
+	(0x201f, "DOUBLE HIGH-REVERSED-9 QUOTATION MARK"),
+	(0x2020, "DAGGER"),
+	(0x2021, "DOUBLE DAGGER"),
+	(0x2022, "BULLET"),
+	(0x2023, "TRIANGULAR BULLET"),
+	(0x2024, "ONE DOT LEADER"),
+	(0x2025, "TWO DOT LEADER"),
+	(0x2026, "HORIZONTAL ELLIPSIS"),
+	(0x2027, "HYPHENATION POINT"),
+	(0x2028, "LINE SEPARATOR"),
+	(0x2029, "PARAGRAPH SEPARATOR"),
+	(0x202a, "LEFT-TO-RIGHT EMBEDDING"),
+	(0x202b, "RIGHT-TO-LEFT EMBEDDING"),
+	(0x202c, "POP DIRECTIONAL FORMATTING"),
+	(0x202d, "LEFT-TO-RIGHT OVERRIDE"),
+	(0x202e, "RIGHT-TO-LEFT OVERRIDE"),
+	(0x202f, "NARROW NO-BREAK SPACE"),
+	(0x2030, "PER MILLE SIGN"),
+	(0x2031, "PER TEN THOUSAND SIGN"),
+	(0x2032, "PRIME"),
+	(0x2033, "DOUBLE PRIME"),
+	(0x2034, "TRIPLE PRIME"),
+	(0x2035, "REVERSED PRIME"),
+	(0x2036, "REVERSED DOUBLE PRIME"),
+	(0x2037, "REVERSED TRIPLE PRIME"),
+	(0x2038, "CARET"),
+	(0x2039, "SINGLE LEFT-POINTING ANGLE QUOTATION MARK"),
+	(0x203a, "SINGLE RIGHT-POINTING ANGLE QUOTATION MARK"),
+	(0x203b, "REFERENCE MARK"),
+	(0x203c, "DOUBLE EXCLAMATION MARK"),
+	(0x203d, "INTERROBANG"),
+	(0x203e, "OVERLINE"),
+	(0x203f, "UNDERTIE"),
+	(0x2040, "CHARACTER TIE"),
+	(0x2041, "CARET INSERTION POINT"),
+	(0x2042, "ASTERISM"),
+	(0x2043, "HYPHEN BULLET"),
+	(0x2044, "FRACTION SLASH"),
+	(0x2045, "LEFT SQUARE BRACKET WITH QUILL"),
+	(0x2046, "RIGHT SQUARE BRACKET WITH QUILL"),
+	(0x2047, "DOUBLE QUESTION MARK"),
+	(0x2048, "QUESTION EXCLAMATION MARK"),
+	(0x2049, "EXCLAMATION QUESTION MARK"),
+	(0x204a, "TIRONIAN SIGN ET"),
+	(0x204b, "REVERSED PILCROW SIGN"),
+	(0x204c, "BLACK LEFTWARDS BULLET"),
+	(0x204d, "BLACK RIGHTWARDS BULLET"),
+	(0x204e, "LOW ASTERISK"),
+	(0x204f, "REVERSED SEMICOLON"),
+	(0x2050, "CLOSE UP"),
+	(0x2051, "TWO ASTERISKS ALIGNED VERTICALLY"),
+	(0x2052, "COMMERCIAL MINUS SIGN"),
+	(0x2053, "SWUNG DASH"),
+	(0x2054, "INVERTED UNDERTIE"),
+	(0x2055, "FLOWER PUNCTUATION MARK"),
+	(0x2056, "THREE DOT PUNCTUATION"),
+	(0x2057, "QUADRUPLE PRIME"),
+	(0x2058, "FOUR DOT PUNCTUATION"),
+	(0x2059, "FIVE DOT PUNCTUATION"),
+	(0x205a, "TWO DOT PUNCTUATION"),
+	(0x205b, "FOUR DOT MARK"),
+	(0x205c, "DOTTED CROSS"),
+	(0x205d, "TRICOLON"),
+	(0x205e, "VERTICAL FOUR DOTS"),
+	(0x205f, "MEDIUM MATHEMATICAL SPACE"),
+	(0x2060, "WORD JOINER"),
+	(0x2061, "FUNCTION APPLICATION"),
+	(0x2062, "INVISIBLE TIMES"),
+	(0x2063, "INVISIBLE SEPARATOR"),
+	(0x2064, "INVISIBLE PLUS"),
+	(0x2066, "LEFT-TO-RIGHT ISOLATE"),
+	(0x2067, "RIGHT-TO-LEFT ISOLATE"),
+	(0x2068, "FIRST STRONG ISOLATE"),
+	(0x2069, "POP DIRECTIONAL ISOLATE"),
+	(0x206a, "INHIBIT SYMMETRIC SWAPPING"),
+	(0x206b, "ACTIVATE SYMMETRIC SWAPPING"),
+	(0x206c, "INHIBIT ARABIC FORM SHAPING"),
+	(0x206d, "ACTIVATE ARABIC FORM SHAPING"),
+	(0x206e, "NATIONAL DIGIT SHAPES"),
+	(0x206f, "NOMINAL DIGIT SHAPES"),
+	(0x2070, "SUPERSCRIPT ZERO"),
+	(0x2071, "SUPERSCRIPT LATIN SMALL LETTER I"),
+	(0x2074, "SUPERSCRIPT FOUR"),
+	(0x2075, "SUPERSCRIPT FIVE"),
+	(0x2076, "SUPERSCRIPT SIX"),
+	(0x2077, "SUPERSCRIPT SEVEN"),
+	(0x2078, "SUPERSCRIPT EIGHT"),
+	(0x2079, "SUPERSCRIPT NINE"),
+	(0x207a, "SUPERSCRIPT PLUS SIGN"),
+	(0x207b, "SUPERSCRIPT MINUS"),
+	(0x207c, "SUPERSCRIPT EQUALS SIGN"),
+	(0x207d, "SUPERSCRIPT LEFT PARENTHESIS"),
+	(0x207e, "SUPERSCRIPT RIGHT PARENTHESIS"),
+	(0x207f, "SUPERSCRIPT LATIN SMALL LETTER N"),
+	(0x2080, "SUBSCRIPT ZERO"),
+	(0x2081, "SUBSCRIPT ONE"),
+	(0x2082, "SUBSCRIPT TWO"),
+	(0x2083, "SUBSCRIPT THREE"),
+	(0x2084, "SUBSCRIPT FOUR"),
+	(0x2085, "SUBSCRIPT FIVE"),
+	(0x2086, "SUBSCRIPT SIX"),
+	(0x2087, "SUBSCRIPT SEVEN"),
+	(0x2088, "SUBSCRIPT EIGHT"),
+	(0x2089, "SUBSCRIPT NINE"),
+	(0x208a, "SUBSCRIPT PLUS SIGN"),
+	(0x208b, "SUBSCRIPT MINUS"),
+	(0x208c, "SUBSCRIPT EQUALS SIGN"),
+	(0x208d, "SUBSCRIPT LEFT PARENTHESIS"),
+	(0x208e, "SUBSCRIPT RIGHT PARENTHESIS"),
+	(0x2090, "LATIN SUBSCRIPT SMALL LETTER A"),
+	(0x2091, "LATIN SUBSCRIPT SMALL LETTER E"),
+	(0x2092, "LATIN SUBSCRIPT SMALL LETTER O"),
+	(0x2093, "LATIN SUBSCRIPT SMALL LETTER X"),
+	(0x2094, "LATIN SUBSCRIPT SMALL LETTER SCHWA"),
+	(0x2095, "LATIN SUBSCRIPT SMALL LETTER H"),
+	(0x2096, "LATIN SUBSCRIPT SMALL LETTER K"),
+	(0x2097, "LATIN SUBSCRIPT SMALL LETTER L"),
+	(0x2098, "LATIN SUBSCRIPT SMALL LETTER M"),
+	(0x2099, "LATIN SUBSCRIPT SMALL LETTER N"),
+	(0x209a, "LATIN SUBSCRIPT SMALL LETTER P"),
+	(0x209b, "LATIN SUBSCRIPT SMALL LETTER S"),
+	(0x209c, "LATIN SUBSCRIPT SMALL LETTER T"),
+	(0x20a0, "EURO-CURRENCY SIGN"),
+	(0x20a1, "COLON SIGN"),
+	(0x20a2, "CRUZEIRO SIGN"),
+	(0x20a3, "FRENCH FRANC SIGN"),
+	(0x20a4, "LIRA SIGN"),
+	(0x20a5, "MILL SIGN"),
+	(0x20a6, "NAIRA SIGN"),
+	(0x20a7, "PESETA SIGN"),
+	(0x20a8, "RUPEE SIGN"),
+	(0x20a9, "WON SIGN"),
+	(0x20aa, "NEW SHEQEL SIGN"),
+	(0x20ab, "DONG SIGN"),
+	(0x20ac, "EURO SIGN"),
+	(0x20ad, "KIP SIGN"),
+	(0x20ae, "TUGRIK SIGN"),
+	(0x20af, "DRACHMA SIGN"),
+	(0x20b0, "GERMAN PENNY SIGN"),
+	(0x20b1, "PESO SIGN"),
+	(0x20b2, "GUARANI SIGN"),
+	(0x20b3, "AUSTRAL SIGN"),
+	(0x20b4, "HRYVNIA SIGN"),
+	(0x20b5, "CEDI SIGN"),
+	(0x20b6, "LIVRE TOURNOIS SIGN"),
+	(0x20b7, "SPESMILO SIGN"),
+	(0x20b8, "TENGE SIGN"),
+	(0x20b9, "INDIAN RUPEE SIGN"),
+	(0x20ba, "TURKISH LIRA SIGN"),
+	(0x20bb, "NORDIC MARK SIGN"),
+	(0x20bc, "MANAT SIGN"),
+	(0x20bd, "RUBLE SIGN"),
+	(0x20be, "LARI SIGN"),
+	(0x20bf, "BITCOIN SIGN"),
+	(0x20c0, "SOM SIGN"),
+	(0x20d0, "COMBINING LEFT HARPOON ABOVE"),
+	(0x20d1, "COMBINING RIGHT HARPOON ABOVE"),
+	(0x20d2, "COMBINING LONG VERTICAL LINE OVERLAY"),
+	(0x20d3, "COMBINING SHORT VERTICAL LINE OVERLAY"),
+	(0x20d4, "COMBINING ANTICLOCKWISE ARROW ABOVE"),
+	(0x20d5, "COMBINING CLOCKWISE ARROW ABOVE"),
+	(0x20d6, "COMBINING LEFT ARROW ABOVE"),
+	(0x20d7, "COMBINING RIGHT ARROW ABOVE"),
+	(0x20d8, "COMBINING RING OVERLAY"),
+	(0x20d9, "COMBINING CLOCKWISE RING OVERLAY"),
+	(0x20da, "COMBINING ANTICLOCKWISE RING OVERLAY"),
+	(0x20db, "COMBINING THREE DOTS ABOVE"),
+	(0x20dc, "COMBINING FOUR DOTS ABOVE"),
+	(0x20dd, "COMBINING ENCLOSING CIRCLE"),
+	(0x20de, "COMBINING ENCLOSING SQUARE"),
+	(0x20df, "COMBINING ENCLOSING DIAMOND"),
+	(0x20e0, "COMBINING ENCLOSING CIRCLE BACKSLASH"),
+	(0x20e1, "COMBINING LEFT RIGHT ARROW ABOVE"),
+	(0x20e2, "COMBINING ENCLOSING SCREEN"),
+	(0x20e3, "COMBINING ENCLOSING KEYCAP"),
+	(0x20e4, "COMBINING ENCLOSING UPWARD POINTING TRIANGLE"),
+	(0x20e5, "COMBINING REVERSE SOLIDUS OVERLAY"),
+	(0x20e6, "COMBINING DOUBLE VERTICAL STROKE OVERLAY"),
+	(0x20e7, "COMBINING ANNUITY SYMBOL"),
+	(0x20e8, "COMBINING TRIPLE UNDERDOT"),
+	(0x20e9, "COMBINING WIDE BRIDGE ABOVE"),
+	(0x20ea, "COMBINING LEFTWARDS ARROW OVERLAY"),
+	(0x20eb, "COMBINING LONG DOUBLE SOLIDUS OVERLAY"),
+	(0x20ec, "COMBINING RIGHTWARDS HARPOON WITH BARB DOWNWARDS"),
+	(0x20ed, "COMBINING LEFTWARDS HARPOON WITH BARB DOWNWARDS"),
+	(0x20ee, "COMBINING LEFT ARROW BELOW"),
+	(0x20ef, "COMBINING RIGHT ARROW BELOW"),
+	(0x20f0, "COMBINING ASTERISK ABOVE"),
+	(0x2100, "ACCOUNT OF"),
+	(0x2101, "ADDRESSED TO THE SUBJECT"),
+	(0x2102, "DOUBLE-STRUCK CAPITAL C"),
+	(0x2103, "DEGREE CELSIUS"),
+	(0x2104, "CENTRE LINE SYMBOL"),
+	(0x2105, "CARE OF"),
+	(0x2106, "CADA UNA"),
+	(0x2107, "EULER CONSTANT"),
+	(0x2108, "SCRUPLE"),
+	(0x2109, "DEGREE FAHRENHEIT"),
+	(0x210a, "SCRIPT SMALL G"),
+	(0x210b, "SCRIPT CAPITAL H"),
+	(0x210c, "BLACK-LETTER CAPITAL H"),
+	(0x210d, "DOUBLE-STRUCK CAPITAL H"),
+	(0x210e, "PLANCK CONSTANT"),
+	(0x210f, "PLANCK CONSTANT OVER TWO PI"),
+	(0x2110, "SCRIPT CAPITAL I"),
+	(0x2111, "BLACK-LETTER CAPITAL I"),
+	(0x2112, "SCRIPT CAPITAL L"),
+	(0x2113, "SCRIPT SMALL L"),
+	(0x2114, "L B BAR SYMBOL"),
+	(0x2115, "DOUBLE-STRUCK CAPITAL N"),
+	(0x2116, "NUMERO SIGN"),
+	(0x2117, "SOUND RECORDING COPYRIGHT"),
+	(0x2118, "SCRIPT CAPITAL P"),
+	(0x2119, "DOUBLE-STRUCK CAPITAL P"),
+	(0x211a, "DOUBLE-STRUCK CAPITAL Q"),
+	(0x211b, "SCRIPT CAPITAL R"),
+	(0x211c, "BLACK-LETTER CAPITAL R"),
+	(0x211d, "DOUBLE-STRUCK CAPITAL R"),
+	(0x211e, "PRESCRIPTION TAKE"),
+	(0x211f, "RESPONSE"),
+	(0x2120, "SERVICE MARK"),
+	(0x2121, "TELEPHONE SIGN"),
+	(0x2122, "TRADE MARK SIGN"),
+	(0x2123, "VERSICLE"),
+	(0x2124, "DOUBLE-STRUCK CAPITAL Z"),
+	(0x2125, "OUNCE SIGN"),
+	(0x2126, "OHM SIGN"),
+	(0x2127, "INVERTED OHM SIGN"),
+	(0x2128, "BLACK-LETTER CAPITAL Z"),
+	(0x2129, "TURNED GREEK SMALL LETTER IOTA"),
+	(0x212a, "KELVIN SIGN"),
+	(0x212b, "ANGSTROM SIGN"),
+	(0x212c, "SCRIPT CAPITAL B"),
+	(0x212d, "BLACK-LETTER CAPITAL C"),
+	(0x212e, "ESTIMATED SYMBOL"),
+	(0x212f, "SCRIPT SMALL E"),
+	(0x2130, "SCRIPT CAPITAL E"),
+	(0x2131, "SCRIPT CAPITAL F"),
+	(0x2132, "TURNED CAPITAL F"),
+	(0x2133, "SCRIPT CAPITAL M"),
+	(0x2134, "SCRIPT SMALL O"),
+	(0x2135, "ALEF SYMBOL"),
+	(0x2136, "BET SYMBOL"),
+	(0x2137, "GIMEL SYMBOL"),
+	(0x2138, "DALET SYMBOL"),
+	(0x2139, "INFORMATION SOURCE"),
+	(0x213a, "ROTATED CAPITAL Q"),
+	(0x213b, "FACSIMILE SIGN"),
+	(0x213c, "DOUBLE-STRUCK SMALL PI"),
+	(0x213d, "DOUBLE-STRUCK SMALL GAMMA"),
+	(0x213e, "DOUBLE-STRUCK CAPITAL GAMMA"),
+	(0x213f, "DOUBLE-STRUCK CAPITAL PI"),
+	(0x2140, "DOUBLE-STRUCK N-ARY SUMMATION"),
+	(0x2141, "TURNED SANS-SERIF CAPITAL G"),
+	(0x2142, "TURNED SANS-SERIF CAPITAL L"),
+	(0x2143, "REVERSED SANS-SERIF CAPITAL L"),
+	(0x2144, "TURNED SANS-SERIF CAPITAL Y"),
+	(0x2145, "DOUBLE-STRUCK ITALIC CAPITAL D"),
+	(0x2146, "DOUBLE-STRUCK ITALIC SMALL D"),
+	(0x2147, "DOUBLE-STRUCK ITALIC SMALL E"),
+	(0x2148, "DOUBLE-STRUCK ITALIC SMALL I"),
+	(0x2149, "DOUBLE-STRUCK ITALIC SMALL J"),
+	(0x214a, "PROPERTY LINE"),
+	(0x214b, "TURNED AMPERSAND"),
+	(0x214c, "PER SIGN"),
+	(0x214d, "AKTIESELSKAB"),
+	(0x214e, "TURNED SMALL F"),
+	(0x214f, "SYMBOL FOR SAMARITAN SOURCE"),
+	(0x2150, "VULGAR FRACTION ONE SEVENTH"),
+	(0x2151, "VULGAR FRACTION ONE NINTH"),
+	(0x2152, "VULGAR FRACTION ONE TENTH"),
+	(0x2153, "VULGAR FRACTION ONE THIRD"),
+	(0x2154, "VULGAR FRACTION TWO THIRDS"),
+	(0x2155, "VULGAR FRACTION ONE FIFTH"),
+	(0x2156, "VULGAR FRACTION TWO FIFTHS"),
+	(0x2157, "VULGAR FRACTION THREE FIFTHS"),
+	(0x2158, "VULGAR FRACTION FOUR FIFTHS"),
+	(0x2159, "VULGAR FRACTION ONE SIXTH"),
+	(0x215a, "VULGAR FRACTION FIVE SIXTHS"),
+	(0x215b, "VULGAR FRACTION ONE EIGHTH"),
+	(0x215c, "VULGAR FRACTION THREE EIGHTHS"),
+	(0x215d, "VULGAR FRACTION FIVE EIGHTHS"),
+	(0x215e, "VULGAR FRACTION SEVEN EIGHTHS"),
+	(0x215f, "FRACTION NUMERATOR ONE"),
+	(0x2160, "ROMAN NUMERAL ONE"),
+	(0x2161, "ROMAN NUMERAL TWO"),
+	(0x2162, "ROMAN NUMERAL THREE"),
+	(0x2163, "ROMAN NUMERAL FOUR"),
+	(0x2164, "ROMAN NUMERAL FIVE"),
+	(0x2165, "ROMAN NUMERAL SIX"),
+	(0x2166, "ROMAN NUMERAL SEVEN"),
+	(0x2167, "ROMAN NUMERAL EIGHT"),
+	(0x2168, "ROMAN NUMERAL NINE"),
+	(0x2169, "ROMAN NUMERAL TEN"),
+	(0x216a, "ROMAN NUMERAL ELEVEN"),
+	(0x216b, "ROMAN NUMERAL TWELVE"),
+	(0x216c, "ROMAN NUMERAL FIFTY"),
+	(0x216d, "ROMAN NUMERAL ONE HUNDRED"),
+	(0x216e, "ROMAN NUMERAL FIVE HUNDRED"),
+	(0x216f, "ROMAN NUMERAL ONE THOUSAND"),
+	(0x2170, "SMALL ROMAN NUMERAL ONE"),
+	(0x2171, "SMALL ROMAN NUMERAL TWO"),
+	(0x2172, "SMALL ROMAN NUMERAL THREE"),
+	(0x2173, "SMALL ROMAN NUMERAL FOUR"),
+	(0x2174, "SMALL ROMAN NUMERAL FIVE"),
+	(0x2175, "SMALL ROMAN NUMERAL SIX"),
+	(0x2176, "SMALL ROMAN NUMERAL SEVEN"),
+	(0x2177, "SMALL ROMAN NUMERAL EIGHT"),
+	(0x2178, "SMALL ROMAN NUMERAL NINE"),
+	(0x2179, "SMALL ROMAN NUMERAL TEN"),
+	(0x217a, "SMALL ROMAN NUMERAL ELEVEN"),
+	(0x217b, "SMALL ROMAN NUMERAL TWELVE"),
+	(0x217c, "SMALL ROMAN NUMERAL FIFTY"),
+	(0x217d, "SMALL ROMAN NUMERAL ONE HUNDRED"),
+	(0x217e, "SMALL ROMAN NUMERAL FIVE HUNDRED"),
+	(0x217f, "SMALL ROMAN NUMERAL ONE THOUSAND"),
+	(0x2180, "ROMAN NUMERAL ONE THOUSAND C D"),
+	(0x2181, "ROMAN NUMERAL FIVE THOUSAND"),
+	(0x2182, "ROMAN NUMERAL TEN THOUSAND"),
+	(0x2183, "ROMAN NUMERAL REVERSED ONE HUNDRED"),
+	(0x2184, "LATIN SMALL LETTER REVERSED C"),
+	(0x2185, "ROMAN NUMERAL SIX LATE FORM"),
+	(0x2186, "ROMAN NUMERAL FIFTY EARLY FORM"),
+	(0x2187, "ROMAN NUMERAL FIFTY THOUSAND"),
+	(0x2188, "ROMAN NUMERAL ONE HUNDRED THOUSAND"),
+	(0x2189, "VULGAR FRACTION ZERO THIRDS"),
+	(0x218a, "TURNED DIGIT TWO"),
+	(0x218b, "TURNED DIGIT THREE"),
+	(0x2190, "LEFTWARDS ARROW"),
+	(0x2191, "UPWARDS ARROW"),
+	(0x2192, "RIGHTWARDS ARROW"),
+	(0x2193, "DOWNWARDS ARROW"),
+	(0x2194, "LEFT RIGHT ARROW"),
+	(0x2195, "UP DOWN ARROW"),
+	(0x2196, "NORTH WEST ARROW"),
+	(0x2197, "NORTH EAST ARROW"),
+	(0x2198, "SOUTH EAST ARROW"),
+	(0x2199, "SOUTH WEST ARROW"),
+	(0x219a, "LEFTWARDS ARROW WITH STROKE"),
+	(0x219b, "RIGHTWARDS ARROW WITH STROKE"),
+	(0x219c, "LEFTWARDS WAVE ARROW"),
+	(0x219d, "RIGHTWARDS WAVE ARROW"),
+	(0x219e, "LEFTWARDS TWO HEADED ARROW"),
+	(0x219f, "UPWARDS TWO HEADED ARROW"),
+	(0x21a0, "RIGHTWARDS TWO HEADED ARROW"),
+	(0x21a1, "DOWNWARDS TWO HEADED ARROW"),
+	(0x21a2, "LEFTWARDS ARROW WITH TAIL"),
+	(0x21a3, "RIGHTWARDS ARROW WITH TAIL"),
+	(0x21a4, "LEFTWARDS ARROW FROM BAR"),
+	(0x21a5, "UPWARDS ARROW FROM BAR"),
+	(0x21a6, "RIGHTWARDS ARROW FROM BAR"),
+	(0x21a7, "DOWNWARDS ARROW FROM BAR"),
+	(0x21a8, "UP DOWN ARROW WITH BASE"),
+	(0x21a9, "LEFTWARDS ARROW WITH HOOK"),
+	(0x21aa, "RIGHTWARDS ARROW WITH HOOK"),
+	(0x21ab, "LEFTWARDS ARROW WITH LOOP"),
+	(0x21ac, "RIGHTWARDS ARROW WITH LOOP"),
+	(0x21ad, "LEFT RIGHT WAVE ARROW"),
+	(0x21ae, "LEFT RIGHT ARROW WITH STROKE"),
+	(0x21af, "DOWNWARDS ZIGZAG ARROW"),
+	(0x21b0, "UPWARDS ARROW WITH TIP LEFTWARDS"),
+	(0x21b1, "UPWARDS ARROW WITH TIP RIGHTWARDS"),
+	(0x21b2, "DOWNWARDS ARROW WITH TIP LEFTWARDS"),
+	(0x21b3, "DOWNWARDS ARROW WITH TIP RIGHTWARDS"),
+	(0x21b4, "RIGHTWARDS ARROW WITH CORNER DOWNWARDS"),
+	(0x21b5, "DOWNWARDS ARROW WITH CORNER LEFTWARDS"),
+	(0x21b6, "ANTICLOCKWISE TOP SEMICIRCLE ARROW"),
+	(0x21b7, "CLOCKWISE TOP SEMICIRCLE ARROW"),
+	(0x21b8, "NORTH WEST ARROW TO LONG BAR"),
+	(0x21b9, "LEFTWARDS ARROW TO BAR OVER RIGHTWARDS ARROW TO BAR"),
+	(0x21ba, "ANTICLOCKWISE OPEN CIRCLE ARROW"),
+	(0x21bb, "CLOCKWISE OPEN CIRCLE ARROW"),
+	(0x21bc, "LEFTWARDS HARPOON WITH BARB UPWARDS"),
+	(0x21bd, "LEFTWARDS HARPOON WITH BARB DOWNWARDS"),
+	(0x21be, "UPWARDS HARPOON WITH BARB RIGHTWARDS"),
+	(0x21bf, "UPWARDS HARPOON WITH BARB LEFTWARDS"),
+	(0x21c0, "RIGHTWARDS HARPOON WITH BARB UPWARDS"),
+	(0x21c1, "RIGHTWARDS HARPOON WITH BARB DOWNWARDS"),
+	(0x21c2, "DOWNWARDS HARPOON WITH BARB RIGHTWARDS"),
+	(0x21c3, "DOWNWARDS HARPOON WITH BARB LEFTWARDS"),
+	(0x21c4, "RIGHTWARDS ARROW OVER LEFTWARDS ARROW"),
+	(0x21c5, "UPWARDS ARROW LEFTWARDS OF DOWNWARDS ARROW"),
+	(0x21c6, "LEFTWARDS ARROW OVER RIGHTWARDS ARROW"),
+	(0x21c7, "LEFTWARDS PAIRED ARROWS"),
+	(0x21c8, "UPWARDS PAIRED ARROWS"),
+	(0x21c9, "RIGHTWARDS PAIRED ARROWS"),
+	(0x21ca, "DOWNWARDS PAIRED ARROWS"),
+	(0x21cb, "LEFTWARDS HARPOON OVER RIGHTWARDS HARPOON"),
+	(0x21cc, "RIGHTWARDS HARPOON OVER LEFTWARDS HARPOON"),
+	(0x21cd, "LEFTWARDS DOUBLE ARROW WITH STROKE"),
+	(0x21ce, "LEFT RIGHT DOUBLE ARROW WITH STROKE"),
+	(0x21cf, "RIGHTWARDS DOUBLE ARROW WITH STROKE"),
+	(0x21d0, "LEFTWARDS DOUBLE ARROW"),
+	(0x21d1, "UPWARDS DOUBLE ARROW"),
+	(0x21d2, "RIGHTWARDS DOUBLE ARROW"),
+	(0x21d3, "DOWNWARDS DOUBLE ARROW"),
+	(0x21d4, "LEFT RIGHT DOUBLE ARROW"),
+	(0x21d5, "UP DOWN DOUBLE ARROW"),
+	(0x21d6, "NORTH WEST DOUBLE ARROW"),
+	(0x21d7, "NORTH EAST DOUBLE ARROW"),
+	(0x21d8, "SOUTH EAST DOUBLE ARROW"),
+	(0x21d9, "SOUTH WEST DOUBLE ARROW"),
+	(0x21da, "LEFTWARDS TRIPLE ARROW"),
+	(0x21db, "RIGHTWARDS TRIPLE ARROW"),
+	(0x21dc, "LEFTWARDS SQUIGGLE ARROW"),
+	(0x21dd, "RIGHTWARDS SQUIGGLE ARROW"),
+	(0x21de, "UPWARDS ARROW WITH DOUBLE STROKE"),
+	(0x21df, "DOWNWARDS ARROW WITH DOUBLE STROKE"),
+	(0x21e0, "LEFTWARDS DASHED ARROW"),
+	(0x21e1, "UPWARDS DASHED ARROW"),
+	(0x21e2, "RIGHTWARDS DASHED ARROW"),
+	(0x21e3, "DOWNWARDS DASHED ARROW"),
+	(0x21e4, "LEFTWARDS ARROW TO BAR"),
+	(0x21e5, "RIGHTWARDS ARROW TO BAR"),
+	(0x21e6, "LEFTWARDS WHITE ARROW"),
+	(0x21e7, "UPWARDS WHITE ARROW"),
+	(0x21e8, "RIGHTWARDS WHITE ARROW"),
+	(0x21e9, "DOWNWARDS WHITE ARROW"),
+	(0x21ea, "UPWARDS WHITE ARROW FROM BAR"),
+	(0x21eb, "UPWARDS WHITE ARROW ON PEDESTAL"),
+	(0x21ec, "UPWARDS WHITE ARROW ON PEDESTAL WITH HORIZONTAL BAR"),
+	(0x21ed, "UPWARDS WHITE ARROW ON PEDESTAL WITH VERTICAL BAR"),
+	(0x21ee, "UPWARDS WHITE DOUBLE ARROW"),
+	(0x21ef, "UPWARDS WHITE DOUBLE ARROW ON PEDESTAL"),
+	(0x21f0, "RIGHTWARDS WHITE ARROW FROM WALL"),
+	(0x21f1, "NORTH WEST ARROW TO CORNER"),
+	(0x21f2, "SOUTH EAST ARROW TO CORNER"),
+	(0x21f3, "UP DOWN WHITE ARROW"),
+	(0x21f4, "RIGHT ARROW WITH SMALL CIRCLE"),
+	(0x21f5, "DOWNWARDS ARROW LEFTWARDS OF UPWARDS ARROW"),
+	(0x21f6, "THREE RIGHTWARDS ARROWS"),
+	(0x21f7, "LEFTWARDS ARROW WITH VERTICAL STROKE"),
+	(0x21f8, "RIGHTWARDS ARROW WITH VERTICAL STROKE"),
+	(0x21f9, "LEFT RIGHT ARROW WITH VERTICAL STROKE"),
+	(0x21fa, "LEFTWARDS ARROW WITH DOUBLE VERTICAL STROKE"),
+	(0x21fb, "RIGHTWARDS ARROW WITH DOUBLE VERTICAL STROKE"),
+	(0x21fc, "LEFT RIGHT ARROW WITH DOUBLE VERTICAL STROKE"),
+	(0x21fd, "LEFTWARDS OPEN-HEADED ARROW"),
+	(0x21fe, "RIGHTWARDS OPEN-HEADED ARROW"),
+	(0x21ff, "LEFT RIGHT OPEN-HEADED ARROW"),
+	(0x2200, "FOR ALL"),
+	(0x2201, "COMPLEMENT"),
+	(0x2202, "PARTIAL DIFFERENTIAL"),
+	(0x2203, "THERE EXISTS"),
+	(0x2204, "THERE DOES NOT EXIST"),
+	(0x2205, "EMPTY SET"),
+	(0x2206, "INCREMENT"),
+	(0x2207, "NABLA"),
+	(0x2208, "ELEMENT OF"),
+	(0x2209, "NOT AN ELEMENT OF"),
+	(0x220a, "SMALL ELEMENT OF"),
+	(0x220b, "CONTAINS AS MEMBER"),
+	(0x220c, "DOES NOT CONTAIN AS MEMBER"),
+	(0x220d, "SMALL CONTAINS AS MEMBER"),
+	(0x220e, "END OF PROOF"),
+	(0x220f, "N-ARY PRODUCT"),
+	(0x2210, "N-ARY COPRODUCT"),
+	(0x2211, "N-ARY SUMMATION"),
+	(0x2212, "MINUS SIGN"),
+	(0x2213, "MINUS-OR-PLUS SIGN"),
+	(0x2214, "DOT PLUS"),
+	(0x2215, "DIVISION SLASH"),
+	(0x2216, "SET MINUS"),
+	(0x2217, "ASTERISK OPERATOR"),
+	(0x2218, "RING OPERATOR"),
+	(0x2219, "BULLET OPERATOR"),
+	(0x221a, "SQUARE ROOT"),
+	(0x221b, "CUBE ROOT"),
+	(0x221c, "FOURTH ROOT"),
+	(0x221d, "PROPORTIONAL TO"),
+	(0x221e, "INFINITY"),
+	(0x221f, "RIGHT ANGLE"),
+	(0x2220, "ANGLE"),
+	(0x2221, "MEASURED ANGLE"),
+	(0x2222, "SPHERICAL ANGLE"),
+	(0x2223, "DIVIDES"),
+	(0x2224, "DOES NOT DIVIDE"),
+	(0x2225, "PARALLEL TO"),
+	(0x2226, "NOT PARALLEL TO"),
+	(0x2227, "LOGICAL AND"),
+	(0x2228, "LOGICAL OR"),
+	(0x2229, "INTERSECTION"),
+	(0x222a, "UNION"),
+	(0x222b, "INTEGRAL"),
+	(0x222c, "DOUBLE INTEGRAL"),
+	(0x222d, "TRIPLE INTEGRAL"),
+	(0x222e, "CONTOUR INTEGRAL"),
+	(0x222f, "SURFACE INTEGRAL"),
+	(0x2230, "VOLUME INTEGRAL"),
+	(0x2231, "CLOCKWISE INTEGRAL"),
+	(0x2232, "CLOCKWISE CONTOUR INTEGRAL"),
+	(0x2233, "ANTICLOCKWISE CONTOUR INTEGRAL"),
+	(0x2234, "THEREFORE"),
+	(0x2235, "BECAUSE"),
+	(0x2236, "RATIO"),
+	(0x2237, "PROPORTION"),
+	(0x2238, "DOT MINUS"),
+	(0x2239, "EXCESS"),
+	(0x223a, "GEOMETRIC PROPORTION"),
+	(0x223b, "HOMOTHETIC"),
+	(0x223c, "TILDE OPERATOR"),
+	(0x223d, "REVERSED TILDE"),
+	(0x223e, "INVERTED LAZY S"),
+	(0x223f, "SINE WAVE"),
+	(0x2240, "WREATH PRODUCT"),
+	(0x2241, "NOT TILDE"),
+	(0x2242, "MINUS TILDE"),
+	(0x2243, "ASYMPTOTICALLY EQUAL TO"),
+	(0x2244, "NOT ASYMPTOTICALLY EQUAL TO"),
+	(0x2245, "APPROXIMATELY EQUAL TO"),
+	(0x2246, "APPROXIMATELY BUT NOT ACTUALLY EQUAL TO"),
+	(0x2247, "NEITHER APPROXIMATELY NOR ACTUALLY EQUAL TO"),
+	(0x2248, "ALMOST EQUAL TO"),
+	(0x2249, "NOT ALMOST EQUAL TO"),
+	(0x224a, "ALMOST EQUAL OR EQUAL TO"),
+	(0x224b, "TRIPLE TILDE"),
+	(0x224c, "ALL EQUAL TO"),
+	(0x224d, "EQUIVALENT TO"),
+	(0x224e, "GEOMETRICALLY EQUIVALENT TO"),
+	(0x224f, "DIFFERENCE BETWEEN"),
+	(0x2250, "APPROACHES THE LIMIT"),
+	(0x2251, "GEOMETRICALLY EQUAL TO"),
+	(0x2252, "APPROXIMATELY EQUAL TO OR THE IMAGE OF"),
+	(0x2253, "IMAGE OF OR APPROXIMATELY EQUAL TO"),
+	(0x2254, "COLON EQUALS"),
+	(0x2255, "EQUALS COLON"),
+	(0x2256, "RING IN EQUAL TO"),
+	(0x2257, "RING EQUAL TO"),
+	(0x2258, "CORRESPONDS TO"),
+	(0x2259, "ESTIMATES"),
+	(0x225a, "EQUIANGULAR TO"),
+	(0x225b, "STAR EQUALS"),
+	(0x225c, "DELTA EQUAL TO"),
+	(0x225d, "EQUAL TO BY DEFINITION"),
+	(0x225e, "MEASURED BY"),
+	(0x225f, "QUESTIONED EQUAL TO"),
+	(0x2260, "NOT EQUAL TO"),
+	(0x2261, "IDENTICAL TO"),
+	(0x2262, "NOT IDENTICAL TO"),
+	(0x2263, "STRICTLY EQUIVALENT TO"),
+	(0x2264, "LESS-THAN OR EQUAL TO"),
+	(0x2265, "GREATER-THAN OR EQUAL TO"),
+	(0x2266, "LESS-THAN OVER EQUAL TO"),
+	(0x2267, "GREATER-THAN OVER EQUAL TO"),
+	(0x2268, "LESS-THAN BUT NOT EQUAL TO"),
+	(0x2269, "GREATER-THAN BUT NOT EQUAL TO"),
+	(0x226a, "MUCH LESS-THAN"),
+	(0x226b, "MUCH GREATER-THAN"),
+	(0x226c, "BETWEEN"),
+	(0x226d, "NOT EQUIVALENT TO"),
+	(0x226e, "NOT LESS-THAN"),
+	(0x226f, "NOT GREATER-THAN"),
+	(0x2270, "NEITHER LESS-THAN NOR EQUAL TO"),
+	(0x2271, "NEITHER GREATER-THAN NOR EQUAL TO"),
+	(0x2272, "LESS-THAN OR EQUIVALENT TO"),
+	(0x2273, "GREATER-THAN OR EQUIVALENT TO"),
+	(0x2274, "NEITHER LESS-THAN NOR EQUIVALENT TO"),
+	(0x2275, "NEITHER GREATER-THAN NOR EQUIVALENT TO"),
+	(0x2276, "LESS-THAN OR GREATER-THAN"),
+	(0x2277, "GREATER-THAN OR LESS-THAN"),
+	(0x2278, "NEITHER LESS-THAN NOR GREATER-THAN"),
+	(0x2279, "NEITHER GREATER-THAN NOR LESS-THAN"),
+	(0x227a, "PRECEDES"),
+	(0x227b, "SUCCEEDS"),
+	(0x227c, "PRECEDES OR EQUAL TO"),
+	(0x227d, "SUCCEEDS OR EQUAL TO"),
+	(0x227e, "PRECEDES OR EQUIVALENT TO"),
+	(0x227f, "SUCCEEDS OR EQUIVALENT TO"),
+	(0x2280, "DOES NOT PRECEDE"),
+	(0x2281, "DOES NOT SUCCEED"),
+	(0x2282, "SUBSET OF"),
+	(0x2283, "SUPERSET OF"),
+	(0x2284, "NOT A SUBSET OF"),
+	(0x2285, "NOT A SUPERSET OF"),
+	(0x2286, "SUBSET OF OR EQUAL TO"),
+	(0x2287, "SUPERSET OF OR EQUAL TO"),
+	(0x2288, "NEITHER A SUBSET OF NOR EQUAL TO"),
+	(0x2289, "NEITHER A SUPERSET OF NOR EQUAL TO"),
+	(0x228a, "SUBSET OF WITH NOT EQUAL TO"),
+	(0x228b, "SUPERSET OF WITH NOT EQUAL TO"),
+	(0x228c, "MULTISET"),
+	(0x228d, "MULTISET MULTIPLICATION"),
+	(0x228e, "MULTISET UNION"),
+	(0x228f, "SQUARE IMAGE OF"),
+	(0x2290, "SQUARE ORIGINAL OF"),
+	(0x2291, "SQUARE IMAGE OF OR EQUAL TO"),
+	(0x2292, "SQUARE ORIGINAL OF OR EQUAL TO"),
+	(0x2293, "SQUARE CAP"),
+	(0x2294, "SQUARE CUP"),
+	(0x2295, "CIRCLED PLUS"),
+	(0x2296, "CIRCLED MINUS"),
+	(0x2297, "CIRCLED TIMES"),
+	(0x2298, "CIRCLED DIVISION SLASH"),
+	(0x2299, "CIRCLED DOT OPERATOR"),
+	(0x229a, "CIRCLED RING OPERATOR"),
+	(0x229b, "CIRCLED ASTERISK OPERATOR"),
+	(0x229c, "CIRCLED EQUALS"),
+	(0x229d, "CIRCLED DASH"),
+	(0x229e, "SQUARED PLUS"),
+	(0x229f, "SQUARED MINUS"),
+	(0x22a0, "SQUARED TIMES"),
+	(0x22a1, "SQUARED DOT OPERATOR"),
+	(0x22a2, "RIGHT TACK"),
+	(0x22a3, "LEFT TACK"),
+	(0x22a4, "DOWN TACK"),
+	(0x22a5, "UP TACK"),
+	(0x22a6, "ASSERTION"),
+	(0x22a7, "MODELS"),
+	(0x22a8, "TRUE"),
+	(0x22a9, "FORCES"),
+	(0x22aa, "TRIPLE VERTICAL BAR RIGHT TURNSTILE"),
+	(0x22ab, "DOUBLE VERTICAL BAR DOUBLE RIGHT TURNSTILE"),
+	(0x22ac, "DOES NOT PROVE"),
+	(0x22ad, "NOT TRUE"),
+	(0x22ae, "DOES NOT FORCE"),
+	(0x22af, "NEGATED DOUBLE VERTICAL BAR DOUBLE RIGHT TURNSTILE"),
+	(0x22b0, "PRECEDES UNDER RELATION"),
+	(0x22b1, "SUCCEEDS UNDER RELATION"),
+	(0x22b2, "NORMAL SUBGROUP OF"),
+	(0x22b3, "CONTAINS AS NORMAL SUBGROUP"),
+	(0x22b4, "NORMAL SUBGROUP OF OR EQUAL TO"),
+	(0x22b5, "CONTAINS AS NORMAL SUBGROUP OR EQUAL TO"),
+	(0x22b6, "ORIGINAL OF"),
+	(0x22b7, "IMAGE OF"),
+	(0x22b8, "MULTIMAP"),
+	(0x22b9, "HERMITIAN CONJUGATE MATRIX"),
+	(0x22ba, "INTERCALATE"),
+	(0x22bb, "XOR"),
+	(0x22bc, "NAND"),
+	(0x22bd, "NOR"),
+	(0x22be, "RIGHT ANGLE WITH ARC"),
+	(0x22bf, "RIGHT TRIANGLE"),
+	(0x22c0, "N-ARY LOGICAL AND"),
+	(0x22c1, "N-ARY LOGICAL OR"),
+	(0x22c2, "N-ARY INTERSECTION"),
+	(0x22c3, "N-ARY UNION"),
+	(0x22c4, "DIAMOND OPERATOR"),
+	(0x22c5, "DOT OPERATOR"),
+	(0x22c6, "STAR OPERATOR"),
+	(0x22c7, "DIVISION TIMES"),
+	(0x22c8, "BOWTIE"),
+	(0x22c9, "LEFT NORMAL FACTOR SEMIDIRECT PRODUCT"),
+	(0x22ca, "RIGHT NORMAL FACTOR SEMIDIRECT PRODUCT"),
+	(0x22cb, "LEFT SEMIDIRECT PRODUCT"),
+	(0x22cc, "RIGHT SEMIDIRECT PRODUCT"),
+	(0x22cd, "REVERSED TILDE EQUALS"),
+	(0x22ce, "CURLY LOGICAL OR"),
+	(0x22cf, "CURLY LOGICAL AND"),
+	(0x22d0, "DOUBLE SUBSET"),
+	(0x22d1, "DOUBLE SUPERSET"),
+	(0x22d2, "DOUBLE INTERSECTION"),
+	(0x22d3, "DOUBLE UNION"),
+	(0x22d4, "PITCHFORK"),
+	(0x22d5, "EQUAL AND PARALLEL TO"),
+	(0x22d6, "LESS-THAN WITH DOT"),
+	(0x22d7, "GREATER-THAN WITH DOT"),
+	(0x22d8, "VERY MUCH LESS-THAN"),
+	(0x22d9, "VERY MUCH GREATER-THAN"),
+	(0x22da, "LESS-THAN EQUAL TO OR GREATER-THAN"),
+	(0x22db, "GREATER-THAN EQUAL TO OR LESS-THAN"),
+	(0x22dc, "EQUAL TO OR LESS-THAN"),
+	(0x22dd, "EQUAL TO OR GREATER-THAN"),
+	(0x22de, "EQUAL TO OR PRECEDES"),
+	(0x22df, "EQUAL TO OR SUCCEEDS"),
+	(0x22e0, "DOES NOT PRECEDE OR EQUAL"),
+	(0x22e1, "DOES NOT SUCCEED OR EQUAL"),
+	(0x22e2, "NOT SQUARE IMAGE OF OR EQUAL TO"),
+	(0x22e3, "NOT SQUARE ORIGINAL OF OR EQUAL TO"),
+	(0x22e4, "SQUARE IMAGE OF OR NOT EQUAL TO"),
+	(0x22e5, "SQUARE ORIGINAL OF OR NOT EQUAL TO"),
+	(0x22e6, "LESS-THAN BUT NOT EQUIVALENT TO"),
+	(0x22e7, "GREATER-THAN BUT NOT EQUIVALENT TO"),
+	(0x22e8, "PRECEDES BUT NOT EQUIVALENT TO"),
+	(0x22e9, "SUCCEEDS BUT NOT EQUIVALENT TO"),
+	(0x22ea, "NOT NORMAL SUBGROUP OF"),
+	(0x22eb, "DOES NOT CONTAIN AS NORMAL SUBGROUP"),
+	(0x22ec, "NOT NORMAL SUBGROUP OF OR EQUAL TO"),
+	(0x22ed, "DOES NOT CONTAIN AS NORMAL SUBGROUP OR EQUAL"),
+	(0x22ee, "VERTICAL ELLIPSIS"),
+	(0x22ef, "MIDLINE HORIZONTAL ELLIPSIS"),
+	(0x22f0, "UP RIGHT DIAGONAL ELLIPSIS"),
+	(0x22f1, "DOWN RIGHT DIAGONAL ELLIPSIS"),
+	(0x22f2, "ELEMENT OF WITH LONG HORIZONTAL STROKE"),
+	(0x22f3, "ELEMENT OF WITH VERTICAL BAR AT END OF HORIZONTAL STROKE"),
+	(0x22f4, "SMALL ELEMENT OF WITH VERTICAL BAR AT END OF HORIZONTAL STROKE"),
+	(0x22f5, "ELEMENT OF WITH DOT ABOVE"),
+	(0x22f6, "ELEMENT OF WITH OVERBAR"),
+	(0x22f7, "SMALL ELEMENT OF WITH OVERBAR"),
+	(0x22f8, "ELEMENT OF WITH UNDERBAR"),
+	(0x22f9, "ELEMENT OF WITH TWO HORIZONTAL STROKES"),
+	(0x22fa, "CONTAINS WITH LONG HORIZONTAL STROKE"),
+	(0x22fb, "CONTAINS WITH VERTICAL BAR AT END OF HORIZONTAL STROKE"),
+	(0x22fc, "SMALL CONTAINS WITH VERTICAL BAR AT END OF HORIZONTAL STROKE"),
+	(0x22fd, "CONTAINS WITH OVERBAR"),
+	(0x22fe, "SMALL CONTAINS WITH OVERBAR"),
+	(0x22ff, "Z NOTATION BAG MEMBERSHIP"),
+	(0x2300, "DIAMETER SIGN"),
+	(0x2301, "ELECTRIC ARROW"),
+	(0x2302, "HOUSE"),
+	(0x2303, "UP ARROWHEAD"),
+	(0x2304, "DOWN ARROWHEAD"),
+	(0x2305, "PROJECTIVE"),
+	(0x2306, "PERSPECTIVE"),
+	(0x2307, "WAVY LINE"),
+	(0x2308, "LEFT CEILING"),
+	(0x2309, "RIGHT CEILING"),
+	(0x230a, "LEFT FLOOR"),
+	(0x230b, "RIGHT FLOOR"),
+	(0x230c, "BOTTOM RIGHT CROP"),
+	(0x230d, "BOTTOM LEFT CROP"),
+	(0x230e, "TOP RIGHT CROP"),
+	(0x230f, "TOP LEFT CROP"),
+	(0x2310, "REVERSED NOT SIGN"),
+	(0x2311, "SQUARE LOZENGE"),
+	(0x2312, "ARC"),
+	(0x2313, "SEGMENT"),
+	(0x2314, "SECTOR"),
+	(0x2315, "TELEPHONE RECORDER"),
+	(0x2316, "POSITION INDICATOR"),
+	(0x2317, "VIEWDATA SQUARE"),
+	(0x2318, "PLACE OF INTEREST SIGN"),
+	(0x2319, "TURNED NOT SIGN"),
+	(0x231a, "WATCH"),
+	(0x231b, "HOURGLASS"),
+	(0x231c, "TOP LEFT CORNER"),
+	(0x231d, "TOP RIGHT CORNER"),
+	(0x231e, "BOTTOM LEFT CORNER"),
+	(0x231f, "BOTTOM RIGHT CORNER"),
+	(0x2320, "TOP HALF INTEGRAL"),
+	(0x2321, "BOTTOM HALF INTEGRAL"),
+	(0x2322, "FROWN"),
+	(0x2323, "SMILE"),
+	(0x2324, "UP ARROWHEAD BETWEEN TWO HORIZONTAL BARS"),
+	(0x2325, "OPTION KEY"),
+	(0x2326, "ERASE TO THE RIGHT"),
+	(0x2327, "X IN A RECTANGLE BOX"),
+	(0x2328, "KEYBOARD"),
+	(0x2329, "LEFT-POINTING ANGLE BRACKET"),
+	(0x232a, "RIGHT-POINTING ANGLE BRACKET"),
+	(0x232b, "ERASE TO THE LEFT"),
+	(0x232c, "BENZENE RING"),
+	(0x232d, "CYLINDRICITY"),
+	(0x232e, "ALL AROUND-PROFILE"),
+	(0x232f, "SYMMETRY"),
+	(0x2330, "TOTAL RUNOUT"),
+	(0x2331, "DIMENSION ORIGIN"),
+	(0x2332, "CONICAL TAPER"),
+	(0x2333, "SLOPE"),
+	(0x2334, "COUNTERBORE"),
+	(0x2335, "COUNTERSINK"),
+	(0x2336, "APL FUNCTIONAL SYMBOL I-BEAM"),
+	(0x2337, "APL FUNCTIONAL SYMBOL SQUISH QUAD"),
+	(0x2338, "APL FUNCTIONAL SYMBOL QUAD EQUAL"),
+	(0x2339, "APL FUNCTIONAL SYMBOL QUAD DIVIDE"),
+	(0x233a, "APL FUNCTIONAL SYMBOL QUAD DIAMOND"),
+	(0x233b, "APL FUNCTIONAL SYMBOL QUAD JOT"),
+	(0x233c, "APL FUNCTIONAL SYMBOL QUAD CIRCLE"),
+	(0x233d, "APL FUNCTIONAL SYMBOL CIRCLE STILE"),
+	(0x233e, "APL FUNCTIONAL SYMBOL CIRCLE JOT"),
+	(0x233f, "APL FUNCTIONAL SYMBOL SLASH BAR"),
+	(0x2340, "APL FUNCTIONAL SYMBOL BACKSLASH BAR"),
+	(0x2341, "APL FUNCTIONAL SYMBOL QUAD SLASH"),
+	(0x2342, "APL FUNCTIONAL SYMBOL QUAD BACKSLASH"),
+	(0x2343, "APL FUNCTIONAL SYMBOL QUAD LESS-THAN"),
+	(0x2344, "APL FUNCTIONAL SYMBOL QUAD GREATER-THAN"),
+	(0x2345, "APL FUNCTIONAL SYMBOL LEFTWARDS VANE"),
+	(0x2346, "APL FUNCTIONAL SYMBOL RIGHTWARDS VANE"),
+	(0x2347, "APL FUNCTIONAL SYMBOL QUAD LEFTWARDS ARROW"),
+	(0x2348, "APL FUNCTIONAL SYMBOL QUAD RIGHTWARDS ARROW"),
+	(0x2349, "APL FUNCTIONAL SYMBOL CIRCLE BACKSLASH"),
+	(0x234a, "APL FUNCTIONAL SYMBOL DOWN TACK UNDERBAR"),
+	(0x234b, "APL FUNCTIONAL SYMBOL DELTA STILE"),
+	(0x234c, "APL FUNCTIONAL SYMBOL QUAD DOWN CARET"),
+	(0x234d, "APL FUNCTIONAL SYMBOL QUAD DELTA"),
+	(0x234e, "APL FUNCTIONAL SYMBOL DOWN TACK JOT"),
+	(0x234f, "APL FUNCTIONAL SYMBOL UPWARDS VANE"),
+	(0x2350, "APL FUNCTIONAL SYMBOL QUAD UPWARDS ARROW"),
+	(0x2351, "APL FUNCTIONAL SYMBOL UP TACK OVERBAR"),
+	(0x2352, "APL FUNCTIONAL SYMBOL DEL STILE"),
+	(0x2353, "APL FUNCTIONAL SYMBOL QUAD UP CARET"),
+	(0x2354, "APL FUNCTIONAL SYMBOL QUAD DEL"),
+	(0x2355, "APL FUNCTIONAL SYMBOL UP TACK JOT"),
+	(0x2356, "APL FUNCTIONAL SYMBOL DOWNWARDS VANE"),
+	(0x2357, "APL FUNCTIONAL SYMBOL QUAD DOWNWARDS ARROW"),
+	(0x2358, "APL FUNCTIONAL SYMBOL QUOTE UNDERBAR"),
+	(0x2359, "APL FUNCTIONAL SYMBOL DELTA UNDERBAR"),
+	(0x235a, "APL FUNCTIONAL SYMBOL DIAMOND UNDERBAR"),
+	(0x235b, "APL FUNCTIONAL SYMBOL JOT UNDERBAR"),
+	(0x235c, "APL FUNCTIONAL SYMBOL CIRCLE UNDERBAR"),
+	(0x235d, "APL FUNCTIONAL SYMBOL UP SHOE JOT"),
+	(0x235e, "APL FUNCTIONAL SYMBOL QUOTE QUAD"),
+	(0x235f, "APL FUNCTIONAL SYMBOL CIRCLE STAR"),
+	(0x2360, "APL FUNCTIONAL SYMBOL QUAD COLON"),
+	(0x2361, "APL FUNCTIONAL SYMBOL UP TACK DIAERESIS"),
+	(0x2362, "APL FUNCTIONAL SYMBOL DEL DIAERESIS"),
+	(0x2363, "APL FUNCTIONAL SYMBOL STAR DIAERESIS"),
+	(0x2364, "APL FUNCTIONAL SYMBOL JOT DIAERESIS"),
+	(0x2365, "APL FUNCTIONAL SYMBOL CIRCLE DIAERESIS"),
+	(0x2366, "APL FUNCTIONAL SYMBOL DOWN SHOE STILE"),
+	(0x2367, "APL FUNCTIONAL SYMBOL LEFT SHOE STILE"),
+	(0x2368, "APL FUNCTIONAL SYMBOL TILDE DIAERESIS"),
+	(0x2369, "APL FUNCTIONAL SYMBOL GREATER-THAN DIAERESIS"),
+	(0x236a, "APL FUNCTIONAL SYMBOL COMMA BAR"),
+	(0x236b, "APL FUNCTIONAL SYMBOL DEL TILDE"),
+	(0x236c, "APL FUNCTIONAL SYMBOL ZILDE"),
+	(0x236d, "APL FUNCTIONAL SYMBOL STILE TILDE"),
+	(0x236e, "APL FUNCTIONAL SYMBOL SEMICOLON UNDERBAR"),
+	(0x236f, "APL FUNCTIONAL SYMBOL QUAD NOT EQUAL"),
+	(0x2370, "APL FUNCTIONAL SYMBOL QUAD QUESTION"),
+	(0x2371, "APL FUNCTIONAL SYMBOL DOWN CARET TILDE"),
+	(0x2372, "APL FUNCTIONAL SYMBOL UP CARET TILDE"),
+	(0x2373, "APL FUNCTIONAL SYMBOL IOTA"),
+	(0x2374, "APL FUNCTIONAL SYMBOL RHO"),
+	(0x2375, "APL FUNCTIONAL SYMBOL OMEGA"),
+	(0x2376, "APL FUNCTIONAL SYMBOL ALPHA UNDERBAR"),
+	(0x2377, "APL FUNCTIONAL SYMBOL EPSILON UNDERBAR"),
+	(0x2378, "APL FUNCTIONAL SYMBOL IOTA UNDERBAR"),
+	(0x2379, "APL FUNCTIONAL SYMBOL OMEGA UNDERBAR"),
+	(0x237a, "APL FUNCTIONAL SYMBOL ALPHA"),
+	(0x237b, "NOT CHECK MARK"),
+	(0x237c, "RIGHT ANGLE WITH DOWNWARDS ZIGZAG ARROW"),
+	(0x237d, "SHOULDERED OPEN BOX"),
+	(0x237e, "BELL SYMBOL"),
+	(0x237f, "VERTICAL LINE WITH MIDDLE DOT"),
+	(0x2380, "INSERTION SYMBOL"),
+	(0x2381, "CONTINUOUS UNDERLINE SYMBOL"),
+	(0x2382, "DISCONTINUOUS UNDERLINE SYMBOL"),
+	(0x2383, "EMPHASIS SYMBOL"),
+	(0x2384, "COMPOSITION SYMBOL"),
+	(0x2385, "WHITE SQUARE WITH CENTRE VERTICAL LINE"),
+	(0x2386, "ENTER SYMBOL"),
+	(0x2387, "ALTERNATIVE KEY SYMBOL"),
+	(0x2388, "HELM SYMBOL"),
+	(0x2389, "CIRCLED HORIZONTAL BAR WITH NOTCH"),
+	(0x238a, "CIRCLED TRIANGLE DOWN"),
+	(0x238b, "BROKEN CIRCLE WITH NORTHWEST ARROW"),
+	(0x238c, "UNDO SYMBOL"),
+	(0x238d, "MONOSTABLE SYMBOL"),
+	(0x238e, "HYSTERESIS SYMBOL"),
+	(0x238f, "OPEN-CIRCUIT-OUTPUT H-TYPE SYMBOL"),
+	(0x2390, "OPEN-CIRCUIT-OUTPUT L-TYPE SYMBOL"),
+	(0x2391, "PASSIVE-PULL-DOWN-OUTPUT SYMBOL"),
+	(0x2392, "PASSIVE-PULL-UP-OUTPUT SYMBOL"),
+	(0x2393, "DIRECT CURRENT SYMBOL FORM TWO"),
+	(0x2394, "SOFTWARE-FUNCTION SYMBOL"),
+	(0x2395, "APL FUNCTIONAL SYMBOL QUAD"),
+	(0x2396, "DECIMAL SEPARATOR KEY SYMBOL"),
+	(0x2397, "PREVIOUS PAGE"),
+	(0x2398, "NEXT PAGE"),
+	(0x2399, "PRINT SCREEN SYMBOL"),
+	(0x239a, "CLEAR SCREEN SYMBOL"),
+	(0x239b, "LEFT PARENTHESIS UPPER HOOK"),
+	(0x239c, "LEFT PARENTHESIS EXTENSION"),
+	(0x239d, "LEFT PARENTHESIS LOWER HOOK"),
+	(0x239e, "RIGHT PARENTHESIS UPPER HOOK"),
+	(0x239f, "RIGHT PARENTHESIS EXTENSION"),
+	(0x23a0, "RIGHT PARENTHESIS LOWER HOOK"),
+	(0x23a1, "LEFT SQUARE BRACKET UPPER CORNER"),
+	(0x23a2, "LEFT SQUARE BRACKET EXTENSION"),
+	(0x23a3, "LEFT SQUARE BRACKET LOWER CORNER"),
+	(0x23a4, "RIGHT SQUARE BRACKET UPPER CORNER"),
+	(0x23a5, "RIGHT SQUARE BRACKET EXTENSION"),
+	(0x23a6, "RIGHT SQUARE BRACKET LOWER CORNER"),
+	(0x23a7, "LEFT CURLY BRACKET UPPER HOOK"),
+	(0x23a8, "LEFT CURLY BRACKET MIDDLE PIECE"),
+	(0x23a9, "LEFT CURLY BRACKET LOWER HOOK"),
+	(0x23aa, "CURLY BRACKET EXTENSION"),
+	(0x23ab, "RIGHT CURLY BRACKET UPPER HOOK"),
+	(0x23ac, "RIGHT CURLY BRACKET MIDDLE PIECE"),
+	(0x23ad, "RIGHT CURLY BRACKET LOWER HOOK"),
+	(0x23ae, "INTEGRAL EXTENSION"),
+	(0x23af, "HORIZONTAL LINE EXTENSION"),
+	(0x23b0, "UPPER LEFT OR LOWER RIGHT CURLY BRACKET SECTION"),
+	(0x23b1, "UPPER RIGHT OR LOWER LEFT CURLY BRACKET SECTION"),
+	(0x23b2, "SUMMATION TOP"),
+	(0x23b3, "SUMMATION BOTTOM"),
+	(0x23b4, "TOP SQUARE BRACKET"),
+	(0x23b5, "BOTTOM SQUARE BRACKET"),
+	(0x23b6, "BOTTOM SQUARE BRACKET OVER TOP SQUARE BRACKET"),
+	(0x23b7, "RADICAL SYMBOL BOTTOM"),
+	(0x23b8, "LEFT VERTICAL BOX LINE"),
+	(0x23b9, "RIGHT VERTICAL BOX LINE"),
+	(0x23ba, "HORIZONTAL SCAN LINE-1"),
+	(0x23bb, "HORIZONTAL SCAN LINE-3"),
+	(0x23bc, "HORIZONTAL SCAN LINE-7"),
+	(0x23bd, "HORIZONTAL SCAN LINE-9"),
+	(0x23be, "DENTISTRY SYMBOL LIGHT VERTICAL AND TOP RIGHT"),
+	(0x23bf, "DENTISTRY SYMBOL LIGHT VERTICAL AND BOTTOM RIGHT"),
+	(0x23c0, "DENTISTRY SYMBOL LIGHT VERTICAL WITH CIRCLE"),
+	(0x23c1, "DENTISTRY SYMBOL LIGHT DOWN AND HORIZONTAL WITH CIRCLE"),
+	(0x23c2, "DENTISTRY SYMBOL LIGHT UP AND HORIZONTAL WITH CIRCLE"),
+	(0x23c3, "DENTISTRY SYMBOL LIGHT VERTICAL WITH TRIANGLE"),
+	(0x23c4, "DENTISTRY SYMBOL LIGHT DOWN AND HORIZONTAL WITH TRIANGLE"),
+	(0x23c5, "DENTISTRY SYMBOL LIGHT UP AND HORIZONTAL WITH TRIANGLE"),
+	(0x23c6, "DENTISTRY SYMBOL LIGHT VERTICAL AND WAVE"),
+	(0x23c7, "DENTISTRY SYMBOL LIGHT DOWN AND HORIZONTAL WITH WAVE"),
+	(0x23c8, "DENTISTRY SYMBOL LIGHT UP AND HORIZONTAL WITH WAVE"),
+	(0x23c9, "DENTISTRY SYMBOL LIGHT DOWN AND HORIZONTAL"),
+	(0x23ca, "DENTISTRY SYMBOL LIGHT UP AND HORIZONTAL"),
+	(0x23cb, "DENTISTRY SYMBOL LIGHT VERTICAL AND TOP LEFT"),
+	(0x23cc, "DENTISTRY SYMBOL LIGHT VERTICAL AND BOTTOM LEFT"),
+	(0x23cd, "SQUARE FOOT"),
+	(0x23ce, "RETURN SYMBOL"),
+	(0x23cf, "EJECT SYMBOL"),
+	(0x23d0, "VERTICAL LINE EXTENSION"),
+	(0x23d1, "METRICAL BREVE"),
+	(0x23d2, "METRICAL LONG OVER SHORT"),
+	(0x23d3, "METRICAL SHORT OVER LONG"),
+	(0x23d4, "METRICAL LONG OVER TWO SHORTS"),
+	(0x23d5, "METRICAL TWO SHORTS OVER LONG"),
+	(0x23d6, "METRICAL TWO SHORTS JOINED"),
+	(0x23d7, "METRICAL TRISEME"),
+	(0x23d8, "METRICAL TETRASEME"),
+	(0x23d9, "METRICAL PENTASEME"),
+	(0x23da, "EARTH GROUND"),
+	(0x23db, "FUSE"),
+	(0x23dc, "TOP PARENTHESIS"),
+	(0x23dd, "BOTTOM PARENTHESIS"),
+	(0x23de, "TOP CURLY BRACKET"),
+	(0x23df, "BOTTOM CURLY BRACKET"),
+	(0x23e0, "TOP TORTOISE SHELL BRACKET"),
+	(0x23e1, "BOTTOM TORTOISE SHELL BRACKET"),
+	(0x23e2, "WHITE TRAPEZIUM"),
+	(0x23e3, "BENZENE RING WITH CIRCLE"),
+	(0x23e4, "STRAIGHTNESS"),
+	(0x23e5, "FLATNESS"),
+	(0x23e6, "AC CURRENT"),
+	(0x23e7, "ELECTRICAL INTERSECTION"),
+	(0x23e8, "DECIMAL EXPONENT SYMBOL"),
+	(0x23e9, "BLACK RIGHT-POINTING DOUBLE TRIANGLE"),
+	(0x23ea, "BLACK LEFT-POINTING DOUBLE TRIANGLE"),
+	(0x23eb, "BLACK UP-POINTING DOUBLE TRIANGLE"),
+	(0x23ec, "BLACK DOWN-POINTING DOUBLE TRIANGLE"),
+	(0x23ed, "BLACK RIGHT-POINTING DOUBLE TRIANGLE WITH VERTICAL BAR"),
+	(0x23ee, "BLACK LEFT-POINTING DOUBLE TRIANGLE WITH VERTICAL BAR"),
+	(0x23ef, "BLACK RIGHT-POINTING TRIANGLE WITH DOUBLE VERTICAL BAR"),
+	(0x23f0, "ALARM CLOCK"),
+	(0x23f1, "STOPWATCH"),
+	(0x23f2, "TIMER CLOCK"),
+	(0x23f3, "HOURGLASS WITH FLOWING SAND"),
+	(0x23f4, "BLACK MEDIUM LEFT-POINTING TRIANGLE"),
+	(0x23f5, "BLACK MEDIUM RIGHT-POINTING TRIANGLE"),
+	(0x23f6, "BLACK MEDIUM UP-POINTING TRIANGLE"),
+	(0x23f7, "BLACK MEDIUM DOWN-POINTING TRIANGLE"),
+	(0x23f8, "DOUBLE VERTICAL BAR"),
+	(0x23f9, "BLACK SQUARE FOR STOP"),
+	(0x23fa, "BLACK CIRCLE FOR RECORD"),
+	(0x23fb, "POWER SYMBOL"),
+	(0x23fc, "POWER ON-OFF SYMBOL"),
+	(0x23fd, "POWER ON SYMBOL"),
+	(0x23fe, "POWER SLEEP SYMBOL"),
+	(0x23ff, "OBSERVER EYE SYMBOL"),
+	(0x2400, "SYMBOL FOR NULL"),
+	(0x2401, "SYMBOL FOR START OF HEADING"),
+	(0x2402, "SYMBOL FOR START OF TEXT"),
+	(0x2403, "SYMBOL FOR END OF TEXT"),
+	(0x2404, "SYMBOL FOR END OF TRANSMISSION"),
+	(0x2405, "SYMBOL FOR ENQUIRY"),
+	(0x2406, "SYMBOL FOR ACKNOWLEDGE"),
+	(0x2407, "SYMBOL FOR BELL"),
+	(0x2408, "SYMBOL FOR BACKSPACE"),
+	(0x2409, "SYMBOL FOR HORIZONTAL TABULATION"),
+	(0x240a, "SYMBOL FOR LINE FEED"),
+	(0x240b, "SYMBOL FOR VERTICAL TABULATION"),
+	(0x240c, "SYMBOL FOR FORM FEED"),
+	(0x240d, "SYMBOL FOR CARRIAGE RETURN"),
+	(0x240e, "SYMBOL FOR SHIFT OUT"),
+	(0x240f, "SYMBOL FOR SHIFT IN"),
+	(0x2410, "SYMBOL FOR DATA LINK ESCAPE"),
+	(0x2411, "SYMBOL FOR DEVICE CONTROL ONE"),
+	(0x2412, "SYMBOL FOR DEVICE CONTROL TWO"),
+	(0x2413, "SYMBOL FOR DEVICE CONTROL THREE"),
+	(0x2414, "SYMBOL FOR DEVICE CONTROL FOUR"),
+	(0x2415, "SYMBOL FOR NEGATIVE ACKNOWLEDGE"),
+	(0x2416, "SYMBOL FOR SYNCHRONOUS IDLE"),
+	(0x2417, "SYMBOL FOR END OF TRANSMISSION BLOCK"),
+	(0x2418, "SYMBOL FOR CANCEL"),
+	(0x2419, "SYMBOL FOR END OF MEDIUM"),
+	(0x241a, "SYMBOL FOR SUBSTITUTE"),
+	(0x241b, "SYMBOL FOR ESCAPE"),
+	(0x241c, "SYMBOL FOR FILE SEPARATOR"),
+	(0x241d, "SYMBOL FOR GROUP SEPARATOR"),
+	(0x241e, "SYMBOL FOR RECORD SEPARATOR"),
+	(0x241f, "SYMBOL FOR UNIT SEPARATOR"),
+	(0x2420, "SYMBOL FOR SPACE"),
+	(0x2421, "SYMBOL FOR DELETE"),
+	(0x2422, "BLANK SYMBOL"),
+	(0x2423, "OPEN BOX"),
+	(0x2424, "SYMBOL FOR NEWLINE"),
+	(0x2425, "SYMBOL FOR DELETE FORM TWO"),
+	(0x2426, "SYMBOL FOR SUBSTITUTE FORM TWO"),
+	(0x2440, "OCR HOOK"),
+	(0x2441, "OCR CHAIR"),
+	(0x2442, "OCR FORK"),
+	(0x2443, "OCR INVERTED FORK"),
+	(0x2444, "OCR BELT BUCKLE"),
+	(0x2445, "OCR BOW TIE"),
+	(0x2446, "OCR BRANCH BANK IDENTIFICATION"),
+	(0x2447, "OCR AMOUNT OF CHECK"),
+	(0x2448, "OCR DASH"),
+	(0x2449, "OCR CUSTOMER ACCOUNT NUMBER"),
+	(0x244a, "OCR DOUBLE BACKSLASH"),
+	(0x2460, "CIRCLED DIGIT ONE"),
+	(0x2461, "CIRCLED DIGIT TWO"),
+	(0x2462, "CIRCLED DIGIT THREE"),
+	(0x2463, "CIRCLED DIGIT FOUR"),
+	(0x2464, "CIRCLED DIGIT FIVE"),
+	(0x2465, "CIRCLED DIGIT SIX"),
+	(0x2466, "CIRCLED DIGIT SEVEN"),
+	(0x2467, "CIRCLED DIGIT EIGHT"),
+	(0x2468, "CIRCLED DIGIT NINE"),
+	(0x2469, "CIRCLED NUMBER TEN"),
+	(0x246a, "CIRCLED NUMBER ELEVEN"),
+	(0x246b, "CIRCLED NUMBER TWELVE"),
+	(0x246c, "CIRCLED NUMBER THIRTEEN"),
+	(0x246d, "CIRCLED NUMBER FOURTEEN"),
+	(0x246e, "CIRCLED NUMBER FIFTEEN"),
+	(0x246f, "CIRCLED NUMBER SIXTEEN"),
+	(0x2470, "CIRCLED NUMBER SEVENTEEN"),
+	(0x2471, "CIRCLED NUMBER EIGHTEEN"),
+	(0x2472, "CIRCLED NUMBER NINETEEN"),
+	(0x2473, "CIRCLED NUMBER TWENTY"),
+	(0x2474, "PARENTHESIZED DIGIT ONE"),
+	(0x2475, "PARENTHESIZED DIGIT TWO"),
+	(0x2476, "PARENTHESIZED DIGIT THREE"),
+	(0x2477, "PARENTHESIZED DIGIT FOUR"),
+	(0x2478, "PARENTHESIZED DIGIT FIVE"),
+	(0x2479, "PARENTHESIZED DIGIT SIX"),
+	(0x247a, "PARENTHESIZED DIGIT SEVEN"),
+	(0x247b, "PARENTHESIZED DIGIT EIGHT"),
+	(0x247c, "PARENTHESIZED DIGIT NINE"),
+	(0x247d, "PARENTHESIZED NUMBER TEN"),
+	(0x247e, "PARENTHESIZED NUMBER ELEVEN"),
+	(0x247f, "PARENTHESIZED NUMBER TWELVE"),
+	(0x2480, "PARENTHESIZED NUMBER THIRTEEN"),
+	(0x2481, "PARENTHESIZED NUMBER FOURTEEN"),
+	(0x2482, "PARENTHESIZED NUMBER FIFTEEN"),
+	(0x2483, "PARENTHESIZED NUMBER SIXTEEN"),
+	(0x2484, "PARENTHESIZED NUMBER SEVENTEEN"),
+	(0x2485, "PARENTHESIZED NUMBER EIGHTEEN"),
+	(0x2486, "PARENTHESIZED NUMBER NINETEEN"),
+	(0x2487, "PARENTHESIZED NUMBER TWENTY"),
+	(0x2488, "DIGIT ONE FULL STOP"),
+	(0x2489, "DIGIT TWO FULL STOP"),
+	(0x248a, "DIGIT THREE FULL STOP"),
+	(0x248b, "DIGIT FOUR FULL STOP"),
+	(0x248c, "DIGIT FIVE FULL STOP"),
+	(0x248d, "DIGIT SIX FULL STOP"),
+	(0x248e, "DIGIT SEVEN FULL STOP"),
+	(0x248f, "DIGIT EIGHT FULL STOP"),
+	(0x2490, "DIGIT NINE FULL STOP"),
+	(0x2491, "NUMBER TEN FULL STOP"),
+	(0x2492, "NUMBER ELEVEN FULL STOP"),
+	(0x2493, "NUMBER TWELVE FULL STOP"),
+	(0x2494, "NUMBER THIRTEEN FULL STOP"),
+	(0x2495, "NUMBER FOURTEEN FULL STOP"),
+	(0x2496, "NUMBER FIFTEEN FULL STOP"),
+	(0x2497, "NUMBER SIXTEEN FULL STOP"),
+	(0x2498, "NUMBER SEVENTEEN FULL STOP"),
+	(0x2499, "NUMBER EIGHTEEN FULL STOP"),
+	(0x249a, "NUMBER NINETEEN FULL STOP"),
+	(0x249b, "NUMBER TWENTY FULL STOP"),
+	(0x249c, "PARENTHESIZED LATIN SMALL LETTER A"),
+	(0x249d, "PARENTHESIZED LATIN SMALL LETTER B"),
+	(0x249e, "PARENTHESIZED LATIN SMALL LETTER C"),
+	(0x249f, "PARENTHESIZED LATIN SMALL LETTER D"),
+	(0x24a0, "PARENTHESIZED LATIN SMALL LETTER E"),
+	(0x24a1, "PARENTHESIZED LATIN SMALL LETTER F"),
+	(0x24a2, "PARENTHESIZED LATIN SMALL LETTER G"),
+	(0x24a3, "PARENTHESIZED LATIN SMALL LETTER H"),
+	(0x24a4, "PARENTHESIZED LATIN SMALL LETTER I"),
+	(0x24a5, "PARENTHESIZED LATIN SMALL LETTER J"),
+	(0x24a6, "PARENTHESIZED LATIN SMALL LETTER K"),
+	(0x24a7, "PARENTHESIZED LATIN SMALL LETTER L"),
+	(0x24a8, "PARENTHESIZED LATIN SMALL LETTER M"),
+	(0x24a9, "PARENTHESIZED LATIN SMALL LETTER N"),
+	(0x24aa, "PARENTHESIZED LATIN SMALL LETTER O"),
+	(0x24ab, "PARENTHESIZED LATIN SMALL LETTER P"),
+	(0x24ac, "PARENTHESIZED LATIN SMALL LETTER Q"),
+	(0x24ad, "PARENTHESIZED LATIN SMALL LETTER R"),
+	(0x24ae, "PARENTHESIZED LATIN SMALL LETTER S"),
+	(0x24af, "PARENTHESIZED LATIN SMALL LETTER T"),
+	(0x24b0, "PARENTHESIZED LATIN SMALL LETTER U"),
+	(0x24b1, "PARENTHESIZED LATIN SMALL LETTER V"),
+	(0x24b2, "PARENTHESIZED LATIN SMALL LETTER W"),
+	(0x24b3, "PARENTHESIZED LATIN SMALL LETTER X"),
+	(0x24b4, "PARENTHESIZED LATIN SMALL LETTER Y"),
+	(0x24b5, "PARENTHESIZED LATIN SMALL LETTER Z"),
+	(0x24b6, "CIRCLED LATIN CAPITAL LETTER A"),
+	(0x24b7, "CIRCLED LATIN CAPITAL LETTER B"),
+	(0x24b8, "CIRCLED LATIN CAPITAL LETTER C"),
+	(0x24b9, "CIRCLED LATIN CAPITAL LETTER D"),
+	(0x24ba, "CIRCLED LATIN CAPITAL LETTER E"),
+	(0x24bb, "CIRCLED LATIN CAPITAL LETTER F"),
+	(0x24bc, "CIRCLED LATIN CAPITAL LETTER G"),
+	(0x24bd, "CIRCLED LATIN CAPITAL LETTER H"),
+	(0x24be, "CIRCLED LATIN CAPITAL LETTER I"),
+	(0x24bf, "CIRCLED LATIN CAPITAL LETTER J"),
+	(0x24c0, "CIRCLED LATIN CAPITAL LETTER K"),
+	(0x24c1, "CIRCLED LATIN CAPITAL LETTER L"),
+	(0x24c2, "CIRCLED LATIN CAPITAL LETTER M"),
+	(0x24c3, "CIRCLED LATIN CAPITAL LETTER N"),
+	(0x24c4, "CIRCLED LATIN CAPITAL LETTER O"),
+	(0x24c5, "CIRCLED LATIN CAPITAL LETTER P"),
+	(0x24c6, "CIRCLED LATIN CAPITAL LETTER Q"),
+	(0x24c7, "CIRCLED LATIN CAPITAL LETTER R"),
+	(0x24c8, "CIRCLED LATIN CAPITAL LETTER S"),
+	(0x24c9, "CIRCLED LATIN CAPITAL LETTER T"),
+	(0x24ca, "CIRCLED LATIN CAPITAL LETTER U"),
+	(0x24cb, "CIRCLED LATIN CAPITAL LETTER V"),
+	(0x24cc, "CIRCLED LATIN CAPITAL LETTER W"),
+	(0x24cd, "CIRCLED LATIN CAPITAL LETTER X"),
+	(0x24ce, "CIRCLED LATIN CAPITAL LETTER Y"),
+	(0x24cf, "CIRCLED LATIN CAPITAL LETTER Z"),
+	(0x24d0, "CIRCLED LATIN SMALL LETTER A"),
+	(0x24d1, "CIRCLED LATIN SMALL LETTER B"),
+	(0x24d2, "CIRCLED LATIN SMALL LETTER C"),
+	(0x24d3, "CIRCLED LATIN SMALL LETTER D"),
+	(0x24d4, "CIRCLED LATIN SMALL LETTER E"),
+	(0x24d5, "CIRCLED LATIN SMALL LETTER F"),
+	(0x24d6, "CIRCLED LATIN SMALL LETTER G"),
+	(0x24d7, "CIRCLED LATIN SMALL LETTER H"),
+	(0x24d8, "CIRCLED LATIN SMALL LETTER I"),
+	(0x24d9, "CIRCLED LATIN SMALL LETTER J"),
+	(0x24da, "CIRCLED LATIN SMALL LETTER K"),
+	(0x24db, "CIRCLED LATIN SMALL LETTER L"),
+	(0x24dc, "CIRCLED LATIN SMALL LETTER M"),
+	(0x24dd, "CIRCLED LATIN SMALL LETTER N"),
+	(0x24de, "CIRCLED LATIN SMALL LETTER O"),
+	(0x24df, "CIRCLED LATIN SMALL LETTER P"),
+	(0x24e0, "CIRCLED LATIN SMALL LETTER Q"),
+	(0x24e1, "CIRCLED LATIN SMALL LETTER R"),
+	(0x24e2, "CIRCLED LATIN SMALL LETTER S"),
+	(0x24e3, "CIRCLED LATIN SMALL LETTER T"),
+	(0x24e4, "CIRCLED LATIN SMALL LETTER U"),
+	(0x24e5, "CIRCLED LATIN SMALL LETTER V"),
+	(0x24e6, "CIRCLED LATIN SMALL LETTER W"),
+	(0x24e7, "CIRCLED LATIN SMALL LETTER X"),
+	(0x24e8, "CIRCLED LATIN SMALL LETTER Y"),
+	(0x24e9, "CIRCLED LATIN SMALL LETTER Z"),
+	(0x24ea, "CIRCLED DIGIT ZERO"),
+	(0x24eb, "NEGATIVE CIRCLED NUMBER ELEVEN"),
+	(0x24ec, "NEGATIVE CIRCLED NUMBER TWELVE"),
+	(0x24ed, "NEGATIVE CIRCLED NUMBER THIRTEEN"),
+	(0x24ee, "NEGATIVE CIRCLED NUMBER FOURTEEN"),
+	(0x24ef, "NEGATIVE CIRCLED NUMBER FIFTEEN"),
+	(0x24f0, "NEGATIVE CIRCLED NUMBER SIXTEEN"),
+	(0x24f1, "NEGATIVE CIRCLED NUMBER SEVENTEEN"),
+	(0x24f2, "NEGATIVE CIRCLED NUMBER EIGHTEEN"),
+	(0x24f3, "NEGATIVE CIRCLED NUMBER NINETEEN"),
+	(0x24f4, "NEGATIVE CIRCLED NUMBER TWENTY"),
+	(0x24f5, "DOUBLE CIRCLED DIGIT ONE"),
+	(0x24f6, "DOUBLE CIRCLED DIGIT TWO"),
+	(0x24f7, "DOUBLE CIRCLED DIGIT THREE"),
+	(0x24f8, "DOUBLE CIRCLED DIGIT FOUR"),
+	(0x24f9, "DOUBLE CIRCLED DIGIT FIVE"),
+	(0x24fa, "DOUBLE CIRCLED DIGIT SIX"),
+	(0x24fb, "DOUBLE CIRCLED DIGIT SEVEN"),
+	(0x24fc, "DOUBLE CIRCLED DIGIT EIGHT"),
+	(0x24fd, "DOUBLE CIRCLED DIGIT NINE"),
+	(0x24fe, "DOUBLE CIRCLED NUMBER TEN"),
+	(0x24ff, "NEGATIVE CIRCLED DIGIT ZERO"),
+	(0x2500, "BOX DRAWINGS LIGHT HORIZONTAL"),
+	(0x2501, "BOX DRAWINGS HEAVY HORIZONTAL"),
+	(0x2502, "BOX DRAWINGS LIGHT VERTICAL"),
+	(0x2503, "BOX DRAWINGS HEAVY VERTICAL"),
+	(0x2504, "BOX DRAWINGS LIGHT TRIPLE DASH HORIZONTAL"),
+	(0x2505, "BOX DRAWINGS HEAVY TRIPLE DASH HORIZONTAL"),
+	(0x2506, "BOX DRAWINGS LIGHT TRIPLE DASH VERTICAL"),
+	(0x2507, "BOX DRAWINGS HEAVY TRIPLE DASH VERTICAL"),
+	(0x2508, "BOX DRAWINGS LIGHT QUADRUPLE DASH HORIZONTAL"),
+	(0x2509, "BOX DRAWINGS HEAVY QUADRUPLE DASH HORIZONTAL"),
+	(0x250a, "BOX DRAWINGS LIGHT QUADRUPLE DASH VERTICAL"),
+	(0x250b, "BOX DRAWINGS HEAVY QUADRUPLE DASH VERTICAL"),
+	(0x250c, "BOX DRAWINGS LIGHT DOWN AND RIGHT"),
+	(0x250d, "BOX DRAWINGS DOWN LIGHT AND RIGHT HEAVY"),
+	(0x250e, "BOX DRAWINGS DOWN HEAVY AND RIGHT LIGHT"),
+	(0x250f, "BOX DRAWINGS HEAVY DOWN AND RIGHT"),
+	(0x2510, "BOX DRAWINGS LIGHT DOWN AND LEFT"),
+	(0x2511, "BOX DRAWINGS DOWN LIGHT AND LEFT HEAVY"),
+	(0x2512, "BOX DRAWINGS DOWN HEAVY AND LEFT LIGHT"),
+	(0x2513, "BOX DRAWINGS HEAVY DOWN AND LEFT"),
+	(0x2514, "BOX DRAWINGS LIGHT UP AND RIGHT"),
+	(0x2515, "BOX DRAWINGS UP LIGHT AND RIGHT HEAVY"),
+	(0x2516, "BOX DRAWINGS UP HEAVY AND RIGHT LIGHT"),
+	(0x2517, "BOX DRAWINGS HEAVY UP AND RIGHT"),
+	(0x2518, "BOX DRAWINGS LIGHT UP AND LEFT"),
+	(0x2519, "BOX DRAWINGS UP LIGHT AND LEFT HEAVY"),
+	(0x251a, "BOX DRAWINGS UP HEAVY AND LEFT LIGHT"),
+	(0x251b, "BOX DRAWINGS HEAVY UP AND LEFT"),
+	(0x251c, "BOX DRAWINGS LIGHT VERTICAL AND RIGHT"),
+	(0x251d, "BOX DRAWINGS VERTICAL LIGHT AND RIGHT HEAVY"),
+	(0x251e, "BOX DRAWINGS UP HEAVY AND RIGHT DOWN LIGHT"),
+	(0x251f, "BOX DRAWINGS DOWN HEAVY AND RIGHT UP LIGHT"),
+	(0x2520, "BOX DRAWINGS VERTICAL HEAVY AND RIGHT LIGHT"),
+	(0x2521, "BOX DRAWINGS DOWN LIGHT AND RIGHT UP HEAVY"),
+	(0x2522, "BOX DRAWINGS UP LIGHT AND RIGHT DOWN HEAVY"),
+	(0x2523, "BOX DRAWINGS HEAVY VERTICAL AND RIGHT"),
+	(0x2524, "BOX DRAWINGS LIGHT VERTICAL AND LEFT"),
+	(0x2525, "BOX DRAWINGS VERTICAL LIGHT AND LEFT HEAVY"),
+	(0x2526, "BOX DRAWINGS UP HEAVY AND LEFT DOWN LIGHT"),
+	(0x2527, "BOX DRAWINGS DOWN HEAVY AND LEFT UP LIGHT"),
+	(0x2528, "BOX DRAWINGS VERTICAL HEAVY AND LEFT LIGHT"),
+	(0x2529, "BOX DRAWINGS DOWN LIGHT AND LEFT UP HEAVY"),
+	(0x252a, "BOX DRAWINGS UP LIGHT AND LEFT DOWN HEAVY"),
+	(0x252b, "BOX DRAWINGS HEAVY VERTICAL AND LEFT"),
+	(0x252c, "BOX DRAWINGS LIGHT DOWN AND HORIZONTAL"),
+	(0x252d, "BOX DRAWINGS LEFT HEAVY AND RIGHT DOWN LIGHT"),
+	(0x252e, "BOX DRAWINGS RIGHT HEAVY AND LEFT DOWN LIGHT"),
+	(0x252f, "BOX DRAWINGS DOWN LIGHT AND HORIZONTAL HEAVY"),
+	(0x2530, "BOX DRAWINGS DOWN HEAVY AND HORIZONTAL LIGHT"),
+	(0x2531, "BOX DRAWINGS RIGHT LIGHT AND LEFT DOWN HEAVY"),
+	(0x2532, "BOX DRAWINGS LEFT LIGHT AND RIGHT DOWN HEAVY"),
+	(0x2533, "BOX DRAWINGS HEAVY DOWN AND HORIZONTAL"),
+	(0x2534, "BOX DRAWINGS LIGHT UP AND HORIZONTAL"),
+	(0x2535, "BOX DRAWINGS LEFT HEAVY AND RIGHT UP LIGHT"),
+	(0x2536, "BOX DRAWINGS RIGHT HEAVY AND LEFT UP LIGHT"),
+	(0x2537, "BOX DRAWINGS UP LIGHT AND HORIZONTAL HEAVY"),
+	(0x2538, "BOX DRAWINGS UP HEAVY AND HORIZONTAL LIGHT"),
+	(0x2539, "BOX DRAWINGS RIGHT LIGHT AND LEFT UP HEAVY"),
+	(0x253a, "BOX DRAWINGS LEFT LIGHT AND RIGHT UP HEAVY"),
+	(0x253b, "BOX DRAWINGS HEAVY UP AND HORIZONTAL"),
+	(0x253c, "BOX DRAWINGS LIGHT VERTICAL AND HORIZONTAL"),
+	(0x253d, "BOX DRAWINGS LEFT HEAVY AND RIGHT VERTICAL LIGHT"),
+	(0x253e, "BOX DRAWINGS RIGHT HEAVY AND LEFT VERTICAL LIGHT"),
+	(0x253f, "BOX DRAWINGS VERTICAL LIGHT AND HORIZONTAL HEAVY"),
+	(0x2540, "BOX DRAWINGS UP HEAVY AND DOWN HORIZONTAL LIGHT"),
+	(0x2541, "BOX DRAWINGS DOWN HEAVY AND UP HORIZONTAL LIGHT"),
+	(0x2542, "BOX DRAWINGS VERTICAL HEAVY AND HORIZONTAL LIGHT"),
+	(0x2543, "BOX DRAWINGS LEFT UP HEAVY AND RIGHT DOWN LIGHT"),
+	(0x2544, "BOX DRAWINGS RIGHT UP HEAVY AND LEFT DOWN LIGHT"),
+	(0x2545, "BOX DRAWINGS LEFT DOWN HEAVY AND RIGHT UP LIGHT"),
+	(0x2546, "BOX DRAWINGS RIGHT DOWN HEAVY AND LEFT UP LIGHT"),
+	(0x2547, "BOX DRAWINGS DOWN LIGHT AND UP HORIZONTAL HEAVY"),
+	(0x2548, "BOX DRAWINGS UP LIGHT AND DOWN HORIZONTAL HEAVY"),
+	(0x2549, "BOX DRAWINGS RIGHT LIGHT AND LEFT VERTICAL HEAVY"),
+	(0x254a, "BOX DRAWINGS LEFT LIGHT AND RIGHT VERTICAL HEAVY"),
+	(0x254b, "BOX DRAWINGS HEAVY VERTICAL AND HORIZONTAL"),
+	(0x254c, "BOX DRAWINGS LIGHT DOUBLE DASH HORIZONTAL"),
+	(0x254d, "BOX DRAWINGS HEAVY DOUBLE DASH HORIZONTAL"),
+	(0x254e, "BOX DRAWINGS LIGHT DOUBLE DASH VERTICAL"),
+	(0x254f, "BOX DRAWINGS HEAVY DOUBLE DASH VERTICAL"),
+	(0x2550, "BOX DRAWINGS DOUBLE HORIZONTAL"),
+	(0x2551, "BOX DRAWINGS DOUBLE VERTICAL"),
+	(0x2552, "BOX DRAWINGS DOWN SINGLE AND RIGHT DOUBLE"),
+	(0x2553, "BOX DRAWINGS DOWN DOUBLE AND RIGHT SINGLE"),
+	(0x2554, "BOX DRAWINGS DOUBLE DOWN AND RIGHT"),
+	(0x2555, "BOX DRAWINGS DOWN SINGLE AND LEFT DOUBLE"),
+	(0x2556, "BOX DRAWINGS DOWN DOUBLE AND LEFT SINGLE"),
+	(0x2557, "BOX DRAWINGS DOUBLE DOWN AND LEFT"),
+	(0x2558, "BOX DRAWINGS UP SINGLE AND RIGHT DOUBLE"),
+	(0x2559, "BOX DRAWINGS UP DOUBLE AND RIGHT SINGLE"),
+	(0x255a, "BOX DRAWINGS DOUBLE UP AND RIGHT"),
+	(0x255b, "BOX DRAWINGS UP SINGLE AND LEFT DOUBLE"),
+	(0x255c, "BOX DRAWINGS UP DOUBLE AND LEFT SINGLE"),
+	(0x255d, "BOX DRAWINGS DOUBLE UP AND LEFT"),
+	(0x255e, "BOX DRAWINGS VERTICAL SINGLE AND RIGHT DOUBLE"),
+	(0x255f, "BOX DRAWINGS VERTICAL DOUBLE AND RIGHT SINGLE"),
+	(0x2560, "BOX DRAWINGS DOUBLE VERTICAL AND RIGHT"),
+	(0x2561, "BOX DRAWINGS VERTICAL SINGLE AND LEFT DOUBLE"),
+	(0x2562, "BOX DRAWINGS VERTICAL DOUBLE AND LEFT SINGLE"),
+	(0x2563, "BOX DRAWINGS DOUBLE VERTICAL AND LEFT"),
+	(0x2564, "BOX DRAWINGS DOWN SINGLE AND HORIZONTAL DOUBLE"),
+	(0x2565, "BOX DRAWINGS DOWN DOUBLE AND HORIZONTAL SINGLE"),
+	(0x2566, "BOX DRAWINGS DOUBLE DOWN AND HORIZONTAL"),
+	(0x2567, "BOX DRAWINGS UP SINGLE AND HORIZONTAL DOUBLE"),
+	(0x2568, "BOX DRAWINGS UP DOUBLE AND HORIZONTAL SINGLE"),
+	(0x2569, "BOX DRAWINGS DOUBLE UP AND HORIZONTAL"),
+	(0x256a, "BOX DRAWINGS VERTICAL SINGLE AND HORIZONTAL DOUBLE"),
+	(0x256b, "BOX DRAWINGS VERTICAL DOUBLE AND HORIZONTAL SINGLE"),
+	(0x256c, "BOX DRAWINGS DOUBLE VERTICAL AND HORIZONTAL"),
+	(0x256d, "BOX DRAWINGS LIGHT ARC DOWN AND RIGHT"),
+	(0x256e, "BOX DRAWINGS LIGHT ARC DOWN AND LEFT"),
+	(0x256f, "BOX DRAWINGS LIGHT ARC UP AND LEFT"),
+	(0x2570, "BOX DRAWINGS LIGHT ARC UP AND RIGHT"),
+	(0x2571, "BOX DRAWINGS LIGHT DIAGONAL UPPER RIGHT TO LOWER LEFT"),
+	(0x2572, "BOX DRAWINGS LIGHT DIAGONAL UPPER LEFT TO LOWER RIGHT"),
+	(0x2573, "BOX DRAWINGS LIGHT DIAGONAL CROSS"),
+	(0x2574, "BOX DRAWINGS LIGHT LEFT"),
+	(0x2575, "BOX DRAWINGS LIGHT UP"),
+	(0x2576, "BOX DRAWINGS LIGHT RIGHT"),
+	(0x2577, "BOX DRAWINGS LIGHT DOWN"),
+	(0x2578, "BOX DRAWINGS HEAVY LEFT"),
+	(0x2579, "BOX DRAWINGS HEAVY UP"),
+	(0x257a, "BOX DRAWINGS HEAVY RIGHT"),
+	(0x257b, "BOX DRAWINGS HEAVY DOWN"),
+	(0x257c, "BOX DRAWINGS LIGHT LEFT AND HEAVY RIGHT"),
+	(0x257d, "BOX DRAWINGS LIGHT UP AND HEAVY DOWN"),
+	(0x257e, "BOX DRAWINGS HEAVY LEFT AND LIGHT RIGHT"),
+	(0x257f, "BOX DRAWINGS HEAVY UP AND LIGHT DOWN"),
+	(0x2580, "UPPER HALF BLOCK"),
+	(0x2581, "LOWER ONE EIGHTH BLOCK"),
+	(0x2582, "LOWER ONE QUARTER BLOCK"),
+	(0x2583, "LOWER THREE EIGHTHS BLOCK"),
+	(0x2584, "LOWER HALF BLOCK"),
+	(0x2585, "LOWER FIVE EIGHTHS BLOCK"),
+	(0x2586, "LOWER THREE QUARTERS BLOCK"),
+	(0x2587, "LOWER SEVEN EIGHTHS BLOCK"),
+	(0x2588, "FULL BLOCK"),
+	(0x2589, "LEFT SEVEN EIGHTHS BLOCK"),
+	(0x258a, "LEFT THREE QUARTERS BLOCK"),
+	(0x258b, "LEFT FIVE EIGHTHS BLOCK"),
+	(0x258c, "LEFT HALF BLOCK"),
+	(0x258d, "LEFT THREE EIGHTHS BLOCK"),
+	(0x258e, "LEFT ONE QUARTER BLOCK"),
+	(0x258f, "LEFT ONE EIGHTH BLOCK"),
+	(0x2590, "RIGHT HALF BLOCK"),
+	(0x2591, "LIGHT SHADE"),
+	(0x2592, "MEDIUM SHADE"),
+	(0x2593, "DARK SHADE"),
+	(0x2594, "UPPER ONE EIGHTH BLOCK"),
+	(0x2595, "RIGHT ONE EIGHTH BLOCK"),
+	(0x2596, "QUADRANT LOWER LEFT"),
+	(0x2597, "QUADRANT LOWER RIGHT"),
+	(0x2598, "QUADRANT UPPER LEFT"),
+	(0x2599, "QUADRANT UPPER LEFT AND LOWER LEFT AND LOWER RIGHT"),
+	(0x259a, "QUADRANT UPPER LEFT AND LOWER RIGHT"),
+	(0x259b, "QUADRANT UPPER LEFT AND UPPER RIGHT AND LOWER LEFT"),
+	(0x259c, "QUADRANT UPPER LEFT AND UPPER RIGHT AND LOWER RIGHT"),
+	(0x259d, "QUADRANT UPPER RIGHT"),
+	(0x259e, "QUADRANT UPPER RIGHT AND LOWER LEFT"),
+	(0x259f, "QUADRANT UPPER RIGHT AND LOWER LEFT AND LOWER RIGHT"),
+	(0x25a0, "BLACK SQUARE"),
+	(0x25a1, "WHITE SQUARE"),
+	(0x25a2, "WHITE SQUARE WITH ROUNDED CORNERS"),
+	(0x25a3, "WHITE SQUARE CONTAINING BLACK SMALL SQUARE"),
+	(0x25a4, "SQUARE WITH HORIZONTAL FILL"),
+	(0x25a5, "SQUARE WITH VERTICAL FILL"),
+	(0x25a6, "SQUARE WITH ORTHOGONAL CROSSHATCH FILL"),
+	(0x25a7, "SQUARE WITH UPPER LEFT TO LOWER RIGHT FILL"),
+	(0x25a8, "SQUARE WITH UPPER RIGHT TO LOWER LEFT FILL"),
+	(0x25a9, "SQUARE WITH DIAGONAL CROSSHATCH FILL"),
+	(0x25aa, "BLACK SMALL SQUARE"),
+	(0x25ab, "WHITE SMALL SQUARE"),
+	(0x25ac, "BLACK RECTANGLE"),
+	(0x25ad, "WHITE RECTANGLE"),
+	(0x25ae, "BLACK VERTICAL RECTANGLE"),
+	(0x25af, "WHITE VERTICAL RECTANGLE"),
+	(0x25b0, "BLACK PARALLELOGRAM"),
+	(0x25b1, "WHITE PARALLELOGRAM"),
+	(0x25b2, "BLACK UP-POINTING TRIANGLE"),
+	(0x25b3, "WHITE UP-POINTING TRIANGLE"),
+	(0x25b4, "BLACK UP-POINTING SMALL TRIANGLE"),
+	(0x25b5, "WHITE UP-POINTING SMALL TRIANGLE"),
+	(0x25b6, "BLACK RIGHT-POINTING TRIANGLE"),
+	(0x25b7, "WHITE RIGHT-POINTING TRIANGLE"),
+	(0x25b8, "BLACK RIGHT-POINTING SMALL TRIANGLE"),
+	(0x25b9, "WHITE RIGHT-POINTING SMALL TRIANGLE"),
+	(0x25ba, "BLACK RIGHT-POINTING POINTER"),
+	(0x25bb, "WHITE RIGHT-POINTING POINTER"),
+	(0x25bc, "BLACK DOWN-POINTING TRIANGLE"),
+	(0x25bd, "WHITE DOWN-POINTING TRIANGLE"),
+	(0x25be, "BLACK DOWN-POINTING SMALL TRIANGLE"),
+	(0x25bf, "WHITE DOWN-POINTING SMALL TRIANGLE"),
+	(0x25c0, "BLACK LEFT-POINTING TRIANGLE"),
+	(0x25c1, "WHITE LEFT-POINTING TRIANGLE"),
+	(0x25c2, "BLACK LEFT-POINTING SMALL TRIANGLE"),
+	(0x25c3, "WHITE LEFT-POINTING SMALL TRIANGLE"),
+	(0x25c4, "BLACK LEFT-POINTING POINTER"),
+	(0x25c5, "WHITE LEFT-POINTING POINTER"),
+	(0x25c6, "BLACK DIAMOND"),
+	(0x25c7, "WHITE DIAMOND"),
+	(0x25c8, "WHITE DIAMOND CONTAINING BLACK SMALL DIAMOND"),
+	(0x25c9, "FISHEYE"),
+	(0x25ca, "LOZENGE"),
+	(0x25cb, "WHITE CIRCLE"),
+	(0x25cc, "DOTTED CIRCLE"),
+	(0x25cd, "CIRCLE WITH VERTICAL FILL"),
+	(0x25ce, "BULLSEYE"),
+	(0x25cf, "BLACK CIRCLE"),
+	(0x25d0, "CIRCLE WITH LEFT HALF BLACK"),
+	(0x25d1, "CIRCLE WITH RIGHT HALF BLACK"),
+	(0x25d2, "CIRCLE WITH LOWER HALF BLACK"),
+	(0x25d3, "CIRCLE WITH UPPER HALF BLACK"),
+	(0x25d4, "CIRCLE WITH UPPER RIGHT QUADRANT BLACK"),
+	(0x25d5, "CIRCLE WITH ALL BUT UPPER LEFT QUADRANT BLACK"),
+	(0x25d6, "LEFT HALF BLACK CIRCLE"),
+	(0x25d7, "RIGHT HALF BLACK CIRCLE"),
+	(0x25d8, "INVERSE BULLET"),
+	(0x25d9, "INVERSE WHITE CIRCLE"),
+	(0x25da, "UPPER HALF INVERSE WHITE CIRCLE"),
+	(0x25db, "LOWER HALF INVERSE WHITE CIRCLE"),
+	(0x25dc, "UPPER LEFT QUADRANT CIRCULAR ARC"),
+	(0x25dd, "UPPER RIGHT QUADRANT CIRCULAR ARC"),
+	(0x25de, "LOWER RIGHT QUADRANT CIRCULAR ARC"),
+	(0x25df, "LOWER LEFT QUADRANT CIRCULAR ARC"),
+	(0x25e0, "UPPER HALF CIRCLE"),
+	(0x25e1, "LOWER HALF CIRCLE"),
+	(0x25e2, "BLACK LOWER RIGHT TRIANGLE"),
+	(0x25e3, "BLACK LOWER LEFT TRIANGLE"),
+	(0x25e4, "BLACK UPPER LEFT TRIANGLE"),
+	(0x25e5, "BLACK UPPER RIGHT TRIANGLE"),
+	(0x25e6, "WHITE BULLET"),
+	(0x25e7, "SQUARE WITH LEFT HALF BLACK"),
+	(0x25e8, "SQUARE WITH RIGHT HALF BLACK"),
+	(0x25e9, "SQUARE WITH UPPER LEFT DIAGONAL HALF BLACK"),
+	(0x25ea, "SQUARE WITH LOWER RIGHT DIAGONAL HALF BLACK"),
+	(0x25eb, "WHITE SQUARE WITH VERTICAL BISECTING LINE"),
+	(0x25ec, "WHITE UP-POINTING TRIANGLE WITH DOT"),
+	(0x25ed, "UP-POINTING TRIANGLE WITH LEFT HALF BLACK"),
+	(0x25ee, "UP-POINTING TRIANGLE WITH RIGHT HALF BLACK"),
+	(0x25ef, "LARGE CIRCLE"),
+	(0x25f0, "WHITE SQUARE WITH UPPER LEFT QUADRANT"),
+	(0x25f1, "WHITE SQUARE WITH LOWER LEFT QUADRANT"),
+	(0x25f2, "WHITE SQUARE WITH LOWER RIGHT QUADRANT"),
+	(0x25f3, "WHITE SQUARE WITH UPPER RIGHT QUADRANT"),
+	(0x25f4, "WHITE CIRCLE WITH UPPER LEFT QUADRANT"),
+	(0x25f5, "WHITE CIRCLE WITH LOWER LEFT QUADRANT"),
+	(0x25f6, "WHITE CIRCLE WITH LOWER RIGHT QUADRANT"),
+	(0x25f7, "WHITE CIRCLE WITH UPPER RIGHT QUADRANT"),
+	(0x25f8, "UPPER LEFT TRIANGLE"),
+	(0x25f9, "UPPER RIGHT TRIANGLE"),
+	(0x25fa, "LOWER LEFT TRIANGLE"),
+	(0x25fb, "WHITE MEDIUM SQUARE"),
+	(0x25fc, "BLACK MEDIUM SQUARE"),
+	(0x25fd, "WHITE MEDIUM SMALL SQUARE"),
+	(0x25fe, "BLACK MEDIUM SMALL SQUARE"),
+	(0x25ff, "LOWER RIGHT TRIANGLE"),
+	(0x2600, "BLACK SUN WITH RAYS"),
+	(0x2601, "CLOUD"),
+	(0x2602, "UMBRELLA"),
+	(0x2603, "SNOWMAN"),
+	(0x2604, "COMET"),
+	(0x2605, "BLACK STAR"),
+	(0x2606, "WHITE STAR"),
+	(0x2607, "LIGHTNING"),
+	(0x2608, "THUNDERSTORM"),
+	(0x2609, "SUN"),
+	(0x260a, "ASCENDING NODE"),
+	(0x260b, "DESCENDING NODE"),
+	(0x260c, "CONJUNCTION"),
+	(0x260d, "OPPOSITION"),
+	(0x260e, "BLACK TELEPHONE"),
+	(0x260f, "WHITE TELEPHONE"),
+	(0x2610, "BALLOT BOX"),
+	(0x2611, "BALLOT BOX WITH CHECK"),
+	(0x2612, "BALLOT BOX WITH X"),
+	(0x2613, "SALTIRE"),
+	(0x2614, "UMBRELLA WITH RAIN DROPS"),
+	(0x2615, "HOT BEVERAGE"),
+	(0x2616, "WHITE SHOGI PIECE"),
+	(0x2617, "BLACK SHOGI PIECE"),
+	(0x2618, "SHAMROCK"),
+	(0x2619, "REVERSED ROTATED FLORAL HEART BULLET"),
+	(0x261a, "BLACK LEFT POINTING INDEX"),
+	(0x261b, "BLACK RIGHT POINTING INDEX"),
+	(0x261c, "WHITE LEFT POINTING INDEX"),
+	(0x261d, "WHITE UP POINTING INDEX"),
+	(0x261e, "WHITE RIGHT POINTING INDEX"),
+	(0x261f, "WHITE DOWN POINTING INDEX"),
+	(0x2620, "SKULL AND CROSSBONES"),
+	(0x2621, "CAUTION SIGN"),
+	(0x2622, "RADIOACTIVE SIGN"),
+	(0x2623, "BIOHAZARD SIGN"),
+	(0x2624, "CADUCEUS"),
+	(0x2625, "ANKH"),
+	(0x2626, "ORTHODOX CROSS"),
+	(0x2627, "CHI RHO"),
+	(0x2628, "CROSS OF LORRAINE"),
+	(0x2629, "CROSS OF JERUSALEM"),
+	(0x262a, "STAR AND CRESCENT"),
+	(0x262b, "FARSI SYMBOL"),
+	(0x262c, "ADI SHAKTI"),
+	(0x262d, "HAMMER AND SICKLE"),
+	(0x262e, "PEACE SYMBOL"),
+	(0x262f, "YIN YANG"),
+	(0x2630, "TRIGRAM FOR HEAVEN"),
+	(0x2631, "TRIGRAM FOR LAKE"),
+	(0x2632, "TRIGRAM FOR FIRE"),
+	(0x2633, "TRIGRAM FOR THUNDER"),
+	(0x2634, "TRIGRAM FOR WIND"),
+	(0x2635, "TRIGRAM FOR WATER"),
+	(0x2636, "TRIGRAM FOR MOUNTAIN"),
+	(0x2637, "TRIGRAM FOR EARTH"),
+	(0x2638, "WHEEL OF DHARMA"),
+	(0x2639, "WHITE FROWNING FACE"),
+	(0x263a, "WHITE SMILING FACE"),
+	(0x263b, "BLACK SMILING FACE"),
+	(0x263c, "WHITE SUN WITH RAYS"),
+	(0x263d, "FIRST QUARTER MOON"),
+	(0x263e, "LAST QUARTER MOON"),
+	(0x263f, "MERCURY"),
+	(0x2640, "FEMALE SIGN"),
+	(0x2641, "EARTH"),
+	(0x2642, "MALE SIGN"),
+	(0x2643, "JUPITER"),
+	(0x2644, "SATURN"),
+	(0x2645, "URANUS"),
+	(0x2646, "NEPTUNE"),
+	(0x2647, "PLUTO"),
+	(0x2648, "ARIES"),
+	(0x2649, "TAURUS"),
+	(0x264a, "GEMINI"),
+	(0x264b, "CANCER"),
+	(0x264c, "LEO"),
+	(0x264d, "VIRGO"),
+	(0x264e, "LIBRA"),
+	(0x264f, "SCORPIUS"),
+	(0x2650, "SAGITTARIUS"),
+	(0x2651, "CAPRICORN"),
+	(0x2652, "AQUARIUS"),
+	(0x2653, "PISCES"),
+	(0x2654, "WHITE CHESS KING"),
+	(0x2655, "WHITE CHESS QUEEN"),
+	(0x2656, "WHITE CHESS ROOK"),
+	(0x2657, "WHITE CHESS BISHOP"),
+	(0x2658, "WHITE CHESS KNIGHT"),
+	(0x2659, "WHITE CHESS PAWN"),
+	(0x265a, "BLACK CHESS KING"),
+	(0x265b, "BLACK CHESS QUEEN"),
+	(0x265c, "BLACK CHESS ROOK"),
+	(0x265d, "BLACK CHESS BISHOP"),
+	(0x265e, "BLACK CHESS KNIGHT"),
+	(0x265f, "BLACK CHESS PAWN"),
+	(0x2660, "BLACK SPADE SUIT"),
+	(0x2661, "WHITE HEART SUIT"),
+	(0x2662, "WHITE DIAMOND SUIT"),
+	(0x2663, "BLACK CLUB SUIT"),
+	(0x2664, "WHITE SPADE SUIT"),
+	(0x2665, "BLACK HEART SUIT"),
+	(0x2666, "BLACK DIAMOND SUIT"),
+	(0x2667, "WHITE CLUB SUIT"),
+	(0x2668, "HOT SPRINGS"),
+	(0x2669, "QUARTER NOTE"),
+	(0x266a, "EIGHTH NOTE"),
+	(0x266b, "BEAMED EIGHTH NOTES"),
+	(0x266c, "BEAMED SIXTEENTH NOTES"),
+	(0x266d, "MUSIC FLAT SIGN"),
+	(0x266e, "MUSIC NATURAL SIGN"),
+	(0x266f, "MUSIC SHARP SIGN"),
+	(0x2670, "WEST SYRIAC CROSS"),
+	(0x2671, "EAST SYRIAC CROSS"),
+	(0x2672, "UNIVERSAL RECYCLING SYMBOL"),
+	(0x2673, "RECYCLING SYMBOL FOR TYPE-1 PLASTICS"),
+	(0x2674, "RECYCLING SYMBOL FOR TYPE-2 PLASTICS"),
+	(0x2675, "RECYCLING SYMBOL FOR TYPE-3 PLASTICS"),
+	(0x2676, "RECYCLING SYMBOL FOR TYPE-4 PLASTICS"),
+	(0x2677, "RECYCLING SYMBOL FOR TYPE-5 PLASTICS"),
+	(0x2678, "RECYCLING SYMBOL FOR TYPE-6 PLASTICS"),
+	(0x2679, "RECYCLING SYMBOL FOR TYPE-7 PLASTICS"),
+	(0x267a, "RECYCLING SYMBOL FOR GENERIC MATERIALS"),
+	(0x267b, "BLACK UNIVERSAL RECYCLING SYMBOL"),
+	(0x267c, "RECYCLED PAPER SYMBOL"),
+	(0x267d, "PARTIALLY-RECYCLED PAPER SYMBOL"),
+	(0x267e, "PERMANENT PAPER SIGN"),
+	(0x267f, "WHEELCHAIR SYMBOL"),
+	(0x2680, "DIE FACE-1"),
+	(0x2681, "DIE FACE-2"),
+	(0x2682, "DIE FACE-3"),
+	(0x2683, "DIE FACE-4"),
+	(0x2684, "DIE FACE-5"),
+	(0x2685, "DIE FACE-6"),
+	(0x2686, "WHITE CIRCLE WITH DOT RIGHT"),
+	(0x2687, "WHITE CIRCLE WITH TWO DOTS"),
+	(0x2688, "BLACK CIRCLE WITH WHITE DOT RIGHT"),
+	(0x2689, "BLACK CIRCLE WITH TWO WHITE DOTS"),
+	(0x268a, "MONOGRAM FOR YANG"),
+	(0x268b, "MONOGRAM FOR YIN"),
+	(0x268c, "DIGRAM FOR GREATER YANG"),
+	(0x268d, "DIGRAM FOR LESSER YIN"),
+	(0x268e, "DIGRAM FOR LESSER YANG"),
+	(0x268f, "DIGRAM FOR GREATER YIN"),
+	(0x2690, "WHITE FLAG"),
+	(0x2691, "BLACK FLAG"),
+	(0x2692, "HAMMER AND PICK"),
+	(0x2693, "ANCHOR"),
+	(0x2694, "CROSSED SWORDS"),
+	(0x2695, "STAFF OF AESCULAPIUS"),
+	(0x2696, "SCALES"),
+	(0x2697, "ALEMBIC"),
+	(0x2698, "FLOWER"),
+	(0x2699, "GEAR"),
+	(0x269a, "STAFF OF HERMES"),
+	(0x269b, "ATOM SYMBOL"),
+	(0x269c, "FLEUR-DE-LIS"),
+	(0x269d, "OUTLINED WHITE STAR"),
+	(0x269e, "THREE LINES CONVERGING RIGHT"),
+	(0x269f, "THREE LINES CONVERGING LEFT"),
+	(0x26a0, "WARNING SIGN"),
+	(0x26a1, "HIGH VOLTAGE SIGN"),
+	(0x26a2, "DOUBLED FEMALE SIGN"),
+	(0x26a3, "DOUBLED MALE SIGN"),
+	(0x26a4, "INTERLOCKED FEMALE AND MALE SIGN"),
+	(0x26a5, "MALE AND FEMALE SIGN"),
+	(0x26a6, "MALE WITH STROKE SIGN"),
+	(0x26a7, "MALE WITH STROKE AND MALE AND FEMALE SIGN"),
+	(0x26a8, "VERTICAL MALE WITH STROKE SIGN"),
+	(0x26a9, "HORIZONTAL MALE WITH STROKE SIGN"),
+	(0x26aa, "MEDIUM WHITE CIRCLE"),
+	(0x26ab, "MEDIUM BLACK CIRCLE"),
+	(0x26ac, "MEDIUM SMALL WHITE CIRCLE"),
+	(0x26ad, "MARRIAGE SYMBOL"),
+	(0x26ae, "DIVORCE SYMBOL"),
+	(0x26af, "UNMARRIED PARTNERSHIP SYMBOL"),
+	(0x26b0, "COFFIN"),
+	(0x26b1, "FUNERAL URN"),
+	(0x26b2, "NEUTER"),
+	(0x26b3, "CERES"),
+	(0x26b4, "PALLAS"),
+	(0x26b5, "JUNO"),
+	(0x26b6, "VESTA"),
+	(0x26b7, "CHIRON"),
+	(0x26b8, "BLACK MOON LILITH"),
+	(0x26b9, "SEXTILE"),
+	(0x26ba, "SEMISEXTILE"),
+	(0x26bb, "QUINCUNX"),
+	(0x26bc, "SESQUIQUADRATE"),
+	(0x26bd, "SOCCER BALL"),
+	(0x26be, "BASEBALL"),
+	(0x26bf, "SQUARED KEY"),
+	(0x26c0, "WHITE DRAUGHTS MAN"),
+	(0x26c1, "WHITE DRAUGHTS KING"),
+	(0x26c2, "BLACK DRAUGHTS MAN"),
+	(0x26c3, "BLACK DRAUGHTS KING"),
+	(0x26c4, "SNOWMAN WITHOUT SNOW"),
+	(0x26c5, "SUN BEHIND CLOUD"),
+	(0x26c6, "RAIN"),
+	(0x26c7, "BLACK SNOWMAN"),
+	(0x26c8, "THUNDER CLOUD AND RAIN"),
+	(0x26c9, "TURNED WHITE SHOGI PIECE"),
+	(0x26ca, "TURNED BLACK SHOGI PIECE"),
+	(0x26cb, "WHITE DIAMOND IN SQUARE"),
+	(0x26cc, "CROSSING LANES"),
+	(0x26cd, "DISABLED CAR"),
+	(0x26ce, "OPHIUCHUS"),
+	(0x26cf, "PICK"),
+	(0x26d0, "CAR SLIDING"),
+	(0x26d1, "HELMET WITH WHITE CROSS"),
+	(0x26d2, "CIRCLED CROSSING LANES"),
+	(0x26d3, "CHAINS"),
+	(0x26d4, "NO ENTRY"),
+	(0x26d5, "ALTERNATE ONE-WAY LEFT WAY TRAFFIC"),
+	(0x26d6, "BLACK TWO-WAY LEFT WAY TRAFFIC"),
+	(0x26d7, "WHITE TWO-WAY LEFT WAY TRAFFIC"),
+	(0x26d8, "BLACK LEFT LANE MERGE"),
+	(0x26d9, "WHITE LEFT LANE MERGE"),
+	(0x26da, "DRIVE SLOW SIGN"),
+	(0x26db, "HEAVY WHITE DOWN-POINTING TRIANGLE"),
+	(0x26dc, "LEFT CLOSED ENTRY"),
+	(0x26dd, "SQUARED SALTIRE"),
+	(0x26de, "FALLING DIAGONAL IN WHITE CIRCLE IN BLACK SQUARE"),
+	(0x26df, "BLACK TRUCK"),
+	(0x26e0, "RESTRICTED LEFT ENTRY-1"),
+	(0x26e1, "RESTRICTED LEFT ENTRY-2"),
+	(0x26e2, "ASTRONOMICAL SYMBOL FOR URANUS"),
+	(0x26e3, "HEAVY CIRCLE WITH STROKE AND TWO DOTS ABOVE"),
+	(0x26e4, "PENTAGRAM"),
+	(0x26e5, "RIGHT-HANDED INTERLACED PENTAGRAM"),
+	(0x26e6, "LEFT-HANDED INTERLACED PENTAGRAM"),
+	(0x26e7, "INVERTED PENTAGRAM"),
+	(0x26e8, "BLACK CROSS ON SHIELD"),
+	(0x26e9, "SHINTO SHRINE"),
+	(0x26ea, "CHURCH"),
+	(0x26eb, "CASTLE"),
+	(0x26ec, "HISTORIC SITE"),
+	(0x26ed, "GEAR WITHOUT HUB"),
+	(0x26ee, "GEAR WITH HANDLES"),
+	(0x26ef, "MAP SYMBOL FOR LIGHTHOUSE"),
+	(0x26f0, "MOUNTAIN"),
+	(0x26f1, "UMBRELLA ON GROUND"),
+	(0x26f2, "FOUNTAIN"),
+	(0x26f3, "FLAG IN HOLE"),
+	(0x26f4, "FERRY"),
+	(0x26f5, "SAILBOAT"),
+	(0x26f6, "SQUARE FOUR CORNERS"),
+	(0x26f7, "SKIER"),
+	(0x26f8, "ICE SKATE"),
+	(0x26f9, "PERSON WITH BALL"),
+	(0x26fa, "TENT"),
+	(0x26fb, "JAPANESE BANK SYMBOL"),
+	(0x26fc, "HEADSTONE GRAVEYARD SYMBOL"),
+	(0x26fd, "FUEL PUMP"),
+	(0x26fe, "CUP ON BLACK SQUARE"),
+	(0x26ff, "WHITE FLAG WITH HORIZONTAL MIDDLE BLACK STRIPE"),
+	(0x2700, "BLACK SAFETY SCISSORS"),
+	(0x2701, "UPPER BLADE SCISSORS"),
+	(0x2702, "BLACK SCISSORS"),
+	(0x2703, "LOWER BLADE SCISSORS"),
+	(0x2704, "WHITE SCISSORS"),
+	(0x2705, "WHITE HEAVY CHECK MARK"),
+	(0x2706, "TELEPHONE LOCATION SIGN"),
+	(0x2707, "TAPE DRIVE"),
+	(0x2708, "AIRPLANE"),
+	(0x2709, "ENVELOPE"),
+	(0x270a, "RAISED FIST"),
+	(0x270b, "RAISED HAND"),
+	(0x270c, "VICTORY HAND"),
+	(0x270d, "WRITING HAND"),
+	(0x270e, "LOWER RIGHT PENCIL"),
+	(0x270f, "PENCIL"),
+	(0x2710, "UPPER RIGHT PENCIL"),
+	(0x2711, "WHITE NIB"),
+	(0x2712, "BLACK NIB"),
+	(0x2713, "CHECK MARK"),
+	(0x2714, "HEAVY CHECK MARK"),
+	(0x2715, "MULTIPLICATION X"),
+	(0x2716, "HEAVY MULTIPLICATION X"),
+	(0x2717, "BALLOT X"),
+	(0x2718, "HEAVY BALLOT X"),
+	(0x2719, "OUTLINED GREEK CROSS"),
+	(0x271a, "HEAVY GREEK CROSS"),
+	(0x271b, "OPEN CENTRE CROSS"),
+	(0x271c, "HEAVY OPEN CENTRE CROSS"),
+	(0x271d, "LATIN CROSS"),
+	(0x271e, "SHADOWED WHITE LATIN CROSS"),
+	(0x271f, "OUTLINED LATIN CROSS"),
+	(0x2720, "MALTESE CROSS"),
+	(0x2721, "STAR OF DAVID"),
+	(0x2722, "FOUR TEARDROP-SPOKED ASTERISK"),
+	(0x2723, "FOUR BALLOON-SPOKED ASTERISK"),
+	(0x2724, "HEAVY FOUR BALLOON-SPOKED ASTERISK"),
+	(0x2725, "FOUR CLUB-SPOKED ASTERISK"),
+	(0x2726, "BLACK FOUR POINTED STAR"),
+	(0x2727, "WHITE FOUR POINTED STAR"),
+	(0x2728, "SPARKLES"),
+	(0x2729, "STRESS OUTLINED WHITE STAR"),
+	(0x272a, "CIRCLED WHITE STAR"),
+	(0x272b, "OPEN CENTRE BLACK STAR"),
+	(0x272c, "BLACK CENTRE WHITE STAR"),
+	(0x272d, "OUTLINED BLACK STAR"),
+	(0x272e, "HEAVY OUTLINED BLACK STAR"),
+	(0x272f, "PINWHEEL STAR"),
+	(0x2730, "SHADOWED WHITE STAR"),
+	(0x2731, "HEAVY ASTERISK"),
+	(0x2732, "OPEN CENTRE ASTERISK"),
+	(0x2733, "EIGHT SPOKED ASTERISK"),
+	(0x2734, "EIGHT POINTED BLACK STAR"),
+	(0x2735, "EIGHT POINTED PINWHEEL STAR"),
+	(0x2736, "SIX POINTED BLACK STAR"),
+	(0x2737, "EIGHT POINTED RECTILINEAR BLACK STAR"),
+	(0x2738, "HEAVY EIGHT POINTED RECTILINEAR BLACK STAR"),
+	(0x2739, "TWELVE POINTED BLACK STAR"),
+	(0x273a, "SIXTEEN POINTED ASTERISK"),
+	(0x273b, "TEARDROP-SPOKED ASTERISK"),
+	(0x273c, "OPEN CENTRE TEARDROP-SPOKED ASTERISK"),
+	(0x273d, "HEAVY TEARDROP-SPOKED ASTERISK"),
+	(0x273e, "SIX PETALLED BLACK AND WHITE FLORETTE"),
+	(0x273f, "BLACK FLORETTE"),
+	(0x2740, "WHITE FLORETTE"),
+	(0x2741, "EIGHT PETALLED OUTLINED BLACK FLORETTE"),
+	(0x2742, "CIRCLED OPEN CENTRE EIGHT POINTED STAR"),
+	(0x2743, "HEAVY TEARDROP-SPOKED PINWHEEL ASTERISK"),
+	(0x2744, "SNOWFLAKE"),
+	(0x2745, "TIGHT TRIFOLIATE SNOWFLAKE"),
+	(0x2746, "HEAVY CHEVRON SNOWFLAKE"),
+	(0x2747, "SPARKLE"),
+	(0x2748, "HEAVY SPARKLE"),
+	(0x2749, "BALLOON-SPOKED ASTERISK"),
+	(0x274a, "EIGHT TEARDROP-SPOKED PROPELLER ASTERISK"),
+	(0x274b, "HEAVY EIGHT TEARDROP-SPOKED PROPELLER ASTERISK"),
+	(0x274c, "CROSS MARK"),
+	(0x274d, "SHADOWED WHITE CIRCLE"),
+	(0x274e, "NEGATIVE SQUARED CROSS MARK"),
+	(0x274f, "LOWER RIGHT DROP-SHADOWED WHITE SQUARE"),
+	(0x2750, "UPPER RIGHT DROP-SHADOWED WHITE SQUARE"),
+	(0x2751, "LOWER RIGHT SHADOWED WHITE SQUARE"),
+	(0x2752, "UPPER RIGHT SHADOWED WHITE SQUARE"),
+	(0x2753, "BLACK QUESTION MARK ORNAMENT"),
+	(0x2754, "WHITE QUESTION MARK ORNAMENT"),
+	(0x2755, "WHITE EXCLAMATION MARK ORNAMENT"),
+	(0x2756, "BLACK DIAMOND MINUS WHITE X"),
+	(0x2757, "HEAVY EXCLAMATION MARK SYMBOL"),
+	(0x2758, "LIGHT VERTICAL BAR"),
+	(0x2759, "MEDIUM VERTICAL BAR"),
+	(0x275a, "HEAVY VERTICAL BAR"),
+	(0x275b, "HEAVY SINGLE TURNED COMMA QUOTATION MARK ORNAMENT"),
+	(0x275c, "HEAVY SINGLE COMMA QUOTATION MARK ORNAMENT"),
+	(0x275d, "HEAVY DOUBLE TURNED COMMA QUOTATION MARK ORNAMENT"),
+	(0x275e, "HEAVY DOUBLE COMMA QUOTATION MARK ORNAMENT"),
+	(0x275f, "HEAVY LOW SINGLE COMMA QUOTATION MARK ORNAMENT"),
+	(0x2760, "HEAVY LOW DOUBLE COMMA QUOTATION MARK ORNAMENT"),
+	(0x2761, "CURVED STEM PARAGRAPH SIGN ORNAMENT"),
+	(0x2762, "HEAVY EXCLAMATION MARK ORNAMENT"),
+	(0x2763, "HEAVY HEART EXCLAMATION MARK ORNAMENT"),
+	(0x2764, "HEAVY BLACK HEART"),
+	(0x2765, "ROTATED HEAVY BLACK HEART BULLET"),
+	(0x2766, "FLORAL HEART"),
+	(0x2767, "ROTATED FLORAL HEART BULLET"),
+	(0x2768, "MEDIUM LEFT PARENTHESIS ORNAMENT"),
+	(0x2769, "MEDIUM RIGHT PARENTHESIS ORNAMENT"),
+	(0x276a, "MEDIUM FLATTENED LEFT PARENTHESIS ORNAMENT"),
+	(0x276b, "MEDIUM FLATTENED RIGHT PARENTHESIS ORNAMENT"),
+	(0x276c, "MEDIUM LEFT-POINTING ANGLE BRACKET ORNAMENT"),
+	(0x276d, "MEDIUM RIGHT-POINTING ANGLE BRACKET ORNAMENT"),
+	(0x276e, "HEAVY LEFT-POINTING ANGLE QUOTATION MARK ORNAMENT"),
+	(0x276f, "HEAVY RIGHT-POINTING ANGLE QUOTATION MARK ORNAMENT"),
+	(0x2770, "HEAVY LEFT-POINTING ANGLE BRACKET ORNAMENT"),
+	(0x2771, "HEAVY RIGHT-POINTING ANGLE BRACKET ORNAMENT"),
+	(0x2772, "LIGHT LEFT TORTOISE SHELL BRACKET ORNAMENT"),
+	(0x2773, "LIGHT RIGHT TORTOISE SHELL BRACKET ORNAMENT"),
+	(0x2774, "MEDIUM LEFT CURLY BRACKET ORNAMENT"),
+	(0x2775, "MEDIUM RIGHT CURLY BRACKET ORNAMENT"),
+	(0x2776, "DINGBAT NEGATIVE CIRCLED DIGIT ONE"),
+	(0x2777, "DINGBAT NEGATIVE CIRCLED DIGIT TWO"),
+	(0x2778, "DINGBAT NEGATIVE CIRCLED DIGIT THREE"),
+	(0x2779, "DINGBAT NEGATIVE CIRCLED DIGIT FOUR"),
+	(0x277a, "DINGBAT NEGATIVE CIRCLED DIGIT FIVE"),
+	(0x277b, "DINGBAT NEGATIVE CIRCLED DIGIT SIX"),
+	(0x277c, "DINGBAT NEGATIVE CIRCLED DIGIT SEVEN"),
+	(0x277d, "DINGBAT NEGATIVE CIRCLED DIGIT EIGHT"),
+	(0x277e, "DINGBAT NEGATIVE CIRCLED DIGIT NINE"),
+	(0x277f, "DINGBAT NEGATIVE CIRCLED NUMBER TEN"),
+	(0x2780, "DINGBAT CIRCLED SANS-SERIF DIGIT ONE"),
+	(0x2781, "DINGBAT CIRCLED SANS-SERIF DIGIT TWO"),
+	(0x2782, "DINGBAT CIRCLED SANS-SERIF DIGIT THREE"),
+	(0x2783, "DINGBAT CIRCLED SANS-SERIF DIGIT FOUR"),
+	(0x2784, "DINGBAT CIRCLED SANS-SERIF DIGIT FIVE"),
+	(0x2785, "DINGBAT CIRCLED SANS-SERIF DIGIT SIX"),
+	(0x2786, "DINGBAT CIRCLED SANS-SERIF DIGIT SEVEN"),
+	(0x2787, "DINGBAT CIRCLED SANS-SERIF DIGIT EIGHT"),
+	(0x2788, "DINGBAT CIRCLED SANS-SERIF DIGIT NINE"),
+	(0x2789, "DINGBAT CIRCLED SANS-SERIF NUMBER TEN"),
+	(0x278a, "DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT ONE"),
+	(0x278b, "DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT TWO"),
+	(0x278c, "DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT THREE"),
+	(0x278d, "DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT FOUR"),
+	(0x278e, "DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT FIVE"),
+	(0x278f, "DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT SIX"),
+	(0x2790, "DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT SEVEN"),
+	(0x2791, "DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT EIGHT"),
+	(0x2792, "DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT NINE"),
+	(0x2793, "DINGBAT NEGATIVE CIRCLED SANS-SERIF NUMBER TEN"),
+	(0x2794, "HEAVY WIDE-HEADED RIGHTWARDS ARROW"),
+	(0x2795, "HEAVY PLUS SIGN"),
+	(0x2796, "HEAVY MINUS SIGN"),
+	(0x2797, "HEAVY DIVISION SIGN"),
+	(0x2798, "HEAVY SOUTH EAST ARROW"),
+	(0x2799, "HEAVY RIGHTWARDS ARROW"),
+	(0x279a, "HEAVY NORTH EAST ARROW"),
+	(0x279b, "DRAFTING POINT RIGHTWARDS ARROW"),
+	(0x279c, "HEAVY ROUND-TIPPED RIGHTWARDS ARROW"),
+	(0x279d, "TRIANGLE-HEADED RIGHTWARDS ARROW"),
+	(0x279e, "HEAVY TRIANGLE-HEADED RIGHTWARDS ARROW"),
+	(0x279f, "DASHED TRIANGLE-HEADED RIGHTWARDS ARROW"),
+	(0x27a0, "HEAVY DASHED TRIANGLE-HEADED RIGHTWARDS ARROW"),
+	(0x27a1, "BLACK RIGHTWARDS ARROW"),
+	(0x27a2, "THREE-D TOP-LIGHTED RIGHTWARDS ARROWHEAD"),
+	(0x27a3, "THREE-D BOTTOM-LIGHTED RIGHTWARDS ARROWHEAD"),
+	(0x27a4, "BLACK RIGHTWARDS ARROWHEAD"),
+	(0x27a5, "HEAVY BLACK CURVED DOWNWARDS AND RIGHTWARDS ARROW"),
+	(0x27a6, "HEAVY BLACK CURVED UPWARDS AND RIGHTWARDS ARROW"),
+	(0x27a7, "SQUAT BLACK RIGHTWARDS ARROW"),
+	(0x27a8, "HEAVY CONCAVE-POINTED BLACK RIGHTWARDS ARROW"),
+	(0x27a9, "RIGHT-SHADED WHITE RIGHTWARDS ARROW"),
+	(0x27aa, "LEFT-SHADED WHITE RIGHTWARDS ARROW"),
+	(0x27ab, "BACK-TILTED SHADOWED WHITE RIGHTWARDS ARROW"),
+	(0x27ac, "FRONT-TILTED SHADOWED WHITE RIGHTWARDS ARROW"),
+	(0x27ad, "HEAVY LOWER RIGHT-SHADOWED WHITE RIGHTWARDS ARROW"),
+	(0x27ae, "HEAVY UPPER RIGHT-SHADOWED WHITE RIGHTWARDS ARROW"),
+	(0x27af, "NOTCHED LOWER RIGHT-SHADOWED WHITE RIGHTWARDS ARROW"),
+	(0x27b0, "CURLY LOOP"),
+	(0x27b1, "NOTCHED UPPER RIGHT-SHADOWED WHITE RIGHTWARDS ARROW"),
+	(0x27b2, "CIRCLED HEAVY WHITE RIGHTWARDS ARROW"),
+	(0x27b3, "WHITE-FEATHERED RIGHTWARDS ARROW"),
+	(0x27b4, "BLACK-FEATHERED SOUTH EAST ARROW"),
+	(0x27b5, "BLACK-FEATHERED RIGHTWARDS ARROW"),
+	(0x27b6, "BLACK-FEATHERED NORTH EAST ARROW"),
+	(0x27b7, "HEAVY BLACK-FEATHERED SOUTH EAST ARROW"),
+	(0x27b8, "HEAVY BLACK-FEATHERED RIGHTWARDS ARROW"),
+	(0x27b9, "HEAVY BLACK-FEATHERED NORTH EAST ARROW"),
+	(0x27ba, "TEARDROP-BARBED RIGHTWARDS ARROW"),
+	(0x27bb, "HEAVY TEARDROP-SHANKED RIGHTWARDS ARROW"),
+	(0x27bc, "WEDGE-TAILED RIGHTWARDS ARROW"),
+	(0x27bd, "HEAVY WEDGE-TAILED RIGHTWARDS ARROW"),
+	(0x27be, "OPEN-OUTLINED RIGHTWARDS ARROW"),
+	(0x27bf, "DOUBLE CURLY LOOP"),
+	(0x27c0, "THREE DIMENSIONAL ANGLE"),
+	(0x27c1, "WHITE TRIANGLE CONTAINING SMALL WHITE TRIANGLE"),
+	(0x27c2, "PERPENDICULAR"),
+	(0x27c3, "OPEN SUBSET"),
+	(0x27c4, "OPEN SUPERSET"),
+	(0x27c5, "LEFT S-SHAPED BAG DELIMITER"),
+	(0x27c6, "RIGHT S-SHAPED BAG DELIMITER"),
+	(0x27c7, "OR WITH DOT INSIDE"),
+	(0x27c8, "REVERSE SOLIDUS PRECEDING SUBSET"),
+	(0x27c9, "SUPERSET PRECEDING SOLIDUS"),
+	(0x27ca, "VERTICAL BAR WITH HORIZONTAL STROKE"),
+	(0x27cb, "MATHEMATICAL RISING DIAGONAL"),
+	(0x27cc, "LONG DIVISION"),
+	(0x27cd, "MATHEMATICAL FALLING DIAGONAL"),
+	(0x27ce, "SQUARED LOGICAL AND"),
+	(0x27cf, "SQUARED LOGICAL OR"),
+	(0x27d0, "WHITE DIAMOND WITH CENTRED DOT"),
+	(0x27d1, "AND WITH DOT"),
+	(0x27d2, "ELEMENT OF OPENING UPWARDS"),
+	(0x27d3, "LOWER RIGHT CORNER WITH DOT"),
+	(0x27d4, "UPPER LEFT CORNER WITH DOT"),
+	(0x27d5, "LEFT OUTER JOIN"),
+	(0x27d6, "RIGHT OUTER JOIN"),
+	(0x27d7, "FULL OUTER JOIN"),
+	(0x27d8, "LARGE UP TACK"),
+	(0x27d9, "LARGE DOWN TACK"),
+	(0x27da, "LEFT AND RIGHT DOUBLE TURNSTILE"),
+	(0x27db, "LEFT AND RIGHT TACK"),
+	(0x27dc, "LEFT MULTIMAP"),
+	(0x27dd, "LONG RIGHT TACK"),
+	(0x27de, "LONG LEFT TACK"),
+	(0x27df, "UP TACK WITH CIRCLE ABOVE"),
+	(0x27e0, "LOZENGE DIVIDED BY HORIZONTAL RULE"),
+	(0x27e1, "WHITE CONCAVE-SIDED DIAMOND"),
+	(0x27e2, "WHITE CONCAVE-SIDED DIAMOND WITH LEFTWARDS TICK"),
+	(0x27e3, "WHITE CONCAVE-SIDED DIAMOND WITH RIGHTWARDS TICK"),
+	(0x27e4, "WHITE SQUARE WITH LEFTWARDS TICK"),
+	(0x27e5, "WHITE SQUARE WITH RIGHTWARDS TICK"),
+	(0x27e6, "MATHEMATICAL LEFT WHITE SQUARE BRACKET"),
+	(0x27e7, "MATHEMATICAL RIGHT WHITE SQUARE BRACKET"),
+	(0x27e8, "MATHEMATICAL LEFT ANGLE BRACKET"),
+	(0x27e9, "MATHEMATICAL RIGHT ANGLE BRACKET"),
+	(0x27ea, "MATHEMATICAL LEFT DOUBLE ANGLE BRACKET"),
+	(0x27eb, "MATHEMATICAL RIGHT DOUBLE ANGLE BRACKET"),
+	(0x27ec, "MATHEMATICAL LEFT WHITE TORTOISE SHELL BRACKET"),
+	(0x27ed, "MATHEMATICAL RIGHT WHITE TORTOISE SHELL BRACKET"),
+	(0x27ee, "MATHEMATICAL LEFT FLATTENED PARENTHESIS"),
+	(0x27ef, "MATHEMATICAL RIGHT FLATTENED PARENTHESIS"),
+	(0x27f0, "UPWARDS QUADRUPLE ARROW"),
+	(0x27f1, "DOWNWARDS QUADRUPLE ARROW"),
+	(0x27f2, "ANTICLOCKWISE GAPPED CIRCLE ARROW"),
+	(0x27f3, "CLOCKWISE GAPPED CIRCLE ARROW"),
+	(0x27f4, "RIGHT ARROW WITH CIRCLED PLUS"),
+	(0x27f5, "LONG LEFTWARDS ARROW"),
+	(0x27f6, "LONG RIGHTWARDS ARROW"),
+	(0x27f7, "LONG LEFT RIGHT ARROW"),
+	(0x27f8, "LONG LEFTWARDS DOUBLE ARROW"),
+	(0x27f9, "LONG RIGHTWARDS DOUBLE ARROW"),
+	(0x27fa, "LONG LEFT RIGHT DOUBLE ARROW"),
+	(0x27fb, "LONG LEFTWARDS ARROW FROM BAR"),
+	(0x27fc, "LONG RIGHTWARDS ARROW FROM BAR"),
+	(0x27fd, "LONG LEFTWARDS DOUBLE ARROW FROM BAR"),
+	(0x27fe, "LONG RIGHTWARDS DOUBLE ARROW FROM BAR"),
+	(0x27ff, "LONG RIGHTWARDS SQUIGGLE ARROW"),
+	(0x2800, "BRAILLE PATTERN BLANK"),
+	(0x2801, "BRAILLE PATTERN DOTS-1"),
+	(0x2802, "BRAILLE PATTERN DOTS-2"),
+	(0x2803, "BRAILLE PATTERN DOTS-12"),
+	(0x2804, "BRAILLE PATTERN DOTS-3"),
+	(0x2805, "BRAILLE PATTERN DOTS-13"),
+	(0x2806, "BRAILLE PATTERN DOTS-23"),
+	(0x2807, "BRAILLE PATTERN DOTS-123"),
+	(0x2808, "BRAILLE PATTERN DOTS-4"),
+	(0x2809, "BRAILLE PATTERN DOTS-14"),
+	(0x280a, "BRAILLE PATTERN DOTS-24"),
+	(0x280b, "BRAILLE PATTERN DOTS-124"),
+	(0x280c, "BRAILLE PATTERN DOTS-34"),
+	(0x280d, "BRAILLE PATTERN DOTS-134"),
+	(0x280e, "BRAILLE PATTERN DOTS-234"),
+	(0x280f, "BRAILLE PATTERN DOTS-1234"),
+	(0x2810, "BRAILLE PATTERN DOTS-5"),
+	(0x2811, "BRAILLE PATTERN DOTS-15"),
+	(0x2812, "BRAILLE PATTERN DOTS-25"),
+	(0x2813, "BRAILLE PATTERN DOTS-125"),
+	(0x2814, "BRAILLE PATTERN DOTS-35"),
+	(0x2815, "BRAILLE PATTERN DOTS-135"),
+	(0x2816, "BRAILLE PATTERN DOTS-235"),
+	(0x2817, "BRAILLE PATTERN DOTS-1235"),
+	(0x2818, "BRAILLE PATTERN DOTS-45"),
+	(0x2819, "BRAILLE PATTERN DOTS-145"),
+	(0x281a, "BRAILLE PATTERN DOTS-245"),
+	(0x281b, "BRAILLE PATTERN DOTS-1245"),
+	(0x281c, "BRAILLE PATTERN DOTS-345"),
+	(0x281d, "BRAILLE PATTERN DOTS-1345"),
+	(0x281e, "BRAILLE PATTERN DOTS-2345"),
+	(0x281f, "BRAILLE PATTERN DOTS-12345"),
+	(0x2820, "BRAILLE PATTERN DOTS-6"),
+	(0x2821, "BRAILLE PATTERN DOTS-16"),
+	(0x2822, "BRAILLE PATTERN DOTS-26"),
+	(0x2823, "BRAILLE PATTERN DOTS-126"),
+	(0x2824, "BRAILLE PATTERN DOTS-36"),
+	(0x2825, "BRAILLE PATTERN DOTS-136"),
+	(0x2826, "BRAILLE PATTERN DOTS-236"),
+	(0x2827, "BRAILLE PATTERN DOTS-1236"),
+	(0x2828, "BRAILLE PATTERN DOTS-46"),
+	(0x2829, "BRAILLE PATTERN DOTS-146"),
+	(0x282a, "BRAILLE PATTERN DOTS-246"),
+	(0x282b, "BRAILLE PATTERN DOTS-1246"),
+	(0x282c, "BRAILLE PATTERN DOTS-346"),
+	(0x282d, "BRAILLE PATTERN DOTS-1346"),
+	(0x282e, "BRAILLE PATTERN DOTS-2346"),
+	(0x282f, "BRAILLE PATTERN DOTS-12346"),
+	(0x2830, "BRAILLE PATTERN DOTS-56"),
+	(0x2831, "BRAILLE PATTERN DOTS-156"),
+	(0x2832, "BRAILLE PATTERN DOTS-256"),
+	(0x2833, "BRAILLE PATTERN DOTS-1256"),
+	(0x2834, "BRAILLE PATTERN DOTS-356"),
+	(0x2835, "BRAILLE PATTERN DOTS-1356"),
+	(0x2836, "BRAILLE PATTERN DOTS-2356"),
+	(0x2837, "BRAILLE PATTERN DOTS-12356"),
+	(0x2838, "BRAILLE PATTERN DOTS-456"),
+	(0x2839, "BRAILLE PATTERN DOTS-1456"),
+	(0x283a, "BRAILLE PATTERN DOTS-2456"),
+	(0x283b, "BRAILLE PATTERN DOTS-12456"),
+	(0x283c, "BRAILLE PATTERN DOTS-3456"),
+	(0x283d, "BRAILLE PATTERN DOTS-13456"),
+	(0x283e, "BRAILLE PATTERN DOTS-23456"),
+	(0x283f, "BRAILLE PATTERN DOTS-123456"),
+	(0x2840, "BRAILLE PATTERN DOTS-7"),
+	(0x2841, "BRAILLE PATTERN DOTS-17"),
+	(0x2842, "BRAILLE PATTERN DOTS-27"),
+	(0x2843, "BRAILLE PATTERN DOTS-127"),
+	(0x2844, "BRAILLE PATTERN DOTS-37"),
+	(0x2845, "BRAILLE PATTERN DOTS-137"),
+	(0x2846, "BRAILLE PATTERN DOTS-237"),
+	(0x2847, "BRAILLE PATTERN DOTS-1237"),
+	(0x2848, "BRAILLE PATTERN DOTS-47"),
+	(0x2849, "BRAILLE PATTERN DOTS-147"),
+	(0x284a, "BRAILLE PATTERN DOTS-247"),
+	(0x284b, "BRAILLE PATTERN DOTS-1247"),
+	(0x284c, "BRAILLE PATTERN DOTS-347"),
+	(0x284d, "BRAILLE PATTERN DOTS-1347"),
+	(0x284e, "BRAILLE PATTERN DOTS-2347"),
+	(0x284f, "BRAILLE PATTERN DOTS-12347"),
+	(0x2850, "BRAILLE PATTERN DOTS-57"),
+	(0x2851, "BRAILLE PATTERN DOTS-157"),
+	(0x2852, "BRAILLE PATTERN DOTS-257"),
+	(0x2853, "BRAILLE PATTERN DOTS-1257"),
+	(0x2854, "BRAILLE PATTERN DOTS-357"),
+	(0x2855, "BRAILLE PATTERN DOTS-1357"),
+	(0x2856, "BRAILLE PATTERN DOTS-2357"),
+	(0x2857, "BRAILLE PATTERN DOTS-12357"),
+	(0x2858, "BRAILLE PATTERN DOTS-457"),
+	(0x2859, "BRAILLE PATTERN DOTS-1457"),
+	(0x285a, "BRAILLE PATTERN DOTS-2457"),
+	(0x285b, "BRAILLE PATTERN DOTS-12457"),
+	(0x285c, "BRAILLE PATTERN DOTS-3457"),
+	(0x285d, "BRAILLE PATTERN DOTS-13457"),
+	(0x285e, "BRAILLE PATTERN DOTS-23457"),
+	(0x285f, "BRAILLE PATTERN DOTS-123457"),
+	(0x2860, "BRAILLE PATTERN DOTS-67"),
+	(0x2861, "BRAILLE PATTERN DOTS-167"),
+	(0x2862, "BRAILLE PATTERN DOTS-267"),
+	(0x2863, "BRAILLE PATTERN DOTS-1267"),
+	(0x2864, "BRAILLE PATTERN DOTS-367"),
+	(0x2865, "BRAILLE PATTERN DOTS-1367"),
+	(0x2866, "BRAILLE PATTERN DOTS-2367"),
+	(0x2867, "BRAILLE PATTERN DOTS-12367"),
+	(0x2868, "BRAILLE PATTERN DOTS-467"),
+	(0x2869, "BRAILLE PATTERN DOTS-1467"),
+	(0x286a, "BRAILLE PATTERN DOTS-2467"),
+	(0x286b, "BRAILLE PATTERN DOTS-12467"),
+	(0x286c, "BRAILLE PATTERN DOTS-3467"),
+	(0x286d, "BRAILLE PATTERN DOTS-13467"),
+	(0x286e, "BRAILLE PATTERN DOTS-23467"),
+	(0x286f, "BRAILLE PATTERN DOTS-123467"),
+	(0x2870, "BRAILLE PATTERN DOTS-567"),
+	(0x2871, "BRAILLE PATTERN DOTS-1567"),
+	(0x2872, "BRAILLE PATTERN DOTS-2567"),
+	(0x2873, "BRAILLE PATTERN DOTS-12567"),
+	(0x2874, "BRAILLE PATTERN DOTS-3567"),
+	(0x2875, "BRAILLE PATTERN DOTS-13567"),
+	(0x2876, "BRAILLE PATTERN DOTS-23567"),
+	(0x2877, "BRAILLE PATTERN DOTS-123567"),
+	(0x2878, "BRAILLE PATTERN DOTS-4567"),
+	(0x2879, "BRAILLE PATTERN DOTS-14567"),
+	(0x287a, "BRAILLE PATTERN DOTS-24567"),
+	(0x287b, "BRAILLE PATTERN DOTS-124567"),
+	(0x287c, "BRAILLE PATTERN DOTS-34567"),
+	(0x287d, "BRAILLE PATTERN DOTS-134567"),
+	(0x287e, "BRAILLE PATTERN DOTS-234567"),
+	(0x287f, "BRAILLE PATTERN DOTS-1234567"),
+	(0x2880, "BRAILLE PATTERN DOTS-8"),
+	(0x2881, "BRAILLE PATTERN DOTS-18"),
+	(0x2882, "BRAILLE PATTERN DOTS-28"),
+	(0x2883, "BRAILLE PATTERN DOTS-128"),
+	(0x2884, "BRAILLE PATTERN DOTS-38"),
+	(0x2885, "BRAILLE PATTERN DOTS-138"),
+	(0x2886, "BRAILLE PATTERN DOTS-238"),
+	(0x2887, "BRAILLE PATTERN DOTS-1238"),
+	(0x2888, "BRAILLE PATTERN DOTS-48"),
+	(0x2889, "BRAILLE PATTERN DOTS-148"),
+	(0x288a, "BRAILLE PATTERN DOTS-248"),
+	(0x288b, "BRAILLE PATTERN DOTS-1248"),
+	(0x288c, "BRAILLE PATTERN DOTS-348"),
+	(0x288d, "BRAILLE PATTERN DOTS-1348"),
+	(0x288e, "BRAILLE PATTERN DOTS-2348"),
+	(0x288f, "BRAILLE PATTERN DOTS-12348"),
+	(0x2890, "BRAILLE PATTERN DOTS-58"),
+	(0x2891, "BRAILLE PATTERN DOTS-158"),
+	(0x2892, "BRAILLE PATTERN DOTS-258"),
+	(0x2893, "BRAILLE PATTERN DOTS-1258"),
+	(0x2894, "BRAILLE PATTERN DOTS-358"),
+	(0x2895, "BRAILLE PATTERN DOTS-1358"),
+	(0x2896, "BRAILLE PATTERN DOTS-2358"),
+	(0x2897, "BRAILLE PATTERN DOTS-12358"),
+	(0x2898, "BRAILLE PATTERN DOTS-458"),
+	(0x2899, "BRAILLE PATTERN DOTS-1458"),
+	(0x289a, "BRAILLE PATTERN DOTS-2458"),
+	(0x289b, "BRAILLE PATTERN DOTS-12458"),
+	(0x289c, "BRAILLE PATTERN DOTS-3458"),
+	(0x289d, "BRAILLE PATTERN DOTS-13458"),
+	(0x289e, "BRAILLE PATTERN DOTS-23458"),
+	(0x289f, "BRAILLE PATTERN DOTS-123458"),
+	(0x28a0, "BRAILLE PATTERN DOTS-68"),
+	(0x28a1, "BRAILLE PATTERN DOTS-168"),
+	(0x28a2, "BRAILLE PATTERN DOTS-268"),
+	(0x28a3, "BRAILLE PATTERN DOTS-1268"),
+	(0x28a4, "BRAILLE PATTERN DOTS-368"),
+	(0x28a5, "BRAILLE PATTERN DOTS-1368"),
+	(0x28a6, "BRAILLE PATTERN DOTS-2368"),
+	(0x28a7, "BRAILLE PATTERN DOTS-12368"),
+	(0x28a8, "BRAILLE PATTERN DOTS-468"),
+	(0x28a9, "BRAILLE PATTERN DOTS-1468"),
+	(0x28aa, "BRAILLE PATTERN DOTS-2468"),
+	(0x28ab, "BRAILLE PATTERN DOTS-12468"),
+	(0x28ac, "BRAILLE PATTERN DOTS-3468"),
+	(0x28ad, "BRAILLE PATTERN DOTS-13468"),
+	(0x28ae, "BRAILLE PATTERN DOTS-23468"),
+	(0x28af, "BRAILLE PATTERN DOTS-123468"),
+	(0x28b0, "BRAILLE PATTERN DOTS-568"),
+	(0x28b1, "BRAILLE PATTERN DOTS-1568"),
+	(0x28b2, "BRAILLE PATTERN DOTS-2568"),
+	(0x28b3, "BRAILLE PATTERN DOTS-12568"),
+	(0x28b4, "BRAILLE PATTERN DOTS-3568"),
+	(0x28b5, "BRAILLE PATTERN DOTS-13568"),
+	(0x28b6, "BRAILLE PATTERN DOTS-23568"),
+	(0x28b7, "BRAILLE PATTERN DOTS-123568"),
+	(0x28b8, "BRAILLE PATTERN DOTS-4568"),
+	(0x28b9, "BRAILLE PATTERN DOTS-14568"),
+	(0x28ba, "BRAILLE PATTERN DOTS-24568"),
+	(0x28bb, "BRAILLE PATTERN DOTS-124568"),
+	(0x28bc, "BRAILLE PATTERN DOTS-34568"),
+	(0x28bd, "BRAILLE PATTERN DOTS-134568"),
+	(0x28be, "BRAILLE PATTERN DOTS-234568"),
+	(0x28bf, "BRAILLE PATTERN DOTS-1234568"),
+	(0x28c0, "BRAILLE PATTERN DOTS-78"),
+	(0x28c1, "BRAILLE PATTERN DOTS-178"),
+	(0x28c2, "BRAILLE PATTERN DOTS-278"),
+	(0x28c3, "BRAILLE PATTERN DOTS-1278"),
+	(0x28c4, "BRAILLE PATTERN DOTS-378"),
+	(0x28c5, "BRAILLE PATTERN DOTS-1378"),
+	(0x28c6, "BRAILLE PATTERN DOTS-2378"),
+	(0x28c7, "BRAILLE PATTERN DOTS-12378"),
+	(0x28c8, "BRAILLE PATTERN DOTS-478"),
+	(0x28c9, "BRAILLE PATTERN DOTS-1478"),
+	(0x28ca, "BRAILLE PATTERN DOTS-2478"),
+	(0x28cb, "BRAILLE PATTERN DOTS-12478"),
+	(0x28cc, "BRAILLE PATTERN DOTS-3478"),
+	(0x28cd, "BRAILLE PATTERN DOTS-13478"),
+	(0x28ce, "BRAILLE PATTERN DOTS-23478"),
+	(0x28cf, "BRAILLE PATTERN DOTS-123478"),
+	(0x28d0, "BRAILLE PATTERN DOTS-578"),
+	(0x28d1, "BRAILLE PATTERN DOTS-1578"),
+	(0x28d2, "BRAILLE PATTERN DOTS-2578"),
+	(0x28d3, "BRAILLE PATTERN DOTS-12578"),
+	(0x28d4, "BRAILLE PATTERN DOTS-3578"),
+	(0x28d5, "BRAILLE PATTERN DOTS-13578"),
+	(0x28d6, "BRAILLE PATTERN DOTS-23578"),
+	(0x28d7, "BRAILLE PATTERN DOTS-123578"),
+	(0x28d8, "BRAILLE PATTERN DOTS-4578"),
+	(0x28d9, "BRAILLE PATTERN DOTS-14578"),
+	(0x28da, "BRAILLE PATTERN DOTS-24578"),
+	(0x28db, "BRAILLE PATTERN DOTS-124578"),
+	(0x28dc, "BRAILLE PATTERN DOTS-34578"),
+	(0x28dd, "BRAILLE PATTERN DOTS-134578"),
+	(0x28de, "BRAILLE PATTERN DOTS-234578"),
+	(0x28df, "BRAILLE PATTERN DOTS-1234578"),
+	(0x28e0, "BRAILLE PATTERN DOTS-678"),
+	(0x28e1, "BRAILLE PATTERN DOTS-1678"),
+	(0x28e2, "BRAILLE PATTERN DOTS-2678"),
+	(0x28e3, "BRAILLE PATTERN DOTS-12678"),
+	(0x28e4, "BRAILLE PATTERN DOTS-3678"),
+	(0x28e5, "BRAILLE PATTERN DOTS-13678"),
+	(0x28e6, "BRAILLE PATTERN DOTS-23678"),
+	(0x28e7, "BRAILLE PATTERN DOTS-123678"),
+	(0x28e8, "BRAILLE PATTERN DOTS-4678"),
+	(0x28e9, "BRAILLE PATTERN DOTS-14678"),
+	(0x28ea, "BRAILLE PATTERN DOTS-24678"),
+	(0x28eb, "BRAILLE PATTERN DOTS-124678"),
+	(0x28ec, "BRAILLE PATTERN DOTS-34678"),
+	(0x28ed, "BRAILLE PATTERN DOTS-134678"),
+	(0x28ee, "BRAILLE PATTERN DOTS-234678"),
+	(0x28ef, "BRAILLE PATTERN DOTS-1234678"),
+	(0x28f0, "BRAILLE PATTERN DOTS-5678"),
+	(0x28f1, "BRAILLE PATTERN DOTS-15678"),
+	(0x28f2, "BRAILLE PATTERN DOTS-25678"),
+	(0x28f3, "BRAILLE PATTERN DOTS-125678"),
+	(0x28f4, "BRAILLE PATTERN DOTS-35678"),
+	(0x28f5, "BRAILLE PATTERN DOTS-135678"),
+	(0x28f6, "BRAILLE PATTERN DOTS-235678"),
+	(0x28f7, "BRAILLE PATTERN DOTS-1235678"),
+	(0x28f8, "BRAILLE PATTERN DOTS-45678"),
+	(0x28f9, "BRAILLE PATTERN DOTS-145678"),
+	(0x28fa, "BRAILLE PATTERN DOTS-245678"),
+	(0x28fb, "BRAILLE PATTERN DOTS-1245678"),
+	(0x28fc, "BRAILLE PATTERN DOTS-345678"),
+	(0x28fd, "BRAILLE PATTERN DOTS-1345678"),
+	(0x28fe, "BRAILLE PATTERN DOTS-2345678"),
+	(0x28ff, "BRAILLE PATTERN DOTS-12345678"),
+	(0x2900, "RIGHTWARDS TWO-HEADED ARROW WITH VERTICAL STROKE"),
+	(0x2901, "RIGHTWARDS TWO-HEADED ARROW WITH DOUBLE VERTICAL STROKE"),
+	(0x2902, "LEFTWARDS DOUBLE ARROW WITH VERTICAL STROKE"),
+	(0x2903, "RIGHTWARDS DOUBLE ARROW WITH VERTICAL STROKE"),
+	(0x2904, "LEFT RIGHT DOUBLE ARROW WITH VERTICAL STROKE"),
+	(0x2905, "RIGHTWARDS TWO-HEADED ARROW FROM BAR"),
+	(0x2906, "LEFTWARDS DOUBLE ARROW FROM BAR"),
+	(0x2907, "RIGHTWARDS DOUBLE ARROW FROM BAR"),
+	(0x2908, "DOWNWARDS ARROW WITH HORIZONTAL STROKE"),
+	(0x2909, "UPWARDS ARROW WITH HORIZONTAL STROKE"),
+	(0x290a, "UPWARDS TRIPLE ARROW"),
+	(0x290b, "DOWNWARDS TRIPLE ARROW"),
+	(0x290c, "LEFTWARDS DOUBLE DASH ARROW"),
+	(0x290d, "RIGHTWARDS DOUBLE DASH ARROW"),
+	(0x290e, "LEFTWARDS TRIPLE DASH ARROW"),
+	(0x290f, "RIGHTWARDS TRIPLE DASH ARROW"),
+	(0x2910, "RIGHTWARDS TWO-HEADED TRIPLE DASH ARROW"),
+	(0x2911, "RIGHTWARDS ARROW WITH DOTTED STEM"),
+	(0x2912, "UPWARDS ARROW TO BAR"),
+	(0x2913, "DOWNWARDS ARROW TO BAR"),
+	(0x2914, "RIGHTWARDS ARROW WITH TAIL WITH VERTICAL STROKE"),
+	(0x2915, "RIGHTWARDS ARROW WITH TAIL WITH DOUBLE VERTICAL STROKE"),
+	(0x2916, "RIGHTWARDS TWO-HEADED ARROW WITH TAIL"),
+	(0x2917, "RIGHTWARDS TWO-HEADED ARROW WITH TAIL WITH VERTICAL STROKE"),
+	(0x2918, "RIGHTWARDS TWO-HEADED ARROW WITH TAIL WITH DOUBLE VERTICAL STROKE"),
+	(0x2919, "LEFTWARDS ARROW-TAIL"),
+	(0x291a, "RIGHTWARDS ARROW-TAIL"),
+	(0x291b, "LEFTWARDS DOUBLE ARROW-TAIL"),
+	(0x291c, "RIGHTWARDS DOUBLE ARROW-TAIL"),
+	(0x291d, "LEFTWARDS ARROW TO BLACK DIAMOND"),
+	(0x291e, "RIGHTWARDS ARROW TO BLACK DIAMOND"),
+	(0x291f, "LEFTWARDS ARROW FROM BAR TO BLACK DIAMOND"),
+	(0x2920, "RIGHTWARDS ARROW FROM BAR TO BLACK DIAMOND"),
+	(0x2921, "NORTH WEST AND SOUTH EAST ARROW"),
+	(0x2922, "NORTH EAST AND SOUTH WEST ARROW"),
+	(0x2923, "NORTH WEST ARROW WITH HOOK"),
+	(0x2924, "NORTH EAST ARROW WITH HOOK"),
+	(0x2925, "SOUTH EAST ARROW WITH HOOK"),
+	(0x2926, "SOUTH WEST ARROW WITH HOOK"),
+	(0x2927, "NORTH WEST ARROW AND NORTH EAST ARROW"),
+	(0x2928, "NORTH EAST ARROW AND SOUTH EAST ARROW"),
+	(0x2929, "SOUTH EAST ARROW AND SOUTH WEST ARROW"),
+	(0x292a, "SOUTH WEST ARROW AND NORTH WEST ARROW"),
+	(0x292b, "RISING DIAGONAL CROSSING FALLING DIAGONAL"),
+	(0x292c, "FALLING DIAGONAL CROSSING RISING DIAGONAL"),
+	(0x292d, "SOUTH EAST ARROW CROSSING NORTH EAST ARROW"),
+	(0x292e, "NORTH EAST ARROW CROSSING SOUTH EAST ARROW"),
+	(0x292f, "FALLING DIAGONAL CROSSING NORTH EAST ARROW"),
+	(0x2930, "RISING DIAGONAL CROSSING SOUTH EAST ARROW"),
+	(0x2931, "NORTH EAST ARROW CROSSING NORTH WEST ARROW"),
+	(0x2932, "NORTH WEST ARROW CROSSING NORTH EAST ARROW"),
+	(0x2933, "WAVE ARROW POINTING DIRECTLY RIGHT"),
+	(0x2934, "ARROW POINTING RIGHTWARDS THEN CURVING UPWARDS"),
+	(0x2935, "ARROW POINTING RIGHTWARDS THEN CURVING DOWNWARDS"),
+	(0x2936, "ARROW POINTING DOWNWARDS THEN CURVING LEFTWARDS"),
+	(0x2937, "ARROW POINTING DOWNWARDS THEN CURVING RIGHTWARDS"),
+	(0x2938, "RIGHT-SIDE ARC CLOCKWISE ARROW"),
+	(0x2939, "LEFT-SIDE ARC ANTICLOCKWISE ARROW"),
+	(0x293a, "TOP ARC ANTICLOCKWISE ARROW"),
+	(0x293b, "BOTTOM ARC ANTICLOCKWISE ARROW"),
+	(0x293c, "TOP ARC CLOCKWISE ARROW WITH MINUS"),
+	(0x293d, "TOP ARC ANTICLOCKWISE ARROW WITH PLUS"),
+	(0x293e, "LOWER RIGHT SEMICIRCULAR CLOCKWISE ARROW"),
+	(0x293f, "LOWER LEFT SEMICIRCULAR ANTICLOCKWISE ARROW"),
+	(0x2940, "ANTICLOCKWISE CLOSED CIRCLE ARROW"),
+	(0x2941, "CLOCKWISE CLOSED CIRCLE ARROW"),
+	(0x2942, "RIGHTWARDS ARROW ABOVE SHORT LEFTWARDS ARROW"),
+	(0x2943, "LEFTWARDS ARROW ABOVE SHORT RIGHTWARDS ARROW"),
+	(0x2944, "SHORT RIGHTWARDS ARROW ABOVE LEFTWARDS ARROW"),
+	(0x2945, "RIGHTWARDS ARROW WITH PLUS BELOW"),
+	(0x2946, "LEFTWARDS ARROW WITH PLUS BELOW"),
+	(0x2947, "RIGHTWARDS ARROW THROUGH X"),
+	(0x2948, "LEFT RIGHT ARROW THROUGH SMALL CIRCLE"),
+	(0x2949, "UPWARDS TWO-HEADED ARROW FROM SMALL CIRCLE"),
+	(0x294a, "LEFT BARB UP RIGHT BARB DOWN HARPOON"),
+	(0x294b, "LEFT BARB DOWN RIGHT BARB UP HARPOON"),
+	(0x294c, "UP BARB RIGHT DOWN BARB LEFT HARPOON"),
+	(0x294d, "UP BARB LEFT DOWN BARB RIGHT HARPOON"),
+	(0x294e, "LEFT BARB UP RIGHT BARB UP HARPOON"),
+	(0x294f, "UP BARB RIGHT DOWN BARB RIGHT HARPOON"),
+	(0x2950, "LEFT BARB DOWN RIGHT BARB DOWN HARPOON"),
+	(0x2951, "UP BARB LEFT DOWN BARB LEFT HARPOON"),
+	(0x2952, "LEFTWARDS HARPOON WITH BARB UP TO BAR"),
+	(0x2953, "RIGHTWARDS HARPOON WITH BARB UP TO BAR"),
+	(0x2954, "UPWARDS HARPOON WITH BARB RIGHT TO BAR"),
+	(0x2955, "DOWNWARDS HARPOON WITH BARB RIGHT TO BAR"),
+	(0x2956, "LEFTWARDS HARPOON WITH BARB DOWN TO BAR"),
+	(0x2957, "RIGHTWARDS HARPOON WITH BARB DOWN TO BAR"),
+	(0x2958, "UPWARDS HARPOON WITH BARB LEFT TO BAR"),
+	(0x2959, "DOWNWARDS HARPOON WITH BARB LEFT TO BAR"),
+	(0x295a, "LEFTWARDS HARPOON WITH BARB UP FROM BAR"),
+	(0x295b, "RIGHTWARDS HARPOON WITH BARB UP FROM BAR"),
+	(0x295c, "UPWARDS HARPOON WITH BARB RIGHT FROM BAR"),
+	(0x295d, "DOWNWARDS HARPOON WITH BARB RIGHT FROM BAR"),
+	(0x295e, "LEFTWARDS HARPOON WITH BARB DOWN FROM BAR"),
+	(0x295f, "RIGHTWARDS HARPOON WITH BARB DOWN FROM BAR"),
+	(0x2960, "UPWARDS HARPOON WITH BARB LEFT FROM BAR"),
+	(0x2961, "DOWNWARDS HARPOON WITH BARB LEFT FROM BAR"),
+	(0x2962, "LEFTWARDS HARPOON WITH BARB UP ABOVE LEFTWARDS HARPOON WITH BARB DOWN"),
+	(0x2963, "UPWARDS HARPOON WITH BARB LEFT BESIDE UPWARDS HARPOON WITH BARB RIGHT"),
+	(0x2964, "RIGHTWARDS HARPOON WITH BARB UP ABOVE RIGHTWARDS HARPOON WITH BARB DOWN"),
+	(0x2965, "DOWNWARDS HARPOON WITH BARB LEFT BESIDE DOWNWARDS HARPOON WITH BARB RIGHT"),
+	(0x2966, "LEFTWARDS HARPOON WITH BARB UP ABOVE RIGHTWARDS HARPOON WITH BARB UP"),
+	(0x2967, "LEFTWARDS HARPOON WITH BARB DOWN ABOVE RIGHTWARDS HARPOON WITH BARB DOWN"),
+	(0x2968, "RIGHTWARDS HARPOON WITH BARB UP ABOVE LEFTWARDS HARPOON WITH BARB UP"),
+	(0x2969, "RIGHTWARDS HARPOON WITH BARB DOWN ABOVE LEFTWARDS HARPOON WITH BARB DOWN"),
+	(0x296a, "LEFTWARDS HARPOON WITH BARB UP ABOVE LONG DASH"),
+	(0x296b, "LEFTWARDS HARPOON WITH BARB DOWN BELOW LONG DASH"),
+	(0x296c, "RIGHTWARDS HARPOON WITH BARB UP ABOVE LONG DASH"),
+	(0x296d, "RIGHTWARDS HARPOON WITH BARB DOWN BELOW LONG DASH"),
+	(0x296e, "UPWARDS HARPOON WITH BARB LEFT BESIDE DOWNWARDS HARPOON WITH BARB RIGHT"),
+	(0x296f, "DOWNWARDS HARPOON WITH BARB LEFT BESIDE UPWARDS HARPOON WITH BARB RIGHT"),
+	(0x2970, "RIGHT DOUBLE ARROW WITH ROUNDED HEAD"),
+	(0x2971, "EQUALS SIGN ABOVE RIGHTWARDS ARROW"),
+	(0x2972, "TILDE OPERATOR ABOVE RIGHTWARDS ARROW"),
+	(0x2973, "LEFTWARDS ARROW ABOVE TILDE OPERATOR"),
+	(0x2974, "RIGHTWARDS ARROW ABOVE TILDE OPERATOR"),
+	(0x2975, "RIGHTWARDS ARROW ABOVE ALMOST EQUAL TO"),
+	(0x2976, "LESS-THAN ABOVE LEFTWARDS ARROW"),
+	(0x2977, "LEFTWARDS ARROW THROUGH LESS-THAN"),
+	(0x2978, "GREATER-THAN ABOVE RIGHTWARDS ARROW"),
+	(0x2979, "SUBSET ABOVE RIGHTWARDS ARROW"),
+	(0x297a, "LEFTWARDS ARROW THROUGH SUBSET"),
+	(0x297b, "SUPERSET ABOVE LEFTWARDS ARROW"),
+	(0x297c, "LEFT FISH TAIL"),
+	(0x297d, "RIGHT FISH TAIL"),
+	(0x297e, "UP FISH TAIL"),
+	(0x297f, "DOWN FISH TAIL"),
+	(0x2980, "TRIPLE VERTICAL BAR DELIMITER"),
+	(0x2981, "Z NOTATION SPOT"),
+	(0x2982, "Z NOTATION TYPE COLON"),
+	(0x2983, "LEFT WHITE CURLY BRACKET"),
+	(0x2984, "RIGHT WHITE CURLY BRACKET"),
+	(0x2985, "LEFT WHITE PARENTHESIS"),
+	(0x2986, "RIGHT WHITE PARENTHESIS"),
+	(0x2987, "Z NOTATION LEFT IMAGE BRACKET"),
+	(0x2988, "Z NOTATION RIGHT IMAGE BRACKET"),
+	(0x2989, "Z NOTATION LEFT BINDING BRACKET"),
+	(0x298a, "Z NOTATION RIGHT BINDING BRACKET"),
+	(0x298b, "LEFT SQUARE BRACKET WITH UNDERBAR"),
+	(0x298c, "RIGHT SQUARE BRACKET WITH UNDERBAR"),
+	(0x298d, "LEFT SQUARE BRACKET WITH TICK IN TOP CORNER"),
+	(0x298e, "RIGHT SQUARE BRACKET WITH TICK IN BOTTOM CORNER"),
+	(0x298f, "LEFT SQUARE BRACKET WITH TICK IN BOTTOM CORNER"),
+	(0x2990, "RIGHT SQUARE BRACKET WITH TICK IN TOP CORNER"),
+	(0x2991, "LEFT ANGLE BRACKET WITH DOT"),
+	(0x2992, "RIGHT ANGLE BRACKET WITH DOT"),
+	(0x2993, "LEFT ARC LESS-THAN BRACKET"),
+	(0x2994, "RIGHT ARC GREATER-THAN BRACKET"),
+	(0x2995, "DOUBLE LEFT ARC GREATER-THAN BRACKET"),
+	(0x2996, "DOUBLE RIGHT ARC LESS-THAN BRACKET"),
+	(0x2997, "LEFT BLACK TORTOISE SHELL BRACKET"),
+	(0x2998, "RIGHT BLACK TORTOISE SHELL BRACKET"),
+	(0x2999, "DOTTED FENCE"),
+	(0x299a, "VERTICAL ZIGZAG LINE"),
+	(0x299b, "MEASURED ANGLE OPENING LEFT"),
+	(0x299c, "RIGHT ANGLE VARIANT WITH SQUARE"),
+	(0x299d, "MEASURED RIGHT ANGLE WITH DOT"),
+	(0x299e, "ANGLE WITH S INSIDE"),
+	(0x299f, "ACUTE ANGLE"),
+	(0x29a0, "SPHERICAL ANGLE OPENING LEFT"),
+	(0x29a1, "SPHERICAL ANGLE OPENING UP"),
+	(0x29a2, "TURNED ANGLE"),
+	(0x29a3, "REVERSED ANGLE"),
+	(0x29a4, "ANGLE WITH UNDERBAR"),
+	(0x29a5, "REVERSED ANGLE WITH UNDERBAR"),
+	(0x29a6, "OBLIQUE ANGLE OPENING UP"),
+	(0x29a7, "OBLIQUE ANGLE OPENING DOWN"),
+	(0x29a8, "MEASURED ANGLE WITH OPEN ARM ENDING IN ARROW POINTING UP AND RIGHT"),
+	(0x29a9, "MEASURED ANGLE WITH OPEN ARM ENDING IN ARROW POINTING UP AND LEFT"),
+	(0x29aa, "MEASURED ANGLE WITH OPEN ARM ENDING IN ARROW POINTING DOWN AND RIGHT"),
+	(0x29ab, "MEASURED ANGLE WITH OPEN ARM ENDING IN ARROW POINTING DOWN AND LEFT"),
+	(0x29ac, "MEASURED ANGLE WITH OPEN ARM ENDING IN ARROW POINTING RIGHT AND UP"),
+	(0x29ad, "MEASURED ANGLE WITH OPEN ARM ENDING IN ARROW POINTING LEFT AND UP"),
+	(0x29ae, "MEASURED ANGLE WITH OPEN ARM ENDING IN ARROW POINTING RIGHT AND DOWN"),
+	(0x29af, "MEASURED ANGLE WITH OPEN ARM ENDING IN ARROW POINTING LEFT AND DOWN"),
+	(0x29b0, "REVERSED EMPTY SET"),
+	(0x29b1, "EMPTY SET WITH OVERBAR"),
+	(0x29b2, "EMPTY SET WITH SMALL CIRCLE ABOVE"),
+	(0x29b3, "EMPTY SET WITH RIGHT ARROW ABOVE"),
+	(0x29b4, "EMPTY SET WITH LEFT ARROW ABOVE"),
+	(0x29b5, "CIRCLE WITH HORIZONTAL BAR"),
+	(0x29b6, "CIRCLED VERTICAL BAR"),
+	(0x29b7, "CIRCLED PARALLEL"),
+	(0x29b8, "CIRCLED REVERSE SOLIDUS"),
+	(0x29b9, "CIRCLED PERPENDICULAR"),
+	(0x29ba, "CIRCLE DIVIDED BY HORIZONTAL BAR AND TOP HALF DIVIDED BY VERTICAL BAR"),
+	(0x29bb, "CIRCLE WITH SUPERIMPOSED X"),
+	(0x29bc, "CIRCLED ANTICLOCKWISE-ROTATED DIVISION SIGN"),
+	(0x29bd, "UP ARROW THROUGH CIRCLE"),
+	(0x29be, "CIRCLED WHITE BULLET"),
+	(0x29bf, "CIRCLED BULLET"),
+	(0x29c0, "CIRCLED LESS-THAN"),
+	(0x29c1, "CIRCLED GREATER-THAN"),
+	(0x29c2, "CIRCLE WITH SMALL CIRCLE TO THE RIGHT"),
+	(0x29c3, "CIRCLE WITH TWO HORIZONTAL STROKES TO THE RIGHT"),
+	(0x29c4, "SQUARED RISING DIAGONAL SLASH"),
+	(0x29c5, "SQUARED FALLING DIAGONAL SLASH"),
+	(0x29c6, "SQUARED ASTERISK"),
+	(0x29c7, "SQUARED SMALL CIRCLE"),
+	(0x29c8, "SQUARED SQUARE"),
+	(0x29c9, "TWO JOINED SQUARES"),
+	(0x29ca, "TRIANGLE WITH DOT ABOVE"),
+	(0x29cb, "TRIANGLE WITH UNDERBAR"),
+	(0x29cc, "S IN TRIANGLE"),
+	(0x29cd, "TRIANGLE WITH SERIFS AT BOTTOM"),
+	(0x29ce, "RIGHT TRIANGLE ABOVE LEFT TRIANGLE"),
+	(0x29cf, "LEFT TRIANGLE BESIDE VERTICAL BAR"),
+	(0x29d0, "VERTICAL BAR BESIDE RIGHT TRIANGLE"),
+	(0x29d1, "BOWTIE WITH LEFT HALF BLACK"),
+	(0x29d2, "BOWTIE WITH RIGHT HALF BLACK"),
+	(0x29d3, "BLACK BOWTIE"),
+	(0x29d4, "TIMES WITH LEFT HALF BLACK"),
+	(0x29d5, "TIMES WITH RIGHT HALF BLACK"),
+	(0x29d6, "WHITE HOURGLASS"),
+	(0x29d7, "BLACK HOURGLASS"),
+	(0x29d8, "LEFT WIGGLY FENCE"),
+	(0x29d9, "RIGHT WIGGLY FENCE"),
+	(0x29da, "LEFT DOUBLE WIGGLY FENCE"),
+	(0x29db, "RIGHT DOUBLE WIGGLY FENCE"),
+	(0x29dc, "INCOMPLETE INFINITY"),
+	(0x29dd, "TIE OVER INFINITY"),
+	(0x29de, "INFINITY NEGATED WITH VERTICAL BAR"),
+	(0x29df, "DOUBLE-ENDED MULTIMAP"),
+	(0x29e0, "SQUARE WITH CONTOURED OUTLINE"),
+	(0x29e1, "INCREASES AS"),
+	(0x29e2, "SHUFFLE PRODUCT"),
+	(0x29e3, "EQUALS SIGN AND SLANTED PARALLEL"),
+	(0x29e4, "EQUALS SIGN AND SLANTED PARALLEL WITH TILDE ABOVE"),
+	(0x29e5, "IDENTICAL TO AND SLANTED PARALLEL"),
+	(0x29e6, "GLEICH STARK"),
+	(0x29e7, "THERMODYNAMIC"),
+	(0x29e8, "DOWN-POINTING TRIANGLE WITH LEFT HALF BLACK"),
+	(0x29e9, "DOWN-POINTING TRIANGLE WITH RIGHT HALF BLACK"),
+	(0x29ea, "BLACK DIAMOND WITH DOWN ARROW"),
+	(0x29eb, "BLACK LOZENGE"),
+	(0x29ec, "WHITE CIRCLE WITH DOWN ARROW"),
+	(0x29ed, "BLACK CIRCLE WITH DOWN ARROW"),
+	(0x29ee, "ERROR-BARRED WHITE SQUARE"),
+	(0x29ef, "ERROR-BARRED BLACK SQUARE"),
+	(0x29f0, "ERROR-BARRED WHITE DIAMOND"),
+	(0x29f1, "ERROR-BARRED BLACK DIAMOND"),
+	(0x29f2, "ERROR-BARRED WHITE CIRCLE"),
+	(0x29f3, "ERROR-BARRED BLACK CIRCLE"),
+	(0x29f4, "RULE-DELAYED"),
+	(0x29f5, "REVERSE SOLIDUS OPERATOR"),
+	(0x29f6, "SOLIDUS WITH OVERBAR"),
+	(0x29f7, "REVERSE SOLIDUS WITH HORIZONTAL STROKE"),
+	(0x29f8, "BIG SOLIDUS"),
+	(0x29f9, "BIG REVERSE SOLIDUS"),
+	(0x29fa, "DOUBLE PLUS"),
+	(0x29fb, "TRIPLE PLUS"),
+	(0x29fc, "LEFT-POINTING CURVED ANGLE BRACKET"),
+	(0x29fd, "RIGHT-POINTING CURVED ANGLE BRACKET"),
+	(0x29fe, "TINY"),
+	(0x29ff, "MINY"),
+	(0x2a00, "N-ARY CIRCLED DOT OPERATOR"),
+	(0x2a01, "N-ARY CIRCLED PLUS OPERATOR"),
+	(0x2a02, "N-ARY CIRCLED TIMES OPERATOR"),
+	(0x2a03, "N-ARY UNION OPERATOR WITH DOT"),
+	(0x2a04, "N-ARY UNION OPERATOR WITH PLUS"),
+	(0x2a05, "N-ARY SQUARE INTERSECTION OPERATOR"),
+	(0x2a06, "N-ARY SQUARE UNION OPERATOR"),
+	(0x2a07, "TWO LOGICAL AND OPERATOR"),
+	(0x2a08, "TWO LOGICAL OR OPERATOR"),
+	(0x2a09, "N-ARY TIMES OPERATOR"),
+	(0x2a0a, "MODULO TWO SUM"),
+	(0x2a0b, "SUMMATION WITH INTEGRAL"),
+	(0x2a0c, "QUADRUPLE INTEGRAL OPERATOR"),
+	(0x2a0d, "FINITE PART INTEGRAL"),
+	(0x2a0e, "INTEGRAL WITH DOUBLE STROKE"),
+	(0x2a0f, "INTEGRAL AVERAGE WITH SLASH"),
+	(0x2a10, "CIRCULATION FUNCTION"),
+	(0x2a11, "ANTICLOCKWISE INTEGRATION"),
+	(0x2a12, "LINE INTEGRATION WITH RECTANGULAR PATH AROUND POLE"),
+	(0x2a13, "LINE INTEGRATION WITH SEMICIRCULAR PATH AROUND POLE"),
+	(0x2a14, "LINE INTEGRATION NOT INCLUDING THE POLE"),
+	(0x2a15, "INTEGRAL AROUND A POINT OPERATOR"),
+	(0x2a16, "QUATERNION INTEGRAL OPERATOR"),
+	(0x2a17, "INTEGRAL WITH LEFTWARDS ARROW WITH HOOK"),
+	(0x2a18, "INTEGRAL WITH TIMES SIGN"),
+	(0x2a19, "INTEGRAL WITH INTERSECTION"),
+	(0x2a1a, "INTEGRAL WITH UNION"),
+	(0x2a1b, "INTEGRAL WITH OVERBAR"),
+	(0x2a1c, "INTEGRAL WITH UNDERBAR"),
+	(0x2a1d, "JOIN"),
+	(0x2a1e, "LARGE LEFT TRIANGLE OPERATOR"),
+	(0x2a1f, "Z NOTATION SCHEMA COMPOSITION"),
+	(0x2a20, "Z NOTATION SCHEMA PIPING"),
+	(0x2a21, "Z NOTATION SCHEMA PROJECTION"),
+	(0x2a22, "PLUS SIGN WITH SMALL CIRCLE ABOVE"),
+	(0x2a23, "PLUS SIGN WITH CIRCUMFLEX ACCENT ABOVE"),
+	(0x2a24, "PLUS SIGN WITH TILDE ABOVE"),
+	(0x2a25, "PLUS SIGN WITH DOT BELOW"),
+	(0x2a26, "PLUS SIGN WITH TILDE BELOW"),
+	(0x2a27, "PLUS SIGN WITH SUBSCRIPT TWO"),
+	(0x2a28, "PLUS SIGN WITH BLACK TRIANGLE"),
+	(0x2a29, "MINUS SIGN WITH COMMA ABOVE"),
+	(0x2a2a, "MINUS SIGN WITH DOT BELOW"),
+	(0x2a2b, "MINUS SIGN WITH FALLING DOTS"),
+	(0x2a2c, "MINUS SIGN WITH RISING DOTS"),
+	(0x2a2d, "PLUS SIGN IN LEFT HALF CIRCLE"),
+	(0x2a2e, "PLUS SIGN IN RIGHT HALF CIRCLE"),
+	(0x2a2f, "VECTOR OR CROSS PRODUCT"),
+	(0x2a30, "MULTIPLICATION SIGN WITH DOT ABOVE"),
+	(0x2a31, "MULTIPLICATION SIGN WITH UNDERBAR"),
+	(0x2a32, "SEMIDIRECT PRODUCT WITH BOTTOM CLOSED"),
+	(0x2a33, "SMASH PRODUCT"),
+	(0x2a34, "MULTIPLICATION SIGN IN LEFT HALF CIRCLE"),
+	(0x2a35, "MULTIPLICATION SIGN IN RIGHT HALF CIRCLE"),
+	(0x2a36, "CIRCLED MULTIPLICATION SIGN WITH CIRCUMFLEX ACCENT"),
+	(0x2a37, "MULTIPLICATION SIGN IN DOUBLE CIRCLE"),
+	(0x2a38, "CIRCLED DIVISION SIGN"),
+	(0x2a39, "PLUS SIGN IN TRIANGLE"),
+	(0x2a3a, "MINUS SIGN IN TRIANGLE"),
+	(0x2a3b, "MULTIPLICATION SIGN IN TRIANGLE"),
+	(0x2a3c, "INTERIOR PRODUCT"),
+	(0x2a3d, "RIGHTHAND INTERIOR PRODUCT"),
+	(0x2a3e, "Z NOTATION RELATIONAL COMPOSITION"),
+	(0x2a3f, "AMALGAMATION OR COPRODUCT"),
+	(0x2a40, "INTERSECTION WITH DOT"),
+	(0x2a41, "UNION WITH MINUS SIGN"),
+	(0x2a42, "UNION WITH OVERBAR"),
+	(0x2a43, "INTERSECTION WITH OVERBAR"),
+	(0x2a44, "INTERSECTION WITH LOGICAL AND"),
+	(0x2a45, "UNION WITH LOGICAL OR"),
+	(0x2a46, "UNION ABOVE INTERSECTION"),
+	(0x2a47, "INTERSECTION ABOVE UNION"),
+	(0x2a48, "UNION ABOVE BAR ABOVE INTERSECTION"),
+	(0x2a49, "INTERSECTION ABOVE BAR ABOVE UNION"),
+	(0x2a4a, "UNION BESIDE AND JOINED WITH UNION"),
+	(0x2a4b, "INTERSECTION BESIDE AND JOINED WITH INTERSECTION"),
+	(0x2a4c, "CLOSED UNION WITH SERIFS"),
+	(0x2a4d, "CLOSED INTERSECTION WITH SERIFS"),
+	(0x2a4e, "DOUBLE SQUARE INTERSECTION"),
+	(0x2a4f, "DOUBLE SQUARE UNION"),
+	(0x2a50, "CLOSED UNION WITH SERIFS AND SMASH PRODUCT"),
+	(0x2a51, "LOGICAL AND WITH DOT ABOVE"),
+	(0x2a52, "LOGICAL OR WITH DOT ABOVE"),
+	(0x2a53, "DOUBLE LOGICAL AND"),
+	(0x2a54, "DOUBLE LOGICAL OR"),
+	(0x2a55, "TWO INTERSECTING LOGICAL AND"),
+	(0x2a56, "TWO INTERSECTING LOGICAL OR"),
+	(0x2a57, "SLOPING LARGE OR"),
+	(0x2a58, "SLOPING LARGE AND"),
+	(0x2a59, "LOGICAL OR OVERLAPPING LOGICAL AND"),
+	(0x2a5a, "LOGICAL AND WITH MIDDLE STEM"),
+	(0x2a5b, "LOGICAL OR WITH MIDDLE STEM"),
+	(0x2a5c, "LOGICAL AND WITH HORIZONTAL DASH"),
+	(0x2a5d, "LOGICAL OR WITH HORIZONTAL DASH"),
+	(0x2a5e, "LOGICAL AND WITH DOUBLE OVERBAR"),
+	(0x2a5f, "LOGICAL AND WITH UNDERBAR"),
+	(0x2a60, "LOGICAL AND WITH DOUBLE UNDERBAR"),
+	(0x2a61, "SMALL VEE WITH UNDERBAR"),
+	(0x2a62, "LOGICAL OR WITH DOUBLE OVERBAR"),
+	(0x2a63, "LOGICAL OR WITH DOUBLE UNDERBAR"),
+	(0x2a64, "Z NOTATION DOMAIN ANTIRESTRICTION"),
+	(0x2a65, "Z NOTATION RANGE ANTIRESTRICTION"),
+	(0x2a66, "EQUALS SIGN WITH DOT BELOW"),
+	(0x2a67, "IDENTICAL WITH DOT ABOVE"),
+	(0x2a68, "TRIPLE HORIZONTAL BAR WITH DOUBLE VERTICAL STROKE"),
+	(0x2a69, "TRIPLE HORIZONTAL BAR WITH TRIPLE VERTICAL STROKE"),
+	(0x2a6a, "TILDE OPERATOR WITH DOT ABOVE"),
+	(0x2a6b, "TILDE OPERATOR WITH RISING DOTS"),
+	(0x2a6c, "SIMILAR MINUS SIMILAR"),
+	(0x2a6d, "CONGRUENT WITH DOT ABOVE"),
+	(0x2a6e, "EQUALS WITH ASTERISK"),
+	(0x2a6f, "ALMOST EQUAL TO WITH CIRCUMFLEX ACCENT"),
+	(0x2a70, "APPROXIMATELY EQUAL OR EQUAL TO"),
+	(0x2a71, "EQUALS SIGN ABOVE PLUS SIGN"),
+	(0x2a72, "PLUS SIGN ABOVE EQUALS SIGN"),
+	(0x2a73, "EQUALS SIGN ABOVE TILDE OPERATOR"),
+	(0x2a74, "DOUBLE COLON EQUAL"),
+	(0x2a75, "TWO CONSECUTIVE EQUALS SIGNS"),
+	(0x2a76, "THREE CONSECUTIVE EQUALS SIGNS"),
+	(0x2a77, "EQUALS SIGN WITH TWO DOTS ABOVE AND TWO DOTS BELOW"),
+	(0x2a78, "EQUIVALENT WITH FOUR DOTS ABOVE"),
+	(0x2a79, "LESS-THAN WITH CIRCLE INSIDE"),
+	(0x2a7a, "GREATER-THAN WITH CIRCLE INSIDE"),
+	(0x2a7b, "LESS-THAN WITH QUESTION MARK ABOVE"),
+	(0x2a7c, "GREATER-THAN WITH QUESTION MARK ABOVE"),
+	(0x2a7d, "LESS-THAN OR SLANTED EQUAL TO"),
+	(0x2a7e, "GREATER-THAN OR SLANTED EQUAL TO"),
+	(0x2a7f, "LESS-THAN OR SLANTED EQUAL TO WITH DOT INSIDE"),
+	(0x2a80, "GREATER-THAN OR SLANTED EQUAL TO WITH DOT INSIDE"),
+	(0x2a81, "LESS-THAN OR SLANTED EQUAL TO WITH DOT ABOVE"),
+	(0x2a82, "GREATER-THAN OR SLANTED EQUAL TO WITH DOT ABOVE"),
+	(0x2a83, "LESS-THAN OR SLANTED EQUAL TO WITH DOT ABOVE RIGHT"),
+	(0x2a84, "GREATER-THAN OR SLANTED EQUAL TO WITH DOT ABOVE LEFT"),
+	(0x2a85, "LESS-THAN OR APPROXIMATE"),
+	(0x2a86, "GREATER-THAN OR APPROXIMATE"),
+	(0x2a87, "LESS-THAN AND SINGLE-LINE NOT EQUAL TO"),
+	(0x2a88, "GREATER-THAN AND SINGLE-LINE NOT EQUAL TO"),
+	(0x2a89, "LESS-THAN AND NOT APPROXIMATE"),
+	(0x2a8a, "GREATER-THAN AND NOT APPROXIMATE"),
+	(0x2a8b, "LESS-THAN ABOVE DOUBLE-LINE EQUAL ABOVE GREATER-THAN"),
+	(0x2a8c, "GREATER-THAN ABOVE DOUBLE-LINE EQUAL ABOVE LESS-THAN"),
+	(0x2a8d, "LESS-THAN ABOVE SIMILAR OR EQUAL"),
+	(0x2a8e, "GREATER-THAN ABOVE SIMILAR OR EQUAL"),
+	(0x2a8f, "LESS-THAN ABOVE SIMILAR ABOVE GREATER-THAN"),
+	(0x2a90, "GREATER-THAN ABOVE SIMILAR ABOVE LESS-THAN"),
+	(0x2a91, "LESS-THAN ABOVE GREATER-THAN ABOVE DOUBLE-LINE EQUAL"),
+	(0x2a92, "GREATER-THAN ABOVE LESS-THAN ABOVE DOUBLE-LINE EQUAL"),
+	(0x2a93, "LESS-THAN ABOVE SLANTED EQUAL ABOVE GREATER-THAN ABOVE SLANTED EQUAL"),
+	(0x2a94, "GREATER-THAN ABOVE SLANTED EQUAL ABOVE LESS-THAN ABOVE SLANTED EQUAL"),
+	(0x2a95, "SLANTED EQUAL TO OR LESS-THAN"),
+	(0x2a96, "SLANTED EQUAL TO OR GREATER-THAN"),
+	(0x2a97, "SLANTED EQUAL TO OR LESS-THAN WITH DOT INSIDE"),
+	(0x2a98, "SLANTED EQUAL TO OR GREATER-THAN WITH DOT INSIDE"),
+	(0x2a99, "DOUBLE-LINE EQUAL TO OR LESS-THAN"),
+	(0x2a9a, "DOUBLE-LINE EQUAL TO OR GREATER-THAN"),
+	(0x2a9b, "DOUBLE-LINE SLANTED EQUAL TO OR LESS-THAN"),
+	(0x2a9c, "DOUBLE-LINE SLANTED EQUAL TO OR GREATER-THAN"),
+	(0x2a9d, "SIMILAR OR LESS-THAN"),
+	(0x2a9e, "SIMILAR OR GREATER-THAN"),
+	(0x2a9f, "SIMILAR ABOVE LESS-THAN ABOVE EQUALS SIGN"),
+	(0x2aa0, "SIMILAR ABOVE GREATER-THAN ABOVE EQUALS SIGN"),
+	(0x2aa1, "DOUBLE NESTED LESS-THAN"),
+	(0x2aa2, "DOUBLE NESTED GREATER-THAN"),
+	(0x2aa3, "DOUBLE NESTED LESS-THAN WITH UNDERBAR"),
+	(0x2aa4, "GREATER-THAN OVERLAPPING LESS-THAN"),
+	(0x2aa5, "GREATER-THAN BESIDE LESS-THAN"),
+	(0x2aa6, "LESS-THAN CLOSED BY CURVE"),
+	(0x2aa7, "GREATER-THAN CLOSED BY CURVE"),
+	(0x2aa8, "LESS-THAN CLOSED BY CURVE ABOVE SLANTED EQUAL"),
+	(0x2aa9, "GREATER-THAN CLOSED BY CURVE ABOVE SLANTED EQUAL"),
+	(0x2aaa, "SMALLER THAN"),
+	(0x2aab, "LARGER THAN"),
+	(0x2aac, "SMALLER THAN OR EQUAL TO"),
+	(0x2aad, "LARGER THAN OR EQUAL TO"),
+	(0x2aae, "EQUALS SIGN WITH BUMPY ABOVE"),
+	(0x2aaf, "PRECEDES ABOVE SINGLE-LINE EQUALS SIGN"),
+	(0x2ab0, "SUCCEEDS ABOVE SINGLE-LINE EQUALS SIGN"),
+	(0x2ab1, "PRECEDES ABOVE SINGLE-LINE NOT EQUAL TO"),
+	(0x2ab2, "SUCCEEDS ABOVE SINGLE-LINE NOT EQUAL TO"),
+	(0x2ab3, "PRECEDES ABOVE EQUALS SIGN"),
+	(0x2ab4, "SUCCEEDS ABOVE EQUALS SIGN"),
+	(0x2ab5, "PRECEDES ABOVE NOT EQUAL TO"),
+	(0x2ab6, "SUCCEEDS ABOVE NOT EQUAL TO"),
+	(0x2ab7, "PRECEDES ABOVE ALMOST EQUAL TO"),
+	(0x2ab8, "SUCCEEDS ABOVE ALMOST EQUAL TO"),
+	(0x2ab9, "PRECEDES ABOVE NOT ALMOST EQUAL TO"),
+	(0x2aba, "SUCCEEDS ABOVE NOT ALMOST EQUAL TO"),
+	(0x2abb, "DOUBLE PRECEDES"),
+	(0x2abc, "DOUBLE SUCCEEDS"),
+	(0x2abd, "SUBSET WITH DOT"),
+	(0x2abe, "SUPERSET WITH DOT"),
+	(0x2abf, "SUBSET WITH PLUS SIGN BELOW"),
+	(0x2ac0, "SUPERSET WITH PLUS SIGN BELOW"),
+	(0x2ac1, "SUBSET WITH MULTIPLICATION SIGN BELOW"),
+	(0x2ac2, "SUPERSET WITH MULTIPLICATION SIGN BELOW"),
+	(0x2ac3, "SUBSET OF OR EQUAL TO WITH DOT ABOVE"),
+	(0x2ac4, "SUPERSET OF OR EQUAL TO WITH DOT ABOVE"),
+	(0x2ac5, "SUBSET OF ABOVE EQUALS SIGN"),
+	(0x2ac6, "SUPERSET OF ABOVE EQUALS SIGN"),
+	(0x2ac7, "SUBSET OF ABOVE TILDE OPERATOR"),
+	(0x2ac8, "SUPERSET OF ABOVE TILDE OPERATOR"),
+	(0x2ac9, "SUBSET OF ABOVE ALMOST EQUAL TO"),
+	(0x2aca, "SUPERSET OF ABOVE ALMOST EQUAL TO"),
+	(0x2acb, "SUBSET OF ABOVE NOT EQUAL TO"),
+	(0x2acc, "SUPERSET OF ABOVE NOT EQUAL TO"),
+	(0x2acd, "SQUARE LEFT OPEN BOX OPERATOR"),
+	(0x2ace, "SQUARE RIGHT OPEN BOX OPERATOR"),
+	(0x2acf, "CLOSED SUBSET"),
+	(0x2ad0, "CLOSED SUPERSET"),
+	(0x2ad1, "CLOSED SUBSET OR EQUAL TO"),
+	(0x2ad2, "CLOSED SUPERSET OR EQUAL TO"),
+	(0x2ad3, "SUBSET ABOVE SUPERSET"),
+	(0x2ad4, "SUPERSET ABOVE SUBSET"),
+	(0x2ad5, "SUBSET ABOVE SUBSET"),
+	(0x2ad6, "SUPERSET ABOVE SUPERSET"),
+	(0x2ad7, "SUPERSET BESIDE SUBSET"),
+	(0x2ad8, "SUPERSET BESIDE AND JOINED BY DASH WITH SUBSET"),
+	(0x2ad9, "ELEMENT OF OPENING DOWNWARDS"),
+	(0x2ada, "PITCHFORK WITH TEE TOP"),
+	(0x2adb, "TRANSVERSAL INTERSECTION"),
+	(0x2adc, "FORKING"),
+	(0x2add, "NONFORKING"),
+	(0x2ade, "SHORT LEFT TACK"),
+	(0x2adf, "SHORT DOWN TACK"),
+	(0x2ae0, "SHORT UP TACK"),
+	(0x2ae1, "PERPENDICULAR WITH S"),
+	(0x2ae2, "VERTICAL BAR TRIPLE RIGHT TURNSTILE"),
+	(0x2ae3, "DOUBLE VERTICAL BAR LEFT TURNSTILE"),
+	(0x2ae4, "VERTICAL BAR DOUBLE LEFT TURNSTILE"),
+	(0x2ae5, "DOUBLE VERTICAL BAR DOUBLE LEFT TURNSTILE"),
+	(0x2ae6, "LONG DASH FROM LEFT MEMBER OF DOUBLE VERTICAL"),
+	(0x2ae7, "SHORT DOWN TACK WITH OVERBAR"),
+	(0x2ae8, "SHORT UP TACK WITH UNDERBAR"),
+	(0x2ae9, "SHORT UP TACK ABOVE SHORT DOWN TACK"),
+	(0x2aea, "DOUBLE DOWN TACK"),
+	(0x2aeb, "DOUBLE UP TACK"),
+	(0x2aec, "DOUBLE STROKE NOT SIGN"),
+	(0x2aed, "REVERSED DOUBLE STROKE NOT SIGN"),
+	(0x2aee, "DOES NOT DIVIDE WITH REVERSED NEGATION SLASH"),
+	(0x2aef, "VERTICAL LINE WITH CIRCLE ABOVE"),
+	(0x2af0, "VERTICAL LINE WITH CIRCLE BELOW"),
+	(0x2af1, "DOWN TACK WITH CIRCLE BELOW"),
+	(0x2af2, "PARALLEL WITH HORIZONTAL STROKE"),
+	(0x2af3, "PARALLEL WITH TILDE OPERATOR"),
+	(0x2af4, "TRIPLE VERTICAL BAR BINARY RELATION"),
+	(0x2af5, "TRIPLE VERTICAL BAR WITH HORIZONTAL STROKE"),
+	(0x2af6, "TRIPLE COLON OPERATOR"),
+	(0x2af7, "TRIPLE NESTED LESS-THAN"),
+	(0x2af8, "TRIPLE NESTED GREATER-THAN"),
+	(0x2af9, "DOUBLE-LINE SLANTED LESS-THAN OR EQUAL TO"),
+	(0x2afa, "DOUBLE-LINE SLANTED GREATER-THAN OR EQUAL TO"),
+	(0x2afb, "TRIPLE SOLIDUS BINARY RELATION"),
+	(0x2afc, "LARGE TRIPLE VERTICAL BAR OPERATOR"),
+	(0x2afd, "DOUBLE SOLIDUS OPERATOR"),
+	(0x2afe, "WHITE VERTICAL BAR"),
+	(0x2aff, "N-ARY WHITE VERTICAL BAR"),
+	(0x2b00, "NORTH EAST WHITE ARROW"),
+	(0x2b01, "NORTH WEST WHITE ARROW"),
+	(0x2b02, "SOUTH EAST WHITE ARROW"),
+	(0x2b03, "SOUTH WEST WHITE ARROW"),
+	(0x2b04, "LEFT RIGHT WHITE ARROW"),
+	(0x2b05, "LEFTWARDS BLACK ARROW"),
+	(0x2b06, "UPWARDS BLACK ARROW"),
+	(0x2b07, "DOWNWARDS BLACK ARROW"),
+	(0x2b08, "NORTH EAST BLACK ARROW"),
+	(0x2b09, "NORTH WEST BLACK ARROW"),
+	(0x2b0a, "SOUTH EAST BLACK ARROW"),
+	(0x2b0b, "SOUTH WEST BLACK ARROW"),
+	(0x2b0c, "LEFT RIGHT BLACK ARROW"),
+	(0x2b0d, "UP DOWN BLACK ARROW"),
+	(0x2b0e, "RIGHTWARDS ARROW WITH TIP DOWNWARDS"),
+	(0x2b0f, "RIGHTWARDS ARROW WITH TIP UPWARDS"),
+	(0x2b10, "LEFTWARDS ARROW WITH TIP DOWNWARDS"),
+	(0x2b11, "LEFTWARDS ARROW WITH TIP UPWARDS"),
+	(0x2b12, "SQUARE WITH TOP HALF BLACK"),
+	(0x2b13, "SQUARE WITH BOTTOM HALF BLACK"),
+	(0x2b14, "SQUARE WITH UPPER RIGHT DIAGONAL HALF BLACK"),
+	(0x2b15, "SQUARE WITH LOWER LEFT DIAGONAL HALF BLACK"),
+	(0x2b16, "DIAMOND WITH LEFT HALF BLACK"),
+	(0x2b17, "DIAMOND WITH RIGHT HALF BLACK"),
+	(0x2b18, "DIAMOND WITH TOP HALF BLACK"),
+	(0x2b19, "DIAMOND WITH BOTTOM HALF BLACK"),
+	(0x2b1a, "DOTTED SQUARE"),
+	(0x2b1b, "BLACK LARGE SQUARE"),
+	(0x2b1c, "WHITE LARGE SQUARE"),
+	(0x2b1d, "BLACK VERY SMALL SQUARE"),
+	(0x2b1e, "WHITE VERY SMALL SQUARE"),
+	(0x2b1f, "BLACK PENTAGON"),
+	(0x2b20, "WHITE PENTAGON"),
+	(0x2b21, "WHITE HEXAGON"),
+	(0x2b22, "BLACK HEXAGON"),
+	(0x2b23, "HORIZONTAL BLACK HEXAGON"),
+	(0x2b24, "BLACK LARGE CIRCLE"),
+	(0x2b25, "BLACK MEDIUM DIAMOND"),
+	(0x2b26, "WHITE MEDIUM DIAMOND"),
+	(0x2b27, "BLACK MEDIUM LOZENGE"),
+	(0x2b28, "WHITE MEDIUM LOZENGE"),
+	(0x2b29, "BLACK SMALL DIAMOND"),
+	(0x2b2a, "BLACK SMALL LOZENGE"),
+	(0x2b2b, "WHITE SMALL LOZENGE"),
+	(0x2b2c, "BLACK HORIZONTAL ELLIPSE"),
+	(0x2b2d, "WHITE HORIZONTAL ELLIPSE"),
+	(0x2b2e, "BLACK VERTICAL ELLIPSE"),
+	(0x2b2f, "WHITE VERTICAL ELLIPSE"),
+	(0x2b30, "LEFT ARROW WITH SMALL CIRCLE"),
+	(0x2b31, "THREE LEFTWARDS ARROWS"),
+	(0x2b32, "LEFT ARROW WITH CIRCLED PLUS"),
+	(0x2b33, "LONG LEFTWARDS SQUIGGLE ARROW"),
+	(0x2b34, "LEFTWARDS TWO-HEADED ARROW WITH VERTICAL STROKE"),
+	(0x2b35, "LEFTWARDS TWO-HEADED ARROW WITH DOUBLE VERTICAL STROKE"),
+	(0x2b36, "LEFTWARDS TWO-HEADED ARROW FROM BAR"),
+	(0x2b37, "LEFTWARDS TWO-HEADED TRIPLE DASH ARROW"),
+	(0x2b38, "LEFTWARDS ARROW WITH DOTTED STEM"),
+	(0x2b39, "LEFTWARDS ARROW WITH TAIL WITH VERTICAL STROKE"),
+	(0x2b3a, "LEFTWARDS ARROW WITH TAIL WITH DOUBLE VERTICAL STROKE"),
+	(0x2b3b, "LEFTWARDS TWO-HEADED ARROW WITH TAIL"),
+	(0x2b3c, "LEFTWARDS TWO-HEADED ARROW WITH TAIL WITH VERTICAL STROKE"),
+	(0x2b3d, "LEFTWARDS TWO-HEADED ARROW WITH TAIL WITH DOUBLE VERTICAL STROKE"),
+	(0x2b3e, "LEFTWARDS ARROW THROUGH X"),
+	(0x2b3f, "WAVE ARROW POINTING DIRECTLY LEFT"),
+	(0x2b40, "EQUALS SIGN ABOVE LEFTWARDS ARROW"),
+	(0x2b41, "REVERSE TILDE OPERATOR ABOVE LEFTWARDS ARROW"),
+	(0x2b42, "LEFTWARDS ARROW ABOVE REVERSE ALMOST EQUAL TO"),
+	(0x2b43, "RIGHTWARDS ARROW THROUGH GREATER-THAN"),
+	(0x2b44, "RIGHTWARDS ARROW THROUGH SUPERSET"),
+	(0x2b45, "LEFTWARDS QUADRUPLE ARROW"),
+	(0x2b46, "RIGHTWARDS QUADRUPLE ARROW"),
+	(0x2b47, "REVERSE TILDE OPERATOR ABOVE RIGHTWARDS ARROW"),
+	(0x2b48, "RIGHTWARDS ARROW ABOVE REVERSE ALMOST EQUAL TO"),
+	(0x2b49, "TILDE OPERATOR ABOVE LEFTWARDS ARROW"),
+	(0x2b4a, "LEFTWARDS ARROW ABOVE ALMOST EQUAL TO"),
+	(0x2b4b, "LEFTWARDS ARROW ABOVE REVERSE TILDE OPERATOR"),
+	(0x2b4c, "RIGHTWARDS ARROW ABOVE REVERSE TILDE OPERATOR"),
+	(0x2b4d, "DOWNWARDS TRIANGLE-HEADED ZIGZAG ARROW"),
+	(0x2b4e, "SHORT SLANTED NORTH ARROW"),
+	(0x2b4f, "SHORT BACKSLANTED SOUTH ARROW"),
+	(0x2b50, "WHITE MEDIUM STAR"),
+	(0x2b51, "BLACK SMALL STAR"),
+	(0x2b52, "WHITE SMALL STAR"),
+	(0x2b53, "BLACK RIGHT-POINTING PENTAGON"),
+	(0x2b54, "WHITE RIGHT-POINTING PENTAGON"),
+	(0x2b55, "HEAVY LARGE CIRCLE"),
+	(0x2b56, "HEAVY OVAL WITH OVAL INSIDE"),
+	(0x2b57, "HEAVY CIRCLE WITH CIRCLE INSIDE"),
+	(0x2b58, "HEAVY CIRCLE"),
+	(0x2b59, "HEAVY CIRCLED SALTIRE"),
+	(0x2b5a, "SLANTED NORTH ARROW WITH HOOKED HEAD"),
+	(0x2b5b, "BACKSLANTED SOUTH ARROW WITH HOOKED TAIL"),
+	(0x2b5c, "SLANTED NORTH ARROW WITH HORIZONTAL TAIL"),
+	(0x2b5d, "BACKSLANTED SOUTH ARROW WITH HORIZONTAL TAIL"),
+	(0x2b5e, "BENT ARROW POINTING DOWNWARDS THEN NORTH EAST"),
+	(0x2b5f, "SHORT BENT ARROW POINTING DOWNWARDS THEN NORTH EAST"),
+	(0x2b60, "LEFTWARDS TRIANGLE-HEADED ARROW"),
+	(0x2b61, "UPWARDS TRIANGLE-HEADED ARROW"),
+	(0x2b62, "RIGHTWARDS TRIANGLE-HEADED ARROW"),
+	(0x2b63, "DOWNWARDS TRIANGLE-HEADED ARROW"),
+	(0x2b64, "LEFT RIGHT TRIANGLE-HEADED ARROW"),
+	(0x2b65, "UP DOWN TRIANGLE-HEADED ARROW"),
+	(0x2b66, "NORTH WEST TRIANGLE-HEADED ARROW"),
+	(0x2b67, "NORTH EAST TRIANGLE-HEADED ARROW"),
+	(0x2b68, "SOUTH EAST TRIANGLE-HEADED ARROW"),
+	(0x2b69, "SOUTH WEST TRIANGLE-HEADED ARROW"),
+	(0x2b6a, "LEFTWARDS TRIANGLE-HEADED DASHED ARROW"),
+	(0x2b6b, "UPWARDS TRIANGLE-HEADED DASHED ARROW"),
+	(0x2b6c, "RIGHTWARDS TRIANGLE-HEADED DASHED ARROW"),
+	(0x2b6d, "DOWNWARDS TRIANGLE-HEADED DASHED ARROW"),
+	(0x2b6e, "CLOCKWISE TRIANGLE-HEADED OPEN CIRCLE ARROW"),
+	(0x2b6f, "ANTICLOCKWISE TRIANGLE-HEADED OPEN CIRCLE ARROW"),
+	(0x2b70, "LEFTWARDS TRIANGLE-HEADED ARROW TO BAR"),
+	(0x2b71, "UPWARDS TRIANGLE-HEADED ARROW TO BAR"),
+	(0x2b72, "RIGHTWARDS TRIANGLE-HEADED ARROW TO BAR"),
+	(0x2b73, "DOWNWARDS TRIANGLE-HEADED ARROW TO BAR"),
+	(0x2b76, "NORTH WEST TRIANGLE-HEADED ARROW TO BAR"),
+	(0x2b77, "NORTH EAST TRIANGLE-HEADED ARROW TO BAR"),
+	(0x2b78, "SOUTH EAST TRIANGLE-HEADED ARROW TO BAR"),
+	(0x2b79, "SOUTH WEST TRIANGLE-HEADED ARROW TO BAR"),
+	(0x2b7a, "LEFTWARDS TRIANGLE-HEADED ARROW WITH DOUBLE HORIZONTAL STROKE"),
+	(0x2b7b, "UPWARDS TRIANGLE-HEADED ARROW WITH DOUBLE HORIZONTAL STROKE"),
+	(0x2b7c, "RIGHTWARDS TRIANGLE-HEADED ARROW WITH DOUBLE HORIZONTAL STROKE"),
+	(0x2b7d, "DOWNWARDS TRIANGLE-HEADED ARROW WITH DOUBLE HORIZONTAL STROKE"),
+	(0x2b7e, "HORIZONTAL TAB KEY"),
+	(0x2b7f, "VERTICAL TAB KEY"),
+	(0x2b80, "LEFTWARDS TRIANGLE-HEADED ARROW OVER RIGHTWARDS TRIANGLE-HEADED ARROW"),
+	(0x2b81, "UPWARDS TRIANGLE-HEADED ARROW LEFTWARDS OF DOWNWARDS TRIANGLE-HEADED ARROW"),
+	(0x2b82, "RIGHTWARDS TRIANGLE-HEADED ARROW OVER LEFTWARDS TRIANGLE-HEADED ARROW"),
+	(0x2b83, "DOWNWARDS TRIANGLE-HEADED ARROW LEFTWARDS OF UPWARDS TRIANGLE-HEADED ARROW"),
+	(0x2b84, "LEFTWARDS TRIANGLE-HEADED PAIRED ARROWS"),
+	(0x2b85, "UPWARDS TRIANGLE-HEADED PAIRED ARROWS"),
+	(0x2b86, "RIGHTWARDS TRIANGLE-HEADED PAIRED ARROWS"),
+	(0x2b87, "DOWNWARDS TRIANGLE-HEADED PAIRED ARROWS"),
+	(0x2b88, "LEFTWARDS BLACK CIRCLED WHITE ARROW"),
+	(0x2b89, "UPWARDS BLACK CIRCLED WHITE ARROW"),
+	(0x2b8a, "RIGHTWARDS BLACK CIRCLED WHITE ARROW"),
+	(0x2b8b, "DOWNWARDS BLACK CIRCLED WHITE ARROW"),
+	(0x2b8c, "ANTICLOCKWISE TRIANGLE-HEADED RIGHT U-SHAPED ARROW"),
+	(0x2b8d, "ANTICLOCKWISE TRIANGLE-HEADED BOTTOM U-SHAPED ARROW"),
+	(0x2b8e, "ANTICLOCKWISE TRIANGLE-HEADED LEFT U-SHAPED ARROW"),
+	(0x2b8f, "ANTICLOCKWISE TRIANGLE-HEADED TOP U-SHAPED ARROW"),
+	(0x2b90, "RETURN LEFT"),
+	(0x2b91, "RETURN RIGHT"),
+	(0x2b92, "NEWLINE LEFT"),
+	(0x2b93, "NEWLINE RIGHT"),
+	(0x2b94, "FOUR CORNER ARROWS CIRCLING ANTICLOCKWISE"),
+	(0x2b95, "RIGHTWARDS BLACK ARROW"),
+	(0x2b97, "SYMBOL FOR TYPE A ELECTRONICS"),
+	(0x2b98, "THREE-D TOP-LIGHTED LEFTWARDS EQUILATERAL ARROWHEAD"),
+	(0x2b99, "THREE-D RIGHT-LIGHTED UPWARDS EQUILATERAL ARROWHEAD"),
+	(0x2b9a, "THREE-D TOP-LIGHTED RIGHTWARDS EQUILATERAL ARROWHEAD"),
+	(0x2b9b, "THREE-D LEFT-LIGHTED DOWNWARDS EQUILATERAL ARROWHEAD"),
+	(0x2b9c, "BLACK LEFTWARDS EQUILATERAL ARROWHEAD"),
+	(0x2b9d, "BLACK UPWARDS EQUILATERAL ARROWHEAD"),
+	(0x2b9e, "BLACK RIGHTWARDS EQUILATERAL ARROWHEAD"),
+	(0x2b9f, "BLACK DOWNWARDS EQUILATERAL ARROWHEAD"),
+	(0x2ba0, "DOWNWARDS TRIANGLE-HEADED ARROW WITH LONG TIP LEFTWARDS"),
+	(0x2ba1, "DOWNWARDS TRIANGLE-HEADED ARROW WITH LONG TIP RIGHTWARDS"),
+	(0x2ba2, "UPWARDS TRIANGLE-HEADED ARROW WITH LONG TIP LEFTWARDS"),
+	(0x2ba3, "UPWARDS TRIANGLE-HEADED ARROW WITH LONG TIP RIGHTWARDS"),
+	(0x2ba4, "LEFTWARDS TRIANGLE-HEADED ARROW WITH LONG TIP UPWARDS"),
+	(0x2ba5, "RIGHTWARDS TRIANGLE-HEADED ARROW WITH LONG TIP UPWARDS"),
+	(0x2ba6, "LEFTWARDS TRIANGLE-HEADED ARROW WITH LONG TIP DOWNWARDS"),
+	(0x2ba7, "RIGHTWARDS TRIANGLE-HEADED ARROW WITH LONG TIP DOWNWARDS"),
+	(0x2ba8, "BLACK CURVED DOWNWARDS AND LEFTWARDS ARROW"),
+	(0x2ba9, "BLACK CURVED DOWNWARDS AND RIGHTWARDS ARROW"),
+	(0x2baa, "BLACK CURVED UPWARDS AND LEFTWARDS ARROW"),
+	(0x2bab, "BLACK CURVED UPWARDS AND RIGHTWARDS ARROW"),
+	(0x2bac, "BLACK CURVED LEFTWARDS AND UPWARDS ARROW"),
+	(0x2bad, "BLACK CURVED RIGHTWARDS AND UPWARDS ARROW"),
+	(0x2bae, "BLACK CURVED LEFTWARDS AND DOWNWARDS ARROW"),
+	(0x2baf, "BLACK CURVED RIGHTWARDS AND DOWNWARDS ARROW"),
+	(0x2bb0, "RIBBON ARROW DOWN LEFT"),
+	(0x2bb1, "RIBBON ARROW DOWN RIGHT"),
+	(0x2bb2, "RIBBON ARROW UP LEFT"),
+	(0x2bb3, "RIBBON ARROW UP RIGHT"),
+	(0x2bb4, "RIBBON ARROW LEFT UP"),
+	(0x2bb5, "RIBBON ARROW RIGHT UP"),
+	(0x2bb6, "RIBBON ARROW LEFT DOWN"),
+	(0x2bb7, "RIBBON ARROW RIGHT DOWN"),
+	(0x2bb8, "UPWARDS WHITE ARROW FROM BAR WITH HORIZONTAL BAR"),
+	(0x2bb9, "UP ARROWHEAD IN A RECTANGLE BOX"),
+	(0x2bba, "OVERLAPPING WHITE SQUARES"),
+	(0x2bbb, "OVERLAPPING WHITE AND BLACK SQUARES"),
+	(0x2bbc, "OVERLAPPING BLACK SQUARES"),
+	(0x2bbd, "BALLOT BOX WITH LIGHT X"),
+	(0x2bbe, "CIRCLED X"),
+	(0x2bbf, "CIRCLED BOLD X"),
+	(0x2bc0, "BLACK SQUARE CENTRED"),
+	(0x2bc1, "BLACK DIAMOND CENTRED"),
+	(0x2bc2, "TURNED BLACK PENTAGON"),
+	(0x2bc3, "HORIZONTAL BLACK OCTAGON"),
+	(0x2bc4, "BLACK OCTAGON"),
+	(0x2bc5, "BLACK MEDIUM UP-POINTING TRIANGLE CENTRED"),
+	(0x2bc6, "BLACK MEDIUM DOWN-POINTING TRIANGLE CENTRED"),
+	(0x2bc7, "BLACK MEDIUM LEFT-POINTING TRIANGLE CENTRED"),
+	(0x2bc8, "BLACK MEDIUM RIGHT-POINTING TRIANGLE CENTRED"),
+	(0x2bc9, "NEPTUNE FORM TWO"),
+	(0x2bca, "TOP HALF BLACK CIRCLE"),
+	(0x2bcb, "BOTTOM HALF BLACK CIRCLE"),
+	(0x2bcc, "LIGHT FOUR POINTED BLACK CUSP"),
+	(0x2bcd, "ROTATED LIGHT FOUR POINTED BLACK CUSP"),
+	(0x2bce, "WHITE FOUR POINTED CUSP"),
+	(0x2bcf, "ROTATED WHITE FOUR POINTED CUSP"),
+	(0x2bd0, "SQUARE POSITION INDICATOR"),
+	(0x2bd1, "UNCERTAINTY SIGN"),
+	(0x2bd2, "GROUP MARK"),
+	(0x2bd3, "PLUTO FORM TWO"),
+	(0x2bd4, "PLUTO FORM THREE"),
+	(0x2bd5, "PLUTO FORM FOUR"),
+	(0x2bd6, "PLUTO FORM FIVE"),
+	(0x2bd7, "TRANSPLUTO"),
+	(0x2bd8, "PROSERPINA"),
+	(0x2bd9, "ASTRAEA"),
+	(0x2bda, "HYGIEA"),
+	(0x2bdb, "PHOLUS"),
+	(0x2bdc, "NESSUS"),
+	(0x2bdd, "WHITE MOON SELENA"),
+	(0x2bde, "BLACK DIAMOND ON CROSS"),
+	(0x2bdf, "TRUE LIGHT MOON ARTA"),
+	(0x2be0, "CUPIDO"),
+	(0x2be1, "HADES"),
+	(0x2be2, "ZEUS"),
+	(0x2be3, "KRONOS"),
+	(0x2be4, "APOLLON"),
+	(0x2be5, "ADMETOS"),
+	(0x2be6, "VULCANUS"),
+	(0x2be7, "POSEIDON"),
+	(0x2be8, "LEFT HALF BLACK STAR"),
+	(0x2be9, "RIGHT HALF BLACK STAR"),
+	(0x2bea, "STAR WITH LEFT HALF BLACK"),
+	(0x2beb, "STAR WITH RIGHT HALF BLACK"),
+	(0x2bec, "LEFTWARDS TWO-HEADED ARROW WITH TRIANGLE ARROWHEADS"),
+	(0x2bed, "UPWARDS TWO-HEADED ARROW WITH TRIANGLE ARROWHEADS"),
+	(0x2bee, "RIGHTWARDS TWO-HEADED ARROW WITH TRIANGLE ARROWHEADS"),
+	(0x2bef, "DOWNWARDS TWO-HEADED ARROW WITH TRIANGLE ARROWHEADS"),
+	(0x2bf0, "ERIS FORM ONE"),
+	(0x2bf1, "ERIS FORM TWO"),
+	(0x2bf2, "SEDNA"),
+	(0x2bf3, "RUSSIAN ASTROLOGICAL SYMBOL VIGINTILE"),
+	(0x2bf4, "RUSSIAN ASTROLOGICAL SYMBOL NOVILE"),
+	(0x2bf5, "RUSSIAN ASTROLOGICAL SYMBOL QUINTILE"),
+	(0x2bf6, "RUSSIAN ASTROLOGICAL SYMBOL BINOVILE"),
+	(0x2bf7, "RUSSIAN ASTROLOGICAL SYMBOL SENTAGON"),
+	(0x2bf8, "RUSSIAN ASTROLOGICAL SYMBOL TREDECILE"),
+	(0x2bf9, "EQUALS SIGN WITH INFINITY BELOW"),
+	(0x2bfa, "UNITED SYMBOL"),
+	(0x2bfb, "SEPARATED SYMBOL"),
+	(0x2bfc, "DOUBLED SYMBOL"),
+	(0x2bfd, "PASSED SYMBOL"),
+	(0x2bfe, "REVERSED RIGHT ANGLE"),
+	(0x2bff, "HELLSCHREIBER PAUSE SYMBOL"),
+	(0x3000, "IDEOGRAPHIC SPACE"),
+	(0x3001, "IDEOGRAPHIC COMMA"),
+	(0x3002, "IDEOGRAPHIC FULL STOP"),
+	(0x3003, "DITTO MARK"),
+	(0x3004, "JAPANESE INDUSTRIAL STANDARD SYMBOL"),
+	(0x3005, "IDEOGRAPHIC ITERATION MARK"),
+	(0x3006, "IDEOGRAPHIC CLOSING MARK"),
+	(0x3007, "IDEOGRAPHIC NUMBER ZERO"),
+	(0x3008, "LEFT ANGLE BRACKET"),
+	(0x3009, "RIGHT ANGLE BRACKET"),
+	(0x300a, "LEFT DOUBLE ANGLE BRACKET"),
+	(0x300b, "RIGHT DOUBLE ANGLE BRACKET"),
+	(0x300c, "LEFT CORNER BRACKET"),
+	(0x300d, "RIGHT CORNER BRACKET"),
+	(0x300e, "LEFT WHITE CORNER BRACKET"),
+	(0x300f, "RIGHT WHITE CORNER BRACKET"),
+	(0x3010, "LEFT BLACK LENTICULAR BRACKET"),
+	(0x3011, "RIGHT BLACK LENTICULAR BRACKET"),
+	(0x3012, "POSTAL MARK"),
+	(0x3013, "GETA MARK"),
+	(0x3014, "LEFT TORTOISE SHELL BRACKET"),
+	(0x3015, "RIGHT TORTOISE SHELL BRACKET"),
+	(0x3016, "LEFT WHITE LENTICULAR BRACKET"),
+	(0x3017, "RIGHT WHITE LENTICULAR BRACKET"),
+	(0x3018, "LEFT WHITE TORTOISE SHELL BRACKET"),
+	(0x3019, "RIGHT WHITE TORTOISE SHELL BRACKET"),
+	(0x301a, "LEFT WHITE SQUARE BRACKET"),
+	(0x301b, "RIGHT WHITE SQUARE BRACKET"),
+	(0x301c, "WAVE DASH"),
+	(0x301d, "REVERSED DOUBLE PRIME QUOTATION MARK"),
+	(0x301e, "DOUBLE PRIME QUOTATION MARK"),
+	(0x301f, "LOW DOUBLE PRIME QUOTATION MARK"),
+	(0x3020, "POSTAL MARK FACE"),
+	(0x3021, "HANGZHOU NUMERAL ONE"),
+	(0x3022, "HANGZHOU NUMERAL TWO"),
+	(0x3023, "HANGZHOU NUMERAL THREE"),
+	(0x3024, "HANGZHOU NUMERAL FOUR"),
+	(0x3025, "HANGZHOU NUMERAL FIVE"),
+	(0x3026, "HANGZHOU NUMERAL SIX"),
+	(0x3027, "HANGZHOU NUMERAL SEVEN"),
+	(0x3028, "HANGZHOU NUMERAL EIGHT"),
+	(0x3029, "HANGZHOU NUMERAL NINE"),
+	(0x302a, "IDEOGRAPHIC LEVEL TONE MARK"),
+	(0x302b, "IDEOGRAPHIC RISING TONE MARK"),
+	(0x302c, "IDEOGRAPHIC DEPARTING TONE MARK"),
+	(0x302d, "IDEOGRAPHIC ENTERING TONE MARK"),
+	(0x302e, "HANGUL SINGLE DOT TONE MARK"),
+	(0x302f, "HANGUL DOUBLE DOT TONE MARK"),
+	(0x3030, "WAVY DASH"),
+	(0x3031, "VERTICAL KANA REPEAT MARK"),
+	(0x3032, "VERTICAL KANA REPEAT WITH VOICED SOUND MARK"),
+	(0x3033, "VERTICAL KANA REPEAT MARK UPPER HALF"),
+	(0x3034, "VERTICAL KANA REPEAT WITH VOICED SOUND MARK UPPER HALF"),
+	(0x3035, "VERTICAL KANA REPEAT MARK LOWER HALF"),
+	(0x3036, "CIRCLED POSTAL MARK"),
+	(0x3037, "IDEOGRAPHIC TELEGRAPH LINE FEED SEPARATOR SYMBOL"),
+	(0x3038, "HANGZHOU NUMERAL TEN"),
+	(0x3039, "HANGZHOU NUMERAL TWENTY"),
+	(0x303a, "HANGZHOU NUMERAL THIRTY"),
+	(0x303b, "VERTICAL IDEOGRAPHIC ITERATION MARK"),
+	(0x303c, "MASU MARK"),
+	(0x303d, "PART ALTERNATION MARK"),
+	(0x303e, "IDEOGRAPHIC VARIATION INDICATOR"),
+	(0x303f, "IDEOGRAPHIC HALF FILL SPACE"),
+	(0x3041, "HIRAGANA LETTER SMALL A"),
+	(0x3042, "HIRAGANA LETTER A"),
+	(0x3043, "HIRAGANA LETTER SMALL I"),
+	(0x3044, "HIRAGANA LETTER I"),
+	(0x3045, "HIRAGANA LETTER SMALL U"),
+	(0x3046, "HIRAGANA LETTER U"),
+	(0x3047, "HIRAGANA LETTER SMALL E"),
+	(0x3048, "HIRAGANA LETTER E"),
+	(0x3049, "HIRAGANA LETTER SMALL O"),
+	(0x304a, "HIRAGANA LETTER O"),
+	(0x304b, "HIRAGANA LETTER KA"),
+	(0x304c, "HIRAGANA LETTER GA"),
+	(0x304d, "HIRAGANA LETTER KI"),
+	(0x304e, "HIRAGANA LETTER GI"),
+	(0x304f, "HIRAGANA LETTER KU"),
+	(0x3050, "HIRAGANA LETTER GU"),
+	(0x3051, "HIRAGANA LETTER KE"),
+	(0x3052, "HIRAGANA LETTER GE"),
+	(0x3053, "HIRAGANA LETTER KO"),
+	(0x3054, "HIRAGANA LETTER GO"),
+	(0x3055, "HIRAGANA LETTER SA"),
+	(0x3056, "HIRAGANA LETTER ZA"),
+	(0x3057, "HIRAGANA LETTER SI"),
+	(0x3058, "HIRAGANA LETTER ZI"),
+	(0x3059, "HIRAGANA LETTER SU"),
+	(0x305a, "HIRAGANA LETTER ZU"),
+	(0x305b, "HIRAGANA LETTER SE"),
+	(0x305c, "HIRAGANA LETTER ZE"),
+	(0x305d, "HIRAGANA LETTER SO"),
+	(0x305e, "HIRAGANA LETTER ZO"),
+	(0x305f, "HIRAGANA LETTER TA"),
+	(0x3060, "HIRAGANA LETTER DA"),
+	(0x3061, "HIRAGANA LETTER TI"),
+	(0x3062, "HIRAGANA LETTER DI"),
+	(0x3063, "HIRAGANA LETTER SMALL TU"),
+	(0x3064, "HIRAGANA LETTER TU"),
+	(0x3065, "HIRAGANA LETTER DU"),
+	(0x3066, "HIRAGANA LETTER TE"),
+	(0x3067, "HIRAGANA LETTER DE"),
+	(0x3068, "HIRAGANA LETTER TO"),
+	(0x3069, "HIRAGANA LETTER DO"),
+	(0x306a, "HIRAGANA LETTER NA"),
+	(0x306b, "HIRAGANA LETTER NI"),
+	(0x306c, "HIRAGANA LETTER NU"),
+	(0x306d, "HIRAGANA LETTER NE"),
+	(0x306e, "HIRAGANA LETTER NO"),
+	(0x306f, "HIRAGANA LETTER HA"),
+	(0x3070, "HIRAGANA LETTER BA"),
+	(0x3071, "HIRAGANA LETTER PA"),
+	(0x3072, "HIRAGANA LETTER HI"),
+	(0x3073, "HIRAGANA LETTER BI"),
+	(0x3074, "HIRAGANA LETTER PI"),
+	(0x3075, "HIRAGANA LETTER HU"),
+	(0x3076, "HIRAGANA LETTER BU"),
+	(0x3077, "HIRAGANA LETTER PU"),
+	(0x3078, "HIRAGANA LETTER HE"),
+	(0x3079, "HIRAGANA LETTER BE"),
+	(0x307a, "HIRAGANA LETTER PE"),
+	(0x307b, "HIRAGANA LETTER HO"),
+	(0x307c, "HIRAGANA LETTER BO"),
+	(0x307d, "HIRAGANA LETTER PO"),
+	(0x307e, "HIRAGANA LETTER MA"),
+	(0x307f, "HIRAGANA LETTER MI"),
+	(0x3080, "HIRAGANA LETTER MU"),
+	(0x3081, "HIRAGANA LETTER ME"),
+	(0x3082, "HIRAGANA LETTER MO"),
+	(0x3083, "HIRAGANA LETTER SMALL YA"),
+	(0x3084, "HIRAGANA LETTER YA"),
+	(0x3085, "HIRAGANA LETTER SMALL YU"),
+	(0x3086, "HIRAGANA LETTER YU"),
+	(0x3087, "HIRAGANA LETTER SMALL YO"),
+	(0x3088, "HIRAGANA LETTER YO"),
+	(0x3089, "HIRAGANA LETTER RA"),
+	(0x308a, "HIRAGANA LETTER RI"),
+	(0x308b, "HIRAGANA LETTER RU"),
+	(0x308c, "HIRAGANA LETTER RE"),
+	(0x308d, "HIRAGANA LETTER RO"),
+	(0x308e, "HIRAGANA LETTER SMALL WA"),
+	(0x308f, "HIRAGANA LETTER WA"),
+	(0x3090, "HIRAGANA LETTER WI"),
+	(0x3091, "HIRAGANA LETTER WE"),
+	(0x3092, "HIRAGANA LETTER WO"),
+	(0x3093, "HIRAGANA LETTER N"),
+	(0x3094, "HIRAGANA LETTER VU"),
+	(0x3095, "HIRAGANA LETTER SMALL KA"),
+	(0x3096, "HIRAGANA LETTER SMALL KE"),
+	(0x3099, "COMBINING KATAKANA-HIRAGANA VOICED SOUND MARK"),
+	(0x309a, "COMBINING KATAKANA-HIRAGANA SEMI-VOICED SOUND MARK"),
+	(0x309b, "KATAKANA-HIRAGANA VOICED SOUND MARK"),
+	(0x309c, "KATAKANA-HIRAGANA SEMI-VOICED SOUND MARK"),
+	(0x309d, "HIRAGANA ITERATION MARK"),
+	(0x309e, "HIRAGANA VOICED ITERATION MARK"),
+	(0x309f, "HIRAGANA DIGRAPH YORI"),
+	(0x30a0, "KATAKANA-HIRAGANA DOUBLE HYPHEN"),
+	(0x30a1, "KATAKANA LETTER SMALL A"),
+	(0x30a2, "KATAKANA LETTER A"),
+	(0x30a3, "KATAKANA LETTER SMALL I"),
+	(0x30a4, "KATAKANA LETTER I"),
+	(0x30a5, "KATAKANA LETTER SMALL U"),
+	(0x30a6, "KATAKANA LETTER U"),
+	(0x30a7, "KATAKANA LETTER SMALL E"),
+	(0x30a8, "KATAKANA LETTER E"),
+	(0x30a9, "KATAKANA LETTER SMALL O"),
+	(0x30aa, "KATAKANA LETTER O"),
+	(0x30ab, "KATAKANA LETTER KA"),
+	(0x30ac, "KATAKANA LETTER GA"),
+	(0x30ad, "KATAKANA LETTER KI"),
+	(0x30ae, "KATAKANA LETTER GI"),
+	(0x30af, "KATAKANA LETTER KU"),
+	(0x30b0, "KATAKANA LETTER GU"),
+	(0x30b1, "KATAKANA LETTER KE"),
+	(0x30b2, "KATAKANA LETTER GE"),
+	(0x30b3, "KATAKANA LETTER KO"),
+	(0x30b4, "KATAKANA LETTER GO"),
+	(0x30b5, "KATAKANA LETTER SA"),
+	(0x30b6, "KATAKANA LETTER ZA"),
+	(0x30b7, "KATAKANA LETTER SI"),
+	(0x30b8, "KATAKANA LETTER ZI"),
+	(0x30b9, "KATAKANA LETTER SU"),
+	(0x30ba, "KATAKANA LETTER ZU"),
+	(0x30bb, "KATAKANA LETTER SE"),
+	(0x30bc, "KATAKANA LETTER ZE"),
+	(0x30bd, "KATAKANA LETTER SO"),
+	(0x30be, "KATAKANA LETTER ZO"),
+	(0x30bf, "KATAKANA LETTER TA"),
+	(0x30c0, "KATAKANA LETTER DA"),
+	(0x30c1, "KATAKANA LETTER TI"),
+	(0x30c2, "KATAKANA LETTER DI"),
+	(0x30c3, "KATAKANA LETTER SMALL TU"),
+	(0x30c4, "KATAKANA LETTER TU"),
+	(0x30c5, "KATAKANA LETTER DU"),
+	(0x30c6, "KATAKANA LETTER TE"),
+	(0x30c7, "KATAKANA LETTER DE"),
+	(0x30c8, "KATAKANA LETTER TO"),
+	(0x30c9, "KATAKANA LETTER DO"),
+	(0x30ca, "KATAKANA LETTER NA"),
+	(0x30cb, "KATAKANA LETTER NI"),
+	(0x30cc, "KATAKANA LETTER NU"),
+	(0x30cd, "KATAKANA LETTER NE"),
+	(0x30ce, "KATAKANA LETTER NO"),
+	(0x30cf, "KATAKANA LETTER HA"),
+	(0x30d0, "KATAKANA LETTER BA"),
+	(0x30d1, "KATAKANA LETTER PA"),
+	(0x30d2, "KATAKANA LETTER HI"),
+	(0x30d3, "KATAKANA LETTER BI"),
+	(0x30d4, "KATAKANA LETTER PI"),
+	(0x30d5, "KATAKANA LETTER HU"),
+	(0x30d6, "KATAKANA LETTER BU"),
+	(0x30d7, "KATAKANA LETTER PU"),
+	(0x30d8, "KATAKANA LETTER HE"),
+	(0x30d9, "KATAKANA LETTER BE"),
+	(0x30da, "KATAKANA LETTER PE"),
+	(0x30db, "KATAKANA LETTER HO"),
+	(0x30dc, "KATAKANA LETTER BO"),
+	(0x30dd, "KATAKANA LETTER PO"),
+	(0x30de, "KATAKANA LETTER MA"),
+	(0x30df, "KATAKANA LETTER MI"),
+	(0x30e0, "KATAKANA LETTER MU"),
+	(0x30e1, "KATAKANA LETTER ME"),
+	(0x30e2, "KATAKANA LETTER MO"),
+	(0x30e3, "KATAKANA LETTER SMALL YA"),
+	(0x30e4, "KATAKANA LETTER YA"),
+	(0x30e5, "KATAKANA LETTER SMALL YU"),
+	(0x30e6, "KATAKANA LETTER YU"),
+	(0x30e7, "KATAKANA LETTER SMALL YO"),
+	(0x30e8, "KATAKANA LETTER YO"),
+	(0x30e9, "KATAKANA LETTER RA"),
+	(0x30ea, "KATAKANA LETTER RI"),
+	(0x30eb, "KATAKANA LETTER RU"),
+	(0x30ec, "KATAKANA LETTER RE"),
+	(0x30ed, "KATAKANA LETTER RO"),
+	(0x30ee, "KATAKANA LETTER SMALL WA"),
+	(0x30ef, "KATAKANA LETTER WA"),
+	(0x30f0, "KATAKANA LETTER WI"),
+	(0x30f1, "KATAKANA LETTER WE"),
+	(0x30f2, "KATAKANA LETTER WO"),
+	(0x30f3, "KATAKANA LETTER N"),
+	(0x30f4, "KATAKANA LETTER VU"),
+	(0x30f5, "KATAKANA LETTER SMALL KA"),
+	(0x30f6, "KATAKANA LETTER SMALL KE"),
+	(0x30f7, "KATAKANA LETTER VA"),
+	(0x30f8, "KATAKANA LETTER VI"),
+	(0x30f9, "KATAKANA LETTER VE"),
+	(0x30fa, "KATAKANA LETTER VO"),
+	(0x30fb, "KATAKANA MIDDLE DOT"),
+	(0x30fc, "KATAKANA-HIRAGANA PROLONGED SOUND MARK"),
+	(0x30fd, "KATAKANA ITERATION MARK"),
+	(0x30fe, "KATAKANA VOICED ITERATION MARK"),
+	(0x30ff, "KATAKANA DIGRAPH KOTO"),
+	(0x1f300, "CYCLONE"),
+	(0x1f301, "FOGGY"),
+	(0x1f302, "CLOSED UMBRELLA"),
+	(0x1f303, "NIGHT WITH STARS"),
+	(0x1f304, "SUNRISE OVER MOUNTAINS"),
+	(0x1f305, "SUNRISE"),
+	(0x1f306, "CITYSCAPE AT DUSK"),
+	(0x1f307, "SUNSET OVER BUILDINGS"),
+	(0x1f308, "RAINBOW"),
+	(0x1f309, "BRIDGE AT NIGHT"),
+	(0x1f30a, "WATER WAVE"),
+	(0x1f30b, "VOLCANO"),
+	(0x1f30c, "MILKY WAY"),
+	(0x1f30d, "EARTH GLOBE EUROPE-AFRICA"),
+	(0x1f30e, "EARTH GLOBE AMERICAS"),
+	(0x1f30f, "EARTH GLOBE ASIA-AUSTRALIA"),
+	(0x1f310, "GLOBE WITH MERIDIANS"),
+	(0x1f311, "NEW MOON SYMBOL"),
+	(0x1f312, "WAXING CRESCENT MOON SYMBOL"),
+	(0x1f313, "FIRST QUARTER MOON SYMBOL"),
+	(0x1f314, "WAXING GIBBOUS MOON SYMBOL"),
+	(0x1f315, "FULL MOON SYMBOL"),
+	(0x1f316, "WANING GIBBOUS MOON SYMBOL"),
+	(0x1f317, "LAST QUARTER MOON SYMBOL"),
+	(0x1f318, "WANING CRESCENT MOON SYMBOL"),
+	(0x1f319, "CRESCENT MOON"),
+	(0x1f31a, "NEW MOON WITH FACE"),
+	(0x1f31b, "FIRST QUARTER MOON WITH FACE"),
+	(0x1f31c, "LAST QUARTER MOON WITH FACE"),
+	(0x1f31d, "FULL MOON WITH FACE"),
+	(0x1f31e, "SUN WITH FACE"),
+	(0x1f31f, "GLOWING STAR"),
+	(0x1f320, "SHOOTING STAR"),
+	(0x1f321, "THERMOMETER"),
+	(0x1f322, "BLACK DROPLET"),
+	(0x1f323, "WHITE SUN"),
+	(0x1f324, "WHITE SUN WITH SMALL CLOUD"),
+	(0x1f325, "WHITE SUN BEHIND CLOUD"),
+	(0x1f326, "WHITE SUN BEHIND CLOUD WITH RAIN"),
+	(0x1f327, "CLOUD WITH RAIN"),
+	(0x1f328, "CLOUD WITH SNOW"),
+	(0x1f329, "CLOUD WITH LIGHTNING"),
+	(0x1f32a, "CLOUD WITH TORNADO"),
+	(0x1f32b, "FOG"),
+	(0x1f32c, "WIND BLOWING FACE"),
+	(0x1f32d, "HOT DOG"),
+	(0x1f32e, "TACO"),
+	(0x1f32f, "BURRITO"),
+	(0x1f330, "CHESTNUT"),
+	(0x1f331, "SEEDLING"),
+	(0x1f332, "EVERGREEN TREE"),
+	(0x1f333, "DECIDUOUS TREE"),
+	(0x1f334, "PALM TREE"),
+	(0x1f335, "CACTUS"),
+	(0x1f336, "HOT PEPPER"),
+	(0x1f337, "TULIP"),
+	(0x1f338, "CHERRY BLOSSOM"),
+	(0x1f339, "ROSE"),
+	(0x1f33a, "HIBISCUS"),
+	(0x1f33b, "SUNFLOWER"),
+	(0x1f33c, "BLOSSOM"),
+	(0x1f33d, "EAR OF MAIZE"),
+	(0x1f33e, "EAR OF RICE"),
+	(0x1f33f, "HERB"),
+	(0x1f340, "FOUR LEAF CLOVER"),
+	(0x1f341, "MAPLE LEAF"),
+	(0x1f342, "FALLEN LEAF"),
+	(0x1f343, "LEAF FLUTTERING IN WIND"),
+	(0x1f344, "MUSHROOM"),
+	(0x1f345, "TOMATO"),
+	(0x1f346, "AUBERGINE"),
+	(0x1f347, "GRAPES"),
+	(0x1f348, "MELON"),
+	(0x1f349, "WATERMELON"),
+	(0x1f34a, "TANGERINE"),
+	(0x1f34b, "LEMON"),
+	(0x1f34c, "BANANA"),
+	(0x1f34d, "PINEAPPLE"),
+	(0x1f34e, "RED APPLE"),
+	(0x1f34f, "GREEN APPLE"),
+	(0x1f350, "PEAR"),
+	(0x1f351, "PEACH"),
+	(0x1f352, "CHERRIES"),
+	(0x1f353, "STRAWBERRY"),
+	(0x1f354, "HAMBURGER"),
+	(0x1f355, "SLICE OF PIZZA"),
+	(0x1f356, "MEAT ON BONE"),
+	(0x1f357, "POULTRY LEG"),
+	(0x1f358, "RICE CRACKER"),
+	(0x1f359, "RICE BALL"),
+	(0x1f35a, "COOKED RICE"),
+	(0x1f35b, "CURRY AND RICE"),
+	(0x1f35c, "STEAMING BOWL"),
+	(0x1f35d, "SPAGHETTI"),
+	(0x1f35e, "BREAD"),
+	(0x1f35f, "FRENCH FRIES"),
+	(0x1f360, "ROASTED SWEET POTATO"),
+	(0x1f361, "DANGO"),
+	(0x1f362, "ODEN"),
+	(0x1f363, "SUSHI"),
+	(0x1f364, "FRIED SHRIMP"),
+	(0x1f365, "FISH CAKE WITH SWIRL DESIGN"),
+	(0x1f366, "SOFT ICE CREAM"),
+	(0x1f367, "SHAVED ICE"),
+	(0x1f368, "ICE CREAM"),
+	(0x1f369, "DOUGHNUT"),
+	(0x1f36a, "COOKIE"),
+	(0x1f36b, "CHOCOLATE BAR"),
+	(0x1f36c, "CANDY"),
+	(0x1f36d, "LOLLIPOP"),
+	(0x1f36e, "CUSTARD"),
+	(0x1f36f, "HONEY POT"),
+	(0x1f370, "SHORTCAKE"),
+	(0x1f371, "BENTO BOX"),
+	(0x1f372, "POT OF FOOD"),
+	(0x1f373, "COOKING"),
+	(0x1f374, "FORK AND KNIFE"),
+	(0x1f375, "TEACUP WITHOUT HANDLE"),
+	(0x1f376, "SAKE BOTTLE AND CUP"),
+	(0x1f377, "WINE GLASS"),
+	(0x1f378, "COCKTAIL GLASS"),
+	(0x1f379, "TROPICAL DRINK"),
+	(0x1f37a, "BEER MUG"),
+	(0x1f37b, "CLINKING BEER MUGS"),
+	(0x1f37c, "BABY BOTTLE"),
+	(0x1f37d, "FORK AND KNIFE WITH PLATE"),
+	(0x1f37e, "BOTTLE WITH POPPING CORK"),
+	(0x1f37f, "POPCORN"),
+	(0x1f380, "RIBBON"),
+	(0x1f381, "WRAPPED PRESENT"),
+	(0x1f382, "BIRTHDAY CAKE"),
+	(0x1f383, "JACK-O-LANTERN"),
+	(0x1f384, "CHRISTMAS TREE"),
+	(0x1f385, "FATHER CHRISTMAS"),
+	(0x1f386, "FIREWORKS"),
+	(0x1f387, "FIREWORK SPARKLER"),
+	(0x1f388, "BALLOON"),
+	(0x1f389, "PARTY POPPER"),
+	(0x1f38a, "CONFETTI BALL"),
+	(0x1f38b, "TANABATA TREE"),
+	(0x1f38c, "CROSSED FLAGS"),
+	(0x1f38d, "PINE DECORATION"),
+	(0x1f38e, "JAPANESE DOLLS"),
+	(0x1f38f, "CARP STREAMER"),
+	(0x1f390, "WIND CHIME"),
+	(0x1f391, "MOON VIEWING CEREMONY"),
+	(0x1f392, "SCHOOL SATCHEL"),
+	(0x1f393, "GRADUATION CAP"),
+	(0x1f394, "HEART WITH TIP ON THE LEFT"),
+	(0x1f395, "BOUQUET OF FLOWERS"),
+	(0x1f396, "MILITARY MEDAL"),
+	(0x1f397, "REMINDER RIBBON"),
+	(0x1f398, "MUSICAL KEYBOARD WITH JACKS"),
+	(0x1f399, "STUDIO MICROPHONE"),
+	(0x1f39a, "LEVEL SLIDER"),
+	(0x1f39b, "CONTROL KNOBS"),
+	(0x1f39c, "BEAMED ASCENDING MUSICAL NOTES"),
+	(0x1f39d, "BEAMED DESCENDING MUSICAL NOTES"),
+	(0x1f39e, "FILM FRAMES"),
+	(0x1f39f, "ADMISSION TICKETS"),
+	(0x1f3a0, "CAROUSEL HORSE"),
+	(0x1f3a1, "FERRIS WHEEL"),
+	(0x1f3a2, "ROLLER COASTER"),
+	(0x1f3a3, "FISHING POLE AND FISH"),
+	(0x1f3a4, "MICROPHONE"),
+	(0x1f3a5, "MOVIE CAMERA"),
+	(0x1f3a6, "CINEMA"),
+	(0x1f3a7, "HEADPHONE"),
+	(0x1f3a8, "ARTIST PALETTE"),
+	(0x1f3a9, "TOP HAT"),
+	(0x1f3aa, "CIRCUS TENT"),
+	(0x1f3ab, "TICKET"),
+	(0x1f3ac, "CLAPPER BOARD"),
+	(0x1f3ad, "PERFORMING ARTS"),
+	(0x1f3ae, "VIDEO GAME"),
+	(0x1f3af, "DIRECT HIT"),
+	(0x1f3b0, "SLOT MACHINE"),
+	(0x1f3b1, "BILLIARDS"),
+	(0x1f3b2, "GAME DIE"),
+	(0x1f3b3, "BOWLING"),
+	(0x1f3b4, "FLOWER PLAYING CARDS"),
+	(0x1f3b5, "MUSICAL NOTE"),
+	(0x1f3b6, "MULTIPLE MUSICAL NOTES"),
+	(0x1f3b7, "SAXOPHONE"),
+	(0x1f3b8, "GUITAR"),
+	(0x1f3b9, "MUSICAL KEYBOARD"),
+	(0x1f3ba, "TRUMPET"),
+	(0x1f3bb, "VIOLIN"),
+	(0x1f3bc, "MUSICAL SCORE"),
+	(0x1f3bd, "RUNNING SHIRT WITH SASH"),
+	(0x1f3be, "TENNIS RACQUET AND BALL"),
+	(0x1f3bf, "SKI AND SKI BOOT"),
+	(0x1f3c0, "BASKETBALL AND HOOP"),
+	(0x1f3c1, "CHEQUERED FLAG"),
+	(0x1f3c2, "SNOWBOARDER"),
+	(0x1f3c3, "RUNNER"),
+	(0x1f3c4, "SURFER"),
+	(0x1f3c5, "SPORTS MEDAL"),
+	(0x1f3c6, "TROPHY"),
+	(0x1f3c7, "HORSE RACING"),
+	(0x1f3c8, "AMERICAN FOOTBALL"),
+	(0x1f3c9, "RUGBY FOOTBALL"),
+	(0x1f3ca, "SWIMMER"),
+	(0x1f3cb, "WEIGHT LIFTER"),
+	(0x1f3cc, "GOLFER"),
+	(0x1f3cd, "RACING MOTORCYCLE"),
+	(0x1f3ce, "RACING CAR"),
+	(0x1f3cf, "CRICKET BAT AND BALL"),
+	(0x1f3d0, "VOLLEYBALL"),
+	(0x1f3d1, "FIELD HOCKEY STICK AND BALL"),
+	(0x1f3d2, "ICE HOCKEY STICK AND PUCK"),
+	(0x1f3d3, "TABLE TENNIS PADDLE AND BALL"),
+	(0x1f3d4, "SNOW CAPPED MOUNTAIN"),
+	(0x1f3d5, "CAMPING"),
+	(0x1f3d6, "BEACH WITH UMBRELLA"),
+	(0x1f3d7, "BUILDING CONSTRUCTION"),
+	(0x1f3d8, "HOUSE BUILDINGS"),
+	(0x1f3d9, "CITYSCAPE"),
+	(0x1f3da, "DERELICT HOUSE BUILDING"),
+	(0x1f3db, "CLASSICAL BUILDING"),
+	(0x1f3dc, "DESERT"),
+	(0x1f3dd, "DESERT ISLAND"),
+	(0x1f3de, "NATIONAL PARK"),
+	(0x1f3df, "STADIUM"),
+	(0x1f3e0, "HOUSE BUILDING"),
+	(0x1f3e1, "HOUSE WITH GARDEN"),
+	(0x1f3e2, "OFFICE BUILDING"),
+	(0x1f3e3, "JAPANESE POST OFFICE"),
+	(0x1f3e4, "EUROPEAN POST OFFICE"),
+	(0x1f3e5, "HOSPITAL"),
+	(0x1f3e6, "BANK"),
+	(0x1f3e7, "AUTOMATED TELLER MACHINE"),
+	(0x1f3e8, "HOTEL"),
+	(0x1f3e9, "LOVE HOTEL"),
+	(0x1f3ea, "CONVENIENCE STORE"),
+	(0x1f3eb, "SCHOOL"),
+	(0x1f3ec, "DEPARTMENT STORE"),
+	(0x1f3ed, "FACTORY"),
+	(0x1f3ee, "IZAKAYA LANTERN"),
+	(0x1f3ef, "JAPANESE CASTLE"),
+	(0x1f3f0, "EUROPEAN CASTLE"),
+	(0x1f3f1, "WHITE PENNANT"),
+	(0x1f3f2, "BLACK PENNANT"),
+	(0x1f3f3, "WAVING WHITE FLAG"),
+	(0x1f3f4, "WAVING BLACK FLAG"),
+	(0x1f3f5, "ROSETTE"),
+	(0x1f3f6, "BLACK ROSETTE"),
+	(0x1f3f7, "LABEL"),
+	(0x1f3f8, "BADMINTON RACQUET AND SHUTTLECOCK"),
+	(0x1f3f9, "BOW AND ARROW"),
+	(0x1f3fa, "AMPHORA"),
+	(0x1f3fb, "EMOJI MODIFIER FITZPATRICK TYPE-1-2"),
+	(0x1f3fc, "EMOJI MODIFIER FITZPATRICK TYPE-3"),
+	(0x1f3fd, "EMOJI MODIFIER FITZPATRICK TYPE-4"),
+	(0x1f3fe, "EMOJI MODIFIER FITZPATRICK TYPE-5"),
+	(0x1f3ff, "EMOJI MODIFIER FITZPATRICK TYPE-6"),
+	(0x1f400, "RAT"),
+	(0x1f401, "MOUSE"),
+	(0x1f402, "OX"),
+	(0x1f403, "WATER BUFFALO"),
+	(0x1f404, "COW"),
+	(0x1f405, "TIGER"),
+	(0x1f406, "LEOPARD"),
+	(0x1f407, "RABBIT"),
+	(0x1f408, "CAT"),
+	(0x1f409, "DRAGON"),
+	(0x1f40a, "CROCODILE"),
+	(0x1f40b, "WHALE"),
+	(0x1f40c, "SNAIL"),
+	(0x1f40d, "SNAKE"),
+	(0x1f40e, "HORSE"),
+	(0x1f40f, "RAM"),
+	(0x1f410, "GOAT"),
+	(0x1f411, "SHEEP"),
+	(0x1f412, "MONKEY"),
+	(0x1f413, "ROOSTER"),
+	(0x1f414, "CHICKEN"),
+	(0x1f415, "DOG"),
+	(0x1f416, "PIG"),
+	(0x1f417, "BOAR"),
+	(0x1f418, "ELEPHANT"),
+	(0x1f419, "OCTOPUS"),
+	(0x1f41a, "SPIRAL SHELL"),
+	(0x1f41b, "BUG"),
+	(0x1f41c, "ANT"),
+	(0x1f41d, "HONEYBEE"),
+	(0x1f41e, "LADY BEETLE"),
+	(0x1f41f, "FISH"),
+	(0x1f420, "TROPICAL FISH"),
+	(0x1f421, "BLOWFISH"),
+	(0x1f422, "TURTLE"),
+	(0x1f423, "HATCHING CHICK"),
+	(0x1f424, "BABY CHICK"),
+	(0x1f425, "FRONT-FACING BABY CHICK"),
+	(0x1f426, "BIRD"),
+	(0x1f427, "PENGUIN"),
+	(0x1f428, "KOALA"),
+	(0x1f429, "POODLE"),
+	(0x1f42a, "DROMEDARY CAMEL"),
+	(0x1f42b, "BACTRIAN CAMEL"),
+	(0x1f42c, "DOLPHIN"),
+	(0x1f42d, "MOUSE FACE"),
+	(0x1f42e, "COW FACE"),
+	(0x1f42f, "TIGER FACE"),
+	(0x1f430, "RABBIT FACE"),
+	(0x1f431, "CAT FACE"),
+	(0x1f432, "DRAGON FACE"),
+	(0x1f433, "SPOUTING WHALE"),
+	(0x1f434, "HORSE FACE"),
+	(0x1f435, "MONKEY FACE"),
+	(0x1f436, "DOG FACE"),
+	(0x1f437, "PIG FACE"),
+	(0x1f438, "FROG FACE"),
+	(0x1f439, "HAMSTER FACE"),
+	(0x1f43a, "WOLF FACE"),
+	(0x1f43b, "BEAR FACE"),
+	(0x1f43c, "PANDA FACE"),
+	(0x1f43d, "PIG NOSE"),
+	(0x1f43e, "PAW PRINTS"),
+	(0x1f43f, "CHIPMUNK"),
+	(0x1f440, "EYES"),
+	(0x1f441, "EYE"),
+	(0x1f442, "EAR"),
+	(0x1f443, "NOSE"),
+	(0x1f444, "MOUTH"),
+	(0x1f445, "TONGUE"),
+	(0x1f446, "WHITE UP POINTING BACKHAND INDEX"),
+	(0x1f447, "WHITE DOWN POINTING BACKHAND INDEX"),
+	(0x1f448, "WHITE LEFT POINTING BACKHAND INDEX"),
+	(0x1f449, "WHITE RIGHT POINTING BACKHAND INDEX"),
+	(0x1f44a, "FISTED HAND SIGN"),
+	(0x1f44b, "WAVING HAND SIGN"),
+	(0x1f44c, "OK HAND SIGN"),
+	(0x1f44d, "THUMBS UP SIGN"),
+	(0x1f44e, "THUMBS DOWN SIGN"),
+	(0x1f44f, "CLAPPING HANDS SIGN"),
+	(0x1f450, "OPEN HANDS SIGN"),
+	(0x1f451, "CROWN"),
+	(0x1f452, "WOMANS HAT"),
+	(0x1f453, "EYEGLASSES"),
+	(0x1f454, "NECKTIE"),
+	(0x1f455, "T-SHIRT"),
+	(0x1f456, "JEANS"),
+	(0x1f457, "DRESS"),
+	(0x1f458, "KIMONO"),
+	(0x1f459, "BIKINI"),
+	(0x1f45a, "WOMANS CLOTHES"),
+	(0x1f45b, "PURSE"),
+	(0x1f45c, "HANDBAG"),
+	(0x1f45d, "POUCH"),
+	(0x1f45e, "MANS SHOE"),
+	(0x1f45f, "ATHLETIC SHOE"),
+	(0x1f460, "HIGH-HEELED SHOE"),
+	(0x1f461, "WOMANS SANDAL"),
+	(0x1f462, "WOMANS BOOTS"),
+	(0x1f463, "FOOTPRINTS"),
+	(0x1f464, "BUST IN SILHOUETTE"),
+	(0x1f465, "BUSTS IN SILHOUETTE"),
+	(0x1f466, "BOY"),
+	(0x1f467, "GIRL"),
+	(0x1f468, "MAN"),
+	(0x1f469, "WOMAN"),
+	(0x1f46a, "FAMILY"),
+	(0x1f46b, "MAN AND WOMAN HOLDING HANDS"),
+	(0x1f46c, "TWO MEN HOLDING HANDS"),
+	(0x1f46d, "TWO WOMEN HOLDING HANDS"),
+	(0x1f46e, "POLICE OFFICER"),
+	(0x1f46f, "WOMAN WITH BUNNY EARS"),
+	(0x1f470, "BRIDE WITH VEIL"),
+	(0x1f471, "PERSON WITH BLOND HAIR"),
+	(0x1f472, "MAN WITH GUA PI MAO"),
+	(0x1f473, "MAN WITH TURBAN"),
+	(0x1f474, "OLDER MAN"),
+	(0x1f475, "OLDER WOMAN"),
+	(0x1f476, "BABY"),
+	(0x1f477, "CONSTRUCTION WORKER"),
+	(0x1f478, "PRINCESS"),
+	(0x1f479, "JAPANESE OGRE"),
+	(0x1f47a, "JAPANESE GOBLIN"),
+	(0x1f47b, "GHOST"),
+	(0x1f47c, "BABY ANGEL"),
+	(0x1f47d, "EXTRATERRESTRIAL ALIEN"),
+	(0x1f47e, "ALIEN MONSTER"),
+	(0x1f47f, "IMP"),
+	(0x1f480, "SKULL"),
+	(0x1f481, "INFORMATION DESK PERSON"),
+	(0x1f482, "GUARDSMAN"),
+	(0x1f483, "DANCER"),
+	(0x1f484, "LIPSTICK"),
+	(0x1f485, "NAIL POLISH"),
+	(0x1f486, "FACE MASSAGE"),
+	(0x1f487, "HAIRCUT"),
+	(0x1f488, "BARBER POLE"),
+	(0x1f489, "SYRINGE"),
+	(0x1f48a, "PILL"),
+	(0x1f48b, "KISS MARK"),
+	(0x1f48c, "LOVE LETTER"),
+	(0x1f48d, "RING"),
+	(0x1f48e, "GEM STONE"),
+	(0x1f48f, "KISS"),
+	(0x1f490, "BOUQUET"),
+	(0x1f491, "COUPLE WITH HEART"),
+	(0x1f492, "WEDDING"),
+	(0x1f493, "BEATING HEART"),
+	(0x1f494, "BROKEN HEART"),
+	(0x1f495, "TWO HEARTS"),
+	(0x1f496, "SPARKLING HEART"),
+	(0x1f497, "GROWING HEART"),
+	(0x1f498, "HEART WITH ARROW"),
+	(0x1f499, "BLUE HEART"),
+	(0x1f49a, "GREEN HEART"),
+	(0x1f49b, "YELLOW HEART"),
+	(0x1f49c, "PURPLE HEART"),
+	(0x1f49d, "HEART WITH RIBBON"),
+	(0x1f49e, "REVOLVING HEARTS"),
+	(0x1f49f, "HEART DECORATION"),
+	(0x1f4a0, "DIAMOND SHAPE WITH A DOT INSIDE"),
+	(0x1f4a1, "ELECTRIC LIGHT BULB"),
+	(0x1f4a2, "ANGER SYMBOL"),
+	(0x1f4a3, "BOMB"),
+	(0x1f4a4, "SLEEPING SYMBOL"),
+	(0x1f4a5, "COLLISION SYMBOL"),
+	(0x1f4a6, "SPLASHING SWEAT SYMBOL"),
+	(0x1f4a7, "DROPLET"),
+	(0x1f4a8, "DASH SYMBOL"),
+	(0x1f4a9, "PILE OF POO"),
+	(0x1f4aa, "FLEXED BICEPS"),
+	(0x1f4ab, "DIZZY SYMBOL"),
+	(0x1f4ac, "SPEECH BALLOON"),
+	(0x1f4ad, "THOUGHT BALLOON"),
+	(0x1f4ae, "WHITE FLOWER"),
+	(0x1f4af, "HUNDRED POINTS SYMBOL"),
+	(0x1f4b0, "MONEY BAG"),
+	(0x1f4b1, "CURRENCY EXCHANGE"),
+	(0x1f4b2, "HEAVY DOLLAR SIGN"),
+	(0x1f4b3, "CREDIT CARD"),
+	(0x1f4b4, "BANKNOTE WITH YEN SIGN"),
+	(0x1f4b5, "BANKNOTE WITH DOLLAR SIGN"),
+	(0x1f4b6, "BANKNOTE WITH EURO SIGN"),
+	(0x1f4b7, "BANKNOTE WITH POUND SIGN"),
+	(0x1f4b8, "MONEY WITH WINGS"),
+	(0x1f4b9, "CHART WITH UPWARDS TREND AND YEN SIGN"),
+	(0x1f4ba, "SEAT"),
+	(0x1f4bb, "PERSONAL COMPUTER"),
+	(0x1f4bc, "BRIEFCASE"),
+	(0x1f4bd, "MINIDISC"),
+	(0x1f4be, "FLOPPY DISK"),
+	(0x1f4bf, "OPTICAL DISC"),
+	(0x1f4c0, "DVD"),
+	(0x1f4c1, "FILE FOLDER"),
+	(0x1f4c2, "OPEN FILE FOLDER"),
+	(0x1f4c3, "PAGE WITH CURL"),
+	(0x1f4c4, "PAGE FACING UP"),
+	(0x1f4c5, "CALENDAR"),
+	(0x1f4c6, "TEAR-OFF CALENDAR"),
+	(0x1f4c7, "CARD INDEX"),
+	(0x1f4c8, "CHART WITH UPWARDS TREND"),
+	(0x1f4c9, "CHART WITH DOWNWARDS TREND"),
+	(0x1f4ca, "BAR CHART"),
+	(0x1f4cb, "CLIPBOARD"),
+	(0x1f4cc, "PUSHPIN"),
+	(0x1f4cd, "ROUND PUSHPIN"),
+	(0x1f4ce, "PAPERCLIP"),
+	(0x1f4cf, "STRAIGHT RULER"),
+	(0x1f4d0, "TRIANGULAR RULER"),
+	(0x1f4d1, "BOOKMARK TABS"),
+	(0x1f4d2, "LEDGER"),
+	(0x1f4d3, "NOTEBOOK"),
+	(0x1f4d4, "NOTEBOOK WITH DECORATIVE COVER"),
+	(0x1f4d5, "CLOSED BOOK"),
+	(0x1f4d6, "OPEN BOOK"),
+	(0x1f4d7, "GREEN BOOK"),
+	(0x1f4d8, "BLUE BOOK"),
+	(0x1f4d9, "ORANGE BOOK"),
+	(0x1f4da, "BOOKS"),
+	(0x1f4db, "NAME BADGE"),
+	(0x1f4dc, "SCROLL"),
+	(0x1f4dd, "MEMO"),
+	(0x1f4de, "TELEPHONE RECEIVER"),
+	(0x1f4df, "PAGER"),
+	(0x1f4e0, "FAX MACHINE"),
+	(0x1f4e1, "SATELLITE ANTENNA"),
+	(0x1f4e2, "PUBLIC ADDRESS LOUDSPEAKER"),
+	(0x1f4e3, "CHEERING MEGAPHONE"),
+	(0x1f4e4, "OUTBOX TRAY"),
+	(0x1f4e5, "INBOX TRAY"),
+	(0x1f4e6, "PACKAGE"),
+	(0x1f4e7, "E-MAIL SYMBOL"),
+	(0x1f4e8, "INCOMING ENVELOPE"),
+	(0x1f4e9, "ENVELOPE WITH DOWNWARDS ARROW ABOVE"),
+	(0x1f4ea, "CLOSED MAILBOX WITH LOWERED FLAG"),
+	(0x1f4eb, "CLOSED MAILBOX WITH RAISED FLAG"),
+	(0x1f4ec, "OPEN MAILBOX WITH RAISED FLAG"),
+	(0x1f4ed, "OPEN MAILBOX WITH LOWERED FLAG"),
+	(0x1f4ee, "POSTBOX"),
+	(0x1f4ef, "POSTAL HORN"),
+	(0x1f4f0, "NEWSPAPER"),
+	(0x1f4f1, "MOBILE PHONE"),
+	(0x1f4f2, "MOBILE PHONE WITH RIGHTWARDS ARROW AT LEFT"),
+	(0x1f4f3, "VIBRATION MODE"),
+	(0x1f4f4, "MOBILE PHONE OFF"),
+	(0x1f4f5, "NO MOBILE PHONES"),
+	(0x1f4f6, "ANTENNA WITH BARS"),
+	(0x1f4f7, "CAMERA"),
+	(0x1f4f8, "CAMERA WITH FLASH"),
+	(0x1f4f9, "VIDEO CAMERA"),
+	(0x1f4fa, "TELEVISION"),
+	(0x1f4fb, "RADIO"),
+	(0x1f4fc, "VIDEOCASSETTE"),
+	(0x1f4fd, "FILM PROJECTOR"),
+	(0x1f4fe, "PORTABLE STEREO"),
+	(0x1f4ff, "PRAYER BEADS"),
+	(0x1f500, "TWISTED RIGHTWARDS ARROWS"),
+	(0x1f501, "CLOCKWISE RIGHTWARDS AND LEFTWARDS OPEN CIRCLE ARROWS"),
+	(0x1f502, "CLOCKWISE RIGHTWARDS AND LEFTWARDS OPEN CIRCLE ARROWS WITH CIRCLED ONE OVERLAY"),
+	(0x1f503, "CLOCKWISE DOWNWARDS AND UPWARDS OPEN CIRCLE ARROWS"),
+	(0x1f504, "ANTICLOCKWISE DOWNWARDS AND UPWARDS OPEN CIRCLE ARROWS"),
+	(0x1f505, "LOW BRIGHTNESS SYMBOL"),
+	(0x1f506, "HIGH BRIGHTNESS SYMBOL"),
+	(0x1f507, "SPEAKER WITH CANCELLATION STROKE"),
+	(0x1f508, "SPEAKER"),
+	(0x1f509, "SPEAKER WITH ONE SOUND WAVE"),
+	(0x1f50a, "SPEAKER WITH THREE SOUND WAVES"),
+	(0x1f50b, "BATTERY"),
+	(0x1f50c, "ELECTRIC PLUG"),
+	(0x1f50d, "LEFT-POINTING MAGNIFYING GLASS"),
+	(0x1f50e, "RIGHT-POINTING MAGNIFYING GLASS"),
+	(0x1f50f, "LOCK WITH INK PEN"),
+	(0x1f510, "CLOSED LOCK WITH KEY"),
+	(0x1f511, "KEY"),
+	(0x1f512, "LOCK"),
+	(0x1f513, "OPEN LOCK"),
+	(0x1f514, "BELL"),
+	(0x1f515, "BELL WITH CANCELLATION STROKE"),
+	(0x1f516, "BOOKMARK"),
+	(0x1f517, "LINK SYMBOL"),
+	(0x1f518, "RADIO BUTTON"),
+	(0x1f519, "BACK WITH LEFTWARDS ARROW ABOVE"),
+	(0x1f51a, "END WITH LEFTWARDS ARROW ABOVE"),
+	(0x1f51b, "ON WITH EXCLAMATION MARK WITH LEFT RIGHT ARROW ABOVE"),
+	(0x1f51c, "SOON WITH RIGHTWARDS ARROW ABOVE"),
+	(0x1f51d, "TOP WITH UPWARDS ARROW ABOVE"),
+	(0x1f51e, "NO ONE UNDER EIGHTEEN SYMBOL"),
+	(0x1f51f, "KEYCAP TEN"),
+	(0x1f520, "INPUT SYMBOL FOR LATIN CAPITAL LETTERS"),
+	(0x1f521, "INPUT SYMBOL FOR LATIN SMALL LETTERS"),
+	(0x1f522, "INPUT SYMBOL FOR NUMBERS"),
+	(0x1f523, "INPUT SYMBOL FOR SYMBOLS"),
+	(0x1f524, "INPUT SYMBOL FOR LATIN LETTERS"),
+	(0x1f525, "FIRE"),
+	(0x1f526, "ELECTRIC TORCH"),
+	(0x1f527, "WRENCH"),
+	(0x1f528, "HAMMER"),
+	(0x1f529, "NUT AND BOLT"),
+	(0x1f52a, "HOCHO"),
+	(0x1f52b, "PISTOL"),
+	(0x1f52c, "MICROSCOPE"),
+	(0x1f52d, "TELESCOPE"),
+	(0x1f52e, "CRYSTAL BALL"),
+	(0x1f52f, "SIX POINTED STAR WITH MIDDLE DOT"),
+	(0x1f530, "JAPANESE SYMBOL FOR BEGINNER"),
+	(0x1f531, "TRIDENT EMBLEM"),
+	(0x1f532, "BLACK SQUARE BUTTON"),
+	(0x1f533, "WHITE SQUARE BUTTON"),
+	(0x1f534, "LARGE RED CIRCLE"),
+	(0x1f535, "LARGE BLUE CIRCLE"),
+	(0x1f536, "LARGE ORANGE DIAMOND"),
+	(0x1f537, "LARGE BLUE DIAMOND"),
+	(0x1f538, "SMALL ORANGE DIAMOND"),
+	(0x1f539, "SMALL BLUE DIAMOND"),
+	(0x1f53a, "UP-POINTING RED TRIANGLE"),
+	(0x1f53b, "DOWN-POINTING RED TRIANGLE"),
+	(0x1f53c, "UP-POINTING SMALL RED TRIANGLE"),
+	(0x1f53d, "DOWN-POINTING SMALL RED TRIANGLE"),
+	(0x1f53e, "LOWER RIGHT SHADOWED WHITE CIRCLE"),
+	(0x1f53f, "UPPER RIGHT SHADOWED WHITE CIRCLE"),
+	(0x1f540, "CIRCLED CROSS POMMEE"),
+	(0x1f541, "CROSS POMMEE WITH HALF-CIRCLE BELOW"),
+	(0x1f542, "CROSS POMMEE"),
+	(0x1f543, "NOTCHED LEFT SEMICIRCLE WITH THREE DOTS"),
+	(0x1f544, "NOTCHED RIGHT SEMICIRCLE WITH THREE DOTS"),
+	(0x1f545, "SYMBOL FOR MARKS CHAPTER"),
+	(0x1f546, "WHITE LATIN CROSS"),
+	(0x1f547, "HEAVY LATIN CROSS"),
+	(0x1f548, "CELTIC CROSS"),
+	(0x1f549, "OM SYMBOL"),
+	(0x1f54a, "DOVE OF PEACE"),
+	(0x1f54b, "KAABA"),
+	(0x1f54c, "MOSQUE"),
+	(0x1f54d, "SYNAGOGUE"),
+	(0x1f54e, "MENORAH WITH NINE BRANCHES"),
+	(0x1f54f, "BOWL OF HYGIEIA"),
+	(0x1f550, "CLOCK FACE ONE OCLOCK"),
+	(0x1f551, "CLOCK FACE TWO OCLOCK"),
+	(0x1f552, "CLOCK FACE THREE OCLOCK"),
+	(0x1f553, "CLOCK FACE FOUR OCLOCK"),
+	(0x1f554, "CLOCK FACE FIVE OCLOCK"),
+	(0x1f555, "CLOCK FACE SIX OCLOCK"),
+	(0x1f556, "CLOCK FACE SEVEN OCLOCK"),
+	(0x1f557, "CLOCK FACE EIGHT OCLOCK"),
+	(0x1f558, "CLOCK FACE NINE OCLOCK"),
+	(0x1f559, "CLOCK FACE TEN OCLOCK"),
+	(0x1f55a, "CLOCK FACE ELEVEN OCLOCK"),
+	(0x1f55b, "CLOCK FACE TWELVE OCLOCK"),
+	(0x1f55c, "CLOCK FACE ONE-THIRTY"),
+	(0x1f55d, "CLOCK FACE TWO-THIRTY"),
+	(0x1f55e, "CLOCK FACE THREE-THIRTY"),
+	(0x1f55f, "CLOCK FACE FOUR-THIRTY"),
+	(0x1f560, "CLOCK FACE FIVE-THIRTY"),
+	(0x1f561, "CLOCK FACE SIX-THIRTY"),
+	(0x1f562, "CLOCK FACE SEVEN-THIRTY"),
+	(0x1f563, "CLOCK FACE EIGHT-THIRTY"),
+	(0x1f564, "CLOCK FACE NINE-THIRTY"),
+	(0x1f565, "CLOCK FACE TEN-THIRTY"),
+	(0x1f566, "CLOCK FACE ELEVEN-THIRTY"),
+	(0x1f567, "CLOCK FACE TWELVE-THIRTY"),
+	(0x1f568, "RIGHT SPEAKER"),
+	(0x1f569, "RIGHT SPEAKER WITH ONE SOUND WAVE"),
+	(0x1f56a, "RIGHT SPEAKER WITH THREE SOUND WAVES"),
+	(0x1f56b, "BULLHORN"),
+	(0x1f56c, "BULLHORN WITH SOUND WAVES"),
+	(0x1f56d, "RINGING BELL"),
+	(0x1f56e, "BOOK"),
+	(0x1f56f, "CANDLE"),
+	(0x1f570, "MANTELPIECE CLOCK"),
+	(0x1f571, "BLACK SKULL AND CROSSBONES"),
+	(0x1f572, "NO PIRACY"),
+	(0x1f573, "HOLE"),
+	(0x1f574, "MAN IN BUSINESS SUIT LEVITATING"),
+	(0x1f575, "SLEUTH OR SPY"),
+	(0x1f576, "DARK SUNGLASSES"),
+	(0x1f577, "SPIDER"),
+	(0x1f578, "SPIDER WEB"),
+	(0x1f579, "JOYSTICK"),
+	(0x1f57a, "MAN DANCING"),
+	(0x1f57b, "LEFT HAND TELEPHONE RECEIVER"),
+	(0x1f57c, "TELEPHONE RECEIVER WITH PAGE"),
+	(0x1f57d, "RIGHT HAND TELEPHONE RECEIVER"),
+	(0x1f57e, "WHITE TOUCHTONE TELEPHONE"),
+	(0x1f57f, "BLACK TOUCHTONE TELEPHONE"),
+	(0x1f580, "TELEPHONE ON TOP OF MODEM"),
+	(0x1f581, "CLAMSHELL MOBILE PHONE"),
+	(0x1f582, "BACK OF ENVELOPE"),
+	(0x1f583, "STAMPED ENVELOPE"),
+	(0x1f584, "ENVELOPE WITH LIGHTNING"),
+	(0x1f585, "FLYING ENVELOPE"),
+	(0x1f586, "PEN OVER STAMPED ENVELOPE"),
+	(0x1f587, "LINKED PAPERCLIPS"),
+	(0x1f588, "BLACK PUSHPIN"),
+	(0x1f589, "LOWER LEFT PENCIL"),
+	(0x1f58a, "LOWER LEFT BALLPOINT PEN"),
+	(0x1f58b, "LOWER LEFT FOUNTAIN PEN"),
+	(0x1f58c, "LOWER LEFT PAINTBRUSH"),
+	(0x1f58d, "LOWER LEFT CRAYON"),
+	(0x1f58e, "LEFT WRITING HAND"),
+	(0x1f58f, "TURNED OK HAND SIGN"),
+	(0x1f590, "RAISED HAND WITH FINGERS SPLAYED"),
+	(0x1f591, "REVERSED RAISED HAND WITH FINGERS SPLAYED"),
+	(0x1f592, "REVERSED THUMBS UP SIGN"),
+	(0x1f593, "REVERSED THUMBS DOWN SIGN"),
+	(0x1f594, "REVERSED VICTORY HAND"),
+	(0x1f595, "REVERSED HAND WITH MIDDLE FINGER EXTENDED"),
+	(0x1f596, "RAISED HAND WITH PART BETWEEN MIDDLE AND RING FINGERS"),
+	(0x1f597, "WHITE DOWN POINTING LEFT HAND INDEX"),
+	(0x1f598, "SIDEWAYS WHITE LEFT POINTING INDEX"),
+	(0x1f599, "SIDEWAYS WHITE RIGHT POINTING INDEX"),
+	(0x1f59a, "SIDEWAYS BLACK LEFT POINTING INDEX"),
+	(0x1f59b, "SIDEWAYS BLACK RIGHT POINTING INDEX"),
+	(0x1f59c, "BLACK LEFT POINTING BACKHAND INDEX"),
+	(0x1f59d, "BLACK RIGHT POINTING BACKHAND INDEX"),
+	(0x1f59e, "SIDEWAYS WHITE UP POINTING INDEX"),
+	(0x1f59f, "SIDEWAYS WHITE DOWN POINTING INDEX"),
+	(0x1f5a0, "SIDEWAYS BLACK UP POINTING INDEX"),
+	(0x1f5a1, "SIDEWAYS BLACK DOWN POINTING INDEX"),
+	(0x1f5a2, "BLACK UP POINTING BACKHAND INDEX"),
+	(0x1f5a3, "BLACK DOWN POINTING BACKHAND INDEX"),
+	(0x1f5a4, "BLACK HEART"),
+	(0x1f5a5, "DESKTOP COMPUTER"),
+	(0x1f5a6, "KEYBOARD AND MOUSE"),
+	(0x1f5a7, "THREE NETWORKED COMPUTERS"),
+	(0x1f5a8, "PRINTER"),
+	(0x1f5a9, "POCKET CALCULATOR"),
+	(0x1f5aa, "BLACK HARD SHELL FLOPPY DISK"),
+	(0x1f5ab, "WHITE HARD SHELL FLOPPY DISK"),
+	(0x1f5ac, "SOFT SHELL FLOPPY DISK"),
+	(0x1f5ad, "TAPE CARTRIDGE"),
+	(0x1f5ae, "WIRED KEYBOARD"),
+	(0x1f5af, "ONE BUTTON MOUSE"),
+	(0x1f5b0, "TWO BUTTON MOUSE"),
+	(0x1f5b1, "THREE BUTTON MOUSE"),
+	(0x1f5b2, "TRACKBALL"),
+	(0x1f5b3, "OLD PERSONAL COMPUTER"),
+	(0x1f5b4, "HARD DISK"),
+	(0x1f5b5, "SCREEN"),
+	(0x1f5b6, "PRINTER ICON"),
+	(0x1f5b7, "FAX ICON"),
+	(0x1f5b8, "OPTICAL DISC ICON"),
+	(0x1f5b9, "DOCUMENT WITH TEXT"),
+	(0x1f5ba, "DOCUMENT WITH TEXT AND PICTURE"),
+	(0x1f5bb, "DOCUMENT WITH PICTURE"),
+	(0x1f5bc, "FRAME WITH PICTURE"),
+	(0x1f5bd, "FRAME WITH TILES"),
+	(0x1f5be, "FRAME WITH AN X"),
+	(0x1f5bf, "BLACK FOLDER"),
+	(0x1f5c0, "FOLDER"),
+	(0x1f5c1, "OPEN FOLDER"),
+	(0x1f5c2, "CARD INDEX DIVIDERS"),
+	(0x1f5c3, "CARD FILE BOX"),
+	(0x1f5c4, "FILE CABINET"),
+	(0x1f5c5, "EMPTY NOTE"),
+	(0x1f5c6, "EMPTY NOTE PAGE"),
+	(0x1f5c7, "EMPTY NOTE PAD"),
+	(0x1f5c8, "NOTE"),
+	(0x1f5c9, "NOTE PAGE"),
+	(0x1f5ca, "NOTE PAD"),
+	(0x1f5cb, "EMPTY DOCUMENT"),
+	(0x1f5cc, "EMPTY PAGE"),
+	(0x1f5cd, "EMPTY PAGES"),
+	(0x1f5ce, "DOCUMENT"),
+	(0x1f5cf, "PAGE"),
+	(0x1f5d0, "PAGES"),
+	(0x1f5d1, "WASTEBASKET"),
+	(0x1f5d2, "SPIRAL NOTE PAD"),
+	(0x1f5d3, "SPIRAL CALENDAR PAD"),
+	(0x1f5d4, "DESKTOP WINDOW"),
+	(0x1f5d5, "MINIMIZE"),
+	(0x1f5d6, "MAXIMIZE"),
+	(0x1f5d7, "OVERLAP"),
+	(0x1f5d8, "CLOCKWISE RIGHT AND LEFT SEMICIRCLE ARROWS"),
+	(0x1f5d9, "CANCELLATION X"),
+	(0x1f5da, "INCREASE FONT SIZE SYMBOL"),
+	(0x1f5db, "DECREASE FONT SIZE SYMBOL"),
+	(0x1f5dc, "COMPRESSION"),
+	(0x1f5dd, "OLD KEY"),
+	(0x1f5de, "ROLLED-UP NEWSPAPER"),
+	(0x1f5df, "PAGE WITH CIRCLED TEXT"),
+	(0x1f5e0, "STOCK CHART"),
+	(0x1f5e1, "DAGGER KNIFE"),
+	(0x1f5e2, "LIPS"),
+	(0x1f5e3, "SPEAKING HEAD IN SILHOUETTE"),
+	(0x1f5e4, "THREE RAYS ABOVE"),
+	(0x1f5e5, "THREE RAYS BELOW"),
+	(0x1f5e6, "THREE RAYS LEFT"),
+	(0x1f5e7, "THREE RAYS RIGHT"),
+	(0x1f5e8, "LEFT SPEECH BUBBLE"),
+	(0x1f5e9, "RIGHT SPEECH BUBBLE"),
+	(0x1f5ea, "TWO SPEECH BUBBLES"),
+	(0x1f5eb, "THREE SPEECH BUBBLES"),
+	(0x1f5ec, "LEFT THOUGHT BUBBLE"),
+	(0x1f5ed, "RIGHT THOUGHT BUBBLE"),
+	(0x1f5ee, "LEFT ANGER BUBBLE"),
+	(0x1f5ef, "RIGHT ANGER BUBBLE"),
+	(0x1f5f0, "MOOD BUBBLE"),
+	(0x1f5f1, "LIGHTNING MOOD BUBBLE"),
+	(0x1f5f2, "LIGHTNING MOOD"),
+	(0x1f5f3, "BALLOT BOX WITH BALLOT"),
+	(0x1f5f4, "BALLOT SCRIPT X"),
+	(0x1f5f5, "BALLOT BOX WITH SCRIPT X"),
+	(0x1f5f6, "BALLOT BOLD SCRIPT X"),
+	(0x1f5f7, "BALLOT BOX WITH BOLD SCRIPT X"),
+	(0x1f5f8, "LIGHT CHECK MARK"),
+	(0x1f5f9, "BALLOT BOX WITH BOLD CHECK"),
+	(0x1f5fa, "WORLD MAP"),
+	(0x1f5fb, "MOUNT FUJI"),
+	(0x1f5fc, "TOKYO TOWER"),
+	(0x1f5fd, "STATUE OF LIBERTY"),
+	(0x1f5fe, "SILHOUETTE OF JAPAN"),
+	(0x1f5ff, "MOYAI"),
+	(0x1f600, "GRINNING FACE"),
+	(0x1f601, "GRINNING FACE WITH SMILING EYES"),
+	(0x1f602, "FACE WITH TEARS OF JOY"),
+	(0x1f603, "SMILING FACE WITH OPEN MOUTH"),
+	(0x1f604, "SMILING FACE WITH OPEN MOUTH AND SMILING EYES"),
+	(0x1f605, "SMILING FACE WITH OPEN MOUTH AND COLD SWEAT"),
+	(0x1f606, "SMILING FACE WITH OPEN MOUTH AND TIGHTLY-CLOSED EYES"),
+	(0x1f607, "SMILING FACE WITH HALO"),
+	(0x1f608, "SMILING FACE WITH HORNS"),
+	(0x1f609, "WINKING FACE"),
+	(0x1f60a, "SMILING FACE WITH SMILING EYES"),
+	(0x1f60b, "FACE SAVOURING DELICIOUS FOOD"),
+	(0x1f60c, "RELIEVED FACE"),
+	(0x1f60d, "SMILING FACE WITH HEART-SHAPED EYES"),
+	(0x1f60e, "SMILING FACE WITH SUNGLASSES"),
+	(0x1f60f, "SMIRKING FACE"),
+	(0x1f610, "NEUTRAL FACE"),
+	(0x1f611, "EXPRESSIONLESS FACE"),
+	(0x1f612, "UNAMUSED FACE"),
+	(0x1f613, "FACE WITH COLD SWEAT"),
+	(0x1f614, "PENSIVE FACE"),
+	(0x1f615, "CONFUSED FACE"),
+	(0x1f616, "CONFOUNDED FACE"),
+	(0x1f617, "KISSING FACE"),
+	(0x1f618, "FACE THROWING A KISS"),
+	(0x1f619, "KISSING FACE WITH SMILING EYES"),
+	(0x1f61a, "KISSING FACE WITH CLOSED EYES"),
+	(0x1f61b, "FACE WITH STUCK-OUT TONGUE"),
+	(0x1f61c, "FACE WITH STUCK-OUT TONGUE AND WINKING EYE"),
+	(0x1f61d, "FACE WITH STUCK-OUT TONGUE AND TIGHTLY-CLOSED EYES"),
+	(0x1f61e, "DISAPPOINTED FACE"),
+	(0x1f61f, "WORRIED FACE"),
+	(0x1f620, "ANGRY FACE"),
+	(0x1f621, "POUTING FACE"),
+	(0x1f622, "CRYING FACE"),
+	(0x1f623, "PERSEVERING FACE"),
+	(0x1f624, "FACE WITH LOOK OF TRIUMPH"),
+	(0x1f625, "DISAPPOINTED BUT RELIEVED FACE"),
+	(0x1f626, "FROWNING FACE WITH OPEN MOUTH"),
+	(0x1f627, "ANGUISHED FACE"),
+	(0x1f628, "FEARFUL FACE"),
+	(0x1f629, "WEARY FACE"),
+	(0x1f62a, "SLEEPY FACE"),
+	(0x1f62b, "TIRED FACE"),
+	(0x1f62c, "GRIMACING FACE"),
+	(0x1f62d, "LOUDLY CRYING FACE"),
+	(0x1f62e, "FACE WITH OPEN MOUTH"),
+	(0x1f62f, "HUSHED FACE"),
+	(0x1f630, "FACE WITH OPEN MOUTH AND COLD SWEAT"),
+	(0x1f631, "FACE SCREAMING IN FEAR"),
+	(0x1f632, "ASTONISHED FACE"),
+	(0x1f633, "FLUSHED FACE"),
+	(0x1f634, "SLEEPING FACE"),
+	(0x1f635, "DIZZY FACE"),
+	(0x1f636, "FACE WITHOUT MOUTH"),
+	(0x1f637, "FACE WITH MEDICAL MASK"),
+	(0x1f638, "GRINNING CAT FACE WITH SMILING EYES"),
+	(0x1f639, "CAT FACE WITH TEARS OF JOY"),
+	(0x1f63a, "SMILING CAT FACE WITH OPEN MOUTH"),
+	(0x1f63b, "SMILING CAT FACE WITH HEART-SHAPED EYES"),
+	(0x1f63c, "CAT FACE WITH WRY SMILE"),
+	(0x1f63d, "KISSING CAT FACE WITH CLOSED EYES"),
+	(0x1f63e, "POUTING CAT FACE"),
+	(0x1f63f, "CRYING CAT FACE"),
+	(0x1f640, "WEARY CAT FACE"),
+	(0x1f641, "SLIGHTLY FROWNING FACE"),
+	(0x1f642, "SLIGHTLY SMILING FACE"),
+	(0x1f643, "UPSIDE-DOWN FACE"),
+	(0x1f644, "FACE WITH ROLLING EYES"),
+	(0x1f645, "FACE WITH NO GOOD GESTURE"),
+	(0x1f646, "FACE WITH OK GESTURE"),
+	(0x1f647, "PERSON BOWING DEEPLY"),
+	(0x1f648, "SEE-NO-EVIL MONKEY"),
+	(0x1f649, "HEAR-NO-EVIL MONKEY"),
+	(0x1f64a, "SPEAK-NO-EVIL MONKEY"),
+	(0x1f64b, "HAPPY PERSON RAISING ONE HAND"),
+	(0x1f64c, "PERSON RAISING BOTH HANDS IN CELEBRATION"),
+	(0x1f64d, "PERSON FROWNING"),
+	(0x1f64e, "PERSON WITH POUTING FACE"),
+	(0x1f64f, "PERSON WITH FOLDED HANDS"),
+	(0x1f650, "NORTH WEST POINTING LEAF"),
+	(0x1f651, "SOUTH WEST POINTING LEAF"),
+	(0x1f652, "NORTH EAST POINTING LEAF"),
+	(0x1f653, "SOUTH EAST POINTING LEAF"),
+	(0x1f654, "TURNED NORTH WEST POINTING LEAF"),
+	(0x1f655, "TURNED SOUTH WEST POINTING LEAF"),
+	(0x1f656, "TURNED NORTH EAST POINTING LEAF"),
+	(0x1f657, "TURNED SOUTH EAST POINTING LEAF"),
+	(0x1f658, "NORTH WEST POINTING VINE LEAF"),
+	(0x1f659, "SOUTH WEST POINTING VINE LEAF"),
+	(0x1f65a, "NORTH EAST POINTING VINE LEAF"),
+	(0x1f65b, "SOUTH EAST POINTING VINE LEAF"),
+	(0x1f65c, "HEAVY NORTH WEST POINTING VINE LEAF"),
+	(0x1f65d, "HEAVY SOUTH WEST POINTING VINE LEAF"),
+	(0x1f65e, "HEAVY NORTH EAST POINTING VINE LEAF"),
+	(0x1f65f, "HEAVY SOUTH EAST POINTING VINE LEAF"),
+	(0x1f660, "NORTH WEST POINTING BUD"),
+	(0x1f661, "SOUTH WEST POINTING BUD"),
+	(0x1f662, "NORTH EAST POINTING BUD"),
+	(0x1f663, "SOUTH EAST POINTING BUD"),
+	(0x1f664, "HEAVY NORTH WEST POINTING BUD"),
+	(0x1f665, "HEAVY SOUTH WEST POINTING BUD"),
+	(0x1f666, "HEAVY NORTH EAST POINTING BUD"),
+	(0x1f667, "HEAVY SOUTH EAST POINTING BUD"),
+	(0x1f668, "HOLLOW QUILT SQUARE ORNAMENT"),
+	(0x1f669, "HOLLOW QUILT SQUARE ORNAMENT IN BLACK SQUARE"),
+	(0x1f66a, "SOLID QUILT SQUARE ORNAMENT"),
+	(0x1f66b, "SOLID QUILT SQUARE ORNAMENT IN BLACK SQUARE"),
+	(0x1f66c, "LEFTWARDS ROCKET"),
+	(0x1f66d, "UPWARDS ROCKET"),
+	(0x1f66e, "RIGHTWARDS ROCKET"),
+	(0x1f66f, "DOWNWARDS ROCKET"),
+	(0x1f670, "SCRIPT LIGATURE ET ORNAMENT"),
+	(0x1f671, "HEAVY SCRIPT LIGATURE ET ORNAMENT"),
+	(0x1f672, "LIGATURE OPEN ET ORNAMENT"),
+	(0x1f673, "HEAVY LIGATURE OPEN ET ORNAMENT"),
+	(0x1f674, "HEAVY AMPERSAND ORNAMENT"),
+	(0x1f675, "SWASH AMPERSAND ORNAMENT"),
+	(0x1f676, "SANS-SERIF HEAVY DOUBLE TURNED COMMA QUOTATION MARK ORNAMENT"),
+	(0x1f677, "SANS-SERIF HEAVY DOUBLE COMMA QUOTATION MARK ORNAMENT"),
+	(0x1f678, "SANS-SERIF HEAVY LOW DOUBLE COMMA QUOTATION MARK ORNAMENT"),
+	(0x1f679, "HEAVY INTERROBANG ORNAMENT"),
+	(0x1f67a, "SANS-SERIF INTERROBANG ORNAMENT"),
+	(0x1f67b, "HEAVY SANS-SERIF INTERROBANG ORNAMENT"),
+	(0x1f67c, "VERY HEAVY SOLIDUS"),
+	(0x1f67d, "VERY HEAVY REVERSE SOLIDUS"),
+	(0x1f67e, "CHECKER BOARD"),
+	(0x1f67f, "REVERSE CHECKER BOARD"),
+	(0x1f680, "ROCKET"),
+	(0x1f681, "HELICOPTER"),
+	(0x1f682, "STEAM LOCOMOTIVE"),
+	(0x1f683, "RAILWAY CAR"),
+	(0x1f684, "HIGH-SPEED TRAIN"),
+	(0x1f685, "HIGH-SPEED TRAIN WITH BULLET NOSE"),
+	(0x1f686, "TRAIN"),
+	(0x1f687, "METRO"),
+	(0x1f688, "LIGHT RAIL"),
+	(0x1f689, "STATION"),
+	(0x1f68a, "TRAM"),
+	(0x1f68b, "TRAM CAR"),
+	(0x1f68c, "BUS"),
+	(0x1f68d, "ONCOMING BUS"),
+	(0x1f68e, "TROLLEYBUS"),
+	(0x1f68f, "BUS STOP"),
+	(0x1f690, "MINIBUS"),
+	(0x1f691, "AMBULANCE"),
+	(0x1f692, "FIRE ENGINE"),
+	(0x1f693, "POLICE CAR"),
+	(0x1f694, "ONCOMING POLICE CAR"),
+	(0x1f695, "TAXI"),
+	(0x1f696, "ONCOMING TAXI"),
+	(0x1f697, "AUTOMOBILE"),
+	(0x1f698, "ONCOMING AUTOMOBILE"),
+	(0x1f699, "RECREATIONAL VEHICLE"),
+	(0x1f69a, "DELIVERY TRUCK"),
+	(0x1f69b, "ARTICULATED LORRY"),
+	(0x1f69c, "TRACTOR"),
+	(0x1f69d, "MONORAIL"),
+	(0x1f69e, "MOUNTAIN RAILWAY"),
+	(0x1f69f, "SUSPENSION RAILWAY"),
+	(0x1f6a0, "MOUNTAIN CABLEWAY"),
+	(0x1f6a1, "AERIAL TRAMWAY"),
+	(0x1f6a2, "SHIP"),
+	(0x1f6a3, "ROWBOAT"),
+	(0x1f6a4, "SPEEDBOAT"),
+	(0x1f6a5, "HORIZONTAL TRAFFIC LIGHT"),
+	(0x1f6a6, "VERTICAL TRAFFIC LIGHT"),
+	(0x1f6a7, "CONSTRUCTION SIGN"),
+	(0x1f6a8, "POLICE CARS REVOLVING LIGHT"),
+	(0x1f6a9, "TRIANGULAR FLAG ON POST"),
+	(0x1f6aa, "DOOR"),
+	(0x1f6ab, "NO ENTRY SIGN"),
+	(0x1f6ac, "SMOKING SYMBOL"),
+	(0x1f6ad, "NO SMOKING SYMBOL"),
+	(0x1f6ae, "PUT LITTER IN ITS PLACE SYMBOL"),
+	(0x1f6af, "DO NOT LITTER SYMBOL"),
+	(0x1f6b0, "POTABLE WATER SYMBOL"),
+	(0x1f6b1, "NON-POTABLE WATER SYMBOL"),
+	(0x1f6b2, "BICYCLE"),
+	(0x1f6b3, "NO BICYCLES"),
+	(0x1f6b4, "BICYCLIST"),
+	(0x1f6b5, "MOUNTAIN BICYCLIST"),
+	(0x1f6b6, "PEDESTRIAN"),
+	(0x1f6b7, "NO PEDESTRIANS"),
+	(0x1f6b8, "CHILDREN CROSSING"),
+	(0x1f6b9, "MENS SYMBOL"),
+	(0x1f6ba, "WOMENS SYMBOL"),
+	(0x1f6bb, "RESTROOM"),
+	(0x1f6bc, "BABY SYMBOL"),
+	(0x1f6bd, "TOILET"),
+	(0x1f6be, "WATER CLOSET"),
+	(0x1f6bf, "SHOWER"),
+	(0x1f6c0, "BATH"),
+	(0x1f6c1, "BATHTUB"),
+	(0x1f6c2, "PASSPORT CONTROL"),
+	(0x1f6c3, "CUSTOMS"),
+	(0x1f6c4, "BAGGAGE CLAIM"),
+	(0x1f6c5, "LEFT LUGGAGE"),
+	(0x1f6c6, "TRIANGLE WITH ROUNDED CORNERS"),
+	(0x1f6c7, "PROHIBITED SIGN"),
+	(0x1f6c8, "CIRCLED INFORMATION SOURCE"),
+	(0x1f6c9, "BOYS SYMBOL"),
+	(0x1f6ca, "GIRLS SYMBOL"),
+	(0x1f6cb, "COUCH AND LAMP"),
+	(0x1f6cc, "SLEEPING ACCOMMODATION"),
+	(0x1f6cd, "SHOPPING BAGS"),
+	(0x1f6ce, "BELLHOP BELL"),
+	(0x1f6cf, "BED"),
+	(0x1f6d0, "PLACE OF WORSHIP"),
+	(0x1f6d1, "OCTAGONAL SIGN"),
+	(0x1f6d2, "SHOPPING TROLLEY"),
+	(0x1f6d3, "STUPA"),
+	(0x1f6d4, "PAGODA"),
+	(0x1f6d5, "HINDU TEMPLE"),
+	(0x1f6d6, "HUT"),
+	(0x1f6d7, "ELEVATOR"),
+	(0x1f6dd, "PLAYGROUND SLIDE"),
+	(0x1f6de, "WHEEL"),
+	(0x1f6df, "RING BUOY"),
+	(0x1f6e0, "HAMMER AND WRENCH"),
+	(0x1f6e1, "SHIELD"),
+	(0x1f6e2, "OIL DRUM"),
+	(0x1f6e3, "MOTORWAY"),
+	(0x1f6e4, "RAILWAY TRACK"),
+	(0x1f6e5, "MOTOR BOAT"),
+	(0x1f6e6, "UP-POINTING MILITARY AIRPLANE"),
+	(0x1f6e7, "UP-POINTING AIRPLANE"),
+	(0x1f6e8, "UP-POINTING SMALL AIRPLANE"),
+	(0x1f6e9, "SMALL AIRPLANE"),
+	(0x1f6ea, "NORTHEAST-POINTING AIRPLANE"),
+	(0x1f6eb, "AIRPLANE DEPARTURE"),
+	(0x1f6ec, "AIRPLANE ARRIVING"),
+	(0x1f6f0, "SATELLITE"),
+	(0x1f6f1, "ONCOMING FIRE ENGINE"),
+	(0x1f6f2, "DIESEL LOCOMOTIVE"),
+	(0x1f6f3, "PASSENGER SHIP"),
+	(0x1f6f4, "SCOOTER"),
+	(0x1f6f5, "MOTOR SCOOTER"),
+	(0x1f6f6, "CANOE"),
+	(0x1f6f7, "SLED"),
+	(0x1f6f8, "FLYING SAUCER"),
+	(0x1f6f9, "SKATEBOARD"),
+	(0x1f6fa, "AUTO RICKSHAW"),
+	(0x1f6fb, "PICKUP TRUCK"),
+	(0x1f6fc, "ROLLER SKATE"),
+	(0x1f700, "ALCHEMICAL SYMBOL FOR QUINTESSENCE"),
+	(0x1f701, "ALCHEMICAL SYMBOL FOR AIR"),
+	(0x1f702, "ALCHEMICAL SYMBOL FOR FIRE"),
+	(0x1f703, "ALCHEMICAL SYMBOL FOR EARTH"),
+	(0x1f704, "ALCHEMICAL SYMBOL FOR WATER"),
+	(0x1f705, "ALCHEMICAL SYMBOL FOR AQUAFORTIS"),
+	(0x1f706, "ALCHEMICAL SYMBOL FOR AQUA REGIA"),
+	(0x1f707, "ALCHEMICAL SYMBOL FOR AQUA REGIA-2"),
+	(0x1f708, "ALCHEMICAL SYMBOL FOR AQUA VITAE"),
+	(0x1f709, "ALCHEMICAL SYMBOL FOR AQUA VITAE-2"),
+	(0x1f70a, "ALCHEMICAL SYMBOL FOR VINEGAR"),
+	(0x1f70b, "ALCHEMICAL SYMBOL FOR VINEGAR-2"),
+	(0x1f70c, "ALCHEMICAL SYMBOL FOR VINEGAR-3"),
+	(0x1f70d, "ALCHEMICAL SYMBOL FOR SULFUR"),
+	(0x1f70e, "ALCHEMICAL SYMBOL FOR PHILOSOPHERS SULFUR"),
+	(0x1f70f, "ALCHEMICAL SYMBOL FOR BLACK SULFUR"),
+	(0x1f710, "ALCHEMICAL SYMBOL FOR MERCURY SUBLIMATE"),
+	(0x1f711, "ALCHEMICAL SYMBOL FOR MERCURY SUBLIMATE-2"),
+	(0x1f712, "ALCHEMICAL SYMBOL FOR MERCURY SUBLIMATE-3"),
+	(0x1f713, "ALCHEMICAL SYMBOL FOR CINNABAR"),
+	(0x1f714, "ALCHEMICAL SYMBOL FOR SALT"),
+	(0x1f715, "ALCHEMICAL SYMBOL FOR NITRE"),
+	(0x1f716, "ALCHEMICAL SYMBOL FOR VITRIOL"),
+	(0x1f717, "ALCHEMICAL SYMBOL FOR VITRIOL-2"),
+	(0x1f718, "ALCHEMICAL SYMBOL FOR ROCK SALT"),
+	(0x1f719, "ALCHEMICAL SYMBOL FOR ROCK SALT-2"),
+	(0x1f71a, "ALCHEMICAL SYMBOL FOR GOLD"),
+	(0x1f71b, "ALCHEMICAL SYMBOL FOR SILVER"),
+	(0x1f71c, "ALCHEMICAL SYMBOL FOR IRON ORE"),
+	(0x1f71d, "ALCHEMICAL SYMBOL FOR IRON ORE-2"),
+	(0x1f71e, "ALCHEMICAL SYMBOL FOR CROCUS OF IRON"),
+	(0x1f71f, "ALCHEMICAL SYMBOL FOR REGULUS OF IRON"),
+	(0x1f720, "ALCHEMICAL SYMBOL FOR COPPER ORE"),
+	(0x1f721, "ALCHEMICAL SYMBOL FOR IRON-COPPER ORE"),
+	(0x1f722, "ALCHEMICAL SYMBOL FOR SUBLIMATE OF COPPER"),
+	(0x1f723, "ALCHEMICAL SYMBOL FOR CROCUS OF COPPER"),
+	(0x1f724, "ALCHEMICAL SYMBOL FOR CROCUS OF COPPER-2"),
+	(0x1f725, "ALCHEMICAL SYMBOL FOR COPPER ANTIMONIATE"),
+	(0x1f726, "ALCHEMICAL SYMBOL FOR SALT OF COPPER ANTIMONIATE"),
+	(0x1f727, "ALCHEMICAL SYMBOL FOR SUBLIMATE OF SALT OF COPPER"),
+	(0x1f728, "ALCHEMICAL SYMBOL FOR VERDIGRIS"),
+	(0x1f729, "ALCHEMICAL SYMBOL FOR TIN ORE"),
+	(0x1f72a, "ALCHEMICAL SYMBOL FOR LEAD ORE"),
+	(0x1f72b, "ALCHEMICAL SYMBOL FOR ANTIMONY ORE"),
+	(0x1f72c, "ALCHEMICAL SYMBOL FOR SUBLIMATE OF ANTIMONY"),
+	(0x1f72d, "ALCHEMICAL SYMBOL FOR SALT OF ANTIMONY"),
+	(0x1f72e, "ALCHEMICAL SYMBOL FOR SUBLIMATE OF SALT OF ANTIMONY"),
+	(0x1f72f, "ALCHEMICAL SYMBOL FOR VINEGAR OF ANTIMONY"),
+	(0x1f730, "ALCHEMICAL SYMBOL FOR REGULUS OF ANTIMONY"),
+	(0x1f731, "ALCHEMICAL SYMBOL FOR REGULUS OF ANTIMONY-2"),
+	(0x1f732, "ALCHEMICAL SYMBOL FOR REGULUS"),
+	(0x1f733, "ALCHEMICAL SYMBOL FOR REGULUS-2"),
+	(0x1f734, "ALCHEMICAL SYMBOL FOR REGULUS-3"),
+	(0x1f735, "ALCHEMICAL SYMBOL FOR REGULUS-4"),
+	(0x1f736, "ALCHEMICAL SYMBOL FOR ALKALI"),
+	(0x1f737, "ALCHEMICAL SYMBOL FOR ALKALI-2"),
+	(0x1f738, "ALCHEMICAL SYMBOL FOR MARCASITE"),
+	(0x1f739, "ALCHEMICAL SYMBOL FOR SAL-AMMONIAC"),
+	(0x1f73a, "ALCHEMICAL SYMBOL FOR ARSENIC"),
+	(0x1f73b, "ALCHEMICAL SYMBOL FOR REALGAR"),
+	(0x1f73c, "ALCHEMICAL SYMBOL FOR REALGAR-2"),
+	(0x1f73d, "ALCHEMICAL SYMBOL FOR AURIPIGMENT"),
+	(0x1f73e, "ALCHEMICAL SYMBOL FOR BISMUTH ORE"),
+	(0x1f73f, "ALCHEMICAL SYMBOL FOR TARTAR"),
+	(0x1f740, "ALCHEMICAL SYMBOL FOR TARTAR-2"),
+	(0x1f741, "ALCHEMICAL SYMBOL FOR QUICK LIME"),
+	(0x1f742, "ALCHEMICAL SYMBOL FOR BORAX"),
+	(0x1f743, "ALCHEMICAL SYMBOL FOR BORAX-2"),
+	(0x1f744, "ALCHEMICAL SYMBOL FOR BORAX-3"),
+	(0x1f745, "ALCHEMICAL SYMBOL FOR ALUM"),
+	(0x1f746, "ALCHEMICAL SYMBOL FOR OIL"),
+	(0x1f747, "ALCHEMICAL SYMBOL FOR SPIRIT"),
+	(0x1f748, "ALCHEMICAL SYMBOL FOR TINCTURE"),
+	(0x1f749, "ALCHEMICAL SYMBOL FOR GUM"),
+	(0x1f74a, "ALCHEMICAL SYMBOL FOR WAX"),
+	(0x1f74b, "ALCHEMICAL SYMBOL FOR POWDER"),
+	(0x1f74c, "ALCHEMICAL SYMBOL FOR CALX"),
+	(0x1f74d, "ALCHEMICAL SYMBOL FOR TUTTY"),
+	(0x1f74e, "ALCHEMICAL SYMBOL FOR CAPUT MORTUUM"),
+	(0x1f74f, "ALCHEMICAL SYMBOL FOR SCEPTER OF JOVE"),
+	(0x1f750, "ALCHEMICAL SYMBOL FOR CADUCEUS"),
+	(0x1f751, "ALCHEMICAL SYMBOL FOR TRIDENT"),
+	(0x1f752, "ALCHEMICAL SYMBOL FOR STARRED TRIDENT"),
+	(0x1f753, "ALCHEMICAL SYMBOL FOR LODESTONE"),
+	(0x1f754, "ALCHEMICAL SYMBOL FOR SOAP"),
+	(0x1f755, "ALCHEMICAL SYMBOL FOR URINE"),
+	(0x1f756, "ALCHEMICAL SYMBOL FOR HORSE DUNG"),
+	(0x1f757, "ALCHEMICAL SYMBOL FOR ASHES"),
+	(0x1f758, "ALCHEMICAL SYMBOL FOR POT ASHES"),
+	(0x1f759, "ALCHEMICAL SYMBOL FOR BRICK"),
+	(0x1f75a, "ALCHEMICAL SYMBOL FOR POWDERED BRICK"),
+	(0x1f75b, "ALCHEMICAL SYMBOL FOR AMALGAM"),
+	(0x1f75c, "ALCHEMICAL SYMBOL FOR STRATUM SUPER STRATUM"),
+	(0x1f75d, "ALCHEMICAL SYMBOL FOR STRATUM SUPER STRATUM-2"),
+	(0x1f75e, "ALCHEMICAL SYMBOL FOR SUBLIMATION"),
+	(0x1f75f, "ALCHEMICAL SYMBOL FOR PRECIPITATE"),
+	(0x1f760, "ALCHEMICAL SYMBOL FOR DISTILL"),
+	(0x1f761, "ALCHEMICAL SYMBOL FOR DISSOLVE"),
+	(0x1f762, "ALCHEMICAL SYMBOL FOR DISSOLVE-2"),
+	(0x1f763, "ALCHEMICAL SYMBOL FOR PURIFY"),
+	(0x1f764, "ALCHEMICAL SYMBOL FOR PUTREFACTION"),
+	(0x1f765, "ALCHEMICAL SYMBOL FOR CRUCIBLE"),
+	(0x1f766, "ALCHEMICAL SYMBOL FOR CRUCIBLE-2"),
+	(0x1f767, "ALCHEMICAL SYMBOL FOR CRUCIBLE-3"),
+	(0x1f768, "ALCHEMICAL SYMBOL FOR CRUCIBLE-4"),
+	(0x1f769, "ALCHEMICAL SYMBOL FOR CRUCIBLE-5"),
+	(0x1f76a, "ALCHEMICAL SYMBOL FOR ALEMBIC"),
+	(0x1f76b, "ALCHEMICAL SYMBOL FOR BATH OF MARY"),
+	(0x1f76c, "ALCHEMICAL SYMBOL FOR BATH OF VAPOURS"),
+	(0x1f76d, "ALCHEMICAL SYMBOL FOR RETORT"),
+	(0x1f76e, "ALCHEMICAL SYMBOL FOR HOUR"),
+	(0x1f76f, "ALCHEMICAL SYMBOL FOR NIGHT"),
+	(0x1f770, "ALCHEMICAL SYMBOL FOR DAY-NIGHT"),
+	(0x1f771, "ALCHEMICAL SYMBOL FOR MONTH"),
+	(0x1f772, "ALCHEMICAL SYMBOL FOR HALF DRAM"),
+	(0x1f773, "ALCHEMICAL SYMBOL FOR HALF OUNCE"),
+	(0x1f780, "BLACK LEFT-POINTING ISOSCELES RIGHT TRIANGLE"),
+	(0x1f781, "BLACK UP-POINTING ISOSCELES RIGHT TRIANGLE"),
+	(0x1f782, "BLACK RIGHT-POINTING ISOSCELES RIGHT TRIANGLE"),
+	(0x1f783, "BLACK DOWN-POINTING ISOSCELES RIGHT TRIANGLE"),
+	(0x1f784, "BLACK SLIGHTLY SMALL CIRCLE"),
+	(0x1f785, "MEDIUM BOLD WHITE CIRCLE"),
+	(0x1f786, "BOLD WHITE CIRCLE"),
+	(0x1f787, "HEAVY WHITE CIRCLE"),
+	(0x1f788, "VERY HEAVY WHITE CIRCLE"),
+	(0x1f789, "EXTREMELY HEAVY WHITE CIRCLE"),
+	(0x1f78a, "WHITE CIRCLE CONTAINING BLACK SMALL CIRCLE"),
+	(0x1f78b, "ROUND TARGET"),
+	(0x1f78c, "BLACK TINY SQUARE"),
+	(0x1f78d, "BLACK SLIGHTLY SMALL SQUARE"),
+	(0x1f78e, "LIGHT WHITE SQUARE"),
+	(0x1f78f, "MEDIUM WHITE SQUARE"),
+	(0x1f790, "BOLD WHITE SQUARE"),
+	(0x1f791, "HEAVY WHITE SQUARE"),
+	(0x1f792, "VERY HEAVY WHITE SQUARE"),
+	(0x1f793, "EXTREMELY HEAVY WHITE SQUARE"),
+	(0x1f794, "WHITE SQUARE CONTAINING BLACK VERY SMALL SQUARE"),
+	(0x1f795, "WHITE SQUARE CONTAINING BLACK MEDIUM SQUARE"),
+	(0x1f796, "SQUARE TARGET"),
+	(0x1f797, "BLACK TINY DIAMOND"),
+	(0x1f798, "BLACK VERY SMALL DIAMOND"),
+	(0x1f799, "BLACK MEDIUM SMALL DIAMOND"),
+	(0x1f79a, "WHITE DIAMOND CONTAINING BLACK VERY SMALL DIAMOND"),
+	(0x1f79b, "WHITE DIAMOND CONTAINING BLACK MEDIUM DIAMOND"),
+	(0x1f79c, "DIAMOND TARGET"),
+	(0x1f79d, "BLACK TINY LOZENGE"),
+	(0x1f79e, "BLACK VERY SMALL LOZENGE"),
+	(0x1f79f, "BLACK MEDIUM SMALL LOZENGE"),
+	(0x1f7a0, "WHITE LOZENGE CONTAINING BLACK SMALL LOZENGE"),
+	(0x1f7a1, "THIN GREEK CROSS"),
+	(0x1f7a2, "LIGHT GREEK CROSS"),
+	(0x1f7a3, "MEDIUM GREEK CROSS"),
+	(0x1f7a4, "BOLD GREEK CROSS"),
+	(0x1f7a5, "VERY BOLD GREEK CROSS"),
+	(0x1f7a6, "VERY HEAVY GREEK CROSS"),
+	(0x1f7a7, "EXTREMELY HEAVY GREEK CROSS"),
+	(0x1f7a8, "THIN SALTIRE"),
+	(0x1f7a9, "LIGHT SALTIRE"),
+	(0x1f7aa, "MEDIUM SALTIRE"),
+	(0x1f7ab, "BOLD SALTIRE"),
+	(0x1f7ac, "HEAVY SALTIRE"),
+	(0x1f7ad, "VERY HEAVY SALTIRE"),
+	(0x1f7ae, "EXTREMELY HEAVY SALTIRE"),
+	(0x1f7af, "LIGHT FIVE SPOKED ASTERISK"),
+	(0x1f7b0, "MEDIUM FIVE SPOKED ASTERISK"),
+	(0x1f7b1, "BOLD FIVE SPOKED ASTERISK"),
+	(0x1f7b2, "HEAVY FIVE SPOKED ASTERISK"),
+	(0x1f7b3, "VERY HEAVY FIVE SPOKED ASTERISK"),
+	(0x1f7b4, "EXTREMELY HEAVY FIVE SPOKED ASTERISK"),
+	(0x1f7b5, "LIGHT SIX SPOKED ASTERISK"),
+	(0x1f7b6, "MEDIUM SIX SPOKED ASTERISK"),
+	(0x1f7b7, "BOLD SIX SPOKED ASTERISK"),
+	(0x1f7b8, "HEAVY SIX SPOKED ASTERISK"),
+	(0x1f7b9, "VERY HEAVY SIX SPOKED ASTERISK"),
+	(0x1f7ba, "EXTREMELY HEAVY SIX SPOKED ASTERISK"),
+	(0x1f7bb, "LIGHT EIGHT SPOKED ASTERISK"),
+	(0x1f7bc, "MEDIUM EIGHT SPOKED ASTERISK"),
+	(0x1f7bd, "BOLD EIGHT SPOKED ASTERISK"),
+	(0x1f7be, "HEAVY EIGHT SPOKED ASTERISK"),
+	(0x1f7bf, "VERY HEAVY EIGHT SPOKED ASTERISK"),
+	(0x1f7c0, "LIGHT THREE POINTED BLACK STAR"),
+	(0x1f7c1, "MEDIUM THREE POINTED BLACK STAR"),
+	(0x1f7c2, "THREE POINTED BLACK STAR"),
+	(0x1f7c3, "MEDIUM THREE POINTED PINWHEEL STAR"),
+	(0x1f7c4, "LIGHT FOUR POINTED BLACK STAR"),
+	(0x1f7c5, "MEDIUM FOUR POINTED BLACK STAR"),
+	(0x1f7c6, "FOUR POINTED BLACK STAR"),
+	(0x1f7c7, "MEDIUM FOUR POINTED PINWHEEL STAR"),
+	(0x1f7c8, "REVERSE LIGHT FOUR POINTED PINWHEEL STAR"),
+	(0x1f7c9, "LIGHT FIVE POINTED BLACK STAR"),
+	(0x1f7ca, "HEAVY FIVE POINTED BLACK STAR"),
+	(0x1f7cb, "MEDIUM SIX POINTED BLACK STAR"),
+	(0x1f7cc, "HEAVY SIX POINTED BLACK STAR"),
+	(0x1f7cd, "SIX POINTED PINWHEEL STAR"),
+	(0x1f7ce, "MEDIUM EIGHT POINTED BLACK STAR"),
+	(0x1f7cf, "HEAVY EIGHT POINTED BLACK STAR"),
+	(0x1f7d0, "VERY HEAVY EIGHT POINTED BLACK STAR"),
+	(0x1f7d1, "HEAVY EIGHT POINTED PINWHEEL STAR"),
+	(0x1f7d2, "LIGHT TWELVE POINTED BLACK STAR"),
+	(0x1f7d3, "HEAVY TWELVE POINTED BLACK STAR"),
+	(0x1f7d4, "HEAVY TWELVE POINTED PINWHEEL STAR"),
+	(0x1f7d5, "CIRCLED TRIANGLE"),
+	(0x1f7d6, "NEGATIVE CIRCLED TRIANGLE"),
+	(0x1f7d7, "CIRCLED SQUARE"),
+	(0x1f7d8, "NEGATIVE CIRCLED SQUARE"),
+	(0x1f7e0, "LARGE ORANGE CIRCLE"),
+	(0x1f7e1, "LARGE YELLOW CIRCLE"),
+	(0x1f7e2, "LARGE GREEN CIRCLE"),
+	(0x1f7e3, "LARGE PURPLE CIRCLE"),
+	(0x1f7e4, "LARGE BROWN CIRCLE"),
+	(0x1f7e5, "LARGE RED SQUARE"),
+	(0x1f7e6, "LARGE BLUE SQUARE"),
+	(0x1f7e7, "LARGE ORANGE SQUARE"),
+	(0x1f7e8, "LARGE YELLOW SQUARE"),
+	(0x1f7e9, "LARGE GREEN SQUARE"),
+	(0x1f7ea, "LARGE PURPLE SQUARE"),
+	(0x1f7eb, "LARGE BROWN SQUARE"),
+	(0x1f7f0, "HEAVY EQUALS SIGN"),
+	(0x1f800, "LEFTWARDS ARROW WITH SMALL TRIANGLE ARROWHEAD"),
+	(0x1f801, "UPWARDS ARROW WITH SMALL TRIANGLE ARROWHEAD"),
+	(0x1f802, "RIGHTWARDS ARROW WITH SMALL TRIANGLE ARROWHEAD"),
+	(0x1f803, "DOWNWARDS ARROW WITH SMALL TRIANGLE ARROWHEAD"),
+	(0x1f804, "LEFTWARDS ARROW WITH MEDIUM TRIANGLE ARROWHEAD"),
+	(0x1f805, "UPWARDS ARROW WITH MEDIUM TRIANGLE ARROWHEAD"),
+	(0x1f806, "RIGHTWARDS ARROW WITH MEDIUM TRIANGLE ARROWHEAD"),
+	(0x1f807, "DOWNWARDS ARROW WITH MEDIUM TRIANGLE ARROWHEAD"),
+	(0x1f808, "LEFTWARDS ARROW WITH LARGE TRIANGLE ARROWHEAD"),
+	(0x1f809, "UPWARDS ARROW WITH LARGE TRIANGLE ARROWHEAD"),
+	(0x1f80a, "RIGHTWARDS ARROW WITH LARGE TRIANGLE ARROWHEAD"),
+	(0x1f80b, "DOWNWARDS ARROW WITH LARGE TRIANGLE ARROWHEAD"),
+	(0x1f810, "LEFTWARDS ARROW WITH SMALL EQUILATERAL ARROWHEAD"),
+	(0x1f811, "UPWARDS ARROW WITH SMALL EQUILATERAL ARROWHEAD"),
+	(0x1f812, "RIGHTWARDS ARROW WITH SMALL EQUILATERAL ARROWHEAD"),
+	(0x1f813, "DOWNWARDS ARROW WITH SMALL EQUILATERAL ARROWHEAD"),
+	(0x1f814, "LEFTWARDS ARROW WITH EQUILATERAL ARROWHEAD"),
+	(0x1f815, "UPWARDS ARROW WITH EQUILATERAL ARROWHEAD"),
+	(0x1f816, "RIGHTWARDS ARROW WITH EQUILATERAL ARROWHEAD"),
+	(0x1f817, "DOWNWARDS ARROW WITH EQUILATERAL ARROWHEAD"),
+	(0x1f818, "HEAVY LEFTWARDS ARROW WITH EQUILATERAL ARROWHEAD"),
+	(0x1f819, "HEAVY UPWARDS ARROW WITH EQUILATERAL ARROWHEAD"),
+	(0x1f81a, "HEAVY RIGHTWARDS ARROW WITH EQUILATERAL ARROWHEAD"),
+	(0x1f81b, "HEAVY DOWNWARDS ARROW WITH EQUILATERAL ARROWHEAD"),
+	(0x1f81c, "HEAVY LEFTWARDS ARROW WITH LARGE EQUILATERAL ARROWHEAD"),
+	(0x1f81d, "HEAVY UPWARDS ARROW WITH LARGE EQUILATERAL ARROWHEAD"),
+	(0x1f81e, "HEAVY RIGHTWARDS ARROW WITH LARGE EQUILATERAL ARROWHEAD"),
+	(0x1f81f, "HEAVY DOWNWARDS ARROW WITH LARGE EQUILATERAL ARROWHEAD"),
+	(0x1f820, "LEFTWARDS TRIANGLE-HEADED ARROW WITH NARROW SHAFT"),
+	(0x1f821, "UPWARDS TRIANGLE-HEADED ARROW WITH NARROW SHAFT"),
+	(0x1f822, "RIGHTWARDS TRIANGLE-HEADED ARROW WITH NARROW SHAFT"),
+	(0x1f823, "DOWNWARDS TRIANGLE-HEADED ARROW WITH NARROW SHAFT"),
+	(0x1f824, "LEFTWARDS TRIANGLE-HEADED ARROW WITH MEDIUM SHAFT"),
+	(0x1f825, "UPWARDS TRIANGLE-HEADED ARROW WITH MEDIUM SHAFT"),
+	(0x1f826, "RIGHTWARDS TRIANGLE-HEADED ARROW WITH MEDIUM SHAFT"),
+	(0x1f827, "DOWNWARDS TRIANGLE-HEADED ARROW WITH MEDIUM SHAFT"),
+	(0x1f828, "LEFTWARDS TRIANGLE-HEADED ARROW WITH BOLD SHAFT"),
+	(0x1f829, "UPWARDS TRIANGLE-HEADED ARROW WITH BOLD SHAFT"),
+	(0x1f82a, "RIGHTWARDS TRIANGLE-HEADED ARROW WITH BOLD SHAFT"),
+	(0x1f82b, "DOWNWARDS TRIANGLE-HEADED ARROW WITH BOLD SHAFT"),
+	(0x1f82c, "LEFTWARDS TRIANGLE-HEADED ARROW WITH HEAVY SHAFT"),
+	(0x1f82d, "UPWARDS TRIANGLE-HEADED ARROW WITH HEAVY SHAFT"),
+	(0x1f82e, "RIGHTWARDS TRIANGLE-HEADED ARROW WITH HEAVY SHAFT"),
+	(0x1f82f, "DOWNWARDS TRIANGLE-HEADED ARROW WITH HEAVY SHAFT"),
+	(0x1f830, "LEFTWARDS TRIANGLE-HEADED ARROW WITH VERY HEAVY SHAFT"),
+	(0x1f831, "UPWARDS TRIANGLE-HEADED ARROW WITH VERY HEAVY SHAFT"),
+	(0x1f832, "RIGHTWARDS TRIANGLE-HEADED ARROW WITH VERY HEAVY SHAFT"),
+	(0x1f833, "DOWNWARDS TRIANGLE-HEADED ARROW WITH VERY HEAVY SHAFT"),
+	(0x1f834, "LEFTWARDS FINGER-POST ARROW"),
+	(0x1f835, "UPWARDS FINGER-POST ARROW"),
+	(0x1f836, "RIGHTWARDS FINGER-POST ARROW"),
+	(0x1f837, "DOWNWARDS FINGER-POST ARROW"),
+	(0x1f838, "LEFTWARDS SQUARED ARROW"),
+	(0x1f839, "UPWARDS SQUARED ARROW"),
+	(0x1f83a, "RIGHTWARDS SQUARED ARROW"),
+	(0x1f83b, "DOWNWARDS SQUARED ARROW"),
+	(0x1f83c, "LEFTWARDS COMPRESSED ARROW"),
+	(0x1f83d, "UPWARDS COMPRESSED ARROW"),
+	(0x1f83e, "RIGHTWARDS COMPRESSED ARROW"),
+	(0x1f83f, "DOWNWARDS COMPRESSED ARROW"),
+	(0x1f840, "LEFTWARDS HEAVY COMPRESSED ARROW"),
+	(0x1f841, "UPWARDS HEAVY COMPRESSED ARROW"),
+	(0x1f842, "RIGHTWARDS HEAVY COMPRESSED ARROW"),
+	(0x1f843, "DOWNWARDS HEAVY COMPRESSED ARROW"),
+	(0x1f844, "LEFTWARDS HEAVY ARROW"),
+	(0x1f845, "UPWARDS HEAVY ARROW"),
+	(0x1f846, "RIGHTWARDS HEAVY ARROW"),
+	(0x1f847, "DOWNWARDS HEAVY ARROW"),
+	(0x1f850, "LEFTWARDS SANS-SERIF ARROW"),
+	(0x1f851, "UPWARDS SANS-SERIF ARROW"),
+	(0x1f852, "RIGHTWARDS SANS-SERIF ARROW"),
+	(0x1f853, "DOWNWARDS SANS-SERIF ARROW"),
+	(0x1f854, "NORTH WEST SANS-SERIF ARROW"),
+	(0x1f855, "NORTH EAST SANS-SERIF ARROW"),
+	(0x1f856, "SOUTH EAST SANS-SERIF ARROW"),
+	(0x1f857, "SOUTH WEST SANS-SERIF ARROW"),
+	(0x1f858, "LEFT RIGHT SANS-SERIF ARROW"),
+	(0x1f859, "UP DOWN SANS-SERIF ARROW"),
+	(0x1f860, "WIDE-HEADED LEFTWARDS LIGHT BARB ARROW"),
+	(0x1f861, "WIDE-HEADED UPWARDS LIGHT BARB ARROW"),
+	(0x1f862, "WIDE-HEADED RIGHTWARDS LIGHT BARB ARROW"),
+	(0x1f863, "WIDE-HEADED DOWNWARDS LIGHT BARB ARROW"),
+	(0x1f864, "WIDE-HEADED NORTH WEST LIGHT BARB ARROW"),
+	(0x1f865, "WIDE-HEADED NORTH EAST LIGHT BARB ARROW"),
+	(0x1f866, "WIDE-HEADED SOUTH EAST LIGHT BARB ARROW"),
+	(0x1f867, "WIDE-HEADED SOUTH WEST LIGHT BARB ARROW"),
+	(0x1f868, "WIDE-HEADED LEFTWARDS BARB ARROW"),
+	(0x1f869, "WIDE-HEADED UPWARDS BARB ARROW"),
+	(0x1f86a, "WIDE-HEADED RIGHTWARDS BARB ARROW"),
+	(0x1f86b, "WIDE-HEADED DOWNWARDS BARB ARROW"),
+	(0x1f86c, "WIDE-HEADED NORTH WEST BARB ARROW"),
+	(0x1f86d, "WIDE-HEADED NORTH EAST BARB ARROW"),
+	(0x1f86e, "WIDE-HEADED SOUTH EAST BARB ARROW"),
+	(0x1f86f, "WIDE-HEADED SOUTH WEST BARB ARROW"),
+	(0x1f870, "WIDE-HEADED LEFTWARDS MEDIUM BARB ARROW"),
+	(0x1f871, "WIDE-HEADED UPWARDS MEDIUM BARB ARROW"),
+	(0x1f872, "WIDE-HEADED RIGHTWARDS MEDIUM BARB ARROW"),
+	(0x1f873, "WIDE-HEADED DOWNWARDS MEDIUM BARB ARROW"),
+	(0x1f874, "WIDE-HEADED NORTH WEST MEDIUM BARB ARROW"),
+	(0x1f875, "WIDE-HEADED NORTH EAST MEDIUM BARB ARROW"),
+	(0x1f876, "WIDE-HEADED SOUTH EAST MEDIUM BARB ARROW"),
+	(0x1f877, "WIDE-HEADED SOUTH WEST MEDIUM BARB ARROW"),
+	(0x1f878, "WIDE-HEADED LEFTWARDS HEAVY BARB ARROW"),
+	(0x1f879, "WIDE-HEADED UPWARDS HEAVY BARB ARROW"),
+	(0x1f87a, "WIDE-HEADED RIGHTWARDS HEAVY BARB ARROW"),
+	(0x1f87b, "WIDE-HEADED DOWNWARDS HEAVY BARB ARROW"),
+	(0x1f87c, "WIDE-HEADED NORTH WEST HEAVY BARB ARROW"),
+	(0x1f87d, "WIDE-HEADED NORTH EAST HEAVY BARB ARROW"),
+	(0x1f87e, "WIDE-HEADED SOUTH EAST HEAVY BARB ARROW"),
+	(0x1f87f, "WIDE-HEADED SOUTH WEST HEAVY BARB ARROW"),
+	(0x1f880, "WIDE-HEADED LEFTWARDS VERY HEAVY BARB ARROW"),
+	(0x1f881, "WIDE-HEADED UPWARDS VERY HEAVY BARB ARROW"),
+	(0x1f882, "WIDE-HEADED RIGHTWARDS VERY HEAVY BARB ARROW"),
+	(0x1f883, "WIDE-HEADED DOWNWARDS VERY HEAVY BARB ARROW"),
+	(0x1f884, "WIDE-HEADED NORTH WEST VERY HEAVY BARB ARROW"),
+	(0x1f885, "WIDE-HEADED NORTH EAST VERY HEAVY BARB ARROW"),
+	(0x1f886, "WIDE-HEADED SOUTH EAST VERY HEAVY BARB ARROW"),
+	(0x1f887, "WIDE-HEADED SOUTH WEST VERY HEAVY BARB ARROW"),
+	(0x1f890, "LEFTWARDS TRIANGLE ARROWHEAD"),
+	(0x1f891, "UPWARDS TRIANGLE ARROWHEAD"),
+	(0x1f892, "RIGHTWARDS TRIANGLE ARROWHEAD"),
+	(0x1f893, "DOWNWARDS TRIANGLE ARROWHEAD"),
+	(0x1f894, "LEFTWARDS WHITE ARROW WITHIN TRIANGLE ARROWHEAD"),
+	(0x1f895, "UPWARDS WHITE ARROW WITHIN TRIANGLE ARROWHEAD"),
+	(0x1f896, "RIGHTWARDS WHITE ARROW WITHIN TRIANGLE ARROWHEAD"),
+	(0x1f897, "DOWNWARDS WHITE ARROW WITHIN TRIANGLE ARROWHEAD"),
+	(0x1f898, "LEFTWARDS ARROW WITH NOTCHED TAIL"),
+	(0x1f899, "UPWARDS ARROW WITH NOTCHED TAIL"),
+	(0x1f89a, "RIGHTWARDS ARROW WITH NOTCHED TAIL"),
+	(0x1f89b, "DOWNWARDS ARROW WITH NOTCHED TAIL"),
+	(0x1f89c, "HEAVY ARROW SHAFT WIDTH ONE"),
+	(0x1f89d, "HEAVY ARROW SHAFT WIDTH TWO THIRDS"),
+	(0x1f89e, "HEAVY ARROW SHAFT WIDTH ONE HALF"),
+	(0x1f89f, "HEAVY ARROW SHAFT WIDTH ONE THIRD"),
+	(0x1f8a0, "LEFTWARDS BOTTOM-SHADED WHITE ARROW"),
+	(0x1f8a1, "RIGHTWARDS BOTTOM SHADED WHITE ARROW"),
+	(0x1f8a2, "LEFTWARDS TOP SHADED WHITE ARROW"),
+	(0x1f8a3, "RIGHTWARDS TOP SHADED WHITE ARROW"),
+	(0x1f8a4, "LEFTWARDS LEFT-SHADED WHITE ARROW"),
+	(0x1f8a5, "RIGHTWARDS RIGHT-SHADED WHITE ARROW"),
+	(0x1f8a6, "LEFTWARDS RIGHT-SHADED WHITE ARROW"),
+	(0x1f8a7, "RIGHTWARDS LEFT-SHADED WHITE ARROW"),
+	(0x1f8a8, "LEFTWARDS BACK-TILTED SHADOWED WHITE ARROW"),
+	(0x1f8a9, "RIGHTWARDS BACK-TILTED SHADOWED WHITE ARROW"),
+	(0x1f8aa, "LEFTWARDS FRONT-TILTED SHADOWED WHITE ARROW"),
+	(0x1f8ab, "RIGHTWARDS FRONT-TILTED SHADOWED WHITE ARROW"),
+	(0x1f8ac, "WHITE ARROW SHAFT WIDTH ONE"),
+	(0x1f8ad, "WHITE ARROW SHAFT WIDTH TWO THIRDS"),
+	(0x1f8b0, "ARROW POINTING UPWARDS THEN NORTH WEST"),
+	(0x1f8b1, "ARROW POINTING RIGHTWARDS THEN CURVING SOUTH WEST"),
+	(0x1f900, "CIRCLED CROSS FORMEE WITH FOUR DOTS"),
+	(0x1f901, "CIRCLED CROSS FORMEE WITH TWO DOTS"),
+	(0x1f902, "CIRCLED CROSS FORMEE"),
+	(0x1f903, "LEFT HALF CIRCLE WITH FOUR DOTS"),
+	(0x1f904, "LEFT HALF CIRCLE WITH THREE DOTS"),
+	(0x1f905, "LEFT HALF CIRCLE WITH TWO DOTS"),
+	(0x1f906, "LEFT HALF CIRCLE WITH DOT"),
+	(0x1f907, "LEFT HALF CIRCLE"),
+	(0x1f908, "DOWNWARD FACING HOOK"),
+	(0x1f909, "DOWNWARD FACING NOTCHED HOOK"),
+	(0x1f90a, "DOWNWARD FACING HOOK WITH DOT"),
+	(0x1f90b, "DOWNWARD FACING NOTCHED HOOK WITH DOT"),
+	(0x1f90c, "PINCHED FINGERS"),
+	(0x1f90d, "WHITE HEART"),
+	(0x1f90e, "BROWN HEART"),
+	(0x1f90f, "PINCHING HAND"),
+	(0x1f910, "ZIPPER-MOUTH FACE"),
+	(0x1f911, "MONEY-MOUTH FACE"),
+	(0x1f912, "FACE WITH THERMOMETER"),
+	(0x1f913, "NERD FACE"),
+	(0x1f914, "THINKING FACE"),
+	(0x1f915, "FACE WITH HEAD-BANDAGE"),
+	(0x1f916, "ROBOT FACE"),
+	(0x1f917, "HUGGING FACE"),
+	(0x1f918, "SIGN OF THE HORNS"),
+	(0x1f919, "CALL ME HAND"),
+	(0x1f91a, "RAISED BACK OF HAND"),
+	(0x1f91b, "LEFT-FACING FIST"),
+	(0x1f91c, "RIGHT-FACING FIST"),
+	(0x1f91d, "HANDSHAKE"),
+	(0x1f91e, "HAND WITH INDEX AND MIDDLE FINGERS CROSSED"),
+	(0x1f91f, "I LOVE YOU HAND SIGN"),
+	(0x1f920, "FACE WITH COWBOY HAT"),
+	(0x1f921, "CLOWN FACE"),
+	(0x1f922, "NAUSEATED FACE"),
+	(0x1f923, "ROLLING ON THE FLOOR LAUGHING"),
+	(0x1f924, "DROOLING FACE"),
+	(0x1f925, "LYING FACE"),
+	(0x1f926, "FACE PALM"),
+	(0x1f927, "SNEEZING FACE"),
+	(0x1f928, "FACE WITH ONE EYEBROW RAISED"),
+	(0x1f929, "GRINNING FACE WITH STAR EYES"),
+	(0x1f92a, "GRINNING FACE WITH ONE LARGE AND ONE SMALL EYE"),
+	(0x1f92b, "FACE WITH FINGER COVERING CLOSED LIPS"),
+	(0x1f92c, "SERIOUS FACE WITH SYMBOLS COVERING MOUTH"),
+	(0x1f92d, "SMILING FACE WITH SMILING EYES AND HAND COVERING MOUTH"),
+	(0x1f92e, "FACE WITH OPEN MOUTH VOMITING"),
+	(0x1f92f, "SHOCKED FACE WITH EXPLODING HEAD"),
+	(0x1f930, "PREGNANT WOMAN"),
+	(0x1f931, "BREAST-FEEDING"),
+	(0x1f932, "PALMS UP TOGETHER"),
+	(0x1f933, "SELFIE"),
+	(0x1f934, "PRINCE"),
+	(0x1f935, "MAN IN TUXEDO"),
+	(0x1f936, "MOTHER CHRISTMAS"),
+	(0x1f937, "SHRUG"),
+	(0x1f938, "PERSON DOING CARTWHEEL"),
+	(0x1f939, "JUGGLING"),
+	(0x1f93a, "FENCER"),
+	(0x1f93b, "MODERN PENTATHLON"),
+	(0x1f93c, "WRESTLERS"),
+	(0x1f93d, "WATER POLO"),
+	(0x1f93e, "HANDBALL"),
+	(0x1f93f, "DIVING MASK"),
+	(0x1f940, "WILTED FLOWER"),
+	(0x1f941, "DRUM WITH DRUMSTICKS"),
+	(0x1f942, "CLINKING GLASSES"),
+	(0x1f943, "TUMBLER GLASS"),
+	(0x1f944, "SPOON"),
+	(0x1f945, "GOAL NET"),
+	(0x1f946, "RIFLE"),
+	(0x1f947, "FIRST PLACE MEDAL"),
+	(0x1f948, "SECOND PLACE MEDAL"),
+	(0x1f949, "THIRD PLACE MEDAL"),
+	(0x1f94a, "BOXING GLOVE"),
+	(0x1f94b, "MARTIAL ARTS UNIFORM"),
+	(0x1f94c, "CURLING STONE"),
+	(0x1f94d, "LACROSSE STICK AND BALL"),
+	(0x1f94e, "SOFTBALL"),
+	(0x1f94f, "FLYING DISC"),
+	(0x1f950, "CROISSANT"),
+	(0x1f951, "AVOCADO"),
+	(0x1f952, "CUCUMBER"),
+	(0x1f953, "BACON"),
+	(0x1f954, "POTATO"),
+	(0x1f955, "CARROT"),
+	(0x1f956, "BAGUETTE BREAD"),
+	(0x1f957, "GREEN SALAD"),
+	(0x1f958, "SHALLOW PAN OF FOOD"),
+	(0x1f959, "STUFFED FLATBREAD"),
+	(0x1f95a, "EGG"),
+	(0x1f95b, "GLASS OF MILK"),
+	(0x1f95c, "PEANUTS"),
+	(0x1f95d, "KIWIFRUIT"),
+	(0x1f95e, "PANCAKES"),
+	(0x1f95f, "DUMPLING"),
+	(0x1f960, "FORTUNE COOKIE"),
+	(0x1f961, "TAKEOUT BOX"),
+	(0x1f962, "CHOPSTICKS"),
+	(0x1f963, "BOWL WITH SPOON"),
+	(0x1f964, "CUP WITH STRAW"),
+	(0x1f965, "COCONUT"),
+	(0x1f966, "BROCCOLI"),
+	(0x1f967, "PIE"),
+	(0x1f968, "PRETZEL"),
+	(0x1f969, "CUT OF MEAT"),
+	(0x1f96a, "SANDWICH"),
+	(0x1f96b, "CANNED FOOD"),
+	(0x1f96c, "LEAFY GREEN"),
+	(0x1f96d, "MANGO"),
+	(0x1f96e, "MOON CAKE"),
+	(0x1f96f, "BAGEL"),
+	(0x1f970, "SMILING FACE WITH SMILING EYES AND THREE HEARTS"),
+	(0x1f971, "YAWNING FACE"),
+	(0x1f972, "SMILING FACE WITH TEAR"),
+	(0x1f973, "FACE WITH PARTY HORN AND PARTY HAT"),
+	(0x1f974, "FACE WITH UNEVEN EYES AND WAVY MOUTH"),
+	(0x1f975, "OVERHEATED FACE"),
+	(0x1f976, "FREEZING FACE"),
+	(0x1f977, "NINJA"),
+	(0x1f978, "DISGUISED FACE"),
+	(0x1f979, "FACE HOLDING BACK TEARS"),
+	(0x1f97a, "FACE WITH PLEADING EYES"),
+	(0x1f97b, "SARI"),
+	(0x1f97c, "LAB COAT"),
+	(0x1f97d, "GOGGLES"),
+	(0x1f97e, "HIKING BOOT"),
+	(0x1f97f, "FLAT SHOE"),
+	(0x1f980, "CRAB"),
+	(0x1f981, "LION FACE"),
+	(0x1f982, "SCORPION"),
+	(0x1f983, "TURKEY"),
+	(0x1f984, "UNICORN FACE"),
+	(0x1f985, "EAGLE"),
+	(0x1f986, "DUCK"),
+	(0x1f987, "BAT"),
+	(0x1f988, "SHARK"),
+	(0x1f989, "OWL"),
+	(0x1f98a, "FOX FACE"),
+	(0x1f98b, "BUTTERFLY"),
+	(0x1f98c, "DEER"),
+	(0x1f98d, "GORILLA"),
+	(0x1f98e, "LIZARD"),
+	(0x1f98f, "RHINOCEROS"),
+	(0x1f990, "SHRIMP"),
+	(0x1f991, "SQUID"),
+	(0x1f992, "GIRAFFE FACE"),
+	(0x1f993, "ZEBRA FACE"),
+	(0x1f994, "HEDGEHOG"),
+	(0x1f995, "SAUROPOD"),
+	(0x1f996, "T-REX"),
+	(0x1f997, "CRICKET"),
+	(0x1f998, "KANGAROO"),
+	(0x1f999, "LLAMA"),
+	(0x1f99a, "PEACOCK"),
+	(0x1f99b, "HIPPOPOTAMUS"),
+	(0x1f99c, "PARROT"),
+	(0x1f99d, "RACCOON"),
+	(0x1f99e, "LOBSTER"),
+	(0x1f99f, "MOSQUITO"),
+	(0x1f9a0, "MICROBE"),
+	(0x1f9a1, "BADGER"),
+	(0x1f9a2, "SWAN"),
+	(0x1f9a3, "MAMMOTH"),
+	(0x1f9a4, "DODO"),
+	(0x1f9a5, "SLOTH"),
+	(0x1f9a6, "OTTER"),
+	(0x1f9a7, "ORANGUTAN"),
+	(0x1f9a8, "SKUNK"),
+	(0x1f9a9, "FLAMINGO"),
+	(0x1f9aa, "OYSTER"),
+	(0x1f9ab, "BEAVER"),
+	(0x1f9ac, "BISON"),
+	(0x1f9ad, "SEAL"),
+	(0x1f9ae, "GUIDE DOG"),
+	(0x1f9af, "PROBING CANE"),
+	(0x1f9b0, "EMOJI COMPONENT RED HAIR"),
+	(0x1f9b1, "EMOJI COMPONENT CURLY HAIR"),
+	(0x1f9b2, "EMOJI COMPONENT BALD"),
+	(0x1f9b3, "EMOJI COMPONENT WHITE HAIR"),
+	(0x1f9b4, "BONE"),
+	(0x1f9b5, "LEG"),
+	(0x1f9b6, "FOOT"),
+	(0x1f9b7, "TOOTH"),
+	(0x1f9b8, "SUPERHERO"),
+	(0x1f9b9, "SUPERVILLAIN"),
+	(0x1f9ba, "SAFETY VEST"),
+	(0x1f9bb, "EAR WITH HEARING AID"),
+	(0x1f9bc, "MOTORIZED WHEELCHAIR"),
+	(0x1f9bd, "MANUAL WHEELCHAIR"),
+	(0x1f9be, "MECHANICAL ARM"),
+	(0x1f9bf, "MECHANICAL LEG"),
+	(0x1f9c0, "CHEESE WEDGE"),
+	(0x1f9c1, "CUPCAKE"),
+	(0x1f9c2, "SALT SHAKER"),
+	(0x1f9c3, "BEVERAGE BOX"),
+	(0x1f9c4, "GARLIC"),
+	(0x1f9c5, "ONION"),
+	(0x1f9c6, "FALAFEL"),
+	(0x1f9c7, "WAFFLE"),
+	(0x1f9c8, "BUTTER"),
+	(0x1f9c9, "MATE DRINK"),
+	(0x1f9ca, "ICE CUBE"),
+	(0x1f9cb, "BUBBLE TEA"),
+	(0x1f9cc, "TROLL"),
+	(0x1f9cd, "STANDING PERSON"),
+	(0x1f9ce, "KNEELING PERSON"),
+	(0x1f9cf, "DEAF PERSON"),
+	(0x1f9d0, "FACE WITH MONOCLE"),
+	(0x1f9d1, "ADULT"),
+	(0x1f9d2, "CHILD"),
+	(0x1f9d3, "OLDER ADULT"),
+	(0x1f9d4, "BEARDED PERSON"),
+	(0x1f9d5, "PERSON WITH HEADSCARF"),
+	(0x1f9d6, "PERSON IN STEAMY ROOM"),
+	(0x1f9d7, "PERSON CLIMBING"),
+	(0x1f9d8, "PERSON IN LOTUS POSITION"),
+	(0x1f9d9, "MAGE"),
+	(0x1f9da, "FAIRY"),
+	(0x1f9db, "VAMPIRE"),
+	(0x1f9dc, "MERPERSON"),
+	(0x1f9dd, "ELF"),
+	(0x1f9de, "GENIE"),
+	(0x1f9df, "ZOMBIE"),
+	(0x1f9e0, "BRAIN"),
+	(0x1f9e1, "ORANGE HEART"),
+	(0x1f9e2, "BILLED CAP"),
+	(0x1f9e3, "SCARF"),
+	(0x1f9e4, "GLOVES"),
+	(0x1f9e5, "COAT"),
+	(0x1f9e6, "SOCKS"),
+	(0x1f9e7, "RED GIFT ENVELOPE"),
+	(0x1f9e8, "FIRECRACKER"),
+	(0x1f9e9, "JIGSAW PUZZLE PIECE"),
+	(0x1f9ea, "TEST TUBE"),
+	(0x1f9eb, "PETRI DISH"),
+	(0x1f9ec, "DNA DOUBLE HELIX"),
+	(0x1f9ed, "COMPASS"),
+	(0x1f9ee, "ABACUS"),
+	(0x1f9ef, "FIRE EXTINGUISHER"),
+	(0x1f9f0, "TOOLBOX"),
+	(0x1f9f1, "BRICK"),
+	(0x1f9f2, "MAGNET"),
+	(0x1f9f3, "LUGGAGE"),
+	(0x1f9f4, "LOTION BOTTLE"),
+	(0x1f9f5, "SPOOL OF THREAD"),
+	(0x1f9f6, "BALL OF YARN"),
+	(0x1f9f7, "SAFETY PIN"),
+	(0x1f9f8, "TEDDY BEAR"),
+	(0x1f9f9, "BROOM"),
+	(0x1f9fa, "BASKET"),
+	(0x1f9fb, "ROLL OF PAPER"),
+	(0x1f9fc, "BAR OF SOAP"),
+	(0x1f9fd, "SPONGE"),
+	(0x1f9fe, "RECEIPT"),
+	(0x1f9ff, "NAZAR AMULET"),
+	(0x1fa00, "NEUTRAL CHESS KING"),
+	(0x1fa01, "NEUTRAL CHESS QUEEN"),
+	(0x1fa02, "NEUTRAL CHESS ROOK"),
+	(0x1fa03, "NEUTRAL CHESS BISHOP"),
+	(0x1fa04, "NEUTRAL CHESS KNIGHT"),
+	(0x1fa05, "NEUTRAL CHESS PAWN"),
+	(0x1fa06, "WHITE CHESS KNIGHT ROTATED FORTY-FIVE DEGREES"),
+	(0x1fa07, "BLACK CHESS KNIGHT ROTATED FORTY-FIVE DEGREES"),
+	(0x1fa08, "NEUTRAL CHESS KNIGHT ROTATED FORTY-FIVE DEGREES"),
+	(0x1fa09, "WHITE CHESS KING ROTATED NINETY DEGREES"),
+	(0x1fa0a, "WHITE CHESS QUEEN ROTATED NINETY DEGREES"),
+	(0x1fa0b, "WHITE CHESS ROOK ROTATED NINETY DEGREES"),
+	(0x1fa0c, "WHITE CHESS BISHOP ROTATED NINETY DEGREES"),
+	(0x1fa0d, "WHITE CHESS KNIGHT ROTATED NINETY DEGREES"),
+	(0x1fa0e, "WHITE CHESS PAWN ROTATED NINETY DEGREES"),
+	(0x1fa0f, "BLACK CHESS KING ROTATED NINETY DEGREES"),
+	(0x1fa10, "BLACK CHESS QUEEN ROTATED NINETY DEGREES"),
+	(0x1fa11, "BLACK CHESS ROOK ROTATED NINETY DEGREES"),
+	(0x1fa12, "BLACK CHESS BISHOP ROTATED NINETY DEGREES"),
+	(0x1fa13, "BLACK CHESS KNIGHT ROTATED NINETY DEGREES"),
+	(0x1fa14, "BLACK CHESS PAWN ROTATED NINETY DEGREES"),
+	(0x1fa15, "NEUTRAL CHESS KING ROTATED NINETY DEGREES"),
+	(0x1fa16, "NEUTRAL CHESS QUEEN ROTATED NINETY DEGREES"),
+	(0x1fa17, "NEUTRAL CHESS ROOK ROTATED NINETY DEGREES"),
+	(0x1fa18, "NEUTRAL CHESS BISHOP ROTATED NINETY DEGREES"),
+	(0x1fa19, "NEUTRAL CHESS KNIGHT ROTATED NINETY DEGREES"),
+	(0x1fa1a, "NEUTRAL CHESS PAWN ROTATED NINETY DEGREES"),
+	(0x1fa1b, "WHITE CHESS KNIGHT ROTATED ONE HUNDRED THIRTY-FIVE DEGREES"),
+	(0x1fa1c, "BLACK CHESS KNIGHT ROTATED ONE HUNDRED THIRTY-FIVE DEGREES"),
+	(0x1fa1d, "NEUTRAL CHESS KNIGHT ROTATED ONE HUNDRED THIRTY-FIVE DEGREES"),
+	(0x1fa1e, "WHITE CHESS TURNED KING"),
+	(0x1fa1f, "WHITE CHESS TURNED QUEEN"),
+	(0x1fa20, "WHITE CHESS TURNED ROOK"),
+	(0x1fa21, "WHITE CHESS TURNED BISHOP"),
+	(0x1fa22, "WHITE CHESS TURNED KNIGHT"),
+	(0x1fa23, "WHITE CHESS TURNED PAWN"),
+	(0x1fa24, "BLACK CHESS TURNED KING"),
+	(0x1fa25, "BLACK CHESS TURNED QUEEN"),
+	(0x1fa26, "BLACK CHESS TURNED ROOK"),
+	(0x1fa27, "BLACK CHESS TURNED BISHOP"),
+	(0x1fa28, "BLACK CHESS TURNED KNIGHT"),
+	(0x1fa29, "BLACK CHESS TURNED PAWN"),
+	(0x1fa2a, "NEUTRAL CHESS TURNED KING"),
+	(0x1fa2b, "NEUTRAL CHESS TURNED QUEEN"),
+	(0x1fa2c, "NEUTRAL CHESS TURNED ROOK"),
+	(0x1fa2d, "NEUTRAL CHESS TURNED BISHOP"),
+	(0x1fa2e, "NEUTRAL CHESS TURNED KNIGHT"),
+	(0x1fa2f, "NEUTRAL CHESS TURNED PAWN"),
+	(0x1fa30, "WHITE CHESS KNIGHT ROTATED TWO HUNDRED TWENTY-FIVE DEGREES"),
+	(0x1fa31, "BLACK CHESS KNIGHT ROTATED TWO HUNDRED TWENTY-FIVE DEGREES"),
+	(0x1fa32, "NEUTRAL CHESS KNIGHT ROTATED TWO HUNDRED TWENTY-FIVE DEGREES"),
+	(0x1fa33, "WHITE CHESS KING ROTATED TWO HUNDRED SEVENTY DEGREES"),
+	(0x1fa34, "WHITE CHESS QUEEN ROTATED TWO HUNDRED SEVENTY DEGREES"),
+	(0x1fa35, "WHITE CHESS ROOK ROTATED TWO HUNDRED SEVENTY DEGREES"),
+	(0x1fa36, "WHITE CHESS BISHOP ROTATED TWO HUNDRED SEVENTY DEGREES"),
+	(0x1fa37, "WHITE CHESS KNIGHT ROTATED TWO HUNDRED SEVENTY DEGREES"),
+	(0x1fa38, "WHITE CHESS PAWN ROTATED TWO HUNDRED SEVENTY DEGREES"),
+	(0x1fa39, "BLACK CHESS KING ROTATED TWO HUNDRED SEVENTY DEGREES"),
+	(0x1fa3a, "BLACK CHESS QUEEN ROTATED TWO HUNDRED SEVENTY DEGREES"),
+	(0x1fa3b, "BLACK CHESS ROOK ROTATED TWO HUNDRED SEVENTY DEGREES"),
+	(0x1fa3c, "BLACK CHESS BISHOP ROTATED TWO HUNDRED SEVENTY DEGREES"),
+	(0x1fa3d, "BLACK CHESS KNIGHT ROTATED TWO HUNDRED SEVENTY DEGREES"),
+	(0x1fa3e, "BLACK CHESS PAWN ROTATED TWO HUNDRED SEVENTY DEGREES"),
+	(0x1fa3f, "NEUTRAL CHESS KING ROTATED TWO HUNDRED SEVENTY DEGREES"),
+	(0x1fa40, "NEUTRAL CHESS QUEEN ROTATED TWO HUNDRED SEVENTY DEGREES"),
+	(0x1fa41, "NEUTRAL CHESS ROOK ROTATED TWO HUNDRED SEVENTY DEGREES"),
+	(0x1fa42, "NEUTRAL CHESS BISHOP ROTATED TWO HUNDRED SEVENTY DEGREES"),
+	(0x1fa43, "NEUTRAL CHESS KNIGHT ROTATED TWO HUNDRED SEVENTY DEGREES"),
+	(0x1fa44, "NEUTRAL CHESS PAWN ROTATED TWO HUNDRED SEVENTY DEGREES"),
+	(0x1fa45, "WHITE CHESS KNIGHT ROTATED THREE HUNDRED FIFTEEN DEGREES"),
+	(0x1fa46, "BLACK CHESS KNIGHT ROTATED THREE HUNDRED FIFTEEN DEGREES"),
+	(0x1fa47, "NEUTRAL CHESS KNIGHT ROTATED THREE HUNDRED FIFTEEN DEGREES"),
+	(0x1fa48, "WHITE CHESS EQUIHOPPER"),
+	(0x1fa49, "BLACK CHESS EQUIHOPPER"),
+	(0x1fa4a, "NEUTRAL CHESS EQUIHOPPER"),
+	(0x1fa4b, "WHITE CHESS EQUIHOPPER ROTATED NINETY DEGREES"),
+	(0x1fa4c, "BLACK CHESS EQUIHOPPER ROTATED NINETY DEGREES"),
+	(0x1fa4d, "NEUTRAL CHESS EQUIHOPPER ROTATED NINETY DEGREES"),
+	(0x1fa4e, "WHITE CHESS KNIGHT-QUEEN"),
+	(0x1fa4f, "WHITE CHESS KNIGHT-ROOK"),
+	(0x1fa50, "WHITE CHESS KNIGHT-BISHOP"),
+	(0x1fa51, "BLACK CHESS KNIGHT-QUEEN"),
+	(0x1fa52, "BLACK CHESS KNIGHT-ROOK"),
+	(0x1fa53, "BLACK CHESS KNIGHT-BISHOP"),
+	(0x1fa60, "XIANGQI RED GENERAL"),
+	(0x1fa61, "XIANGQI RED MANDARIN"),
+	(0x1fa62, "XIANGQI RED ELEPHANT"),
+	(0x1fa63, "XIANGQI RED HORSE"),
+	(0x1fa64, "XIANGQI RED CHARIOT"),
+	(0x1fa65, "XIANGQI RED CANNON"),
+	(0x1fa66, "XIANGQI RED SOLDIER"),
+	(0x1fa67, "XIANGQI BLACK GENERAL"),
+	(0x1fa68, "XIANGQI BLACK MANDARIN"),
+	(0x1fa69, "XIANGQI BLACK ELEPHANT"),
+	(0x1fa6a, "XIANGQI BLACK HORSE"),
+	(0x1fa6b, "XIANGQI BLACK CHARIOT"),
+	(0x1fa6c, "XIANGQI BLACK CANNON"),
+	(0x1fa6d, "XIANGQI BLACK SOLDIER"),
+	(0x1fa70, "BALLET SHOES"),
+	(0x1fa71, "ONE-PIECE SWIMSUIT"),
+	(0x1fa72, "BRIEFS"),
+	(0x1fa73, "SHORTS"),
+	(0x1fa74, "THONG SANDAL"),
+	(0x1fa78, "DROP OF BLOOD"),
+	(0x1fa79, "ADHESIVE BANDAGE"),
+	(0x1fa7a, "STETHOSCOPE"),
+	(0x1fa7b, "X-RAY"),
+	(0x1fa7c, "CRUTCH"),
+	(0x1fa80, "YO-YO"),
+	(0x1fa81, "KITE"),
+	(0x1fa82, "PARACHUTE"),
+	(0x1fa83, "BOOMERANG"),
+	(0x1fa84, "MAGIC WAND"),
+	(0x1fa85, "PINATA"),
+	(0x1fa86, "NESTING DOLLS"),
+	(0x1fa90, "RINGED PLANET"),
+	(0x1fa91, "CHAIR"),
+	(0x1fa92, "RAZOR"),
+	(0x1fa93, "AXE"),
+	(0x1fa94, "DIYA LAMP"),
+	(0x1fa95, "BANJO"),
+	(0x1fa96, "MILITARY HELMET"),
+	(0x1fa97, "ACCORDION"),
+	(0x1fa98, "LONG DRUM"),
+	(0x1fa99, "COIN"),
+	(0x1fa9a, "CARPENTRY SAW"),
+	(0x1fa9b, "SCREWDRIVER"),
+	(0x1fa9c, "LADDER"),
+	(0x1fa9d, "HOOK"),
+	(0x1fa9e, "MIRROR"),
+	(0x1fa9f, "WINDOW"),
+	(0x1faa0, "PLUNGER"),
+	(0x1faa1, "SEWING NEEDLE"),
+	(0x1faa2, "KNOT"),
+	(0x1faa3, "BUCKET"),
+	(0x1faa4, "MOUSE TRAP"),
+	(0x1faa5, "TOOTHBRUSH"),
+	(0x1faa6, "HEADSTONE"),
+	(0x1faa7, "PLACARD"),
+	(0x1faa8, "ROCK"),
+	(0x1faa9, "MIRROR BALL"),
+	(0x1faaa, "IDENTIFICATION CARD"),
+	(0x1faab, "LOW BATTERY"),
+	(0x1faac, "HAMSA"),
+	(0x1fab0, "FLY"),
+	(0x1fab1, "WORM"),
+	(0x1fab2, "BEETLE"),
+	(0x1fab3, "COCKROACH"),
+	(0x1fab4, "POTTED PLANT"),
+	(0x1fab5, "WOOD"),
+	(0x1fab6, "FEATHER"),
+	(0x1fab7, "LOTUS"),
+	(0x1fab8, "CORAL"),
+	(0x1fab9, "EMPTY NEST"),
+	(0x1faba, "NEST WITH EGGS"),
+	(0x1fac0, "ANATOMICAL HEART"),
+	(0x1fac1, "LUNGS"),
+	(0x1fac2, "PEOPLE HUGGING"),
+	(0x1fac3, "PREGNANT MAN"),
+	(0x1fac4, "PREGNANT PERSON"),
+	(0x1fac5, "PERSON WITH CROWN"),
+	(0x1fad0, "BLUEBERRIES"),
+	(0x1fad1, "BELL PEPPER"),
+	(0x1fad2, "OLIVE"),
+	(0x1fad3, "FLATBREAD"),
+	(0x1fad4, "TAMALE"),
+	(0x1fad5, "FONDUE"),
+	(0x1fad6, "TEAPOT"),
+	(0x1fad7, "POURING LIQUID"),
+	(0x1fad8, "BEANS"),
+	(0x1fad9, "JAR"),
+	(0x1fae0, "MELTING FACE"),
+	(0x1fae1, "SALUTING FACE"),
+	(0x1fae2, "FACE WITH OPEN EYES AND HAND OVER MOUTH"),
+	(0x1fae3, "FACE WITH PEEKING EYE"),
+	(0x1fae4, "FACE WITH DIAGONAL MOUTH"),
+	(0x1fae5, "DOTTED LINE FACE"),
+	(0x1fae6, "BITING LIP"),
+	(0x1fae7, "BUBBLES"),
+	(0x1faf0, "HAND WITH INDEX FINGER AND THUMB CROSSED"),
+	(0x1faf1, "RIGHTWARDS HAND"),
+	(0x1faf2, "LEFTWARDS HAND"),
+	(0x1faf3, "PALM DOWN HAND"),
+	(0x1faf4, "PALM UP HAND"),
+	(0x1faf5, "INDEX POINTING AT THE VIEWER"),
+	(0x1faf6, "HEART HANDS"),
+];
@@ -0,0 +1,1716 @@
+// This file is generated by `scripts/unicode.py` from the Unicode Character Database,
+// version 14.0.0. Do not edit it manually.
+
+/// Numeric value of the code points with an integer numeric value.
+pub(crate) const NUMERIC: &[(u32, u32, i64)] = &[
+	(0x30, 0x30, 0),
+	(0x31, 0x31, 1),
+	(0x32, 0x32, 2),
+	(0x33, 0x33, 3),
+	(0x34, 0x34, 4),
+	(0x35, 0x35, 5),
+	(0x36, 0x36, 6),
+	(0x37, 0x37, 7),
+	(0x38, 0x38, 8),
+	(0x39, 0x39, 9),
+	(0xb2, 0xb2, 2),
+	(0xb3, 0xb3, 3),
+	(0xb9, 0xb9, 1),
+	(0x660, 0x660, 0),
+	(0x661, 0x661, 1),
+	(0x662, 0x662, 2),
+	(0x663, 0x663, 3),
+	(0x664, 0x664, 4),
+	(0x665, 0x665, 5),
+	(0x666, 0x666, 6),
+	(0x667, 0x667, 7),
+	(0x668, 0x668, 8),
+	(0x669, 0x669, 9),
+	(0x6f0, 0x6f0, 0),
+	(0x6f1, 0x6f1, 1),
+	(0x6f2, 0x6f2, 2),
+	(0x6f3, 0x6f3, 3),
+	(0x6f4, 0x6f4, 4),
+	(0x6f5, 0x6f5, 5),
+	(0x6f6, 0x6f6, 6),
+	(0x6f7, 0x6f7, 7),
+	(0x6f8, 0x6f8, 8),
+	(0x6f9, 0x6f9, 9),
+	(0x7c0, 0x7c0, 0),
+	(0x7c1, 0x7c1, 1),
+	(0x7c2, 0x7c2, 2),
+	(0x7c3, 0x7c3, 3),
+	(0x7c4, 0x7c4, 4),
+	(0x7c5, 0x7c5, 5),
+	(0x7c6, 0x7c6, 6),
+	(0x7c7, 0x7c7, 7),
+	(0x7c8, 0x7c8, 8),
+	(0x7c9, 0x7c9, 9),
+	(0x966, 0x966, 0),
+	(0x967, 0x967, 1),
+	(0x968, 0x968, 2),
+	(0x969, 0x969, 3),
+	(0x96a, 0x96a, 4),
+	(0x96b, 0x96b, 5),
+	(0x96c, 0x96c, 6),
+	(0x96d, 0x96d, 7),
+	(0x96e, 0x96e, 8),
+	(0x96f, 0x96f, 9),
+	(0x9e6, 0x9e6, 0),
+	(0x9e7, 0x9e7, 1),
+	(0x9e8, 0x9e8, 2),
+	(0x9e9, 0x9e9, 3),
+	(0x9ea, 0x9ea, 4),
+	(0x9eb, 0x9eb, 5),
+	(0x9ec, 0x9ec, 6),
+	(0x9ed, 0x9ed, 7),
+	(0x9ee, 0x9ee, 8),
+	(0x9ef, 0x9ef, 9),
+	(0x9f9, 0x9f9, 16),
+	(0xa66, 0xa66, 0),
+	(0xa67, 0xa67, 1),
+	(0xa68, 0xa68, 2),
+	(0xa69, 0xa69, 3),
+	(0xa6a, 0xa6a, 4),
+	(0xa6b, 0xa6b, 5),
+	(0xa6c, 0xa6c, 6),
+	(0xa6d, 0xa6d, 7),
+	(0xa6e, 0xa6e, 8),
+	(0xa6f, 0xa6f, 9),
+	(0xae6, 0xae6, 0),
+	(0xae7, 0xae7, 1),
+	(0xae8, 0xae8, 2),
+	(0xae9, 0xae9, 3),
+	(0xaea, 0xaea, 4),
+	(0xaeb, 0xaeb, 5),
+	(0xaec, 0xaec, 6),
+	(0xaed, 0xaed, 7),
+	(0xaee, 0xaee, 8),
+	(0xaef, 0xaef, 9),
+	(0xb66, 0xb66, 0),
+	(0xb67, 0xb67, 1),
+	(0xb68, 0xb68, 2),
+	(0xb69, 0xb69, 3),
+	(0xb6a, 0xb6a, 4),
+	(0xb6b, 0xb6b, 5),
+	(0xb6c, 0xb6c, 6),
+	(0xb6d, 0xb6d, 7),
+	(0xb6e, 0xb6e, 8),
+	(0xb6f, 0xb6f, 9),
+	(0xbe6, 0xbe6, 0),
+	(0xbe7, 0xbe7, 1),
+	(0xbe8, 0xbe8, 2),
+	(0xbe9, 0xbe9, 3),
+	(0xbea, 0xbea, 4),
+	(0xbeb, 0xbeb, 5),
+	(0xbec, 0xbec, 6),
+	(0xbed, 0xbed, 7),
+	(0xbee, 0xbee, 8),
+	(0xbef, 0xbef, 9),
+	(0xbf0, 0xbf0, 10),
+	(0xbf1, 0xbf1, 100),
+	(0xbf2, 0xbf2, 1000),
+	(0xc66, 0xc66, 0),
+	(0xc67, 0xc67, 1),
+	(0xc68, 0xc68, 2),
+	(0xc69, 0xc69, 3),
+	(0xc6a, 0xc6a, 4),
+	(0xc6b, 0xc6b, 5),
+	(0xc6c, 0xc6c, 6),
+	(0xc6d, 0xc6d, 7),
+	(0xc6e, 0xc6e, 8),
+	(0xc6f, 0xc6f, 9),
+	(0xc78, 0xc78, 0),
+	(0xc79, 0xc79, 1),
+	(0xc7a, 0xc7a, 2),
+	(0xc7b, 0xc7b, 3),
+	(0xc7c, 0xc7c, 1),
+	(0xc7d, 0xc7d, 2),
+	(0xc7e, 0xc7e, 3),
+	(0xce6, 0xce6, 0),
+	(0xce7, 0xce7, 1),
+	(0xce8, 0xce8, 2),
+	(0xce9, 0xce9, 3),
+	(0xcea, 0xcea, 4),
+	(0xceb, 0xceb, 5),
+	(0xcec, 0xcec, 6),
+	(0xced, 0xced, 7),
+	(0xcee, 0xcee, 8),
+	(0xcef, 0xcef, 9),
+	(0xd66, 0xd66, 0),
+	(0xd67, 0xd67, 1),
+	(0xd68, 0xd68, 2),
+	(0xd69, 0xd69, 3),
+	(0xd6a, 0xd6a, 4),
+	(0xd6b, 0xd6b, 5),
+	(0xd6c, 0xd6c, 6),
+	(0xd6d, 0xd6d, 7),
+	(0xd6e, 0xd6e, 8),
+	(0xd6f, 0xd6f, 9),
+	(0xd70, 0xd70, 10),
+	(0xd71, 0xd71, 100),
+	(0xd72, 0xd72, 1000),
+	(0xde6, 0xde6, 0),
+	(0xde7, 0xde7, 1),
+	(0xde8, 0xde8, 2),
+	(0xde9, 0xde9, 3),
+	(0xdea, 0xdea, 4),
+	(0xdeb, 0xdeb, 5),
+	(0xdec, 0xdec, 6),
+	(0xded, 0xded, 7),
+	(0xdee, 0xdee, 8),
+	(0xdef, 0xdef, 9),
+	(0xe50, 0xe50, 0),
+	(0xe51, 0xe51, 1),
+	(0xe52, 0xe52, 2),
+	(0xe53, 0xe53, 3),
+	(0xe54, 0xe54, 4),
+	(0xe55, 0xe55, 5),
+	(0xe56, 0xe56, 6),
+	(0xe57, 0xe57, 7),
+	(0xe58, 0xe58, 8),
+	(0xe59, 0xe59, 9),
+	(0xed0, 0xed0, 0),
+	(0xed1, 0xed1, 1),
+	(0xed2, 0xed2, 2),
+	(0xed3, 0xed3, 3),
+	(0xed4, 0xed4, 4),
+	(0xed5, 0xed5, 5),
+	(0xed6, 0xed6, 6),
+	(0xed7, 0xed7, 7),
+	(0xed8, 0xed8, 8),
+	(0xed9, 0xed9, 9),
+	(0xf20, 0xf20, 0),
+	(0xf21, 0xf21, 1),
+	(0xf22, 0xf22, 2),
+	(0xf23, 0xf23, 3),
+	(0xf24, 0xf24, 4),
+	(0xf25, 0xf25, 5),
+	(0xf26, 0xf26, 6),
+	(0xf27, 0xf27, 7),
+	(0xf28, 0xf28, 8),
+	(0xf29, 0xf29, 9),
+	(0x1040, 0x1040, 0),
+	(0x1041, 0x1041, 1),
+	(0x1042, 0x1042, 2),
+	(0x1043, 0x1043, 3),
+	(0x1044, 0x1044, 4),
+	(0x1045, 0x1045, 5),
+	(0x1046, 0x1046, 6),
+	(0x1047, 0x1047, 7),
+	(0x1048, 0x1048, 8),
+	(0x1049, 0x1049, 9),
+	(0x1090, 0x1090, 0),
+	(0x1091, 0x1091, 1),
+	(0x1092, 0x1092, 2),
+	(0x1093, 0x1093, 3),
+	(0x1094, 0x1094, 4),
+	(0x1095, 0x1095, 5),
+	(0x1096, 0x1096, 6),
+	(0x1097, 0x1097, 7),
+	(0x1098, 0x1098, 8),
+	(0x1099, 0x1099, 9),
+	(0x1369, 0x1369, 1),
+	(0x136a, 0x136a, 2),
+	(0x136b, 0x136b, 3),
+	(0x136c, 0x136c, 4),
+	(0x136d, 0x136d, 5),
+	(0x136e, 0x136e, 6),
+	(0x136f, 0x136f, 7),
+	(0x1370, 0x1370, 8),
+	(0x1371, 0x1371, 9),
+	(0x1372, 0x1372, 10),
+	(0x1373, 0x1373, 20),
+	(0x1374, 0x1374, 30),
+	(0x1375, 0x1375, 40),
+	(0x1376, 0x1376, 50),
+	(0x1377, 0x1377, 60),
+	(0x1378, 0x1378, 70),
+	(0x1379, 0x1379, 80),
+	(0x137a, 0x137a, 90),
+	(0x137b, 0x137b, 100),
+	(0x137c, 0x137c, 10000),
+	(0x16ee, 0x16ee, 17),
+	(0x16ef, 0x16ef, 18),
+	(0x16f0, 0x16f0, 19),
+	(0x17e0, 0x17e0, 0),
+	(0x17e1, 0x17e1, 1),
+	(0x17e2, 0x17e2, 2),
+	(0x17e3, 0x17e3, 3),
+	(0x17e4, 0x17e4, 4),
+	(0x17e5, 0x17e5, 5),
+	(0x17e6, 0x17e6, 6),
+	(0x17e7, 0x17e7, 7),
+	(0x17e8, 0x17e8, 8),
+	(0x17e9, 0x17e9, 9),
+	(0x17f0, 0x17f0, 0),
+	(0x17f1, 0x17f1, 1),
+	(0x17f2, 0x17f2, 2),
+	(0x17f3, 0x17f3, 3),
+	(0x17f4, 0x17f4, 4),
+	(0x17f5, 0x17f5, 5),
+	(0x17f6, 0x17f6, 6),
+	(0x17f7, 0x17f7, 7),
+	(0x17f8, 0x17f8, 8),
+	(0x17f9, 0x17f9, 9),
+	(0x1810, 0x1810, 0),
+	(0x1811, 0x1811, 1),
+	(0x1812, 0x1812, 2),
+	(0x1813, 0x1813, 3),
+	(0x1814, 0x1814, 4),
+	(0x1815, 0x1815, 5),
+	(0x1816, 0x1816, 6),
+	(0x1817, 0x1817, 7),
+	(0x1818, 0x1818, 8),
+	(0x1819, 0x1819, 9),
+	(0x1946, 0x1946, 0),
+	(0x1947, 0x1947, 1),
+	(0x1948, 0x1948, 2),
+	(0x1949, 0x1949, 3),
+	(0x194a, 0x194a, 4),
+	(0x194b, 0x194b, 5),
+	(0x194c, 0x194c, 6),
+	(0x194d, 0x194d, 7),
+	(0x194e, 0x194e, 8),
+	(0x194f, 0x194f, 9),
+	(0x19d0, 0x19d0, 0),
+	(0x19d1, 0x19d1, 1),
+	(0x19d2, 0x19d2, 2),
+	(0x19d3, 0x19d3, 3),
+	(0x19d4, 0x19d4, 4),
+	(0x19d5, 0x19d5, 5),
+	(0x19d6, 0x19d6, 6),
+	(0x19d7, 0x19d7, 7),
+	(0x19d8, 0x19d8, 8),
+	(0x19d9, 0x19d9, 9),
+	(0x19da, 0x19da, 1),
+	(0x1a80, 0x1a80, 0),
+	(0x1a81, 0x1a81, 1),
+	(0x1a82, 0x1a82, 2),
+	(0x1a83, 0x1a83, 3),
+	(0x1a84, 0x1a84, 4),
+	(0x1a85, 0x1a85, 5),
+	(0x1a86, 0x1a86, 6),
+	(0x1a87, 0x1a87, 7),
+	(0x1a88, 0x1a88, 8),
+	(0x1a89, 0x1a89, 9),
+	(0x1a90, 0x1a90, 0),
+	(0x1a91, 0x1a91, 1),
+	(0x1a92, 0x1a92, 2),
+	(0x1a93, 0x1a93, 3),
+	(0x1a94, 0x1a94, 4),
+	(0x1a95, 0x1a95, 5),
+	(0x1a96, 0x1a96, 6),
+	(0x1a97, 0x1a97, 7),
+	(0x1a98, 0x1a98, 8),
+	(0x1a99, 0x1a99, 9),
+	(0x1b50, 0x1b50, 0),
+	(0x1b51, 0x1b51, 1),
+	(0x1b52, 0x1b52, 2),
+	(0x1b53, 0x1b53, 3),
+	(0x1b54, 0x1b54, 4),
+	(0x1b55, 0x1b55, 5),
+	(0x1b56, 0x1b56, 6),
+	(0x1b57, 0x1b57, 7),
+	(0x1b58, 0x1b58, 8),
+	(0x1b59, 0x1b59, 9),
+	(0x1bb0, 0x1bb0, 0),
+	(0x1bb1, 0x1bb1, 1),
+	(0x1bb2, 0x1bb2, 2),
+	(0x1bb3, 0x1bb3, 3),
+	(0x1bb4, 0x1bb4, 4),
+	(0x1bb5, 0x1bb5, 5),
+	(0x1bb6, 0x1bb6, 6),
+	(0x1bb7, 0x1bb7, 7),
+	(0x1bb8, 0x1bb8, 8),
+	(0x1bb9, 0x1bb9, 9),
+	(0x1c40, 0x1c40, 0),
+	(0x1c41, 0x1c41, 1),
+	(0x1c42, 0x1c42, 2),
+	(0x1c43, 0x1c43, 3),
+	(0x1c44, 0x1c44, 4),
+	(0x1c45, 0x1c45, 5),
+	(0x1c46, 0x1c46, 6),
+	(0x1c47, 0x1c47, 7),
+	(0x1c48, 0x1c48, 8),
+	(0x1c49, 0x1c49, 9),
+	(0x1c50, 0x1c50, 0),
+	(0x1c51, 0x1c51, 1),
+	(0x1c52, 0x1c52, 2),
+	(0x1c53, 0x1c53, 3),
+	(0x1c54, 0x1c54, 4),
+	(0x1c55, 0x1c55, 5),
+	(0x1c56, 0x1c56, 6),
+	(0x1c57, 0x1c57, 7),
+	(0x1c58, 0x1c58, 8),
+	(0x1c59, 0x1c59, 9),
+	(0x2070, 0x2070, 0),
+	(0x2074, 0x2074, 4),
+	(0x2075, 0x2075, 5),
+	(0x2076, 0x2076, 6),
+	(0x2077, 0x2077, 7),
+	(0x2078, 0x2078, 8),
+	(0x2079, 0x2079, 9),
+	(0x2080, 0x2080, 0),
+	(0x2081, 0x2081, 1),
+	(0x2082, 0x2082, 2),
+	(0x2083, 0x2083, 3),
+	(0x2084, 0x2084, 4),
+	(0x2085, 0x2085, 5),
+	(0x2086, 0x2086, 6),
+	(0x2087, 0x2087, 7),
+	(0x2088, 0x2088, 8),
+	(0x2089, 0x2089, 9),
+	(0x215f, 0x2160, 1),
+	(0x2161, 0x2161, 2),
+	(0x2162, 0x2162, 3),
+	(0x2163, 0x2163, 4),
+	(0x2164, 0x2164, 5),
+	(0x2165, 0x2165, 6),
+	(0x2166, 0x2166, 7),
+	(0x2167, 0x2167, 8),
+	(0x2168, 0x2168, 9),
+	(0x2169, 0x2169, 10),
+	(0x216a, 0x216a, 11),
+	(0x216b, 0x216b, 12),
+	(0x216c, 0x216c, 50),
+	(0x216d, 0x216d, 100),
+	(0x216e, 0x216e, 500),
+	(0x216f, 0x216f, 1000),
+	(0x2170, 0x2170, 1),
+	(0x2171, 0x2171, 2),
+	(0x2172, 0x2172, 3),
+	(0x2173, 0x2173, 4),
+	(0x2174, 0x2174, 5),
+	(0x2175, 0x2175, 6),
+	(0x2176, 0x2176, 7),
+	(0x2177, 0x2177, 8),
+	(0x2178, 0x2178, 9),
+	(0x2179, 0x2179, 10),
+	(0x217a, 0x217a, 11),
+	(0x217b, 0x217b, 12),
+	(0x217c, 0x217c, 50),
+	(0x217d, 0x217d, 100),
+	(0x217e, 0x217e, 500),
+	(0x217f, 0x2180, 1000),
+	(0x2181, 0x2181, 5000),
+	(0x2182, 0x2182, 10000),
+	(0x2185, 0x2185, 6),
+	(0x2186, 0x2186, 50),
+	(0x2187, 0x2187, 50000),
+	(0x2188, 0x2188, 100000),
+	(0x2189, 0x2189, 0),
+	(0x2460, 0x2460, 1),
+	(0x2461, 0x2461, 2),
+	(0x2462, 0x2462, 3),
+	(0x2463, 0x2463, 4),
+	(0x2464, 0x2464, 5),
+	(0x2465, 0x2465, 6),
+	(0x2466, 0x2466, 7),
+	(0x2467, 0x2467, 8),
+	(0x2468, 0x2468, 9),
+	(0x2469, 0x2469, 10),
+	(0x246a, 0x246a, 11),
+	(0x246b, 0x246b, 12),
+	(0x246c, 0x246c, 13),
+	(0x246d, 0x246d, 14),
+	(0x246e, 0x246e, 15),
+	(0x246f, 0x246f, 16),
+	(0x2470, 0x2470, 17),
+	(0x2471, 0x2471, 18),
+	(0x2472, 0x2472, 19),
+	(0x2473, 0x2473, 20),
+	(0x2474, 0x2474, 1),
+	(0x2475, 0x2475, 2),
+	(0x2476, 0x2476, 3),
+	(0x2477, 0x2477, 4),
+	(0x2478, 0x2478, 5),
+	(0x2479, 0x2479, 6),
+	(0x247a, 0x247a, 7),
+	(0x247b, 0x247b, 8),
+	(0x247c, 0x247c, 9),
+	(0x247d, 0x247d, 10),
+	(0x247e, 0x247e, 11),
+	(0x247f, 0x247f, 12),
+	(0x2480, 0x2480, 13),
+	(0x2481, 0x2481, 14),
+	(0x2482, 0x2482, 15),
+	(0x2483, 0x2483, 16),
+	(0x2484, 0x2484, 17),
+	(0x2485, 0x2485, 18),
+	(0x2486, 0x2486, 19),
+	(0x2487, 0x2487, 20),
+	(0x2488, 0x2488, 1),
+	(0x2489, 0x2489, 2),
+	(0x248a, 0x248a, 3),
+	(0x248b, 0x248b, 4),
+	(0x248c, 0x248c, 5),
+	(0x248d, 0x248d, 6),
+	(0x248e, 0x248e, 7),
+	(0x248f, 0x248f, 8),
+	(0x2490, 0x2490, 9),
+	(0x2491, 0x2491, 10),
+	(0x2492, 0x2492, 11),
+	(0x2493, 0x2493, 12),
+	(0x2494, 0x2494, 13),
+	(0x2495, 0x2495, 14),
+	(0x2496, 0x2496, 15),
+	(0x2497, 0x2497, 16),
+	(0x2498, 0x2498, 17),
+	(0x2499, 0x2499, 18),
+	(0x249a, 0x249a, 19),
+	(0x249b, 0x249b, 20),
+	(0x24ea, 0x24ea, 0),
+	(0x24eb, 0x24eb, 11),
+	(0x24ec, 0x24ec, 12),
+	(0x24ed, 0x24ed, 13),
+	(0x24ee, 0x24ee, 14),
+	(0x24ef, 0x24ef, 15),
+	(0x24f0, 0x24f0, 16),
+	(0x24f1, 0x24f1, 17),
+	(0x24f2, 0x24f2, 18),
+	(0x24f3, 0x24f3, 19),
+	(0x24f4, 0x24f4, 20),
+	(0x24f5, 0x24f5, 1),
+	(0x24f6, 0x24f6, 2),
+	(0x24f7, 0x24f7, 3),
+	(0x24f8, 0x24f8, 4),
+	(0x24f9, 0x24f9, 5),
+	(0x24fa, 0x24fa, 6),
+	(0x24fb, 0x24fb, 7),
+	(0x24fc, 0x24fc, 8),
+	(0x24fd, 0x24fd, 9),
+	(0x24fe, 0x24fe, 10),
+	(0x24ff, 0x24ff, 0),
+	(0x2776, 0x2776, 1),
+	(0x2777, 0x2777, 2),
+	(0x2778, 0x2778, 3),
+	(0x2779, 0x2779, 4),
+	(0x277a, 0x277a, 5),
+	(0x277b, 0x277b, 6),
+	(0x277c, 0x277c, 7),
+	(0x277d, 0x277d, 8),
+	(0x277e, 0x277e, 9),
+	(0x277f, 0x277f, 10),
+	(0x2780, 0x2780, 1),
+	(0x2781, 0x2781, 2),
+	(0x2782, 0x2782, 3),
+	(0x2783, 0x2783, 4),
+	(0x2784, 0x2784, 5),
+	(0x2785, 0x2785, 6),
+	(0x2786, 0x2786, 7),
+	(0x2787, 0x2787, 8),
+	(0x2788, 0x2788, 9),
+	(0x2789, 0x2789, 10),
+	(0x278a, 0x278a, 1),
+	(0x278b, 0x278b, 2),
+	(0x278c, 0x278c, 3),
+	(0x278d, 0x278d, 4),
+	(0x278e, 0x278e, 5),
+	(0x278f, 0x278f, 6),
+	(0x2790, 0x2790, 7),
+	(0x2791, 0x2791, 8),
+	(0x2792, 0x2792, 9),
+	(0x2793, 0x2793, 10),
+	(0x3007, 0x3007, 0),
+	(0x3021, 0x3021, 1),
+	(0x3022, 0x3022, 2),
+	(0x3023, 0x3023, 3),
+	(0x3024, 0x3024, 4),
+	(0x3025, 0x3025, 5),
+	(0x3026, 0x3026, 6),
+	(0x3027, 0x3027, 7),
+	(0x3028, 0x3028, 8),
+	(0x3029, 0x3029, 9),
+	(0x3038, 0x3038, 10),
+	(0x3039, 0x3039, 20),
+	(0x303a, 0x303a, 30),
+	(0x3192, 0x3192, 1),
+	(0x3193, 0x3193, 2),
+	(0x3194, 0x3194, 3),
+	(0x3195, 0x3195, 4),
+	(0x3220, 0x3220, 1),
+	(0x3221, 0x3221, 2),
+	(0x3222, 0x3222, 3),
+	(0x3223, 0x3223, 4),
+	(0x3224, 0x3224, 5),
+	(0x3225, 0x3225, 6),
+	(0x3226, 0x3226, 7),
+	(0x3227, 0x3227, 8),
+	(0x3228, 0x3228, 9),
+	(0x3229, 0x3229, 10),
+	(0x3248, 0x3248, 10),
+	(0x3249, 0x3249, 20),
+	(0x324a, 0x324a, 30),
+	(0x324b, 0x324b, 40),
+	(0x324c, 0x324c, 50),
+	(0x324d, 0x324d, 60),
+	(0x324e, 0x324e, 70),
+	(0x324f, 0x324f, 80),
+	(0x3251, 0x3251, 21),
+	(0x3252, 0x3252, 22),
+	(0x3253, 0x3253, 23),
+	(0x3254, 0x3254, 24),
+	(0x3255, 0x3255, 25),
+	(0x3256, 0x3256, 26),
+	(0x3257, 0x3257, 27),
+	(0x3258, 0x3258, 28),
+	(0x3259, 0x3259, 29),
+	(0x325a, 0x325a, 30),
+	(0x325b, 0x325b, 31),
+	(0x325c, 0x325c, 32),
+	(0x325d, 0x325d, 33),
+	(0x325e, 0x325e, 34),
+	(0x325f, 0x325f, 35),
+	(0x3280, 0x3280, 1),
+	(0x3281, 0x3281, 2),
+	(0x3282, 0x3282, 3),
+	(0x3283, 0x3283, 4),
+	(0x3284, 0x3284, 5),
+	(0x3285, 0x3285, 6),
+	(0x3286, 0x3286, 7),
+	(0x3287, 0x3287, 8),
+	(0x3288, 0x3288, 9),
+	(0x3289, 0x3289, 10),
+	(0x32b1, 0x32b1, 36),
+	(0x32b2, 0x32b2, 37),
+	(0x32b3, 0x32b3, 38),
+	(0x32b4, 0x32b4, 39),
+	(0x32b5, 0x32b5, 40),
+	(0x32b6, 0x32b6, 41),
+	(0x32b7, 0x32b7, 42),
+	(0x32b8, 0x32b8, 43),
+	(0x32b9, 0x32b9, 44),
+	(0x32ba, 0x32ba, 45),
+	(0x32bb, 0x32bb, 46),
+	(0x32bc, 0x32bc, 47),
+	(0x32bd, 0x32bd, 48),
+	(0x32be, 0x32be, 49),
+	(0x32bf, 0x32bf, 50),
+	(0x3405, 0x3405, 5),
+	(0x3483, 0x3483, 2),
+	(0x382a, 0x382a, 5),
+	(0x3b4d, 0x3b4d, 7),
+	(0x4e00, 0x4e00, 1),
+	(0x4e03, 0x4e03, 7),
+	(0x4e07, 0x4e07, 10000),
+	(0x4e09, 0x4e09, 3),
+	(0x4e5d, 0x4e5d, 9),
+	(0x4e8c, 0x4e8c, 2),
+	(0x4e94, 0x4e94, 5),
+	(0x4e96, 0x4e96, 4),
+	(0x4ebf, 0x4ebf, 100000000),
+	(0x4ec0, 0x4ec0, 10),
+	(0x4edf, 0x4edf, 1000),
+	(0x4ee8, 0x4ee8, 3),
+	(0x4f0d, 0x4f0d, 5),
+	(0x4f70, 0x4f70, 100),
+	(0x5104, 0x5104, 100000000),
+	(0x5146, 0x5146, 1000000000000),
+	(0x5169, 0x5169, 2),
+	(0x516b, 0x516b, 8),
+	(0x516d, 0x516d, 6),
+	(0x5341, 0x5341, 10),
+	(0x5343, 0x5343, 1000),
+	(0x5344, 0x5344, 20),
+	(0x5345, 0x5345, 30),
+	(0x534c, 0x534c, 40),
+	(0x53c1, 0x53c4, 3),
+	(0x56db, 0x56db, 4),
+	(0x58f1, 0x58f1, 1),
+	(0x58f9, 0x58f9, 1),
+	(0x5e7a, 0x5e7a, 1),
+	(0x5efe, 0x5efe, 9),
+	(0x5eff, 0x5eff, 20),
+	(0x5f0c, 0x5f0c, 1),
+	(0x5f0d, 0x5f0d, 2),
+	(0x5f0e, 0x5f0e, 3),
+	(0x5f10, 0x5f10, 2),
+	(0x62fe, 0x62fe, 10),
+	(0x634c, 0x634c, 8),
+	(0x67d2, 0x67d2, 7),
+	(0x6f06, 0x6f06, 7),
+	(0x7396, 0x7396, 9),
+	(0x767e, 0x767e, 100),
+	(0x8086, 0x8086, 4),
+	(0x842c, 0x842c, 10000),
+	(0x8cae, 0x8cae, 2),
+	(0x8cb3, 0x8cb3, 2),
+	(0x8d30, 0x8d30, 2),
+	(0x9621, 0x9621, 1000),
+	(0x9646, 0x9646, 6),
+	(0x964c, 0x964c, 100),
+	(0x9678, 0x9678, 6),
+	(0x96f6, 0x96f6, 0),
+	(0xa620, 0xa620, 0),
+	(0xa621, 0xa621, 1),
+	(0xa622, 0xa622, 2),
+	(0xa623, 0xa623, 3),
+	(0xa624, 0xa624, 4),
+	(0xa625, 0xa625, 5),
+	(0xa626, 0xa626, 6),
+	(0xa627, 0xa627, 7),
+	(0xa628, 0xa628, 8),
+	(0xa629, 0xa629, 9),
+	(0xa6e6, 0xa6e6, 1),
+	(0xa6e7, 0xa6e7, 2),
+	(0xa6e8, 0xa6e8, 3),
+	(0xa6e9, 0xa6e9, 4),
+	(0xa6ea, 0xa6ea, 5),
+	(0xa6eb, 0xa6eb, 6),
+	(0xa6ec, 0xa6ec, 7),
+	(0xa6ed, 0xa6ed, 8),
+	(0xa6ee, 0xa6ee, 9),
+	(0xa6ef, 0xa6ef, 0),
+	(0xa8d0, 0xa8d0, 0),
+	(0xa8d1, 0xa8d1, 1),
+	(0xa8d2, 0xa8d2, 2),
+	(0xa8d3, 0xa8d3, 3),
+	(0xa8d4, 0xa8d4, 4),
+	(0xa8d5, 0xa8d5, 5),
+	(0xa8d6, 0xa8d6, 6),
+	(0xa8d7, 0xa8d7, 7),
+	(0xa8d8, 0xa8d8, 8),
+	(0xa8d9, 0xa8d9, 9),
+	(0xa900, 0xa900, 0),
+	(0xa901, 0xa901, 1),
+	(0xa902, 0xa902, 2),
+	(0xa903, 0xa903, 3),
+	(0xa904, 0xa904, 4),
+	(0xa905, 0xa905, 5),
+	(0xa906, 0xa906, 6),
+	(0xa907, 0xa907, 7),
+	(0xa908, 0xa908, 8),
+	(0xa909, 0xa909, 9),
+	(0xa9d0, 0xa9d0, 0),
+	(0xa9d1, 0xa9d1, 1),
+	(0xa9d2, 0xa9d2, 2),
+	(0xa9d3, 0xa9d3, 3),
+	(0xa9d4, 0xa9d4, 4),
+	(0xa9d5, 0xa9d5, 5),
+	(0xa9d6, 0xa9d6, 6),
+	(0xa9d7, 0xa9d7, 7),
+	(0xa9d8, 0xa9d8, 8),
+	(0xa9d9, 0xa9d9, 9),
+	(0xa9f0, 0xa9f0, 0),
+	(0xa9f1, 0xa9f1, 1),
+	(0xa9f2, 0xa9f2, 2),
+	(0xa9f3, 0xa9f3, 3),
+	(0xa9f4, 0xa9f4, 4),
+	(0xa9f5, 0xa9f5, 5),
+	(0xa9f6, 0xa9f6, 6),
+	(0xa9f7, 0xa9f7, 7),
+	(0xa9f8, 0xa9f8, 8),
+	(0xa9f9, 0xa9f9, 9),
+	(0xaa50, 0xaa50, 0),
+	(0xaa51, 0xaa51, 1),
+	(0xaa52, 0xaa52, 2),
+	(0xaa53, 0xaa53, 3),
+	(0xaa54, 0xaa54, 4),
+	(0xaa55, 0xaa55, 5),
+	(0xaa56, 0xaa56, 6),
+	(0xaa57, 0xaa57, 7),
+	(0xaa58, 0xaa58, 8),
+	(0xaa59, 0xaa59, 9),
+	(0xabf0, 0xabf0, 0),
+	(0xabf1, 0xabf1, 1),
+	(0xabf2, 0xabf2, 2),
+	(0xabf3, 0xabf3, 3),
+	(0xabf4, 0xabf4, 4),
+	(0xabf5, 0xabf5, 5),
+	(0xabf6, 0xabf6, 6),
+	(0xabf7, 0xabf7, 7),
+	(0xabf8, 0xabf8, 8),
+	(0xabf9, 0xabf9, 9),
+	(0xf96b, 0xf96b, 3),
+	(0xf973, 0xf973, 10),
+	(0xf978, 0xf978, 2),
+	(0xf9b2, 0xf9b2, 0),
+	(0xf9d1, 0xf9d1, 6),
+	(0xf9d3, 0xf9d3, 6),
+	(0xf9fd, 0xf9fd, 10),
+	(0xff10, 0xff10, 0),
+	(0xff11, 0xff11, 1),
+	(0xff12, 0xff12, 2),
+	(0xff13, 0xff13, 3),
+	(0xff14, 0xff14, 4),
+	(0xff15, 0xff15, 5),
+	(0xff16, 0xff16, 6),
+	(0xff17, 0xff17, 7),
+	(0xff18, 0xff18, 8),
+	(0xff19, 0xff19, 9),
+	(0x10107, 0x10107, 1),
+	(0x10108, 0x10108, 2),
+	(0x10109, 0x10109, 3),
+	(0x1010a, 0x1010a, 4),
+	(0x1010b, 0x1010b, 5),
+	(0x1010c, 0x1010c, 6),
+	(0x1010d, 0x1010d, 7),
+	(0x1010e, 0x1010e, 8),
+	(0x1010f, 0x1010f, 9),
+	(0x10110, 0x10110, 10),
+	(0x10111, 0x10111, 20),
+	(0x10112, 0x10112, 30),
+	(0x10113, 0x10113, 40),
+	(0x10114, 0x10114, 50),
+	(0x10115, 0x10115, 60),
+	(0x10116, 0x10116, 70),
+	(0x10117, 0x10117, 80),
+	(0x10118, 0x10118, 90),
+	(0x10119, 0x10119, 100),
+	(0x1011a, 0x1011a, 200),
+	(0x1011b, 0x1011b, 300),
+	(0x1011c, 0x1011c, 400),
+	(0x1011d, 0x1011d, 500),
+	(0x1011e, 0x1011e, 600),
+	(0x1011f, 0x1011f, 700),
+	(0x10120, 0x10120, 800),
+	(0x10121, 0x10121, 900),
+	(0x10122, 0x10122, 1000),
+	(0x10123, 0x10123, 2000),
+	(0x10124, 0x10124, 3000),
+	(0x10125, 0x10125, 4000),
+	(0x10126, 0x10126, 5000),
+	(0x10127, 0x10127, 6000),
+	(0x10128, 0x10128, 7000),
+	(0x10129, 0x10129, 8000),
+	(0x1012a, 0x1012a, 9000),
+	(0x1012b, 0x1012b, 10000),
+	(0x1012c, 0x1012c, 20000),
+	(0x1012d, 0x1012d, 30000),
+	(0x1012e, 0x1012e, 40000),
+	(0x1012f, 0x1012f, 50000),
+	(0x10130, 0x10130, 60000),
+	(0x10131, 0x10131, 70000),
+	(0x10132, 0x10132, 80000),
+	(0x10133, 0x10133, 90000),
+	(0x10142, 0x10142, 1),
+	(0x10143, 0x10143, 5),
+	(0x10144, 0x10144, 50),
+	(0x10145, 0x10145, 500),
+	(0x10146, 0x10146, 5000),
+	(0x10147, 0x10147, 50000),
+	(0x10148, 0x10148, 5),
+	(0x10149, 0x10149, 10),
+	(0x1014a, 0x1014a, 50),
+	(0x1014b, 0x1014b, 100),
+	(0x1014c, 0x1014c, 500),
+	(0x1014d, 0x1014d, 1000),
+	(0x1014e, 0x1014e, 5000),
+	(0x1014f, 0x1014f, 5),
+	(0x10150, 0x10150, 10),
+	(0x10151, 0x10151, 50),
+	(0x10152, 0x10152, 100),
+	(0x10153, 0x10153, 500),
+	(0x10154, 0x10154, 1000),
+	(0x10155, 0x10155, 10000),
+	(0x10156, 0x10156, 50000),
+	(0x10157, 0x10157, 10),
+	(0x10158, 0x1015a, 1),
+	(0x1015b, 0x1015e, 2),
+	(0x1015f, 0x1015f, 5),
+	(0x10160, 0x10164, 10),
+	(0x10165, 0x10165, 30),
+	(0x10166, 0x10169, 50),
+	(0x1016a, 0x1016a, 100),
+	(0x1016b, 0x1016b, 300),
+	(0x1016c, 0x10170, 500),
+	(0x10171, 0x10171, 1000),
+	(0x10172, 0x10172, 5000),
+	(0x10173, 0x10173, 5),
+	(0x10174, 0x10174, 50),
+	(0x1018a, 0x1018a, 0),
+	(0x102e1, 0x102e1, 1),
+	(0x102e2, 0x102e2, 2),
+	(0x102e3, 0x102e3, 3),
+	(0x102e4, 0x102e4, 4),
+	(0x102e5, 0x102e5, 5),
+	(0x102e6, 0x102e6, 6),
+	(0x102e7, 0x102e7, 7),
+	(0x102e8, 0x102e8, 8),
+	(0x102e9, 0x102e9, 9),
+	(0x102ea, 0x102ea, 10),
+	(0x102eb, 0x102eb, 20),
+	(0x102ec, 0x102ec, 30),
+	(0x102ed, 0x102ed, 40),
+	(0x102ee, 0x102ee, 50),
+	(0x102ef, 0x102ef, 60),
+	(0x102f0, 0x102f0, 70),
+	(0x102f1, 0x102f1, 80),
+	(0x102f2, 0x102f2, 90),
+	(0x102f3, 0x102f3, 100),
+	(0x102f4, 0x102f4, 200),
+	(0x102f5, 0x102f5, 300),
+	(0x102f6, 0x102f6, 400),
+	(0x102f7, 0x102f7, 500),
+	(0x102f8, 0x102f8, 600),
+	(0x102f9, 0x102f9, 700),
+	(0x102fa, 0x102fa, 800),
+	(0x102fb, 0x102fb, 900),
+	(0x10320, 0x10320, 1),
+	(0x10321, 0x10321, 5),
+	(0x10322, 0x10322, 10),
+	(0x10323, 0x10323, 50),
+	(0x10341, 0x10341, 90),
+	(0x1034a, 0x1034a, 900),
+	(0x103d1, 0x103d1, 1),
+	(0x103d2, 0x103d2, 2),
+	(0x103d3, 0x103d3, 10),
+	(0x103d4, 0x103d4, 20),
+	(0x103d5, 0x103d5, 100),
+	(0x104a0, 0x104a0, 0),
+	(0x104a1, 0x104a1, 1),
+	(0x104a2, 0x104a2, 2),
+	(0x104a3, 0x104a3, 3),
+	(0x104a4, 0x104a4, 4),
+	(0x104a5, 0x104a5, 5),
+	(0x104a6, 0x104a6, 6),
+	(0x104a7, 0x104a7, 7),
+	(0x104a8, 0x104a8, 8),
+	(0x104a9, 0x104a9, 9),
+	(0x10858, 0x10858, 1),
+	(0x10859, 0x10859, 2),
+	(0x1085a, 0x1085a, 3),
+	(0x1085b, 0x1085b, 10),
+	(0x1085c, 0x1085c, 20),
+	(0x1085d, 0x1085d, 100),
+	(0x1085e, 0x1085e, 1000),
+	(0x1085f, 0x1085f, 10000),
+	(0x10879, 0x10879, 1),
+	(0x1087a, 0x1087a, 2),
+	(0x1087b, 0x1087b, 3),
+	(0x1087c, 0x1087c, 4),
+	(0x1087d, 0x1087d, 5),
+	(0x1087e, 0x1087e, 10),
+	(0x1087f, 0x1087f, 20),
+	(0x108a7, 0x108a7, 1),
+	(0x108a8, 0x108a8, 2),
+	(0x108a9, 0x108a9, 3),
+	(0x108aa, 0x108ab, 4),
+	(0x108ac, 0x108ac, 5),
+	(0x108ad, 0x108ad, 10),
+	(0x108ae, 0x108ae, 20),
+	(0x108af, 0x108af, 100),
+	(0x108fb, 0x108fb, 1),
+	(0x108fc, 0x108fc, 5),
+	(0x108fd, 0x108fd, 10),
+	(0x108fe, 0x108fe, 20),
+	(0x108ff, 0x108ff, 100),
+	(0x10916, 0x10916, 1),
+	(0x10917, 0x10917, 10),
+	(0x10918, 0x10918, 20),
+	(0x10919, 0x10919, 100),
+	(0x1091a, 0x1091a, 2),
+	(0x1091b, 0x1091b, 3),
+	(0x109c0, 0x109c0, 1),
+	(0x109c1, 0x109c1, 2),
+	(0x109c2, 0x109c2, 3),
+	(0x109c3, 0x109c3, 4),
+	(0x109c4, 0x109c4, 5),
+	(0x109c5, 0x109c5, 6),
+	(0x109c6, 0x109c6, 7),
+	(0x109c7, 0x109c7, 8),
+	(0x109c8, 0x109c8, 9),
+	(0x109c9, 0x109c9, 10),
+	(0x109ca, 0x109ca, 20),
+	(0x109cb, 0x109cb, 30),
+	(0x109cc, 0x109cc, 40),
+	(0x109cd, 0x109cd, 50),
+	(0x109ce, 0x109ce, 60),
+	(0x109cf, 0x109cf, 70),
+	(0x109d2, 0x109d2, 100),
+	(0x109d3, 0x109d3, 200),
+	(0x109d4, 0x109d4, 300),
+	(0x109d5, 0x109d5, 400),
+	(0x109d6, 0x109d6, 500),
+	(0x109d7, 0x109d7, 600),
+	(0x109d8, 0x109d8, 700),
+	(0x109d9, 0x109d9, 800),
+	(0x109da, 0x109da, 900),
+	(0x109db, 0x109db, 1000),
+	(0x109dc, 0x109dc, 2000),
+	(0x109dd, 0x109dd, 3000),
+	(0x109de, 0x109de, 4000),
+	(0x109df, 0x109df, 5000),
+	(0x109e0, 0x109e0, 6000),
+	(0x109e1, 0x109e1, 7000),
+	(0x109e2, 0x109e2, 8000),
+	(0x109e3, 0x109e3, 9000),
+	(0x109e4, 0x109e4, 10000),
+	(0x109e5, 0x109e5, 20000),
+	(0x109e6, 0x109e6, 30000),
+	(0x109e7, 0x109e7, 40000),
+	(0x109e8, 0x109e8, 50000),
+	(0x109e9, 0x109e9, 60000),
+	(0x109ea, 0x109ea, 70000),
+	(0x109eb, 0x109eb, 80000),
+	(0x109ec, 0x109ec, 90000),
+	(0x109ed, 0x109ed, 100000),
+	(0x109ee, 0x109ee, 200000),
+	(0x109ef, 0x109ef, 300000),
+	(0x109f0, 0x109f0, 400000),
+	(0x109f1, 0x109f1, 500000),
+	(0x109f2, 0x109f2, 600000),
+	(0x109f3, 0x109f3, 700000),
+	(0x109f4, 0x109f4, 800000),
+	(0x109f5, 0x109f5, 900000),
+	(0x10a40, 0x10a40, 1),
+	(0x10a41, 0x10a41, 2),
+	(0x10a42, 0x10a42, 3),
+	(0x10a43, 0x10a43, 4),
+	(0x10a44, 0x10a44, 10),
+	(0x10a45, 0x10a45, 20),
+	(0x10a46, 0x10a46, 100),
+	(0x10a47, 0x10a47, 1000),
+	(0x10a7d, 0x10a7d, 1),
+	(0x10a7e, 0x10a7e, 50),
+	(0x10a9d, 0x10a9d, 1),
+	(0x10a9e, 0x10a9e, 10),
+	(0x10a9f, 0x10a9f, 20),
+	(0x10aeb, 0x10aeb, 1),
+	(0x10aec, 0x10aec, 5),
+	(0x10aed, 0x10aed, 10),
+	(0x10aee, 0x10aee, 20),
+	(0x10aef, 0x10aef, 100),
+	(0x10b58, 0x10b58, 1),
+	(0x10b59, 0x10b59, 2),
+	(0x10b5a, 0x10b5a, 3),
+	(0x10b5b, 0x10b5b, 4),
+	(0x10b5c, 0x10b5c, 10),
+	(0x10b5d, 0x10b5d, 20),
+	(0x10b5e, 0x10b5e, 100),
+	(0x10b5f, 0x10b5f, 1000),
+	(0x10b78, 0x10b78, 1),
+	(0x10b79, 0x10b79, 2),
+	(0x10b7a, 0x10b7a, 3),
+	(0x10b7b, 0x10b7b, 4),
+	(0x10b7c, 0x10b7c, 10),
+	(0x10b7d, 0x10b7d, 20),
+	(0x10b7e, 0x10b7e, 100),
+	(0x10b7f, 0x10b7f, 1000),
+	(0x10ba9, 0x10ba9, 1),
+	(0x10baa, 0x10baa, 2),
+	(0x10bab, 0x10bab, 3),
+	(0x10bac, 0x10bac, 4),
+	(0x10bad, 0x10bad, 10),
+	(0x10bae, 0x10bae, 20),
+	(0x10baf, 0x10baf, 100),
+	(0x10cfa, 0x10cfa, 1),
+	(0x10cfb, 0x10cfb, 5),
+	(0x10cfc, 0x10cfc, 10),
+	(0x10cfd, 0x10cfd, 50),
+	(0x10cfe, 0x10cfe, 100),
+	(0x10cff, 0x10cff, 1000),
+	(0x10d30, 0x10d30, 0),
+	(0x10d31, 0x10d31, 1),
+	(0x10d32, 0x10d32, 2),
+	(0x10d33, 0x10d33, 3),
+	(0x10d34, 0x10d34, 4),
+	(0x10d35, 0x10d35, 5),
+	(0x10d36, 0x10d36, 6),
+	(0x10d37, 0x10d37, 7),
+	(0x10d38, 0x10d38, 8),
+	(0x10d39, 0x10d39, 9),
+	(0x10e60, 0x10e60, 1),
+	(0x10e61, 0x10e61, 2),
+	(0x10e62, 0x10e62, 3),
+	(0x10e63, 0x10e63, 4),
+	(0x10e64, 0x10e64, 5),
+	(0x10e65, 0x10e65, 6),
+	(0x10e66, 0x10e66, 7),
+	(0x10e67, 0x10e67, 8),
+	(0x10e68, 0x10e68, 9),
+	(0x10e69, 0x10e69, 10),
+	(0x10e6a, 0x10e6a, 20),
+	(0x10e6b, 0x10e6b, 30),
+	(0x10e6c, 0x10e6c, 40),
+	(0x10e6d, 0x10e6d, 50),
+	(0x10e6e, 0x10e6e, 60),
+	(0x10e6f, 0x10e6f, 70),
+	(0x10e70, 0x10e70, 80),
+	(0x10e71, 0x10e71, 90),
+	(0x10e72, 0x10e72, 100),
+	(0x10e73, 0x10e73, 200),
+	(0x10e74, 0x10e74, 300),
+	(0x10e75, 0x10e75, 400),
+	(0x10e76, 0x10e76, 500),
+	(0x10e77, 0x10e77, 600),
+	(0x10e78, 0x10e78, 700),
+	(0x10e79, 0x10e79, 800),
+	(0x10e7a, 0x10e7a, 900),
+	(0x10f1d, 0x10f1d, 1),
+	(0x10f1e, 0x10f1e, 2),
+	(0x10f1f, 0x10f1f, 3),
+	(0x10f20, 0x10f20, 4),
+	(0x10f21, 0x10f21, 5),
+	(0x10f22, 0x10f22, 10),
+	(0x10f23, 0x10f23, 20),
+	(0x10f24, 0x10f24, 30),
+	(0x10f25, 0x10f25, 100),
+	(0x10f51, 0x10f51, 1),
+	(0x10f52, 0x10f52, 10),
+	(0x10f53, 0x10f53, 20),
+	(0x10f54, 0x10f54, 100),
+	(0x10fc5, 0x10fc5, 1),
+	(0x10fc6, 0x10fc6, 2),
+	(0x10fc7, 0x10fc7, 3),
+	(0x10fc8, 0x10fc8, 4),
+	(0x10fc9, 0x10fc9, 10),
+	(0x10fca, 0x10fca, 20),
+	(0x10fcb, 0x10fcb, 100),
+	(0x11052, 0x11052, 1),
+	(0x11053, 0x11053, 2),
+	(0x11054, 0x11054, 3),
+	(0x11055, 0x11055, 4),
+	(0x11056, 0x11056, 5),
+	(0x11057, 0x11057, 6),
+	(0x11058, 0x11058, 7),
+	(0x11059, 0x11059, 8),
+	(0x1105a, 0x1105a, 9),
+	(0x1105b, 0x1105b, 10),
+	(0x1105c, 0x1105c, 20),
+	(0x1105d, 0x1105d, 30),
+	(0x1105e, 0x1105e, 40),
+	(0x1105f, 0x1105f, 50),
+	(0x11060, 0x11060, 60),
+	(0x11061, 0x11061, 70),
+	(0x11062, 0x11062, 80),
+	(0x11063, 0x11063, 90),
+	(0x11064, 0x11064, 100),
+	(0x11065, 0x11065, 1000),
+	(0x11066, 0x11066, 0),
+	(0x11067, 0x11067, 1),
+	(0x11068, 0x11068, 2),
+	(0x11069, 0x11069, 3),
+	(0x1106a, 0x1106a, 4),
+	(0x1106b, 0x1106b, 5),
+	(0x1106c, 0x1106c, 6),
+	(0x1106d, 0x1106d, 7),
+	(0x1106e, 0x1106e, 8),
+	(0x1106f, 0x1106f, 9),
+	(0x110f0, 0x110f0, 0),
+	(0x110f1, 0x110f1, 1),
+	(0x110f2, 0x110f2, 2),
+	(0x110f3, 0x110f3, 3),
+	(0x110f4, 0x110f4, 4),
+	(0x110f5, 0x110f5, 5),
+	(0x110f6, 0x110f6, 6),
+	(0x110f7, 0x110f7, 7),
+	(0x110f8, 0x110f8, 8),
+	(0x110f9, 0x110f9, 9),
+	(0x11136, 0x11136, 0),
+	(0x11137, 0x11137, 1),
+	(0x11138, 0x11138, 2),
+	(0x11139, 0x11139, 3),
+	(0x1113a, 0x1113a, 4),
+	(0x1113b, 0x1113b, 5),
+	(0x1113c, 0x1113c, 6),
+	(0x1113d, 0x1113d, 7),
+	(0x1113e, 0x1113e, 8),
+	(0x1113f, 0x1113f, 9),
+	(0x111d0, 0x111d0, 0),
+	(0x111d1, 0x111d1, 1),
+	(0x111d2, 0x111d2, 2),
+	(0x111d3, 0x111d3, 3),
+	(0x111d4, 0x111d4, 4),
+	(0x111d5, 0x111d5, 5),
+	(0x111d6, 0x111d6, 6),
+	(0x111d7, 0x111d7, 7),
+	(0x111d8, 0x111d8, 8),
+	(0x111d9, 0x111d9, 9),
+	(0x111e1, 0x111e1, 1),
+	(0x111e2, 0x111e2, 2),
+	(0x111e3, 0x111e3, 3),
+	(0x111e4, 0x111e4, 4),
+	(0x111e5, 0x111e5, 5),
+	(0x111e6, 0x111e6, 6),
+	(0x111e7, 0x111e7, 7),
+	(0x111e8, 0x111e8, 8),
+	(0x111e9, 0x111e9, 9),
+	(0x111ea, 0x111ea, 10),
+	(0x111eb, 0x111eb, 20),
+	(0x111ec, 0x111ec, 30),
+	(0x111ed, 0x111ed, 40),
+	(0x111ee, 0x111ee, 50),
+	(0x111ef, 0x111ef, 60),
+	(0x111f0, 0x111f0, 70),
+	(0x111f1, 0x111f1, 80),
+	(0x111f2, 0x111f2, 90),
+	(0x111f3, 0x111f3, 100),
+	(0x111f4, 0x111f4, 1000),
+	(0x112f0, 0x112f0, 0),
+	(0x112f1, 0x112f1, 1),
+	(0x112f2, 0x112f2, 2),
+	(0x112f3, 0x112f3, 3),
+	(0x112f4, 0x112f4, 4),
+	(0x112f5, 0x112f5, 5),
+	(0x112f6, 0x112f6, 6),
+	(0x112f7, 0x112f7, 7),
+	(0x112f8, 0x112f8, 8),
+	(0x112f9, 0x112f9, 9),
+	(0x11450, 0x11450, 0),
+	(0x11451, 0x11451, 1),
+	(0x11452, 0x11452, 2),
+	(0x11453, 0x11453, 3),
+	(0x11454, 0x11454, 4),
+	(0x11455, 0x11455, 5),
+	(0x11456, 0x11456, 6),
+	(0x11457, 0x11457, 7),
+	(0x11458, 0x11458, 8),
+	(0x11459, 0x11459, 9),
+	(0x114d0, 0x114d0, 0),
+	(0x114d1, 0x114d1, 1),
+	(0x114d2, 0x114d2, 2),
+	(0x114d3, 0x114d3, 3),
+	(0x114d4, 0x114d4, 4),
+	(0x114d5, 0x114d5, 5),
+	(0x114d6, 0x114d6, 6),
+	(0x114d7, 0x114d7, 7),
+	(0x114d8, 0x114d8, 8),
+	(0x114d9, 0x114d9, 9),
+	(0x11650, 0x11650, 0),
+	(0x11651, 0x11651, 1),
+	(0x11652, 0x11652, 2),
+	(0x11653, 0x11653, 3),
+	(0x11654, 0x11654, 4),
+	(0x11655, 0x11655, 5),
+	(0x11656, 0x11656, 6),
+	(0x11657, 0x11657, 7),
+	(0x11658, 0x11658, 8),
+	(0x11659, 0x11659, 9),
+	(0x116c0, 0x116c0, 0),
+	(0x116c1, 0x116c1, 1),
+	(0x116c2, 0x116c2, 2),
+	(0x116c3, 0x116c3, 3),
+	(0x116c4, 0x116c4, 4),
+	(0x116c5, 0x116c5, 5),
+	(0x116c6, 0x116c6, 6),
+	(0x116c7, 0x116c7, 7),
+	(0x116c8, 0x116c8, 8),
+	(0x116c9, 0x116c9, 9),
+	(0x11730, 0x11730, 0),
+	(0x11731, 0x11731, 1),
+	(0x11732, 0x11732, 2),
+	(0x11733, 0x11733, 3),
+	(0x11734, 0x11734, 4),
+	(0x11735, 0x11735, 5),
+	(0x11736, 0x11736, 6),
+	(0x11737, 0x11737, 7),
+	(0x11738, 0x11738, 8),
+	(0x11739, 0x11739, 9),
+	(0x1173a, 0x1173a, 10),
+	(0x1173b, 0x1173b, 20),
+	(0x118e0, 0x118e0, 0),
+	(0x118e1, 0x118e1, 1),
+	(0x118e2, 0x118e2, 2),
+	(0x118e3, 0x118e3, 3),
+	(0x118e4, 0x118e4, 4),
+	(0x118e5, 0x118e5, 5),
+	(0x118e6, 0x118e6, 6),
+	(0x118e7, 0x118e7, 7),
+	(0x118e8, 0x118e8, 8),
+	(0x118e9, 0x118e9, 9),
+	(0x118ea, 0x118ea, 10),
+	(0x118eb, 0x118eb, 20),
+	(0x118ec, 0x118ec, 30),
+	(0x118ed, 0x118ed, 40),
+	(0x118ee, 0x118ee, 50),
+	(0x118ef, 0x118ef, 60),
+	(0x118f0, 0x118f0, 70),
+	(0x118f1, 0x118f1, 80),
+	(0x118f2, 0x118f2, 90),
+	(0x11950, 0x11950, 0),
+	(0x11951, 0x11951, 1),
+	(0x11952, 0x11952, 2),
+	(0x11953, 0x11953, 3),
+	(0x11954, 0x11954, 4),
+	(0x11955, 0x11955, 5),
+	(0x11956, 0x11956, 6),
+	(0x11957, 0x11957, 7),
+	(0x11958, 0x11958, 8),
+	(0x11959, 0x11959, 9),
+	(0x11c50, 0x11c50, 0),
+	(0x11c51, 0x11c51, 1),
+	(0x11c52, 0x11c52, 2),
+	(0x11c53, 0x11c53, 3),
+	(0x11c54, 0x11c54, 4),
+	(0x11c55, 0x11c55, 5),
+	(0x11c56, 0x11c56, 6),
+	(0x11c57, 0x11c57, 7),
+	(0x11c58, 0x11c58, 8),
+	(0x11c59, 0x11c59, 9),
+	(0x11c5a, 0x11c5a, 1),
+	(0x11c5b, 0x11c5b, 2),
+	(0x11c5c, 0x11c5c, 3),
+	(0x11c5d, 0x11c5d, 4),
+	(0x11c5e, 0x11c5e, 5),
+	(0x11c5f, 0x11c5f, 6),
+	(0x11c60, 0x11c60, 7),
+	(0x11c61, 0x11c61, 8),
+	(0x11c62, 0x11c62, 9),
+	(0x11c63, 0x11c63, 10),
+	(0x11c64, 0x11c64, 20),
+	(0x11c65, 0x11c65, 30),
+	(0x11c66, 0x11c66, 40),
+	(0x11c67, 0x11c67, 50),
+	(0x11c68, 0x11c68, 60),
+	(0x11c69, 0x11c69, 70),
+	(0x11c6a, 0x11c6a, 80),
+	(0x11c6b, 0x11c6b, 90),
+	(0x11c6c, 0x11c6c, 100),
+	(0x11d50, 0x11d50, 0),
+	(0x11d51, 0x11d51, 1),
+	(0x11d52, 0x11d52, 2),
+	(0x11d53, 0x11d53, 3),
+	(0x11d54, 0x11d54, 4),
+	(0x11d55, 0x11d55, 5),
+	(0x11d56, 0x11d56, 6),
+	(0x11d57, 0x11d57, 7),
+	(0x11d58, 0x11d58, 8),
+	(0x11d59, 0x11d59, 9),
+	(0x11da0, 0x11da0, 0),
+	(0x11da1, 0x11da1, 1),
+	(0x11da2, 0x11da2, 2),
+	(0x11da3, 0x11da3, 3),
+	(0x11da4, 0x11da4, 4),
+	(0x11da5, 0x11da5, 5),
+	(0x11da6, 0x11da6, 6),
+	(0x11da7, 0x11da7, 7),
+	(0x11da8, 0x11da8, 8),
+	(0x11da9, 0x11da9, 9),
+	(0x12400, 0x12400, 2),
+	(0x12401, 0x12401, 3),
+	(0x12402, 0x12402, 4),
+	(0x12403, 0x12403, 5),
+	(0x12404, 0x12404, 6),
+	(0x12405, 0x12405, 7),
+	(0x12406, 0x12406, 8),
+	(0x12407, 0x12407, 9),
+	(0x12408, 0x12408, 3),
+	(0x12409, 0x12409, 4),
+	(0x1240a, 0x1240a, 5),
+	(0x1240b, 0x1240b, 6),
+	(0x1240c, 0x1240c, 7),
+	(0x1240d, 0x1240d, 8),
+	(0x1240e, 0x1240e, 9),
+	(0x1240f, 0x1240f, 4),
+	(0x12410, 0x12410, 5),
+	(0x12411, 0x12411, 6),
+	(0x12412, 0x12412, 7),
+	(0x12413, 0x12413, 8),
+	(0x12414, 0x12414, 9),
+	(0x12415, 0x12415, 1),
+	(0x12416, 0x12416, 2),
+	(0x12417, 0x12417, 3),
+	(0x12418, 0x12418, 4),
+	(0x12419, 0x12419, 5),
+	(0x1241a, 0x1241a, 6),
+	(0x1241b, 0x1241b, 7),
+	(0x1241c, 0x1241c, 8),
+	(0x1241d, 0x1241d, 9),
+	(0x1241e, 0x1241e, 1),
+	(0x1241f, 0x1241f, 2),
+	(0x12420, 0x12420, 3),
+	(0x12421, 0x12421, 4),
+	(0x12422, 0x12422, 5),
+	(0x12423, 0x12423, 2),
+	(0x12424, 0x12425, 3),
+	(0x12426, 0x12426, 4),
+	(0x12427, 0x12427, 5),
+	(0x12428, 0x12428, 6),
+	(0x12429, 0x12429, 7),
+	(0x1242a, 0x1242a, 8),
+	(0x1242b, 0x1242b, 9),
+	(0x1242c, 0x1242c, 1),
+	(0x1242d, 0x1242d, 2),
+	(0x1242e, 0x1242f, 3),
+	(0x12430, 0x12430, 4),
+	(0x12431, 0x12431, 5),
+	(0x12432, 0x12432, 216000),
+	(0x12433, 0x12433, 432000),
+	(0x12434, 0x12434, 1),
+	(0x12435, 0x12435, 2),
+	(0x12436, 0x12437, 3),
+	(0x12438, 0x12438, 4),
+	(0x12439, 0x12439, 5),
+	(0x1243a, 0x1243b, 3),
+	(0x1243c, 0x1243f, 4),
+	(0x12440, 0x12440, 6),
+	(0x12441, 0x12443, 7),
+	(0x12444, 0x12445, 8),
+	(0x12446, 0x12449, 9),
+	(0x1244a, 0x1244a, 2),
+	(0x1244b, 0x1244b, 3),
+	(0x1244c, 0x1244c, 4),
+	(0x1244d, 0x1244d, 5),
+	(0x1244e, 0x1244e, 6),
+	(0x1244f, 0x1244f, 1),
+	(0x12450, 0x12450, 2),
+	(0x12451, 0x12451, 3),
+	(0x12452, 0x12453, 4),
+	(0x12454, 0x12455, 5),
+	(0x12456, 0x12456, 2),
+	(0x12457, 0x12457, 3),
+	(0x12458, 0x12458, 1),
+	(0x12459, 0x12459, 2),
+	(0x12467, 0x12467, 40),
+	(0x12468, 0x12468, 50),
+	(0x12469, 0x12469, 4),
+	(0x1246a, 0x1246a, 5),
+	(0x1246b, 0x1246b, 6),
+	(0x1246c, 0x1246c, 7),
+	(0x1246d, 0x1246d, 8),
+	(0x1246e, 0x1246e, 9),
+	(0x16a60, 0x16a60, 0),
+	(0x16a61, 0x16a61, 1),
+	(0x16a62, 0x16a62, 2),
+	(0x16a63, 0x16a63, 3),
+	(0x16a64, 0x16a64, 4),
+	(0x16a65, 0x16a65, 5),
+	(0x16a66, 0x16a66, 6),
+	(0x16a67, 0x16a67, 7),
+	(0x16a68, 0x16a68, 8),
+	(0x16a69, 0x16a69, 9),
+	(0x16ac0, 0x16ac0, 0),
+	(0x16ac1, 0x16ac1, 1),
+	(0x16ac2, 0x16ac2, 2),
+	(0x16ac3, 0x16ac3, 3),
+	(0x16ac4, 0x16ac4, 4),
+	(0x16ac5, 0x16ac5, 5),
+	(0x16ac6, 0x16ac6, 6),
+	(0x16ac7, 0x16ac7, 7),
+	(0x16ac8, 0x16ac8, 8),
+	(0x16ac9, 0x16ac9, 9),
+	(0x16b50, 0x16b50, 0),
+	(0x16b51, 0x16b51, 1),
+	(0x16b52, 0x16b52, 2),
+	(0x16b53, 0x16b53, 3),
+	(0x16b54, 0x16b54, 4),
+	(0x16b55, 0x16b55, 5),
+	(0x16b56, 0x16b56, 6),
+	(0x16b57, 0x16b57, 7),
+	(0x16b58, 0x16b58, 8),
+	(0x16b59, 0x16b59, 9),
+	(0x16b5b, 0x16b5b, 10),
+	(0x16b5c, 0x16b5c, 100),
+	(0x16b5d, 0x16b5d, 10000),
+	(0x16b5e, 0x16b5e, 1000000),
+	(0x16b5f, 0x16b5f, 100000000),
+	(0x16b60, 0x16b60, 10000000000),
+	(0x16b61, 0x16b61, 1000000000000),
+	(0x16e80, 0x16e80, 0),
+	(0x16e81, 0x16e81, 1),
+	(0x16e82, 0x16e82, 2),
+	(0x16e83, 0x16e83, 3),
+	(0x16e84, 0x16e84, 4),
+	(0x16e85, 0x16e85, 5),
+	(0x16e86, 0x16e86, 6),
+	(0x16e87, 0x16e87, 7),
+	(0x16e88, 0x16e88, 8),
+	(0x16e89, 0x16e89, 9),
+	(0x16e8a, 0x16e8a, 10),
+	(0x16e8b, 0x16e8b, 11),
+	(0x16e8c, 0x16e8c, 12),
+	(0x16e8d, 0x16e8d, 13),
+	(0x16e8e, 0x16e8e, 14),
+	(0x16e8f, 0x16e8f, 15),
+	(0x16e90, 0x16e90, 16),
+	(0x16e91, 0x16e91, 17),
+	(0x16e92, 0x16e92, 18),
+	(0x16e93, 0x16e93, 19),
+	(0x16e94, 0x16e94, 1),
+	(0x16e95, 0x16e95, 2),
+	(0x16e96, 0x16e96, 3),
+	(0x1d2e0, 0x1d2e0, 0),
+	(0x1d2e1, 0x1d2e1, 1),
+	(0x1d2e2, 0x1d2e2, 2),
+	(0x1d2e3, 0x1d2e3, 3),
+	(0x1d2e4, 0x1d2e4, 4),
+	(0x1d2e5, 0x1d2e5, 5),
+	(0x1d2e6, 0x1d2e6, 6),
+	(0x1d2e7, 0x1d2e7, 7),
+	(0x1d2e8, 0x1d2e8, 8),
+	(0x1d2e9, 0x1d2e9, 9),
+	(0x1d2ea, 0x1d2ea, 10),
+	(0x1d2eb, 0x1d2eb, 11),
+	(0x1d2ec, 0x1d2ec, 12),
+	(0x1d2ed, 0x1d2ed, 13),
+	(0x1d2ee, 0x1d2ee, 14),
+	(0x1d2ef, 0x1d2ef, 15),
+	(0x1d2f0, 0x1d2f0, 16),
+	(0x1d2f1, 0x1d2f1, 17),
+	(0x1d2f2, 0x1d2f2, 18),
+	(0x1d2f3, 0x1d2f3, 19),
+	(0x1d360, 0x1d360, 1),
+	(0x1d361, 0x1d361, 2),
+	(0x1d362, 0x1d362, 3),
+	(0x1d363, 0x1d363, 4),
+	(0x1d364, 0x1d364, 5),
+	(0x1d365, 0x1d365, 6),
+	(0x1d366, 0x1d366, 7),
+	(0x1d367, 0x1d367, 8),
+	(0x1d368, 0x1d368, 9),
+	(0x1d369, 0x1d369, 10),
+	(0x1d36a, 0x1d36a, 20),
+	(0x1d36b, 0x1d36b, 30),
+	(0x1d36c, 0x1d36c, 40),
+	(0x1d36d, 0x1d36d, 50),
+	(0x1d36e, 0x1d36e, 60),
+	(0x1d36f, 0x1d36f, 70),
+	(0x1d370, 0x1d370, 80),
+	(0x1d371, 0x1d371, 90),
+	(0x1d372, 0x1d372, 1),
+	(0x1d373, 0x1d373, 2),
+	(0x1d374, 0x1d374, 3),
+	(0x1d375, 0x1d375, 4),
+	(0x1d376, 0x1d376, 5),
+	(0x1d377, 0x1d377, 1),
+	(0x1d378, 0x1d378, 5),
+	(0x1d7ce, 0x1d7ce, 0),
+	(0x1d7cf, 0x1d7cf, 1),
+	(0x1d7d0, 0x1d7d0, 2),
+	(0x1d7d1, 0x1d7d1, 3),
+	(0x1d7d2, 0x1d7d2, 4),
+	(0x1d7d3, 0x1d7d3, 5),
+	(0x1d7d4, 0x1d7d4, 6),
+	(0x1d7d5, 0x1d7d5, 7),
+	(0x1d7d6, 0x1d7d6, 8),
+	(0x1d7d7, 0x1d7d7, 9),
+	(0x1d7d8, 0x1d7d8, 0),
+	(0x1d7d9, 0x1d7d9, 1),
+	(0x1d7da, 0x1d7da, 2),
+	(0x1d7db, 0x1d7db, 3),
+	(0x1d7dc, 0x1d7dc, 4),
+	(0x1d7dd, 0x1d7dd, 5),
+	(0x1d7de, 0x1d7de, 6),
+	(0x1d7df, 0x1d7df, 7),
+	(0x1d7e0, 0x1d7e0, 8),
+	(0x1d7e1, 0x1d7e1, 9),
+	(0x1d7e2, 0x1d7e2, 0),
+	(0x1d7e3, 0x1d7e3, 1),
+	(0x1d7e4, 0x1d7e4, 2),
+	(0x1d7e5, 0x1d7e5, 3),
+	(0x1d7e6, 0x1d7e6, 4),
+	(0x1d7e7, 0x1d7e7, 5),
+	(0x1d7e8, 0x1d7e8, 6),
+	(0x1d7e9, 0x1d7e9, 7),
+	(0x1d7ea, 0x1d7ea, 8),
+	(0x1d7eb, 0x1d7eb, 9),
+	(0x1d7ec, 0x1d7ec, 0),
+	(0x1d7ed, 0x1d7ed, 1),
+	(0x1d7ee, 0x1d7ee, 2),
+	(0x1d7ef, 0x1d7ef, 3),
+	(0x1d7f0, 0x1d7f0, 4),
+	(0x1d7f1, 0x1d7f1, 5),
+	(0x1d7f2, 0x1d7f2, 6),
+	(0x1d7f3, 0x1d7f3, 7),
+	(0x1d7f4, 0x1d7f4, 8),
+	(0x1d7f5, 0x1d7f5, 9),
+	(0x1d7f6, 0x1d7f6, 0),
+	(0x1d7f7, 0x1d7f7, 1),
+	(0x1d7f8, 0x1d7f8, 2),
+	(0x1d7f9, 0x1d7f9, 3),
+	(0x1d7fa, 0x1d7fa, 4),
+	(0x1d7fb, 0x1d7fb, 5),
+	(0x1d7fc, 0x1d7fc, 6),
+	(0x1d7fd, 0x1d7fd, 7),
+	(0x1d7fe, 0x1d7fe, 8),
+	(0x1d7ff, 0x1d7ff, 9),
+	(0x1e140, 0x1e140, 0),
+	(0x1e141, 0x1e141, 1),
+	(0x1e142, 0x1e142, 2),
+	(0x1e143, 0x1e143, 3),
+	(0x1e144, 0x1e144, 4),
+	(0x1e145, 0x1e145, 5),
+	(0x1e146, 0x1e146, 6),
+	(0x1e147, 0x1e147, 7),
+	(0x1e148, 0x1e148, 8),
+	(0x1e149, 0x1e149, 9),
+	(0x1e2f0, 0x1e2f0, 0),
+	(0x1e2f1, 0x1e2f1, 1),
+	(0x1e2f2, 0x1e2f2, 2),
+	(0x1e2f3, 0x1e2f3, 3),
+	(0x1e2f4, 0x1e2f4, 4),
+	(0x1e2f5, 0x1e2f5, 5),
+	(0x1e2f6, 0x1e2f6, 6),
+	(0x1e2f7, 0x1e2f7, 7),
+	(0x1e2f8, 0x1e2f8, 8),
+	(0x1e2f9, 0x1e2f9, 9),
+	(0x1e8c7, 0x1e8c7, 1),
+	(0x1e8c8, 0x1e8c8, 2),
+	(0x1e8c9, 0x1e8c9, 3),
+	(0x1e8ca, 0x1e8ca, 4),
+	(0x1e8cb, 0x1e8cb, 5),
+	(0x1e8cc, 0x1e8cc, 6),
+	(0x1e8cd, 0x1e8cd, 7),
+	(0x1e8ce, 0x1e8ce, 8),
+	(0x1e8cf, 0x1e8cf, 9),
+	(0x1e950, 0x1e950, 0),
+	(0x1e951, 0x1e951, 1),
+	(0x1e952, 0x1e952, 2),
+	(0x1e953, 0x1e953, 3),
+	(0x1e954, 0x1e954, 4),
+	(0x1e955, 0x1e955, 5),
+	(0x1e956, 0x1e956, 6),
+	(0x1e957, 0x1e957, 7),
+	(0x1e958, 0x1e958, 8),
+	(0x1e959, 0x1e959, 9),
+	(0x1ec71, 0x1ec71, 1),
+	(0x1ec72, 0x1ec72, 2),
+	(0x1ec73, 0x1ec73, 3),
+	(0x1ec74, 0x1ec74, 4),
+	(0x1ec75, 0x1ec75, 5),
+	(0x1ec76, 0x1ec76, 6),
+	(0x1ec77, 0x1ec77, 7),
+	(0x1ec78, 0x1ec78, 8),
+	(0x1ec79, 0x1ec79, 9),
+	(0x1ec7a, 0x1ec7a, 10),
+	(0x1ec7b, 0x1ec7b, 20),
+	(0x1ec7c, 0x1ec7c, 30),
+	(0x1ec7d, 0x1ec7d, 40),
+	(0x1ec7e, 0x1ec7e, 50),
+	(0x1ec7f, 0x1ec7f, 60),
+	(0x1ec80, 0x1ec80, 70),
+	(0x1ec81, 0x1ec81, 80),
+	(0x1ec82, 0x1ec82, 90),
+	(0x1ec83, 0x1ec83, 100),
+	(0x1ec84, 0x1ec84, 200),
+	(0x1ec85, 0x1ec85, 300),
+	(0x1ec86, 0x1ec86, 400),
+	(0x1ec87, 0x1ec87, 500),
+	(0x1ec88, 0x1ec88, 600),
+	(0x1ec89, 0x1ec89, 700),
+	(0x1ec8a, 0x1ec8a, 800),
+	(0x1ec8b, 0x1ec8b, 900),
+	(0x1ec8c, 0x1ec8c, 1000),
+	(0x1ec8d, 0x1ec8d, 2000),
+	(0x1ec8e, 0x1ec8e, 3000),
+	(0x1ec8f, 0x1ec8f, 4000),
+	(0x1ec90, 0x1ec90, 5000),
+	(0x1ec91, 0x1ec91, 6000),
+	(0x1ec92, 0x1ec92, 7000),
+	(0x1ec93, 0x1ec93, 8000),
+	(0x1ec94, 0x1ec94, 9000),
+	(0x1ec95, 0x1ec95, 10000),
+	(0x1ec96, 0x1ec96, 20000),
+	(0x1ec97, 0x1ec97, 30000),
+	(0x1ec98, 0x1ec98, 40000),
+	(0x1ec99, 0x1ec99, 50000),
+	(0x1ec9a, 0x1ec9a, 60000),
+	(0x1ec9b, 0x1ec9b, 70000),
+	(0x1ec9c, 0x1ec9c, 80000),
+	(0x1ec9d, 0x1ec9d, 90000),
+	(0x1ec9e, 0x1ec9e, 100000),
+	(0x1ec9f, 0x1ec9f, 200000),
+	(0x1eca0, 0x1eca0, 100000),
+	(0x1eca1, 0x1eca1, 10000000),
+	(0x1eca2, 0x1eca2, 20000000),
+	(0x1eca3, 0x1eca3, 1),
+	(0x1eca4, 0x1eca4, 2),
+	(0x1eca5, 0x1eca5, 3),
+	(0x1eca6, 0x1eca6, 4),
+	(0x1eca7, 0x1eca7, 5),
+	(0x1eca8, 0x1eca8, 6),
+	(0x1eca9, 0x1eca9, 7),
+	(0x1ecaa, 0x1ecaa, 8),
+	(0x1ecab, 0x1ecab, 9),
+	(0x1ecb1, 0x1ecb1, 1),
+	(0x1ecb2, 0x1ecb2, 2),
+	(0x1ecb3, 0x1ecb3, 10000),
+	(0x1ecb4, 0x1ecb4, 100000),
+	(0x1ed01, 0x1ed01, 1),
+	(0x1ed02, 0x1ed02, 2),
+	(0x1ed03, 0x1ed03, 3),
+	(0x1ed04, 0x1ed04, 4),
+	(0x1ed05, 0x1ed05, 5),
+	(0x1ed06, 0x1ed06, 6),
+	(0x1ed07, 0x1ed07, 7),
+	(0x1ed08, 0x1ed08, 8),
+	(0x1ed09, 0x1ed09, 9),
+	(0x1ed0a, 0x1ed0a, 10),
+	(0x1ed0b, 0x1ed0b, 20),
+	(0x1ed0c, 0x1ed0c, 30),
+	(0x1ed0d, 0x1ed0d, 40),
+	(0x1ed0e, 0x1ed0e, 50),
+	(0x1ed0f, 0x1ed0f, 60),
+	(0x1ed10, 0x1ed10, 70),
+	(0x1ed11, 0x1ed11, 80),
+	(0x1ed12, 0x1ed12, 90),
+	(0x1ed13, 0x1ed13, 100),
+	(0x1ed14, 0x1ed14, 200),
+	(0x1ed15, 0x1ed15, 300),
+	(0x1ed16, 0x1ed16, 400),
+	(0x1ed17, 0x1ed17, 500),
+	(0x1ed18, 0x1ed18, 600),
+	(0x1ed19, 0x1ed19, 700),
+	(0x1ed1a, 0x1ed1a, 800),
+	(0x1ed1b, 0x1ed1b, 900),
+	(0x1ed1c, 0x1ed1c, 1000),
+	(0x1ed1d, 0x1ed1d, 2000),
+	(0x1ed1e, 0x1ed1e, 3000),
+	(0x1ed1f, 0x1ed1f, 4000),
+	(0x1ed20, 0x1ed20, 5000),
+	(0x1ed21, 0x1ed21, 6000),
+	(0x1ed22, 0x1ed22, 7000),
+	(0x1ed23, 0x1ed23, 8000),
+	(0x1ed24, 0x1ed24, 9000),
+	(0x1ed25, 0x1ed25, 10000),
+	(0x1ed26, 0x1ed26, 20000),
+	(0x1ed27, 0x1ed27, 30000),
+	(0x1ed28, 0x1ed28, 40000),
+	(0x1ed29, 0x1ed29, 50000),
+	(0x1ed2a, 0x1ed2a, 60000),
+	(0x1ed2b, 0x1ed2b, 70000),
+	(0x1ed2c, 0x1ed2c, 80000),
+	(0x1ed2d, 0x1ed2d, 90000),
+	(0x1ed2f, 0x1ed2f, 2),
+	(0x1ed30, 0x1ed30, 3),
+	(0x1ed31, 0x1ed31, 4),
+	(0x1ed32, 0x1ed32, 5),
+	(0x1ed33, 0x1ed33, 6),
+	(0x1ed34, 0x1ed34, 7),
+	(0x1ed35, 0x1ed35, 8),
+	(0x1ed36, 0x1ed36, 9),
+	(0x1ed37, 0x1ed37, 10),
+	(0x1ed38, 0x1ed38, 400),
+	(0x1ed39, 0x1ed39, 600),
+	(0x1ed3a, 0x1ed3a, 2000),
+	(0x1ed3b, 0x1ed3b, 10000),
+	(0x1f100, 0x1f101, 0),
+	(0x1f102, 0x1f102, 1),
+	(0x1f103, 0x1f103, 2),
+	(0x1f104, 0x1f104, 3),
+	(0x1f105, 0x1f105, 4),
+	(0x1f106, 0x1f106, 5),
+	(0x1f107, 0x1f107, 6),
+	(0x1f108, 0x1f108, 7),
+	(0x1f109, 0x1f109, 8),
+	(0x1f10a, 0x1f10a, 9),
+	(0x1f10b, 0x1f10c, 0),
+	(0x1fbf0, 0x1fbf0, 0),
+	(0x1fbf1, 0x1fbf1, 1),
+	(0x1fbf2, 0x1fbf2, 2),
+	(0x1fbf3, 0x1fbf3, 3),
+	(0x1fbf4, 0x1fbf4, 4),
+	(0x1fbf5, 0x1fbf5, 5),
+	(0x1fbf6, 0x1fbf6, 6),
+	(0x1fbf7, 0x1fbf7, 7),
+	(0x1fbf8, 0x1fbf8, 8),
+	(0x1fbf9, 0x1fbf9, 9),
+	(0x20001, 0x20001, 7),
+	(0x20064, 0x20064, 4),
+	(0x200e2, 0x200e2, 4),
+	(0x20121, 0x20121, 5),
+	(0x2092a, 0x2092a, 1),
+	(0x20983, 0x20983, 30),
+	(0x2098c, 0x2098c, 40),
+	(0x2099c, 0x2099c, 40),
+	(0x20aea, 0x20aea, 6),
+	(0x20afd, 0x20afd, 3),
+	(0x20b19, 0x20b19, 3),
+	(0x22390, 0x22390, 2),
+	(0x22998, 0x22998, 3),
+	(0x23b1b, 0x23b1b, 3),
+	(0x2626d, 0x2626d, 4),
+	(0x2f890, 0x2f890, 9),
+];
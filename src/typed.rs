@@ -0,0 +1,262 @@
+use core::iter::FusedIterator;
+use core::fmt;
+use crate::DecodeError;
+use crate::lead::{LEAD_BYTES, CONTINUATION, INVALID_LEAD, MIN_CODE_POINTS};
+
+/// Read the next Unicode character out of the given [`u8`](u8) iterator, reporting errors with
+/// the [`DecodeError`](crate::DecodeError) type.
+///
+/// Error offsets are relative to the first byte read by this call, which is the start of the
+/// faulty sequence, so they are always `0`. Use a [`TypedDecoder`](crate::TypedDecoder) to get
+/// offsets relative to the start of the input.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::{decode_typed, DecodeError};
+/// let mut bytes = [0xe2, 0x82, 0xac, 0xc3, 0x28].iter().cloned();
+/// assert_eq!(decode_typed(&mut bytes), Some(Ok('€')));
+/// assert_eq!(decode_typed(&mut bytes), Some(Err(DecodeError::InvalidContinuation { offset: 0, byte: 0x28 })));
+/// assert_eq!(decode_typed(&mut bytes), None);
+/// ```
+pub fn decode_typed<I: Iterator<Item=u8>>(iter: &mut I) -> Option<Result<char, DecodeError>> {
+	TypedDecoder::new(iter).next()
+}
+
+/// UTF-8 decoder iterator reporting errors with the [`DecodeError`](crate::DecodeError) type.
+///
+/// The [`Decoder`](crate::Decoder) iterator returns [`std::io::Error`] errors, which only
+/// distinguish invalid sequences from unexpected ends of input. This decoder yields a
+/// [`DecodeError`](crate::DecodeError) telling exactly why and where the decoding failed, which
+/// can still be converted into an [`std::io::Error`] with the `?` operator.
+///
+/// Without the `std` feature, this is the [`Decoder`](crate::Decoder) type of the crate.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::{TypedDecoder, DecodeError};
+/// fn errors(bytes: &[u8]) -> Vec<DecodeError> {
+///     TypedDecoder::new(bytes.iter().cloned()).filter_map(Result::err).collect()
+/// }
+///
+/// assert_eq!(errors(b"a\xff"), [DecodeError::InvalidLeadByte { offset: 1, byte: 0xff }]);
+/// assert_eq!(errors(b"ab\x80"), [DecodeError::InvalidLeadByte { offset: 2, byte: 0x80 }]);
+/// assert_eq!(errors(b"a\xe2\x82a"), [DecodeError::InvalidContinuation { offset: 1, byte: 0x61 }]);
+/// assert_eq!(errors(b"a\xe0\x80\xaf"), [DecodeError::Overlong { offset: 1, code_point: 0x2f }]);
+/// assert_eq!(errors(b"\xed\xa0\x80"), [DecodeError::Surrogate { offset: 0, code_point: 0xd800 }]);
+/// assert_eq!(errors(b"\xf4\x90\x80\x80"), [DecodeError::OutOfRange { offset: 0, code_point: 0x110000 }]);
+/// assert_eq!(errors(b"a\xf0\x9f\x98"), [DecodeError::UnexpectedEof { offset: 1 }]);
+///
+/// let mut decoder = TypedDecoder::new(b"a\xc3\xa9\xff".iter().cloned());
+/// assert_eq!(decoder.next(), Some(Ok('a')));
+/// assert_eq!(decoder.next(), Some(Ok('é')));
+/// assert_eq!(decoder.next(), Some(Err(DecodeError::InvalidLeadByte { offset: 3, byte: 0xff })));
+/// assert_eq!(decoder.next(), None);
+/// ```
+///
+/// ## Errors
+/// Like the [`Decoder`](crate::Decoder), this decoder resumes after an error at the next byte
+/// that may start a sequence, which may be the byte that ended the invalid sequence.
+/// The `0xC0`, `0xC1` and `0xF5` to `0xFF` bytes, that cannot start any valid sequence, are
+/// rejected before reading any continuation byte, so the byte following them is decoded.
+///
+/// ```rust
+/// # use utf8_decode::{TypedDecoder, DecodeError};
+/// for &a in &[0xc0, 0xc1, 0xf5, 0xff] {
+///     let bytes = [a, 0x41];
+///     let mut decoder = TypedDecoder::new(bytes.iter().cloned());
+///     assert_eq!(decoder.next(), Some(Err(DecodeError::InvalidLeadByte { offset: 0, byte: a })));
+///     assert_eq!(decoder.next(), Some(Ok('A')));
+///     assert_eq!(decoder.next(), None);
+/// }
+///
+/// let mut decoder = TypedDecoder::new(b"\xe2\x82AB".iter().cloned());
+/// assert_eq!(decoder.next(), Some(Err(DecodeError::InvalidContinuation { offset: 0, byte: 0x41 })));
+/// assert_eq!(decoder.next(), Some(Ok('A')));
+/// assert_eq!(decoder.next(), Some(Ok('B')));
+///
+/// // The stray continuation bytes following an error are skipped.
+/// let mut decoder = TypedDecoder::new(b"\xff\x80\x80C".iter().cloned());
+/// assert_eq!(decoder.next(), Some(Err(DecodeError::InvalidLeadByte { offset: 0, byte: 0xff })));
+/// assert_eq!(decoder.next(), Some(Ok('C')));
+/// ```
+pub struct TypedDecoder<R: Iterator<Item=u8>> {
+	bytes: R,
+	pending: Option<u8>,
+	offset: usize,
+	overlong: bool
+}
+
+impl<R: Iterator<Item=u8>> TypedDecoder<R> {
+	/// Creates a new `TypedDecoder` iterator from the given `u8` source iterator.
+	pub fn new(source: R) -> TypedDecoder<R> {
+		TypedDecoder {
+			bytes: source,
+			pending: None,
+			offset: 0,
+			overlong: false
+		}
+	}
+
+	/// Creates a new `TypedDecoder` iterator resuming the decoding of an input after `offset`
+	/// bytes, already decoded from `pending` and `source`: `pending` is the byte, if any, kept to
+	/// be decoded before the rest of `source`.
+	#[cfg(feature = "std")]
+	pub(crate) fn resume(source: R, pending: Option<u8>, offset: usize) -> TypedDecoder<R> {
+		TypedDecoder {
+			bytes: source,
+			pending,
+			offset,
+			overlong: false
+		}
+	}
+
+	/// Makes this decoder read `0xC0` and `0xC1` as the lead bytes of (overlong) two-byte
+	/// sequences, reported as [`Overlong`](DecodeError::Overlong) once complete, instead of
+	/// rejecting them right away.
+	#[cfg(feature = "std")]
+	pub(crate) fn accept_overlong(mut self) -> TypedDecoder<R> {
+		self.overlong = true;
+		self
+	}
+
+	/// Returns a reference to the source iterator.
+	pub fn get_ref(&self) -> &R {
+		&self.bytes
+	}
+
+	/// Returns a mutable reference to the source iterator.
+	pub fn get_mut(&mut self) -> &mut R {
+		&mut self.bytes
+	}
+
+	/// Unwraps this `TypedDecoder`, returning the source iterator.
+	///
+	/// Right after an error, the byte that ended the invalid sequence may be kept by the decoder
+	/// to be decoded next: it is then lost.
+	pub fn into_inner(self) -> R {
+		self.bytes
+	}
+
+	/// Read the next continuation byte of the UTF-8 character started at `start`.
+	/// The byte is returned as a `u32` for later shifting.
+	/// Any other byte is kept to be decoded next.
+	fn next_byte(&mut self, start: usize) -> Result<u32, DecodeError> {
+		match self.bytes.next() {
+			Some(c) => {
+				if c & 0xC0 == 0x80 {
+					self.offset += 1;
+					Ok((c & 0x3F) as u32)
+				} else {
+					self.pending = Some(c);
+					Err(DecodeError::InvalidContinuation { offset: start, byte: c })
+				}
+			},
+			None => Err(DecodeError::UnexpectedEof { offset: start })
+		}
+	}
+
+	/// Number of continuation bytes following the given lead byte, or [`CONTINUATION`] or
+	/// [`INVALID_LEAD`] if it cannot start a sequence.
+	fn len(&self, a: u8) -> u8 {
+		match LEAD_BYTES[a as usize] {
+			INVALID_LEAD if self.overlong && a < 0xC2 => 1,
+			len => len
+		}
+	}
+
+	/// Skips the bytes following an invalid sequence, up to the next byte that may start a
+	/// sequence, kept to be decoded next.
+	fn skip_invalid(&mut self) {
+		let mut b = match self.pending.take() {
+			Some(b) => b,
+			None => match self.bytes.next() {
+				Some(b) => b,
+				None => return
+			}
+		};
+		while self.len(b) >= CONTINUATION {
+			self.offset += 1;
+			b = match self.bytes.next() {
+				Some(b) => b,
+				None => return
+			}
+		}
+
+		self.pending = Some(b)
+	}
+
+	/// Read the next Unicode character given its first byte, starting at `start`.
+	fn decode_from(&mut self, a: u8, start: usize) -> Result<char, DecodeError> {
+		let len = self.len(a);
+		let (c, min) = match len {
+			0 => return Ok(a as char),
+			// Rejected before reading any continuation byte.
+			CONTINUATION | INVALID_LEAD => return Err(DecodeError::InvalidLeadByte { offset: start, byte: a }),
+			len => {
+				let mut c = a as u32 & (0x7F >> (len + 1));
+				for _ in 0..len {
+					c = c << 6 | self.next_byte(start)?;
+				}
+
+				(c, MIN_CODE_POINTS[len as usize])
+			}
+		};
+
+		if c < min {
+			Err(DecodeError::Overlong { offset: start, code_point: c })
+		} else if (0xD800..=0xDFFF).contains(&c) {
+			Err(DecodeError::Surrogate { offset: start, code_point: c })
+		} else {
+			core::char::from_u32(c).ok_or(DecodeError::OutOfRange { offset: start, code_point: c })
+		}
+	}
+}
+
+impl<R: Iterator<Item=u8>> Iterator for TypedDecoder<R> {
+	type Item = Result<char, DecodeError>;
+
+	fn next(&mut self) -> Option<Result<char, DecodeError>> {
+		let start = self.offset;
+		let a = match self.pending.take() {
+			Some(a) => a,
+			None => self.bytes.next()?
+		};
+		self.offset += 1;
+		let c = self.decode_from(a, start);
+		if c.is_err() {
+			self.skip_invalid()
+		}
+
+		Some(c)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let pending = self.pending.is_some() as usize;
+		let (lower, upper) = self.bytes.size_hint();
+		((lower + pending) / 4, upper.and_then(|upper| upper.checked_add(pending)))
+	}
+}
+
+impl<R: FusedIterator<Item=u8>> FusedIterator for TypedDecoder<R> {}
+
+impl<R: Clone + Iterator<Item=u8>> Clone for TypedDecoder<R> {
+	fn clone(&self) -> TypedDecoder<R> {
+		TypedDecoder {
+			bytes: self.bytes.clone(),
+			pending: self.pending,
+			offset: self.offset,
+			overlong: self.overlong
+		}
+	}
+}
+
+impl<R: fmt::Debug + Iterator<Item=u8>> fmt::Debug for TypedDecoder<R> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("TypedDecoder")
+			.field("bytes", &self.bytes)
+			.field("pending", &self.pending)
+			.field("offset", &self.offset)
+			.field("overlong", &self.overlong)
+			.finish()
+	}
+}
@@ -0,0 +1,53 @@
+use std::io::Result;
+use crate::Decoder;
+
+/// Decodes the given bytes and returns the number of UTF-16 code units needed to encode them.
+///
+/// Characters of the Basic Multilingual Plane count for one code unit, other characters for two
+/// (a surrogate pair). This is the exact capacity needed to transcode the input into a
+/// `Vec<u16>`.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::utf16_len;
+/// let s = "aé€😀";
+/// assert_eq!(utf16_len(s.as_bytes()).unwrap(), 5);
+/// assert_eq!(utf16_len(s.as_bytes()).unwrap(), s.encode_utf16().count());
+/// ```
+///
+/// ## Errors
+/// Returns the first decoding error encountered.
+pub fn utf16_len(bytes: &[u8]) -> Result<usize> {
+	let mut len = 0;
+	for c in Decoder::new(bytes.iter().cloned()) {
+		len += c?.len_utf16();
+	}
+
+	Ok(len)
+}
+
+/// Decodes the given bytes into UTF-16 code units.
+///
+/// Characters outside of the Basic Multilingual Plane are encoded as surrogate pairs.
+/// The output is preallocated with the exact capacity computed by [`utf16_len`].
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::decode_to_utf16;
+/// let s = "aé€😀";
+/// let units = decode_to_utf16(s.as_bytes()).unwrap();
+/// assert_eq!(units, [0x61, 0xe9, 0x20ac, 0xd83d, 0xde00]);
+/// assert_eq!(units, s.encode_utf16().collect::<Vec<_>>());
+/// ```
+///
+/// ## Errors
+/// Returns the first decoding error encountered.
+pub fn decode_to_utf16(bytes: &[u8]) -> Result<Vec<u16>> {
+	let mut units = Vec::with_capacity(utf16_len(bytes)?);
+	let mut buffer = [0; 2];
+	for c in Decoder::new(bytes.iter().cloned()) {
+		units.extend_from_slice(c?.encode_utf16(&mut buffer));
+	}
+
+	Ok(units)
+}
@@ -0,0 +1,66 @@
+use std::io::Result;
+use crate::tables;
+
+/// East Asian Width property of a character.
+///
+/// See [Unicode Standard Annex #11](https://www.unicode.org/reports/tr11/).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum EastAsianWidth {
+	/// Neutral (`N`), characters not occurring in East Asian typography.
+	Neutral,
+
+	/// Ambiguous (`A`), characters whose width depends on the context.
+	Ambiguous,
+
+	/// Fullwidth (`F`), wide compatibility characters.
+	Fullwidth,
+
+	/// Halfwidth (`H`), narrow compatibility characters.
+	Halfwidth,
+
+	/// Narrow (`Na`), characters with a wide counterpart.
+	Narrow,
+
+	/// Wide (`W`), characters always wide in East Asian typography.
+	Wide
+}
+
+impl EastAsianWidth {
+	/// Returns the East Asian Width property of the given character.
+	pub fn of(c: char) -> EastAsianWidth {
+		tables::lookup(tables::EAST_ASIAN_WIDTH, c).unwrap_or(EastAsianWidth::Neutral)
+	}
+}
+
+/// UTF-8 decoder iterator yielding each character with its East Asian Width.
+///
+/// Created by the [`Decoder::with_eaw`](crate::Decoder::with_eaw) method.
+///
+/// ## Example
+/// ```rust
+/// # use utf8_decode::{Decoder, EastAsianWidth};
+/// let mut decoder = Decoder::new("a漢１".bytes()).with_eaw();
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('a', EastAsianWidth::Narrow));
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('漢', EastAsianWidth::Wide));
+/// assert_eq!(decoder.next().unwrap().unwrap(), ('１', EastAsianWidth::Fullwidth));
+/// assert!(decoder.next().is_none());
+/// ```
+pub struct WithEaw<I: Iterator<Item=Result<char>>> {
+	chars: I
+}
+
+impl<I: Iterator<Item=Result<char>>> WithEaw<I> {
+	pub(crate) fn new(chars: I) -> WithEaw<I> {
+		WithEaw {
+			chars
+		}
+	}
+}
+
+impl<I: Iterator<Item=Result<char>>> Iterator for WithEaw<I> {
+	type Item = Result<(char, EastAsianWidth)>;
+
+	fn next(&mut self) -> Option<Result<(char, EastAsianWidth)>> {
+		self.chars.next().map(|c| c.map(|c| (c, EastAsianWidth::of(c))))
+	}
+}